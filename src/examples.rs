@@ -197,8 +197,8 @@ fn game_object_example() {
     
     impl BinarySerializable for GameObject {}
     
-    impl std::fmt::Display for GameObject {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    impl core::fmt::Display for GameObject {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
             write!(
                 f,
                 "GameObject '{}' (Health: {:.1}, Active: {})\n  Transform: {}\n  Color: {}\n  Bounds: {}",
@@ -14,6 +14,16 @@
 //! - JSON serialization/deserialization with serde
 //! - Binary serialization/deserialization with bincode
 //! - Built on top of the high-performance `glam` math library
+//!
+//! The `std` feature (on by default) provides clocks (`DateTime::now`,
+//! `GameSessionInfo::new`, ...), OS-seeded `Guid::new_guid`, the global
+//! `Name` table and `BinarySerializable`'s bincode methods. Without it the
+//! crate is `no_std` and only needs `alloc`; clock-dependent types take
+//! explicit timestamps through their `_at` constructors instead.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
 pub mod types;
 
@@ -23,9 +33,26 @@ pub use glam::{Mat3, Mat4, Quat, Vec2, Vec3, Vec4, DQuat, DMat3, DMat4, DVec2, D
 // Re-export our custom types
 pub use types::*;
 
+/// Most bytes `BinarySerializable::from_binary` will read before giving up (16 MiB)
+///
+/// Length prefixes in untrusted input count against this budget, so a bogus
+/// prefix fails with a size-limit error instead of a huge allocation. Use
+/// `from_binary_with_limit` to pick a different budget.
+pub const DEFAULT_BINARY_LIMIT: u64 = 16 * 1024 * 1024;
+
 /// Trait for binary serialization/deserialization
+///
+/// # Requires `std`
+///
+/// Every method needs the `std` feature, because bincode 1 does. Without it
+/// this is a marker trait with no methods and there is no fallback encoding:
+/// serialize with any serde format that supports `no_std` (the types all
+/// implement `Serialize` and `Deserialize`), or with `StableBinary` for the
+/// types that have it. This is the same split as `Name`, which is gated out
+/// of `no_std` builds entirely because its global table needs a lock.
 pub trait BinarySerializable: Sized {
     /// Serialize to binary format
+    #[cfg(feature = "std")]
     fn to_binary(&self) -> Result<Vec<u8>, bincode::Error>
     where
         Self: serde::Serialize,
@@ -33,11 +60,44 @@ pub trait BinarySerializable: Sized {
         bincode::serialize(self)
     }
 
-    /// Deserialize from binary format
+    /// Serialize to binary format, appending to `buf`
+    ///
+    /// Same bytes as `to_binary`, but reuses the caller's buffer instead of
+    /// allocating a new one per call. Clear the buffer between packets, or
+    /// append several values to build one.
+    #[cfg(feature = "std")]
+    fn to_binary_into(&self, buf: &mut Vec<u8>) -> Result<(), bincode::Error>
+    where
+        Self: serde::Serialize,
+    {
+        bincode::serialize_into(buf, self)
+    }
+
+    /// Deserialize from binary format, reading at most `DEFAULT_BINARY_LIMIT` bytes
+    #[cfg(feature = "std")]
     fn from_binary(data: &[u8]) -> Result<Self, bincode::Error>
     where
         Self: serde::de::DeserializeOwned,
     {
-        bincode::deserialize(data)
+        Self::from_binary_with_limit(data, DEFAULT_BINARY_LIMIT)
+    }
+
+    /// Deserialize from binary format, failing once more than `max_bytes` would be read
+    ///
+    /// Uses the same encoding as `to_binary` (fixed-width integers, trailing
+    /// bytes ignored). bincode drops the limit when reading from a slice, so
+    /// this reads through `deserialize_from`, which enforces it.
+    #[cfg(feature = "std")]
+    fn from_binary_with_limit(data: &[u8], max_bytes: u64) -> Result<Self, bincode::Error>
+    where
+        Self: serde::de::DeserializeOwned,
+    {
+        use bincode::Options;
+
+        bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .allow_trailing_bytes()
+            .with_limit(max_bytes)
+            .deserialize_from(data)
     }
 }
\ No newline at end of file
@@ -1,22 +1,117 @@
 //! Axis-Aligned Bounding Box (AABB)
 
 use crate::BinarySerializable;
-use crate::types::{Vector, Transform};
+use crate::types::math::nearly_equal::max_error;
+use crate::types::{Axis, NearlyEqual, Plane, Vector, VectorExt, Transform, Ray};
+use glam::Vec3;
 use serde::{Deserialize, Serialize};
-use std::fmt;
+use core::fmt;
+#[cfg(not(feature = "std"))]
+use crate::types::math::float_math::FloatMath;
 
 /// Axis-Aligned Bounding Box (AABB)
 /// 
 /// Represents a 3D bounding box aligned with the coordinate axes.
 /// Commonly used for collision detection and spatial partitioning.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(feature = "rkyv", archive(check_bytes, compare(PartialEq)), archive_attr(derive(Debug)))]
 pub struct BoundingBox {
     /// Minimum corner of the box
+    #[serde(with = "corner_serde")]
     pub min: Vector,
     /// Maximum corner of the box
+    #[serde(with = "corner_serde")]
     pub max: Vector,
 }
 
+/// Serde for box corners that survives the infinities in [`BoundingBox::EMPTY`]
+///
+/// JSON has no infinity: serde_json writes it as `null` and then refuses to
+/// read it back. In human-readable formats, non-finite components are
+/// written as the strings `"inf"`, `"-inf"` and `"nan"` instead. Finite
+/// corners and binary formats keep glam's plain `[x, y, z]` encoding.
+mod corner_serde {
+    use super::Vector;
+    use core::fmt;
+    use serde::de::{self, Deserializer, Visitor};
+    use serde::ser::{SerializeTupleStruct, Serializer};
+    use serde::{Deserialize, Serialize};
+
+    pub fn serialize<S: Serializer>(corner: &Vector, serializer: S) -> Result<S::Ok, S::Error> {
+        if !serializer.is_human_readable() || corner.is_finite() {
+            return corner.serialize(serializer);
+        }
+        let mut tuple = serializer.serialize_tuple_struct("DVec3", 3)?;
+        for component in corner.to_array() {
+            tuple.serialize_field(&Component(component))?;
+        }
+        tuple.end()
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vector, D::Error> {
+        if !deserializer.is_human_readable() {
+            return Vector::deserialize(deserializer);
+        }
+        let [x, y, z] = <[Component; 3]>::deserialize(deserializer)?;
+        Ok(Vector::new(x.0, y.0, z.0))
+    }
+
+    /// One corner component, as a number or a non-finite string
+    struct Component(f64);
+
+    impl Serialize for Component {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            if self.0.is_nan() {
+                serializer.serialize_str("nan")
+            } else if self.0 == f64::INFINITY {
+                serializer.serialize_str("inf")
+            } else if self.0 == f64::NEG_INFINITY {
+                serializer.serialize_str("-inf")
+            } else {
+                serializer.serialize_f64(self.0)
+            }
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Component {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_any(ComponentVisitor)
+        }
+    }
+
+    struct ComponentVisitor;
+
+    impl Visitor<'_> for ComponentVisitor {
+        type Value = Component;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "a number or one of \"inf\", \"-inf\" and \"nan\"")
+        }
+
+        fn visit_f64<E: de::Error>(self, value: f64) -> Result<Component, E> {
+            Ok(Component(value))
+        }
+
+        fn visit_i64<E: de::Error>(self, value: i64) -> Result<Component, E> {
+            Ok(Component(value as f64))
+        }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<Component, E> {
+            Ok(Component(value as f64))
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Component, E> {
+            match value {
+                "inf" => Ok(Component(f64::INFINITY)),
+                "-inf" => Ok(Component(f64::NEG_INFINITY)),
+                "nan" => Ok(Component(f64::NAN)),
+                _ => Err(E::invalid_value(de::Unexpected::Str(value), &self)),
+            }
+        }
+    }
+}
+
 impl fmt::Display for BoundingBox {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let center = self.center();
@@ -34,6 +129,21 @@ impl fmt::Display for BoundingBox {
 
 impl BinarySerializable for BoundingBox {}
 
+impl crate::types::SerializedSize for BoundingBox {
+    fn serialized_size(&self) -> usize {
+        48
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl crate::types::ZeroCopy for BoundingBox {}
+
+impl NearlyEqual for BoundingBox {
+    fn max_component_error(&self, other: &Self) -> f64 {
+        max_error([self.min.max_component_error(&other.min), self.max.max_component_error(&other.max)])
+    }
+}
+
 impl BoundingBox {
     /// Empty bounding box (inverted min/max for initialization)
     pub const EMPTY: Self = Self {
@@ -41,8 +151,17 @@ impl BoundingBox {
         max: Vector::new(f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY),
     };
 
+    /// Zero-size bounding box at the origin
+    ///
+    /// Unlike `EMPTY` it is valid and contains the origin, but with no
+    /// volume `is_empty` still reports true.
+    pub const ZERO: Self = Self {
+        min: Vector::ZERO,
+        max: Vector::ZERO,
+    };
+
     /// Create a new bounding box with the given min and max corners
-    pub fn new(min: Vector, max: Vector) -> Self {
+    pub const fn new(min: Vector, max: Vector) -> Self {
         Self { min, max }
     }
 
@@ -63,16 +182,50 @@ impl BoundingBox {
     }
 
     /// Create a bounding box that encompasses all given points
+    ///
+    /// Points are folded in chunks of four independent running bounds, so
+    /// the min/max chains don't wait on each other and the loop has no
+    /// per-point branches.
     pub fn from_points(points: &[Vector]) -> Self {
-        if points.is_empty() {
+        const LANES: usize = 4;
+        let Some(&first) = points.first() else {
             return Self::EMPTY;
+        };
+
+        let mut min = [first; LANES];
+        let mut max = [first; LANES];
+        let chunks = points.chunks_exact(LANES);
+        let remainder = chunks.remainder();
+        for chunk in chunks {
+            for lane in 0..LANES {
+                min[lane] = min[lane].min(chunk[lane]);
+                max[lane] = max[lane].max(chunk[lane]);
+            }
+        }
+        for (lane, &point) in remainder.iter().enumerate() {
+            min[lane] = min[lane].min(point);
+            max[lane] = max[lane].max(point);
         }
 
-        let mut bbox = Self::from_point(points[0]);
-        for &point in &points[1..] {
-            bbox = bbox.expand_to_include(point);
+        Self {
+            min: min[0].min(min[1]).min(min[2].min(min[3])),
+            max: max[0].max(max[1]).max(max[2].max(max[3])),
         }
-        bbox
+    }
+
+    /// Bounds of `points` after applying `transform`, without storing the transformed points
+    ///
+    /// Same result as transforming each point and calling `from_points`,
+    /// but the matrix is built once.
+    pub fn from_transformed_points(points: &[Vector], transform: Transform) -> Self {
+        let matrix = transform.to_matrix();
+        let Some(&first) = points.first() else {
+            return Self::EMPTY;
+        };
+        let first = matrix.transform_point3(first);
+        points[1..].iter().fold(Self::from_point(first), |bbox, &point| {
+            bbox.expand_to_include(matrix.transform_point3(point))
+        })
     }
 
     /// Get the center point of the bounding box
@@ -176,29 +329,88 @@ impl BoundingBox {
     }
 
     /// Transform the bounding box by the given transform
+    ///
+    /// Corners go through the transform in f64, so boxes far from the
+    /// origin keep sub-millimetre precision.
     pub fn transform(self, transform: Transform) -> Self {
         if self.is_empty() {
             return Self::EMPTY;
         }
 
-        // Transform all 8 corners of the box
-        let corners = [
-            Vector::new(self.min.x, self.min.y, self.min.z),
-            Vector::new(self.max.x, self.min.y, self.min.z),
-            Vector::new(self.min.x, self.max.y, self.min.z),
-            Vector::new(self.max.x, self.max.y, self.min.z),
-            Vector::new(self.min.x, self.min.y, self.max.z),
-            Vector::new(self.max.x, self.min.y, self.max.z),
-            Vector::new(self.min.x, self.max.y, self.max.z),
-            Vector::new(self.max.x, self.max.y, self.max.z),
-        ];
+        let mut corners = self.corners();
+        transform.transform_points(&mut corners);
+        Self::from_points(&corners)
+    }
 
-        let transformed_corners: Vec<Vector> = corners
-            .iter()
-            .map(|&corner| transform.transform_point(corner))
-            .collect();
+    /// Get the 8 corners of the box
+    ///
+    /// Bit 0 of the index selects max X, bit 1 max Y and bit 2 max Z, so
+    /// corner 0 is `min`, corner 7 is `max`, and corners whose indices
+    /// differ in one bit share an edge.
+    pub fn corners(self) -> [Vector; 8] {
+        core::array::from_fn(|index| {
+            Vector::new(
+                if index & 1 == 0 { self.min.x } else { self.max.x },
+                if index & 2 == 0 { self.min.y } else { self.max.y },
+                if index & 4 == 0 { self.min.z } else { self.max.z },
+            )
+        })
+    }
+
+    /// Get the 12 edges of the box as pairs of corners, for debug drawing
+    ///
+    /// The four X-aligned edges come first, then Y, then Z; each edge runs
+    /// from its lower to its higher corner (see `corners`).
+    pub fn edges(self) -> [(Vector, Vector); 12] {
+        let corners = self.corners();
+        core::array::from_fn(|index| {
+            let axis_bit = 1 << (index / 4);
+            // Spread the two remaining bits of the edge number around the axis bit
+            let low_bits = index % 4;
+            let start = (low_bits & (axis_bit - 1)) | ((low_bits & !(axis_bit - 1)) << 1);
+            (corners[start], corners[start | axis_bit])
+        })
+    }
+
+    /// Get the 6 face planes with normals pointing outward
+    ///
+    /// Ordered -X, +X, -Y, +Y, -Z, +Z.
+    pub fn face_planes(self) -> [Plane; 6] {
+        core::array::from_fn(|index| {
+            let axis = Axis::ALL[index / 2].to_vector();
+            if index % 2 == 0 {
+                Plane::new(-axis, -self.min.dot(axis))
+            } else {
+                Plane::new(axis, self.max.dot(axis))
+            }
+        })
+    }
+
+    /// Get the extent (half-size) along one axis
+    pub fn get_axis_extent(self, axis: Axis) -> f64 {
+        self.extent().get_component(axis)
+    }
 
-        Self::from_points(&transformed_corners)
+    /// Split the box with a plane perpendicular to `axis`, returning the lower and upper halves
+    ///
+    /// `position` is clamped to the box, so the halves always tile it
+    /// exactly (one of them is flat when the position is outside).
+    pub fn split(self, axis: Axis, position: f64) -> (BoundingBox, BoundingBox) {
+        let index = axis.index();
+        let position = position.clamp(self.min[index], self.max[index]);
+        let mut lower = self;
+        let mut upper = self;
+        lower.max[index] = position;
+        upper.min[index] = position;
+        (lower, upper)
+    }
+
+    /// Get the per-axis overlap depth with another box
+    ///
+    /// Components are zero where the boxes touch and negative where they
+    /// are separated (by that distance).
+    pub fn overlap_amount(self, other: BoundingBox) -> Vector {
+        self.max.min(other.max) - self.min.max(other.min)
     }
 
     /// Get the distance from a point to the bounding box (0 if inside)
@@ -211,6 +423,158 @@ impl BoundingBox {
     pub fn closest_point_to(self, point: Vector) -> Vector {
         point.clamp(self.min, self.max)
     }
+
+    /// Intersect a ray with this box, returning the entry distance along the ray
+    ///
+    /// See `Ray::intersects_box` for the exact semantics.
+    pub fn intersects_ray(self, ray: Ray) -> Option<f64> {
+        ray.intersects_box(self)
+    }
+
+    /// Get the minimum translation vector that moves this box out of `other`
+    ///
+    /// On each axis the push depth is the distance to the nearer face of
+    /// `other` to exit through, which also holds when one box contains the
+    /// other. The vector points along the axis with the shallowest push,
+    /// away from the center of `other` when both exits are equally near.
+    /// Boxes that only touch return a zero-length vector; separated or
+    /// invalid boxes return `None`.
+    pub fn penetration(self, other: BoundingBox) -> Option<Vector> {
+        if !self.is_valid() || !other.is_valid() {
+            return None;
+        }
+
+        if self.overlap_amount(other).min_element() < 0.0 {
+            return None;
+        }
+
+        // Distance to move along +axis (out past other.max) and -axis (out past other.min)
+        let positive = other.max - self.min;
+        let negative = self.max - other.min;
+        let depth = positive.min(negative);
+        let delta = self.center() - other.center();
+        let mut mtv = Vector::ZERO;
+        let axis = if depth.x <= depth.y && depth.x <= depth.z {
+            0
+        } else if depth.y <= depth.z {
+            1
+        } else {
+            2
+        };
+        let toward_positive = positive[axis] < negative[axis] || (positive[axis] == negative[axis] && delta[axis] >= 0.0);
+        mtv[axis] = if toward_positive { positive[axis] } else { -negative[axis] };
+        Some(mtv)
+    }
+
+    /// Sweep this box along `velocity` against a static `other` box
+    ///
+    /// Returns the time of impact in `[0, 1]`, where 1 is the full
+    /// `velocity` step. Boxes that already overlap return `Some(0.0)`.
+    pub fn sweep(self, velocity: Vector, other: BoundingBox) -> Option<f64> {
+        if !self.is_valid() || !other.is_valid() {
+            return None;
+        }
+        if self.intersects(other) {
+            return Some(0.0);
+        }
+
+        let mut t_entry = f64::NEG_INFINITY;
+        let mut t_exit = f64::INFINITY;
+
+        for axis in 0..3 {
+            let v = velocity[axis];
+            if v == 0.0 {
+                // No motion on this axis: it must already overlap
+                if self.max[axis] < other.min[axis] || self.min[axis] > other.max[axis] {
+                    return None;
+                }
+                continue;
+            }
+
+            let (enter, exit) = if v > 0.0 {
+                ((other.min[axis] - self.max[axis]) / v, (other.max[axis] - self.min[axis]) / v)
+            } else {
+                ((other.max[axis] - self.min[axis]) / v, (other.min[axis] - self.max[axis]) / v)
+            };
+            t_entry = t_entry.max(enter);
+            t_exit = t_exit.min(exit);
+        }
+
+        if t_entry > t_exit || !(0.0..=1.0).contains(&t_entry) {
+            return None;
+        }
+        Some(t_entry)
+    }
+}
+
+/// Conservative world bounds of `local_box` over a move from `transform_a` to `transform_b`
+///
+/// Covers every intermediate pose of `Transform::lerp` (linear location and
+/// scale, shortest-path slerp rotation): the union of the boxes at both
+/// endpoints, padded by how far a rotating corner can stray from the
+/// straight line between its endpoint positions. Pure translations get no
+/// padding. Flat boxes such as floor or trigger planes sweep like any other;
+/// only an inverted `local_box` gives `BoundingBox::EMPTY`.
+pub fn swept_bounds(transform_a: Transform, transform_b: Transform, local_box: BoundingBox) -> BoundingBox {
+    if !local_box.is_valid() {
+        return BoundingBox::EMPTY;
+    }
+
+    // A corner at scaled offset s rotating by angle t*theta is within
+    // 2 sin(theta/4) |s| of its linear interpolation for every t
+    let cos_half_angle = transform_a.rotation.dot(transform_b.rotation).abs().min(1.0);
+    let chord = 2.0 * ((1.0 - cos_half_angle) * 0.5).sqrt();
+    let reach = local_box
+        .corners()
+        .iter()
+        .map(|&corner| (corner * transform_a.scale).length().max((corner * transform_b.scale).length()))
+        .fold(0.0, f64::max);
+
+    // Union the endpoint corners directly; `transform` would drop a flat box as empty
+    let local_corners = local_box.corners();
+    let mut corners: [Vector; 16] = core::array::from_fn(|index| local_corners[index % 8]);
+    let (at_a, at_b) = corners.split_at_mut(8);
+    transform_a.transform_points(at_a);
+    transform_b.transform_points(at_b);
+    BoundingBox::from_points(&corners).expand_by(chord * reach)
+}
+
+/// Single precision copy of a [`BoundingBox`] for GPU upload or compact payloads
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BoundingBox32 {
+    /// Minimum corner of the box
+    pub min: Vec3,
+    /// Maximum corner of the box
+    pub max: Vec3,
+}
+
+impl BinarySerializable for BoundingBox32 {}
+
+impl BoundingBox32 {
+    /// Widen to a double precision [`BoundingBox`]
+    pub fn to_f64(self) -> BoundingBox {
+        BoundingBox::new(self.min.as_dvec3(), self.max.as_dvec3())
+    }
+}
+
+impl BoundingBox {
+    /// Narrow to a single precision [`BoundingBox32`]
+    ///
+    /// Rounds outward, so the narrowed box always contains the original.
+    pub fn to_f32(self) -> BoundingBox32 {
+        let round_down = |value: f64| {
+            let narrow = value as f32;
+            if (narrow as f64) > value { narrow.next_down() } else { narrow }
+        };
+        let round_up = |value: f64| {
+            let narrow = value as f32;
+            if (narrow as f64) < value { narrow.next_up() } else { narrow }
+        };
+        BoundingBox32 {
+            min: Vec3::new(round_down(self.min.x), round_down(self.min.y), round_down(self.min.z)),
+            max: Vec3::new(round_up(self.max.x), round_up(self.max.y), round_up(self.max.z)),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -259,6 +623,175 @@ mod tests {
         assert_eq!(intersection.max, Vector::ONE);
     }
 
+    #[test]
+    fn test_bounding_box_penetration() {
+        let unit = BoundingBox::new(Vector::ZERO, Vector::ONE);
+
+        // Overlapping: least overlap is along X, pushed back towards -X
+        let other = BoundingBox::new(Vector::new(0.75, -1.0, -1.0), Vector::new(3.0, 2.0, 2.0));
+        let mtv = unit.penetration(other).unwrap();
+        assert_eq!(mtv, Vector::new(-0.25, 0.0, 0.0));
+        // Applying the MTV leaves the boxes just touching
+        let resolved = BoundingBox::new(unit.min + mtv, unit.max + mtv);
+        assert_eq!(resolved.penetration(other), Some(Vector::ZERO));
+
+        // Pushed the other way when coming from above
+        let below = BoundingBox::new(Vector::new(-1.0, -1.0, -2.0), Vector::new(2.0, 2.0, 0.1));
+        let mtv = unit.penetration(below).unwrap();
+        assert!((mtv - Vector::new(0.0, 0.0, 0.1)).length() < 1e-12);
+
+        // Exactly touching boxes give a zero-length MTV
+        let touching = BoundingBox::new(Vector::new(1.0, 0.0, 0.0), Vector::new(2.0, 1.0, 1.0));
+        assert_eq!(unit.penetration(touching), Some(Vector::ZERO));
+
+        // Separated boxes don't penetrate
+        let apart = BoundingBox::new(Vector::splat(5.0), Vector::splat(6.0));
+        assert_eq!(unit.penetration(apart), None);
+        assert_eq!(unit.penetration(BoundingBox::EMPTY), None);
+
+        // A box inside another is pushed all the way out through the nearest face
+        let room = BoundingBox::new(Vector::ZERO, Vector::splat(10.0));
+        let crate_box = BoundingBox::new(Vector::new(3.0, 4.0, 4.0), Vector::new(5.0, 6.0, 6.0));
+        let mtv = crate_box.penetration(room).unwrap();
+        assert_eq!(mtv, Vector::new(-5.0, 0.0, 0.0));
+        let resolved = BoundingBox::new(crate_box.min + mtv, crate_box.max + mtv);
+        assert!(resolved.overlap_amount(room).min_element() <= 0.0);
+        assert_eq!(resolved.penetration(room), Some(Vector::ZERO));
+
+        let centered = BoundingBox::new(Vector::splat(4.0), Vector::splat(6.0));
+        let mtv = centered.penetration(room).unwrap();
+        assert_eq!(mtv.length(), 6.0);
+        let resolved = BoundingBox::new(centered.min + mtv, centered.max + mtv);
+        assert!(resolved.overlap_amount(room).min_element() <= 0.0);
+
+        // And the container is pushed clear of what it holds
+        let mtv = room.penetration(crate_box).unwrap();
+        let resolved = BoundingBox::new(room.min + mtv, room.max + mtv);
+        assert!(resolved.overlap_amount(crate_box).min_element() <= 0.0);
+    }
+
+    #[test]
+    fn test_bounding_box_sweep() {
+        let mover = BoundingBox::new(Vector::ZERO, Vector::ONE);
+        let wall = BoundingBox::new(Vector::new(5.0, -1.0, -1.0), Vector::new(6.0, 2.0, 2.0));
+
+        // Hits the wall 4 units into a 10 unit step
+        let toi = mover.sweep(Vector::new(10.0, 0.0, 0.0), wall).unwrap();
+        assert!((toi - 0.4).abs() < 1e-12);
+
+        // Too short, wrong direction, or missing sideways
+        assert_eq!(mover.sweep(Vector::new(3.0, 0.0, 0.0), wall), None);
+        assert_eq!(mover.sweep(Vector::new(-10.0, 0.0, 0.0), wall), None);
+        assert_eq!(mover.sweep(Vector::new(10.0, 0.0, 10.0), wall), None);
+        assert_eq!(mover.sweep(Vector::ZERO, wall), None);
+
+        // Diagonal approach where one axis enters later than the other
+        let pillar = BoundingBox::new(Vector::new(4.0, 4.0, 0.0), Vector::new(5.0, 5.0, 1.0));
+        let toi = mover.sweep(Vector::new(8.0, 6.0, 0.0), pillar).unwrap();
+        assert!((toi - 0.5).abs() < 1e-12);
+
+        // Starting overlapped reports an immediate hit
+        let overlapping = BoundingBox::new(Vector::splat(0.5), Vector::splat(2.0));
+        assert_eq!(mover.sweep(Vector::new(1.0, 0.0, 0.0), overlapping), Some(0.0));
+        assert_eq!(mover.sweep(Vector::ZERO, overlapping), Some(0.0));
+    }
+
+    #[test]
+    fn test_bounding_box_corners_and_edges() {
+        let bbox = BoundingBox::new(Vector::new(-1.0, -2.0, -3.0), Vector::new(1.0, 2.0, 3.0));
+        let corners = bbox.corners();
+        assert_eq!(corners[0], bbox.min);
+        assert_eq!(corners[7], bbox.max);
+        for (index, corner) in corners.iter().enumerate() {
+            assert_eq!(corner.x, if index & 1 != 0 { 1.0 } else { -1.0 });
+            assert_eq!(corner.y, if index & 2 != 0 { 2.0 } else { -2.0 });
+            assert_eq!(corner.z, if index & 4 != 0 { 3.0 } else { -3.0 });
+        }
+
+        let edges = bbox.edges();
+        let mut seen = std::collections::HashSet::new();
+        for (index, (start, end)) in edges.iter().enumerate() {
+            let axis = index / 4;
+            let delta = *end - *start;
+            // Each edge runs along its axis, lower corner first, and is listed once
+            assert!(delta[axis] > 0.0 && delta[(axis + 1) % 3] == 0.0 && delta[(axis + 2) % 3] == 0.0);
+            assert_eq!(delta[axis], bbox.size()[axis]);
+            assert!(seen.insert(format!("{:?}", (start, end))));
+        }
+
+        let planes = bbox.face_planes();
+        let normals = [-Vector::X, Vector::X, -Vector::Y, Vector::Y, -Vector::Z, Vector::Z];
+        for (plane, normal) in planes.iter().zip(normals) {
+            assert_eq!(plane.normal, normal);
+            // Every corner is on or behind every face
+            assert!(corners.iter().all(|&corner| plane.distance_to_point(corner) <= 0.0));
+            assert_eq!(corners.iter().filter(|&&corner| plane.distance_to_point(corner) == 0.0).count(), 4);
+        }
+        assert!(!planes.iter().all(|plane| plane.distance_to_point(Vector::new(0.0, 0.0, 3.5)) <= 0.0));
+
+        assert_eq!(bbox.get_axis_extent(Axis::X), 1.0);
+        assert_eq!(bbox.get_axis_extent(Axis::Z), 3.0);
+    }
+
+    #[test]
+    fn test_bounding_box_split_and_overlap() {
+        let bbox = BoundingBox::new(Vector::new(0.1, -7.3, 2.0), Vector::new(9.7, 4.4, 5.5));
+        for axis in Axis::ALL {
+            let i = axis.index();
+            for position in [0.3, 1.0, 3.25, -100.0, 100.0] {
+                let (lower, upper) = bbox.split(axis, position);
+                // Halves share the split face and together cover exactly the original
+                assert_eq!(lower.max[i], upper.min[i]);
+                assert_eq!(lower.min, bbox.min);
+                assert_eq!(upper.max, bbox.max);
+                assert_eq!(lower.expand_to_include_box(upper).min, bbox.min);
+                assert!((lower.volume() + upper.volume() - bbox.volume()).abs() < 1e-9);
+                for j in (0..3).filter(|&j| j != i) {
+                    assert_eq!((lower.min[j], lower.max[j]), (bbox.min[j], bbox.max[j]));
+                    assert_eq!((upper.min[j], upper.max[j]), (bbox.min[j], bbox.max[j]));
+                }
+            }
+        }
+
+        let a = BoundingBox::new(Vector::ZERO, Vector::splat(4.0));
+        let b = BoundingBox::new(Vector::new(3.0, 4.0, 6.0), Vector::new(5.0, 8.0, 9.0));
+        assert_eq!(a.overlap_amount(b), Vector::new(1.0, 0.0, -2.0));
+        assert_eq!(b.overlap_amount(a), a.overlap_amount(b));
+        assert_eq!(a.overlap_amount(a), a.size());
+    }
+
+    #[test]
+    fn test_bounding_box_batch_points() {
+        let mut stream = crate::types::RandomStream::new(7);
+        let points: Vec<Vector> = (0..1_003).map(|_| stream.vrand() * stream.frand_range_f64(1.0, 50.0)).collect();
+
+        // Every length exercises a different chunk remainder
+        for len in 0..=points.len().min(11) {
+            let slice = &points[..len];
+            let expected = slice.iter().fold(BoundingBox::EMPTY, |bbox, &point| bbox.expand_to_include(point));
+            assert_eq!(BoundingBox::from_points(slice), expected);
+        }
+
+        let transform = Transform::new(
+            Vector::new(10.0, 0.0, -5.0),
+            crate::types::Rotator::new(20.0, 45.0, 0.0).to_quaternion(),
+            Vector::new(2.0, 1.0, 0.5),
+        );
+        let mut moved = points.clone();
+        transform.transform_points(&mut moved);
+        let calls = crate::types::transform::to_matrix_calls();
+        assert_eq!(BoundingBox::from_transformed_points(&points, transform), BoundingBox::from_points(&moved));
+        assert_eq!(BoundingBox::from_transformed_points(&[], transform), BoundingBox::EMPTY);
+
+        // Transforming a box builds the matrix once rather than once per corner
+        let bbox = BoundingBox::from_points(&points);
+        let expected = BoundingBox::from_points(&bbox.corners().map(|corner| transform.transform_point(corner)));
+        let calls_after_expected = crate::types::transform::to_matrix_calls();
+        assert_eq!(bbox.transform(transform), expected);
+        assert_eq!(crate::types::transform::to_matrix_calls(), calls_after_expected + 1);
+        assert_eq!(calls_after_expected, calls + 2 + 8);
+    }
+
     #[test]
     fn test_bounding_box_display() {
         let bbox = BoundingBox::new(
@@ -287,6 +820,27 @@ mod tests {
         assert_eq!(bbox, deserialized);
     }
 
+    #[test]
+    fn test_empty_bounding_box_serialization() {
+        let json = serde_json::to_string(&BoundingBox::EMPTY).unwrap();
+        assert_eq!(json, r#"{"min":["inf","inf","inf"],"max":["-inf","-inf","-inf"]}"#);
+        assert_eq!(serde_json::from_str::<BoundingBox>(&json).unwrap(), BoundingBox::EMPTY);
+        let binary = BoundingBox::EMPTY.to_binary().unwrap();
+        assert_eq!(BoundingBox::from_binary(&binary).unwrap(), BoundingBox::EMPTY);
+
+        // Only the non-finite components become strings
+        let half_open = BoundingBox::new(Vector::new(0.0, f64::NEG_INFINITY, -2.5), Vector::new(4.0, 1.0, 2.0));
+        let json = serde_json::to_string(&half_open).unwrap();
+        assert_eq!(json, r#"{"min":[0.0,"-inf",-2.5],"max":[4.0,1.0,2.0]}"#);
+        assert_eq!(serde_json::from_str::<BoundingBox>(&json).unwrap(), half_open);
+        let parsed: BoundingBox = serde_json::from_str(r#"{"min":[0,-1,"nan"],"max":[1,2,3]}"#).unwrap();
+        assert!(parsed.min.z.is_nan());
+        assert!(serde_json::from_str::<BoundingBox>(r#"{"min":[0,0,"Infinity"],"max":[1,1,1]}"#).is_err());
+
+        assert_eq!(serde_json::from_str::<BoundingBox>(&serde_json::to_string(&BoundingBox::ZERO).unwrap()).unwrap(), BoundingBox::ZERO);
+        assert!(BoundingBox::ZERO.is_valid() && !BoundingBox::EMPTY.is_valid());
+    }
+
     #[test]
     fn test_bounding_box_binary_serialization() {
         let bbox = BoundingBox::new(
@@ -300,4 +854,95 @@ mod tests {
         
         assert_eq!(bbox, deserialized);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_bounding_box_precision_conversion() {
+        let bbox = BoundingBox::new(Vector::new(0.1, -0.1, 5_000_000.3), Vector::new(1.5, 2.7, 5_000_100.7));
+        let single = bbox.to_f32();
+        let widened = single.to_f64();
+        assert!(widened.contains_box(bbox));
+        assert!((widened.min - bbox.min).length() < 1.0);
+        assert!((widened.max - bbox.max).length() < 1.0);
+
+        // Exactly representable values survive unchanged
+        let exact = BoundingBox::new(Vector::new(-1.0, 0.0, 2.5), Vector::new(4.0, 8.0, 16.0));
+        assert_eq!(exact.to_f32().to_f64(), exact);
+        assert_eq!(exact.to_f32().min, Vec3::new(-1.0, 0.0, 2.5));
+    }
+
+    #[test]
+    fn test_swept_bounds_covers_rotation() {
+        // A stick pointing along +X swings from -45 to +45 degrees of yaw; at
+        // the midpoint its tip reaches X = 10, past both endpoint boxes
+        let stick = BoundingBox::new(Vector::new(9.0, -0.5, -0.5), Vector::new(10.0, 0.5, 0.5));
+        let start = Transform::from_rotation(glam::DQuat::from_rotation_z(-core::f64::consts::FRAC_PI_4));
+        let end = Transform::from_rotation(glam::DQuat::from_rotation_z(core::f64::consts::FRAC_PI_4));
+        let endpoints = stick.transform(start).expand_to_include_box(stick.transform(end));
+        assert!(endpoints.max.x < 8.0);
+
+        let swept = swept_bounds(start, end, stick);
+        for step in 0..=20 {
+            let pose = start.lerp(end, step as f64 / 20.0);
+            assert!(swept.expand_by(1e-9).contains_box(stick.transform(pose)), "step {}", step);
+        }
+        assert!(swept.contains_box(endpoints));
+
+        // Translation and scale alone interpolate linearly, so no padding is needed
+        let a = Transform::from_location(Vector::new(-100.0, 0.0, 0.0));
+        let b = Transform::new(Vector::new(100.0, 5.0, 0.0), glam::DQuat::IDENTITY, Vector::splat(2.0));
+        let linear = swept_bounds(a, b, stick);
+        assert_eq!(linear, stick.transform(a).expand_to_include_box(stick.transform(b)));
+        assert_eq!(swept_bounds(a, b, BoundingBox::EMPTY), BoundingBox::EMPTY);
+    }
+
+    #[test]
+    fn test_swept_bounds_flat_box() {
+        // A zero-thickness trigger plane still sweeps out a volume
+        let floor = BoundingBox::new(Vector::new(-50.0, -50.0, 0.0), Vector::new(50.0, 50.0, 0.0));
+        assert!(floor.is_empty() && floor.is_valid());
+
+        let a = Transform::from_location(Vector::new(0.0, 0.0, 10.0));
+        let b = Transform::from_location(Vector::new(200.0, 0.0, 30.0));
+        let swept = swept_bounds(a, b, floor);
+        assert_eq!(swept, BoundingBox::new(Vector::new(-50.0, -50.0, 10.0), Vector::new(250.0, 50.0, 30.0)));
+
+        // Standing still leaves it flat rather than empty
+        assert_eq!(swept_bounds(a, a, floor), BoundingBox::new(Vector::new(-50.0, -50.0, 10.0), Vector::new(50.0, 50.0, 10.0)));
+
+        // Tipping it over covers every pose in between
+        let tipped = Transform::from_rotation(glam::DQuat::from_rotation_y(core::f64::consts::FRAC_PI_2));
+        let swept = swept_bounds(Transform::IDENTITY, tipped, floor);
+        for step in 0..=20 {
+            let pose = Transform::IDENTITY.lerp(tipped, step as f64 / 20.0);
+            let mut corners = floor.corners();
+            pose.transform_points(&mut corners);
+            assert!(corners.iter().all(|&corner| swept.expand_by(1e-9).contains_point(corner)), "step {}", step);
+        }
+    }
+
+    #[test]
+    fn test_bounding_box_transform_far_from_origin() {
+        // At 5,000,000 units f32 steps by 0.5, so any f32 round-trip would show up here
+        let error = |a: BoundingBox, b: BoundingBox| (a.min - b.min).abs().max_element().max((a.max - b.max).abs().max_element());
+        let bbox = BoundingBox::new(Vector::new(10.0, -20.0, -5.0), Vector::new(30.0, 40.0, 5.0));
+        let transform = Transform::new(
+            Vector::new(5_000_000.3, 0.0, 0.0),
+            glam::DQuat::from_rotation_z(core::f64::consts::FRAC_PI_2),
+            Vector::new(2.0, 1.0, 3.0),
+        );
+        // Scale to X 20..60, Y -20..40, Z -15..15, then yaw 90 maps (x, y) to (-y, x)
+        let expected = BoundingBox::new(Vector::new(4_999_960.3, 20.0, -15.0), Vector::new(5_000_020.3, 60.0, 15.0));
+        assert!(error(bbox.transform(transform), expected) < 1e-3);
+
+        // A 45 degree yaw widens a centered cube by sqrt(2) in X and Y
+        let cube = BoundingBox::from_center_and_extent(Vector::ZERO, Vector::splat(100.0));
+        let spun = Transform::new(
+            Vector::new(5_000_000.0, -2_500_000.7, 0.0),
+            glam::DQuat::from_rotation_z(core::f64::consts::FRAC_PI_4),
+            Vector::ONE,
+        );
+        let half = 100.0 * core::f64::consts::SQRT_2;
+        let expected = BoundingBox::from_center_and_extent(spun.location, Vector::new(half, half, 100.0));
+        assert!(error(cube.transform(spun), expected) < 1e-3);
+    }
+}
@@ -0,0 +1,291 @@
+//! Bounding Capsule for character collision
+
+use crate::BinarySerializable;
+use crate::types::{LineSegment, Transform, Vector, Ray};
+use super::{BoundingBox, BoundingSphere};
+use glam::DQuat;
+use serde::{Deserialize, Serialize};
+use core::fmt;
+
+/// Bounding Capsule
+///
+/// All points within `radius` of a segment through `center` along the
+/// rotated Z axis, like UE's capsule component. `half_height` is half the
+/// length of that segment, excluding the hemispherical caps, so a capsule
+/// with zero half height is a sphere. UE's `CapsuleHalfHeight` includes the
+/// caps; see `from_ue_half_height` and `total_half_height`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BoundingCapsule {
+    /// Center of the capsule
+    pub center: Vector,
+    /// Half the length of the core segment, excluding the caps
+    pub half_height: f64,
+    /// Radius of the capsule
+    pub radius: f64,
+    /// Rotation taking the Z axis to the capsule's axis
+    pub rotation: DQuat,
+}
+
+impl fmt::Display for BoundingCapsule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let axis = self.axis();
+        write!(
+            f,
+            "BoundingCapsule(Center: ({:.2}, {:.2}, {:.2}), Axis: ({:.3}, {:.3}, {:.3}), HalfHeight: {:.2}, Radius: {:.2})",
+            self.center.x, self.center.y, self.center.z, axis.x, axis.y, axis.z, self.half_height, self.radius
+        )
+    }
+}
+
+impl BinarySerializable for BoundingCapsule {}
+
+impl BoundingCapsule {
+    /// Create a new bounding capsule
+    pub fn new(center: Vector, half_height: f64, radius: f64, rotation: DQuat) -> Self {
+        Self { center, half_height, radius, rotation }
+    }
+
+    /// Create an upright capsule, as used by characters
+    pub fn vertical(center: Vector, half_height: f64, radius: f64) -> Self {
+        Self::new(center, half_height, radius, DQuat::IDENTITY)
+    }
+
+    /// Create a capsule from UE's `CapsuleHalfHeight`, which includes the caps
+    ///
+    /// Half heights shorter than the radius give a sphere, as in UE.
+    pub fn from_ue_half_height(center: Vector, capsule_half_height: f64, radius: f64, rotation: DQuat) -> Self {
+        Self::new(center, (capsule_half_height - radius).max(0.0), radius, rotation)
+    }
+
+    /// Create a capsule around the segment from `start` to `end`
+    pub fn from_segment(start: Vector, end: Vector, radius: f64) -> Self {
+        let offset = end - start;
+        let direction = offset.normalize_or_zero();
+        let rotation = if direction == Vector::ZERO {
+            DQuat::IDENTITY
+        } else {
+            DQuat::from_rotation_arc(Vector::Z, direction)
+        };
+        Self::new((start + end) * 0.5, offset.length() * 0.5, radius, rotation)
+    }
+
+    /// Unit direction of the capsule's axis
+    pub fn axis(self) -> Vector {
+        self.rotation * Vector::Z
+    }
+
+    /// The core segment the capsule is swept along
+    pub fn segment(self) -> LineSegment {
+        let offset = self.axis() * self.half_height;
+        LineSegment::new(self.center - offset, self.center + offset)
+    }
+
+    /// Half the capsule's full length including the caps (UE `CapsuleHalfHeight`)
+    pub fn total_half_height(self) -> f64 {
+        self.half_height + self.radius
+    }
+
+    /// Check if a point is inside the capsule
+    pub fn contains_point(self, point: Vector) -> bool {
+        self.segment().distance_squared_to_point(point) <= self.radius * self.radius
+    }
+
+    /// Get the closest point in the capsule to a given point (the point itself if inside)
+    pub fn closest_point_to(self, point: Vector) -> Vector {
+        let on_axis = self.segment().closest_point_to(point);
+        let offset = point - on_axis;
+        if offset.length_squared() <= self.radius * self.radius {
+            return point;
+        }
+        on_axis + offset.normalize() * self.radius
+    }
+
+    /// Check if this capsule intersects a sphere
+    pub fn intersects_sphere(self, sphere: BoundingSphere) -> bool {
+        let radii_sum = self.radius + sphere.radius;
+        self.segment().distance_squared_to_point(sphere.center) <= radii_sum * radii_sum
+    }
+
+    /// Check if this capsule intersects another capsule
+    ///
+    /// Compares the distance between the two core segments with the radii,
+    /// which also covers parallel capsules.
+    pub fn intersects_capsule(self, other: BoundingCapsule) -> bool {
+        let radii_sum = self.radius + other.radius;
+        self.segment().closest_points_to_segment(other.segment()).distance_squared() <= radii_sum * radii_sum
+    }
+
+    /// Check if this capsule intersects a bounding box
+    ///
+    /// An invalid (inverted) box intersects nothing.
+    pub fn intersects_box(self, bbox: BoundingBox) -> bool {
+        bbox.is_valid() && segment_box_distance(self.segment(), bbox) <= self.radius
+    }
+
+    /// Transform the capsule by the given transform
+    ///
+    /// The core segment is transformed exactly. Under non-uniform scale the
+    /// radius grows by the largest scale factor, so the result still
+    /// contains the transformed capsule, as for `BoundingSphere::transform`.
+    pub fn transform(self, transform: Transform) -> Self {
+        let segment = self.segment();
+        let start = transform.transform_point(segment.start);
+        let end = transform.transform_point(segment.end);
+        let max_scale = transform.scale.abs().max_element();
+
+        let mut rotation = transform.rotation * self.rotation;
+        let direction = (end - start).normalize_or_zero();
+        if direction != Vector::ZERO {
+            // Non-uniform scale can tilt the axis away from the plain rotation
+            rotation = DQuat::from_rotation_arc(rotation * Vector::Z, direction) * rotation;
+        }
+
+        Self {
+            center: (start + end) * 0.5,
+            half_height: start.distance(end) * 0.5,
+            radius: self.radius * max_scale,
+            rotation,
+        }
+    }
+}
+
+/// Shortest distance between a segment and a valid box (0 if they touch)
+///
+/// When the two are apart, a closest pair either has an endpoint of the
+/// segment on one side or lies on one of the box's edges.
+fn segment_box_distance(segment: LineSegment, bbox: BoundingBox) -> f64 {
+    let from_endpoints = bbox.distance_to_point(segment.start).min(bbox.distance_to_point(segment.end));
+    let length = segment.length();
+    if from_endpoints == 0.0 || length == 0.0 {
+        return from_endpoints;
+    }
+    let ray = Ray::new(segment.start, segment.end - segment.start);
+    if bbox.intersects_ray(ray).is_some_and(|distance| distance <= length) {
+        return 0.0;
+    }
+    bbox.edges()
+        .into_iter()
+        .map(|(start, end)| segment.closest_points_to_segment(LineSegment::new(start, end)).distance())
+        .fold(from_endpoints, f64::min)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vertical_capsule() {
+        let capsule = BoundingCapsule::vertical(Vector::new(0.0, 0.0, 100.0), 60.0, 34.0);
+        assert_eq!(capsule.segment(), LineSegment::new(Vector::new(0.0, 0.0, 40.0), Vector::new(0.0, 0.0, 160.0)));
+        assert_eq!(capsule.total_half_height(), 94.0);
+        assert_eq!(BoundingCapsule::from_ue_half_height(capsule.center, 94.0, 34.0, DQuat::IDENTITY), capsule);
+
+        assert!(capsule.contains_point(Vector::new(0.0, 0.0, 193.0)));
+        assert!(!capsule.contains_point(Vector::new(0.0, 0.0, 195.0)));
+        assert!(capsule.contains_point(Vector::new(34.0, 0.0, 150.0)));
+        assert!(!capsule.contains_point(Vector::new(30.0, 30.0, 150.0)));
+
+        assert_eq!(capsule.closest_point_to(Vector::new(100.0, 0.0, 120.0)), Vector::new(34.0, 0.0, 120.0));
+        assert_eq!(capsule.closest_point_to(Vector::new(0.0, 0.0, 300.0)), Vector::new(0.0, 0.0, 194.0));
+        assert_eq!(capsule.closest_point_to(Vector::new(10.0, 0.0, 100.0)), Vector::new(10.0, 0.0, 100.0));
+
+        assert!(capsule.intersects_sphere(BoundingSphere::new(Vector::new(0.0, 50.0, 150.0), 20.0)));
+        assert!(!capsule.intersects_sphere(BoundingSphere::new(Vector::new(0.0, 60.0, 150.0), 20.0)));
+
+        // Standing on a floor box, and just above one
+        let floor = BoundingBox::new(Vector::new(-500.0, -500.0, -10.0), Vector::new(500.0, 500.0, 6.0));
+        assert!(capsule.intersects_box(floor));
+        assert!(!BoundingCapsule { center: Vector::new(0.0, 0.0, 101.0), ..capsule }.intersects_box(floor));
+    }
+
+    #[test]
+    fn test_tilted_capsule() {
+        let capsule = BoundingCapsule::from_segment(Vector::ZERO, Vector::new(100.0, 100.0, 0.0), 10.0);
+        assert!((capsule.axis() - Vector::new(1.0, 1.0, 0.0).normalize()).length() < 1e-12);
+        assert!((capsule.half_height - 50.0 * std::f64::consts::SQRT_2).abs() < 1e-9);
+        assert!(capsule.contains_point(Vector::new(50.0, 50.0, 9.0)));
+        assert!(!capsule.contains_point(Vector::new(50.0, 50.0, 11.0)));
+        assert!(capsule.contains_point(Vector::new(55.0, 45.0, 0.0)));
+        assert!(!capsule.contains_point(Vector::new(60.0, 40.0, 0.0)));
+
+        // Boxes beside the middle of the diagonal, where only a box corner comes close
+        let near = BoundingBox::new(Vector::new(55.0, 30.0, -5.0), Vector::new(80.0, 48.0, 5.0));
+        assert!(capsule.intersects_box(near));
+        let miss = BoundingBox::new(Vector::new(62.0, 30.0, -5.0), Vector::new(80.0, 47.0, 5.0));
+        assert!(!capsule.intersects_box(miss));
+        // The segment passing straight through a box
+        assert!(capsule.intersects_box(BoundingBox::new(Vector::new(40.0, 40.0, -1.0), Vector::new(45.0, 45.0, 1.0))));
+
+        // Crossing a vertical capsule without touching either end
+        let vertical = BoundingCapsule::vertical(Vector::new(50.0, 50.0, 0.0), 100.0, 1.0);
+        assert!(capsule.intersects_capsule(vertical));
+        let beside = BoundingCapsule::vertical(Vector::new(60.0, 40.0, 0.0), 100.0, 1.0);
+        assert!(!capsule.intersects_capsule(beside));
+    }
+
+    #[test]
+    fn test_parallel_capsules() {
+        let a = BoundingCapsule::vertical(Vector::ZERO, 50.0, 10.0);
+        let b = BoundingCapsule::vertical(Vector::new(19.0, 0.0, 30.0), 50.0, 10.0);
+        let c = BoundingCapsule::vertical(Vector::new(21.0, 0.0, 30.0), 50.0, 10.0);
+        assert!(a.intersects_capsule(b));
+        assert!(!a.intersects_capsule(c));
+
+        // End to end along the same line
+        let stacked = BoundingCapsule::vertical(Vector::new(0.0, 0.0, 119.0), 50.0, 10.0);
+        assert!(a.intersects_capsule(stacked));
+        assert!(!a.intersects_capsule(BoundingCapsule { center: Vector::new(0.0, 0.0, 121.0), ..stacked }));
+    }
+
+    #[test]
+    fn test_degenerate_capsule_is_sphere() {
+        let capsule = BoundingCapsule::vertical(Vector::new(1.0, 2.0, 3.0), 0.0, 5.0);
+        let sphere = BoundingSphere::new(capsule.center, 5.0);
+        assert_eq!(BoundingCapsule::from_ue_half_height(capsule.center, 3.0, 5.0, DQuat::IDENTITY), capsule);
+        assert_eq!(BoundingCapsule::from_segment(capsule.center, capsule.center, 5.0), capsule);
+
+        for point in [Vector::new(5.0, 2.0, 3.0), Vector::new(1.0, 2.0, 8.5), Vector::new(4.0, 5.0, 6.0)] {
+            assert_eq!(capsule.contains_point(point), sphere.contains_point(point));
+        }
+        let bbox = BoundingBox::new(Vector::new(5.0, 5.0, 5.0), Vector::new(8.0, 8.0, 8.0));
+        assert_eq!(capsule.intersects_box(bbox), sphere.intersects_box(bbox));
+        let other = BoundingSphere::new(Vector::new(1.0, 2.0, 12.0), 4.5);
+        assert_eq!(capsule.intersects_sphere(other), sphere.intersects_sphere(other));
+        assert!(capsule.intersects_capsule(BoundingCapsule::vertical(Vector::new(1.0, 2.0, 20.0), 10.0, 2.0)));
+        assert!(!capsule.intersects_box(BoundingBox::new(Vector::ONE, Vector::ZERO)));
+    }
+
+    #[test]
+    fn test_capsule_transform() {
+        let capsule = BoundingCapsule::vertical(Vector::ZERO, 50.0, 10.0);
+        let transform = Transform::new(
+            Vector::new(100.0, 0.0, 0.0),
+            DQuat::from_rotation_y(std::f64::consts::FRAC_PI_2),
+            Vector::splat(2.0),
+        );
+        let moved = capsule.transform(transform);
+        assert!((moved.center - Vector::new(100.0, 0.0, 0.0)).length() < 1e-9);
+        assert!((moved.axis() - Vector::X).length() < 1e-9);
+        assert!((moved.half_height - 100.0).abs() < 1e-9);
+        assert_eq!(moved.radius, 20.0);
+
+        // Stretching along Z only lengthens the segment; the radius takes the largest scale
+        let stretched = capsule.transform(Transform::from_scale(Vector::new(1.0, 1.0, 3.0)));
+        assert!((stretched.half_height - 150.0).abs() < 1e-9);
+        assert_eq!(stretched.radius, 30.0);
+        assert!((stretched.axis() - Vector::Z).length() < 1e-9);
+    }
+
+    #[test]
+    fn test_capsule_display_and_serialization() {
+        let capsule = BoundingCapsule::new(Vector::new(1.0, 2.0, 3.0), 40.0, 20.0, DQuat::from_xyzw(0.0, 0.6, 0.0, 0.8));
+        let display = format!("{}", capsule);
+        assert!(display.contains("Center: (1.00, 2.00, 3.00)"));
+        assert!(display.contains("HalfHeight: 40.00, Radius: 20.00"));
+
+        let json = serde_json::to_string(&capsule).unwrap();
+        assert_eq!(serde_json::from_str::<BoundingCapsule>(&json).unwrap(), capsule);
+        let binary = capsule.to_binary().unwrap();
+        assert_eq!(BoundingCapsule::from_binary(&binary).unwrap(), capsule);
+    }
+}
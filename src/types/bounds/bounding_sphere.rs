@@ -1,16 +1,22 @@
 //! Bounding Sphere for fast collision detection and culling
 
 use crate::BinarySerializable;
-use crate::types::{Vector, Transform};
+use crate::types::math::nearly_equal::max_error;
+use crate::types::{NearlyEqual, Vector, Transform, Ray};
 use super::BoundingBox;
+use glam::Vec3;
 use serde::{Deserialize, Serialize};
-use std::fmt;
+use core::fmt;
+#[cfg(not(feature = "std"))]
+use crate::types::math::float_math::FloatMath;
 
 /// Bounding Sphere
 /// 
 /// Represents a 3D sphere defined by center and radius.
 /// Often used for fast collision detection and culling.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(feature = "rkyv", archive(check_bytes, compare(PartialEq)), archive_attr(derive(Debug)))]
 pub struct BoundingSphere {
     /// Center of the sphere
     pub center: Vector,
@@ -30,9 +36,24 @@ impl fmt::Display for BoundingSphere {
 
 impl BinarySerializable for BoundingSphere {}
 
+impl crate::types::SerializedSize for BoundingSphere {
+    fn serialized_size(&self) -> usize {
+        32
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl crate::types::ZeroCopy for BoundingSphere {}
+
+impl NearlyEqual for BoundingSphere {
+    fn max_component_error(&self, other: &Self) -> f64 {
+        max_error([self.center.max_component_error(&other.center), self.radius.max_component_error(&other.radius)])
+    }
+}
+
 impl BoundingSphere {
     /// Create a new bounding sphere
-    pub fn new(center: Vector, radius: f64) -> Self {
+    pub const fn new(center: Vector, radius: f64) -> Self {
         Self { center, radius }
     }
 
@@ -44,15 +65,85 @@ impl BoundingSphere {
     }
 
     /// Create a bounding sphere that encompasses all given points
+    ///
+    /// Uses Ritter's algorithm: a sphere around an approximately farthest
+    /// pair of points, grown just enough to take in any point left outside.
+    /// Linear time and usually within a few percent of the minimal sphere;
+    /// use `minimal_from_points` for the exact one.
     pub fn from_points(points: &[Vector]) -> Self {
+        let Some(&first) = points.first() else {
+            return Self::new(Vector::ZERO, 0.0);
+        };
+
+        let farthest_from = |from: Vector| {
+            points
+                .iter()
+                .copied()
+                .max_by(|a, b| (*a - from).length_squared().total_cmp(&(*b - from).length_squared()))
+                .unwrap_or(from)
+        };
+        let x = farthest_from(first);
+        let y = farthest_from(x);
+        let mut sphere = Self::from_two_points(x, y);
+
+        for &point in points {
+            sphere = sphere.expand_to_include(point);
+        }
+        sphere
+    }
+
+    /// Create the smallest sphere that encompasses all given points
+    ///
+    /// Welzl's algorithm in its iterative move-to-front form, run over the
+    /// points in a fixed pseudo-random order, so results are reproducible.
+    /// Expected linear time.
+    pub fn minimal_from_points(points: &[Vector]) -> Self {
+        if points.is_empty() {
+            return Self::new(Vector::ZERO, 0.0);
+        }
+
+        let mut points = points.to_vec();
+        shuffle(&mut points);
+
+        let mut sphere = Self::new(points[0], 0.0);
+        for i in 1..points.len() {
+            if sphere.encloses(points[i]) {
+                continue;
+            }
+            sphere = Self::new(points[i], 0.0);
+            for j in 0..i {
+                if sphere.encloses(points[j]) {
+                    continue;
+                }
+                sphere = Self::from_two_points(points[i], points[j]);
+                for k in 0..j {
+                    if sphere.encloses(points[k]) {
+                        continue;
+                    }
+                    sphere = Self::circumscribe_triangle(points[i], points[j], points[k]);
+                    for l in 0..k {
+                        if !sphere.encloses(points[l]) {
+                            sphere = Self::circumscribe_tetrahedron(points[i], points[j], points[k], points[l]);
+                        }
+                    }
+                }
+            }
+        }
+        sphere
+    }
+
+    /// Create a bounding sphere around the bounding box of the points
+    ///
+    /// The previous `from_points` heuristic: cheap, but up to ~40% larger
+    /// than necessary for elongated point clouds.
+    pub fn from_points_fast(points: &[Vector]) -> Self {
         if points.is_empty() {
             return Self::new(Vector::ZERO, 0.0);
         }
 
-        // Simple implementation: use bounding box center and max distance
         let bbox = BoundingBox::from_points(points);
         let center = bbox.center();
-        
+
         let radius = points
             .iter()
             .map(|&point| (point - center).length())
@@ -61,14 +152,71 @@ impl BoundingSphere {
         Self { center, radius }
     }
 
+    fn from_two_points(a: Vector, b: Vector) -> Self {
+        Self::new((a + b) * 0.5, (a - b).length() * 0.5)
+    }
+
+    /// Smallest sphere with all three points on its surface
+    fn circumscribe_triangle(a: Vector, b: Vector, c: Vector) -> Self {
+        let ab = b - a;
+        let ac = c - a;
+        let normal = ab.cross(ac);
+        let normal_length_squared = normal.length_squared();
+        if normal_length_squared <= DEGENERATE_TOLERANCE * ab.length_squared() * ac.length_squared() {
+            // Collinear: the outer pair bounds the middle point
+            return [Self::from_two_points(a, b), Self::from_two_points(a, c), Self::from_two_points(b, c)]
+                .into_iter()
+                .max_by(|x, y| x.radius.total_cmp(&y.radius))
+                .unwrap();
+        }
+        let offset = (ac * ab.length_squared() - ab * ac.length_squared()).cross(normal) / (2.0 * normal_length_squared);
+        Self::new(a + offset, offset.length())
+    }
+
+    /// Sphere with all four points on its surface
+    fn circumscribe_tetrahedron(a: Vector, b: Vector, c: Vector, d: Vector) -> Self {
+        let ab = b - a;
+        let ac = c - a;
+        let ad = d - a;
+        let determinant = 2.0 * ab.dot(ac.cross(ad));
+        let scale = ab.length() * ac.length() * ad.length();
+        if determinant.abs() <= DEGENERATE_TOLERANCE.sqrt() * scale {
+            // Coplanar: the smallest triangle sphere that holds the fourth point
+            let candidates = [
+                Self::circumscribe_triangle(a, b, c),
+                Self::circumscribe_triangle(a, b, d),
+                Self::circumscribe_triangle(a, c, d),
+                Self::circumscribe_triangle(b, c, d),
+            ];
+            let fits = |sphere: &&Self| [a, b, c, d].iter().all(|&point| sphere.encloses(point));
+            return candidates
+                .iter()
+                .filter(fits)
+                .min_by(|x, y| x.radius.total_cmp(&y.radius))
+                .or_else(|| candidates.iter().max_by(|x, y| x.radius.total_cmp(&y.radius)))
+                .copied()
+                .unwrap();
+        }
+        let offset = (ac.cross(ad) * ab.length_squared()
+            + ad.cross(ab) * ac.length_squared()
+            + ab.cross(ac) * ad.length_squared())
+            / determinant;
+        Self::new(a + offset, offset.length())
+    }
+
+    /// `contains_point` with slack for rounding in the constructions above
+    fn encloses(self, point: Vector) -> bool {
+        (point - self.center).length() <= self.radius * (1.0 + 1e-9) + 1e-9
+    }
+
     /// Get the volume of the sphere
     pub fn volume(self) -> f64 {
-        (4.0 / 3.0) * std::f64::consts::PI * self.radius.powi(3)
+        (4.0 / 3.0) * core::f64::consts::PI * self.radius.powi(3)
     }
 
     /// Get the surface area of the sphere
     pub fn surface_area(self) -> f64 {
-        4.0 * std::f64::consts::PI * self.radius * self.radius
+        4.0 * core::f64::consts::PI * self.radius * self.radius
     }
 
     /// Check if a point is inside the sphere
@@ -95,6 +243,11 @@ impl BoundingSphere {
         self.contains_point(closest_point)
     }
 
+    /// Check if a ray hits this sphere (see `Ray::intersects_sphere`)
+    pub fn intersects_ray(self, ray: Ray) -> bool {
+        ray.intersects_sphere(self).is_some()
+    }
+
     /// Transform the bounding sphere by the given transform
     pub fn transform(self, transform: Transform) -> Self {
         let new_center = transform.transform_point(self.center);
@@ -117,26 +270,177 @@ impl BoundingSphere {
     }
 
     /// Expand the sphere to include a point
+    ///
+    /// The result is the smallest sphere containing both, so the center
+    /// moves toward the point.
     pub fn expand_to_include(self, point: Vector) -> Self {
-        let distance = (point - self.center).length();
-        if distance <= self.radius {
+        self.expand_to_include_sphere(Self::new(point, 0.0))
+    }
+
+    /// Expand the sphere to include another sphere
+    ///
+    /// The result is the smallest sphere containing both.
+    pub fn expand_to_include_sphere(self, other: BoundingSphere) -> Self {
+        let offset = other.center - self.center;
+        let distance = offset.length();
+        if distance + other.radius <= self.radius {
             return self;
         }
+        if distance + self.radius <= other.radius {
+            return other;
+        }
 
+        let radius = (distance + self.radius + other.radius) * 0.5;
         Self {
-            center: self.center,
-            radius: distance,
+            center: self.center + offset * ((radius - self.radius) / distance),
+            radius,
         }
     }
 
-    /// Expand the sphere to include another sphere
-    pub fn expand_to_include_sphere(self, other: BoundingSphere) -> Self {
-        let distance = (other.center - self.center).length();
-        let new_radius = (distance + other.radius).max(self.radius);
-        
-        Self {
-            center: self.center,
-            radius: new_radius,
+    /// Sweep this sphere against a static box, returning the time of impact
+    ///
+    /// `start` and `end` are translations of the sphere: its center moves
+    /// from `center + start` to `center + end`, so a sphere in an actor's
+    /// local space can be swept with the actor's old and new locations.
+    /// The result is in `[0, 1]` along that motion, `Some(0.0)` if the
+    /// sphere already touches the box. The test is continuous, so a fast
+    /// sphere cannot tunnel through a thin box between the endpoints.
+    pub fn sweep_against_box(self, start: Vector, end: Vector, bbox: BoundingBox) -> Option<f64> {
+        if !bbox.is_valid() {
+            return None;
+        }
+        let origin = self.center + start;
+        let delta = end - start;
+        if bbox.distance_to_point(origin) <= self.radius {
+            return Some(0.0);
+        }
+
+        // The box grown by the radius bounds the rounded box; entering it
+        // through a face region is a hit, otherwise the nearby edges decide
+        let time = BoundingBox::from_point(origin).sweep(delta, bbox.expand_by(self.radius))?;
+        let hit = origin + delta * time;
+        let mut outside = 0;
+        let mut corner = 0;
+        for axis in 0..3 {
+            if hit[axis] < bbox.min[axis] {
+                outside += 1;
+            } else if hit[axis] > bbox.max[axis] {
+                outside += 1;
+                corner |= 1 << axis;
+            }
+        }
+        if outside <= 1 {
+            return Some(time);
+        }
+
+        let corners = bbox.corners();
+        let capsule = |axis: usize| {
+            let base = corner & !(1 << axis);
+            sweep_point_against_capsule(origin, delta, corners[base], corners[base | 1 << axis], self.radius)
+        };
+        (0..3)
+            .filter(|&axis| outside == 3 || (hit[axis] >= bbox.min[axis] && hit[axis] <= bbox.max[axis]))
+            .filter_map(capsule)
+            .min_by(f64::total_cmp)
+    }
+
+    /// Sweep this sphere against a static sphere, returning the time of impact
+    ///
+    /// Same conventions as `sweep_against_box`.
+    pub fn sweep_against_sphere(self, start: Vector, end: Vector, other: BoundingSphere) -> Option<f64> {
+        let origin = self.center + start;
+        let combined = BoundingSphere::new(other.center, self.radius + other.radius);
+        if combined.contains_point(origin) {
+            return Some(0.0);
+        }
+        sweep_point_against_sphere(origin, end - start, combined)
+    }
+}
+
+/// First time in `[0, 1]` at which `origin + delta * t` enters `sphere`
+fn sweep_point_against_sphere(origin: Vector, delta: Vector, sphere: BoundingSphere) -> Option<f64> {
+    // The ray test works in units of its direction, so an unnormalized
+    // `delta` gives times along the segment
+    let (near, _) = Ray { origin, direction: delta }.intersects_sphere(sphere)?;
+    (near <= 1.0).then_some(near)
+}
+
+/// First time in `[0, 1]` at which `origin + delta * t` comes within `radius` of segment `a`-`b`
+fn sweep_point_against_capsule(origin: Vector, delta: Vector, a: Vector, b: Vector, radius: f64) -> Option<f64> {
+    let axis = b - a;
+    let axis_length_squared = axis.length_squared();
+    let to_origin = origin - a;
+
+    // Side of the cylinder, kept only where the contact lies along the segment
+    let perpendicular = |v: Vector| v - axis * (v.dot(axis) / axis_length_squared);
+    let side = (axis_length_squared > 0.0)
+        .then(|| {
+            let d = perpendicular(delta);
+            let m = perpendicular(to_origin);
+            let a2 = d.length_squared();
+            let b2 = m.dot(d);
+            let discriminant = b2 * b2 - a2 * (m.length_squared() - radius * radius);
+            if a2 == 0.0 || discriminant < 0.0 {
+                return None;
+            }
+            let time = (-b2 - discriminant.sqrt()) / a2;
+            let along = (to_origin + delta * time).dot(axis) / axis_length_squared;
+            ((0.0..=1.0).contains(&time) && (0.0..=1.0).contains(&along)).then_some(time)
+        })
+        .flatten();
+
+    let caps = [a, b].map(|end| sweep_point_against_sphere(origin, delta, BoundingSphere::new(end, radius)));
+    [side, caps[0], caps[1]]
+        .into_iter()
+        .flatten()
+        .min_by(f64::total_cmp)
+}
+
+/// Relative size below which constructions treat points as collinear or coplanar
+const DEGENERATE_TOLERANCE: f64 = 1e-20;
+
+/// Deterministic Fisher-Yates shuffle (xorshift), so Welzl's expected-linear
+/// bound holds for sorted inputs without making results vary between runs
+fn shuffle(points: &mut [Vector]) {
+    let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+    for i in (1..points.len()).rev() {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        points.swap(i, (state % (i as u64 + 1)) as usize);
+    }
+}
+
+/// Single precision copy of a [`BoundingSphere`] for GPU upload or compact payloads
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BoundingSphere32 {
+    /// Center of the sphere
+    pub center: Vec3,
+    /// Radius of the sphere
+    pub radius: f32,
+}
+
+impl BinarySerializable for BoundingSphere32 {}
+
+impl BoundingSphere32 {
+    /// Widen to a double precision [`BoundingSphere`]
+    pub fn to_f64(self) -> BoundingSphere {
+        BoundingSphere::new(self.center.as_dvec3(), self.radius as f64)
+    }
+}
+
+impl BoundingSphere {
+    /// Narrow to a single precision [`BoundingSphere32`]
+    ///
+    /// The radius grows by the center's rounding error (and its own), so the
+    /// narrowed sphere always contains the original.
+    pub fn to_f32(self) -> BoundingSphere32 {
+        let center = self.center.as_vec3();
+        let needed = self.radius + (center.as_dvec3() - self.center).length();
+        let radius = needed as f32;
+        BoundingSphere32 {
+            center,
+            radius: if (radius as f64) < needed { radius.next_up() } else { radius },
         }
     }
 }
@@ -194,4 +498,179 @@ mod tests {
         
         assert_eq!(sphere, deserialized);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_bounding_sphere_precision_conversion() {
+        let sphere = BoundingSphere::new(Vector::new(5_000_000.3, 0.1, -2.0), 0.7);
+        let single = sphere.to_f32();
+        let widened = single.to_f64();
+        assert!(widened.contains_sphere(sphere));
+        assert!(widened.radius < 1.0);
+
+        let exact = BoundingSphere::new(Vector::new(1.0, 2.0, 3.0), 4.5);
+        assert_eq!(exact.to_f32().to_f64(), exact);
+    }
+
+    #[test]
+    fn test_sphere_from_line_of_points() {
+        let points: Vec<Vector> = (0..=10).map(|i| Vector::new(i as f64, 0.0, 0.0)).collect();
+        for sphere in [BoundingSphere::from_points(&points), BoundingSphere::minimal_from_points(&points)] {
+            assert!((sphere.center - Vector::new(5.0, 0.0, 0.0)).length() < 1e-9);
+            assert!((sphere.radius - 5.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_sphere_from_points_known_optimum() {
+        // Regular tetrahedron inscribed in a sphere of radius sqrt(3) around (10, 20, 30), plus interior points
+        let center = Vector::new(10.0, 20.0, 30.0);
+        let mut points: Vec<Vector> = [
+            Vector::new(1.0, 1.0, 1.0),
+            Vector::new(1.0, -1.0, -1.0),
+            Vector::new(-1.0, 1.0, -1.0),
+            Vector::new(-1.0, -1.0, 1.0),
+        ]
+        .iter()
+        .map(|&corner| center + corner)
+        .collect();
+        let mut state = 12345u32;
+        for _ in 0..200 {
+            let mut coordinate = || {
+                state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                (state >> 8) as f64 / (1u32 << 24) as f64 - 0.5
+            };
+            points.push(center + Vector::new(coordinate(), coordinate(), coordinate()));
+        }
+
+        let minimal = BoundingSphere::minimal_from_points(&points);
+        assert!((minimal.radius - 3.0_f64.sqrt()).abs() < 1e-9);
+        assert!((minimal.center - center).length() < 1e-9);
+
+        let ritter = BoundingSphere::from_points(&points);
+        let fast = BoundingSphere::from_points_fast(&points);
+        for sphere in [minimal, ritter, fast] {
+            assert!(points.iter().all(|&p| (p - sphere.center).length() <= sphere.radius + 1e-9));
+        }
+        assert!(ritter.radius >= minimal.radius - 1e-9);
+        // Ritter is approximate; a regular tetrahedron is close to its worst case
+        assert!(ritter.radius < minimal.radius * 1.3);
+    }
+
+    #[test]
+    fn test_sphere_from_elongated_cloud() {
+        // The bounding box heuristic wastes space on long, thin clouds
+        let points = [
+            Vector::new(0.0, 0.0, 0.0),
+            Vector::new(100.0, 10.0, 0.0),
+            Vector::new(50.0, 5.0, 1.0),
+            Vector::new(100.0, 0.0, 0.0),
+            Vector::new(0.0, 10.0, 0.0),
+        ];
+        let minimal = BoundingSphere::minimal_from_points(&points);
+        let ritter = BoundingSphere::from_points(&points);
+        let fast = BoundingSphere::from_points_fast(&points);
+        assert!((minimal.radius - 101.0_f64.sqrt() * 5.0).abs() < 1e-9);
+        assert!(ritter.radius <= fast.radius);
+        assert!(minimal.radius <= ritter.radius + 1e-9);
+        assert_eq!(BoundingSphere::minimal_from_points(&[]).radius, 0.0);
+        assert_eq!(BoundingSphere::minimal_from_points(&[Vector::ONE]), BoundingSphere::new(Vector::ONE, 0.0));
+    }
+
+    #[test]
+    fn test_expand_to_include_sphere_moves_center() {
+        let a = BoundingSphere::new(Vector::ZERO, 1.0);
+        let b = BoundingSphere::new(Vector::new(10.0, 0.0, 0.0), 1.0);
+        let merged = a.expand_to_include_sphere(b);
+        assert_eq!(merged, BoundingSphere::new(Vector::new(5.0, 0.0, 0.0), 6.0));
+        assert!(merged.contains_sphere(a) && merged.contains_sphere(b));
+
+        // Containment either way returns the larger sphere unchanged
+        let big = BoundingSphere::new(Vector::ZERO, 10.0);
+        assert_eq!(big.expand_to_include_sphere(a), big);
+        assert_eq!(a.expand_to_include_sphere(big), big);
+
+        let grown = a.expand_to_include(Vector::new(3.0, 0.0, 0.0));
+        assert_eq!(grown, BoundingSphere::new(Vector::new(1.0, 0.0, 0.0), 2.0));
+    }
+
+    #[test]
+    fn test_sweep_starting_inside_box() {
+        let bbox = BoundingBox::new(Vector::ZERO, Vector::ONE);
+        let sphere = BoundingSphere::new(Vector::ZERO, 0.5);
+        let touching = Vector::new(-0.5, 0.5, 0.5);
+        assert_eq!(sphere.sweep_against_box(touching, Vector::new(-50.0, 0.5, 0.5), bbox), Some(0.0));
+        assert_eq!(sphere.sweep_against_box(Vector::splat(0.5), Vector::splat(0.5), bbox), Some(0.0));
+        assert_eq!(sphere.sweep_against_box(Vector::splat(5.0), Vector::splat(5.0), bbox), None);
+
+        let other = BoundingSphere::new(Vector::new(1.0, 0.0, 0.0), 0.6);
+        assert_eq!(sphere.sweep_against_sphere(Vector::ZERO, Vector::new(-9.0, 0.0, 0.0), other), Some(0.0));
+    }
+
+    #[test]
+    fn test_sweep_grazes_box_corner() {
+        // The path's closest approach is to the (1, 1, 1) corner, one unit
+        // out along the diagonal, halfway through the move
+        let bbox = BoundingBox::new(Vector::ZERO, Vector::ONE);
+        let closest = Vector::ONE + Vector::ONE.normalize();
+        let across = Vector::new(1.0, -1.0, 0.0).normalize() * 5.0;
+        let (start, end) = (closest - across, closest + across);
+
+        let time = BoundingSphere::new(Vector::ZERO, 1.0 + 1e-9).sweep_against_box(start, end, bbox).unwrap();
+        assert!((time - 0.5).abs() < 1e-4);
+        assert_eq!(BoundingSphere::new(Vector::ZERO, 1.0 - 1e-6).sweep_against_box(start, end, bbox), None);
+
+        // Inside the grown box's corner region but clear of the rounded corner
+        let near_miss = Vector::splat(1.0 + 0.9 / 3.0_f64.sqrt() * 1.2);
+        assert!(bbox.expand_by(0.9).contains_point(near_miss));
+        let sphere = BoundingSphere::new(Vector::ZERO, 0.9);
+        assert_eq!(sphere.sweep_against_box(near_miss - across, near_miss + across, bbox), None);
+    }
+
+    #[test]
+    fn test_fast_sweep_cannot_tunnel() {
+        // A wall 0.1 thick, crossed in a single 200 unit step
+        let wall = BoundingBox::new(Vector::new(0.0, -10.0, -10.0), Vector::new(0.1, 10.0, 10.0));
+        let sphere = BoundingSphere::new(Vector::new(0.0, 0.0, 1.0), 0.5);
+        let (start, end) = (Vector::new(-100.0, 0.0, 0.0), Vector::new(100.0, 0.0, 0.0));
+        assert!(!BoundingSphere::new(sphere.center + start, 0.5).intersects_box(wall));
+        assert!(!BoundingSphere::new(sphere.center + end, 0.5).intersects_box(wall));
+
+        let time = sphere.sweep_against_box(start, end, wall).unwrap();
+        assert!((time - 99.5 / 200.0).abs() < 1e-12);
+        let back = sphere.sweep_against_box(end, start, wall).unwrap();
+        assert!((back - 99.4 / 200.0).abs() < 1e-12);
+
+        let post = BoundingSphere::new(Vector::new(0.0, 0.0, 1.0), 0.25);
+        let time = sphere.sweep_against_sphere(start, end, post).unwrap();
+        assert!((time - 99.25 / 200.0).abs() < 1e-12);
+        assert_eq!(sphere.sweep_against_sphere(start, end, BoundingSphere::new(Vector::new(0.0, 5.0, 0.0), 1.0)), None);
+    }
+
+    #[test]
+    fn test_sweep_matches_sampled_contact() {
+        let bbox = BoundingBox::new(Vector::new(-1.0, -2.0, -0.5), Vector::new(1.0, 2.0, 0.5));
+        let sphere = BoundingSphere::new(Vector::ZERO, 0.75);
+        let mut state = 0x2545_F491u32;
+        let mut coordinate = || {
+            state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            ((state >> 8) as f64 / (1u32 << 24) as f64 - 0.5) * 8.0
+        };
+
+        let mut hits = 0;
+        for _ in 0..300 {
+            let start = Vector::new(coordinate(), coordinate(), coordinate());
+            let end = Vector::new(coordinate(), coordinate(), coordinate());
+            let at = |t: f64| bbox.distance_to_point(start + (end - start) * t);
+            match sphere.sweep_against_box(start, end, bbox) {
+                Some(0.0) => assert!(at(0.0) <= sphere.radius),
+                Some(time) => {
+                    hits += 1;
+                    assert!((at(time) - sphere.radius).abs() < 1e-9);
+                    assert!((0..100).all(|i| at(time * i as f64 / 100.0) >= sphere.radius - 1e-9));
+                }
+                None => assert!((0..=1000).all(|i| at(i as f64 / 1000.0) > sphere.radius - 1e-9)),
+            }
+        }
+        assert!(hits > 30);
+    }
+}
@@ -0,0 +1,208 @@
+//! Axis-aligned 2D box (FBox2D)
+
+use crate::BinarySerializable;
+use crate::types::{Ray2D, Vector2D};
+use serde::{Deserialize, Serialize};
+use core::fmt;
+
+/// Axis-aligned 2D box, for minimaps, zones and other top-down queries
+///
+/// Mirrors the [`BoundingBox`](super::BoundingBox) API in two dimensions.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Box2D {
+    /// Minimum corner of the box
+    pub min: Vector2D,
+    /// Maximum corner of the box
+    pub max: Vector2D,
+}
+
+impl fmt::Display for Box2D {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Box2D(Min: ({:.2}, {:.2}), Max: ({:.2}, {:.2}))",
+            self.min.x, self.min.y, self.max.x, self.max.y
+        )
+    }
+}
+
+impl BinarySerializable for Box2D {}
+
+impl Box2D {
+    /// Empty box (inverted min/max for initialization)
+    pub const EMPTY: Self = Self {
+        min: Vector2D::new(f64::INFINITY, f64::INFINITY),
+        max: Vector2D::new(f64::NEG_INFINITY, f64::NEG_INFINITY),
+    };
+
+    /// Create a new box with the given min and max corners
+    pub fn new(min: Vector2D, max: Vector2D) -> Self {
+        Self { min, max }
+    }
+
+    /// Create a box from center and extent (half-size)
+    pub fn from_center_and_extent(center: Vector2D, extent: Vector2D) -> Self {
+        Self {
+            min: center - extent,
+            max: center + extent,
+        }
+    }
+
+    /// Create a box from a single point
+    pub fn from_point(point: Vector2D) -> Self {
+        Self { min: point, max: point }
+    }
+
+    /// Create a box that encompasses all given points
+    pub fn from_points(points: &[Vector2D]) -> Self {
+        points.iter().fold(Self::EMPTY, |bbox, &point| bbox.expand_to_include(point))
+    }
+
+    /// Get the center point of the box
+    pub fn center(self) -> Vector2D {
+        (self.min + self.max) * 0.5
+    }
+
+    /// Get the extent (half-size) of the box
+    pub fn extent(self) -> Vector2D {
+        (self.max - self.min) * 0.5
+    }
+
+    /// Get the size (full dimensions) of the box
+    pub fn size(self) -> Vector2D {
+        self.max - self.min
+    }
+
+    /// Get the area of the box
+    pub fn area(self) -> f64 {
+        let size = self.size();
+        size.x * size.y
+    }
+
+    /// Check if the box is valid (min <= max for both axes)
+    pub fn is_valid(self) -> bool {
+        self.min.x <= self.max.x && self.min.y <= self.max.y
+    }
+
+    /// Check if the box is empty (has zero or negative area)
+    pub fn is_empty(self) -> bool {
+        self.min.x >= self.max.x || self.min.y >= self.max.y
+    }
+
+    /// Check if a point is inside the box (edges included)
+    pub fn contains_point(self, point: Vector2D) -> bool {
+        point.x >= self.min.x && point.x <= self.max.x && point.y >= self.min.y && point.y <= self.max.y
+    }
+
+    /// Check if another box is completely inside this one
+    pub fn contains_box(self, other: Box2D) -> bool {
+        self.contains_point(other.min) && self.contains_point(other.max)
+    }
+
+    /// Check if this box intersects with another (touching counts)
+    pub fn intersects(self, other: Box2D) -> bool {
+        self.min.x <= other.max.x && self.max.x >= other.min.x && self.min.y <= other.max.y && self.max.y >= other.min.y
+    }
+
+    /// Expand the box to include a point
+    pub fn expand_to_include(self, point: Vector2D) -> Self {
+        Self {
+            min: self.min.min(point),
+            max: self.max.max(point),
+        }
+    }
+
+    /// Expand the box to include another box
+    pub fn expand_to_include_box(self, other: Box2D) -> Self {
+        if !other.is_valid() {
+            return self;
+        }
+        if !self.is_valid() {
+            return other;
+        }
+        Self {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+
+    /// Expand the box by a given amount in all directions
+    pub fn expand_by(self, amount: f64) -> Self {
+        let expansion = Vector2D::splat(amount);
+        Self {
+            min: self.min - expansion,
+            max: self.max + expansion,
+        }
+    }
+
+    /// Get the overlap of two boxes, or `EMPTY` if they don't intersect
+    pub fn intersection(self, other: Box2D) -> Self {
+        if !self.intersects(other) {
+            return Self::EMPTY;
+        }
+        Self {
+            min: self.min.max(other.min),
+            max: self.max.min(other.max),
+        }
+    }
+
+    /// Get the closest point on (or in) the box to a given point
+    pub fn closest_point_to(self, point: Vector2D) -> Vector2D {
+        point.clamp(self.min, self.max)
+    }
+
+    /// Get the distance from a point to the box (0 if inside)
+    pub fn distance_to_point(self, point: Vector2D) -> f64 {
+        (point - self.closest_point_to(point)).length()
+    }
+
+    /// Intersect a ray with this box, returning the entry distance along the ray
+    ///
+    /// See `Ray2D::intersects_box` for the exact semantics.
+    pub fn intersects_ray(self, ray: Ray2D) -> Option<f64> {
+        ray.intersects_box(self)
+    }
+}
+
+impl Default for Box2D {
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_box2d_queries() {
+        let bbox = Box2D::from_points(&[Vector2D::new(0.0, 0.0), Vector2D::new(10.0, 4.0), Vector2D::new(2.0, -2.0)]);
+        assert_eq!(bbox, Box2D::new(Vector2D::new(0.0, -2.0), Vector2D::new(10.0, 4.0)));
+        assert_eq!(bbox.center(), Vector2D::new(5.0, 1.0));
+        assert_eq!(bbox.extent(), Vector2D::new(5.0, 3.0));
+        assert_eq!(bbox.area(), 60.0);
+        assert!(bbox.contains_point(Vector2D::new(10.0, 4.0)));
+        assert!(!bbox.contains_point(Vector2D::new(10.1, 0.0)));
+        assert_eq!(bbox.distance_to_point(Vector2D::new(13.0, 8.0)), 5.0);
+
+        let other = Box2D::from_center_and_extent(Vector2D::new(10.0, 4.0), Vector2D::splat(1.0));
+        assert!(bbox.intersects(other));
+        assert_eq!(bbox.intersection(other), Box2D::new(Vector2D::new(9.0, 3.0), Vector2D::new(10.0, 4.0)));
+        assert!(bbox.expand_to_include_box(other).contains_box(other));
+        assert_eq!(bbox.intersection(Box2D::from_point(Vector2D::new(50.0, 50.0))), Box2D::EMPTY);
+
+        assert_eq!(Box2D::from_points(&[]), Box2D::EMPTY);
+        assert_eq!(Box2D::EMPTY.expand_to_include_box(bbox), bbox);
+        assert_eq!(bbox.expand_by(1.0).size(), Vector2D::new(12.0, 8.0));
+    }
+
+    #[test]
+    fn test_box2d_serialization() {
+        let bbox = Box2D::new(Vector2D::new(-1.5, 2.0), Vector2D::new(3.0, 4.25));
+        assert_eq!(format!("{}", bbox), "Box2D(Min: (-1.50, 2.00), Max: (3.00, 4.25))");
+
+        let json = serde_json::to_string(&bbox).unwrap();
+        assert_eq!(serde_json::from_str::<Box2D>(&json).unwrap(), bbox);
+        assert_eq!(Box2D::from_binary(&bbox.to_binary().unwrap()).unwrap(), bbox);
+    }
+}
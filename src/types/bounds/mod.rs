@@ -2,7 +2,11 @@
 
 pub mod bounding_box;
 pub mod bounding_sphere;
+pub mod bounding_capsule;
+pub mod box2d;
 
 // Re-export all types for convenience
 pub use bounding_box::*;
-pub use bounding_sphere::*;
\ No newline at end of file
+pub use bounding_sphere::*;
+pub use bounding_capsule::*;
+pub use box2d::*;
@@ -3,13 +3,69 @@
 use crate::BinarySerializable;
 use super::LinearColor;
 use serde::{Deserialize, Serialize};
-use std::fmt;
+use core::fmt;
+use core::str::FromStr;
+use alloc::string::{String, ToString};
+use alloc::format;
+#[cfg(not(feature = "std"))]
+use crate::types::math::float_math::FloatMath;
+
+/// Reason a color string couldn't be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColorParseError {
+    /// Hex strings need 6 (RRGGBB) or 8 (RRGGBBAA) digits after the optional `#`
+    InvalidLength(usize),
+    /// A character that isn't a hex digit
+    InvalidDigit(char),
+    /// Not a hex string or a known color name
+    UnknownName(String),
+}
+
+impl fmt::Display for ColorParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColorParseError::InvalidLength(len) => {
+                write!(f, "expected 6 or 8 hex digits, found {}", len)
+            }
+            ColorParseError::InvalidDigit(c) => write!(f, "invalid hex digit '{}'", c),
+            ColorParseError::UnknownName(name) => write!(f, "unknown color name \"{}\"", name),
+        }
+    }
+}
+
+impl core::error::Error for ColorParseError {}
+
+/// CSS 2.1 basic colors, plus the usual aliases
+const NAMED_COLORS: &[(&str, u32)] = &[
+    ("black", 0x000000),
+    ("silver", 0xC0C0C0),
+    ("gray", 0x808080),
+    ("grey", 0x808080),
+    ("white", 0xFFFFFF),
+    ("maroon", 0x800000),
+    ("red", 0xFF0000),
+    ("purple", 0x800080),
+    ("fuchsia", 0xFF00FF),
+    ("magenta", 0xFF00FF),
+    ("green", 0x008000),
+    ("lime", 0x00FF00),
+    ("olive", 0x808000),
+    ("yellow", 0xFFFF00),
+    ("navy", 0x000080),
+    ("blue", 0x0000FF),
+    ("teal", 0x008080),
+    ("aqua", 0x00FFFF),
+    ("cyan", 0x00FFFF),
+    ("orange", 0xFFA500),
+];
 
 /// Color with 0-255 integer values (sRGB color space)
 /// 
 /// Standard 8-bit per channel color representation commonly used in textures
 /// and UI elements. Values are in sRGB color space.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(feature = "rkyv", archive(check_bytes, compare(PartialEq)), archive_attr(derive(Debug)))]
 pub struct Color {
     pub r: u8,
     pub g: u8,
@@ -26,6 +82,15 @@ impl fmt::Display for Color {
 
 impl BinarySerializable for Color {}
 
+impl crate::types::SerializedSize for Color {
+    fn serialized_size(&self) -> usize {
+        4
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl crate::types::ZeroCopy for Color {}
+
 impl Color {
     /// Pure white color
     pub const WHITE: Self = Self { r: 255, g: 255, b: 255, a: 255 };
@@ -47,14 +112,24 @@ impl Color {
     pub const TRANSPARENT: Self = Self { r: 0, g: 0, b: 0, a: 0 };
     /// Gray (50% brightness)
     pub const GRAY: Self = Self { r: 128, g: 128, b: 128, a: 255 };
+    /// Orange (UE: `FColor::Orange`, not the CSS `orange` that `from_name` returns)
+    pub const ORANGE: Self = Self { r: 243, g: 156, b: 18, a: 255 };
+    /// Purple (UE: `FColor::Purple`)
+    pub const PURPLE: Self = Self { r: 169, g: 7, b: 228, a: 255 };
+    /// Turquoise (UE: `FColor::Turquoise`)
+    pub const TURQUOISE: Self = Self { r: 26, g: 188, b: 156, a: 255 };
+    /// Silver (UE: `FColor::Silver`)
+    pub const SILVER: Self = Self { r: 189, g: 195, b: 199, a: 255 };
+    /// Emerald (UE: `FColor::Emerald`)
+    pub const EMERALD: Self = Self { r: 46, g: 204, b: 113, a: 255 };
 
     /// Create a new color with the given RGBA values
-    pub fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
+    pub const fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
         Self { r, g, b, a }
     }
 
     /// Create a new color with RGB values and full alpha
-    pub fn rgb(r: u8, g: u8, b: u8) -> Self {
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
         Self { r, g, b, a: 255 }
     }
 
@@ -93,17 +168,48 @@ impl Color {
         ((self.r as u32) << 24) | ((self.g as u32) << 16) | ((self.b as u32) << 8) | (self.a as u32)
     }
 
-    /// Convert to LinearColor (applies sRGB to linear conversion)
-    pub fn to_linear(self) -> LinearColor {
-        fn srgb_to_linear(value: u8) -> f32 {
-            let normalized = value as f32 / 255.0;
-            if normalized <= 0.04045 {
-                normalized / 12.92
-            } else {
-                ((normalized + 0.055) / 1.055).powf(2.4)
-            }
+    /// Parse a hex string: `"#FF8040"`, `"FF8040"` or with alpha `"#FF8040CC"`
+    pub fn from_hex_str(hex: &str) -> Result<Self, ColorParseError> {
+        let digits = hex.trim().strip_prefix('#').unwrap_or(hex.trim());
+        if let Some(c) = digits.chars().find(|c| !c.is_ascii_hexdigit()) {
+            return Err(ColorParseError::InvalidDigit(c));
+        }
+        let value = |index: usize| u8::from_str_radix(&digits[index..index + 2], 16).unwrap();
+        match digits.len() {
+            6 => Ok(Self::rgb(value(0), value(2), value(4))),
+            8 => Ok(Self::new(value(0), value(2), value(4), value(6))),
+            len => Err(ColorParseError::InvalidLength(len)),
         }
+    }
 
+    /// Format as `"#RRGGBB"`, or `"#RRGGBBAA"` with alpha
+    pub fn to_hex_string(self, include_alpha: bool) -> String {
+        if include_alpha {
+            format!("#{:02X}{:02X}{:02X}{:02X}", self.r, self.g, self.b, self.a)
+        } else {
+            format!("#{:02X}{:02X}{:02X}", self.r, self.g, self.b)
+        }
+    }
+
+    /// Look up a CSS basic color by name (case-insensitive)
+    ///
+    /// Covers the CSS 2.1 set (black, silver, gray, white, maroon, red,
+    /// purple, fuchsia, green, lime, olive, yellow, navy, blue, teal, aqua,
+    /// orange) plus grey, magenta and cyan. Note CSS "green" is `#008000`;
+    /// `Color::GREEN` is CSS "lime".
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim();
+        NAMED_COLORS
+            .iter()
+            .find(|(known, _)| known.eq_ignore_ascii_case(name))
+            .map(|&(_, hex)| Self::from_hex(hex))
+    }
+
+    /// Convert to LinearColor (applies sRGB to linear conversion)
+    ///
+    /// For many colors at once, `convert_srgb_to_linear` gives the same
+    /// result from a lookup table.
+    pub fn to_linear(self) -> LinearColor {
         LinearColor {
             r: srgb_to_linear(self.r),
             g: srgb_to_linear(self.g),
@@ -113,17 +219,10 @@ impl Color {
     }
 
     /// Convert from LinearColor (applies linear to sRGB conversion)
+    ///
+    /// For many colors at once, `convert_linear_to_srgb` gives the same
+    /// result from a lookup table.
     pub fn from_linear(linear: LinearColor) -> Self {
-        fn linear_to_srgb(value: f32) -> u8 {
-            let clamped = value.clamp(0.0, 1.0);
-            let converted = if clamped <= 0.0031308 {
-                clamped * 12.92
-            } else {
-                1.055 * clamped.powf(1.0 / 2.4) - 0.055
-            };
-            (converted * 255.0).round() as u8
-        }
-
         Self {
             r: linear_to_srgb(linear.r),
             g: linear_to_srgb(linear.g),
@@ -132,6 +231,18 @@ impl Color {
         }
     }
 
+    /// Pack as `0xRRGGBBAA` (UE: `FColor::ToPackedRGBA`, same as `to_hex_rgba`)
+    ///
+    /// R is the most significant byte, so the big-endian bytes are R, G, B, A.
+    pub fn to_packed_rgba(self) -> u32 {
+        self.to_hex_rgba()
+    }
+
+    /// Unpack a `0xRRGGBBAA` value, the inverse of `to_packed_rgba`
+    pub fn from_packed_rgba(packed: u32) -> Self {
+        Self::from_hex_rgba(packed)
+    }
+
     /// Get luminance (perceived brightness) 0-255
     pub fn luminance(self) -> u8 {
         let linear = self.to_linear();
@@ -152,6 +263,54 @@ impl Color {
     }
 }
 
+/// sRGB-encoded channel to linear
+pub(super) fn srgb_to_linear(value: u8) -> f32 {
+    let normalized = value as f32 / 255.0;
+    if normalized <= 0.04045 {
+        normalized / 12.92
+    } else {
+        ((normalized + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Linear channel to sRGB-encoded, clamped and rounded
+pub(super) fn linear_to_srgb(value: f32) -> u8 {
+    let clamped = value.clamp(0.0, 1.0);
+    let converted = if clamped <= 0.0031308 {
+        clamped * 12.92
+    } else {
+        1.055 * clamped.powf(1.0 / 2.4) - 0.055
+    };
+    (converted * 255.0).round() as u8
+}
+
+/// Parses a hex string (see `from_hex_str`) or a CSS color name (see `from_name`)
+impl FromStr for Color {
+    type Err = ColorParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        if trimmed.starts_with('#') || trimmed.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Self::from_hex_str(trimmed);
+        }
+        Self::from_name(trimmed).ok_or_else(|| ColorParseError::UnknownName(trimmed.to_string()))
+    }
+}
+
+/// Decodes sRGB like `to_linear` (UE: `FLinearColor(const FColor&)`); alpha is already linear
+impl From<Color> for LinearColor {
+    fn from(color: Color) -> Self {
+        color.to_linear()
+    }
+}
+
+/// Encodes to sRGB like `from_linear`, clamping to [0, 1]
+impl From<LinearColor> for Color {
+    fn from(linear: LinearColor) -> Self {
+        Color::from_linear(linear)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -175,6 +334,21 @@ mod tests {
         assert!((color.b as i16 - back_to_color.b as i16).abs() <= 1);
     }
 
+    #[test]
+    fn test_color_from_impls() {
+        // Every 8-bit channel value survives the sRGB round trip
+        for value in 0..=255u8 {
+            let color = Color::new(value, 255 - value, value / 2, value);
+            let linear = LinearColor::from(color);
+            assert_eq!(linear, color.to_linear());
+            assert_eq!(Color::from(linear), color);
+        }
+        // Mid gray is far darker in linear space
+        let gray = LinearColor::from(Color::rgb(128, 128, 128));
+        assert!((gray.r - 0.2158).abs() < 1e-3);
+        assert_eq!(Color::from(LinearColor::new(2.0, -1.0, 0.5, 1.0)), Color::new(255, 0, 188, 255));
+    }
+
     #[test]
     fn test_color_lerp() {
         let black = Color::BLACK;
@@ -217,4 +391,83 @@ mod tests {
         
         assert_eq!(color, deserialized);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_color_hex_string() {
+        let expected = Color::rgb(0xFF, 0x80, 0x40);
+        assert_eq!(Color::from_hex_str("#FF8040"), Ok(expected));
+        assert_eq!(Color::from_hex_str("ff8040"), Ok(expected));
+        assert_eq!(Color::from_hex_str("#FF8040CC"), Ok(Color::new(0xFF, 0x80, 0x40, 0xCC)));
+        assert_eq!(expected.to_hex_string(false), "#FF8040");
+        assert_eq!(Color::new(1, 2, 3, 4).to_hex_string(true), "#01020304");
+
+        let color = Color::new(12, 200, 77, 9);
+        assert_eq!(Color::from_hex_str(&color.to_hex_string(true)), Ok(color));
+    }
+
+    #[test]
+    fn test_color_parse_malformed() {
+        assert_eq!(Color::from_hex_str(""), Err(ColorParseError::InvalidLength(0)));
+        assert_eq!(Color::from_hex_str("#"), Err(ColorParseError::InvalidLength(0)));
+        assert_eq!(Color::from_hex_str("#FFF"), Err(ColorParseError::InvalidLength(3)));
+        assert_eq!(Color::from_hex_str("#FF80401"), Err(ColorParseError::InvalidLength(7)));
+        assert_eq!(Color::from_hex_str("#GG8040"), Err(ColorParseError::InvalidDigit('G')));
+        assert_eq!(Color::from_hex_str("##FF8040"), Err(ColorParseError::InvalidDigit('#')));
+        assert_eq!(Color::from_hex_str("#FF 8040"), Err(ColorParseError::InvalidDigit(' ')));
+        assert_eq!(Color::from_hex_str("#ÿÿ8040"), Err(ColorParseError::InvalidDigit('ÿ')));
+        assert_eq!(
+            "chartreuse".parse::<Color>(),
+            Err(ColorParseError::UnknownName("chartreuse".to_string()))
+        );
+        assert_eq!(
+            ColorParseError::InvalidLength(3).to_string(),
+            "expected 6 or 8 hex digits, found 3"
+        );
+    }
+
+    #[test]
+    fn test_color_from_name() {
+        assert_eq!(Color::from_name("red"), Some(Color::RED));
+        assert_eq!(Color::from_name("Orange"), Some(Color::rgb(255, 165, 0)));
+        assert_eq!(Color::from_name("lime"), Some(Color::GREEN));
+        assert_eq!(Color::from_name("green"), Some(Color::rgb(0, 128, 0)));
+        assert_eq!(Color::from_name("grey"), Color::from_name("gray"));
+        assert_eq!(Color::from_name("nope"), None);
+
+        assert_eq!(" navy ".parse::<Color>(), Ok(Color::rgb(0, 0, 128)));
+        assert_eq!("#00FF00".parse::<Color>(), Ok(Color::GREEN));
+        assert_eq!("00ff00".parse::<Color>(), Ok(Color::GREEN));
+    }
+
+    #[test]
+    fn test_color_packed_rgba() {
+        let color = Color::new(0x11, 0x22, 0x33, 0x44);
+        assert_eq!(color.to_packed_rgba(), 0x11223344);
+        assert_eq!(color.to_packed_rgba().to_be_bytes(), [0x11, 0x22, 0x33, 0x44]);
+        assert_eq!(Color::from_packed_rgba(0x11223344), color);
+        assert_eq!(Color::from_packed_rgba(Color::WHITE.to_packed_rgba()), Color::WHITE);
+    }
+
+    #[test]
+    fn test_ue_palette_constants() {
+        // Usable in statics now that the constructors are const
+        const ACCENT: Color = Color::rgb(243, 156, 18);
+        const HIGHLIGHT: LinearColor = LinearColor::new(0.0, 0.5, 1.0, 0.25);
+        assert_eq!(ACCENT, Color::ORANGE);
+        assert_eq!(HIGHLIGHT.a, 0.25);
+
+        let palette = [
+            (Color::ORANGE, LinearColor::ORANGE),
+            (Color::PURPLE, LinearColor::PURPLE),
+            (Color::TURQUOISE, LinearColor::TURQUOISE),
+            (Color::SILVER, LinearColor::SILVER),
+            (Color::EMERALD, LinearColor::EMERALD),
+        ];
+        for (srgb, linear) in palette {
+            let converted = srgb.to_linear();
+            let error = (converted.r - linear.r).abs().max((converted.g - linear.g).abs()).max((converted.b - linear.b).abs());
+            assert!(error < 1e-4, "{} vs {}", converted, linear);
+            assert_eq!(Color::from_linear(linear), srgb);
+        }
+    }
+}
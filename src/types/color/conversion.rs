@@ -0,0 +1,157 @@
+//! Batch sRGB <-> linear conversion through lookup tables
+
+use super::color::{linear_to_srgb, srgb_to_linear};
+use super::{Color, LinearColor};
+#[cfg(feature = "std")]
+use std::sync::OnceLock;
+#[cfg(not(feature = "std"))]
+use crate::types::math::float_math::FloatMath;
+
+/// Linear value of each 8-bit sRGB level
+fn build_srgb_to_linear_table() -> [f32; 256] {
+    core::array::from_fn(|level| srgb_to_linear(level as u8))
+}
+
+/// Smallest linear value that encodes to each sRGB level 1..=255
+///
+/// Found by bisecting the bit patterns of `[0, 1]` (positive floats order
+/// like their bits) against the per-pixel conversion, so looking a value up
+/// gives exactly what `Color::from_linear` would.
+fn build_linear_to_srgb_thresholds() -> [f32; 255] {
+    core::array::from_fn(|index| {
+        let level = index as u8 + 1;
+        let (mut low, mut high) = (0.0_f32.to_bits(), 1.0_f32.to_bits());
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if linear_to_srgb(f32::from_bits(mid)) >= level {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        }
+        f32::from_bits(low)
+    })
+}
+
+#[cfg(feature = "std")]
+fn srgb_to_linear_table() -> &'static [f32; 256] {
+    static TABLE: OnceLock<[f32; 256]> = OnceLock::new();
+    TABLE.get_or_init(build_srgb_to_linear_table)
+}
+
+#[cfg(feature = "std")]
+fn linear_to_srgb_thresholds() -> &'static [f32; 255] {
+    static TABLE: OnceLock<[f32; 255]> = OnceLock::new();
+    TABLE.get_or_init(build_linear_to_srgb_thresholds)
+}
+
+// Without std there is nowhere to cache the tables, so each batch builds its own
+#[cfg(not(feature = "std"))]
+fn srgb_to_linear_table() -> [f32; 256] {
+    build_srgb_to_linear_table()
+}
+
+#[cfg(not(feature = "std"))]
+fn linear_to_srgb_thresholds() -> [f32; 255] {
+    build_linear_to_srgb_thresholds()
+}
+
+fn encode_channel(thresholds: &[f32; 255], value: f32) -> u8 {
+    // NaN compares false and lands on 0, like the per-pixel conversion
+    thresholds.partition_point(|&threshold| threshold <= value) as u8
+}
+
+/// Convert sRGB colors to linear, same result as `Color::to_linear` per element
+///
+/// Uses a 256-entry table instead of `powf`.
+///
+/// # Panics
+///
+/// Panics if `src` and `dst` have different lengths.
+pub fn convert_srgb_to_linear(src: &[Color], dst: &mut [LinearColor]) {
+    assert_eq!(src.len(), dst.len(), "source and destination lengths differ");
+    let table = srgb_to_linear_table();
+    for (color, linear) in src.iter().zip(dst.iter_mut()) {
+        *linear = LinearColor {
+            r: table[color.r as usize],
+            g: table[color.g as usize],
+            b: table[color.b as usize],
+            a: color.a as f32 / 255.0,
+        };
+    }
+}
+
+/// Convert linear colors to sRGB, same result as `Color::from_linear` per element
+///
+/// Each channel is a binary search over a table of level thresholds
+/// instead of a `powf`.
+///
+/// # Panics
+///
+/// Panics if `src` and `dst` have different lengths.
+pub fn convert_linear_to_srgb(src: &[LinearColor], dst: &mut [Color]) {
+    assert_eq!(src.len(), dst.len(), "source and destination lengths differ");
+    let thresholds = &linear_to_srgb_thresholds();
+    for (linear, color) in src.iter().zip(dst.iter_mut()) {
+        *color = Color {
+            r: encode_channel(thresholds, linear.r),
+            g: encode_channel(thresholds, linear.g),
+            b: encode_channel(thresholds, linear.b),
+            a: (linear.a * 255.0).round() as u8,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_srgb_to_linear_matches_per_pixel() {
+        let src: Vec<Color> = (0..=255).map(|level| Color::new(level, 255 - level, level / 2, level)).collect();
+        let mut dst = vec![LinearColor::TRANSPARENT; src.len()];
+        convert_srgb_to_linear(&src, &mut dst);
+        for (color, linear) in src.iter().zip(&dst) {
+            assert_eq!(*linear, color.to_linear());
+            assert!(linear.is_nearly_equal(color.to_linear(), 1.0 / 255.0));
+        }
+    }
+
+    #[test]
+    fn test_linear_to_srgb_matches_per_pixel() {
+        // Every threshold and its neighbouring floats, plus out-of-range values
+        let mut values = vec![-1.0, 0.0, 1.0, 2.0, f32::NAN, f32::INFINITY];
+        for &threshold in linear_to_srgb_thresholds() {
+            values.extend([threshold.next_down(), threshold, threshold.next_up()]);
+        }
+        let src: Vec<LinearColor> = values.iter().map(|&v| LinearColor::new(v, v * 0.5, 1.0 - v, 0.5)).collect();
+        let mut dst = vec![Color::TRANSPARENT; src.len()];
+        convert_linear_to_srgb(&src, &mut dst);
+        for (linear, color) in src.iter().zip(&dst) {
+            assert_eq!(*color, Color::from_linear(*linear), "{}", linear);
+        }
+    }
+
+    #[test]
+    fn test_batch_round_trip_identical_to_per_pixel() {
+        // A 64K-color payload through both paths gives identical bytes
+        let src: Vec<Color> = (0..65536u32)
+            .map(|i| Color::from_packed_rgba(i.wrapping_mul(2654435761)))
+            .collect();
+        let mut linear = vec![LinearColor::TRANSPARENT; src.len()];
+        let mut back = vec![Color::TRANSPARENT; src.len()];
+        convert_srgb_to_linear(&src, &mut linear);
+        convert_linear_to_srgb(&linear, &mut back);
+
+        assert_eq!(back, src);
+        for (color, linear) in src.iter().zip(&linear).step_by(97) {
+            assert_eq!(Color::from_linear(*linear), Color::from_linear(color.to_linear()));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "lengths differ")]
+    fn test_mismatched_lengths_panic() {
+        convert_srgb_to_linear(&[Color::WHITE], &mut []);
+    }
+}
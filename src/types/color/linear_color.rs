@@ -1,15 +1,22 @@
 //! Linear Color (0.0 to 1.0 range, HDR capable)
 
+use crate::types::math::nearly_equal::max_error;
+use crate::types::NearlyEqual;
 use crate::BinarySerializable;
 use glam::Vec4;
 use serde::{Deserialize, Serialize};
-use std::fmt;
+use core::fmt;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+#[cfg(not(feature = "std"))]
+use crate::types::math::float_math::FloatMath;
 
 /// Linear Color (0.0 to 1.0 range, HDR capable)
 /// 
 /// Represents color in linear color space with floating point precision.
 /// This is the preferred color format for mathematical operations and shaders.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(feature = "rkyv", archive(check_bytes, compare(PartialEq)), archive_attr(derive(Debug)))]
 pub struct LinearColor {
     pub r: f32,
     pub g: f32,
@@ -26,6 +33,15 @@ impl fmt::Display for LinearColor {
 
 impl BinarySerializable for LinearColor {}
 
+impl crate::types::SerializedSize for LinearColor {
+    fn serialized_size(&self) -> usize {
+        16
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl crate::types::ZeroCopy for LinearColor {}
+
 impl LinearColor {
     /// Pure white color
     pub const WHITE: Self = Self { r: 1.0, g: 1.0, b: 1.0, a: 1.0 };
@@ -47,14 +63,24 @@ impl LinearColor {
     pub const TRANSPARENT: Self = Self { r: 0.0, g: 0.0, b: 0.0, a: 0.0 };
     /// Gray (50% brightness)
     pub const GRAY: Self = Self { r: 0.5, g: 0.5, b: 0.5, a: 1.0 };
+    /// Orange, the linear value of `Color::ORANGE`
+    pub const ORANGE: Self = Self { r: 0.8963, g: 0.3325, b: 0.0060, a: 1.0 };
+    /// Purple, the linear value of `Color::PURPLE`
+    pub const PURPLE: Self = Self { r: 0.3968, g: 0.0021, b: 0.7758, a: 1.0 };
+    /// Turquoise, the linear value of `Color::TURQUOISE`
+    pub const TURQUOISE: Self = Self { r: 0.0103, g: 0.5029, b: 0.3325, a: 1.0 };
+    /// Silver, the linear value of `Color::SILVER`
+    pub const SILVER: Self = Self { r: 0.5089, g: 0.5457, b: 0.5711, a: 1.0 };
+    /// Emerald, the linear value of `Color::EMERALD`
+    pub const EMERALD: Self = Self { r: 0.0273, g: 0.6038, b: 0.1651, a: 1.0 };
 
     /// Create a new linear color with the given RGBA values
-    pub fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
         Self { r, g, b, a }
     }
 
     /// Create a new linear color with RGB values and full alpha
-    pub fn rgb(r: f32, g: f32, b: f32) -> Self {
+    pub const fn rgb(r: f32, g: f32, b: f32) -> Self {
         Self { r, g, b, a: 1.0 }
     }
 
@@ -64,8 +90,10 @@ impl LinearColor {
     }
 
     /// Create from HSV (Hue, Saturation, Value) color space
+    ///
+    /// Hue is in degrees and wraps, so -120 and 240 are the same hue.
     pub fn from_hsv(h: f32, s: f32, v: f32) -> Self {
-        let h = h % 360.0;
+        let h = h.rem_euclid(360.0);
         let c = v * s;
         let x = c * (1.0 - ((h / 60.0) % 2.0 - 1.0).abs());
         let m = v - c;
@@ -87,6 +115,31 @@ impl LinearColor {
         Self::rgb(r + m, g + m, b + m)
     }
 
+    /// Convert to HSV, the inverse of `from_hsv`
+    ///
+    /// Returns hue in degrees `[0, 360)`, saturation and value. Grays
+    /// (including black) have no hue and report 0; black also reports
+    /// saturation 0. Alpha is ignored.
+    pub fn to_hsv(self) -> (f32, f32, f32) {
+        let max = self.r.max(self.g).max(self.b);
+        let min = self.r.min(self.g).min(self.b);
+        let delta = max - min;
+
+        let hue = if delta <= 0.0 {
+            0.0
+        } else if max == self.r {
+            60.0 * ((self.g - self.b) / delta).rem_euclid(6.0)
+        } else if max == self.g {
+            60.0 * ((self.b - self.r) / delta + 2.0)
+        } else {
+            60.0 * ((self.r - self.g) / delta + 4.0)
+        };
+        let saturation = if max > 0.0 { delta / max } else { 0.0 };
+
+        // rem_euclid can round up to exactly 360 for tiny negative inputs
+        (if hue >= 360.0 { 0.0 } else { hue }, saturation, max)
+    }
+
     /// Convert to Vec4 for shader usage
     pub fn to_vec4(self) -> Vec4 {
         Vec4::new(self.r, self.g, self.b, self.a)
@@ -132,18 +185,152 @@ impl LinearColor {
         }
     }
 
-    /// Check if the color is nearly equal to another
+    /// Blend toward gray of the same luminance; 0 keeps the color, 1 is fully gray
+    ///
+    /// Alpha is unchanged. Values above 1.0 are kept, so HDR colors stay bright.
+    pub fn desaturate(self, fraction: f32) -> Self {
+        let gray = Self { a: self.a, ..Self::gray(self.luminance()) };
+        self.lerp(gray, fraction)
+    }
+
+    /// Encode linear RGB with the sRGB transfer curve (alpha unchanged)
+    ///
+    /// Unlike `Color::from_linear` this doesn't clamp or quantize, so HDR
+    /// values above 1.0 follow the curve's extension.
+    pub fn to_srgb(self) -> Self {
+        fn encode(value: f32) -> f32 {
+            if value <= 0.0031308 {
+                value * 12.92
+            } else {
+                1.055 * value.powf(1.0 / 2.4) - 0.055
+            }
+        }
+        Self::new(encode(self.r), encode(self.g), encode(self.b), self.a)
+    }
+
+    /// Decode sRGB-encoded RGB to linear, the inverse of `to_srgb`
+    pub fn from_srgb(srgb: LinearColor) -> Self {
+        fn decode(value: f32) -> f32 {
+            if value <= 0.04045 {
+                value / 12.92
+            } else {
+                ((value + 0.055) / 1.055).powf(2.4)
+            }
+        }
+        Self::new(decode(srgb.r), decode(srgb.g), decode(srgb.b), srgb.a)
+    }
+
+    /// Multiply RGB by alpha, for premultiplied blending
+    pub fn premultiply_alpha(self) -> Self {
+        Self::new(self.r * self.a, self.g * self.a, self.b * self.a, self.a)
+    }
+
+    /// Largest of the R, G and B channels
+    pub fn max_component(self) -> f32 {
+        self.r.max(self.g).max(self.b)
+    }
+
+    /// Euclidean distance to another color in linear space, including alpha (UE: `Dist`)
+    pub fn distance(self, other: LinearColor) -> f32 {
+        let (r, g, b, a) = (self.r - other.r, self.g - other.g, self.b - other.b, self.a - other.a);
+        (r * r + g * g + b * b + a * a).sqrt()
+    }
+
+    /// Check if the color is nearly equal to another, including alpha (see [`NearlyEqual`])
     pub fn is_nearly_equal(self, other: LinearColor, tolerance: f32) -> bool {
-        (self.r - other.r).abs() <= tolerance
-            && (self.g - other.g).abs() <= tolerance
-            && (self.b - other.b).abs() <= tolerance
-            && (self.a - other.a).abs() <= tolerance
+        self.nearly_equal(&other, tolerance as f64)
+    }
+}
+
+// Adding, subtracting and scaling work on RGB and keep the left-hand alpha,
+// like `scale`; modulating by another color multiplies alpha too.
+
+impl Add for LinearColor {
+    type Output = LinearColor;
+
+    fn add(self, rhs: LinearColor) -> LinearColor {
+        Self::new(self.r + rhs.r, self.g + rhs.g, self.b + rhs.b, self.a)
+    }
+}
+
+impl AddAssign for LinearColor {
+    fn add_assign(&mut self, rhs: LinearColor) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for LinearColor {
+    type Output = LinearColor;
+
+    fn sub(self, rhs: LinearColor) -> LinearColor {
+        Self::new(self.r - rhs.r, self.g - rhs.g, self.b - rhs.b, self.a)
+    }
+}
+
+impl SubAssign for LinearColor {
+    fn sub_assign(&mut self, rhs: LinearColor) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<f32> for LinearColor {
+    type Output = LinearColor;
+
+    fn mul(self, rhs: f32) -> LinearColor {
+        self.scale(rhs)
+    }
+}
+
+impl MulAssign<f32> for LinearColor {
+    fn mul_assign(&mut self, rhs: f32) {
+        *self = self.scale(rhs);
+    }
+}
+
+/// Component-wise modulate (tint), including alpha
+impl Mul for LinearColor {
+    type Output = LinearColor;
+
+    fn mul(self, rhs: LinearColor) -> LinearColor {
+        Self::new(self.r * rhs.r, self.g * rhs.g, self.b * rhs.b, self.a * rhs.a)
+    }
+}
+
+impl MulAssign for LinearColor {
+    fn mul_assign(&mut self, rhs: LinearColor) {
+        *self = *self * rhs;
+    }
+}
+
+impl NearlyEqual for LinearColor {
+    fn max_component_error(&self, other: &Self) -> f64 {
+        // Differences are taken in f32, so f32 tolerances behave exactly as before widening
+        let difference = |a: f32, b: f32| if a == b { 0.0 } else { (a - b).abs() as f64 };
+        max_error([
+            difference(self.r, other.r),
+            difference(self.g, other.g),
+            difference(self.b, other.b),
+            difference(self.a, other.a),
+        ])
+    }
+}
+
+impl From<LinearColor> for Vec4 {
+    fn from(color: LinearColor) -> Self {
+        color.to_vec4()
+    }
+}
+
+impl From<Vec4> for LinearColor {
+    fn from(v: Vec4) -> Self {
+        Self::from_vec4(v)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::Color;
 
     #[test]
     fn test_linear_color_hsv() {
@@ -157,6 +344,14 @@ mod tests {
         assert!(blue.is_nearly_equal(LinearColor::BLUE, 0.001));
     }
 
+    #[test]
+    fn test_linear_color_vec4_from_impls() {
+        let color = LinearColor::new(0.25, 1.5, 0.0, 0.75);
+        let v = Vec4::from(color);
+        assert_eq!(v, Vec4::new(0.25, 1.5, 0.0, 0.75));
+        assert_eq!(LinearColor::from(v), color);
+    }
+
     #[test]
     fn test_linear_color_display() {
         let color = LinearColor::new(0.5, 0.75, 1.0, 0.8);
@@ -188,4 +383,74 @@ mod tests {
         
         assert!(color.is_nearly_equal(deserialized, 0.001));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_linear_color_hsv_round_trip() {
+        for hue_step in 0..36 {
+            for &saturation in &[0.25_f32, 0.5, 1.0] {
+                for &value in &[0.1_f32, 0.6, 1.0] {
+                    let hue = hue_step as f32 * 10.0;
+                    let color = LinearColor::from_hsv(hue, saturation, value);
+                    let (h, s, v) = color.to_hsv();
+                    assert!((h - hue).abs() < 1e-3, "hue {} -> {}", hue, h);
+                    assert!((s - saturation).abs() < 1e-5);
+                    assert!((v - value).abs() < 1e-6);
+                    assert!(LinearColor::from_hsv(h, s, v).is_nearly_equal(color, 1e-5));
+                }
+            }
+        }
+
+        // Grays have no hue
+        assert_eq!(LinearColor::gray(0.5).to_hsv(), (0.0, 0.0, 0.5));
+        assert_eq!(LinearColor::BLACK.to_hsv(), (0.0, 0.0, 0.0));
+        assert!(LinearColor::from_hsv(-120.0, 1.0, 1.0).is_nearly_equal(LinearColor::BLUE, 1e-6));
+    }
+
+    #[test]
+    fn test_linear_color_operators() {
+        let color = LinearColor::new(0.8, 0.4, 0.2, 0.5);
+        assert_eq!(color * LinearColor::WHITE, color);
+        assert_eq!(color + LinearColor::BLACK, color);
+        assert_eq!(color - LinearColor::BLACK, color);
+        assert_eq!(color * 1.0, color);
+
+        // HDR values survive, alpha is kept when adding and scaling
+        let hdr = color * 4.0 + LinearColor::rgb(1.0, 1.0, 1.0);
+        assert!(hdr.is_nearly_equal(LinearColor::new(4.2, 2.6, 1.8, 0.5), 1e-6));
+        assert!((hdr.max_component() - 4.2).abs() < 1e-6);
+        assert_eq!((color * LinearColor::new(2.0, 2.0, 2.0, 0.5)).a, 0.25);
+
+        let mut accumulated = color;
+        accumulated += LinearColor::RED;
+        accumulated -= LinearColor::RED;
+        accumulated *= 2.0;
+        accumulated *= LinearColor::WHITE;
+        assert!(accumulated.is_nearly_equal(color * 2.0, 1e-6));
+
+        assert_eq!(color.premultiply_alpha(), LinearColor::new(0.4, 0.2, 0.1, 0.5));
+        assert_eq!(LinearColor::BLACK.distance(LinearColor::new(3.0, 4.0, 0.0, 1.0)), 5.0);
+    }
+
+    #[test]
+    fn test_linear_color_desaturate_and_srgb() {
+        let color = LinearColor::new(2.0, 0.4, 0.1, 0.7);
+        assert_eq!(color.desaturate(0.0), color);
+        let gray = color.desaturate(1.0);
+        assert_eq!(gray.r, gray.g);
+        assert_eq!(gray.g, gray.b);
+        assert!((gray.r - color.luminance()).abs() < 1e-6);
+        assert_eq!(gray.a, 0.7);
+
+        for &value in &[0.0_f32, 0.002, 0.2, 0.5, 1.0, 3.0] {
+            let linear = LinearColor::new(value, value * 0.5, 0.0, 0.3);
+            let round_trip = LinearColor::from_srgb(linear.to_srgb());
+            assert!(round_trip.is_nearly_equal(linear, 1e-5 * value.max(1.0)), "{}", value);
+        }
+        assert!(LinearColor::new(4.0, 0.0, 0.0, 1.0).to_srgb().r > 1.0);
+
+        // Matches the u8 conversion on Color
+        let srgb = Color::new(200, 100, 50, 255);
+        let decoded = LinearColor::from_srgb(LinearColor::new(200.0 / 255.0, 100.0 / 255.0, 50.0 / 255.0, 1.0));
+        assert!(decoded.is_nearly_equal(srgb.to_linear(), 1e-6));
+    }
+}
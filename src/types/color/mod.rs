@@ -1,8 +1,11 @@
 //! Color types for UE-style color handling
 
 pub mod linear_color;
+#[allow(clippy::module_inception)]
 pub mod color;
+pub mod conversion;
 
 // Re-export all types for convenience
 pub use linear_color::*;
-pub use color::*;
\ No newline at end of file
+pub use color::*;
+pub use conversion::*;
\ No newline at end of file
@@ -0,0 +1,70 @@
+//! Hash map and set implementations behind `TMap` and `TSet`
+//!
+//! The standard library's with `std`, and `hashbrown` (the same table) in
+//! `no_std` builds. Without `std` there is no OS randomness to seed the
+//! hasher from, so `no_std` maps hash with `SeededHashBuilder`, which takes
+//! its seed from `set_hash_seed`.
+
+#[cfg(feature = "std")]
+pub(crate) use std::collections::{hash_map, hash_set, HashMap, HashSet};
+
+#[cfg(not(feature = "std"))]
+pub(crate) use hashbrown::{hash_map, hash_set};
+
+#[cfg(not(feature = "std"))]
+use core::sync::atomic::{AtomicU64, Ordering};
+
+/// Hasher builder `HashMap` and `HashSet` use by default
+#[cfg(feature = "std")]
+pub(crate) type DefaultHashBuilder = std::collections::hash_map::RandomState;
+
+/// Hasher builder `HashMap` and `HashSet` use by default
+#[cfg(not(feature = "std"))]
+pub(crate) type DefaultHashBuilder = SeededHashBuilder;
+
+#[cfg(not(feature = "std"))]
+pub(crate) type HashMap<K, V, S = SeededHashBuilder> = hashbrown::HashMap<K, V, S>;
+
+#[cfg(not(feature = "std"))]
+pub(crate) type HashSet<T, S = SeededHashBuilder> = hashbrown::HashSet<T, S>;
+
+#[cfg(not(feature = "std"))]
+static HASH_SEED: AtomicU64 = AtomicU64::new(0);
+
+/// Seed the hasher of every `TMap` and `TSet` created from now on (`no_std` only)
+///
+/// Call this once at startup with a value from a real entropy source (a
+/// hardware RNG, the platform's random syscall). Until then maps hash with a
+/// fixed seed, so anyone who can choose the keys, such as clients sending
+/// names, can pick ones that collide and make every lookup linear. Maps
+/// created before the call keep their old seed.
+#[cfg(not(feature = "std"))]
+pub fn set_hash_seed(seed: u64) {
+    HASH_SEED.store(seed, Ordering::Relaxed);
+}
+
+/// Hasher builder seeded from `set_hash_seed` (`no_std` only)
+///
+/// Each builder also mixes in a per-map counter, so two maps with the same
+/// keys don't share a bucket layout.
+#[cfg(not(feature = "std"))]
+#[derive(Clone, Copy, Debug)]
+pub struct SeededHashBuilder(foldhash::fast::FixedState);
+
+#[cfg(not(feature = "std"))]
+impl Default for SeededHashBuilder {
+    fn default() -> Self {
+        static MAPS_CREATED: AtomicU64 = AtomicU64::new(0);
+        let per_map = MAPS_CREATED.fetch_add(1, Ordering::Relaxed).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        Self(foldhash::fast::FixedState::with_seed(HASH_SEED.load(Ordering::Relaxed) ^ per_map))
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl core::hash::BuildHasher for SeededHashBuilder {
+    type Hasher = foldhash::fast::FoldHasher;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        self.0.build_hasher()
+    }
+}
@@ -3,11 +3,18 @@
 //! This module provides UE5-compatible container types that mirror
 //! the behavior and API of Unreal Engine's container classes.
 
+pub(crate) mod hash;
 pub mod tarray;
+pub mod tinline_array;
 pub mod tmap;
 pub mod tset;
+pub mod tsparse_array;
 
 // Re-export container types for convenience
-pub use tarray::TArray;
-pub use tmap::TMap;
-pub use tset::TSet;
\ No newline at end of file
+pub use tarray::{TArray, INDEX_NONE, MAX_DESERIALIZED_ELEMENTS};
+pub use tinline_array::{InlineArrayFullError, TInlineArray};
+pub use tmap::{tmap_as_pairs, TMap};
+pub use tset::TSet;
+pub use tsparse_array::{SparseIndex, TSparseArray};
+#[cfg(not(feature = "std"))]
+pub use hash::{set_hash_seed, SeededHashBuilder};
\ No newline at end of file
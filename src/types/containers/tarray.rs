@@ -1,14 +1,27 @@
 //! UE5-style dynamic array (TArray)
 
 use crate::BinarySerializable;
-use serde::{Deserialize, Serialize};
-use std::fmt;
+use serde::de::{self, MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use core::cmp::Ordering;
+use core::fmt;
+use core::marker::PhantomData;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Index returned by lookups that find nothing (UE5: INDEX_NONE)
+pub const INDEX_NONE: i32 = -1;
 
 /// UE5-style dynamic array (equivalent to TArray)
 /// 
 /// A growable array with UE5-compatible methods and naming conventions.
 /// Internally uses Vec<T> but provides UE5-style API.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// Serializes as a bare sequence: a JSON array, matching UE's
+/// FJsonObjectConverter, and a length-prefixed sequence in bincode.
+/// Human-readable formats also accept the `{"data": [...]}` object written
+/// by earlier versions of this crate.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TArray<T> {
     data: Vec<T>,
 }
@@ -28,6 +41,118 @@ impl<T: fmt::Display> fmt::Display for TArray<T> {
 
 impl<T: serde::Serialize + serde::de::DeserializeOwned> BinarySerializable for TArray<T> {}
 
+impl<T: crate::types::SerializedSize> crate::types::SerializedSize for TArray<T> {
+    fn serialized_size(&self) -> usize {
+        self.as_slice().serialized_size()
+    }
+}
+
+impl<T: Serialize> Serialize for TArray<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.data.serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for TArray<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_seq_or_legacy(deserializer).map(Self::from_vec)
+    }
+}
+
+/// Most elements a container accepts when deserializing (16M)
+///
+/// Binary formats announce a length up front; a corrupt or hostile prefix
+/// above this is rejected before any element is read, and sequences without
+/// a length are cut off once they pass it.
+pub const MAX_DESERIALIZED_ELEMENTS: usize = 16 * 1024 * 1024;
+
+/// Fail with `invalid_length` if `count` exceeds `MAX_DESERIALIZED_ELEMENTS`
+pub(super) fn check_element_count<E: de::Error>(count: usize) -> Result<(), E> {
+    if count > MAX_DESERIALIZED_ELEMENTS {
+        Err(E::invalid_length(count, &"at most MAX_DESERIALIZED_ELEMENTS elements"))
+    } else {
+        Ok(())
+    }
+}
+
+/// Collect a sequence into a Vec, enforcing `MAX_DESERIALIZED_ELEMENTS`
+fn collect_bounded<'de, A: SeqAccess<'de>, T: Deserialize<'de>>(mut seq: A) -> Result<Vec<T>, A::Error> {
+    if let Some(hint) = seq.size_hint() {
+        check_element_count(hint)?;
+    }
+    // Don't trust the hint with more than a modest preallocation
+    let mut data = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+    while let Some(item) = seq.next_element()? {
+        check_element_count(data.len() + 1)?;
+        data.push(item);
+    }
+    Ok(data)
+}
+
+/// Read a bare sequence of at most `MAX_DESERIALIZED_ELEMENTS` elements
+pub(super) fn deserialize_bounded_seq<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    struct BoundedSeq<T>(PhantomData<T>);
+
+    impl<'de, T: Deserialize<'de>> Visitor<'de> for BoundedSeq<T> {
+        type Value = Vec<T>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a sequence")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+            collect_bounded(seq)
+        }
+    }
+
+    deserializer.deserialize_seq(BoundedSeq(PhantomData))
+}
+
+/// Read a bare sequence, or in human-readable formats the legacy `{"data": [...]}` wrapper
+pub(super) fn deserialize_seq_or_legacy<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    struct SeqOrLegacy<T>(PhantomData<T>);
+
+    impl<'de, T: Deserialize<'de>> Visitor<'de> for SeqOrLegacy<T> {
+        type Value = Vec<T>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a sequence or an object with a \"data\" sequence")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+            collect_bounded(seq)
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            let mut data = None;
+            while let Some(key) = map.next_key::<String>()? {
+                if key != "data" {
+                    return Err(de::Error::unknown_field(&key, &["data"]));
+                }
+                if data.is_some() {
+                    return Err(de::Error::duplicate_field("data"));
+                }
+                data = Some(map.next_value()?);
+            }
+            data.ok_or_else(|| de::Error::missing_field("data"))
+        }
+    }
+
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(SeqOrLegacy(PhantomData))
+    } else {
+        deserialize_bounded_seq(deserializer)
+    }
+}
+
 impl<T> TArray<T> {
     /// Create a new empty array
     pub fn new() -> Self {
@@ -101,6 +226,46 @@ impl<T> TArray<T> {
         }
     }
 
+    /// Remove an element at the given index by swapping in the last element (UE5: RemoveAtSwap())
+    ///
+    /// Faster than `remove_at` but does not preserve order. Invalid indices are ignored.
+    pub fn remove_at_swap(&mut self, index: i32) {
+        if self.is_valid_index(index) {
+            self.data.swap_remove(index as usize);
+        }
+    }
+
+    /// Remove all elements matching the predicate, preserving order (UE5: RemoveAll())
+    ///
+    /// Returns the number of removed elements.
+    pub fn remove_all<F>(&mut self, mut pred: F) -> i32
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let before = self.data.len();
+        self.data.retain(|item| !pred(item));
+        (before - self.data.len()) as i32
+    }
+
+    /// Remove all elements matching the predicate without preserving order (UE5: RemoveAllSwap())
+    ///
+    /// Returns the number of removed elements.
+    pub fn remove_all_swap<F>(&mut self, mut pred: F) -> i32
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let before = self.data.len();
+        let mut index = 0;
+        while index < self.data.len() {
+            if pred(&self.data[index]) {
+                self.data.swap_remove(index);
+            } else {
+                index += 1;
+            }
+        }
+        (before - self.data.len()) as i32
+    }
+
     /// Remove the first occurrence of an item (UE5: Remove())
     pub fn remove(&mut self, item: &T) -> bool 
     where 
@@ -124,6 +289,56 @@ impl<T> TArray<T> {
         self.data.clear();
     }
 
+    /// Add an element only if it isn't already present (UE5: AddUnique())
+    ///
+    /// Returns the index of the new element, or of the existing one.
+    pub fn add_unique(&mut self, item: T) -> i32
+    where
+        T: PartialEq,
+    {
+        let existing = self.find(&item);
+        if existing != INDEX_NONE {
+            return existing;
+        }
+        self.data.push(item);
+        (self.data.len() - 1) as i32
+    }
+
+    /// Add `count` default-constructed elements (UE5: AddDefaulted())
+    ///
+    /// Returns the index of the first added element. A negative count adds nothing.
+    pub fn add_defaulted(&mut self, count: i32) -> i32
+    where
+        T: Default,
+    {
+        let first = self.data.len() as i32;
+        if count > 0 {
+            self.data.extend(core::iter::repeat_with(T::default).take(count as usize));
+        }
+        first
+    }
+
+    /// Insert a default-constructed element at the given index (UE5: InsertZeroed())
+    ///
+    /// Invalid indices are ignored, matching `insert`.
+    pub fn insert_zeroed(&mut self, index: i32)
+    where
+        T: Default,
+    {
+        self.insert(index, T::default());
+    }
+
+    /// Replace the contents with `count` copies of `value` (UE5: Init())
+    pub fn init(&mut self, value: T, count: i32)
+    where
+        T: Clone,
+    {
+        self.data.clear();
+        if count > 0 {
+            self.data.resize(count as usize, value);
+        }
+    }
+
     /// Reserve space for at least additional elements (UE5: Reserve())
     pub fn reserve(&mut self, additional: usize) {
         self.data.reserve(additional);
@@ -170,7 +385,40 @@ impl<T> TArray<T> {
     where 
         T: PartialEq 
     {
-        self.data.iter().position(|x| x == item).map_or(-1, |pos| pos as i32)
+        self.data.iter().position(|x| x == item).map_or(INDEX_NONE, |pos| pos as i32)
+    }
+
+    /// Find the first element matching the predicate (UE5: FindByPredicate())
+    pub fn find_by_predicate<F>(&self, mut pred: F) -> Option<&T>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.data.iter().find(|item| pred(item))
+    }
+
+    /// Find the index of the first element matching the predicate (UE5: IndexOfByPredicate())
+    pub fn index_of_by_predicate<F>(&self, pred: F) -> i32
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.data.iter().position(pred).map_or(INDEX_NONE, |pos| pos as i32)
+    }
+
+    /// Check if any element matches the predicate (UE5: ContainsByPredicate())
+    pub fn contains_by_predicate<F>(&self, pred: F) -> bool
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.data.iter().any(pred)
+    }
+
+    /// Swap two elements (UE5: Swap())
+    ///
+    /// Does nothing if either index is invalid.
+    pub fn swap(&mut self, a: i32, b: i32) {
+        if self.is_valid_index(a) && self.is_valid_index(b) {
+            self.data.swap(a as usize, b as usize);
+        }
     }
 
     /// Check if a valid index (UE5: IsValidIndex())
@@ -178,6 +426,16 @@ impl<T> TArray<T> {
         index >= 0 && (index as usize) < self.data.len()
     }
 
+    /// Get the first element
+    pub fn first(&self) -> Option<&T> {
+        self.data.first()
+    }
+
+    /// Get a mutable reference to the first element
+    pub fn first_mut(&mut self) -> Option<&mut T> {
+        self.data.first_mut()
+    }
+
     /// Get the last element (UE5: Last())
     pub fn last(&self) -> Option<&T> {
         self.data.last()
@@ -210,13 +468,193 @@ impl<T> TArray<T> {
         self.data.sort_by_key(f);
     }
 
+    /// Sort with a comparator (UE5: Sort(Predicate))
+    ///
+    /// Not stable, as in UE: equal elements may be reordered. Use
+    /// `stable_sort_by` to keep them in their original order.
+    pub fn sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.data.sort_unstable_by(compare);
+    }
+
+    /// Sort with a comparator, keeping equal elements in order (UE5: StableSort(Predicate))
+    pub fn stable_sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.data.sort_by(compare);
+    }
+
+    /// Stable sort by a floating point key, ascending
+    ///
+    /// NaN keys sort after every number instead of panicking, and keep their
+    /// original relative order.
+    pub fn sort_by_key_f64<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> f64,
+    {
+        self.data.sort_by(|a, b| {
+            let (a, b) = (f(a), f(b));
+            match (a.is_nan(), b.is_nan()) {
+                (false, false) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+                (a_nan, b_nan) => a_nan.cmp(&b_nan),
+            }
+        });
+    }
+
+    /// Check whether the array is sorted under a comparator
+    ///
+    /// True when no element compares greater than the one after it, so an
+    /// array sorted with `sort_by(compare)` always passes.
+    pub fn is_sorted_by<F>(&self, mut compare: F) -> bool
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.data.windows(2).all(|pair| compare(&pair[0], &pair[1]) != Ordering::Greater)
+    }
+
+    /// Binary search a sorted array (UE5: BinarySearch(Predicate))
+    ///
+    /// `compare` orders an element against the target, as for
+    /// `slice::binary_search_by`. Returns the index of a matching element,
+    /// or `INDEX_NONE` like `find`. With several matches any of them may be
+    /// returned.
+    pub fn binary_search_by<F>(&self, compare: F) -> i32
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        self.data.binary_search_by(compare).map_or(INDEX_NONE, |index| index as i32)
+    }
+
+    /// Remove consecutive duplicate elements, returning how many were removed
+    pub fn dedup(&mut self) -> i32
+    where
+        T: PartialEq,
+    {
+        let before = self.data.len();
+        self.data.dedup();
+        (before - self.data.len()) as i32
+    }
+
+    /// Remove consecutive elements with equal keys, returning how many were removed
+    pub fn dedup_by_key<K, F>(&mut self, key: F) -> i32
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        let before = self.data.len();
+        self.data.dedup_by_key(key);
+        (before - self.data.len()) as i32
+    }
+
+    /// Reverse the order of the elements
+    pub fn reverse(&mut self) {
+        self.data.reverse();
+    }
+
+    /// Rotate the elements `count` places toward the front
+    ///
+    /// The first `count` elements move to the end. Counts wrap around the
+    /// length, so any count is valid, including on an empty array.
+    pub fn rotate_left(&mut self, count: usize) {
+        if !self.data.is_empty() {
+            let count = count % self.data.len();
+            self.data.rotate_left(count);
+        }
+    }
+
+    /// Rotate the elements `count` places toward the back
+    ///
+    /// The last `count` elements move to the front; counts wrap like `rotate_left`.
+    pub fn rotate_right(&mut self, count: usize) {
+        if !self.data.is_empty() {
+            let count = count % self.data.len();
+            self.data.rotate_right(count);
+        }
+    }
+
+    /// Rearrange the elements into a binary min-heap (UE5: Heapify())
+    ///
+    /// Like UE, and unlike `std::collections::BinaryHeap`, heaps here are
+    /// min-heaps: `heap_top` and `heap_pop` yield the smallest element.
+    /// Reverse the ordering (or wrap elements in `core::cmp::Reverse`) for a
+    /// max-heap.
+    pub fn heapify(&mut self)
+    where
+        T: Ord,
+    {
+        self.heapify_by(T::cmp);
+    }
+
+    /// Rearrange the elements into a min-heap under `compare` (UE5: Heapify(Predicate))
+    ///
+    /// The element that compares least sits at the top. The other `_by`
+    /// heap operations must be given the same comparator.
+    pub fn heapify_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        for index in (0..self.data.len() / 2).rev() {
+            sift_down(&mut self.data, index, &mut compare);
+        }
+    }
+
+    /// Add an element to a min-heap (UE5: HeapPush())
+    pub fn heap_push(&mut self, item: T)
+    where
+        T: Ord,
+    {
+        self.heap_push_by(item, T::cmp);
+    }
+
+    /// Add an element to a heap built with `compare` (UE5: HeapPush(Predicate))
+    pub fn heap_push_by<F>(&mut self, item: T, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let index = self.data.len();
+        self.data.push(item);
+        sift_up(&mut self.data, index, &mut compare);
+    }
+
+    /// Remove and return the smallest element of a min-heap (UE5: HeapPop())
+    pub fn heap_pop(&mut self) -> Option<T>
+    where
+        T: Ord,
+    {
+        self.heap_pop_by(T::cmp)
+    }
+
+    /// Remove and return the top of a heap built with `compare` (UE5: HeapPop(Predicate))
+    pub fn heap_pop_by<F>(&mut self, mut compare: F) -> Option<T>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        if self.data.is_empty() {
+            return None;
+        }
+        let top = self.data.swap_remove(0);
+        sift_down(&mut self.data, 0, &mut compare);
+        Some(top)
+    }
+
+    /// Get the top of the heap without removing it (UE5: HeapTop())
+    ///
+    /// This is the first element, which is the smallest only if the array
+    /// is currently a heap.
+    pub fn heap_top(&self) -> Option<&T> {
+        self.data.first()
+    }
+
     /// Get an iterator over the elements
-    pub fn iter(&self) -> std::slice::Iter<T> {
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
         self.data.iter()
     }
 
     /// Get a mutable iterator over the elements
-    pub fn iter_mut(&mut self) -> std::slice::IterMut<T> {
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, T> {
         self.data.iter_mut()
     }
 
@@ -229,6 +667,156 @@ impl<T> TArray<T> {
     pub fn as_mut_slice(&mut self) -> &mut [T] {
         &mut self.data
     }
+
+    /// Build a new array by applying `f` to every element
+    pub fn map<U, F>(&self, f: F) -> TArray<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        let mut data = Vec::with_capacity(self.data.len());
+        data.extend(self.data.iter().map(f));
+        TArray { data }
+    }
+
+    /// Copy the elements matching `pred` into a new array (UE5: FilterByPredicate())
+    pub fn filter<F>(&self, mut pred: F) -> TArray<T>
+    where
+        T: Clone,
+        F: FnMut(&T) -> bool,
+    {
+        self.data.iter().filter(|item| pred(item)).cloned().collect()
+    }
+
+    /// Build a new array from the `Some` results of `f`
+    pub fn filter_map<U, F>(&self, f: F) -> TArray<U>
+    where
+        F: FnMut(&T) -> Option<U>,
+    {
+        self.data.iter().filter_map(f).collect()
+    }
+
+    /// Split copies of the elements into those matching `pred` and the rest, keeping order
+    pub fn partition<F>(&self, mut pred: F) -> (TArray<T>, TArray<T>)
+    where
+        T: Clone,
+        F: FnMut(&T) -> bool,
+    {
+        let (matching, rest): (Vec<T>, Vec<T>) = self.data.iter().cloned().partition(|item| pred(item));
+        (TArray::from_vec(matching), TArray::from_vec(rest))
+    }
+
+    /// Get up to `count` elements starting at `start`, clamped to the valid range
+    pub fn slice(&self, start: i32, count: i32) -> &[T] {
+        let len = self.data.len();
+        let start = (start.max(0) as usize).min(len);
+        let end = start + (count.max(0) as usize).min(len - start);
+        &self.data[start..end]
+    }
+
+    /// Get a parallel iterator over the elements
+    #[cfg(feature = "rayon")]
+    pub fn par_iter(&self) -> rayon::slice::Iter<'_, T>
+    where
+        T: Sync,
+    {
+        rayon::prelude::IntoParallelRefIterator::par_iter(&self.data)
+    }
+
+    /// Get a parallel mutable iterator over the elements
+    #[cfg(feature = "rayon")]
+    pub fn par_iter_mut(&mut self) -> rayon::slice::IterMut<'_, T>
+    where
+        T: Send,
+    {
+        rayon::prelude::IntoParallelRefMutIterator::par_iter_mut(&mut self.data)
+    }
+
+    fn checked_index(&self, index: i32) -> usize {
+        if !self.is_valid_index(index) {
+            panic!(
+                "Array index out of bounds: {} from an array of size {}",
+                index,
+                self.data.len()
+            );
+        }
+        index as usize
+    }
+}
+
+/// Move the element at `index` up until its parent is not greater
+fn sift_up<T, F: FnMut(&T, &T) -> Ordering>(heap: &mut [T], mut index: usize, compare: &mut F) {
+    while index > 0 {
+        let parent = (index - 1) / 2;
+        if compare(&heap[index], &heap[parent]) != Ordering::Less {
+            break;
+        }
+        heap.swap(index, parent);
+        index = parent;
+    }
+}
+
+/// Move the element at `index` down until neither child is smaller
+fn sift_down<T, F: FnMut(&T, &T) -> Ordering>(heap: &mut [T], mut index: usize, compare: &mut F) {
+    loop {
+        let left = 2 * index + 1;
+        if left >= heap.len() {
+            break;
+        }
+        let right = left + 1;
+        let child = if right < heap.len() && compare(&heap[right], &heap[left]) == Ordering::Less {
+            right
+        } else {
+            left
+        };
+        if compare(&heap[child], &heap[index]) != Ordering::Less {
+            break;
+        }
+        heap.swap(index, child);
+        index = child;
+    }
+}
+
+impl<T> core::ops::Index<i32> for TArray<T> {
+    type Output = T;
+
+    fn index(&self, index: i32) -> &Self::Output {
+        &self.data[self.checked_index(index)]
+    }
+}
+
+impl<T> core::ops::IndexMut<i32> for TArray<T> {
+    fn index_mut(&mut self, index: i32) -> &mut Self::Output {
+        let index = self.checked_index(index);
+        &mut self.data[index]
+    }
+}
+
+impl<T> core::ops::Index<usize> for TArray<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.data[index]
+    }
+}
+
+impl<T> core::ops::IndexMut<usize> for TArray<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.data[index]
+    }
+}
+
+impl<T> core::ops::Deref for TArray<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl<T> core::ops::DerefMut for TArray<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.data
+    }
 }
 
 impl<T> Default for TArray<T> {
@@ -245,7 +833,7 @@ impl<T> FromIterator<T> for TArray<T> {
 
 impl<T> IntoIterator for TArray<T> {
     type Item = T;
-    type IntoIter = std::vec::IntoIter<T>;
+    type IntoIter = alloc::vec::IntoIter<T>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.data.into_iter()
@@ -254,7 +842,7 @@ impl<T> IntoIterator for TArray<T> {
 
 impl<'a, T> IntoIterator for &'a TArray<T> {
     type Item = &'a T;
-    type IntoIter = std::slice::Iter<'a, T>;
+    type IntoIter = core::slice::Iter<'a, T>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
@@ -303,6 +891,134 @@ mod tests {
         assert_eq!(arr.find(&"missing"), -1);
     }
 
+    #[test]
+    fn test_tarray_add_unique() {
+        let mut arr = TArray::from_vec(vec![10, 20]);
+        assert_eq!(arr.add_unique(30), 2);
+        assert_eq!(arr.add_unique(20), 1);
+        assert_eq!(arr.as_slice(), &[10, 20, 30]);
+    }
+
+    #[test]
+    fn test_tarray_remove_all() {
+        let mut arr = TArray::from_vec(vec![1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(arr.remove_all(|x| x % 2 == 0), 3);
+        // Order is preserved
+        assert_eq!(arr.as_slice(), &[1, 3, 5, 7]);
+        assert_eq!(arr.remove_all(|x| *x > 100), 0);
+
+        let mut arr = TArray::from_vec(vec![1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(arr.remove_all_swap(|x| x % 2 == 0), 3);
+        let mut remaining = arr.into_vec();
+        remaining.sort();
+        assert_eq!(remaining, vec![1, 3, 5, 7]);
+    }
+
+    #[test]
+    fn test_tarray_remove_at_swap() {
+        let mut arr = TArray::from_vec(vec!['a', 'b', 'c', 'd']);
+        arr.remove_at_swap(1);
+        // The last element fills the hole
+        assert_eq!(arr.as_slice(), &['a', 'd', 'c']);
+
+        arr.remove_at_swap(2);
+        assert_eq!(arr.as_slice(), &['a', 'd']);
+
+        // Invalid indices are ignored like remove_at
+        arr.remove_at_swap(-1);
+        arr.remove_at_swap(2);
+        assert_eq!(arr.as_slice(), &['a', 'd']);
+    }
+
+    #[test]
+    fn test_tarray_predicates() {
+        let arr = TArray::from_vec(vec!["apple", "banana", "cherry"]);
+        assert_eq!(arr.find_by_predicate(|s| s.starts_with('b')), Some(&"banana"));
+        assert_eq!(arr.find_by_predicate(|s| s.is_empty()), None);
+        assert_eq!(arr.index_of_by_predicate(|s| s.len() == 6), 1);
+        assert_eq!(arr.index_of_by_predicate(|s| s.len() == 2), INDEX_NONE);
+        assert!(arr.contains_by_predicate(|s| s.ends_with("rry")));
+        assert!(!arr.contains_by_predicate(|s| s.contains('z')));
+    }
+
+    #[test]
+    fn test_tarray_swap() {
+        let mut arr = TArray::from_vec(vec![1, 2, 3]);
+        arr.swap(0, 2);
+        assert_eq!(arr.as_slice(), &[3, 2, 1]);
+
+        arr.swap(1, 1);
+        arr.swap(-1, 0);
+        arr.swap(0, 3);
+        assert_eq!(arr.as_slice(), &[3, 2, 1]);
+    }
+
+    #[test]
+    fn test_tarray_defaulted_and_init() {
+        let mut arr: TArray<i32> = TArray::from_vec(vec![7]);
+        assert_eq!(arr.add_defaulted(2), 1);
+        assert_eq!(arr.as_slice(), &[7, 0, 0]);
+        assert_eq!(arr.add_defaulted(-3), 3);
+        assert_eq!(arr.num(), 3);
+
+        arr.insert_zeroed(0);
+        assert_eq!(arr.as_slice(), &[0, 7, 0, 0]);
+        arr.insert_zeroed(4);
+        assert_eq!(arr.num(), 5);
+        arr.insert_zeroed(-1);
+        arr.insert_zeroed(10);
+        assert_eq!(arr.num(), 5);
+
+        arr.init(9, 3);
+        assert_eq!(arr.as_slice(), &[9, 9, 9]);
+        arr.init(1, -1);
+        assert!(arr.is_empty());
+    }
+
+    #[test]
+    fn test_tarray_indexing() {
+        let mut arr = TArray::from_vec(vec![10, 20, 30]);
+        assert_eq!(&arr[2], arr.get(2).unwrap());
+        assert_eq!(arr[0_usize], 10);
+
+        arr[1] += 5;
+        arr[2_usize] = 99;
+        assert_eq!(arr.as_slice(), &[10, 25, 99]);
+
+        // Slice methods through Deref
+        assert_eq!(arr.iter().max(), Some(&99));
+        assert!(arr.starts_with(&[10, 25]));
+        arr.reverse();
+        assert_eq!(arr.first(), Some(&99));
+        *arr.first_mut().unwrap() = 1;
+        assert_eq!(arr.as_slice(), &[1, 25, 10]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Array index out of bounds: 3 from an array of size 3")]
+    fn test_tarray_index_out_of_range_panics() {
+        let arr = TArray::from_vec(vec![1, 2, 3]);
+        let _ = arr[3];
+    }
+
+    #[test]
+    #[should_panic(expected = "Array index out of bounds: -1 from an array of size 2")]
+    fn test_tarray_negative_index_panics() {
+        let mut arr = TArray::from_vec(vec![1, 2]);
+        arr[-1] = 0;
+    }
+
+    #[test]
+    fn test_tarray_slice() {
+        let arr = TArray::from_vec(vec![1, 2, 3, 4, 5]);
+        assert_eq!(arr.slice(1, 3), &[2, 3, 4]);
+        assert_eq!(arr.slice(3, 10), &[4, 5]);
+        assert_eq!(arr.slice(-2, 2), &[1, 2]);
+        assert_eq!(arr.slice(7, 2), &[] as &[i32]);
+        assert_eq!(arr.slice(2, -1), &[] as &[i32]);
+        assert_eq!(TArray::<i32>::new().first(), None);
+    }
+
     #[test]
     fn test_tarray_display() {
         let arr = TArray::from_vec(vec![1, 2, 3]);
@@ -323,6 +1039,33 @@ mod tests {
         assert_eq!(arr, deserialized);
     }
 
+    #[test]
+    fn test_ue_json_and_legacy_forms() {
+        // As written by FJsonObjectConverter for a TArray<FVector> property
+        let fixture = r#"[
+            [100.0, -50.5, 0.0],
+            [0.0, 0.0, 1200.25]
+        ]"#;
+        let locations: TArray<crate::types::Vector> = serde_json::from_str(fixture).unwrap();
+        assert_eq!(locations.len(), 2);
+        assert_eq!(locations.as_slice()[1].z, 1200.25);
+        assert_eq!(serde_json::to_string(&locations).unwrap(), "[[100.0,-50.5,0.0],[0.0,0.0,1200.25]]");
+
+        let names: TArray<String> = serde_json::from_str(r#"["Alpha", "Bravo"]"#).unwrap();
+        assert_eq!(serde_json::to_value(&names).unwrap(), serde_json::json!(["Alpha", "Bravo"]));
+
+        // Earlier versions of this crate wrapped the elements in an object
+        let legacy: TArray<String> = serde_json::from_str(r#"{"data": ["Alpha", "Bravo"]}"#).unwrap();
+        assert_eq!(legacy, names);
+        assert!(serde_json::from_str::<TArray<i32>>(r#"{"items": [1]}"#).is_err());
+        assert!(serde_json::from_str::<TArray<i32>>("{}").is_err());
+
+        // Bincode keeps its u64 length prefix
+        let binary = TArray::from_vec(vec![7u16, 9]).to_binary().unwrap();
+        assert_eq!(binary, [2, 0, 0, 0, 0, 0, 0, 0, 7, 0, 9, 0]);
+        assert_eq!(TArray::<u16>::from_binary(&binary).unwrap().as_slice(), [7, 9]);
+    }
+
     #[test]
     fn test_tarray_capacity_and_reallocation() {
         let mut arr = TArray::with_capacity(2);
@@ -480,4 +1223,220 @@ mod tests {
         
         assert_eq!(closest_index, Some(1)); // v2 should be closest to center
     }
+
+    #[test]
+    fn test_binary_length_prefix_limits() {
+        use crate::types::{TSet, Vector};
+
+        let arr = TArray::from_vec(vec![Vector::ONE; 100]);
+        let bytes = arr.to_binary().unwrap();
+        assert_eq!(TArray::<Vector>::from_binary(&bytes).unwrap(), arr);
+        assert!(TArray::<Vector>::from_binary_with_limit(&bytes, 256).is_err());
+
+        // A bogus prefix fails cleanly instead of attempting the allocation
+        let mut bogus = u64::MAX.to_le_bytes().to_vec();
+        bogus.extend_from_slice(&[0; 24]);
+        assert!(TArray::<Vector>::from_binary(&bogus).is_err());
+        assert!(TArray::<u8>::from_binary(&bogus).is_err());
+
+        // The element cap holds even without a byte limit
+        let mut over = ((MAX_DESERIALIZED_ELEMENTS + 1) as u64).to_le_bytes().to_vec();
+        over.extend_from_slice(&[0; 24]);
+        let error = bincode::deserialize::<TArray<Vector>>(&over).unwrap_err();
+        assert!(error.to_string().contains("invalid length"), "{}", error);
+        assert!(bincode::deserialize::<TSet<u8>>(&over).is_err());
+    }
+
+    #[test]
+    fn test_heap_pops_in_sorted_order() {
+        let mut stream = crate::RandomStream::new(1828);
+        let values: Vec<i32> = (0..200).map(|_| stream.rand_range(-50, 50)).collect();
+
+        let mut heap = TArray::new();
+        for &value in &values {
+            heap.heap_push(value);
+            assert_eq!(heap.heap_top(), heap.iter().min());
+        }
+        let mut popped = Vec::new();
+        while let Some(value) = heap.heap_pop() {
+            popped.push(value);
+        }
+        let mut sorted = values.clone();
+        sorted.sort();
+        assert_eq!(popped, sorted);
+        assert_eq!(heap.heap_pop(), None);
+        assert_eq!(heap.heap_top(), None);
+
+        // Heapify an existing array, then keep using it as a heap
+        let mut heapified = TArray::from_vec(values.clone());
+        heapified.heapify();
+        assert_eq!(heapified.heap_top(), sorted.first());
+        heapified.heap_push(-100);
+        assert_eq!(heapified.heap_pop(), Some(-100));
+        let drained: Vec<i32> = core::iter::from_fn(|| heapified.heap_pop()).collect();
+        assert_eq!(drained, sorted);
+
+        // Reverse gives a max-heap
+        let mut max_heap: TArray<core::cmp::Reverse<i32>> = values.iter().map(|&v| core::cmp::Reverse(v)).collect();
+        max_heap.heapify();
+        assert_eq!(max_heap.heap_pop(), Some(core::cmp::Reverse(*sorted.last().unwrap())));
+    }
+
+    #[test]
+    fn test_heap_by_distance() {
+        use crate::types::Vector;
+
+        // An open list ordered by distance to a goal, as in A*
+        let goal = Vector::new(10.0, 0.0, 0.0);
+        let closer = |a: &Vector, b: &Vector| a.distance_squared(goal).total_cmp(&b.distance_squared(goal));
+
+        let mut open = TArray::from_vec(vec![
+            Vector::new(0.0, 0.0, 0.0),
+            Vector::new(10.0, 5.0, 0.0),
+            Vector::new(-20.0, 0.0, 0.0),
+        ]);
+        open.heapify_by(closer);
+        open.heap_push_by(Vector::new(9.0, 0.0, 0.0), closer);
+        open.heap_push_by(Vector::new(10.0, 0.0, 25.0), closer);
+        assert_eq!(open.heap_top(), Some(&Vector::new(9.0, 0.0, 0.0)));
+
+        let order: Vec<Vector> = core::iter::from_fn(|| open.heap_pop_by(closer)).collect();
+        assert_eq!(
+            order,
+            vec![
+                Vector::new(9.0, 0.0, 0.0),
+                Vector::new(10.0, 5.0, 0.0),
+                Vector::new(0.0, 0.0, 0.0),
+                Vector::new(10.0, 0.0, 25.0),
+                Vector::new(-20.0, 0.0, 0.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sort_by_float_key_with_nan() {
+        use crate::types::Vector;
+
+        let mut points = TArray::from_vec(vec![
+            Vector::new(3.0, 0.0, 0.0),
+            Vector::new(f64::NAN, 0.0, 0.0),
+            Vector::new(-1.0, 0.0, 0.0),
+            Vector::new(f64::NAN, 1.0, 0.0),
+            Vector::new(2.0, 0.0, 0.0),
+        ]);
+        points.sort_by_key_f64(|point| point.x);
+        let xs: Vec<f64> = points.iter().map(|point| point.x).collect();
+        assert_eq!(&xs[..3], &[-1.0, 2.0, 3.0]);
+        assert!(xs[3].is_nan() && xs[4].is_nan());
+        // NaNs keep their original order
+        assert_eq!((points.as_slice()[3].y, points.as_slice()[4].y), (0.0, 1.0));
+
+        let mut distances = TArray::from_vec(vec![5.0, -f64::NAN, 1.0, f64::INFINITY, f64::NEG_INFINITY]);
+        distances.sort_by_key_f64(|&d| d);
+        assert_eq!(distances.slice(0, 4), &[f64::NEG_INFINITY, 1.0, 5.0, f64::INFINITY]);
+        assert!(distances.last().is_some_and(|d| d.is_nan()));
+    }
+
+    #[test]
+    fn test_sort_by_and_is_sorted() {
+        let mut arr = TArray::from_vec(vec![(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd'), (0, 'e')]);
+        arr.stable_sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(arr.as_slice(), &[(0, 'e'), (1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')]);
+        assert!(arr.is_sorted_by(|a, b| a.0.cmp(&b.0)));
+        assert!(!arr.is_sorted_by(|a, b| b.0.cmp(&a.0)));
+
+        arr.sort_by(|a, b| b.0.cmp(&a.0));
+        assert_eq!(arr.iter().map(|pair| pair.0).collect::<Vec<_>>(), vec![2, 2, 1, 1, 0]);
+
+        // Already sorted inputs stay put, and trivial arrays are sorted
+        let mut sorted = TArray::from_vec((0..10).collect::<Vec<i32>>());
+        sorted.sort_by(i32::cmp);
+        sorted.stable_sort_by(i32::cmp);
+        sorted.sort_by_key_f64(|&v| v as f64);
+        assert_eq!(sorted.as_slice(), &(0..10).collect::<Vec<i32>>()[..]);
+        assert!(sorted.is_sorted_by(i32::cmp));
+        assert!(TArray::<i32>::new().is_sorted_by(i32::cmp));
+        assert!(TArray::from_vec(vec![7]).is_sorted_by(i32::cmp));
+    }
+
+    #[test]
+    fn test_binary_search_by() {
+        let empty: TArray<i32> = TArray::new();
+        assert_eq!(empty.binary_search_by(|x| x.cmp(&3)), INDEX_NONE);
+
+        let arr = TArray::from_vec(vec![1, 3, 5, 7, 9]);
+        assert_eq!(arr.binary_search_by(|x| x.cmp(&1)), 0);
+        assert_eq!(arr.binary_search_by(|x| x.cmp(&7)), 3);
+        assert_eq!(arr.binary_search_by(|x| x.cmp(&4)), INDEX_NONE);
+        assert_eq!(arr.binary_search_by(|x| x.cmp(&10)), INDEX_NONE);
+
+        let times = TArray::from_vec(vec![0.5_f64, 1.0, 2.5]);
+        assert_eq!(times.binary_search_by(|t| t.total_cmp(&2.5)), 2);
+    }
+
+    #[test]
+    fn test_dedup_reverse_rotate() {
+        let mut arr = TArray::from_vec(vec![1, 1, 2, 2, 2, 3, 1, 1]);
+        assert_eq!(arr.dedup(), 4);
+        assert_eq!(arr.as_slice(), &[1, 2, 3, 1]);
+        assert_eq!(arr.dedup(), 0);
+
+        let mut words = TArray::from_vec(vec!["Alpha", "alpha", "Bravo", "ALPHA"]);
+        assert_eq!(words.dedup_by_key(|word| word.to_lowercase()), 1);
+        assert_eq!(words.as_slice(), &["Alpha", "Bravo", "ALPHA"]);
+
+        arr.reverse();
+        assert_eq!(arr.as_slice(), &[1, 3, 2, 1]);
+        arr.rotate_left(1);
+        assert_eq!(arr.as_slice(), &[3, 2, 1, 1]);
+        arr.rotate_right(6);
+        assert_eq!(arr.as_slice(), &[1, 1, 3, 2]);
+
+        let mut empty: TArray<i32> = TArray::new();
+        empty.rotate_left(3);
+        empty.rotate_right(3);
+        empty.reverse();
+        assert_eq!(empty.dedup(), 0);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_functional_bulk_ops() {
+        let arr = TArray::from_vec(vec![5, -2, 8, 0, -7, 3]);
+
+        let doubled = arr.map(|x| x * 2);
+        assert_eq!(doubled.into_vec(), arr.iter().map(|x| x * 2).collect::<Vec<_>>());
+        let labels: TArray<String> = arr.map(|x| x.to_string());
+        assert_eq!(labels.capacity(), arr.len());
+
+        let positive = arr.filter(|x| *x > 0);
+        assert_eq!(positive.into_vec(), arr.iter().copied().filter(|x| *x > 0).collect::<Vec<_>>());
+
+        let halves = arr.filter_map(|x| (x % 2 == 0).then_some(x / 2));
+        assert_eq!(halves.into_vec(), vec![-1, 4, 0]);
+
+        let (even, odd) = arr.partition(|x| x % 2 == 0);
+        let (expected_even, expected_odd): (Vec<i32>, Vec<i32>) = arr.iter().partition(|x| *x % 2 == 0);
+        assert_eq!((even.into_vec(), odd.into_vec()), (expected_even, expected_odd));
+
+        // The source is untouched
+        assert_eq!(arr.len(), 6);
+        assert!(TArray::<i32>::new().map(|x| x + 1).is_empty());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_iter() {
+        use rayon::prelude::*;
+
+        let mut arr: TArray<u64> = (0..10_000).collect();
+        let mut parallel: Vec<u64> = arr.par_iter().map(|x| x * x).collect();
+        let mut sequential: Vec<u64> = arr.iter().map(|x| x * x).collect();
+        parallel.sort_unstable();
+        sequential.sort_unstable();
+        assert_eq!(parallel, sequential);
+
+        arr.par_iter_mut().for_each(|x| *x += 1);
+        assert_eq!(arr.par_iter().sum::<u64>(), (1..=10_000).sum::<u64>());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,469 @@
+//! UE5-style fixed-capacity inline array (TInlineArray)
+
+use super::tarray::{check_element_count, TArray};
+use crate::BinarySerializable;
+use arrayvec::ArrayVec;
+use serde::de::{self, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use core::fmt;
+use core::marker::PhantomData;
+
+/// Fixed-capacity array stored inline (in the spirit of TStaticArray and
+/// TInlineAllocator)
+///
+/// Holds up to `N` elements without touching the heap, for short lists on
+/// hot paths such as the handful of actors overlapping a volume. It never
+/// falls back to the heap: adding past capacity fails with an
+/// [`InlineArrayFullError`] that hands the element back, so the cost of a
+/// push is always known. Use `TArray` when the bound is not hard.
+///
+/// Collecting from an iterator panics if it yields more than `N` elements;
+/// use `try_from_iter` when the length is not known to fit. Converting from
+/// a `TArray` and deserializing fail instead of truncating.
+///
+/// Serializes as a bare sequence, the same as `TArray`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct TInlineArray<T, const N: usize> {
+    data: ArrayVec<T, N>,
+}
+
+/// Error returned when a `TInlineArray` has no room left
+///
+/// Carries back whatever could not be stored: the rejected element for
+/// `add`, or the whole source array for conversions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InlineArrayFullError<T> {
+    value: T,
+    capacity: usize,
+}
+
+impl<T> InlineArrayFullError<T> {
+    /// Capacity of the array that was full
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Take back the value that did not fit
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> fmt::Display for InlineArrayFullError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "TInlineArray capacity of {} exceeded", self.capacity)
+    }
+}
+
+impl<T: fmt::Debug> core::error::Error for InlineArrayFullError<T> {}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for TInlineArray<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.data.iter()).finish()
+    }
+}
+
+impl<T: fmt::Display, const N: usize> fmt::Display for TInlineArray<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "TInlineArray[")?;
+        for (i, item) in self.data.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", item)?;
+        }
+        write!(f, "]")
+    }
+}
+
+impl<T: Serialize + serde::de::DeserializeOwned, const N: usize> BinarySerializable for TInlineArray<T, N> {}
+
+impl<T: Serialize, const N: usize> Serialize for TInlineArray<T, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_slice().serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>, const N: usize> Deserialize<'de> for TInlineArray<T, N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct InlineSeq<T, const N: usize>(PhantomData<T>);
+
+        impl<'de, T: Deserialize<'de>, const N: usize> Visitor<'de> for InlineSeq<T, N> {
+            type Value = TInlineArray<T, N>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a sequence of at most {} elements", N)
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                if let Some(hint) = seq.size_hint() {
+                    check_element_count(hint)?;
+                    if hint > N {
+                        return Err(de::Error::invalid_length(hint, &self));
+                    }
+                }
+                let mut array = TInlineArray::new();
+                while let Some(item) = seq.next_element()? {
+                    if array.add(item).is_err() {
+                        return Err(de::Error::invalid_length(N + 1, &self));
+                    }
+                }
+                Ok(array)
+            }
+        }
+
+        deserializer.deserialize_seq(InlineSeq(PhantomData))
+    }
+}
+
+impl<T, const N: usize> TInlineArray<T, N> {
+    /// Most elements the array can hold
+    pub const CAPACITY: usize = N;
+
+    /// Create a new empty array
+    pub fn new() -> Self {
+        Self { data: ArrayVec::new() }
+    }
+
+    /// Get the number of elements (UE5: Num())
+    pub fn num(&self) -> i32 {
+        self.data.len() as i32
+    }
+
+    /// Get the number of elements
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Check if the array is empty
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Check if the array is at capacity
+    pub fn is_full(&self) -> bool {
+        self.data.is_full()
+    }
+
+    /// Get the fixed capacity, `N`
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Get how many more elements fit
+    pub fn remaining_capacity(&self) -> usize {
+        self.data.remaining_capacity()
+    }
+
+    /// Collect an iterator, failing if it yields more than `N` elements
+    ///
+    /// The error hands back the first element that did not fit; anything the
+    /// iterator would have yielded after it is never pulled.
+    pub fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, InlineArrayFullError<T>> {
+        let mut array = Self::new();
+        for item in iter {
+            array.add(item)?;
+        }
+        Ok(array)
+    }
+
+    /// Add an element to the end, returning its index (UE5: Add())
+    ///
+    /// Fails with the element handed back when the array is full.
+    pub fn add(&mut self, item: T) -> Result<i32, InlineArrayFullError<T>> {
+        match self.data.try_push(item) {
+            Ok(()) => Ok(self.num() - 1),
+            Err(error) => Err(InlineArrayFullError { value: error.element(), capacity: N }),
+        }
+    }
+
+    /// Remove and return the last element (UE5: Pop())
+    pub fn pop(&mut self) -> Option<T> {
+        self.data.pop()
+    }
+
+    /// Remove an element at the given index, preserving order (UE5: RemoveAt())
+    pub fn remove_at(&mut self, index: i32) -> Option<T> {
+        if self.is_valid_index(index) {
+            Some(self.data.remove(index as usize))
+        } else {
+            None
+        }
+    }
+
+    /// Remove an element at the given index by swapping in the last element (UE5: RemoveAtSwap())
+    ///
+    /// Faster than `remove_at` but does not preserve order. Invalid indices are ignored.
+    pub fn remove_at_swap(&mut self, index: i32) {
+        if self.is_valid_index(index) {
+            self.data.swap_remove(index as usize);
+        }
+    }
+
+    /// Clear all elements (UE5: Empty())
+    pub fn empty(&mut self) {
+        self.data.clear();
+    }
+
+    /// Clear all elements
+    pub fn clear(&mut self) {
+        self.data.clear();
+    }
+
+    /// Get element at index (returns None if out of bounds)
+    pub fn get(&self, index: i32) -> Option<&T> {
+        if self.is_valid_index(index) {
+            self.data.get(index as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Get mutable element at index
+    pub fn get_mut(&mut self, index: i32) -> Option<&mut T> {
+        if self.is_valid_index(index) {
+            self.data.get_mut(index as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Check if index is valid (UE5: IsValidIndex())
+    pub fn is_valid_index(&self, index: i32) -> bool {
+        index >= 0 && (index as usize) < self.data.len()
+    }
+
+    /// Check if the array contains an element
+    pub fn contains(&self, item: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.data.contains(item)
+    }
+
+    /// Get the first element
+    pub fn first(&self) -> Option<&T> {
+        self.data.first()
+    }
+
+    /// Get the last element
+    pub fn last(&self) -> Option<&T> {
+        self.data.last()
+    }
+
+    /// Get an iterator over elements
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    /// Get a mutable iterator over elements
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, T> {
+        self.data.iter_mut()
+    }
+
+    /// Get the elements as a slice
+    pub fn as_slice(&self) -> &[T] {
+        self.data.as_slice()
+    }
+
+    /// Get the elements as a mutable slice
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.data.as_mut_slice()
+    }
+
+    /// Copy the elements into a heap-allocated `TArray`
+    pub fn to_tarray(&self) -> TArray<T>
+    where
+        T: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T, const N: usize> core::ops::Deref for TInlineArray<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl<T, const N: usize> core::ops::DerefMut for TInlineArray<T, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.data
+    }
+}
+
+impl<T, const N: usize> Default for TInlineArray<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// # Panics
+///
+/// Panics if the iterator yields more than `N` elements. Use
+/// `TInlineArray::try_from_iter` to get an error instead.
+impl<T, const N: usize> FromIterator<T> for TInlineArray<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        match Self::try_from_iter(iter) {
+            Ok(array) => array,
+            Err(_) => panic!("TInlineArray::from_iter: iterator yielded more than {} elements", N),
+        }
+    }
+}
+
+impl<T, const N: usize> IntoIterator for TInlineArray<T, N> {
+    type Item = T;
+    type IntoIter = arrayvec::IntoIter<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a TInlineArray<T, N> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T, const N: usize> From<TInlineArray<T, N>> for TArray<T> {
+    fn from(array: TInlineArray<T, N>) -> Self {
+        array.into_iter().collect()
+    }
+}
+
+/// Fails, returning the untouched array, if it has more than `N` elements
+impl<T, const N: usize> TryFrom<TArray<T>> for TInlineArray<T, N> {
+    type Error = InlineArrayFullError<TArray<T>>;
+
+    fn try_from(array: TArray<T>) -> Result<Self, Self::Error> {
+        if array.len() > N {
+            return Err(InlineArrayFullError { value: array, capacity: N });
+        }
+        Ok(array.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_capacity_boundary() {
+        let mut array: TInlineArray<i32, 3> = TInlineArray::new();
+        assert_eq!((array.capacity(), TInlineArray::<i32, 3>::CAPACITY), (3, 3));
+        assert_eq!(array.add(10), Ok(0));
+        assert_eq!(array.add(20), Ok(1));
+        assert_eq!(array.add(30), Ok(2));
+        assert!(array.is_full());
+        assert_eq!(array.remaining_capacity(), 0);
+
+        let error = array.add(40).unwrap_err();
+        assert_eq!((error.capacity(), error.into_inner()), (3, 40));
+        assert_eq!(error.to_string(), "TInlineArray capacity of 3 exceeded");
+        assert_eq!(array.as_slice(), &[10, 20, 30]);
+
+        array.remove_at_swap(0);
+        assert_eq!(array.as_slice(), &[30, 20]);
+        array.remove_at_swap(5);
+        assert_eq!(array.num(), 2);
+        assert_eq!(array.add(40), Ok(2));
+        assert_eq!(array.get(2), Some(&40));
+        assert_eq!(array.get(3), None);
+        assert_eq!(array.get(-1), None);
+
+        array.clear();
+        assert!(array.is_empty());
+        let mut empty: TInlineArray<i32, 0> = TInlineArray::new();
+        assert!(empty.is_full());
+        assert!(empty.add(1).is_err());
+    }
+
+    #[test]
+    fn test_drops_non_copy_elements() {
+        let tracker = Rc::new(());
+        {
+            let mut array: TInlineArray<Rc<()>, 4> = TInlineArray::new();
+            for _ in 0..4 {
+                array.add(Rc::clone(&tracker)).unwrap();
+            }
+            assert_eq!(Rc::strong_count(&tracker), 5);
+
+            // The rejected element comes back to the caller, not leaked or kept
+            let rejected = array.add(Rc::clone(&tracker)).unwrap_err().into_inner();
+            drop(rejected);
+            assert_eq!(Rc::strong_count(&tracker), 5);
+
+            array.remove_at_swap(1);
+            assert_eq!(Rc::strong_count(&tracker), 4);
+            drop(array.pop());
+            assert_eq!(Rc::strong_count(&tracker), 3);
+
+            // A failed collect drops what it stored and hands back the overflow
+            let error = TInlineArray::<Rc<()>, 2>::try_from_iter((0..5).map(|_| Rc::clone(&tracker))).unwrap_err();
+            assert_eq!(Rc::strong_count(&tracker), 4);
+            drop(error);
+            assert_eq!(Rc::strong_count(&tracker), 3);
+        }
+        assert_eq!(Rc::strong_count(&tracker), 1);
+
+        let mut names: TInlineArray<String, 2> = TInlineArray::new();
+        names.add("Alpha".to_string()).unwrap();
+        names.add("Bravo".to_string()).unwrap();
+        names.clear();
+        names.add("Charlie".to_string()).unwrap();
+        assert_eq!(names.to_string(), "TInlineArray[Charlie]");
+    }
+
+    #[test]
+    fn test_tarray_conversion() {
+        let inline: TInlineArray<i32, 4> = [1, 2, 3].into_iter().collect();
+        assert_eq!(inline.to_tarray(), TArray::from_vec(vec![1, 2, 3]));
+        assert_eq!(TArray::from(inline.clone()), TArray::from_vec(vec![1, 2, 3]));
+
+        let back = TInlineArray::<i32, 4>::try_from(TArray::from_vec(vec![1, 2, 3])).unwrap();
+        assert_eq!(back, inline);
+        let full = TInlineArray::<i32, 3>::try_from(TArray::from_vec(vec![1, 2, 3])).unwrap();
+        assert!(full.is_full());
+
+        let error = TInlineArray::<i32, 2>::try_from(TArray::from_vec(vec![1, 2, 3])).unwrap_err();
+        assert_eq!(error.into_inner(), TArray::from_vec(vec![1, 2, 3]));
+
+        let exact = TInlineArray::<i32, 2>::try_from_iter(1..=2).unwrap();
+        assert_eq!(exact.as_slice(), &[1, 2]);
+        assert_eq!(exact.iter().sum::<i32>(), 3);
+        assert_eq!(exact.into_iter().collect::<Vec<_>>(), vec![1, 2]);
+
+        let error = TInlineArray::<i32, 2>::try_from_iter(1..=5).unwrap_err();
+        assert_eq!(error.capacity(), 2);
+        assert_eq!(error.into_inner(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "more than 2 elements")]
+    fn test_collect_past_capacity_panics() {
+        let _: TInlineArray<i32, 2> = (1..=5).collect();
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        let array: TInlineArray<String, 4> = ["Alpha", "Bravo"].iter().map(|s| s.to_string()).collect();
+
+        let json = serde_json::to_string(&array).unwrap();
+        assert_eq!(json, r#"["Alpha","Bravo"]"#);
+        assert_eq!(serde_json::from_str::<TInlineArray<String, 4>>(&json).unwrap(), array);
+        assert!(serde_json::from_str::<TInlineArray<String, 1>>(&json).is_err());
+
+        let binary = array.to_binary().unwrap();
+        assert_eq!(binary, array.to_tarray().to_binary().unwrap());
+        assert_eq!(TInlineArray::<String, 4>::from_binary(&binary).unwrap(), array);
+        assert!(TInlineArray::<String, 1>::from_binary(&binary).is_err());
+        assert!(TInlineArray::<u8, 8>::from_binary(&u64::MAX.to_le_bytes()).is_err());
+    }
+}
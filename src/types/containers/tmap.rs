@@ -1,16 +1,29 @@
 //! UE5-style map (TMap)
 
 use crate::BinarySerializable;
-use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use std::fmt;
-use std::hash::Hash;
+use super::tarray::check_element_count;
+use super::TArray;
+use serde::de::{self, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use super::hash::HashMap;
+use core::fmt;
+use core::hash::Hash;
+use core::marker::PhantomData;
+use alloc::string::String;
 
 /// UE5-style map (equivalent to TMap)
 /// 
 /// A hash map with UE5-compatible methods and naming conventions.
 /// Internally uses HashMap<K, V> but provides UE5-style API.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// Serializes as a bare map: a JSON object keyed by the map's keys, matching
+/// UE's FJsonObjectConverter, and a length-prefixed list of entries in
+/// bincode. JSON object keys must be strings, so this suits `String` and
+/// `Name` keys, and integer keys (written as `"7"`). Maps with other keys
+/// can opt into a `[[key, value], ...]` array with
+/// `#[serde(with = "ue_types::tmap_as_pairs")]`. Data written by earlier
+/// versions of this crate (`{"data": {...}}`) loads with `deserialize_legacy`.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TMap<K: Eq + Hash, V> {
     data: HashMap<K, V>,
 }
@@ -34,18 +47,129 @@ where
     V: serde::Serialize + serde::de::DeserializeOwned,
 {}
 
+impl<K, V> crate::types::SerializedSize for TMap<K, V>
+where
+    K: crate::types::SerializedSize + Eq + Hash,
+    V: crate::types::SerializedSize,
+{
+    fn serialized_size(&self) -> usize {
+        crate::types::serialization::serialized_size::map_serialized_size(self.data.iter())
+    }
+}
+
+impl<K: Serialize + Eq + Hash, V: Serialize> Serialize for TMap<K, V> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.data.serialize(serializer)
+    }
+}
+
+impl<'de, K: Deserialize<'de> + Eq + Hash, V: Deserialize<'de>> Deserialize<'de> for TMap<K, V> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct BoundedMap<K, V>(PhantomData<(K, V)>);
+
+        impl<'de, K: Deserialize<'de> + Eq + Hash, V: Deserialize<'de>> Visitor<'de> for BoundedMap<K, V> {
+            type Value = TMap<K, V>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a map")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                if let Some(hint) = map.size_hint() {
+                    check_element_count(hint)?;
+                }
+                let capacity = map.size_hint().unwrap_or(0).min(4096);
+                let mut data = HashMap::with_capacity_and_hasher(capacity, Default::default());
+                while let Some((key, value)) = map.next_entry()? {
+                    check_element_count(data.len() + 1)?;
+                    data.insert(key, value);
+                }
+                Ok(TMap::from_hash_map(data))
+            }
+        }
+
+        deserializer.deserialize_map(BoundedMap(PhantomData))
+    }
+}
+
+impl<'de, K: Deserialize<'de> + Eq + Hash, V: Deserialize<'de>> TMap<K, V> {
+    /// Deserialize the `{"data": {...}}` form written by earlier versions of this crate
+    ///
+    /// A bare map can't be told apart from a legacy wrapper in general (a
+    /// string-keyed map may well have a "data" entry), so this is opt-in:
+    /// call it directly or use `#[serde(deserialize_with = "TMap::deserialize_legacy")]`.
+    pub fn deserialize_legacy<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct LegacyVisitor<K, V>(PhantomData<(K, V)>);
+
+        impl<'de, K: Deserialize<'de> + Eq + Hash, V: Deserialize<'de>> Visitor<'de> for LegacyVisitor<K, V> {
+            type Value = TMap<K, V>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("an object with a \"data\" map")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut data = None;
+                while let Some(key) = map.next_key::<String>()? {
+                    if key != "data" {
+                        return Err(de::Error::unknown_field(&key, &["data"]));
+                    }
+                    if data.is_some() {
+                        return Err(de::Error::duplicate_field("data"));
+                    }
+                    data = Some(map.next_value()?);
+                }
+                data.map(TMap::from_hash_map).ok_or_else(|| de::Error::missing_field("data"))
+            }
+        }
+
+        deserializer.deserialize_struct("TMap", &["data"], LegacyVisitor(PhantomData))
+    }
+}
+
+/// `#[serde(with = "...")]` support for serializing a `TMap` as `[[key, value], ...]`
+///
+/// Use this for maps whose keys aren't strings or integers (e.g.
+/// `NetworkGUID`, `IntVector2`), which JSON objects can't hold.
+pub mod tmap_as_pairs {
+    use super::super::tarray::deserialize_bounded_seq;
+    use super::TMap;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use core::hash::Hash;
+
+    /// Serialize the entries as a sequence of `(key, value)` pairs
+    pub fn serialize<K, V, S>(map: &TMap<K, V>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        K: Serialize + Eq + Hash,
+        V: Serialize,
+        S: Serializer,
+    {
+        serializer.collect_seq(map.iter())
+    }
+
+    /// Deserialize a sequence of `(key, value)` pairs; later duplicates win
+    pub fn deserialize<'de, K, V, D>(deserializer: D) -> Result<TMap<K, V>, D::Error>
+    where
+        K: Deserialize<'de> + Eq + Hash,
+        V: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        deserialize_bounded_seq::<_, (K, V)>(deserializer).map(|pairs| pairs.into_iter().collect())
+    }
+}
+
 impl<K, V> TMap<K, V> 
 where 
     K: Eq + Hash,
 {
     /// Create a new empty map
     pub fn new() -> Self {
-        Self { data: HashMap::new() }
+        Self { data: HashMap::default() }
     }
 
     /// Create a map with the given capacity
     pub fn with_capacity(capacity: usize) -> Self {
-        Self { data: HashMap::with_capacity(capacity) }
+        Self { data: HashMap::with_capacity_and_hasher(capacity, Default::default()) }
     }
 
     /// Create from a HashMap
@@ -103,13 +227,72 @@ where
         self.data.get_mut(key)
     }
 
+    /// Get a value by key, panicking if it is missing (UE5: FindChecked())
+    pub fn find_checked(&self, key: &K) -> &V {
+        match self.data.get(key) {
+            Some(value) => value,
+            None => panic!("TMap::find_checked: key not found"),
+        }
+    }
+
+    /// Get a copy of the value for a key, or the default value if missing (UE5: FindRef())
+    pub fn find_ref(&self, key: &K) -> V
+    where
+        V: Clone + Default,
+    {
+        self.data.get(key).cloned().unwrap_or_default()
+    }
+
     /// Get a value by key, returning a default if not found
-    pub fn find_or_add(&mut self, key: K, default_value: V) -> &mut V 
-    where 
+    pub fn find_or_add(&mut self, key: K, default_value: V) -> &mut V {
+        self.data.entry(key).or_insert(default_value)
+    }
+
+    /// Get a value by key, adding a default-constructed value if not found (UE5: FindOrAdd())
+    pub fn find_or_add_default(&mut self, key: K) -> &mut V
+    where
+        V: Default,
+    {
+        self.data.entry(key).or_default()
+    }
+
+    /// Move all pairs from another map into this one, overwriting existing keys (UE5: Append())
+    pub fn append(&mut self, other: TMap<K, V>) {
+        self.data.extend(other.data);
+    }
+
+    /// Keep only the pairs for which the predicate returns true
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        self.data.retain(f);
+    }
+
+    /// Remove all pairs matching the predicate, returning the number removed
+    pub fn remove_by_predicate<F>(&mut self, mut pred: F) -> i32
+    where
+        F: FnMut(&K, &V) -> bool,
+    {
+        let before = self.data.len();
+        self.data.retain(|k, v| !pred(k, v));
+        (before - self.data.len()) as i32
+    }
+
+    /// Collect all keys into an array (UE5: GetKeys())
+    pub fn get_keys(&self) -> TArray<K>
+    where
         K: Clone,
+    {
+        self.data.keys().cloned().collect()
+    }
+
+    /// Collect all values into an array (UE5: GenerateValueArray())
+    pub fn generate_value_array(&self) -> TArray<V>
+    where
         V: Clone,
     {
-        self.data.entry(key).or_insert(default_value)
+        self.data.values().cloned().collect()
     }
 
     /// Clear all key-value pairs (UE5: Empty())
@@ -128,29 +311,60 @@ where
     }
 
     /// Get an iterator over the key-value pairs
-    pub fn iter(&self) -> std::collections::hash_map::Iter<K, V> {
+    pub fn iter(&self) -> super::hash::hash_map::Iter<'_, K, V> {
         self.data.iter()
     }
 
     /// Get a mutable iterator over the key-value pairs
-    pub fn iter_mut(&mut self) -> std::collections::hash_map::IterMut<K, V> {
+    pub fn iter_mut(&mut self) -> super::hash::hash_map::IterMut<'_, K, V> {
         self.data.iter_mut()
     }
 
     /// Get an iterator over the keys
-    pub fn keys(&self) -> std::collections::hash_map::Keys<K, V> {
+    pub fn keys(&self) -> super::hash::hash_map::Keys<'_, K, V> {
         self.data.keys()
     }
 
     /// Get an iterator over the values
-    pub fn values(&self) -> std::collections::hash_map::Values<K, V> {
+    pub fn values(&self) -> super::hash::hash_map::Values<'_, K, V> {
         self.data.values()
     }
 
     /// Get a mutable iterator over the values
-    pub fn values_mut(&mut self) -> std::collections::hash_map::ValuesMut<K, V> {
+    pub fn values_mut(&mut self) -> super::hash::hash_map::ValuesMut<'_, K, V> {
         self.data.values_mut()
     }
+
+    /// Build a new map with the same keys and `f` applied to every entry
+    pub fn map_values<U, F>(&self, mut f: F) -> TMap<K, U>
+    where
+        K: Clone,
+        F: FnMut(&K, &V) -> U,
+    {
+        let mut data = HashMap::with_capacity_and_hasher(self.data.len(), Default::default());
+        data.extend(self.data.iter().map(|(key, value)| (key.clone(), f(key, value))));
+        TMap { data }
+    }
+
+    /// Get a parallel iterator over the key-value pairs
+    #[cfg(feature = "rayon")]
+    pub fn par_iter(&self) -> rayon::collections::hash_map::Iter<'_, K, V>
+    where
+        K: Sync,
+        V: Sync,
+    {
+        rayon::prelude::IntoParallelRefIterator::par_iter(&self.data)
+    }
+
+    /// Get a parallel iterator over the keys and mutable values
+    #[cfg(feature = "rayon")]
+    pub fn par_iter_mut(&mut self) -> rayon::collections::hash_map::IterMut<'_, K, V>
+    where
+        K: Sync,
+        V: Send,
+    {
+        rayon::prelude::IntoParallelRefMutIterator::par_iter_mut(&mut self.data)
+    }
 }
 
 impl<K, V> Default for TMap<K, V> 
@@ -162,6 +376,71 @@ where
     }
 }
 
+impl<K, V> FromIterator<(K, V)> for TMap<K, V>
+where
+    K: Eq + Hash,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self::from_hash_map(iter.into_iter().collect())
+    }
+}
+
+impl<K, V> Extend<(K, V)> for TMap<K, V>
+where
+    K: Eq + Hash,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        self.data.extend(iter);
+    }
+}
+
+impl<K, V> IntoIterator for TMap<K, V>
+where
+    K: Eq + Hash,
+{
+    type Item = (K, V);
+    type IntoIter = super::hash::hash_map::IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a TMap<K, V>
+where
+    K: Eq + Hash,
+{
+    type Item = (&'a K, &'a V);
+    type IntoIter = super::hash::hash_map::Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a mut TMap<K, V>
+where
+    K: Eq + Hash,
+{
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = super::hash::hash_map::IterMut<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<K, V> core::ops::Index<&K> for TMap<K, V>
+where
+    K: Eq + Hash,
+{
+    type Output = V;
+
+    fn index(&self, key: &K) -> &Self::Output {
+        self.find_checked(key)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -199,6 +478,95 @@ mod tests {
         assert_eq!(map.find(&"update"), Some(&2));
     }
 
+    #[test]
+    fn test_tmap_find_or_add() {
+        let mut map: TMap<&str, Vec<i32>> = TMap::new();
+        map.find_or_add_default("scores").push(10);
+        map.find_or_add_default("scores").push(20);
+        assert_eq!(map.find(&"scores"), Some(&vec![10, 20]));
+
+        // No Clone bound needed for find_or_add
+        struct NoClone(i32);
+        let mut map = TMap::new();
+        map.find_or_add(1, NoClone(5)).0 += 1;
+        assert_eq!(map.find_or_add(1, NoClone(100)).0, 6);
+    }
+
+    #[test]
+    fn test_tmap_find_checked_and_ref() {
+        let map: TMap<&str, i32> = [("a", 1), ("b", 2)].into_iter().collect();
+        assert_eq!(*map.find_checked(&"a"), 1);
+        assert_eq!(map[&"b"], 2);
+        assert_eq!(map.find_ref(&"b"), 2);
+        assert_eq!(map.find_ref(&"missing"), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "key not found")]
+    fn test_tmap_find_checked_panics() {
+        let map: TMap<&str, i32> = TMap::new();
+        map.find_checked(&"missing");
+    }
+
+    #[test]
+    #[should_panic(expected = "key not found")]
+    fn test_tmap_index_panics() {
+        let map: TMap<i32, i32> = [(1, 1)].into_iter().collect();
+        let _ = map[&2];
+    }
+
+    #[test]
+    fn test_tmap_append_overwrites() {
+        let mut map: TMap<&str, i32> = [("a", 1), ("b", 2)].into_iter().collect();
+        let other: TMap<&str, i32> = [("b", 20), ("c", 30)].into_iter().collect();
+        map.append(other);
+
+        assert_eq!(map.num(), 3);
+        assert_eq!(map[&"a"], 1);
+        assert_eq!(map[&"b"], 20);
+        assert_eq!(map[&"c"], 30);
+
+        map.extend([("a", 100)]);
+        assert_eq!(map[&"a"], 100);
+    }
+
+    #[test]
+    fn test_tmap_remove_by_predicate() {
+        let mut map: TMap<i32, i32> = (0..10).map(|i| (i, i * i)).collect();
+        assert_eq!(map.remove_by_predicate(|_, v| *v > 20), 5);
+        assert_eq!(map.num(), 5);
+
+        map.retain(|k, v| {
+            *v += 1;
+            k % 2 == 0
+        });
+        assert_eq!(map.num(), 3);
+        assert_eq!(map[&4], 17);
+    }
+
+    #[test]
+    fn test_tmap_key_and_value_arrays() {
+        let map: TMap<i32, &str> = [(1, "one"), (2, "two"), (3, "three")].into_iter().collect();
+
+        let mut keys = map.get_keys().into_vec();
+        keys.sort();
+        assert_eq!(keys, vec![1, 2, 3]);
+
+        let mut values = map.generate_value_array().into_vec();
+        values.sort();
+        assert_eq!(values, vec!["one", "three", "two"]);
+
+        let mut total = 0;
+        for (k, _) in &map {
+            total += k;
+        }
+        assert_eq!(total, 6);
+
+        let mut pairs: Vec<(i32, &str)> = map.into_iter().collect();
+        pairs.sort();
+        assert_eq!(pairs[0], (1, "one"));
+    }
+
     #[test]
     fn test_tmap_display() {
         let mut map = TMap::new();
@@ -225,4 +593,88 @@ mod tests {
         let deserialized = TMap::from_binary(&binary).unwrap();
         assert_eq!(map, deserialized);
     }
+
+    #[test]
+    fn test_ue_json_and_legacy_forms() {
+        use crate::types::{IntVector2, Name};
+
+        // As written by FJsonObjectConverter for a TMap<FName, int32> property
+        let fixture = r#"{
+            "Kills": 12,
+            "Deaths": 3
+        }"#;
+        let stats: TMap<Name, i32> = serde_json::from_str(fixture).unwrap();
+        assert_eq!(stats.find(&Name::new("Kills")), Some(&12));
+        assert_eq!(serde_json::to_value(&stats).unwrap(), serde_json::json!({"Kills": 12, "Deaths": 3}));
+
+        // Integer keys are written as strings and read back
+        let by_id: TMap<u32, String> = serde_json::from_str(r#"{"7": "Alpha"}"#).unwrap();
+        assert_eq!(by_id.find(&7).map(String::as_str), Some("Alpha"));
+        assert_eq!(serde_json::to_string(&by_id).unwrap(), r#"{"7":"Alpha"}"#);
+
+        // A bare map with a "data" entry is just an entry
+        let bare: TMap<String, TMap<String, i32>> = serde_json::from_str(r#"{"data": {"a": 1}}"#).unwrap();
+        assert_eq!(bare.find(&"data".to_string()).and_then(|inner| inner.find(&"a".to_string())), Some(&1));
+
+        let legacy = r#"{"data": {"Kills": 12, "Deaths": 3}}"#;
+        let loaded = TMap::<Name, i32>::deserialize_legacy(&mut serde_json::Deserializer::from_str(legacy)).unwrap();
+        assert_eq!(loaded, stats);
+        assert!(TMap::<Name, i32>::deserialize_legacy(&mut serde_json::Deserializer::from_str(fixture)).is_err());
+
+        // Struct keys need the pair form
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Grid {
+            #[serde(with = "tmap_as_pairs")]
+            cells: TMap<IntVector2, u8>,
+        }
+        let grid = Grid { cells: TMap::from_iter([(IntVector2::new(1, -2), 5)]) };
+        assert!(serde_json::to_string(&grid.cells).is_err());
+        let json = serde_json::to_string(&grid).unwrap();
+        assert_eq!(json, r#"{"cells":[[{"x":1,"y":-2},5]]}"#);
+        assert_eq!(serde_json::from_str::<Grid>(&json).unwrap(), grid);
+        assert_eq!(bincode::deserialize::<Grid>(&bincode::serialize(&grid).unwrap()).unwrap(), grid);
+    }
+
+    #[test]
+    fn test_binary_rejects_absurd_entry_count() {
+        use crate::types::MAX_DESERIALIZED_ELEMENTS;
+
+        let mut over = ((MAX_DESERIALIZED_ELEMENTS + 1) as u64).to_le_bytes().to_vec();
+        over.extend_from_slice(&[0; 16]);
+        let error = bincode::deserialize::<TMap<u32, u32>>(&over).unwrap_err();
+        assert!(error.to_string().contains("invalid length"), "{}", error);
+        assert!(TMap::<u32, u32>::from_binary(&u64::MAX.to_le_bytes()).is_err());
+
+        let map = TMap::from_iter([(1u32, 2u32), (3, 4)]);
+        assert_eq!(TMap::<u32, u32>::from_binary(&map.to_binary().unwrap()).unwrap(), map);
+    }
+
+    #[test]
+    fn test_map_values() {
+        let mut health: TMap<String, i32> = TMap::new();
+        health.add("alice".to_string(), 100);
+        health.add("bob".to_string(), 35);
+
+        let labels = health.map_values(|name, hp| format!("{}: {}", name, hp));
+        let expected: TMap<String, String> = health.iter().map(|(name, hp)| (name.clone(), format!("{}: {}", name, hp))).collect();
+        assert_eq!(labels, expected);
+        assert_eq!(labels.find(&"bob".to_string()), Some(&"bob: 35".to_string()));
+        assert_eq!(health.find(&"bob".to_string()), Some(&35));
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_iter() {
+        use rayon::prelude::*;
+
+        let mut map: TMap<u32, u32> = (0..5_000).map(|i| (i, i % 7)).collect();
+        let mut parallel: Vec<(u32, u32)> = map.par_iter().map(|(k, v)| (*k, k + v)).collect();
+        let mut sequential: Vec<(u32, u32)> = map.iter().map(|(k, v)| (*k, k + v)).collect();
+        parallel.sort_unstable();
+        sequential.sort_unstable();
+        assert_eq!(parallel, sequential);
+
+        map.par_iter_mut().for_each(|(k, v)| *v += k);
+        assert!(map.iter().all(|(k, v)| *v == k + k % 7));
+    }
 }
\ No newline at end of file
@@ -1,16 +1,22 @@
 //! UE5-style set (TSet)
 
 use crate::BinarySerializable;
-use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
-use std::fmt;
-use std::hash::Hash;
+use super::tarray::deserialize_seq_or_legacy;
+use super::TArray;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use super::hash::HashSet;
+use core::fmt;
+use core::hash::Hash;
+use alloc::vec::Vec;
 
 /// UE5-style set (equivalent to TSet)
 /// 
 /// A hash set with UE5-compatible methods and naming conventions.
 /// Internally uses HashSet<T> but provides UE5-style API.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// Serializes as a bare sequence in unspecified order, like `TArray`, and
+/// also accepts the legacy `{"data": [...]}` object in human-readable formats.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TSet<T: Eq + Hash> {
     data: HashSet<T>,
 }
@@ -33,18 +39,30 @@ where
     T: serde::Serialize + serde::de::DeserializeOwned + Eq + Hash,
 {}
 
+impl<T: Serialize + Eq + Hash> Serialize for TSet<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.data.serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de> + Eq + Hash> Deserialize<'de> for TSet<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_seq_or_legacy(deserializer).map(|items: Vec<T>| items.into_iter().collect())
+    }
+}
+
 impl<T> TSet<T> 
 where 
     T: Eq + Hash,
 {
     /// Create a new empty set
     pub fn new() -> Self {
-        Self { data: HashSet::new() }
+        Self { data: HashSet::default() }
     }
 
     /// Create a set with the given capacity
     pub fn with_capacity(capacity: usize) -> Self {
-        Self { data: HashSet::with_capacity(capacity) }
+        Self { data: HashSet::with_capacity_and_hasher(capacity, Default::default()) }
     }
 
     /// Create from a HashSet
@@ -108,24 +126,95 @@ where
     }
 
     /// Get an iterator over the elements
-    pub fn iter(&self) -> std::collections::hash_set::Iter<T> {
+    pub fn iter(&self) -> super::hash::hash_set::Iter<'_, T> {
         self.data.iter()
     }
 
     /// Get the union with another set
-    pub fn union<'a>(&'a self, other: &'a TSet<T>) -> std::collections::hash_set::Union<'a, T, std::collections::hash_map::RandomState> {
+    pub fn union<'a>(&'a self, other: &'a TSet<T>) -> super::hash::hash_set::Union<'a, T, super::hash::DefaultHashBuilder> {
         self.data.union(&other.data)
     }
 
     /// Get the intersection with another set
-    pub fn intersection<'a>(&'a self, other: &'a TSet<T>) -> std::collections::hash_set::Intersection<'a, T, std::collections::hash_map::RandomState> {
+    pub fn intersection<'a>(&'a self, other: &'a TSet<T>) -> super::hash::hash_set::Intersection<'a, T, super::hash::DefaultHashBuilder> {
         self.data.intersection(&other.data)
     }
 
     /// Get the difference with another set
-    pub fn difference<'a>(&'a self, other: &'a TSet<T>) -> std::collections::hash_set::Difference<'a, T, std::collections::hash_map::RandomState> {
+    pub fn difference<'a>(&'a self, other: &'a TSet<T>) -> super::hash::hash_set::Difference<'a, T, super::hash::DefaultHashBuilder> {
         self.data.difference(&other.data)
     }
+
+    /// Get the union with another set as a new set (UE5: Union())
+    pub fn union_set(&self, other: &TSet<T>) -> TSet<T>
+    where
+        T: Clone,
+    {
+        self.union(other).cloned().collect()
+    }
+
+    /// Get the intersection with another set as a new set (UE5: Intersect())
+    pub fn intersect_set(&self, other: &TSet<T>) -> TSet<T>
+    where
+        T: Clone,
+    {
+        self.intersection(other).cloned().collect()
+    }
+
+    /// Get the elements of this set that are not in another as a new set (UE5: Difference())
+    pub fn difference_set(&self, other: &TSet<T>) -> TSet<T>
+    where
+        T: Clone,
+    {
+        self.difference(other).cloned().collect()
+    }
+
+    /// Get the elements in exactly one of the two sets as a new set
+    pub fn symmetric_difference_set(&self, other: &TSet<T>) -> TSet<T>
+    where
+        T: Clone,
+    {
+        self.data.symmetric_difference(&other.data).cloned().collect()
+    }
+
+    /// Check if every element of this set is in another
+    pub fn is_subset_of(&self, other: &TSet<T>) -> bool {
+        self.data.is_subset(&other.data)
+    }
+
+    /// Check if this set contains every element of another
+    pub fn is_superset_of(&self, other: &TSet<T>) -> bool {
+        self.data.is_superset(&other.data)
+    }
+
+    /// Check if this set contains every element of another (UE5: Includes())
+    pub fn includes(&self, other: &TSet<T>) -> bool {
+        self.is_superset_of(other)
+    }
+
+    /// Copy the elements into an array (UE5: Array())
+    pub fn array(&self) -> TArray<T>
+    where
+        T: Clone,
+    {
+        self.data.iter().cloned().collect()
+    }
+
+    /// Move all elements from another set into this one (UE5: Append())
+    pub fn append(&mut self, other: TSet<T>) {
+        self.data.extend(other.data);
+    }
+
+    /// Build a new set from `f` applied to every element; equal results merge
+    pub fn map<U, F>(&self, f: F) -> TSet<U>
+    where
+        U: Eq + Hash,
+        F: FnMut(&T) -> U,
+    {
+        let mut data = HashSet::with_capacity_and_hasher(self.data.len(), Default::default());
+        data.extend(self.data.iter().map(f));
+        TSet { data }
+    }
 }
 
 impl<T> Default for TSet<T> 
@@ -146,6 +235,39 @@ where
     }
 }
 
+impl<T> Extend<T> for TSet<T>
+where
+    T: Eq + Hash,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.data.extend(iter);
+    }
+}
+
+impl<T> IntoIterator for TSet<T>
+where
+    T: Eq + Hash,
+{
+    type Item = T;
+    type IntoIter = super::hash::hash_set::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a TSet<T>
+where
+    T: Eq + Hash,
+{
+    type Item = &'a T;
+    type IntoIter = super::hash::hash_set::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -170,6 +292,73 @@ mod tests {
         assert_eq!(set.num(), 1);
     }
 
+    #[test]
+    fn test_tset_owned_set_algebra() {
+        let a: TSet<i32> = TSet::from_iter(vec![1, 2, 3, 4]);
+        let b: TSet<i32> = TSet::from_iter(vec![3, 4, 5]);
+
+        let union = a.union_set(&b);
+        // Overlapping elements appear once
+        assert_eq!(union.num(), 5);
+        assert_eq!(union, TSet::from_iter(1..=5));
+
+        assert_eq!(a.intersect_set(&b), TSet::from_iter(vec![3, 4]));
+        assert_eq!(a.difference_set(&b), TSet::from_iter(vec![1, 2]));
+        assert_eq!(a.symmetric_difference_set(&b), TSet::from_iter(vec![1, 2, 5]));
+
+        // Results chain with other TSet APIs
+        assert!(a.intersect_set(&b).union_set(&a).is_subset_of(&union));
+    }
+
+    #[test]
+    fn test_tset_empty_set_algebra() {
+        let a: TSet<i32> = TSet::from_iter(vec![1, 2]);
+        let empty: TSet<i32> = TSet::new();
+
+        assert_eq!(a.union_set(&empty), a);
+        assert!(a.intersect_set(&empty).is_empty());
+        assert_eq!(a.difference_set(&empty), a);
+        assert!(empty.difference_set(&a).is_empty());
+        assert_eq!(empty.symmetric_difference_set(&a), a);
+
+        assert!(empty.is_subset_of(&a));
+        assert!(empty.is_subset_of(&empty));
+        assert!(a.includes(&empty));
+        assert!(!empty.includes(&a));
+        assert!(empty.array().is_empty());
+    }
+
+    #[test]
+    fn test_tset_subset_and_superset() {
+        let all: TSet<&str> = TSet::from_iter(vec!["a", "b", "c"]);
+        let some: TSet<&str> = TSet::from_iter(vec!["a", "c"]);
+
+        assert!(some.is_subset_of(&all));
+        assert!(!all.is_subset_of(&some));
+        assert!(all.is_superset_of(&some));
+        assert!(all.includes(&some));
+        assert!(all.includes(&all));
+    }
+
+    #[test]
+    fn test_tset_append_extend_and_iterate() {
+        let mut set: TSet<i32> = TSet::from_iter(vec![1, 2]);
+        set.append(TSet::from_iter(vec![2, 3]));
+        set.extend(vec![3, 4]);
+        assert_eq!(set.num(), 4);
+
+        let mut array = set.array();
+        array.sort();
+        assert_eq!(array.as_slice(), &[1, 2, 3, 4]);
+
+        let borrowed_sum: i32 = (&set).into_iter().sum();
+        assert_eq!(borrowed_sum, 10);
+
+        let mut owned: Vec<i32> = set.into_iter().collect();
+        owned.sort();
+        assert_eq!(owned, vec![1, 2, 3, 4]);
+    }
+
     #[test]
     fn test_tset_display() {
         let set: TSet<i32> = TSet::from_iter(vec![1, 2, 3]);
@@ -194,4 +383,31 @@ mod tests {
         let deserialized = TSet::from_binary(&binary).unwrap();
         assert_eq!(set, deserialized);
     }
+
+    #[test]
+    fn test_ue_json_and_legacy_forms() {
+        // As written by FJsonObjectConverter for a TSet<FString> property
+        let fixture = r#"["Red", "Blue", "Red"]"#;
+        let teams: TSet<String> = serde_json::from_str(fixture).unwrap();
+        assert_eq!(teams, TSet::from_iter(["Red".to_string(), "Blue".to_string()]));
+
+        let json = serde_json::to_value(&teams).unwrap();
+        let mut written: Vec<&str> = json.as_array().unwrap().iter().map(|value| value.as_str().unwrap()).collect();
+        written.sort();
+        assert_eq!(written, ["Blue", "Red"]);
+
+        let legacy: TSet<String> = serde_json::from_str(r#"{"data": ["Blue", "Red"]}"#).unwrap();
+        assert_eq!(legacy, teams);
+    }
+
+    #[test]
+    fn test_map() {
+        let set: TSet<i32> = [-3, -1, 1, 2, 3].into_iter().collect();
+        let squares = set.map(|x| x * x);
+        let expected: TSet<i32> = set.iter().map(|x| x * x).collect();
+        assert_eq!(squares, expected);
+        // Equal results merge
+        assert_eq!(squares.len(), 3);
+        assert_eq!(set.len(), 5);
+    }
 }
\ No newline at end of file
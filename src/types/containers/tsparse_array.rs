@@ -0,0 +1,435 @@
+//! UE5-style sparse array with generational handles (TSparseArray)
+
+use crate::BinarySerializable;
+use super::tarray::deserialize_bounded_seq;
+use super::TMap;
+use serde::de;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Stable handle to an element of a `TSparseArray`
+///
+/// Pairs the slot index with the slot's generation. Removing an element
+/// advances its slot's generation, so handles to it stop resolving even
+/// after the slot is reused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct SparseIndex {
+    index: u32,
+    generation: u32,
+}
+
+impl fmt::Display for SparseIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SparseIndex({}v{})", self.index, self.generation)
+    }
+}
+
+impl SparseIndex {
+    /// Slot the element lives in
+    pub fn index(self) -> u32 {
+        self.index
+    }
+
+    /// Generation of the slot when the handle was issued
+    pub fn generation(self) -> u32 {
+        self.generation
+    }
+}
+
+/// One slot: the generation it is on, and its element if occupied
+///
+/// A free slot's generation has never been handed out. A free slot on
+/// `u32::MAX` is retired and never reused.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Slot<V> {
+    generation: u32,
+    value: Option<V>,
+}
+
+/// UE5-style sparse array (equivalent to TSparseArray)
+///
+/// Elements keep their slot when others are removed, so the `SparseIndex`
+/// returned by `add` stays valid until that element is removed. Freed slots
+/// are reused lowest index first.
+///
+/// Serializes every slot with its generation, so handles saved alongside the
+/// array still resolve (or stay stale) after loading, and the next `add`
+/// returns the same handle it would have before saving.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TSparseArray<T> {
+    slots: Vec<Slot<T>>,
+    free: BTreeSet<u32>,
+    len: usize,
+    /// Generation for newly created slots; above any generation `compact` discarded
+    next_slot_generation: u32,
+}
+
+impl<T: fmt::Display> fmt::Display for TSparseArray<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "TSparseArray[")?;
+        for (i, (_, item)) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", item)?;
+        }
+        write!(f, "]")
+    }
+}
+
+impl<T: Serialize + de::DeserializeOwned> BinarySerializable for TSparseArray<T> {}
+
+/// Serialized form of a sparse array
+#[derive(Serialize, Deserialize)]
+#[serde(bound(deserialize = "V: Deserialize<'de>"))]
+struct SparseArrayData<V> {
+    next_slot_generation: u32,
+    #[serde(deserialize_with = "deserialize_bounded_seq")]
+    slots: Vec<Slot<V>>,
+}
+
+impl<T: Serialize> Serialize for TSparseArray<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SparseArrayData {
+            next_slot_generation: self.next_slot_generation,
+            slots: self.slots.iter().map(|slot| Slot { generation: slot.generation, value: slot.value.as_ref() }).collect(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for TSparseArray<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data = SparseArrayData::<T>::deserialize(deserializer)?;
+        if data.slots.len() > u32::MAX as usize {
+            return Err(de::Error::invalid_length(data.slots.len(), &"at most u32::MAX slots"));
+        }
+        let mut array = Self {
+            slots: data.slots,
+            free: BTreeSet::new(),
+            len: 0,
+            next_slot_generation: data.next_slot_generation,
+        };
+        for (index, slot) in array.slots.iter().enumerate() {
+            match slot.value {
+                Some(_) if slot.generation == u32::MAX => {
+                    return Err(de::Error::custom("occupied slot on a retired generation"));
+                }
+                Some(_) => array.len += 1,
+                None if slot.generation != u32::MAX => {
+                    array.free.insert(index as u32);
+                }
+                None => {}
+            }
+        }
+        Ok(array)
+    }
+}
+
+impl<T> TSparseArray<T> {
+    /// Create an empty sparse array
+    pub fn new() -> Self {
+        Self {
+            slots: Vec::new(),
+            free: BTreeSet::new(),
+            len: 0,
+            next_slot_generation: 0,
+        }
+    }
+
+    /// Create an empty sparse array with room for `capacity` slots
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            slots: Vec::with_capacity(capacity),
+            ..Self::new()
+        }
+    }
+
+    /// Get the number of live elements (UE5: Num())
+    pub fn num(&self) -> i32 {
+        self.len as i32
+    }
+
+    /// Get the number of live elements
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check if there are no live elements
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Number of slots, live or free (UE5: GetMaxIndex())
+    pub fn max_index(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Add an element, returning its handle (UE5: Add())
+    pub fn add(&mut self, value: T) -> SparseIndex {
+        self.len += 1;
+        if let Some(index) = self.free.pop_first() {
+            let slot = &mut self.slots[index as usize];
+            slot.value = Some(value);
+            return SparseIndex { index, generation: slot.generation };
+        }
+        let index = u32::try_from(self.slots.len()).expect("TSparseArray is limited to u32::MAX slots");
+        let generation = self.next_slot_generation;
+        self.slots.push(Slot { generation, value: Some(value) });
+        SparseIndex { index, generation }
+    }
+
+    /// Remove an element, returning it, or `None` if the handle is stale (UE5: RemoveAt())
+    pub fn remove(&mut self, handle: SparseIndex) -> Option<T> {
+        let slot = self.slots.get_mut(handle.index as usize)?;
+        if slot.generation != handle.generation {
+            return None;
+        }
+        let value = slot.value.take()?;
+        slot.generation += 1;
+        // A slot that has used up its generations is retired rather than risk reissuing a handle
+        if slot.generation != u32::MAX {
+            self.free.insert(handle.index);
+        }
+        self.len -= 1;
+        Some(value)
+    }
+
+    /// Get an element, or `None` if the handle is stale
+    pub fn get(&self, handle: SparseIndex) -> Option<&T> {
+        self.slots
+            .get(handle.index as usize)
+            .filter(|slot| slot.generation == handle.generation)
+            .and_then(|slot| slot.value.as_ref())
+    }
+
+    /// Get an element mutably, or `None` if the handle is stale
+    pub fn get_mut(&mut self, handle: SparseIndex) -> Option<&mut T> {
+        self.slots
+            .get_mut(handle.index as usize)
+            .filter(|slot| slot.generation == handle.generation)
+            .and_then(|slot| slot.value.as_mut())
+    }
+
+    /// Check if a handle refers to a live element (UE5: IsValidIndex())
+    pub fn contains(&self, handle: SparseIndex) -> bool {
+        self.get(handle).is_some()
+    }
+
+    /// Remove every element; all existing handles become stale (UE5: Empty())
+    pub fn clear(&mut self) {
+        for index in 0..self.slots.len() {
+            let generation = self.slots[index].generation;
+            self.remove(SparseIndex { index: index as u32, generation });
+        }
+    }
+
+    /// Iterate over live elements and their handles in slot order, skipping holes
+    pub fn iter(&self) -> impl Iterator<Item = (SparseIndex, &T)> {
+        self.slots.iter().enumerate().filter_map(|(index, slot)| {
+            let handle = SparseIndex { index: index as u32, generation: slot.generation };
+            slot.value.as_ref().map(|value| (handle, value))
+        })
+    }
+
+    /// Iterate mutably over live elements and their handles in slot order
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (SparseIndex, &mut T)> {
+        self.slots.iter_mut().enumerate().filter_map(|(index, slot)| {
+            let handle = SparseIndex { index: index as u32, generation: slot.generation };
+            slot.value.as_mut().map(|value| (handle, value))
+        })
+    }
+
+    /// Move elements into the lowest free slots and drop trailing free slots (UE5: Compact())
+    ///
+    /// Returns the new handle of every element that moved, keyed by its old
+    /// handle; elements that didn't move keep their handles. Old handles of
+    /// moved elements become stale, never aliasing another element.
+    pub fn compact(&mut self) -> TMap<SparseIndex, SparseIndex> {
+        let mut moved = TMap::new();
+        while let (Some(&target), Some(source)) = (self.free.first(), self.last_occupied()) {
+            if target as usize > source {
+                break;
+            }
+            self.free.pop_first();
+            let from = SparseIndex { index: source as u32, generation: self.slots[source].generation };
+            let value = self.remove(from).expect("last occupied slot holds a value");
+            let slot = &mut self.slots[target as usize];
+            slot.value = Some(value);
+            moved.add(from, SparseIndex { index: target, generation: slot.generation });
+        }
+        while let Some(slot) = self.slots.last() {
+            if slot.value.is_some() || slot.generation == u32::MAX {
+                break;
+            }
+            self.next_slot_generation = self.next_slot_generation.max(slot.generation);
+            self.free.remove(&(self.slots.len() as u32 - 1));
+            self.slots.pop();
+        }
+        self.slots.shrink_to_fit();
+        moved
+    }
+
+    fn last_occupied(&self) -> Option<usize> {
+        self.slots.iter().rposition(|slot| slot.value.is_some())
+    }
+}
+
+impl<T> Default for TSparseArray<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<T> for TSparseArray<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut array = Self::new();
+        for value in iter {
+            array.add(value);
+        }
+        array
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::{String, ToString};
+    use alloc::vec;
+
+    #[test]
+    fn test_add_get_remove() {
+        let mut array = TSparseArray::new();
+        let a = array.add("a".to_string());
+        let b = array.add("b".to_string());
+        assert_eq!(array.num(), 2);
+        assert_eq!(array.get(a).map(String::as_str), Some("a"));
+
+        array.get_mut(b).unwrap().push('!');
+        assert_eq!(array.remove(b), Some("b!".to_string()));
+        assert_eq!(array.remove(b), None);
+        assert_eq!(array.get(b), None);
+        assert!(!array.contains(b));
+        assert_eq!(array.len(), 1);
+        assert_eq!(array.to_string(), "TSparseArray[a]");
+    }
+
+    #[test]
+    fn test_reused_slot_invalidates_old_handle() {
+        let mut array = TSparseArray::new();
+        let first = array.add(10);
+        array.add(20);
+        array.remove(first);
+
+        let reused = array.add(30);
+        assert_eq!(reused.index(), first.index());
+        assert_ne!(reused.generation(), first.generation());
+        assert_eq!(array.get(first), None);
+        assert_eq!(array.get_mut(first), None);
+        assert_eq!(array.remove(first), None);
+        assert_eq!(array.get(reused), Some(&30));
+    }
+
+    #[test]
+    fn test_exhausted_slot_is_retired() {
+        let mut array = TSparseArray::new();
+        let handle = array.add(1);
+        array.slots[0].generation = u32::MAX - 1;
+        let handle = SparseIndex { generation: u32::MAX - 1, ..handle };
+        assert_eq!(array.remove(handle), Some(1));
+
+        // The slot is never reused, so no handle can repeat
+        let next = array.add(2);
+        assert_eq!(next.index(), 1);
+        assert_eq!(array.compact().len(), 0);
+        assert_eq!(array.max_index(), 2);
+    }
+
+    #[test]
+    fn test_iteration_skips_holes() {
+        let mut array: TSparseArray<i32> = (0..6).collect();
+        let handles: Vec<SparseIndex> = array.iter().map(|(handle, _)| handle).collect();
+        array.remove(handles[1]);
+        array.remove(handles[4]);
+
+        assert_eq!(array.iter().map(|(_, value)| *value).collect::<Vec<_>>(), vec![0, 2, 3, 5]);
+        for (handle, value) in array.iter_mut() {
+            *value += handle.index() as i32;
+        }
+        assert_eq!(array.get(handles[5]), Some(&10));
+        assert_eq!(array.max_index(), 6);
+    }
+
+    #[test]
+    fn test_compact() {
+        let mut array: TSparseArray<char> = "abcdef".chars().collect();
+        let handles: Vec<SparseIndex> = array.iter().map(|(handle, _)| handle).collect();
+        array.remove(handles[0]);
+        array.remove(handles[2]);
+        array.remove(handles[5]);
+
+        let moved = array.compact();
+        assert_eq!(array.max_index(), 3);
+        assert_eq!(array.iter().map(|(_, value)| *value).collect::<String>(), "ebd");
+
+        // 'e' moved from slot 4 to 0 and 'd' from 3 to 2; 'b' kept its handle
+        assert_eq!(moved.len(), 2);
+        let e = *moved.find(&handles[4]).unwrap();
+        let d = *moved.find(&handles[3]).unwrap();
+        assert_eq!((e.index(), d.index()), (0, 2));
+        assert_eq!((array.get(e), array.get(d)), (Some(&'e'), Some(&'d')));
+        assert_eq!(array.get(handles[4]), None);
+        assert_eq!(array.get(handles[3]), None);
+        assert_eq!(array.get(handles[0]), None);
+        assert_eq!(array.get(handles[1]), Some(&'b'));
+
+        // Recreated slots start past any generation the dropped ones issued
+        let mut recreated = Vec::new();
+        for value in "xyz".chars() {
+            recreated.push(array.add(value));
+        }
+        assert_eq!(recreated.iter().map(|handle| handle.index()).collect::<Vec<_>>(), vec![3, 4, 5]);
+        assert!(handles[3..].iter().all(|handle| array.get(*handle).is_none()));
+        assert!(recreated.iter().all(|handle| handle.generation() > 0));
+    }
+
+    #[test]
+    fn test_serialization_preserves_handles() {
+        let mut array = TSparseArray::new();
+        let keep = array.add("keep".to_string());
+        let gone = array.add("gone".to_string());
+        let also_keep = array.add("also keep".to_string());
+        array.remove(gone);
+
+        let json = serde_json::to_string(&array).unwrap();
+        let from_json: TSparseArray<String> = serde_json::from_str(&json).unwrap();
+        let from_binary = TSparseArray::<String>::from_binary(&array.to_binary().unwrap()).unwrap();
+
+        for mut loaded in [from_json, from_binary] {
+            assert_eq!(loaded, array);
+            assert_eq!(loaded.get(keep).map(String::as_str), Some("keep"));
+            assert_eq!(loaded.get(also_keep).map(String::as_str), Some("also keep"));
+            assert_eq!(loaded.get(gone), None);
+
+            // The freed slot is reused with a fresh generation, as it would have been before saving
+            let mut original = array.clone();
+            assert_eq!(loaded.add("new".to_string()), original.add("new".to_string()));
+        }
+
+        let handle_json = serde_json::to_string(&keep).unwrap();
+        assert_eq!(serde_json::from_str::<SparseIndex>(&handle_json).unwrap(), keep);
+    }
+
+    #[test]
+    fn test_rejects_occupied_retired_slot() {
+        let json = r#"{"next_slot_generation":0,"slots":[{"generation":4294967295,"value":1}]}"#;
+        assert!(serde_json::from_str::<TSparseArray<i32>>(json).is_err());
+
+        let json = r#"{"next_slot_generation":3,"slots":[{"generation":4294967295,"value":null},{"generation":2,"value":null}]}"#;
+        let mut array: TSparseArray<i32> = serde_json::from_str(json).unwrap();
+        assert!(array.is_empty());
+        assert_eq!(array.add(5), SparseIndex { index: 1, generation: 2 });
+    }
+}
@@ -0,0 +1,37 @@
+//! Crate-level error for operations that depend on the environment
+
+use core::fmt;
+use core::time::Duration;
+
+/// Error from operations that read the clock or draw from process-wide state
+///
+/// Of the infallible counterparts, `DateTime::now` and `GameSessionInfo::new`
+/// fall back to the Unix epoch on a clock before it, while
+/// `NetworkGUID::generate` (and so `GameSessionInfo::new`) panics once GUIDs
+/// run out, since no fallback GUID would be unique. Use the `try_` variants
+/// to handle these cases.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UeTypesError {
+    /// The clock reads earlier than the Unix epoch, by the given amount
+    ///
+    /// Seen on machines booting with a dead RTC before NTP has synced.
+    ClockBeforeEpoch(Duration),
+    /// Every non-zero `NetworkGUID` value has been handed out
+    NetworkGuidsExhausted,
+    /// Interning a new `Name` would take the global name table past its byte budget
+    NameTableFull,
+}
+
+impl fmt::Display for UeTypesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UeTypesError::ClockBeforeEpoch(behind) => {
+                write!(f, "system clock is {:?} before the Unix epoch", behind)
+            }
+            UeTypesError::NetworkGuidsExhausted => write!(f, "all NetworkGUID values are in use"),
+            UeTypesError::NameTableFull => write!(f, "the global Name table is full"),
+        }
+    }
+}
+
+impl core::error::Error for UeTypesError {}
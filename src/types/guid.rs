@@ -2,9 +2,159 @@
 
 use crate::BinarySerializable;
 use serde::{Deserialize, Serialize};
-use std::fmt;
+use alloc::string::String;
+use core::fmt;
+use alloc::vec::Vec;
+use alloc::format;
+#[cfg(feature = "std")]
+use core::hash::{BuildHasher, Hasher};
+#[cfg(feature = "std")]
+use core::sync::atomic::{AtomicU64, Ordering};
+#[cfg(feature = "std")]
+use std::collections::hash_map::RandomState;
+#[cfg(feature = "std")]
+use std::sync::OnceLock;
+#[cfg(feature = "std")]
 use std::time::{SystemTime, UNIX_EPOCH};
 
+/// SplitMix64 increment (golden ratio)
+#[cfg(feature = "std")]
+const SPLITMIX_GAMMA: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// Bits of `b` holding the RFC-4122 version nibble
+const VERSION_MASK: u32 = 0x0000_F000;
+/// Version 4 (random) nibble in `b`
+const VERSION_4: u32 = 0x0000_4000;
+/// Bits of `c` holding the RFC-4122 variant
+const VARIANT_MASK: u32 = 0xC000_0000;
+/// RFC-4122 variant (`10` in the top bits of `c`)
+const VARIANT_RFC4122: u32 = 0x8000_0000;
+
+/// SplitMix64 finalizer; a bijection on u64
+#[cfg(feature = "std")]
+fn mix64(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Draw the next pair of random words from the process-wide generator
+///
+/// The generator is seeded once from the OS-randomised `RandomState` keys
+/// and the clock. Each call claims a unique step from an atomic counter, so
+/// the first word never repeats within a process.
+#[cfg(feature = "std")]
+fn next_random_words() -> (u64, u64) {
+    static SEED: OnceLock<u64> = OnceLock::new();
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    let seed = *SEED.get_or_init(|| {
+        let mut hasher = RandomState::new().build_hasher();
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_nanos());
+        hasher.write_u128(nanos);
+        hasher.write_u32(std::process::id());
+        hasher.finish()
+    });
+
+    let step = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let state = seed.wrapping_add(step.wrapping_mul(SPLITMIX_GAMMA));
+    (mix64(state), mix64(state ^ seed.rotate_left(32)))
+}
+
+/// Which check failed while parsing a GUID string
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GuidParseError {
+    /// The string length matches none of the supported formats
+    InvalidLength(usize),
+    /// An opening `{` without a closing `}` (or vice versa)
+    UnbalancedBraces,
+    /// UE-style input did not have four hyphen-separated groups of 8 digits
+    InvalidUeGroups,
+    /// Canonical input did not follow the 8-4-4-4-12 hyphen layout
+    InvalidUuidGroups,
+    /// A non-hexadecimal character at the given offset (after braces are stripped)
+    InvalidHexDigit { position: usize, found: char },
+}
+
+impl fmt::Display for GuidParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GuidParseError::InvalidLength(len) => write!(
+                f,
+                "GUID string has length {}, expected 32 digits, UE format (35) or canonical UUID (36)",
+                len
+            ),
+            GuidParseError::UnbalancedBraces => write!(f, "GUID string has unbalanced braces"),
+            GuidParseError::InvalidUeGroups => {
+                write!(f, "UE GUID must be four groups of 8 hex digits (XXXXXXXX-XXXXXXXX-XXXXXXXX-XXXXXXXX)")
+            }
+            GuidParseError::InvalidUuidGroups => {
+                write!(f, "UUID must use the 8-4-4-4-12 hyphen layout")
+            }
+            GuidParseError::InvalidHexDigit { position, found } => {
+                write!(f, "invalid hex digit '{}' at position {}", found, position)
+            }
+        }
+    }
+}
+
+impl core::error::Error for GuidParseError {}
+
+/// Parse exactly 32 hex digits into a GUID
+fn parse_hex_digits(digits: &str, offsets: impl Fn(usize) -> usize) -> Result<Guid, GuidParseError> {
+    let mut bytes = [0u8; 16];
+    for (i, c) in digits.chars().enumerate() {
+        let value = c.to_digit(16).ok_or(GuidParseError::InvalidHexDigit {
+            position: offsets(i),
+            found: c,
+        })? as u8;
+        bytes[i / 2] = (bytes[i / 2] << 4) | value;
+    }
+    Ok(Guid::from_bytes(bytes))
+}
+
+/// Strip hyphens at the expected positions and parse the remaining digits
+fn parse_grouped(s: &str, group_lengths: &[usize], error: GuidParseError) -> Result<Guid, GuidParseError> {
+    let groups: Vec<&str> = s.split('-').collect();
+    let layout_ok = groups.len() == group_lengths.len()
+        && groups.iter().zip(group_lengths).all(|(group, &len)| group.len() == len);
+    if !layout_ok {
+        return Err(error);
+    }
+
+    // Map digit index back to its position in the hyphenated string
+    let mut positions = Vec::with_capacity(32);
+    let mut offset = 0;
+    for group in &groups {
+        positions.extend(offset..offset + group.len());
+        offset += group.len() + 1;
+    }
+    parse_hex_digits(&groups.concat(), |i| positions[i])
+}
+
+/// Serde helper that (de)serializes a `Guid` as a canonical UUID string
+///
+/// Use with `#[serde(with = "ue_types::guid::serde_uuid")]`. Deserialization
+/// accepts every format understood by `str::parse::<Guid>()`.
+pub mod serde_uuid {
+    use super::Guid;
+    use alloc::string::String;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    /// Serialize as `xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`
+    pub fn serialize<S: Serializer>(guid: &Guid, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&guid.to_uuid_string())
+    }
+
+    /// Deserialize from a canonical, hyphen-less, braced or UE-style string
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Guid, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
+
 /// UE5-style GUID for unique identifiers
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Guid {
@@ -35,42 +185,63 @@ impl Guid {
         Self { a, b, c, d }
     }
 
-    /// Generate a new GUID (simple pseudo-random implementation)
+    /// Generate a new random RFC-4122 version 4 GUID
+    #[cfg(feature = "std")]
     pub fn new_guid() -> Self {
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_nanos() as u64;
-        
-        // Simple pseudo-random generation based on current time
-        let a = (now & 0xFFFFFFFF) as u32;
-        let b = ((now >> 32) & 0xFFFFFFFF) as u32;
-        let c = (now.wrapping_mul(1103515245).wrapping_add(12345) & 0xFFFFFFFF) as u32;
-        let d = (now.wrapping_mul(214013).wrapping_add(2531011) & 0xFFFFFFFF) as u32;
-        
+        let (unique, random) = next_random_words();
+
+        // The never-repeating word fills `a` and `d` untouched; the version
+        // and variant bits are stamped into `b` and `c`.
+        let a = (unique >> 32) as u32;
+        let d = unique as u32;
+        let b = ((random >> 32) as u32 & !VERSION_MASK) | VERSION_4;
+        let c = (random as u32 & !VARIANT_MASK) | VARIANT_RFC4122;
+
         Self::new(a, b, c, d)
     }
 
-    /// Parse GUID from string (format: XXXXXXXX-XXXXXXXX-XXXXXXXX-XXXXXXXX)
-    pub fn parse(s: &str) -> Result<Self, &'static str> {
-        let parts: Vec<&str> = s.split('-').collect();
-        if parts.len() != 4 {
-            return Err("Invalid GUID format");
-        }
+    /// Check if this GUID has the RFC-4122 version 4 and variant bits set
+    pub fn is_v4(self) -> bool {
+        self.b & VERSION_MASK == VERSION_4 && self.c & VARIANT_MASK == VARIANT_RFC4122
+    }
 
-        let a = u32::from_str_radix(parts[0], 16).map_err(|_| "Invalid hex in part A")?;
-        let b = u32::from_str_radix(parts[1], 16).map_err(|_| "Invalid hex in part B")?;
-        let c = u32::from_str_radix(parts[2], 16).map_err(|_| "Invalid hex in part C")?;
-        let d = u32::from_str_radix(parts[3], 16).map_err(|_| "Invalid hex in part D")?;
+    /// Parse GUID from string (format: XXXXXXXX-XXXXXXXX-XXXXXXXX-XXXXXXXX)
+    pub fn parse(s: &str) -> Result<Self, GuidParseError> {
+        parse_grouped(s, &[8, 8, 8, 8], GuidParseError::InvalidUeGroups)
+    }
 
-        Ok(Self::new(a, b, c, d))
+    /// Parse a canonical UUID string
+    ///
+    /// Accepts `8-4-4-4-12` with or without hyphens, optionally wrapped in
+    /// braces, in any letter case.
+    pub fn parse_uuid(s: &str) -> Result<Self, GuidParseError> {
+        let inner = strip_braces(s)?;
+        match inner.len() {
+            32 => parse_hex_digits(inner, |i| i),
+            36 => parse_grouped(inner, &[8, 4, 4, 4, 12], GuidParseError::InvalidUuidGroups),
+            len => Err(GuidParseError::InvalidLength(len)),
+        }
     }
 
     /// Convert to string representation
+    #[allow(clippy::inherent_to_string_shadow_display)]
     pub fn to_string(self) -> String {
         format!("{:08X}-{:08X}-{:08X}-{:08X}", self.a, self.b, self.c, self.d)
     }
 
+    /// Convert to the canonical lowercase UUID form (`xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`)
+    pub fn to_uuid_string(self) -> String {
+        format!(
+            "{:08x}-{:04x}-{:04x}-{:04x}-{:04x}{:08x}",
+            self.a,
+            self.b >> 16,
+            self.b & 0xFFFF,
+            self.c >> 16,
+            self.c & 0xFFFF,
+            self.d
+        )
+    }
+
     /// Check if this GUID is valid (non-zero)
     pub fn is_valid(self) -> bool {
         self.a != 0 || self.b != 0 || self.c != 0 || self.d != 0
@@ -96,6 +267,49 @@ impl Guid {
     }
 }
 
+fn strip_braces(s: &str) -> Result<&str, GuidParseError> {
+    match (s.strip_prefix('{'), s.ends_with('}')) {
+        (Some(inner), true) => Ok(&inner[..inner.len() - 1]),
+        (None, false) => Ok(s),
+        _ => Err(GuidParseError::UnbalancedBraces),
+    }
+}
+
+impl core::str::FromStr for Guid {
+    type Err = GuidParseError;
+
+    /// Parse either the UE-style or the canonical UUID format
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let inner = strip_braces(s)?;
+        match inner.len() {
+            35 => Self::parse(inner),
+            _ => Self::parse_uuid(inner),
+        }
+    }
+}
+
+impl TryFrom<&str> for Guid {
+    type Error = GuidParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// Every 16-byte array is a valid GUID, so `TryFrom<[u8; 16]>` is provided
+/// through this infallible conversion.
+impl From<[u8; 16]> for Guid {
+    fn from(bytes: [u8; 16]) -> Self {
+        Self::from_bytes(bytes)
+    }
+}
+
+impl From<Guid> for [u8; 16] {
+    fn from(guid: Guid) -> Self {
+        guid.to_bytes()
+    }
+}
+
 impl Default for Guid {
     fn default() -> Self {
         Self::INVALID
@@ -123,4 +337,125 @@ mod tests {
         let deserialized = Guid::from_binary(&binary).unwrap();
         assert_eq!(guid1, deserialized);
     }
+
+    #[test]
+    fn test_guid_uuid_string_round_trip() {
+        let guid = Guid::new(0x12345678, 0x9ABCDEF0, 0x0FEDCBA9, 0x87654321);
+        let uuid = guid.to_uuid_string();
+        assert_eq!(uuid, "12345678-9abc-def0-0fed-cba987654321");
+        assert_eq!(Guid::parse_uuid(&uuid), Ok(guid));
+
+        // Hyphen-less, braced and mixed-case inputs
+        assert_eq!(Guid::parse_uuid("123456789abcdef00fedcba987654321"), Ok(guid));
+        assert_eq!(Guid::parse_uuid("{12345678-9ABC-def0-0FeD-cba987654321}"), Ok(guid));
+        assert_eq!(Guid::parse_uuid("{123456789ABCDEF00FEDCBA987654321}"), Ok(guid));
+
+        let random = Guid::new_guid();
+        assert_eq!(Guid::parse_uuid(&random.to_uuid_string()), Ok(random));
+        assert_eq!(Guid::parse_uuid(&random.to_uuid_string().to_uppercase()), Ok(random));
+    }
+
+    #[test]
+    fn test_guid_from_str_detects_format() {
+        let guid = Guid::new(0x12345678, 0x9ABCDEF0, 0x0FEDCBA9, 0x87654321);
+
+        let ue: Guid = guid.to_string().parse().unwrap();
+        assert_eq!(ue, guid);
+        let ue_lower: Guid = "12345678-9abcdef0-0fedcba9-87654321".parse().unwrap();
+        assert_eq!(ue_lower, guid);
+
+        let canonical: Guid = guid.to_uuid_string().parse().unwrap();
+        assert_eq!(canonical, guid);
+        assert_eq!(Guid::try_from("{12345678-9abc-def0-0fed-cba987654321}"), Ok(guid));
+
+        #[allow(clippy::unnecessary_fallible_conversions)]
+        let from_bytes = Guid::try_from(guid.to_bytes()).unwrap();
+        assert_eq!(from_bytes, guid);
+        let bytes: [u8; 16] = guid.into();
+        assert_eq!(Guid::from(bytes), guid);
+    }
+
+    #[test]
+    fn test_guid_parse_errors() {
+        assert_eq!("1234".parse::<Guid>(), Err(GuidParseError::InvalidLength(4)));
+        assert_eq!(
+            "{12345678-9abc-def0-0fed-cba987654321".parse::<Guid>(),
+            Err(GuidParseError::UnbalancedBraces)
+        );
+        assert_eq!(
+            "1234567-89abcdef0-0fedcba9-87654321".parse::<Guid>(),
+            Err(GuidParseError::InvalidUeGroups)
+        );
+        assert_eq!(
+            "12345678-9abcd-ef0-0fed-cba987654321".parse::<Guid>(),
+            Err(GuidParseError::InvalidUuidGroups)
+        );
+        assert_eq!(
+            "12345678-9abc-def0-0fed-cba98765432g".parse::<Guid>(),
+            Err(GuidParseError::InvalidHexDigit { position: 35, found: 'g' })
+        );
+        assert_eq!(
+            Guid::parse("12345678-9ABCDEF0-0FEDCBA9-8765432+"),
+            Err(GuidParseError::InvalidHexDigit { position: 34, found: '+' })
+        );
+
+        let message = "1234".parse::<Guid>().unwrap_err().to_string();
+        assert!(message.contains("length 4"));
+    }
+
+    #[test]
+    fn test_guid_serde_uuid() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Record {
+            #[serde(with = "serde_uuid")]
+            id: Guid,
+        }
+
+        let record = Record { id: Guid::new(0x12345678, 0x9ABCDEF0, 0x0FEDCBA9, 0x87654321) };
+        let json = serde_json::to_string(&record).unwrap();
+        assert_eq!(json, r#"{"id":"12345678-9abc-def0-0fed-cba987654321"}"#);
+        assert_eq!(serde_json::from_str::<Record>(&json).unwrap(), record);
+
+        // Deserialization also accepts the UE format
+        let ue = r#"{"id":"12345678-9ABCDEF0-0FEDCBA9-87654321"}"#;
+        assert_eq!(serde_json::from_str::<Record>(ue).unwrap(), record);
+        assert!(serde_json::from_str::<Record>(r#"{"id":"nope"}"#).is_err());
+    }
+
+    #[test]
+    fn test_guid_is_v4() {
+        let guid = Guid::new_guid();
+        assert!(guid.is_v4());
+
+        // Canonical byte layout: version nibble in byte 6, variant in byte 8
+        let bytes = guid.to_bytes();
+        assert_eq!(bytes[6] >> 4, 4);
+        assert_eq!(bytes[8] >> 6, 0b10);
+
+        assert!(!Guid::INVALID.is_v4());
+        assert!(!Guid::new(1, 2, 3, 4).is_v4());
+    }
+
+    #[test]
+    fn test_guid_uniqueness() {
+        let mut seen = std::collections::HashSet::with_capacity(100_000);
+        for _ in 0..100_000 {
+            assert!(seen.insert(Guid::new_guid()), "duplicate GUID generated");
+        }
+    }
+
+    #[test]
+    fn test_guid_uniqueness_across_threads() {
+        let handles: Vec<_> = (0..4)
+            .map(|_| std::thread::spawn(|| (0..10_000).map(|_| Guid::new_guid()).collect::<Vec<_>>()))
+            .collect();
+
+        let mut seen = std::collections::HashSet::new();
+        for handle in handles {
+            for guid in handle.join().unwrap() {
+                assert!(seen.insert(guid));
+            }
+        }
+        assert_eq!(seen.len(), 40_000);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,212 @@
+//! Axis selection enums (EAxis)
+
+use crate::vector::Vector;
+use serde::{Deserialize, Serialize};
+use core::fmt;
+
+/// One of the three coordinate axes (UE: `EAxis::Type`, without `None`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Axis {
+    /// The X axis (forward)
+    X,
+    /// The Y axis (right)
+    Y,
+    /// The Z axis (up)
+    Z,
+}
+
+impl fmt::Display for Axis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Axis::X => write!(f, "X"),
+            Axis::Y => write!(f, "Y"),
+            Axis::Z => write!(f, "Z"),
+        }
+    }
+}
+
+impl Axis {
+    /// All axes in X, Y, Z order
+    pub const ALL: [Axis; 3] = [Axis::X, Axis::Y, Axis::Z];
+
+    /// Component index (X = 0, Y = 1, Z = 2)
+    pub fn index(self) -> usize {
+        match self {
+            Axis::X => 0,
+            Axis::Y => 1,
+            Axis::Z => 2,
+        }
+    }
+
+    /// Unit vector along this axis
+    pub fn to_vector(self) -> Vector {
+        match self {
+            Axis::X => Vector::X,
+            Axis::Y => Vector::Y,
+            Axis::Z => Vector::Z,
+        }
+    }
+
+    /// The positive direction along this axis
+    pub fn positive(self) -> AxisSigned {
+        match self {
+            Axis::X => AxisSigned::X,
+            Axis::Y => AxisSigned::Y,
+            Axis::Z => AxisSigned::Z,
+        }
+    }
+
+    /// The negative direction along this axis
+    pub fn negative(self) -> AxisSigned {
+        match self {
+            Axis::X => AxisSigned::NegX,
+            Axis::Y => AxisSigned::NegY,
+            Axis::Z => AxisSigned::NegZ,
+        }
+    }
+}
+
+/// One of the six axis directions, for picking a face or facing
+///
+/// Serializes as `"x"`, `"-x"`, `"y"`, `"-y"`, `"z"` or `"-z"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AxisSigned {
+    /// +X (forward)
+    #[serde(rename = "x")]
+    X,
+    /// -X (backward)
+    #[serde(rename = "-x")]
+    NegX,
+    /// +Y (right)
+    #[serde(rename = "y")]
+    Y,
+    /// -Y (left)
+    #[serde(rename = "-y")]
+    NegY,
+    /// +Z (up)
+    #[serde(rename = "z")]
+    Z,
+    /// -Z (down)
+    #[serde(rename = "-z")]
+    NegZ,
+}
+
+impl fmt::Display for AxisSigned {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_negative() {
+            write!(f, "-{}", self.axis())
+        } else {
+            write!(f, "{}", self.axis())
+        }
+    }
+}
+
+impl AxisSigned {
+    /// All directions in X, -X, Y, -Y, Z, -Z order
+    pub const ALL: [AxisSigned; 6] = [
+        AxisSigned::X,
+        AxisSigned::NegX,
+        AxisSigned::Y,
+        AxisSigned::NegY,
+        AxisSigned::Z,
+        AxisSigned::NegZ,
+    ];
+
+    /// The axis this direction lies along
+    pub fn axis(self) -> Axis {
+        match self {
+            AxisSigned::X | AxisSigned::NegX => Axis::X,
+            AxisSigned::Y | AxisSigned::NegY => Axis::Y,
+            AxisSigned::Z | AxisSigned::NegZ => Axis::Z,
+        }
+    }
+
+    /// Check whether this points down its axis
+    pub fn is_negative(self) -> bool {
+        matches!(self, AxisSigned::NegX | AxisSigned::NegY | AxisSigned::NegZ)
+    }
+
+    /// 1 for a positive direction, -1 for a negative one
+    pub fn sign(self) -> f64 {
+        if self.is_negative() { -1.0 } else { 1.0 }
+    }
+
+    /// The opposite direction along the same axis
+    pub fn opposite(self) -> AxisSigned {
+        if self.is_negative() { self.axis().positive() } else { self.axis().negative() }
+    }
+
+    /// Unit vector in this direction
+    pub fn to_vector(self) -> Vector {
+        self.axis().to_vector() * self.sign()
+    }
+
+    /// Snap a vector to the axis direction it points most along
+    ///
+    /// Returns `None` if every component's magnitude is within `tolerance`
+    /// of zero, or if any component is NaN. When two components tie for the
+    /// largest magnitude the earlier axis wins, in X, Y, Z order, so the
+    /// result is stable for diagonal input.
+    pub fn from_vector(v: Vector, tolerance: f64) -> Option<AxisSigned> {
+        if v.is_nan() {
+            return None;
+        }
+        let mut best = Axis::X;
+        for axis in [Axis::Y, Axis::Z] {
+            if v[axis.index()].abs() > v[best.index()].abs() {
+                best = axis;
+            }
+        }
+        let component = v[best.index()];
+        if component.abs() <= tolerance {
+            return None;
+        }
+        Some(if component < 0.0 { best.negative() } else { best.positive() })
+    }
+}
+
+impl From<Axis> for AxisSigned {
+    fn from(axis: Axis) -> Self {
+        axis.positive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_vector_snaps_to_dominant_axis() {
+        assert_eq!(AxisSigned::from_vector(Vector::new(0.2, -0.9, 0.3), 0.0), Some(AxisSigned::NegY));
+        assert_eq!(AxisSigned::from_vector(Vector::new(0.0, 0.0, 1e-3), 0.0), Some(AxisSigned::Z));
+        assert_eq!(AxisSigned::from_vector(Vector::new(-5.0, 4.9, -4.9), 0.0), Some(AxisSigned::NegX));
+        for direction in AxisSigned::ALL {
+            assert_eq!(AxisSigned::from_vector(direction.to_vector() * 3.0, 1e-6), Some(direction));
+            assert_eq!(direction.opposite().to_vector(), -direction.to_vector());
+        }
+
+        // Ties go to the earlier axis, whatever the signs
+        assert_eq!(AxisSigned::from_vector(Vector::new(1.0, 1.0, 1.0), 0.0), Some(AxisSigned::X));
+        assert_eq!(AxisSigned::from_vector(Vector::new(-2.0, 2.0, 0.0), 0.0), Some(AxisSigned::NegX));
+        assert_eq!(AxisSigned::from_vector(Vector::new(0.5, -3.0, 3.0), 0.0), Some(AxisSigned::NegY));
+
+        // Nothing to snap to
+        assert_eq!(AxisSigned::from_vector(Vector::ZERO, 0.0), None);
+        assert_eq!(AxisSigned::from_vector(Vector::new(0.01, -0.02, 0.0), 0.05), None);
+        assert_eq!(AxisSigned::from_vector(Vector::new(f64::NAN, 1.0, 0.0), 0.0), None);
+    }
+
+    #[test]
+    fn test_serde_strings() {
+        let json = serde_json::to_string(&AxisSigned::ALL).unwrap();
+        assert_eq!(json, r#"["x","-x","y","-y","z","-z"]"#);
+        assert_eq!(serde_json::from_str::<Vec<AxisSigned>>(&json).unwrap(), AxisSigned::ALL);
+        assert_eq!(serde_json::to_string(&Axis::ALL).unwrap(), r#"["x","y","z"]"#);
+        assert!(serde_json::from_str::<AxisSigned>(r#""-X""#).is_err());
+
+        assert_eq!(AxisSigned::NegZ.to_string(), "-Z");
+        assert_eq!(AxisSigned::from(Axis::Y), AxisSigned::Y);
+        assert_eq!((AxisSigned::NegY.axis(), AxisSigned::NegY.sign()), (Axis::Y, -1.0));
+    }
+}
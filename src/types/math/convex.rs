@@ -0,0 +1,372 @@
+//! Convex polygons and convex volumes for zone and visibility queries
+
+use crate::vector::{Vector, Vector2D};
+use crate::bounds::BoundingBox;
+use crate::BinarySerializable;
+use super::Plane;
+use serde::{Deserialize, Serialize};
+use core::fmt;
+use alloc::vec::Vec;
+
+/// Reason a convex shape couldn't be built
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConvexError {
+    /// A polygon needs at least 3 points
+    TooFewPoints(usize),
+    /// All points lie on one line, so they enclose no area
+    Collinear,
+    /// A point has a NaN or infinite coordinate
+    NonFinite,
+    /// A volume needs at least one plane
+    NoPlanes,
+    /// The plane at this index has a zero or non-finite normal
+    DegeneratePlane(usize),
+}
+
+impl fmt::Display for ConvexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConvexError::TooFewPoints(count) => write!(f, "need at least 3 points, got {}", count),
+            ConvexError::Collinear => write!(f, "points are collinear"),
+            ConvexError::NonFinite => write!(f, "points must be finite"),
+            ConvexError::NoPlanes => write!(f, "a convex volume needs at least one plane"),
+            ConvexError::DegeneratePlane(index) => write!(f, "plane {} has a degenerate normal", index),
+        }
+    }
+}
+
+impl core::error::Error for ConvexError {}
+
+/// Convex polygon with counter-clockwise vertices, e.g. a capture zone outline
+///
+/// Always built as a convex hull, so the vertices are in a canonical order
+/// starting from the lowest X (then lowest Y). Deserializing a vertex list
+/// rebuilds the hull and rejects degenerate input.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "Vec<Vector2D>", into = "Vec<Vector2D>")]
+pub struct ConvexPolygon2D {
+    vertices: Vec<Vector2D>,
+}
+
+impl fmt::Display for ConvexPolygon2D {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ConvexPolygon2D({} vertices, Area: {:.2})", self.vertices.len(), self.area())
+    }
+}
+
+impl BinarySerializable for ConvexPolygon2D {}
+
+impl ConvexPolygon2D {
+    /// Build the convex hull of a point set (Andrew's monotone chain)
+    ///
+    /// Interior points, duplicates and points in the middle of hull edges
+    /// are dropped.
+    pub fn from_points_convex_hull(points: &[Vector2D]) -> Result<Self, ConvexError> {
+        if points.len() < 3 {
+            return Err(ConvexError::TooFewPoints(points.len()));
+        }
+        if !points.iter().all(|point| point.is_finite()) {
+            return Err(ConvexError::NonFinite);
+        }
+
+        let mut sorted = points.to_vec();
+        sorted.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+        sorted.dedup();
+
+        // Keep only left turns; the last point of each chain starts the other
+        let mut hull: Vec<Vector2D> = Vec::with_capacity(sorted.len() + 1);
+        for pass in [sorted.as_slice(), &sorted.iter().rev().copied().collect::<Vec<_>>()] {
+            let chain_start = hull.len();
+            for &point in pass {
+                while hull.len() >= chain_start + 2
+                    && cross(hull[hull.len() - 2], hull[hull.len() - 1], point) <= 0.0
+                {
+                    hull.pop();
+                }
+                hull.push(point);
+            }
+            hull.pop();
+        }
+
+        if hull.len() < 3 {
+            return Err(ConvexError::Collinear);
+        }
+        Ok(Self { vertices: hull })
+    }
+
+    /// Hull vertices in counter-clockwise order
+    pub fn vertices(&self) -> &[Vector2D] {
+        &self.vertices
+    }
+
+    /// Check if a point is inside the polygon (edges and vertices included)
+    pub fn contains_point(&self, point: Vector2D) -> bool {
+        self.edges().all(|(start, end)| cross(start, end, point) >= 0.0)
+    }
+
+    /// Enclosed area
+    pub fn area(&self) -> f64 {
+        self.edges().map(|(start, end)| start.perp_dot(end)).sum::<f64>() * 0.5
+    }
+
+    /// Center of mass of the enclosed area
+    pub fn centroid(&self) -> Vector2D {
+        let origin = self.vertices[0];
+        let mut weighted = Vector2D::ZERO;
+        let mut twice_area = 0.0;
+        for (start, end) in self.edges() {
+            let (start, end) = (start - origin, end - origin);
+            let term = start.perp_dot(end);
+            weighted += (start + end) * term;
+            twice_area += term;
+        }
+        origin + weighted / (3.0 * twice_area)
+    }
+
+    fn edges(&self) -> impl Iterator<Item = (Vector2D, Vector2D)> + '_ {
+        self.vertices
+            .iter()
+            .zip(self.vertices.iter().cycle().skip(1))
+            .map(|(&start, &end)| (start, end))
+    }
+}
+
+impl TryFrom<Vec<Vector2D>> for ConvexPolygon2D {
+    type Error = ConvexError;
+
+    fn try_from(points: Vec<Vector2D>) -> Result<Self, Self::Error> {
+        Self::from_points_convex_hull(&points)
+    }
+}
+
+impl From<ConvexPolygon2D> for Vec<Vector2D> {
+    fn from(polygon: ConvexPolygon2D) -> Self {
+        polygon.vertices
+    }
+}
+
+/// Twice the signed area of triangle `a, b, c`; positive for a left turn
+fn cross(a: Vector2D, b: Vector2D, c: Vector2D) -> f64 {
+    (b - a).perp_dot(c - a)
+}
+
+/// Convex volume bounded by planes (FConvexVolume), e.g. a view frustum
+///
+/// Plane normals point out of the volume, so a point is inside when it is
+/// on or behind every plane. Normals are normalized on construction, and
+/// deserializing a plane list validates it the same way.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "Vec<Plane>", into = "Vec<Plane>")]
+pub struct ConvexVolume {
+    planes: Vec<Plane>,
+}
+
+impl fmt::Display for ConvexVolume {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ConvexVolume({} planes)", self.planes.len())
+    }
+}
+
+impl BinarySerializable for ConvexVolume {}
+
+impl ConvexVolume {
+    /// Create a volume from outward-facing planes, such as the six planes of a frustum
+    ///
+    /// Normals don't need to be unit length; each plane is rescaled so its
+    /// normal is. Fails if there are no planes or a normal is zero or non-finite.
+    pub fn from_frustum_planes(planes: &[Plane]) -> Result<Self, ConvexError> {
+        if planes.is_empty() {
+            return Err(ConvexError::NoPlanes);
+        }
+        let planes = planes
+            .iter()
+            .enumerate()
+            .map(|(index, plane)| {
+                let length = plane.normal.length();
+                if !length.is_finite() || length == 0.0 || !plane.distance.is_finite() {
+                    return Err(ConvexError::DegeneratePlane(index));
+                }
+                Ok(Plane::new(plane.normal / length, plane.distance / length))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { planes })
+    }
+
+    /// Create the volume enclosed by a box
+    pub fn from_box(bbox: BoundingBox) -> Self {
+        Self {
+            planes: bbox.face_planes().to_vec(),
+        }
+    }
+
+    /// The bounding planes, normals pointing outward
+    pub fn planes(&self) -> &[Plane] {
+        &self.planes
+    }
+
+    /// Check if a point is inside the volume (boundary included)
+    pub fn contains_point(&self, point: Vector) -> bool {
+        self.planes.iter().all(|plane| plane.distance_to_point(point) <= 0.0)
+    }
+
+    /// Check if a box overlaps the volume
+    ///
+    /// Rejects the box only when it lies entirely in front of one plane, like
+    /// UE's `IntersectBox`. That is exact for boxes near faces but can report
+    /// an overlap for a box just outside an edge or corner of the volume, which
+    /// is the usual trade-off for frustum culling.
+    pub fn intersects_box(&self, bbox: BoundingBox) -> bool {
+        let (center, extent) = (bbox.center(), bbox.extent());
+        self.planes
+            .iter()
+            .all(|plane| plane.distance_to_point(center) <= extent.dot(plane.normal.abs()))
+    }
+}
+
+impl TryFrom<Vec<Plane>> for ConvexVolume {
+    type Error = ConvexError;
+
+    fn try_from(planes: Vec<Plane>) -> Result<Self, Self::Error> {
+        Self::from_frustum_planes(&planes)
+    }
+}
+
+impl From<ConvexVolume> for Vec<Plane> {
+    fn from(volume: ConvexVolume) -> Self {
+        volume.planes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v2(x: f64, y: f64) -> Vector2D {
+        Vector2D::new(x, y)
+    }
+
+    #[test]
+    fn test_hull_drops_interior_and_concave_points() {
+        // An arrow shape with a notch, interior points and a point mid-edge
+        let points = [
+            v2(0.0, 0.0),
+            v2(4.0, 0.0),
+            v2(2.0, 1.0), // notch
+            v2(4.0, 4.0),
+            v2(0.0, 4.0),
+            v2(2.0, 2.0), // interior
+            v2(1.0, 3.0), // interior
+            v2(2.0, 4.0), // on the top edge
+            v2(4.0, 0.0), // duplicate
+        ];
+        let hull = ConvexPolygon2D::from_points_convex_hull(&points).unwrap();
+        assert_eq!(hull.vertices(), &[v2(0.0, 0.0), v2(4.0, 0.0), v2(4.0, 4.0), v2(0.0, 4.0)]);
+        assert_eq!(hull.area(), 16.0);
+        assert!((hull.centroid() - v2(2.0, 2.0)).length() < 1e-12);
+
+        let triangle = ConvexPolygon2D::from_points_convex_hull(&[v2(0.0, 0.0), v2(3.0, 0.0), v2(0.0, 3.0)]).unwrap();
+        assert_eq!(triangle.area(), 4.5);
+        assert!((triangle.centroid() - v2(1.0, 1.0)).length() < 1e-12);
+        assert_eq!(format!("{}", triangle), "ConvexPolygon2D(3 vertices, Area: 4.50)");
+    }
+
+    #[test]
+    fn test_polygon_containment() {
+        let hull = ConvexPolygon2D::from_points_convex_hull(&[v2(0.0, 0.0), v2(4.0, 0.0), v2(4.0, 4.0), v2(0.0, 4.0)]).unwrap();
+        assert!(hull.contains_point(v2(2.0, 2.0)));
+        // Exactly on an edge and on a vertex
+        assert!(hull.contains_point(v2(4.0, 1.5)));
+        assert!(hull.contains_point(v2(2.0, 0.0)));
+        assert!(hull.contains_point(v2(0.0, 4.0)));
+        assert!(!hull.contains_point(v2(4.0 + 1e-9, 1.5)));
+        assert!(!hull.contains_point(v2(-1.0, 2.0)));
+
+        let diamond = ConvexPolygon2D::from_points_convex_hull(&[v2(0.0, -1.0), v2(1.0, 0.0), v2(0.0, 1.0), v2(-1.0, 0.0)]).unwrap();
+        assert!(diamond.contains_point(v2(0.5, 0.5)));
+        assert!(!diamond.contains_point(v2(0.75, 0.75)));
+    }
+
+    #[test]
+    fn test_degenerate_polygons() {
+        assert_eq!(ConvexPolygon2D::from_points_convex_hull(&[]), Err(ConvexError::TooFewPoints(0)));
+        assert_eq!(
+            ConvexPolygon2D::from_points_convex_hull(&[v2(0.0, 0.0), v2(1.0, 1.0)]),
+            Err(ConvexError::TooFewPoints(2))
+        );
+        assert_eq!(
+            ConvexPolygon2D::from_points_convex_hull(&[v2(0.0, 0.0), v2(1.0, 1.0), v2(3.0, 3.0), v2(2.0, 2.0)]),
+            Err(ConvexError::Collinear)
+        );
+        assert_eq!(
+            ConvexPolygon2D::from_points_convex_hull(&[v2(1.0, 1.0), v2(1.0, 1.0), v2(1.0, 1.0)]),
+            Err(ConvexError::Collinear)
+        );
+        assert_eq!(
+            ConvexPolygon2D::from_points_convex_hull(&[v2(0.0, 0.0), v2(1.0, f64::NAN), v2(0.0, 1.0)]),
+            Err(ConvexError::NonFinite)
+        );
+    }
+
+    #[test]
+    fn test_volume_from_box() {
+        let bbox = BoundingBox::new(Vector::new(-1.0, 0.0, 2.0), Vector::new(1.0, 5.0, 4.0));
+        let volume = ConvexVolume::from_box(bbox);
+        assert_eq!(volume.planes().len(), 6);
+        for point in [
+            Vector::new(0.0, 2.5, 3.0),
+            Vector::new(1.0, 5.0, 4.0),
+            Vector::new(-1.0, 0.0, 2.0),
+            Vector::new(1.1, 2.5, 3.0),
+            Vector::new(0.0, -0.1, 3.0),
+            Vector::new(0.0, 2.5, 4.5),
+        ] {
+            assert_eq!(volume.contains_point(point), bbox.contains_point(point), "{}", point);
+        }
+
+        assert!(volume.intersects_box(BoundingBox::new(Vector::new(0.5, 4.0, 3.5), Vector::new(3.0, 9.0, 9.0))));
+        assert!(volume.intersects_box(BoundingBox::new(Vector::new(1.0, 0.0, 0.0), Vector::new(2.0, 1.0, 2.0))));
+        assert!(!volume.intersects_box(BoundingBox::new(Vector::new(1.5, 0.0, 2.0), Vector::new(3.0, 1.0, 3.0))));
+        assert_eq!(format!("{}", volume), "ConvexVolume(6 planes)");
+    }
+
+    #[test]
+    fn test_volume_from_frustum_planes() {
+        // A wedge: x <= 10, and the two sides |y| <= x, with unnormalized normals
+        let planes = [
+            Plane::new(Vector::new(2.0, 0.0, 0.0), 20.0),
+            Plane::new(Vector::new(-1.0, 1.0, 0.0), 0.0),
+            Plane::new(Vector::new(-1.0, -1.0, 0.0), 0.0),
+        ];
+        let volume = ConvexVolume::from_frustum_planes(&planes).unwrap();
+        assert!(volume.planes().iter().all(|plane| (plane.normal.length() - 1.0).abs() < 1e-12));
+        assert!(volume.contains_point(Vector::new(5.0, 4.0, 100.0)));
+        assert!(volume.contains_point(Vector::new(10.0, 10.0, 0.0)));
+        assert!(!volume.contains_point(Vector::new(5.0, 6.0, 0.0)));
+        assert!(!volume.contains_point(Vector::new(11.0, 0.0, 0.0)));
+        assert!(volume.intersects_box(BoundingBox::from_center_and_extent(Vector::new(12.0, 0.0, 0.0), Vector::splat(2.5))));
+        assert!(!volume.intersects_box(BoundingBox::from_center_and_extent(Vector::new(-5.0, 0.0, 0.0), Vector::splat(1.0))));
+
+        assert_eq!(ConvexVolume::from_frustum_planes(&[]), Err(ConvexError::NoPlanes));
+        let mut degenerate = planes;
+        degenerate[1].normal = Vector::ZERO;
+        assert_eq!(ConvexVolume::from_frustum_planes(&degenerate), Err(ConvexError::DegeneratePlane(1)));
+        degenerate[1].normal = Vector::new(f64::INFINITY, 0.0, 0.0);
+        assert_eq!(ConvexVolume::from_frustum_planes(&degenerate), Err(ConvexError::DegeneratePlane(1)));
+    }
+
+    #[test]
+    fn test_convex_serialization() {
+        let hull = ConvexPolygon2D::from_points_convex_hull(&[v2(0.0, 0.0), v2(4.0, 0.0), v2(1.0, 1.0), v2(0.0, 4.0)]).unwrap();
+        let json = serde_json::to_string(&hull).unwrap();
+        assert_eq!(json, "[[0.0,0.0],[4.0,0.0],[0.0,4.0]]");
+        assert_eq!(serde_json::from_str::<ConvexPolygon2D>(&json).unwrap(), hull);
+        assert_eq!(ConvexPolygon2D::from_binary(&hull.to_binary().unwrap()).unwrap(), hull);
+        assert!(serde_json::from_str::<ConvexPolygon2D>("[[0.0,0.0],[1.0,1.0]]").is_err());
+
+        let volume = ConvexVolume::from_box(BoundingBox::new(Vector::ZERO, Vector::ONE));
+        let restored: ConvexVolume = serde_json::from_str(&serde_json::to_string(&volume).unwrap()).unwrap();
+        assert_eq!(restored, volume);
+        assert_eq!(ConvexVolume::from_binary(&volume.to_binary().unwrap()).unwrap(), volume);
+        assert!(serde_json::from_str::<ConvexVolume>("[]").is_err());
+    }
+}
@@ -0,0 +1,623 @@
+//! Keyframed float and vector curves for server-driven value animation (UE: `FRichCurve`)
+
+use crate::vector::Vector;
+use crate::BinarySerializable;
+use serde::{Deserialize, Serialize};
+use core::fmt;
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+use crate::types::math::float_math::FloatMath;
+
+/// Reason a key could not be added to a curve
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CurveError {
+    /// Key times must be finite
+    InvalidTime(f64),
+}
+
+impl fmt::Display for CurveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CurveError::InvalidTime(time) => write!(f, "curve key time {} is not finite", time),
+        }
+    }
+}
+
+impl core::error::Error for CurveError {}
+
+/// How a curve moves from one key to the next (UE: `ERichCurveInterpMode`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum CurveInterpMode {
+    /// Hold this key's value until the next key
+    Constant,
+    /// Straight line to the next key
+    #[default]
+    Linear,
+    /// Cubic Hermite spline using this key's leave tangent and the next key's arrive tangent
+    Cubic,
+}
+
+/// What a curve does before its first key or after its last (UE: `ERichCurveExtrapolation`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum CurveExtrapolation {
+    /// Hold the end key's value
+    #[default]
+    Constant,
+    /// Continue along the slope at the end key
+    Linear,
+    /// Repeat the keyed range
+    Cycle,
+}
+
+/// One keyframe on a `Curve` (UE: `FRichCurveKey`)
+///
+/// Tangents are slopes in value per unit time and only matter for cubic
+/// segments.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CurveKey {
+    /// Time of the key
+    pub time: f64,
+    /// Value at `time`
+    pub value: f64,
+    /// Interpolation from this key to the next
+    #[serde(default)]
+    pub interp_mode: CurveInterpMode,
+    /// Slope entering the key
+    #[serde(default)]
+    pub arrive_tangent: f64,
+    /// Slope leaving the key
+    #[serde(default)]
+    pub leave_tangent: f64,
+}
+
+impl CurveKey {
+    /// Create a linear key
+    pub fn new(time: f64, value: f64) -> Self {
+        Self {
+            time,
+            value,
+            interp_mode: CurveInterpMode::Linear,
+            arrive_tangent: 0.0,
+            leave_tangent: 0.0,
+        }
+    }
+
+    /// Create a key that holds its value until the next key
+    pub fn constant(time: f64, value: f64) -> Self {
+        Self {
+            interp_mode: CurveInterpMode::Constant,
+            ..Self::new(time, value)
+        }
+    }
+
+    /// Create a cubic key with separate arrive and leave tangents
+    pub fn cubic(time: f64, value: f64, arrive_tangent: f64, leave_tangent: f64) -> Self {
+        Self {
+            interp_mode: CurveInterpMode::Cubic,
+            arrive_tangent,
+            leave_tangent,
+            ..Self::new(time, value)
+        }
+    }
+}
+
+/// Serialized form of a `CurveWithExtrapolation`
+#[derive(Serialize, Deserialize)]
+struct CurveData {
+    keys: Vec<CurveKey>,
+    #[serde(default)]
+    pre_infinity: CurveExtrapolation,
+    #[serde(default)]
+    post_infinity: CurveExtrapolation,
+}
+
+/// A float value keyed over time (UE: `FRichCurve`)
+///
+/// Keys stay sorted by time, so `eval` finds the surrounding keys with a
+/// binary search. An empty curve evaluates to 0. Serializes as a bare array
+/// of its keys in time order; the extrapolation modes are not written and
+/// load back as `Constant`, so wrap the curve in [`CurveWithExtrapolation`]
+/// to keep them.
+///
+/// ```
+/// use ue_types::*;
+///
+/// let mut radius = Curve::new();
+/// radius.add_key(CurveKey::new(0.0, 5000.0)).unwrap();
+/// radius.add_key(CurveKey::new(60.0, 1000.0)).unwrap();
+///
+/// assert_eq!(radius.eval(30.0), 3000.0);
+/// assert_eq!(radius.eval(90.0), 1000.0); // held after the last key
+/// ```
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(into = "Vec<CurveKey>", try_from = "Vec<CurveKey>")]
+pub struct Curve {
+    keys: Vec<CurveKey>,
+    pre_infinity: CurveExtrapolation,
+    post_infinity: CurveExtrapolation,
+}
+
+impl fmt::Display for Curve {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.time_range() {
+            Some((start, end)) => write!(f, "Curve({} keys, Time: {:.3}..{:.3})", self.len(), start, end),
+            None => write!(f, "Curve(empty)"),
+        }
+    }
+}
+
+impl BinarySerializable for Curve {}
+
+impl Curve {
+    /// Create an empty curve that holds its end values
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a curve from keys in any order
+    pub fn from_keys(keys: impl IntoIterator<Item = CurveKey>) -> Result<Self, CurveError> {
+        let mut curve = Self::new();
+        for key in keys {
+            curve.add_key(key)?;
+        }
+        Ok(curve)
+    }
+
+    /// Set the extrapolation before the first key and after the last
+    pub fn with_extrapolation(mut self, pre_infinity: CurveExtrapolation, post_infinity: CurveExtrapolation) -> Self {
+        self.pre_infinity = pre_infinity;
+        self.post_infinity = post_infinity;
+        self
+    }
+
+    /// Extrapolation before the first key
+    pub fn pre_infinity(&self) -> CurveExtrapolation {
+        self.pre_infinity
+    }
+
+    /// Extrapolation after the last key
+    pub fn post_infinity(&self) -> CurveExtrapolation {
+        self.post_infinity
+    }
+
+    /// Number of keys
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Check if the curve has no keys
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// All keys in time order
+    pub fn keys(&self) -> &[CurveKey] {
+        &self.keys
+    }
+
+    /// Get a key by index
+    pub fn key(&self, index: usize) -> Option<&CurveKey> {
+        self.keys.get(index)
+    }
+
+    /// Add a key, returning its index
+    ///
+    /// A key at the same time as an existing one replaces it.
+    pub fn add_key(&mut self, key: CurveKey) -> Result<usize, CurveError> {
+        if !key.time.is_finite() {
+            return Err(CurveError::InvalidTime(key.time));
+        }
+        let index = self.keys.partition_point(|existing| existing.time < key.time);
+        match self.keys.get_mut(index) {
+            Some(existing) if existing.time == key.time => *existing = key,
+            _ => self.keys.insert(index, key),
+        }
+        Ok(index)
+    }
+
+    /// Remove a key by index
+    pub fn remove_key(&mut self, index: usize) -> Option<CurveKey> {
+        (index < self.keys.len()).then(|| self.keys.remove(index))
+    }
+
+    /// Index of the key within `tolerance` of `time`, preferring the closest
+    pub fn find_key(&self, time: f64, tolerance: f64) -> Option<usize> {
+        let index = self.keys.partition_point(|key| key.time < time);
+        let before = index.checked_sub(1).map(|i| (i, time - self.keys[i].time));
+        let after = self.keys.get(index).map(|key| (index, key.time - time));
+        [before, after]
+            .into_iter()
+            .flatten()
+            .filter(|&(_, distance)| distance <= tolerance)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(i, _)| i)
+    }
+
+    /// Times of the first and last keys
+    pub fn time_range(&self) -> Option<(f64, f64)> {
+        Some((self.keys.first()?.time, self.keys.last()?.time))
+    }
+
+    /// Smallest and largest values between the first and last keys
+    ///
+    /// Includes cubic overshoot between keys, not just the key values.
+    pub fn min_max_values(&self) -> Option<(f64, f64)> {
+        let first = self.keys.first()?.value;
+        let mut range = (first, first);
+        let mut include = |value: f64| range = (range.0.min(value), range.1.max(value));
+        for pair in self.keys.windows(2) {
+            include(pair[1].value);
+            if pair[0].interp_mode == CurveInterpMode::Cubic {
+                let [p0, p1, p2, p3] = bezier_points(&pair[0], &pair[1]);
+                for s in bezier_extrema(p0, p1, p2, p3).into_iter().flatten() {
+                    include(bezier(p0, p1, p2, p3, s));
+                }
+            }
+        }
+        Some(range)
+    }
+
+    /// Value of the curve at `time`
+    pub fn eval(&self, time: f64) -> f64 {
+        let (Some(first), Some(last)) = (self.keys.first(), self.keys.last()) else {
+            return 0.0;
+        };
+        if time < first.time {
+            return match self.pre_infinity {
+                CurveExtrapolation::Constant => first.value,
+                CurveExtrapolation::Linear => first.value - self.start_slope() * (first.time - time),
+                CurveExtrapolation::Cycle => self.eval_cycled(time),
+            };
+        }
+        if time > last.time {
+            return match self.post_infinity {
+                CurveExtrapolation::Constant => last.value,
+                CurveExtrapolation::Linear => last.value + self.end_slope() * (time - last.time),
+                CurveExtrapolation::Cycle => self.eval_cycled(time),
+            };
+        }
+        self.eval_within(time)
+    }
+
+    /// Evaluate at a time between the first and last keys
+    fn eval_within(&self, time: f64) -> f64 {
+        let next = self.keys.partition_point(|key| key.time <= time);
+        if next == 0 || next == self.keys.len() {
+            // On the first key of a single-key curve, or exactly on the last key
+            return self.keys[next.saturating_sub(1)].value;
+        }
+        let (start, end) = (&self.keys[next - 1], &self.keys[next]);
+        let alpha = (time - start.time) / (end.time - start.time);
+        match start.interp_mode {
+            CurveInterpMode::Constant => start.value,
+            CurveInterpMode::Linear => start.value + (end.value - start.value) * alpha,
+            CurveInterpMode::Cubic => {
+                let [p0, p1, p2, p3] = bezier_points(start, end);
+                bezier(p0, p1, p2, p3, alpha)
+            }
+        }
+    }
+
+    /// Wrap `time` into the keyed range and evaluate there
+    fn eval_cycled(&self, time: f64) -> f64 {
+        let Some((start, end)) = self.time_range() else {
+            return 0.0;
+        };
+        let duration = end - start;
+        if duration <= 0.0 {
+            return self.keys[0].value;
+        }
+        self.eval_within(start + (time - start).rem_euclid(duration))
+    }
+
+    /// Slope leaving the first key
+    fn start_slope(&self) -> f64 {
+        match self.keys.as_slice() {
+            [first, second, ..] => segment_slope(first, second, first.leave_tangent),
+            _ => 0.0,
+        }
+    }
+
+    /// Slope arriving at the last key
+    fn end_slope(&self) -> f64 {
+        match self.keys.as_slice() {
+            [.., before_last, last] => segment_slope(before_last, last, last.arrive_tangent),
+            _ => 0.0,
+        }
+    }
+}
+
+/// The keys in time order, dropping the extrapolation modes
+impl From<Curve> for Vec<CurveKey> {
+    fn from(curve: Curve) -> Self {
+        curve.keys
+    }
+}
+
+/// Same as `Curve::from_keys`
+impl TryFrom<Vec<CurveKey>> for Curve {
+    type Error = CurveError;
+
+    fn try_from(keys: Vec<CurveKey>) -> Result<Self, Self::Error> {
+        Self::from_keys(keys)
+    }
+}
+
+/// A `Curve` that serializes with its extrapolation modes
+///
+/// Writes `{"keys": [...], "pre_infinity": ..., "post_infinity": ...}`.
+/// Missing modes load as `Constant`.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(into = "CurveData", try_from = "CurveData")]
+pub struct CurveWithExtrapolation(pub Curve);
+
+impl BinarySerializable for CurveWithExtrapolation {}
+
+impl From<CurveWithExtrapolation> for CurveData {
+    fn from(CurveWithExtrapolation(curve): CurveWithExtrapolation) -> Self {
+        Self {
+            keys: curve.keys,
+            pre_infinity: curve.pre_infinity,
+            post_infinity: curve.post_infinity,
+        }
+    }
+}
+
+impl TryFrom<CurveData> for CurveWithExtrapolation {
+    type Error = CurveError;
+
+    fn try_from(data: CurveData) -> Result<Self, Self::Error> {
+        let curve = Curve::from_keys(data.keys)?.with_extrapolation(data.pre_infinity, data.post_infinity);
+        Ok(Self(curve))
+    }
+}
+
+/// Slope at an end of the segment from `start` to `end`, given the cubic tangent there
+fn segment_slope(start: &CurveKey, end: &CurveKey, cubic_tangent: f64) -> f64 {
+    match start.interp_mode {
+        CurveInterpMode::Constant => 0.0,
+        CurveInterpMode::Linear => (end.value - start.value) / (end.time - start.time),
+        CurveInterpMode::Cubic => cubic_tangent,
+    }
+}
+
+/// Bezier control values equivalent to the Hermite segment between two keys
+fn bezier_points(start: &CurveKey, end: &CurveKey) -> [f64; 4] {
+    let third = (end.time - start.time) / 3.0;
+    [
+        start.value,
+        start.value + start.leave_tangent * third,
+        end.value - end.arrive_tangent * third,
+        end.value,
+    ]
+}
+
+fn bezier(p0: f64, p1: f64, p2: f64, p3: f64, s: f64) -> f64 {
+    let t = 1.0 - s;
+    t * t * t * p0 + 3.0 * t * t * s * p1 + 3.0 * t * s * s * p2 + s * s * s * p3
+}
+
+/// Parameters in (0, 1) where a cubic Bezier's derivative is zero
+fn bezier_extrema(p0: f64, p1: f64, p2: f64, p3: f64) -> [Option<f64>; 2] {
+    let (d0, d1, d2) = (p1 - p0, p2 - p1, p3 - p2);
+    let a = d0 - 2.0 * d1 + d2;
+    let b = 2.0 * (d1 - d0);
+    let c = d0;
+    let inside = |s: f64| (s > 0.0 && s < 1.0).then_some(s);
+    if a.abs() < 1e-12 {
+        return [if b == 0.0 { None } else { inside(-c / b) }, None];
+    }
+    let discriminant = b * b - 4.0 * a * c;
+    if discriminant < 0.0 {
+        return [None, None];
+    }
+    let root = discriminant.sqrt();
+    [inside((-b - root) / (2.0 * a)), inside((-b + root) / (2.0 * a))]
+}
+
+/// A vector keyed over time, one `Curve` per component (UE: `FVectorCurve`)
+///
+/// Serializes as `{"x": [...], "y": [...], "z": [...]}`, each component a
+/// bare key array, so the components' extrapolation modes are not kept.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct VectorCurve {
+    /// X component
+    pub x: Curve,
+    /// Y component
+    pub y: Curve,
+    /// Z component
+    pub z: Curve,
+}
+
+impl fmt::Display for VectorCurve {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "VectorCurve(X: {}, Y: {}, Z: {})", self.x, self.y, self.z)
+    }
+}
+
+impl BinarySerializable for VectorCurve {}
+
+impl VectorCurve {
+    /// Create a vector curve from its component curves
+    pub fn new(x: Curve, y: Curve, z: Curve) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Add a linear key to every component
+    pub fn add_key(&mut self, time: f64, value: Vector) -> Result<(), CurveError> {
+        self.x.add_key(CurveKey::new(time, value.x))?;
+        self.y.add_key(CurveKey::new(time, value.y))?;
+        self.z.add_key(CurveKey::new(time, value.z))?;
+        Ok(())
+    }
+
+    /// Value of the curve at `time`
+    pub fn eval(&self, time: f64) -> Vector {
+        Vector::new(self.x.eval(time), self.y.eval(time), self.z.eval(time))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn linear(points: &[(f64, f64)]) -> Curve {
+        Curve::from_keys(points.iter().map(|&(time, value)| CurveKey::new(time, value))).unwrap()
+    }
+
+    #[test]
+    fn test_constant_keys_step() {
+        let curve = Curve::from_keys([CurveKey::constant(0.0, 1.0), CurveKey::constant(2.0, 5.0), CurveKey::constant(4.0, 3.0)]).unwrap();
+        assert_eq!(curve.eval(0.0), 1.0);
+        assert_eq!(curve.eval(1.999), 1.0);
+        assert_eq!(curve.eval(2.0), 5.0);
+        assert_eq!(curve.eval(3.5), 5.0);
+        assert_eq!(curve.eval(4.0), 3.0);
+    }
+
+    #[test]
+    fn test_exact_hits_on_key_times() {
+        let curve = Curve::from_keys([
+            CurveKey::new(0.0, 10.0),
+            CurveKey::cubic(1.0, -4.0, 3.0, -7.0),
+            CurveKey::constant(2.5, 8.0),
+            CurveKey::new(3.0, 0.5),
+        ])
+        .unwrap();
+        for key in curve.keys() {
+            assert_eq!(curve.eval(key.time), key.value);
+        }
+        assert_eq!(curve.eval(0.5), 3.0);
+    }
+
+    #[test]
+    fn test_cubic_smooth_across_key() {
+        let curve = Curve::from_keys([
+            CurveKey::cubic(0.0, 0.0, 0.0, 4.0),
+            CurveKey::cubic(1.0, 2.0, 1.5, 1.5),
+            CurveKey::cubic(3.0, 1.0, -2.0, 0.0),
+        ])
+        .unwrap();
+
+        let h = 1e-6;
+        let left = (curve.eval(1.0) - curve.eval(1.0 - h)) / h;
+        let right = (curve.eval(1.0 + h) - curve.eval(1.0)) / h;
+        assert!((left - 1.5).abs() < 1e-4, "left slope {}", left);
+        assert!((right - 1.5).abs() < 1e-4, "right slope {}", right);
+        assert!((curve.eval(1.0 - h) - 2.0).abs() < 1e-5);
+        assert!((curve.eval(1.0 + h) - 2.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_cycle_extrapolation() {
+        let curve = linear(&[(1.0, 0.0), (3.0, 10.0)]).with_extrapolation(CurveExtrapolation::Cycle, CurveExtrapolation::Cycle);
+        assert_eq!(curve.eval(4.0), 5.0);
+        assert_eq!(curve.eval(5.0), 0.0);
+        assert_eq!(curve.eval(8.5), 7.5);
+        assert_eq!(curve.eval(0.0), 5.0);
+        assert_eq!(curve.eval(-3.0), 0.0);
+        assert_eq!(curve.eval(3.0), 10.0);
+    }
+
+    #[test]
+    fn test_constant_and_linear_extrapolation() {
+        let curve = linear(&[(0.0, 0.0), (2.0, 4.0), (4.0, 5.0)]);
+        assert_eq!(curve.eval(-10.0), 0.0);
+        assert_eq!(curve.eval(10.0), 5.0);
+
+        let curve = curve.with_extrapolation(CurveExtrapolation::Linear, CurveExtrapolation::Linear);
+        assert_eq!(curve.eval(-1.0), -2.0);
+        assert_eq!(curve.eval(6.0), 6.0);
+
+        let cubic = Curve::from_keys([CurveKey::cubic(0.0, 1.0, 0.0, 3.0), CurveKey::cubic(1.0, 2.0, -1.0, 0.0)])
+            .unwrap()
+            .with_extrapolation(CurveExtrapolation::Linear, CurveExtrapolation::Linear);
+        assert_eq!(cubic.eval(-1.0), -2.0);
+        assert_eq!(cubic.eval(2.0), 1.0);
+
+        let single = linear(&[(1.0, 7.0)]).with_extrapolation(CurveExtrapolation::Linear, CurveExtrapolation::Cycle);
+        assert_eq!(single.eval(-5.0), 7.0);
+        assert_eq!(single.eval(5.0), 7.0);
+        assert_eq!(Curve::new().eval(1.0), 0.0);
+    }
+
+    #[test]
+    fn test_keys_stay_sorted() {
+        let mut curve = linear(&[(3.0, 30.0), (1.0, 10.0), (2.0, 20.0)]);
+        let times: Vec<f64> = curve.keys().iter().map(|key| key.time).collect();
+        assert_eq!(times, vec![1.0, 2.0, 3.0]);
+
+        // Same time replaces the key
+        assert_eq!(curve.add_key(CurveKey::new(2.0, -1.0)), Ok(1));
+        assert_eq!(curve.len(), 3);
+        assert_eq!(curve.eval(2.0), -1.0);
+
+        assert_eq!(curve.add_key(CurveKey::new(f64::NAN, 0.0)).unwrap_err().to_string(), "curve key time NaN is not finite");
+        assert_eq!(curve.remove_key(0), Some(CurveKey::new(1.0, 10.0)));
+        assert_eq!(curve.remove_key(5), None);
+        assert_eq!(curve.time_range(), Some((2.0, 3.0)));
+    }
+
+    #[test]
+    fn test_find_key() {
+        let curve = linear(&[(0.0, 0.0), (1.0, 1.0), (1.5, 2.0)]);
+        assert_eq!(curve.find_key(1.0, 0.0), Some(1));
+        assert_eq!(curve.find_key(1.3, 0.25), Some(2));
+        assert_eq!(curve.find_key(1.2, 0.25), Some(1));
+        assert_eq!(curve.find_key(0.5, 0.1), None);
+        assert_eq!(curve.find_key(-0.05, 0.1), Some(0));
+        assert_eq!(Curve::new().find_key(0.0, 1.0), None);
+    }
+
+    #[test]
+    fn test_min_max_values() {
+        assert_eq!(Curve::new().min_max_values(), None);
+        assert_eq!(linear(&[(0.0, 3.0), (1.0, -2.0), (2.0, 5.0)]).min_max_values(), Some((-2.0, 5.0)));
+
+        // Overshoots above both keys
+        let cubic = Curve::from_keys([CurveKey::cubic(0.0, 0.0, 0.0, 3.0), CurveKey::cubic(1.0, 0.0, -3.0, 0.0)]).unwrap();
+        let (min, max) = cubic.min_max_values().unwrap();
+        assert_eq!(min, 0.0);
+        assert!((max - cubic.eval(0.5)).abs() < 1e-12);
+        assert!(max > 0.7);
+    }
+
+    #[test]
+    fn test_serialization() {
+        let curve = Curve::from_keys([CurveKey::constant(0.0, 1.0), CurveKey::cubic(1.0, 2.0, 0.5, -0.5), CurveKey::new(2.0, 0.0)])
+            .unwrap()
+            .with_extrapolation(CurveExtrapolation::Linear, CurveExtrapolation::Cycle);
+        let keys_only = curve.clone().with_extrapolation(CurveExtrapolation::Constant, CurveExtrapolation::Constant);
+
+        // A bare curve is just its keys
+        let json = serde_json::to_string(&curve).unwrap();
+        assert!(json.starts_with(r#"[{"time":0.0,"value":1.0,"interp_mode":"Constant""#));
+        assert_eq!(serde_json::from_str::<Curve>(&json).unwrap(), keys_only);
+        assert_eq!(Curve::from_binary(&curve.to_binary().unwrap()).unwrap(), keys_only);
+
+        // The wrapper keeps the extrapolation modes
+        let wrapped = CurveWithExtrapolation(curve.clone());
+        let json = serde_json::to_string(&wrapped).unwrap();
+        assert!(json.ends_with(r#""pre_infinity":"Linear","post_infinity":"Cycle"}"#));
+        assert_eq!(serde_json::from_str::<CurveWithExtrapolation>(&json).unwrap(), wrapped);
+        assert_eq!(CurveWithExtrapolation::from_binary(&wrapped.to_binary().unwrap()).unwrap(), wrapped);
+        let defaulted: CurveWithExtrapolation = serde_json::from_str(r#"{"keys":[{"time":0,"value":1}]}"#).unwrap();
+        assert_eq!(defaulted.0.post_infinity(), CurveExtrapolation::Constant);
+
+        // Keys may be given out of order; interpolation and tangents default
+        let loaded: Curve = serde_json::from_str(r#"[{"time":4,"value":8},{"time":0,"value":0}]"#).unwrap();
+        assert_eq!(loaded, linear(&[(0.0, 0.0), (4.0, 8.0)]));
+        assert!(serde_json::from_str::<Curve>(r#"[{"time":1e999,"value":0}]"#).is_err());
+        assert!(serde_json::from_str::<CurveWithExtrapolation>(r#"{"keys":[{"time":1e999,"value":0}]}"#).is_err());
+    }
+
+    #[test]
+    fn test_vector_curve() {
+        let mut curve = VectorCurve::default();
+        curve.add_key(0.0, Vector::ZERO).unwrap();
+        curve.add_key(2.0, Vector::new(2.0, -4.0, 8.0)).unwrap();
+        assert_eq!(curve.eval(1.0), Vector::new(1.0, -2.0, 4.0));
+        assert_eq!(curve.eval(5.0), Vector::new(2.0, -4.0, 8.0));
+        assert_eq!(VectorCurve::from_binary(&curve.to_binary().unwrap()).unwrap(), curve);
+    }
+}
@@ -0,0 +1,78 @@
+//! Scalar float functions for `no_std` builds
+//!
+//! `core` leaves out the float methods that need a math library. This trait
+//! puts `libm` behind the same method names, so code written against `std`
+//! compiles unchanged once it is imported.
+
+/// The `std`-only float methods the crate uses, backed by `libm`
+pub(crate) trait FloatMath: Sized {
+    fn sqrt(self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn fract(self) -> Self;
+    fn powf(self, exponent: Self) -> Self;
+    fn powi(self, exponent: i32) -> Self;
+    fn rem_euclid(self, rhs: Self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn asin(self) -> Self;
+    fn acos(self) -> Self;
+}
+
+macro_rules! impl_float_math {
+    ($t:ty, $sqrt:ident, $floor:ident, $ceil:ident, $round:ident, $trunc:ident, $pow:ident, $atan2:ident, $asin:ident, $acos:ident) => {
+        impl FloatMath for $t {
+            fn sqrt(self) -> Self {
+                libm::$sqrt(self)
+            }
+
+            fn floor(self) -> Self {
+                libm::$floor(self)
+            }
+
+            fn ceil(self) -> Self {
+                libm::$ceil(self)
+            }
+
+            fn round(self) -> Self {
+                libm::$round(self)
+            }
+
+            fn fract(self) -> Self {
+                self - libm::$trunc(self)
+            }
+
+            fn powf(self, exponent: Self) -> Self {
+                libm::$pow(self, exponent)
+            }
+
+            fn powi(self, exponent: i32) -> Self {
+                libm::$pow(self, exponent as $t)
+            }
+
+            fn rem_euclid(self, rhs: Self) -> Self {
+                let remainder = self % rhs;
+                if remainder < 0.0 {
+                    remainder + rhs.abs()
+                } else {
+                    remainder
+                }
+            }
+
+            fn atan2(self, other: Self) -> Self {
+                libm::$atan2(self, other)
+            }
+
+            fn asin(self) -> Self {
+                libm::$asin(self)
+            }
+
+            fn acos(self) -> Self {
+                libm::$acos(self)
+            }
+        }
+    };
+}
+
+impl_float_math!(f32, sqrtf, floorf, ceilf, roundf, truncf, powf, atan2f, asinf, acosf);
+impl_float_math!(f64, sqrt, floor, ceil, round, trunc, pow, atan2, asin, acos);
@@ -0,0 +1,345 @@
+//! Grid flood fill and connected-region labelling
+//!
+//! All searches are iterative (explicit queue) and bounded by the provided
+//! rectangle or box, so they are safe to run on large occupancy maps.
+
+use super::{IntBox, IntRect, IntVector, IntVector2};
+use crate::containers::{TMap, TSet};
+use serde::{Deserialize, Serialize};
+use alloc::collections::VecDeque;
+use alloc::vec;
+
+/// Neighbourhood used when stepping between 2D grid cells
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Connectivity {
+    /// Orthogonal neighbours only (no diagonal moves)
+    Four,
+    /// Orthogonal and diagonal neighbours
+    Eight,
+}
+
+impl Connectivity {
+    /// Get the neighbour offsets for this connectivity
+    pub fn offsets(self) -> &'static [IntVector2] {
+        const FOUR: [IntVector2; 4] = [
+            IntVector2 { x: 1, y: 0 },
+            IntVector2 { x: -1, y: 0 },
+            IntVector2 { x: 0, y: 1 },
+            IntVector2 { x: 0, y: -1 },
+        ];
+        const EIGHT: [IntVector2; 8] = [
+            IntVector2 { x: 1, y: 0 },
+            IntVector2 { x: -1, y: 0 },
+            IntVector2 { x: 0, y: 1 },
+            IntVector2 { x: 0, y: -1 },
+            IntVector2 { x: 1, y: 1 },
+            IntVector2 { x: 1, y: -1 },
+            IntVector2 { x: -1, y: 1 },
+            IntVector2 { x: -1, y: -1 },
+        ];
+        match self {
+            Connectivity::Four => &FOUR,
+            Connectivity::Eight => &EIGHT,
+        }
+    }
+}
+
+/// Neighbour offsets for 6-connectivity on a 3D grid
+const SIX: [IntVector; 6] = [
+    IntVector { x: 1, y: 0, z: 0 },
+    IntVector { x: -1, y: 0, z: 0 },
+    IntVector { x: 0, y: 1, z: 0 },
+    IntVector { x: 0, y: -1, z: 0 },
+    IntVector { x: 0, y: 0, z: 1 },
+    IntVector { x: 0, y: 0, z: -1 },
+];
+
+/// Dense row-major index of a cell inside a rect (caller checks containment)
+fn rect_index(bounds: IntRect, cell: IntVector2) -> usize {
+    (cell.y - bounds.min.y) as usize * bounds.width() as usize + (cell.x - bounds.min.x) as usize
+}
+
+/// Dense index of a cell inside a box (caller checks containment)
+fn box_index(bounds: IntBox, cell: IntVector) -> usize {
+    let size = bounds.size();
+    let x = (cell.x - bounds.min.x) as usize;
+    let y = (cell.y - bounds.min.y) as usize;
+    let z = (cell.z - bounds.min.z) as usize;
+    (z * size.y as usize + y) * size.x as usize + x
+}
+
+/// Breadth-first fill from `start`, marking cells in `visited` and calling `visit` for each
+fn fill_2d(
+    start: IntVector2,
+    is_walkable: &impl Fn(IntVector2) -> bool,
+    bounds: IntRect,
+    connectivity: Connectivity,
+    visited: &mut [bool],
+    mut visit: impl FnMut(IntVector2),
+) {
+    let mut queue = VecDeque::new();
+    visited[rect_index(bounds, start)] = true;
+    queue.push_back(start);
+
+    while let Some(cell) = queue.pop_front() {
+        visit(cell);
+        for &offset in connectivity.offsets() {
+            // Cells on the edge of the i32 range have no neighbour past it
+            let Some(next) = cell.checked_add(offset).filter(|&next| bounds.contains(next)) else {
+                continue;
+            };
+            let index = rect_index(bounds, next);
+            if !visited[index] && is_walkable(next) {
+                visited[index] = true;
+                queue.push_back(next);
+            }
+        }
+    }
+}
+
+/// Get every cell reachable from `start` through walkable cells inside `bounds`
+///
+/// Returns an empty set if `start` is outside the bounds or not walkable.
+pub fn reachable_from(
+    start: IntVector2,
+    is_walkable: impl Fn(IntVector2) -> bool,
+    bounds: IntRect,
+    connectivity: Connectivity,
+) -> TSet<IntVector2> {
+    let mut reachable = TSet::new();
+    if !bounds.contains(start) || !is_walkable(start) {
+        return reachable;
+    }
+
+    let mut visited = vec![false; bounds.area() as usize];
+    fill_2d(start, &is_walkable, bounds, connectivity, &mut visited, |cell| {
+        reachable.add(cell);
+    });
+    reachable
+}
+
+/// Assign a region id to every walkable cell inside `bounds` (connected components)
+///
+/// Region ids start at 0 and are assigned in row-major scan order (by y, then x),
+/// so labelling the same map always produces the same ids.
+pub fn region_label(
+    bounds: IntRect,
+    is_walkable: impl Fn(IntVector2) -> bool,
+    connectivity: Connectivity,
+) -> TMap<IntVector2, u32> {
+    let mut labels = TMap::new();
+    let mut visited = vec![false; bounds.area() as usize];
+    let mut next_region = 0u32;
+
+    for y in bounds.min.y..bounds.max.y {
+        for x in bounds.min.x..bounds.max.x {
+            let cell = IntVector2::new(x, y);
+            if visited[rect_index(bounds, cell)] || !is_walkable(cell) {
+                continue;
+            }
+            let region = next_region;
+            fill_2d(cell, &is_walkable, bounds, connectivity, &mut visited, |member| {
+                labels.add(member, region);
+            });
+            next_region += 1;
+        }
+    }
+    labels
+}
+
+/// Get the cells of the largest connected walkable region inside `bounds`
+///
+/// Ties are broken in favour of the region found first in row-major order.
+/// Returns an empty set if no cell is walkable.
+pub fn largest_region(
+    bounds: IntRect,
+    is_walkable: impl Fn(IntVector2) -> bool,
+    connectivity: Connectivity,
+) -> TSet<IntVector2> {
+    let mut largest = TSet::new();
+    let mut visited = vec![false; bounds.area() as usize];
+
+    for y in bounds.min.y..bounds.max.y {
+        for x in bounds.min.x..bounds.max.x {
+            let cell = IntVector2::new(x, y);
+            if visited[rect_index(bounds, cell)] || !is_walkable(cell) {
+                continue;
+            }
+            let mut region = TSet::new();
+            fill_2d(cell, &is_walkable, bounds, connectivity, &mut visited, |member| {
+                region.add(member);
+            });
+            if region.len() > largest.len() {
+                largest = region;
+            }
+        }
+    }
+    largest
+}
+
+/// Get every voxel reachable from `start` through walkable voxels inside `bounds`
+/// using 6-connectivity (face neighbours only)
+pub fn reachable_from_3d(
+    start: IntVector,
+    is_walkable: impl Fn(IntVector) -> bool,
+    bounds: IntBox,
+) -> TSet<IntVector> {
+    let mut reachable = TSet::new();
+    if !bounds.contains(start) || !is_walkable(start) {
+        return reachable;
+    }
+
+    let mut visited = vec![false; bounds.volume() as usize];
+    let mut queue = VecDeque::new();
+    visited[box_index(bounds, start)] = true;
+    queue.push_back(start);
+
+    while let Some(cell) = queue.pop_front() {
+        reachable.add(cell);
+        for &offset in &SIX {
+            // Cells on the edge of the i32 range have no neighbour past it
+            let Some(next) = cell.checked_add(offset).filter(|&next| bounds.contains(next)) else {
+                continue;
+            };
+            let index = box_index(bounds, next);
+            if !visited[index] && is_walkable(next) {
+                visited[index] = true;
+                queue.push_back(next);
+            }
+        }
+    }
+    reachable
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build a walkability function from an ASCII map ('.' walkable, '#' blocked)
+    fn ascii_map(rows: &[&str]) -> (IntRect, impl Fn(IntVector2) -> bool) {
+        let grid: Vec<Vec<bool>> = rows
+            .iter()
+            .map(|row| row.chars().map(|c| c == '.').collect())
+            .collect();
+        let bounds = IntRect::from_size(grid[0].len() as i32, grid.len() as i32);
+        (bounds, move |p: IntVector2| grid[p.y as usize][p.x as usize])
+    }
+
+    fn region_count(labels: &TMap<IntVector2, u32>) -> usize {
+        labels.values().collect::<TSet<_>>().len()
+    }
+
+    #[test]
+    fn test_component_counts() {
+        let (bounds, walkable) = ascii_map(&[
+            "..#..",
+            "..#..",
+            "#####",
+            ".#.#.",
+        ]);
+
+        let labels = region_label(bounds, &walkable, Connectivity::Four);
+        assert_eq!(labels.len(), 11);
+        assert_eq!(region_count(&labels), 5);
+
+        let largest = largest_region(bounds, &walkable, Connectivity::Four);
+        assert_eq!(largest.len(), 4);
+        assert!(largest.contains(&IntVector2::new(0, 0)));
+    }
+
+    #[test]
+    fn test_diagonal_gap_connectivity() {
+        let (bounds, walkable) = ascii_map(&[
+            ".#",
+            "#.",
+        ]);
+
+        let four = reachable_from(IntVector2::new(0, 0), &walkable, bounds, Connectivity::Four);
+        assert_eq!(four.len(), 1);
+
+        let eight = reachable_from(IntVector2::new(0, 0), &walkable, bounds, Connectivity::Eight);
+        assert_eq!(eight.len(), 2);
+        assert!(eight.contains(&IntVector2::new(1, 1)));
+
+        assert_eq!(region_count(&region_label(bounds, &walkable, Connectivity::Four)), 2);
+        assert_eq!(region_count(&region_label(bounds, &walkable, Connectivity::Eight)), 1);
+    }
+
+    #[test]
+    fn test_reachable_respects_bounds_and_start() {
+        let bounds = IntRect::new(IntVector2::new(-2, -2), IntVector2::new(3, 3));
+        let open = |_: IntVector2| true;
+
+        let reachable = reachable_from(IntVector2::ZERO, open, bounds, Connectivity::Four);
+        assert_eq!(reachable.len(), 25);
+        assert!(!reachable.contains(&IntVector2::new(3, 0)));
+
+        let outside = reachable_from(IntVector2::new(10, 10), open, bounds, Connectivity::Four);
+        assert!(outside.is_empty());
+
+        let blocked = reachable_from(IntVector2::ZERO, |_| false, bounds, Connectivity::Eight);
+        assert!(blocked.is_empty());
+    }
+
+    #[test]
+    fn test_fill_at_integer_limits() {
+        let low = IntRect::new(IntVector2::new(i32::MIN, i32::MIN), IntVector2::new(i32::MIN + 2, i32::MIN + 2));
+        let reachable = reachable_from(low.min, |_| true, low, Connectivity::Eight);
+        assert_eq!(reachable.len(), 4);
+        assert_eq!(region_label(low, |_| true, Connectivity::Four).len(), 4);
+
+        let high = IntBox::new(IntVector::new(i32::MAX - 2, i32::MAX - 2, i32::MIN), IntVector::new(i32::MAX, i32::MAX, i32::MIN + 2));
+        let corner = IntVector::new(i32::MAX - 1, i32::MAX - 1, i32::MIN);
+        assert_eq!(reachable_from_3d(corner, |_| true, high).len(), 8);
+    }
+
+    #[test]
+    fn test_large_map_completes() {
+        let bounds = IntRect::from_size(1000, 1000);
+        // A serpentine wall layout forces a single very long path
+        let walkable = |p: IntVector2| {
+            if p.y % 2 == 0 {
+                true
+            } else if (p.y / 2) % 2 == 0 {
+                p.x == 999
+            } else {
+                p.x == 0
+            }
+        };
+
+        let reachable = reachable_from(IntVector2::ZERO, walkable, bounds, Connectivity::Four);
+        assert_eq!(reachable.len(), 500 * 1000 + 500);
+    }
+
+    #[test]
+    fn test_region_label_determinism() {
+        let (bounds, walkable) = ascii_map(&[
+            ".#.#.",
+            ".#.#.",
+            "...#.",
+        ]);
+
+        let first = region_label(bounds, &walkable, Connectivity::Four);
+        let second = region_label(bounds, &walkable, Connectivity::Four);
+        assert_eq!(first, second);
+
+        assert_eq!(first.find(&IntVector2::new(0, 0)), Some(&0));
+        assert_eq!(first.find(&IntVector2::new(2, 0)), Some(&0));
+        assert_eq!(first.find(&IntVector2::new(4, 0)), Some(&1));
+        assert_eq!(first.find(&IntVector2::new(1, 0)), None);
+    }
+
+    #[test]
+    fn test_reachable_3d_six_connectivity() {
+        let bounds = IntBox::new(IntVector::ZERO, IntVector::new(3, 3, 3));
+        // Only cells on the main diagonal plus the origin's face neighbours are walkable
+        let walkable = |p: IntVector| (p.x == p.y && p.y == p.z) || p.manhattan_distance(IntVector::ZERO) <= 1;
+
+        let reachable = reachable_from_3d(IntVector::ZERO, walkable, bounds);
+        assert_eq!(reachable.len(), 4);
+        assert!(!reachable.contains(&IntVector::new(1, 1, 1)));
+
+        let open = reachable_from_3d(IntVector::ZERO, |_| true, bounds);
+        assert_eq!(open.len(), 27);
+    }
+}
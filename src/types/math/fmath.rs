@@ -0,0 +1,257 @@
+//! Scalar math helpers mirroring UE's `FMath` utilities
+//!
+//! All functions are generic over [`Float`], so they work with both `f32`
+//! and `f64`. Degenerate inputs (zero-width ranges, zero or negative grid
+//! sizes) produce well-defined results instead of NaN.
+//!
+//! The crate root re-exports the distinctively named helpers; `Float`,
+//! `lerp` and `wrap` are reached through this module (`fmath::lerp`).
+//!
+//! ```
+//! use ue_types::*;
+//!
+//! assert_eq!(grid_snap(137.0, 50.0), 150.0);
+//! assert_eq!(fmath::lerp(0.0, 10.0, 0.5), 5.0);
+//! ```
+//!
+//! ```compile_fail
+//! use ue_types::*;
+//!
+//! let mid = lerp(0.0, 10.0, 0.5);
+//! ```
+
+use core::ops::{Add, Div, Mul, Neg, Sub};
+#[cfg(not(feature = "std"))]
+use crate::types::math::float_math::FloatMath;
+
+/// Minimal floating point abstraction used by the `fmath` helpers
+pub trait Float:
+    Copy
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    /// Additive identity
+    const ZERO: Self;
+    /// Multiplicative identity
+    const ONE: Self;
+    /// One half
+    const HALF: Self;
+
+    /// Absolute value
+    fn abs(self) -> Self;
+    /// Largest integer less than or equal to `self`
+    fn floor(self) -> Self;
+    /// Euclidean remainder (always non-negative for a positive divisor)
+    fn rem_euclid(self, rhs: Self) -> Self;
+    /// Neither infinite nor NaN
+    fn is_finite(self) -> bool;
+}
+
+macro_rules! impl_float {
+    ($t:ty) => {
+        impl Float for $t {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+            const HALF: Self = 0.5;
+
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+
+            fn floor(self) -> Self {
+                #[cfg(feature = "std")]
+                return <$t>::floor(self);
+                #[cfg(not(feature = "std"))]
+                return <$t as FloatMath>::floor(self);
+            }
+
+            fn rem_euclid(self, rhs: Self) -> Self {
+                #[cfg(feature = "std")]
+                return <$t>::rem_euclid(self, rhs);
+                #[cfg(not(feature = "std"))]
+                return <$t as FloatMath>::rem_euclid(self, rhs);
+            }
+
+            fn is_finite(self) -> bool {
+                <$t>::is_finite(self)
+            }
+        }
+    };
+}
+
+impl_float!(f32);
+impl_float!(f64);
+
+fn clamp01<T: Float>(value: T) -> T {
+    if value < T::ZERO {
+        T::ZERO
+    } else if value > T::ONE {
+        T::ONE
+    } else {
+        value
+    }
+}
+
+/// Linearly interpolate between `a` and `b` (`alpha` is not clamped)
+pub fn lerp<T: Float>(a: T, b: T, alpha: T) -> T {
+    a + (b - a) * alpha
+}
+
+/// Inverse of [`lerp`]: where `value` sits between `a` and `b` (UE `GetRangePct`).
+/// A zero-width range returns 0 below `b` and 1 at or above it.
+pub fn inv_lerp<T: Float>(a: T, b: T, value: T) -> T {
+    let divisor = b - a;
+    if divisor == T::ZERO {
+        return if value >= b { T::ONE } else { T::ZERO };
+    }
+    (value - a) / divisor
+}
+
+/// Map `value` from the input range onto the output range without clamping
+pub fn map_range_unclamped<T: Float>(value: T, in_min: T, in_max: T, out_min: T, out_max: T) -> T {
+    lerp(out_min, out_max, inv_lerp(in_min, in_max, value))
+}
+
+/// Map `value` from the input range onto the output range, clamped to the output range
+pub fn map_range_clamped<T: Float>(value: T, in_min: T, in_max: T, out_min: T, out_max: T) -> T {
+    lerp(out_min, out_max, clamp01(inv_lerp(in_min, in_max, value)))
+}
+
+/// Snap `value` to the nearest multiple of `grid_size`.
+/// The sign of `grid_size` is ignored and a zero grid leaves `value` unchanged.
+pub fn grid_snap<T: Float>(value: T, grid_size: T) -> T {
+    let grid = grid_size.abs();
+    if grid == T::ZERO || !grid.is_finite() {
+        return value;
+    }
+    ((value + grid * T::HALF) / grid).floor() * grid
+}
+
+/// Wrap `value` into the inclusive range `[min, max]` (UE `FMath::Wrap`).
+/// Reversed bounds are swapped and an empty range returns `min`.
+pub fn wrap<T: Float>(value: T, min: T, max: T) -> T {
+    let (min, max) = if min > max { (max, min) } else { (min, max) };
+    let size = max - min;
+    if size == T::ZERO {
+        return min;
+    }
+    if !value.is_finite() || (value >= min && value <= max) {
+        return value;
+    }
+    min + (value - min).rem_euclid(size)
+}
+
+/// Bounce `t` back and forth between 0 and `length`.
+/// A non-positive or non-finite `length` always returns 0.
+pub fn ping_pong<T: Float>(t: T, length: T) -> T {
+    if length <= T::ZERO || !length.is_finite() {
+        return T::ZERO;
+    }
+    let doubled = length + length;
+    length - (t.rem_euclid(doubled) - length).abs()
+}
+
+/// Hermite interpolation from 0 to 1 as `x` moves from `a` to `b` (UE `SmoothStep`).
+/// A zero-width range acts as a step at `b`.
+pub fn smooth_step<T: Float>(a: T, b: T, x: T) -> T {
+    if x < a {
+        return T::ZERO;
+    }
+    if x >= b {
+        return T::ONE;
+    }
+    let t = (x - a) / (b - a);
+    let three = T::ONE + T::ONE + T::ONE;
+    t * t * (three - (t + t))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lerp_and_inv_lerp() {
+        assert_eq!(lerp(10.0, 20.0, 0.25), 12.5);
+        assert_eq!(lerp(10.0_f32, 20.0, 1.5), 25.0);
+        assert_eq!(inv_lerp(10.0, 20.0, 12.5), 0.25);
+        assert_eq!(inv_lerp(10.0_f32, 20.0, 5.0), -0.5);
+
+        // Zero-width range never divides by zero
+        assert_eq!(inv_lerp(5.0, 5.0, 4.0), 0.0);
+        assert_eq!(inv_lerp(5.0, 5.0, 5.0), 1.0);
+        assert_eq!(inv_lerp(5.0_f32, 5.0, 6.0), 1.0);
+    }
+
+    #[test]
+    fn test_map_range() {
+        assert_eq!(map_range_unclamped(15.0, 10.0, 20.0, 0.0, 100.0), 50.0);
+        assert_eq!(map_range_unclamped(30.0, 10.0, 20.0, 0.0, 100.0), 200.0);
+        assert_eq!(map_range_clamped(30.0, 10.0, 20.0, 0.0, 100.0), 100.0);
+        assert_eq!(map_range_clamped(-30.0_f32, 10.0, 20.0, 0.0, 100.0), 0.0);
+
+        // Reversed output range
+        assert_eq!(map_range_clamped(12.5, 10.0, 20.0, 1.0, 0.0), 0.75);
+
+        // Zero-width input range
+        let mapped = map_range_clamped(3.0_f64, 5.0, 5.0, 0.0, 1.0);
+        assert!(!mapped.is_nan());
+        assert_eq!(mapped, 0.0);
+        assert_eq!(map_range_unclamped(5.0_f32, 5.0, 5.0, 0.0, 10.0), 10.0);
+    }
+
+    #[test]
+    fn test_grid_snap() {
+        assert_eq!(grid_snap(12.0, 5.0), 10.0);
+        assert_eq!(grid_snap(13.0, 5.0), 15.0);
+        assert_eq!(grid_snap(-12.0, 5.0), -10.0);
+        assert_eq!(grid_snap(7.4_f32, 0.5), 7.5);
+
+        // Negative grid behaves like its magnitude
+        assert_eq!(grid_snap(13.0, -5.0), 15.0);
+
+        // Degenerate grids leave the value untouched
+        assert_eq!(grid_snap(13.0, 0.0), 13.0);
+        assert_eq!(grid_snap(13.0_f32, f32::NAN), 13.0);
+    }
+
+    #[test]
+    fn test_wrap() {
+        assert_eq!(wrap(370.0, 0.0, 360.0), 10.0);
+        assert_eq!(wrap(-10.0, 0.0, 360.0), 350.0);
+        assert_eq!(wrap(-190.0_f32, -180.0, 180.0), 170.0);
+        assert_eq!(wrap(360.0, 0.0, 360.0), 360.0);
+        assert_eq!(wrap(5.0, 0.0, 10.0), 5.0);
+
+        // Reversed bounds and empty range
+        assert_eq!(wrap(12.0, 10.0, 0.0), 2.0);
+        assert_eq!(wrap(42.0, 3.0, 3.0), 3.0);
+    }
+
+    #[test]
+    fn test_ping_pong() {
+        assert_eq!(ping_pong(0.0, 2.0), 0.0);
+        assert_eq!(ping_pong(1.5, 2.0), 1.5);
+        assert_eq!(ping_pong(3.0, 2.0), 1.0);
+        assert_eq!(ping_pong(4.0_f32, 2.0), 0.0);
+        assert_eq!(ping_pong(-1.0, 2.0), 1.0);
+
+        assert_eq!(ping_pong(3.0, 0.0), 0.0);
+        assert_eq!(ping_pong(3.0, -2.0), 0.0);
+    }
+
+    #[test]
+    fn test_smooth_step() {
+        assert_eq!(smooth_step(0.0, 1.0, -1.0), 0.0);
+        assert_eq!(smooth_step(0.0, 1.0, 0.5), 0.5);
+        assert_eq!(smooth_step(0.0, 1.0, 2.0), 1.0);
+        assert!((smooth_step(0.0_f32, 1.0, 0.25) - 0.15625).abs() < 1e-6);
+
+        // Zero-width range is a step function
+        assert_eq!(smooth_step(1.0, 1.0, 0.5), 0.0);
+        assert_eq!(smooth_step(1.0, 1.0, 1.0), 1.0);
+    }
+}
@@ -0,0 +1,275 @@
+//! Trace hit results shared by the ray, segment and plane queries (UE: `FHitResult`)
+
+use crate::vector::Vector;
+use crate::bounds::{BoundingBox, BoundingSphere};
+use crate::types::NetworkGUID;
+use crate::BinarySerializable;
+use super::{LineSegment, Plane, Ray};
+use serde::{Deserialize, Serialize};
+use core::fmt;
+
+/// Where and how a trace hit something (UE: `FHitResult`)
+///
+/// Built by the `from_*` constructors, which return `None` on a miss. For
+/// line traces `location` equals `impact_point` and `normal` equals
+/// `impact_normal`; both pairs exist so sweep results can use the same type.
+///
+/// ```
+/// use ue_types::*;
+///
+/// let crate_box = BoundingBox::new(Vector::new(5.0, -1.0, -1.0), Vector::new(7.0, 1.0, 1.0));
+/// let trace = LineSegment::new(Vector::ZERO, Vector::new(10.0, 0.0, 0.0));
+///
+/// let hit = HitResult::from_segment_box(trace, crate_box).unwrap();
+/// assert_eq!(hit.impact_point, Vector::new(5.0, 0.0, 0.0));
+/// assert_eq!(hit.impact_normal, Vector::new(-1.0, 0.0, 0.0));
+/// assert_eq!(hit.time, 0.5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct HitResult {
+    /// The trace was stopped by what it hit
+    pub blocking_hit: bool,
+    /// The trace started inside the shape; the normals then point back along the trace
+    pub start_penetrating: bool,
+    /// Where the trace ended up
+    pub location: Vector,
+    /// Point of contact on the shape's surface
+    pub impact_point: Vector,
+    /// Surface normal at `location`, facing the trace
+    pub normal: Vector,
+    /// Surface normal of the shape at `impact_point`, facing the trace
+    pub impact_normal: Vector,
+    /// Distance from the trace start to `location`
+    pub distance: f64,
+    /// Fraction of the way along the trace, from 0 at the start to 1 at the end
+    ///
+    /// Rays have no end, so for ray queries this is the ray parameter,
+    /// which equals `distance` when the direction has unit length.
+    pub time: f64,
+    /// Network identity of what was hit, if the caller knows it
+    pub hit_guid: Option<NetworkGUID>,
+}
+
+impl fmt::Display for HitResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "HitResult(Point: ({:.3}, {:.3}, {:.3}), Normal: ({:.3}, {:.3}, {:.3}), Distance: {:.3}, Time: {:.3}",
+            self.impact_point.x, self.impact_point.y, self.impact_point.z,
+            self.impact_normal.x, self.impact_normal.y, self.impact_normal.z,
+            self.distance, self.time
+        )?;
+        if self.start_penetrating {
+            write!(f, ", StartPenetrating")?;
+        }
+        if let Some(guid) = self.hit_guid {
+            write!(f, ", Hit: {}", guid)?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl BinarySerializable for HitResult {}
+
+impl HitResult {
+    /// Create a blocking hit at `point` with `normal`, not started inside
+    pub fn new(point: Vector, normal: Vector, distance: f64, time: f64) -> Self {
+        Self {
+            blocking_hit: true,
+            start_penetrating: false,
+            location: point,
+            impact_point: point,
+            normal,
+            impact_normal: normal,
+            distance,
+            time,
+            hit_guid: None,
+        }
+    }
+
+    /// Tag the hit with the network identity of what was hit
+    pub fn with_hit_guid(mut self, guid: NetworkGUID) -> Self {
+        self.hit_guid = Some(guid);
+        self
+    }
+
+    /// Trace a ray against a box; the normal is the face the ray enters through
+    ///
+    /// Edge and corner hits report the face of the last slab entered, X
+    /// before Y before Z on an exact tie.
+    pub fn from_ray_box(ray: Ray, bbox: BoundingBox) -> Option<Self> {
+        let t = ray.intersects_box(bbox)?;
+        let (normal, entry) = box_entry(ray.origin, ray.direction, bbox);
+        Some(Self::from_ray_parameter(ray, t, normal, entry < 0.0))
+    }
+
+    /// Trace a ray against a sphere; the normal points out from the center
+    pub fn from_ray_sphere(ray: Ray, sphere: BoundingSphere) -> Option<Self> {
+        let (t, _) = ray.intersects_sphere(sphere)?;
+        let point = ray.point_at_distance(t);
+        let inside = ray.origin.distance_squared(sphere.center) < sphere.radius * sphere.radius;
+        Some(Self::from_ray_parameter(ray, t, (point - sphere.center).normalize_or_zero(), inside))
+    }
+
+    /// Trace a ray against a plane; the normal faces the side the ray came from
+    pub fn from_ray_plane(ray: Ray, plane: Plane) -> Option<Self> {
+        let point = plane.intersect_ray(ray)?;
+        let t = (point - ray.origin).dot(ray.direction) / ray.direction.length_squared();
+        let normal = if plane.normal.dot(ray.direction) > 0.0 { -plane.normal } else { plane.normal };
+        Some(Self::from_ray_parameter(ray, t, normal, false))
+    }
+
+    /// Trace a segment against a box; `time` is the fraction of the segment's length
+    pub fn from_segment_box(segment: LineSegment, bbox: BoundingBox) -> Option<Self> {
+        let time = segment.intersects_box(bbox)?;
+        let direction = segment.direction_vector();
+        let (normal, entry) = box_entry(segment.start, direction, bbox);
+        let mut hit = Self::new(segment.lerp_unclamped(time), normal, time * segment.length(), time);
+        if entry < 0.0 {
+            hit.set_start_penetrating(direction);
+        }
+        Some(hit)
+    }
+
+    /// Build a ray hit at parameter `t` with the surface normal there
+    fn from_ray_parameter(ray: Ray, t: f64, surface_normal: Vector, starts_inside: bool) -> Self {
+        let mut hit = Self::new(ray.point_at_distance(t), surface_normal, t * ray.direction.length(), t);
+        if starts_inside {
+            hit.set_start_penetrating(ray.direction);
+        }
+        hit
+    }
+
+    /// Mark the hit as starting inside, with normals back along the trace
+    fn set_start_penetrating(&mut self, direction: Vector) {
+        self.start_penetrating = true;
+        self.normal = -direction.normalize_or_zero();
+        self.impact_normal = self.normal;
+    }
+}
+
+/// Normal of the face a line through `origin` along `direction` enters the box
+/// by, and the line parameter where it does (negative if `origin` is inside)
+///
+/// The entry face belongs to the slab whose near plane is crossed last.
+fn box_entry(origin: Vector, direction: Vector, bbox: BoundingBox) -> (Vector, f64) {
+    let mut latest = f64::NEG_INFINITY;
+    let mut normal = Vector::ZERO;
+    for axis in 0..3 {
+        if direction[axis] == 0.0 {
+            continue;
+        }
+        let near_plane = if direction[axis] > 0.0 { bbox.min[axis] } else { bbox.max[axis] };
+        let t = (near_plane - origin[axis]) / direction[axis];
+        if t > latest {
+            latest = t;
+            normal = Vector::ZERO;
+            normal[axis] = -direction[axis].signum();
+        }
+    }
+    (normal, latest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    fn unit_box() -> BoundingBox {
+        BoundingBox::new(Vector::splat(-1.0), Vector::splat(1.0))
+    }
+
+    #[test]
+    fn test_ray_box_normal_for_each_face() {
+        let faces = [Vector::X, -Vector::X, Vector::Y, -Vector::Y, Vector::Z, -Vector::Z];
+        for face in faces {
+            // Come in from 5 units out, slightly off-center so no edge is involved
+            let offset = Vector::new(0.1, 0.2, 0.3) * (Vector::ONE - face.abs());
+            let origin = face * 5.0 + offset;
+            let hit = HitResult::from_ray_box(Ray::new(origin, -face), unit_box()).unwrap();
+            assert_eq!(hit.impact_normal, face, "entering through {}", face);
+            assert_eq!(hit.normal, face);
+            assert_eq!(hit.impact_point, face + offset);
+            assert_eq!(hit.distance, 4.0);
+            assert!(hit.blocking_hit && !hit.start_penetrating);
+        }
+
+        // A diagonal ray enters through whichever face it crosses last
+        let diagonal = Ray::from_origin_to_target(Vector::new(-5.0, -2.0, 0.0), Vector::new(0.0, 0.5, 0.0));
+        let hit = HitResult::from_ray_box(diagonal, unit_box()).unwrap();
+        assert_eq!(hit.impact_normal, -Vector::X);
+        assert_eq!(HitResult::from_ray_box(Ray::new(Vector::new(0.0, 5.0, 0.0), Vector::X), unit_box()), None);
+    }
+
+    #[test]
+    fn test_segment_box_time_is_normalized() {
+        let segment = LineSegment::new(Vector::new(-10.0, 0.0, 0.0), Vector::new(10.0, 0.0, 0.0));
+        let hit = HitResult::from_segment_box(segment, unit_box()).unwrap();
+        assert_eq!(hit.time, 0.45);
+        assert_eq!(hit.distance, 9.0);
+        assert_eq!(hit.distance, hit.time * segment.length());
+        assert_eq!(hit.impact_normal, -Vector::X);
+
+        // Same box, a segment twice as long: half the time, same distance
+        let longer = LineSegment::new(Vector::new(-10.0, 0.0, 0.0), Vector::new(30.0, 0.0, 0.0));
+        let hit = HitResult::from_segment_box(longer, unit_box()).unwrap();
+        assert_eq!((hit.time, hit.distance), (0.225, 9.0));
+
+        // Stops short of the box
+        let short = LineSegment::new(Vector::new(-10.0, 0.0, 0.0), Vector::new(-2.0, 0.0, 0.0));
+        assert_eq!(HitResult::from_segment_box(short, unit_box()), None);
+
+        // Starts inside: time 0, normal back along the segment
+        let inside = LineSegment::new(Vector::ZERO, Vector::new(0.0, 0.0, 4.0));
+        let hit = HitResult::from_segment_box(inside, unit_box()).unwrap();
+        assert!(hit.start_penetrating);
+        assert_eq!((hit.time, hit.distance, hit.impact_normal), (0.0, 0.0, -Vector::Z));
+    }
+
+    #[test]
+    fn test_ray_sphere_and_plane() {
+        let sphere = BoundingSphere::new(Vector::new(10.0, 0.0, 0.0), 2.0);
+        let hit = HitResult::from_ray_sphere(Ray::new(Vector::new(10.0, 0.0, 10.0), -Vector::Z), sphere).unwrap();
+        assert_eq!(hit.impact_point, Vector::new(10.0, 0.0, 2.0));
+        assert_eq!(hit.impact_normal, Vector::Z);
+        assert_eq!(hit.distance, 8.0);
+
+        // Off-center hits get the radial normal, not the ray direction
+        let glancing = HitResult::from_ray_sphere(Ray::new(Vector::new(0.0, 1.0, 0.0), Vector::X), sphere).unwrap();
+        let radial = (glancing.impact_point - sphere.center) / 2.0;
+        assert!((glancing.impact_normal - radial).length() < 1e-12);
+        assert!((glancing.impact_normal.length() - 1.0).abs() < 1e-12);
+
+        let inside_box = HitResult::from_ray_box(Ray::new(Vector::ZERO, Vector::X), unit_box()).unwrap();
+        assert!(inside_box.start_penetrating);
+        assert_eq!((inside_box.impact_point, inside_box.impact_normal), (Vector::ZERO, -Vector::X));
+        // Starting on a face and heading in only touches
+        let on_face = HitResult::from_ray_box(Ray::new(Vector::new(-1.0, 0.0, 0.0), Vector::X), unit_box()).unwrap();
+        assert!(!on_face.start_penetrating);
+        assert_eq!(on_face.impact_normal, -Vector::X);
+
+        let inside = HitResult::from_ray_sphere(Ray::new(sphere.center, Vector::Y), sphere).unwrap();
+        assert!(inside.start_penetrating);
+        assert_eq!(inside.impact_normal, -Vector::Y);
+
+        let floor = Plane::new(Vector::Z, 0.0);
+        let from_above = HitResult::from_ray_plane(Ray::new(Vector::new(3.0, 4.0, 5.0), -Vector::Z), floor).unwrap();
+        assert_eq!((from_above.impact_point, from_above.impact_normal, from_above.distance), (Vector::new(3.0, 4.0, 0.0), Vector::Z, 5.0));
+        let from_below = HitResult::from_ray_plane(Ray::new(Vector::new(0.0, 0.0, -2.0), Vector::Z), floor).unwrap();
+        assert_eq!((from_below.impact_normal, from_below.time), (-Vector::Z, 2.0));
+        assert_eq!(HitResult::from_ray_plane(Ray::new(Vector::new(0.0, 0.0, 2.0), Vector::Z), floor), None);
+    }
+
+    #[test]
+    fn test_hit_result_display_and_serialization() {
+        let hit = HitResult::new(Vector::new(1.0, 2.0, 3.0), Vector::Z, 4.0, 0.5).with_hit_guid(NetworkGUID::new(12));
+        assert_eq!(
+            hit.to_string(),
+            "HitResult(Point: (1.000, 2.000, 3.000), Normal: (0.000, 0.000, 1.000), Distance: 4.000, Time: 0.500, Hit: NetworkGUID(12))"
+        );
+
+        let json = serde_json::to_string(&hit).unwrap();
+        assert_eq!(serde_json::from_str::<HitResult>(&json).unwrap(), hit);
+        assert_eq!(HitResult::from_binary(&hit.to_binary().unwrap()).unwrap(), hit);
+    }
+}
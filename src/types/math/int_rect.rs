@@ -0,0 +1,198 @@
+//! Integer rectangle and box regions for grid bounds
+
+use super::{IntVector, IntVector2};
+use crate::BinarySerializable;
+use serde::{Deserialize, Serialize};
+use core::fmt;
+
+/// 2D integer rectangle (equivalent to FIntRect)
+///
+/// `min` is inclusive and `max` is exclusive, so a rect from (0, 0) to
+/// (4, 3) covers 4x3 cells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct IntRect {
+    /// Inclusive minimum corner
+    pub min: IntVector2,
+    /// Exclusive maximum corner
+    pub max: IntVector2,
+}
+
+impl fmt::Display for IntRect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "IntRect(Min: ({}, {}), Max: ({}, {}))",
+               self.min.x, self.min.y, self.max.x, self.max.y)
+    }
+}
+
+impl BinarySerializable for IntRect {}
+
+impl IntRect {
+    /// Create a new rectangle from inclusive min and exclusive max corners
+    pub fn new(min: IntVector2, max: IntVector2) -> Self {
+        Self { min, max }
+    }
+
+    /// Create a rectangle at the origin with the given width and height
+    pub fn from_size(width: i32, height: i32) -> Self {
+        Self::new(IntVector2::ZERO, IntVector2::new(width, height))
+    }
+
+    /// Get the width (number of columns), saturating at `i32::MAX`
+    pub fn width(self) -> i32 {
+        saturate(span(self.min.x, self.max.x))
+    }
+
+    /// Get the height (number of rows), saturating at `i32::MAX`
+    pub fn height(self) -> i32 {
+        saturate(span(self.min.y, self.max.y))
+    }
+
+    /// Get the number of cells covered by the rectangle
+    pub fn area(self) -> i64 {
+        span(self.min.x, self.max.x).saturating_mul(span(self.min.y, self.max.y))
+    }
+
+    /// Check if the rectangle covers no cells
+    pub fn is_empty(self) -> bool {
+        self.width() == 0 || self.height() == 0
+    }
+
+    /// Check if a cell lies inside the rectangle
+    pub fn contains(self, point: IntVector2) -> bool {
+        point.x >= self.min.x && point.x < self.max.x
+            && point.y >= self.min.y && point.y < self.max.y
+    }
+}
+
+/// 3D integer box for voxel grid bounds
+///
+/// `min` is inclusive and `max` is exclusive on every axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct IntBox {
+    /// Inclusive minimum corner
+    pub min: IntVector,
+    /// Exclusive maximum corner
+    pub max: IntVector,
+}
+
+impl fmt::Display for IntBox {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "IntBox(Min: ({}, {}, {}), Max: ({}, {}, {}))",
+               self.min.x, self.min.y, self.min.z, self.max.x, self.max.y, self.max.z)
+    }
+}
+
+impl BinarySerializable for IntBox {}
+
+impl IntBox {
+    /// Create a new box from inclusive min and exclusive max corners
+    pub fn new(min: IntVector, max: IntVector) -> Self {
+        Self { min, max }
+    }
+
+    /// Get the size along each axis (clamped to zero, saturating at `i32::MAX`)
+    pub fn size(self) -> IntVector {
+        IntVector::new(
+            saturate(span(self.min.x, self.max.x)),
+            saturate(span(self.min.y, self.max.y)),
+            saturate(span(self.min.z, self.max.z)),
+        )
+    }
+
+    /// Get the number of cells covered by the box, saturating at `i64::MAX`
+    pub fn volume(self) -> i64 {
+        span(self.min.x, self.max.x)
+            .saturating_mul(span(self.min.y, self.max.y))
+            .saturating_mul(span(self.min.z, self.max.z))
+    }
+
+    /// Check if the box covers no cells
+    pub fn is_empty(self) -> bool {
+        self.volume() == 0
+    }
+
+    /// Check if a cell lies inside the box
+    pub fn contains(self, point: IntVector) -> bool {
+        point.x >= self.min.x && point.x < self.max.x
+            && point.y >= self.min.y && point.y < self.max.y
+            && point.z >= self.min.z && point.z < self.max.z
+    }
+}
+
+/// Number of cells from `min` up to `max`, computed in i64 so it can't overflow
+fn span(min: i32, max: i32) -> i64 {
+    (i64::from(max) - i64::from(min)).max(0)
+}
+
+fn saturate(span: i64) -> i32 {
+    i32::try_from(span).unwrap_or(i32::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_int_rect_dimensions() {
+        let rect = IntRect::new(IntVector2::new(-2, 1), IntVector2::new(3, 4));
+        assert_eq!(rect.width(), 5);
+        assert_eq!(rect.height(), 3);
+        assert_eq!(rect.area(), 15);
+        assert!(!rect.is_empty());
+
+        let inverted = IntRect::new(IntVector2::new(3, 3), IntVector2::new(1, 1));
+        assert!(inverted.is_empty());
+        assert_eq!(inverted.area(), 0);
+    }
+
+    #[test]
+    fn test_extreme_coordinates_saturate() {
+        let whole = IntRect::new(IntVector2::new(i32::MIN, i32::MIN), IntVector2::new(i32::MAX, i32::MAX));
+        assert_eq!(whole.width(), i32::MAX);
+        assert_eq!(whole.height(), i32::MAX);
+        assert_eq!(whole.area(), i64::MAX);
+        assert!(!whole.is_empty());
+
+        let wide = IntRect::new(IntVector2::new(i32::MIN, 0), IntVector2::new(i32::MAX, 1));
+        assert_eq!(wide.area(), u32::MAX as i64);
+        let inverted = IntRect::new(IntVector2::new(i32::MAX, 0), IntVector2::new(i32::MIN, 1));
+        assert_eq!(inverted.width(), 0);
+        assert!(inverted.is_empty());
+
+        let slab = IntBox::new(IntVector::new(i32::MIN, 0, 0), IntVector::new(i32::MAX, 2, 3));
+        assert_eq!(slab.size(), IntVector::new(i32::MAX, 2, 3));
+        assert_eq!(slab.volume(), u32::MAX as i64 * 6);
+        let lowest = IntVector::new(i32::MIN, i32::MIN, i32::MIN);
+        let highest = IntVector::new(i32::MAX, i32::MAX, i32::MAX);
+        assert_eq!(IntBox::new(lowest, highest).volume(), i64::MAX);
+        assert!(IntBox::new(highest, lowest).is_empty());
+    }
+
+    #[test]
+    fn test_int_rect_contains_is_half_open() {
+        let rect = IntRect::from_size(4, 3);
+        assert!(rect.contains(IntVector2::new(0, 0)));
+        assert!(rect.contains(IntVector2::new(3, 2)));
+        assert!(!rect.contains(IntVector2::new(4, 2)));
+        assert!(!rect.contains(IntVector2::new(3, 3)));
+        assert!(!rect.contains(IntVector2::new(-1, 0)));
+    }
+
+    #[test]
+    fn test_int_box_contains() {
+        let bounds = IntBox::new(IntVector::ZERO, IntVector::new(2, 3, 4));
+        assert_eq!(bounds.volume(), 24);
+        assert!(bounds.contains(IntVector::new(1, 2, 3)));
+        assert!(!bounds.contains(IntVector::new(2, 0, 0)));
+    }
+
+    #[test]
+    fn test_int_rect_serialization() {
+        let rect = IntRect::new(IntVector2::new(-2, 1), IntVector2::new(3, 4));
+        let json = serde_json::to_string(&rect).unwrap();
+        assert_eq!(serde_json::from_str::<IntRect>(&json).unwrap(), rect);
+
+        let binary = rect.to_binary().unwrap();
+        assert_eq!(IntRect::from_binary(&binary).unwrap(), rect);
+    }
+}
@@ -2,8 +2,47 @@
 
 use crate::vector::Vector;
 use crate::BinarySerializable;
+use super::Axis;
 use serde::{Deserialize, Serialize};
-use std::fmt;
+use core::fmt;
+#[cfg(not(feature = "std"))]
+use crate::types::math::float_math::FloatMath;
+
+/// Reason a floating point vector couldn't be converted to an integer vector
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IntVectorConversionError {
+    /// The component is NaN or infinite
+    NotFinite { axis: Axis, value: f64 },
+    /// The component rounds to a value outside the `i32` range
+    OutOfRange { axis: Axis, value: f64 },
+}
+
+impl fmt::Display for IntVectorConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IntVectorConversionError::NotFinite { axis, value } => {
+                write!(f, "{} component {} is not finite", axis, value)
+            }
+            IntVectorConversionError::OutOfRange { axis, value } => {
+                write!(f, "{} component {} is outside the i32 range", axis, value)
+            }
+        }
+    }
+}
+
+impl core::error::Error for IntVectorConversionError {}
+
+/// Round one component to the nearest `i32`, rejecting values that don't fit
+pub(super) fn component_to_i32(axis: Axis, value: f64) -> Result<i32, IntVectorConversionError> {
+    if !value.is_finite() {
+        return Err(IntVectorConversionError::NotFinite { axis, value });
+    }
+    let rounded = value.round();
+    if rounded < i32::MIN as f64 || rounded > i32::MAX as f64 {
+        return Err(IntVectorConversionError::OutOfRange { axis, value });
+    }
+    Ok(rounded as i32)
+}
 
 /// 3D Integer Vector for grid coordinates, voxel positions, etc.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -34,7 +73,7 @@ impl IntVector {
     pub const ONE: Self = Self { x: 1, y: 1, z: 1 };
 
     /// Create a new 3D integer vector
-    pub fn new(x: i32, y: i32, z: i32) -> Self {
+    pub const fn new(x: i32, y: i32, z: i32) -> Self {
         Self { x, y, z }
     }
 
@@ -59,15 +98,26 @@ impl IntVector {
     }
 
     /// Component-wise addition
+    #[allow(clippy::should_implement_trait)]
     pub fn add(self, other: IntVector) -> Self {
         Self::new(self.x + other.x, self.y + other.y, self.z + other.z)
     }
 
     /// Component-wise subtraction
+    #[allow(clippy::should_implement_trait)]
     pub fn sub(self, other: IntVector) -> Self {
         Self::new(self.x - other.x, self.y - other.y, self.z - other.z)
     }
 
+    /// Component-wise addition, or `None` if any component overflows
+    pub fn checked_add(self, other: IntVector) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add(other.x)?,
+            self.y.checked_add(other.y)?,
+            self.z.checked_add(other.z)?,
+        ))
+    }
+
     /// Scale by an integer factor
     pub fn scale(self, factor: i32) -> Self {
         Self::new(self.x * factor, self.y * factor, self.z * factor)
@@ -94,7 +144,7 @@ impl IntVector {
 }
 
 // Operator overloads
-impl std::ops::Add for IntVector {
+impl core::ops::Add for IntVector {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
@@ -102,7 +152,7 @@ impl std::ops::Add for IntVector {
     }
 }
 
-impl std::ops::Sub for IntVector {
+impl core::ops::Sub for IntVector {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
@@ -110,7 +160,7 @@ impl std::ops::Sub for IntVector {
     }
 }
 
-impl std::ops::Mul<i32> for IntVector {
+impl core::ops::Mul<i32> for IntVector {
     type Output = Self;
 
     fn mul(self, rhs: i32) -> Self::Output {
@@ -118,6 +168,43 @@ impl std::ops::Mul<i32> for IntVector {
     }
 }
 
+impl From<IntVector> for Vector {
+    fn from(v: IntVector) -> Self {
+        v.to_vector()
+    }
+}
+
+/// Rounds to the nearest integer like `from_vector`, but fails instead of saturating
+impl TryFrom<Vector> for IntVector {
+    type Error = IntVectorConversionError;
+
+    fn try_from(v: Vector) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            component_to_i32(Axis::X, v.x)?,
+            component_to_i32(Axis::Y, v.y)?,
+            component_to_i32(Axis::Z, v.z)?,
+        ))
+    }
+}
+
+impl From<[i32; 3]> for IntVector {
+    fn from([x, y, z]: [i32; 3]) -> Self {
+        Self::new(x, y, z)
+    }
+}
+
+impl From<(i32, i32, i32)> for IntVector {
+    fn from((x, y, z): (i32, i32, i32)) -> Self {
+        Self::new(x, y, z)
+    }
+}
+
+impl From<IntVector> for [i32; 3] {
+    fn from(v: IntVector) -> Self {
+        [v.x, v.y, v.z]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -128,6 +215,8 @@ mod tests {
         let iv2 = IntVector::new(4, 5, 6);
         
         assert_eq!(iv1.add(iv2), IntVector::new(5, 7, 9));
+        assert_eq!(iv1.checked_add(iv2), Some(IntVector::new(5, 7, 9)));
+        assert_eq!(IntVector::new(0, 0, i32::MIN).checked_add(IntVector::new(0, 0, -1)), None);
         assert_eq!(iv2.sub(iv1), IntVector::new(3, 3, 3));
         assert_eq!(iv1.dot(iv2), 32); // 1*4 + 2*5 + 3*6 = 32
     }
@@ -142,6 +231,26 @@ mod tests {
         assert_eq!(back_to_fv, Vector::new(4.0, -2.0, 2.0));
     }
 
+    #[test]
+    fn test_int_vector_from_impls() {
+        let iv = IntVector::new(-7, 0, 2_000_000_000);
+        let fv = Vector::from(iv);
+        assert_eq!(IntVector::try_from(fv), Ok(iv));
+        assert_eq!(IntVector::try_from(Vector::new(0.5, -0.4, 2.5)), Ok(IntVector::new(1, 0, 3)));
+        assert_eq!(IntVector::from([1, 2, 3]), IntVector::new(1, 2, 3));
+        assert_eq!(IntVector::from((1, 2, 3)), IntVector::new(1, 2, 3));
+        assert_eq!(<[i32; 3]>::from(iv), [-7, 0, 2_000_000_000]);
+
+        let error = IntVector::try_from(Vector::new(0.0, f64::NAN, 0.0)).unwrap_err();
+        assert!(matches!(error, IntVectorConversionError::NotFinite { axis: Axis::Y, .. }));
+        assert_eq!(error.to_string(), "Y component NaN is not finite");
+        let error = IntVector::try_from(Vector::new(0.0, 0.0, 3e9)).unwrap_err();
+        assert_eq!(error, IntVectorConversionError::OutOfRange { axis: Axis::Z, value: 3e9 });
+        assert_eq!(error.to_string(), "Z component 3000000000 is outside the i32 range");
+        assert_eq!(IntVector::try_from(Vector::splat(-2_147_483_648.4)), Ok(IntVector::new(i32::MIN, i32::MIN, i32::MIN)));
+        assert!(IntVector::try_from(Vector::splat(2_147_483_647.5)).is_err());
+    }
+
     #[test]
     fn test_int_vector_manhattan_distance() {
         let p1 = IntVector::new(0, 0, 0);
@@ -2,8 +2,12 @@
 
 use crate::vector::Vector2D;
 use crate::BinarySerializable;
+use super::int_vector::component_to_i32;
+use super::{Axis, IntVectorConversionError};
 use serde::{Deserialize, Serialize};
-use std::fmt;
+use core::fmt;
+#[cfg(not(feature = "std"))]
+use crate::types::math::float_math::FloatMath;
 
 /// 2D Integer Vector for grid coordinates, texture coordinates, etc.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -31,7 +35,7 @@ impl IntVector2 {
     pub const ONE: Self = Self { x: 1, y: 1 };
 
     /// Create a new 2D integer vector
-    pub fn new(x: i32, y: i32) -> Self {
+    pub const fn new(x: i32, y: i32) -> Self {
         Self { x, y }
     }
 
@@ -56,15 +60,22 @@ impl IntVector2 {
     }
 
     /// Component-wise addition
+    #[allow(clippy::should_implement_trait)]
     pub fn add(self, other: IntVector2) -> Self {
         Self::new(self.x + other.x, self.y + other.y)
     }
 
     /// Component-wise subtraction
+    #[allow(clippy::should_implement_trait)]
     pub fn sub(self, other: IntVector2) -> Self {
         Self::new(self.x - other.x, self.y - other.y)
     }
 
+    /// Component-wise addition, or `None` if any component overflows
+    pub fn checked_add(self, other: IntVector2) -> Option<Self> {
+        Some(Self::new(self.x.checked_add(other.x)?, self.y.checked_add(other.y)?))
+    }
+
     /// Scale by an integer factor
     pub fn scale(self, factor: i32) -> Self {
         Self::new(self.x * factor, self.y * factor)
@@ -82,7 +93,7 @@ impl IntVector2 {
 }
 
 // Operator overloads
-impl std::ops::Add for IntVector2 {
+impl core::ops::Add for IntVector2 {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
@@ -90,7 +101,7 @@ impl std::ops::Add for IntVector2 {
     }
 }
 
-impl std::ops::Sub for IntVector2 {
+impl core::ops::Sub for IntVector2 {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
@@ -98,7 +109,7 @@ impl std::ops::Sub for IntVector2 {
     }
 }
 
-impl std::ops::Mul<i32> for IntVector2 {
+impl core::ops::Mul<i32> for IntVector2 {
     type Output = Self;
 
     fn mul(self, rhs: i32) -> Self::Output {
@@ -106,6 +117,39 @@ impl std::ops::Mul<i32> for IntVector2 {
     }
 }
 
+impl From<IntVector2> for Vector2D {
+    fn from(v: IntVector2) -> Self {
+        v.to_vector2d()
+    }
+}
+
+/// Rounds to the nearest integer like `from_vector2d`, but fails instead of saturating
+impl TryFrom<Vector2D> for IntVector2 {
+    type Error = IntVectorConversionError;
+
+    fn try_from(v: Vector2D) -> Result<Self, Self::Error> {
+        Ok(Self::new(component_to_i32(Axis::X, v.x)?, component_to_i32(Axis::Y, v.y)?))
+    }
+}
+
+impl From<[i32; 2]> for IntVector2 {
+    fn from([x, y]: [i32; 2]) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl From<(i32, i32)> for IntVector2 {
+    fn from((x, y): (i32, i32)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl From<IntVector2> for [i32; 2] {
+    fn from(v: IntVector2) -> Self {
+        [v.x, v.y]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -127,12 +171,28 @@ mod tests {
         assert_eq!(back_to_fv, Vector2D::new(4.0, -2.0));
     }
 
+    #[test]
+    fn test_int_vector2_from_impls() {
+        let iv = IntVector2::new(12, -40);
+        assert_eq!(IntVector2::try_from(Vector2D::from(iv)), Ok(iv));
+        assert_eq!(IntVector2::from([12, -40]), iv);
+        assert_eq!(IntVector2::from((12, -40)), iv);
+        assert_eq!(<[i32; 2]>::from(iv), [12, -40]);
+
+        let error = IntVector2::try_from(Vector2D::new(f64::INFINITY, 0.0)).unwrap_err();
+        assert_eq!(error.to_string(), "X component inf is not finite");
+        let error = IntVector2::try_from(Vector2D::new(0.0, -1e12)).unwrap_err();
+        assert_eq!(error.to_string(), "Y component -1000000000000 is outside the i32 range");
+    }
+
     #[test]
     fn test_int_vector2_operations() {
         let iv1 = IntVector2::new(3, 4);
         let iv2 = IntVector2::new(1, 2);
         
         assert_eq!(iv1.add(iv2), IntVector2::new(4, 6));
+        assert_eq!(iv1.checked_add(iv2), Some(IntVector2::new(4, 6)));
+        assert_eq!(IntVector2::new(0, i32::MAX).checked_add(IntVector2::UNIT_Y), None);
         assert_eq!(iv1.sub(iv2), IntVector2::new(2, 2));
         assert_eq!(iv1.dot(iv2), 11); // 3*1 + 4*2 = 11
     }
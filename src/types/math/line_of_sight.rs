@@ -0,0 +1,210 @@
+//! Line-of-sight queries against sets of blocking volumes
+
+use crate::bounds::{BoundingBox, BoundingSphere};
+use super::line_segment::nearest_hit;
+use super::LineSegment;
+
+/// Which blocker stopped a line-of-sight check, by index into its list
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LosBlocker {
+    /// Index into the query's boxes
+    Box(usize),
+    /// Index into the query's spheres
+    Sphere(usize),
+}
+
+/// The nearest blocker along a segment
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LosHit {
+    /// The blocking volume
+    pub blocker: LosBlocker,
+    /// Entry time along the segment, from 0 at the start to 1 at the end
+    pub time: f64,
+    /// Distance from the segment start to the entry point
+    pub distance: f64,
+}
+
+/// Line-of-sight test against mixed lists of blocking boxes and spheres
+///
+/// The query only borrows the blocker lists, so one query can be built per
+/// tick and checked against thousands of segments without allocating.
+/// Blockers entered more than `max_distance` from the segment start are
+/// ignored, so long sight lines can stop early.
+///
+/// ```
+/// use ue_types::*;
+///
+/// let walls = [BoundingBox::new(Vector::new(4.0, -5.0, 0.0), Vector::new(5.0, 5.0, 3.0))];
+/// let pillars = [BoundingSphere::new(Vector::new(2.0, 0.0, 1.0), 0.5)];
+/// let query = LosQuery::new().boxes(&walls).spheres(&pillars);
+///
+/// let shot = LineSegment::new(Vector::new(0.0, 0.0, 1.0), Vector::new(10.0, 0.0, 1.0));
+/// assert_eq!(query.first_blocker(shot).unwrap().blocker, LosBlocker::Sphere(0));
+/// assert!(!query.max_distance(1.0).is_blocked(shot));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LosQuery<'a> {
+    boxes: &'a [BoundingBox],
+    spheres: &'a [BoundingSphere],
+    max_distance: f64,
+}
+
+impl Default for LosQuery<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> LosQuery<'a> {
+    /// Create a query with no blockers and no distance limit
+    pub fn new() -> Self {
+        Self {
+            boxes: &[],
+            spheres: &[],
+            max_distance: f64::INFINITY,
+        }
+    }
+
+    /// Set the blocking boxes
+    pub fn boxes(mut self, boxes: &'a [BoundingBox]) -> Self {
+        self.boxes = boxes;
+        self
+    }
+
+    /// Set the blocking spheres
+    pub fn spheres(mut self, spheres: &'a [BoundingSphere]) -> Self {
+        self.spheres = spheres;
+        self
+    }
+
+    /// Ignore blockers entered farther than `distance` from the segment start
+    pub fn max_distance(mut self, distance: f64) -> Self {
+        self.max_distance = distance;
+        self
+    }
+
+    /// Find the blocker the segment enters first, if any
+    ///
+    /// Boxes and spheres are ranked together by distance; on a tie boxes
+    /// win, then lower indices.
+    pub fn first_blocker(&self, segment: LineSegment) -> Option<LosHit> {
+        let limit = self.time_limit(segment)?;
+        let within = |hit: Option<f64>| hit.filter(|&time| time <= limit);
+
+        let nearest_box = nearest_hit(self.boxes.iter().map(|&bbox| within(segment.intersects_box(bbox))));
+        // Nothing beats a box that already contains the start
+        let nearest_sphere = match nearest_box {
+            Some((_, time)) if time <= 0.0 => None,
+            _ => nearest_hit(self.spheres.iter().map(|&sphere| within(segment.intersects_sphere(sphere)))),
+        };
+
+        let (blocker, time) = match (nearest_box, nearest_sphere) {
+            (Some((index, box_time)), Some((_, sphere_time))) if box_time <= sphere_time => {
+                (LosBlocker::Box(index), box_time)
+            }
+            (_, Some((index, time))) => (LosBlocker::Sphere(index), time),
+            (Some((index, time)), None) => (LosBlocker::Box(index), time),
+            (None, None) => return None,
+        };
+        Some(LosHit {
+            blocker,
+            time,
+            distance: time * segment.length(),
+        })
+    }
+
+    /// Check whether anything blocks the segment
+    ///
+    /// Cheaper than `first_blocker`: it stops at the first blocker found
+    /// rather than looking for the nearest.
+    pub fn is_blocked(&self, segment: LineSegment) -> bool {
+        let Some(limit) = self.time_limit(segment) else {
+            return false;
+        };
+        let blocks = |hit: Option<f64>| hit.is_some_and(|time| time <= limit);
+        self.boxes.iter().any(|&bbox| blocks(segment.intersects_box(bbox)))
+            || self.spheres.iter().any(|&sphere| blocks(segment.intersects_sphere(sphere)))
+    }
+
+    /// Check whether the segment is unobstructed
+    pub fn is_clear(&self, segment: LineSegment) -> bool {
+        !self.is_blocked(segment)
+    }
+
+    /// Latest segment time within `max_distance`, or `None` for a negative limit
+    fn time_limit(&self, segment: LineSegment) -> Option<f64> {
+        if self.max_distance < 0.0 {
+            return None;
+        }
+        let length = segment.length();
+        Some(if length > 0.0 { (self.max_distance / length).min(1.0) } else { 1.0 })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Vector;
+
+    fn segment_along_x(length: f64) -> LineSegment {
+        LineSegment::new(Vector::ZERO, Vector::new(length, 0.0, 0.0))
+    }
+
+    #[test]
+    fn test_nearest_blocker_across_kinds() {
+        let boxes = [
+            BoundingBox::new(Vector::new(8.0, -1.0, -1.0), Vector::new(9.0, 1.0, 1.0)),
+            BoundingBox::new(Vector::new(5.0, -1.0, -1.0), Vector::new(6.0, 1.0, 1.0)),
+        ];
+        let spheres = [
+            BoundingSphere::new(Vector::new(7.0, 0.0, 0.0), 0.5),
+            BoundingSphere::new(Vector::new(4.0, 0.0, 0.0), 0.5),
+            BoundingSphere::new(Vector::new(2.0, 9.0, 0.0), 0.5),
+        ];
+        let query = LosQuery::new().boxes(&boxes).spheres(&spheres);
+        let segment = segment_along_x(10.0);
+
+        let hit = query.first_blocker(segment).unwrap();
+        assert_eq!(hit.blocker, LosBlocker::Sphere(1));
+        assert!((hit.distance - 3.5).abs() < 1e-12);
+        assert!((hit.time - 0.35).abs() < 1e-12);
+
+        let boxes_only = LosQuery::new().boxes(&boxes);
+        assert_eq!(boxes_only.first_blocker(segment).map(|hit| hit.blocker), Some(LosBlocker::Box(1)));
+
+        // A box and sphere touching at the same point: the box wins
+        let flush = [BoundingSphere::new(Vector::new(5.5, 0.0, 0.0), 0.5)];
+        let tie = LosQuery::new().boxes(&boxes).spheres(&flush);
+        assert_eq!(tie.first_blocker(segment).map(|hit| hit.blocker), Some(LosBlocker::Box(1)));
+    }
+
+    #[test]
+    fn test_max_distance_limits_blockers() {
+        let boxes = [BoundingBox::new(Vector::new(5.0, -1.0, -1.0), Vector::new(6.0, 1.0, 1.0))];
+        let query = LosQuery::new().boxes(&boxes);
+        let segment = segment_along_x(10.0);
+
+        assert!(query.is_blocked(segment));
+        assert!(query.max_distance(5.0).is_blocked(segment));
+        assert!(query.max_distance(4.9).is_clear(segment));
+        assert_eq!(query.max_distance(4.9).first_blocker(segment), None);
+        assert_eq!(query.max_distance(100.0).first_blocker(segment).map(|hit| hit.distance), Some(5.0));
+        assert!(query.max_distance(-1.0).is_clear(segment));
+        assert!(LosQuery::default().is_clear(segment));
+    }
+
+    #[test]
+    fn test_start_inside_blocker() {
+        let spheres = [BoundingSphere::new(Vector::ZERO, 1.0), BoundingSphere::new(Vector::new(5.0, 0.0, 0.0), 1.0)];
+        let boxes = [BoundingBox::new(Vector::splat(-0.5), Vector::splat(0.5))];
+        let query = LosQuery::new().boxes(&boxes).spheres(&spheres);
+
+        let hit = query.first_blocker(segment_along_x(10.0)).unwrap();
+        assert_eq!((hit.blocker, hit.distance), (LosBlocker::Box(0), 0.0));
+
+        // A zero-length segment is blocked only if it sits inside a blocker
+        let point = LineSegment::new(Vector::new(5.0, 0.0, 0.0), Vector::new(5.0, 0.0, 0.0));
+        assert_eq!(query.first_blocker(point).map(|hit| hit.blocker), Some(LosBlocker::Sphere(1)));
+        assert!(query.is_clear(LineSegment::new(Vector::splat(3.0), Vector::splat(3.0))));
+    }
+}
@@ -1,9 +1,15 @@
 //! Line segment representation for geometric operations
 
-use crate::vector::Vector;
+use crate::vector::{Vector, Vector2D};
+use crate::bounds::{BoundingBox, BoundingSphere};
 use crate::BinarySerializable;
+use super::nearly_equal::max_error;
+use super::{NearlyEqual, Ray};
 use serde::{Deserialize, Serialize};
-use std::fmt;
+use core::fmt;
+
+/// Relative cross product below which two directions are treated as parallel
+const PARALLEL_TOLERANCE: f64 = 1e-12;
 
 /// Line segment representation
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
@@ -24,6 +30,38 @@ impl fmt::Display for LineSegment {
 
 impl BinarySerializable for LineSegment {}
 
+/// Segments are directed: a reversed segment is not nearly equal
+impl NearlyEqual for LineSegment {
+    fn max_component_error(&self, other: &Self) -> f64 {
+        max_error([self.start.max_component_error(&other.start), self.end.max_component_error(&other.end)])
+    }
+}
+
+/// Closest points between two line segments, from `LineSegment::closest_points_to_segment`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SegmentClosestPoints {
+    /// Closest point on the segment the query was made on
+    pub point: Vector,
+    /// Closest point on the other segment
+    pub other_point: Vector,
+    /// Position of `point` along its segment, from 0 at the start to 1 at the end
+    pub time: f64,
+    /// Position of `other_point` along the other segment
+    pub other_time: f64,
+}
+
+impl SegmentClosestPoints {
+    /// Distance between the two points
+    pub fn distance(&self) -> f64 {
+        self.point.distance(self.other_point)
+    }
+
+    /// Squared distance between the two points (faster)
+    pub fn distance_squared(&self) -> f64 {
+        self.point.distance_squared(self.other_point)
+    }
+}
+
 impl LineSegment {
     /// Create a new line segment
     pub fn new(start: Vector, end: Vector) -> Self {
@@ -83,7 +121,7 @@ impl LineSegment {
         
         let to_point = point - self.start;
         let t = to_point.dot(segment_vec) / segment_length_squared;
-        self.lerp(t as f64)
+        self.lerp(t)
     }
 
     /// Get the distance from the line segment to a point
@@ -123,15 +161,235 @@ impl LineSegment {
     }
 
     /// Get the closest points between this line segment and another
-    pub fn closest_points_to_segment(self, other: LineSegment) -> (Vector, Vector) {
-        // This is a simplified implementation - for production use,
-        // a more sophisticated algorithm might be needed
-        let point_on_self = self.closest_point_to(other.center());
-        let point_on_other = other.closest_point_to(point_on_self);
-        (point_on_self, point_on_other)
+    ///
+    /// Ericson, *Real-Time Collision Detection* §5.1.9: both parameters are
+    /// clamped to `[0, 1]`, and zero-length segments act as points. For
+    /// parallel segments any pair at the minimum distance is valid; this one
+    /// starts from `self.start` and clamps onto the overlap.
+    pub fn closest_points_to_segment(self, other: LineSegment) -> SegmentClosestPoints {
+        let d1 = self.end - self.start;
+        let d2 = other.end - other.start;
+        let r = self.start - other.start;
+        let a = d1.length_squared();
+        let e = d2.length_squared();
+        let f = d2.dot(r);
+
+        let (time, other_time) = if a < f64::EPSILON && e < f64::EPSILON {
+            (0.0, 0.0)
+        } else if a < f64::EPSILON {
+            (0.0, (f / e).clamp(0.0, 1.0))
+        } else {
+            let c = d1.dot(r);
+            if e < f64::EPSILON {
+                ((-c / a).clamp(0.0, 1.0), 0.0)
+            } else {
+                let b = d1.dot(d2);
+                // a * e * sin²(angle): zero when the segments are parallel
+                let denominator = a * e - b * b;
+                let time = if denominator > PARALLEL_TOLERANCE * a * e {
+                    ((b * f - c * e) / denominator).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                };
+                // Closest point on the other line to `time`, re-clamping `time` if it falls off the end
+                let other_time = (b * time + f) / e;
+                if other_time < 0.0 {
+                    ((-c / a).clamp(0.0, 1.0), 0.0)
+                } else if other_time > 1.0 {
+                    (((b - c) / a).clamp(0.0, 1.0), 1.0)
+                } else {
+                    (time, other_time)
+                }
+            }
+        };
+
+        SegmentClosestPoints {
+            point: self.lerp_unclamped(time),
+            other_point: other.lerp_unclamped(other_time),
+            time,
+            other_time,
+        }
+    }
+
+    /// Get the shortest distance between this segment and another
+    pub fn distance_to_segment(self, other: LineSegment) -> f64 {
+        self.closest_points_to_segment(other).distance()
+    }
+
+    /// Intersect the segments' projections onto the XY plane, ignoring Z
+    ///
+    /// Same rules as `LineSegment2D::intersect_segment`, including touching
+    /// endpoints and collinear overlaps.
+    pub fn intersects_segment_2d(self, other: LineSegment) -> Option<Vector2D> {
+        let flatten = |segment: LineSegment| LineSegment2D::new(segment.start.truncate(), segment.end.truncate());
+        flatten(self).intersect_segment(flatten(other))
+    }
+
+    /// Intersect the segment with a box, returning the entry time in `[0, 1]`
+    ///
+    /// Same slab test as `Ray::intersects_box`, limited to the segment:
+    /// `Some(0.0)` if the start is inside, and touching a face counts as a hit.
+    pub fn intersects_box(self, bbox: BoundingBox) -> Option<f64> {
+        self.as_ray().intersects_box(bbox).filter(|&time| time <= 1.0)
+    }
+
+    /// Intersect the segment with a sphere, returning the entry time in `[0, 1]`
+    ///
+    /// `Some(0.0)` if the start is inside; touching the surface counts as a hit.
+    pub fn intersects_sphere(self, sphere: BoundingSphere) -> Option<f64> {
+        if sphere.contains_point(self.start) {
+            return Some(0.0);
+        }
+        self.as_ray().intersects_sphere(sphere).map(|(near, _)| near).filter(|&time| time <= 1.0)
+    }
+
+    /// Index of the box the segment enters first, if any blocks it
+    ///
+    /// Boxes are ranked by distance along the segment, not array order; on
+    /// a tie the lower index wins. Stops early once a box containing the
+    /// start is found.
+    pub fn blocked_by_boxes(self, boxes: &[BoundingBox]) -> Option<usize> {
+        nearest_hit(boxes.iter().map(|&bbox| self.intersects_box(bbox))).map(|(index, _)| index)
+    }
+
+    /// Index of the sphere the segment enters first, if any blocks it
+    ///
+    /// Same ordering as `blocked_by_boxes`.
+    pub fn blocked_by_spheres(self, spheres: &[BoundingSphere]) -> Option<usize> {
+        nearest_hit(spheres.iter().map(|&sphere| self.intersects_sphere(sphere))).map(|(index, _)| index)
+    }
+
+    /// The segment as a ray whose distances are fractions of the segment
+    fn as_ray(self) -> Ray {
+        Ray { origin: self.start, direction: self.direction_vector() }
+    }
+}
+
+/// Index and time of the earliest hit, preferring lower indices on ties
+///
+/// Stops consuming `hits` at a hit at time 0, which nothing can beat.
+pub(crate) fn nearest_hit(hits: impl Iterator<Item = Option<f64>>) -> Option<(usize, f64)> {
+    let mut nearest: Option<(usize, f64)> = None;
+    for (index, hit) in hits.enumerate() {
+        let Some(time) = hit else { continue };
+        if nearest.is_none_or(|(_, best)| time < best) {
+            nearest = Some((index, time));
+            if time <= 0.0 {
+                break;
+            }
+        }
+    }
+    nearest
+}
+
+/// 2D line segment for top-down queries
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LineSegment2D {
+    /// Start point of the line segment
+    pub start: Vector2D,
+    /// End point of the line segment
+    pub end: Vector2D,
+}
+
+impl fmt::Display for LineSegment2D {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "LineSegment2D(Start: ({:.3}, {:.3}), End: ({:.3}, {:.3}))",
+               self.start.x, self.start.y, self.end.x, self.end.y)
+    }
+}
+
+impl BinarySerializable for LineSegment2D {}
+
+impl LineSegment2D {
+    /// Create a new line segment
+    pub fn new(start: Vector2D, end: Vector2D) -> Self {
+        Self { start, end }
+    }
+
+    /// Get the direction vector of the line segment (not normalized)
+    pub fn direction_vector(self) -> Vector2D {
+        self.end - self.start
+    }
+
+    /// Get the normalized direction vector of the line segment
+    pub fn direction(self) -> Vector2D {
+        (self.end - self.start).normalize()
+    }
+
+    /// Get the length of the line segment
+    pub fn length(self) -> f64 {
+        (self.end - self.start).length()
+    }
+
+    /// Get the squared length of the line segment (faster than length())
+    pub fn length_squared(self) -> f64 {
+        (self.end - self.start).length_squared()
+    }
+
+    /// Get the center point of the line segment
+    pub fn center(self) -> Vector2D {
+        (self.start + self.end) * 0.5
+    }
+
+    /// Get a point along the line segment (t=0 is start, t=1 is end)
+    pub fn lerp(self, t: f64) -> Vector2D {
+        self.start.lerp(self.end, t.clamp(0.0, 1.0))
+    }
+
+    /// Get the closest point on the line segment to a given point
+    pub fn closest_point_to(self, point: Vector2D) -> Vector2D {
+        let segment_vec = self.end - self.start;
+        let segment_length_squared = segment_vec.length_squared();
+        if segment_length_squared < f64::EPSILON {
+            return self.start;
+        }
+        self.lerp((point - self.start).dot(segment_vec) / segment_length_squared)
+    }
+
+    /// Get the distance from the line segment to a point
+    pub fn distance_to_point(self, point: Vector2D) -> f64 {
+        (point - self.closest_point_to(point)).length()
+    }
+
+    /// Check if a point is approximately on the line segment
+    pub fn contains_point(self, point: Vector2D, tolerance: f64) -> bool {
+        self.distance_to_point(point) <= tolerance
+    }
+
+    /// Find where this segment crosses `other`
+    ///
+    /// Touching endpoints count. When the segments are collinear and overlap,
+    /// the overlap is a segment rather than a point; the overlap point nearest
+    /// to `self.start` is returned.
+    pub fn intersect_segment(self, other: LineSegment2D) -> Option<Vector2D> {
+        let r = self.end - self.start;
+        let s = other.end - other.start;
+        let offset = other.start - self.start;
+        let denominator = r.perp_dot(s);
+        let scale = r.length() * s.length();
+
+        if denominator.abs() > PARALLEL_TOLERANCE * scale {
+            let t = offset.perp_dot(s) / denominator;
+            let u = offset.perp_dot(r) / denominator;
+            return ((0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u)).then(|| self.start + r * t);
+        }
+
+        // Parallel (or degenerate): only collinear segments can touch
+        let r_length_squared = r.length_squared();
+        if r_length_squared < f64::EPSILON {
+            return other.contains_point(self.start, PARALLEL_TOLERANCE).then_some(self.start);
+        }
+        if offset.perp_dot(r).abs() > PARALLEL_TOLERANCE * r_length_squared.max(offset.length() * r.length()) {
+            return None;
+        }
+        let t0 = offset.dot(r) / r_length_squared;
+        let t1 = t0 + s.dot(r) / r_length_squared;
+        let (low, high) = (t0.min(t1).max(0.0), t0.max(t1).min(1.0));
+        (low <= high).then(|| self.start + r * low)
     }
 }
 
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -278,4 +536,225 @@ mod tests {
         assert!(tiny_length >= 0.0);
         assert!(tiny_length < 0.001);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_line_segment2d_intersection() {
+        let horizontal = LineSegment2D::new(Vector2D::new(0.0, 0.0), Vector2D::new(10.0, 0.0));
+        let vertical = LineSegment2D::new(Vector2D::new(4.0, -5.0), Vector2D::new(4.0, 5.0));
+        assert_eq!(horizontal.intersect_segment(vertical), Some(Vector2D::new(4.0, 0.0)));
+
+        // Touching at an endpoint
+        let touching = LineSegment2D::new(Vector2D::new(10.0, 0.0), Vector2D::new(12.0, 3.0));
+        assert_eq!(horizontal.intersect_segment(touching), Some(Vector2D::new(10.0, 0.0)));
+
+        // Would cross if extended
+        let short = LineSegment2D::new(Vector2D::new(4.0, 1.0), Vector2D::new(4.0, 5.0));
+        assert_eq!(horizontal.intersect_segment(short), None);
+
+        // Parallel, not collinear
+        let parallel = LineSegment2D::new(Vector2D::new(0.0, 1.0), Vector2D::new(10.0, 1.0));
+        assert_eq!(horizontal.intersect_segment(parallel), None);
+    }
+
+    #[test]
+    fn test_line_segment2d_collinear_overlap() {
+        let a = LineSegment2D::new(Vector2D::new(0.0, 0.0), Vector2D::new(10.0, 10.0));
+        let b = LineSegment2D::new(Vector2D::new(15.0, 15.0), Vector2D::new(5.0, 5.0));
+        assert_eq!(a.intersect_segment(b), Some(Vector2D::new(5.0, 5.0)));
+        assert_eq!(b.intersect_segment(a), Some(Vector2D::new(10.0, 10.0)));
+
+        // Collinear but disjoint, and collinear touching at one point
+        let disjoint = LineSegment2D::new(Vector2D::new(11.0, 11.0), Vector2D::new(12.0, 12.0));
+        assert_eq!(a.intersect_segment(disjoint), None);
+        let end_to_end = LineSegment2D::new(Vector2D::new(10.0, 10.0), Vector2D::new(20.0, 20.0));
+        assert_eq!(a.intersect_segment(end_to_end), Some(Vector2D::new(10.0, 10.0)));
+
+        // Degenerate segment lying on the other one
+        let point = LineSegment2D::new(Vector2D::new(3.0, 3.0), Vector2D::new(3.0, 3.0));
+        assert_eq!(point.intersect_segment(a), Some(Vector2D::new(3.0, 3.0)));
+    }
+
+    #[test]
+    fn test_line_segment2d_closest_point_and_serialization() {
+        let segment = LineSegment2D::new(Vector2D::ZERO, Vector2D::new(10.0, 0.0));
+        assert_eq!(segment.closest_point_to(Vector2D::new(3.0, 4.0)), Vector2D::new(3.0, 0.0));
+        assert_eq!(segment.closest_point_to(Vector2D::new(-3.0, 4.0)), Vector2D::ZERO);
+        assert_eq!(segment.distance_to_point(Vector2D::new(13.0, 4.0)), 5.0);
+        assert_eq!(segment.center(), Vector2D::new(5.0, 0.0));
+
+        assert_eq!(format!("{}", segment), "LineSegment2D(Start: (0.000, 0.000), End: (10.000, 0.000))");
+        let json = serde_json::to_string(&segment).unwrap();
+        assert_eq!(serde_json::from_str::<LineSegment2D>(&json).unwrap(), segment);
+        assert_eq!(LineSegment2D::from_binary(&segment.to_binary().unwrap()).unwrap(), segment);
+    }
+
+    #[test]
+    fn test_blocked_by_nearest_not_first() {
+        let boxes = [
+            BoundingBox::new(Vector::new(8.0, -1.0, -1.0), Vector::new(9.0, 1.0, 1.0)),
+            BoundingBox::new(Vector::new(20.0, -1.0, -1.0), Vector::new(21.0, 1.0, 1.0)),
+            BoundingBox::new(Vector::new(3.0, -1.0, -1.0), Vector::new(4.0, 1.0, 1.0)),
+            BoundingBox::new(Vector::new(5.0, 2.0, -1.0), Vector::new(6.0, 3.0, 1.0)),
+        ];
+        let segment = LineSegment::new(Vector::ZERO, Vector::new(10.0, 0.0, 0.0));
+        assert_eq!(segment.blocked_by_boxes(&boxes), Some(2));
+        assert_eq!(segment.intersects_box(boxes[2]), Some(0.3));
+        assert_eq!(segment.blocked_by_boxes(&boxes[3..]), None);
+        assert_eq!(segment.blocked_by_boxes(&[]), None);
+        // Beyond the end of the segment
+        assert_eq!(segment.intersects_box(boxes[1]), None);
+
+        let spheres = [
+            BoundingSphere::new(Vector::new(7.0, 0.0, 0.0), 1.0),
+            BoundingSphere::new(Vector::new(2.0, 0.5, 0.0), 1.0),
+            BoundingSphere::new(Vector::new(-3.0, 0.0, 0.0), 1.0),
+        ];
+        assert_eq!(segment.blocked_by_spheres(&spheres), Some(1));
+        assert_eq!(segment.intersects_sphere(spheres[0]), Some(0.6));
+        assert_eq!(segment.intersects_sphere(spheres[2]), None);
+    }
+
+    #[test]
+    fn test_segment_inside_box() {
+        let bbox = BoundingBox::new(Vector::splat(-10.0), Vector::splat(10.0));
+        let inside = LineSegment::new(Vector::new(-1.0, 0.0, 0.0), Vector::new(1.0, 2.0, 3.0));
+        assert_eq!(inside.intersects_box(bbox), Some(0.0));
+
+        // Ties go to the lower index
+        let boxes = [BoundingBox::new(Vector::new(0.5, -1.0, -1.0), Vector::new(2.0, 5.0, 5.0)), bbox, bbox];
+        assert_eq!(inside.blocked_by_boxes(&boxes), Some(1));
+        assert_eq!(inside.intersects_sphere(BoundingSphere::new(Vector::ZERO, 50.0)), Some(0.0));
+    }
+
+    #[test]
+    fn test_segment_touching_box_face() {
+        let bbox = BoundingBox::new(Vector::ZERO, Vector::ONE);
+
+        // Ending exactly on a face, and sliding along one, both count as blocked
+        let ends_on_face = LineSegment::new(Vector::new(-1.0, 0.5, 0.5), Vector::new(0.0, 0.5, 0.5));
+        assert_eq!(ends_on_face.intersects_box(bbox), Some(1.0));
+        let along_face = LineSegment::new(Vector::new(-1.0, 1.0, 0.5), Vector::new(2.0, 1.0, 0.5));
+        assert!((along_face.intersects_box(bbox).unwrap() - 1.0 / 3.0).abs() < 1e-12);
+
+        let short = LineSegment::new(Vector::new(-1.0, 0.5, 0.5), Vector::new(-1e-9, 0.5, 0.5));
+        assert_eq!(short.intersects_box(bbox), None);
+        let above_face = LineSegment::new(Vector::new(-1.0, 1.0 + 1e-9, 0.5), Vector::new(2.0, 1.0 + 1e-9, 0.5));
+        assert_eq!(above_face.blocked_by_boxes(&[bbox]), None);
+    }
+
+    #[test]
+    fn test_closest_points_between_segments() {
+        let pair = |closest: SegmentClosestPoints| (closest.point, closest.other_point);
+
+        // Skew segments crossing at different heights
+        let a = LineSegment::new(Vector::new(-1.0, 0.0, 0.0), Vector::new(1.0, 0.0, 0.0));
+        let b = LineSegment::new(Vector::new(0.5, -1.0, 2.0), Vector::new(0.5, 1.0, 2.0));
+        assert_eq!(pair(a.closest_points_to_segment(b)), (Vector::new(0.5, 0.0, 0.0), Vector::new(0.5, 0.0, 2.0)));
+
+        // Parallel segments, overlapping and not
+        let parallel = LineSegment::new(Vector::new(0.5, 3.0, 0.0), Vector::new(4.0, 3.0, 0.0));
+        let closest = a.closest_points_to_segment(parallel);
+        assert_eq!(closest.distance(), 3.0);
+        let beyond = LineSegment::new(Vector::new(5.0, 3.0, 0.0), Vector::new(9.0, 3.0, 0.0));
+        assert_eq!(pair(a.closest_points_to_segment(beyond)), (Vector::new(1.0, 0.0, 0.0), Vector::new(5.0, 3.0, 0.0)));
+
+        // Zero-length segments on either side
+        let point = LineSegment::new(Vector::new(0.0, 2.0, 0.0), Vector::new(0.0, 2.0, 0.0));
+        assert_eq!(pair(a.closest_points_to_segment(point)), (Vector::ZERO, Vector::new(0.0, 2.0, 0.0)));
+        assert_eq!(pair(point.closest_points_to_segment(a)), (Vector::new(0.0, 2.0, 0.0), Vector::ZERO));
+    }
+
+    fn segment(start: (f64, f64, f64), end: (f64, f64, f64)) -> LineSegment {
+        LineSegment::new(Vector::new(start.0, start.1, start.2), Vector::new(end.0, end.1, end.2))
+    }
+
+    #[test]
+    fn test_closest_points_parallel_overlapping() {
+        let a = segment((0.0, 0.0, 0.0), (10.0, 0.0, 0.0));
+        let b = segment((5.0, 3.0, 0.0), (15.0, 3.0, 0.0));
+        let closest = a.closest_points_to_segment(b);
+        assert_eq!(closest.point, Vector::new(5.0, 0.0, 0.0));
+        assert_eq!(closest.other_point, Vector::new(5.0, 3.0, 0.0));
+        assert_eq!((closest.time, closest.other_time), (0.5, 0.0));
+        assert_eq!(closest.distance(), 3.0);
+
+        // Reversed direction, fully inside the other
+        let inner = segment((8.0, 0.0, 4.0), (2.0, 0.0, 4.0));
+        assert_eq!(a.distance_to_segment(inner), 4.0);
+        assert_eq!(inner.distance_to_segment(a), 4.0);
+
+        // Collinear and overlapping
+        let collinear = segment((-5.0, 0.0, 0.0), (5.0, 0.0, 0.0));
+        assert_eq!(a.distance_to_segment(collinear), 0.0);
+
+        // Parallel, with a gap along the shared direction
+        let beyond = segment((13.0, 4.0, 0.0), (20.0, 4.0, 0.0));
+        let closest = a.closest_points_to_segment(beyond);
+        assert_eq!((closest.point, closest.other_point), (Vector::new(10.0, 0.0, 0.0), Vector::new(13.0, 4.0, 0.0)));
+        assert_eq!(closest.distance(), 5.0);
+    }
+
+    #[test]
+    fn test_closest_points_perpendicular_skew() {
+        let a = segment((-5.0, 0.0, 0.0), (5.0, 0.0, 0.0));
+        let b = segment((1.0, -5.0, 2.0), (1.0, 5.0, 2.0));
+        let closest = a.closest_points_to_segment(b);
+        assert_eq!(closest.point, Vector::new(1.0, 0.0, 0.0));
+        assert_eq!(closest.other_point, Vector::new(1.0, 0.0, 2.0));
+        assert_eq!((closest.time, closest.other_time), (0.6, 0.5));
+        assert_eq!(closest.distance(), 2.0);
+
+        // The infinite lines meet beyond both segments' ends, so both parameters clamp
+        let off_end = segment((8.0, 1.0, 2.0), (8.0, 5.0, 2.0));
+        let closest = a.closest_points_to_segment(off_end);
+        assert_eq!((closest.time, closest.other_time), (1.0, 0.0));
+        assert_eq!(closest.distance_squared(), 14.0);
+
+        // The old center-based approximation got this one wrong
+        let long = segment((0.0, 0.0, 0.0), (100.0, 0.0, 0.0));
+        let crossing = segment((1.0, -1.0, 1.0), (1.0, 99.0, 1.0));
+        assert_eq!(long.distance_to_segment(crossing), 1.0);
+    }
+
+    #[test]
+    fn test_closest_points_coincident_and_degenerate() {
+        let a = segment((0.0, 0.0, 0.0), (1.0, 0.0, 0.0));
+        let b = segment((1.0, 0.0, 0.0), (1.0, 1.0, 1.0));
+        let closest = a.closest_points_to_segment(b);
+        assert_eq!((closest.point, closest.other_point), (Vector::new(1.0, 0.0, 0.0), Vector::new(1.0, 0.0, 0.0)));
+        assert_eq!((closest.time, closest.other_time), (1.0, 0.0));
+        assert_eq!(closest.distance(), 0.0);
+
+        // Same start point, diverging
+        let diverging = segment((0.0, 0.0, 0.0), (0.0, 3.0, 0.0));
+        assert_eq!(a.closest_points_to_segment(diverging).distance(), 0.0);
+
+        // A zero-length segment on either side acts as a point
+        let point = segment((2.0, 2.0, 0.0), (2.0, 2.0, 0.0));
+        let closest = point.closest_points_to_segment(a);
+        assert_eq!((closest.point, closest.other_point), (Vector::new(2.0, 2.0, 0.0), Vector::new(1.0, 0.0, 0.0)));
+        assert_eq!(closest.other_time, 1.0);
+        assert_eq!(closest.distance_squared(), 5.0);
+        let closest = a.closest_points_to_segment(point);
+        assert_eq!((closest.time, closest.other_time), (1.0, 0.0));
+
+        // Both zero-length
+        let other_point = segment((2.0, 5.0, 4.0), (2.0, 5.0, 4.0));
+        assert_eq!(point.distance_to_segment(other_point), 5.0);
+    }
+
+    #[test]
+    fn test_intersects_segment_2d() {
+        // Crossing in XY at different heights still intersects
+        let a = segment((0.0, 0.0, 0.0), (10.0, 10.0, 0.0));
+        let b = segment((0.0, 10.0, 50.0), (10.0, 0.0, -50.0));
+        assert_eq!(a.intersects_segment_2d(b), Some(Vector2D::new(5.0, 5.0)));
+
+        let short = segment((0.0, 10.0, 0.0), (4.0, 6.0, 0.0));
+        assert_eq!(a.intersects_segment_2d(short), None);
+
+        // Vertical segment projects to a point
+        let post = segment((3.0, 3.0, 0.0), (3.0, 3.0, 10.0));
+        assert_eq!(post.intersects_segment_2d(a), Some(Vector2D::new(3.0, 3.0)));
+    }
+}
@@ -2,13 +2,43 @@
 
 pub mod int_vector;
 pub mod int_vector2;
+pub mod int_rect;
 pub mod plane;
 pub mod ray;
 pub mod line_segment;
+pub mod line_of_sight;
+pub mod hit_result;
+pub mod curve;
+pub mod floodfill;
+pub mod fmath;
+pub mod axis;
+pub mod random;
+pub mod noise;
+pub mod sampling;
+pub mod convex;
+pub mod nearly_equal;
+pub mod quantized_key;
+pub mod units;
+#[cfg(not(feature = "std"))]
+pub(crate) mod float_math;
 
 // Re-export all types for convenience
 pub use int_vector::*;
 pub use int_vector2::*;
+pub use int_rect::*;
 pub use plane::*;
 pub use ray::*;
-pub use line_segment::*;
\ No newline at end of file
+pub use line_segment::*;
+pub use line_of_sight::*;
+pub use hit_result::*;
+pub use curve::*;
+pub use floodfill::*;
+// `Float`, `lerp` and `wrap` are too generic for the crate root; use `fmath::`
+pub use fmath::{grid_snap, inv_lerp, map_range_clamped, map_range_unclamped, ping_pong, smooth_step};
+pub use axis::*;
+pub use random::*;
+pub use noise::*;
+pub use sampling::*;
+pub use convex::*;
+pub use nearly_equal::*;
+pub use units::*;
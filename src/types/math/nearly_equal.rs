@@ -0,0 +1,184 @@
+//! Approximate equality shared by every floating point type
+
+use crate::vector::{Vector, Vector2D, Vector4};
+use glam::DQuat;
+
+/// Default tolerance for [`NearlyEqual`] and [`assert_nearly_eq!`](crate::assert_nearly_eq) (UE: `KINDA_SMALL_NUMBER`)
+pub const NEARLY_EQUAL_TOLERANCE: f64 = 1.0e-4;
+
+/// Approximate equality with an absolute, per-component tolerance
+///
+/// Two values are nearly equal when no component differs by more than the
+/// tolerance. Types with several representations of the same value compare
+/// the closest ones: a quaternion matches its negation and rotator angles
+/// are compared modulo 360°. NaN is never nearly equal to anything.
+pub trait NearlyEqual {
+    /// Largest component difference from `other`, or NaN if either side has a NaN
+    fn max_component_error(&self, other: &Self) -> f64;
+
+    /// Check if every component is within `tolerance` of `other`
+    fn nearly_equal(&self, other: &Self, tolerance: f64) -> bool {
+        self.max_component_error(other) <= tolerance
+    }
+}
+
+/// Largest of several errors, propagating NaN (which `f64::max` would drop)
+pub(crate) fn max_error(errors: impl IntoIterator<Item = f64>) -> f64 {
+    errors.into_iter().fold(0.0, |max, error| {
+        if max.is_nan() || error.is_nan() {
+            f64::NAN
+        } else {
+            max.max(error)
+        }
+    })
+}
+
+/// Largest absolute difference between paired components
+pub(crate) fn max_difference(pairs: impl IntoIterator<Item = (f64, f64)>) -> f64 {
+    // Equal infinities (e.g. `BoundingBox::EMPTY`) differ by nothing rather than NaN
+    max_error(pairs.into_iter().map(|(a, b)| if a == b { 0.0 } else { (a - b).abs() }))
+}
+
+impl NearlyEqual for f64 {
+    fn max_component_error(&self, other: &Self) -> f64 {
+        max_difference([(*self, *other)])
+    }
+}
+
+impl NearlyEqual for Vector {
+    fn max_component_error(&self, other: &Self) -> f64 {
+        max_difference(self.to_array().into_iter().zip(other.to_array()))
+    }
+}
+
+impl NearlyEqual for Vector2D {
+    fn max_component_error(&self, other: &Self) -> f64 {
+        max_difference(self.to_array().into_iter().zip(other.to_array()))
+    }
+}
+
+impl NearlyEqual for Vector4 {
+    fn max_component_error(&self, other: &Self) -> f64 {
+        max_difference(self.to_array().into_iter().zip(other.to_array()))
+    }
+}
+
+/// `q` and `-q` are the same rotation, so the closer of the two is compared
+impl NearlyEqual for DQuat {
+    fn max_component_error(&self, other: &Self) -> f64 {
+        let same = max_difference(self.to_array().into_iter().zip(other.to_array()));
+        let negated = max_difference(self.to_array().into_iter().zip((-*other).to_array()));
+        if same.is_nan() {
+            same
+        } else {
+            same.min(negated)
+        }
+    }
+}
+
+/// Assert that two [`NearlyEqual`] values are within a tolerance
+///
+/// The tolerance defaults to [`NEARLY_EQUAL_TOLERANCE`]. On failure both
+/// values and the largest component error are printed.
+///
+/// ```
+/// use ue_types::{assert_nearly_eq, Vector};
+///
+/// assert_nearly_eq!(Vector::new(1.0, 2.0, 3.0), Vector::new(1.0, 2.0, 3.00001));
+/// assert_nearly_eq!(0.1 + 0.2, 0.3, 1e-12);
+/// ```
+#[macro_export]
+macro_rules! assert_nearly_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_nearly_eq!($left, $right, $crate::NEARLY_EQUAL_TOLERANCE)
+    };
+    ($left:expr, $right:expr, $tolerance:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                let tolerance: f64 = $tolerance;
+                if !$crate::NearlyEqual::nearly_equal(left, right, tolerance) {
+                    let error = $crate::NearlyEqual::max_component_error(left, right);
+                    panic!(
+                        "assertion `left ≈ right` failed\n     left: {:?}\n    right: {:?}\nmax error: {} (tolerance {})",
+                        left, right, error, tolerance
+                    );
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{
+        BoundingBox, BoundingSphere, LineSegment, LinearColor, Plane, Quaternion, Ray, Rotator, Transform,
+    };
+
+    #[test]
+    fn test_component_errors() {
+        let a = Vector::new(1.0, 2.0, 3.0);
+        assert_eq!(a.max_component_error(&Vector::new(1.5, 1.75, 3.0)), 0.5);
+        assert!(a.nearly_equal(&(a + Vector::splat(5e-5)), NEARLY_EQUAL_TOLERANCE));
+        assert!(!a.nearly_equal(&Vector::new(1.0, 2.0, f64::NAN), 1.0));
+        assert!(!f64::NAN.nearly_equal(&f64::NAN, f64::INFINITY));
+        assert!(Vector2D::new(1.0, 2.0).nearly_equal(&Vector2D::new(1.001, 2.0), 1e-2));
+        assert!(!Vector4::ONE.nearly_equal(&Vector4::new(1.0, 1.0, 1.0, 1.1), 1e-2));
+        assert!(BoundingBox::EMPTY.nearly_equal(&BoundingBox::EMPTY, 0.0));
+    }
+
+    #[test]
+    fn test_equivalent_representations() {
+        // q and -q are the same orientation
+        let quat = Quaternion::from_axis_angle(Vector::new(1.0, -2.0, 0.5), 75.0);
+        assert!(quat.nearly_equal(&-quat, 1e-12));
+        assert!(!quat.nearly_equal(&quat.inverse(), 1e-3));
+
+        // Angles wrap around
+        let rotator = Rotator::new(179.9, -0.05, 360.0);
+        assert!(rotator.nearly_equal(&Rotator::new(-180.1, 359.95, 0.0), 1e-9));
+        assert!(rotator.is_nearly_equal(Rotator::new(-180.1, 359.95, 0.0), 1e-9));
+        assert!(!rotator.nearly_equal(&Rotator::new(179.9, 0.05, 0.0), 0.05));
+
+        let transform = Transform::new(Vector::new(1.0, 2.0, 3.0), *quat, Vector::splat(2.0));
+        let mut flipped = transform;
+        flipped.rotation = -flipped.rotation;
+        flipped.location.z += 1e-6;
+        assert!(transform.nearly_equal(&flipped, 1e-5));
+        assert!(transform.is_nearly_equal_per_component(flipped, 1e-5));
+        // The older Euclidean check doesn't treat -q as the same rotation
+        assert!(!transform.is_nearly_equal(flipped, 1e-5));
+        assert!(!transform.nearly_equal(&flipped, 1e-7));
+    }
+
+    #[test]
+    fn test_geometric_types() {
+        let bbox = BoundingBox::new(Vector::ZERO, Vector::ONE);
+        assert_nearly_eq!(bbox, BoundingBox::new(Vector::splat(1e-5), Vector::ONE));
+        assert!(!bbox.nearly_equal(&BoundingBox::new(Vector::ZERO, Vector::splat(1.1)), 0.01));
+
+        let sphere = BoundingSphere::new(Vector::X, 2.0);
+        assert_eq!(sphere.max_component_error(&BoundingSphere::new(Vector::X, 2.5)), 0.5);
+        assert_nearly_eq!(Plane::new(Vector::Z, 3.0), Plane::new(Vector::Z, 3.00001));
+        assert!(!Plane::new(Vector::Z, 3.0).nearly_equal(&Plane::new(-Vector::Z, -3.0), 0.1));
+        assert_nearly_eq!(Ray::new(Vector::ZERO, Vector::X), Ray::new(Vector::splat(1e-6), Vector::X), 1e-5);
+        assert_eq!(
+            LineSegment::new(Vector::ZERO, Vector::X).max_component_error(&LineSegment::new(Vector::ZERO, Vector::Y)),
+            1.0
+        );
+        assert_nearly_eq!(LinearColor::new(0.5, 0.25, 1.0, 1.0), LinearColor::new(0.50001, 0.25, 1.0, 1.0));
+        assert!(LinearColor::RED.is_nearly_equal(LinearColor::new(0.999, 0.0, 0.0, 1.0), 0.01));
+    }
+
+    #[test]
+    fn test_assert_macro_message() {
+        let failure = std::panic::catch_unwind(|| {
+            assert_nearly_eq!(Vector::new(1.0, 2.0, 3.0), Vector::new(1.0, 2.25, 3.0), 0.1);
+        })
+        .unwrap_err();
+        let message = failure.downcast_ref::<String>().unwrap();
+        assert!(message.contains("left: DVec3(1.0, 2.0, 3.0)"), "{}", message);
+        assert!(message.contains("right: DVec3(1.0, 2.25, 3.0)"), "{}", message);
+        assert!(message.contains("max error: 0.25 (tolerance 0.1)"), "{}", message);
+    }
+}
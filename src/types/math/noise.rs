@@ -0,0 +1,328 @@
+//! Deterministic gradient noise for procedural placement
+//!
+//! Not compatible with UE's `FMath::PerlinNoise1D/2D/3D`: the engine
+//! evaluates in `f32`, takes no seed and picks its own gradients and output
+//! scaling, so its values differ from these. Servers and clients only agree
+//! when both sample through this crate.
+//!
+//! Uses Ken Perlin's improved noise: quintic fade, hashed lattice gradients
+//! and a 256-entry permutation table. Everything is plain IEEE `f64`
+//! arithmetic (`+ - *` and `floor`), with no fused multiply-add or other
+//! fast-math, so the same input gives the same bits on every platform.
+//!
+//! Seed 0 uses Perlin's reference permutation. Other seeds shuffle it with a
+//! `RandomStream`, so a seed picks the same field everywhere.
+
+use crate::types::{Vector, Vector2D};
+use super::RandomStream;
+use core::fmt;
+#[cfg(not(feature = "std"))]
+use crate::types::math::float_math::FloatMath;
+
+/// Ken Perlin's reference permutation of 0..=255
+const REFERENCE_PERMUTATION: [u8; 256] = [
+    151, 160, 137, 91, 90, 15, 131, 13, 201, 95, 96, 53, 194, 233, 7, 225, 140, 36, 103, 30, 69, 142, 8, 99, 37, 240,
+    21, 10, 23, 190, 6, 148, 247, 120, 234, 75, 0, 26, 197, 62, 94, 252, 219, 203, 117, 35, 11, 32, 57, 177, 33, 88,
+    237, 149, 56, 87, 174, 20, 125, 136, 171, 168, 68, 175, 74, 165, 71, 134, 139, 48, 27, 166, 77, 146, 158, 231, 83,
+    111, 229, 122, 60, 211, 133, 230, 220, 105, 92, 41, 55, 46, 245, 40, 244, 102, 143, 54, 65, 25, 63, 161, 1, 216,
+    80, 73, 209, 76, 132, 187, 208, 89, 18, 169, 200, 196, 135, 130, 116, 188, 159, 86, 164, 100, 109, 198, 173, 186,
+    3, 64, 52, 217, 226, 250, 124, 123, 5, 202, 38, 147, 118, 126, 255, 82, 85, 212, 207, 206, 59, 227, 47, 16, 58,
+    17, 182, 189, 28, 42, 223, 183, 170, 213, 119, 248, 152, 2, 44, 154, 163, 70, 221, 153, 101, 155, 167, 43, 172, 9,
+    129, 22, 39, 253, 19, 98, 108, 110, 79, 113, 224, 232, 178, 185, 112, 104, 218, 246, 97, 228, 251, 34, 242, 193,
+    238, 210, 144, 12, 191, 179, 162, 241, 81, 51, 145, 235, 249, 14, 239, 107, 49, 192, 214, 31, 181, 199, 106, 157,
+    184, 84, 204, 176, 115, 121, 50, 45, 127, 4, 150, 254, 138, 236, 205, 93, 222, 114, 67, 29, 24, 72, 243, 141, 128,
+    195, 78, 66, 215, 61, 156, 180,
+];
+
+/// Perlin noise with a cached, seeded permutation table
+///
+/// Building the table shuffles 256 entries, so keep one of these around
+/// when sampling many points with the same seed. Two instances built from
+/// the same seed always produce identical values.
+///
+/// ```
+/// use ue_types::*;
+///
+/// let noise = PerlinNoise::new(1234);
+/// let density = noise.perlin_2d(Vector2D::new(12.3, 45.6));
+/// assert!((-1.0..=1.0).contains(&density));
+/// assert_eq!(density, perlin_2d(Vector2D::new(12.3, 45.6), 1234));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PerlinNoise {
+    seed: i32,
+    permutation: [u8; 256],
+}
+
+impl fmt::Display for PerlinNoise {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "PerlinNoise(Seed: {})", self.seed)
+    }
+}
+
+impl PerlinNoise {
+    /// Build the permutation table for `seed`
+    pub fn new(seed: i32) -> Self {
+        let mut permutation = REFERENCE_PERMUTATION;
+        if seed != 0 {
+            let mut stream = RandomStream::new(seed);
+            for i in (1..permutation.len()).rev() {
+                let j = stream.rand_range(0, i as i32) as usize;
+                permutation.swap(i, j);
+            }
+        }
+        Self { seed, permutation }
+    }
+
+    /// Seed the table was built from
+    pub fn seed(&self) -> i32 {
+        self.seed
+    }
+
+    /// 1D noise in [-1, 1]; zero at whole numbers
+    pub fn perlin_1d(&self, x: f64) -> f64 {
+        let (cell, x) = lattice(x);
+        let a = grad_1d(self.hash(cell), x);
+        let b = grad_1d(self.hash(cell + 1), x - 1.0);
+        // Gradients go up to 8, so the raw value stays within [-4, 4]
+        lerp(a, b, fade(x)) * 0.25
+    }
+
+    /// 2D noise in [-1, 1]; zero at integer lattice points
+    pub fn perlin_2d(&self, position: Vector2D) -> f64 {
+        let (cx, x) = lattice(position.x);
+        let (cy, y) = lattice(position.y);
+        let (u, v) = (fade(x), fade(y));
+
+        let a = self.hash(cx) + cy;
+        let b = self.hash(cx + 1) + cy;
+        let bottom = lerp(grad_2d(self.hash(a), x, y), grad_2d(self.hash(b), x - 1.0, y), u);
+        let top = lerp(grad_2d(self.hash(a + 1), x, y - 1.0), grad_2d(self.hash(b + 1), x - 1.0, y - 1.0), u);
+        clamp_unit(lerp(bottom, top, v))
+    }
+
+    /// 3D noise in [-1, 1]; zero at integer lattice points
+    pub fn perlin_3d(&self, position: Vector) -> f64 {
+        let (cx, x) = lattice(position.x);
+        let (cy, y) = lattice(position.y);
+        let (cz, z) = lattice(position.z);
+        let (u, v, w) = (fade(x), fade(y), fade(z));
+
+        let a = self.hash(cx) + cy;
+        let aa = self.hash(a) + cz;
+        let ab = self.hash(a + 1) + cz;
+        let b = self.hash(cx + 1) + cy;
+        let ba = self.hash(b) + cz;
+        let bb = self.hash(b + 1) + cz;
+
+        let near = lerp(
+            lerp(grad_3d(self.hash(aa), x, y, z), grad_3d(self.hash(ba), x - 1.0, y, z), u),
+            lerp(grad_3d(self.hash(ab), x, y - 1.0, z), grad_3d(self.hash(bb), x - 1.0, y - 1.0, z), u),
+            v,
+        );
+        let far = lerp(
+            lerp(grad_3d(self.hash(aa + 1), x, y, z - 1.0), grad_3d(self.hash(ba + 1), x - 1.0, y, z - 1.0), u),
+            lerp(grad_3d(self.hash(ab + 1), x, y - 1.0, z - 1.0), grad_3d(self.hash(bb + 1), x - 1.0, y - 1.0, z - 1.0), u),
+            v,
+        );
+        // Edge gradients peak slightly above 1; scale back into range
+        clamp_unit(lerp(near, far, w) * 0.97)
+    }
+
+    /// Fractal sum of `octaves` layers of 3D noise, normalized to [-1, 1]
+    ///
+    /// Each octave multiplies the frequency by `lacunarity` and the amplitude
+    /// by `gain` (typically 2.0 and 0.5). Zero octaves give 0.
+    pub fn fbm(&self, position: Vector, octaves: u32, lacunarity: f64, gain: f64) -> f64 {
+        let mut sum = 0.0;
+        let mut total_amplitude = 0.0;
+        let mut amplitude = 1.0;
+        let mut frequency = 1.0;
+        for _ in 0..octaves {
+            sum += self.perlin_3d(position * frequency) * amplitude;
+            total_amplitude += amplitude.abs();
+            amplitude *= gain;
+            frequency *= lacunarity;
+        }
+        if total_amplitude == 0.0 {
+            return 0.0;
+        }
+        clamp_unit(sum / total_amplitude)
+    }
+
+    fn hash(&self, index: i64) -> i64 {
+        i64::from(self.permutation[(index & 0xff) as usize])
+    }
+}
+
+impl Default for PerlinNoise {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+/// 1D Perlin noise in [-1, 1] for `seed`
+///
+/// Builds the permutation table on every call; use `PerlinNoise` for many samples.
+pub fn perlin_1d(x: f64, seed: i32) -> f64 {
+    PerlinNoise::new(seed).perlin_1d(x)
+}
+
+/// 2D Perlin noise in [-1, 1] for `seed`
+pub fn perlin_2d(position: Vector2D, seed: i32) -> f64 {
+    PerlinNoise::new(seed).perlin_2d(position)
+}
+
+/// 3D Perlin noise in [-1, 1] for `seed`
+pub fn perlin_3d(position: Vector, seed: i32) -> f64 {
+    PerlinNoise::new(seed).perlin_3d(position)
+}
+
+/// Fractal Brownian motion over 3D Perlin noise for `seed` (see [`PerlinNoise::fbm`])
+pub fn fbm(position: Vector, octaves: u32, lacunarity: f64, gain: f64, seed: i32) -> f64 {
+    PerlinNoise::new(seed).fbm(position, octaves, lacunarity, gain)
+}
+
+/// Split a coordinate into its lattice cell and the offset within it
+fn lattice(value: f64) -> (i64, f64) {
+    let floor = value.floor();
+    // Saturates for huge inputs, which only changes which cell is hashed
+    (floor as i64, value - floor)
+}
+
+/// Perlin's quintic fade curve 6t^5 - 15t^4 + 10t^3
+fn fade(t: f64) -> f64 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+fn lerp(a: f64, b: f64, alpha: f64) -> f64 {
+    a + alpha * (b - a)
+}
+
+fn clamp_unit(value: f64) -> f64 {
+    value.clamp(-1.0, 1.0)
+}
+
+/// Gradient slopes ±1..±8 so neighbouring cells differ in height
+fn grad_1d(hash: i64, x: f64) -> f64 {
+    let slope = (1 + (hash & 7)) as f64;
+    if hash & 8 == 0 { slope * x } else { -slope * x }
+}
+
+/// One of the eight axis and diagonal directions
+fn grad_2d(hash: i64, x: f64, y: f64) -> f64 {
+    let (u, v) = if hash & 4 == 0 { (x, y) } else { (y, x) };
+    let u = if hash & 1 == 0 { u } else { -u };
+    let v = if hash & 2 == 0 { v } else { -v };
+    // Half the hashes use only the axis component
+    if hash & 8 == 0 { u + v } else { u }
+}
+
+/// One of the twelve cube edge directions (Perlin 2002)
+fn grad_3d(hash: i64, x: f64, y: f64, z: f64) -> f64 {
+    let h = hash & 15;
+    let u = if h < 8 { x } else { y };
+    let v = if h < 4 {
+        y
+    } else if h == 12 || h == 14 {
+        x
+    } else {
+        z
+    };
+    (if h & 1 == 0 { u } else { -u }) + (if h & 2 == 0 { v } else { -v })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    /// Deterministic spread of sample points, including negative coordinates
+    fn sample_points(count: usize) -> Vec<Vector> {
+        let mut stream = RandomStream::new(99);
+        (0..count)
+            .map(|_| Vector::new(
+                stream.frand_range_f64(-300.0, 300.0),
+                stream.frand_range_f64(-300.0, 300.0),
+                stream.frand_range_f64(-300.0, 300.0),
+            ))
+            .collect()
+    }
+
+    #[test]
+    fn test_reference_permutation_is_a_permutation() {
+        let mut seen = [false; 256];
+        for &value in &REFERENCE_PERMUTATION {
+            assert!(!seen[value as usize]);
+            seen[value as usize] = true;
+        }
+
+        let mut shuffled = PerlinNoise::new(7).permutation;
+        assert_ne!(shuffled, REFERENCE_PERMUTATION);
+        shuffled.sort_unstable();
+        assert!(shuffled.iter().enumerate().all(|(i, &value)| value as usize == i));
+    }
+
+    #[test]
+    fn test_pinned_values() {
+        // Zero on the lattice for every seed
+        for seed in [0, 1, -42] {
+            assert_eq!(perlin_1d(3.0, seed), 0.0);
+            assert_eq!(perlin_2d(Vector2D::new(-4.0, 17.0), seed), 0.0);
+            assert_eq!(perlin_3d(Vector::new(2.0, -1.0, 5.0), seed), 0.0);
+        }
+
+        // Hashes 151 and 160 give slopes +8 and +1: lerp(4, -0.5, 0.5) / 4
+        assert_eq!(perlin_1d(0.5, 0), 0.4375);
+
+        // Regression pins computed by this implementation, not captured from
+        // UE (which gives different values); any change here changes every
+        // generated level
+        assert_eq!(perlin_1d(1.25, 0), 0.0948486328125);
+        assert_eq!(perlin_2d(Vector2D::new(0.5, 0.5), 0), -0.375);
+        assert_eq!(perlin_2d(Vector2D::new(1.25, -2.75), 0), -0.4794788360595703);
+        assert_eq!(perlin_3d(Vector::new(0.5, 0.5, 0.5), 0), -0.2425);
+        assert_eq!(perlin_3d(Vector::new(3.75, -1.5, 10.25), 0), 0.1312027645111084);
+        assert_eq!(perlin_3d(Vector::new(3.75, -1.5, 10.25), 1234), 0.017383341789245606);
+        assert_eq!(fbm(Vector::new(3.75, -1.5, 10.25), 4, 2.0, 0.5, 0), 0.16697480773925782);
+    }
+
+    #[test]
+    fn test_deterministic() {
+        let first = PerlinNoise::new(2024);
+        let second = PerlinNoise::new(2024);
+        assert_eq!(first, second);
+        for point in sample_points(200) {
+            let value = first.perlin_3d(point);
+            assert_eq!(value.to_bits(), first.perlin_3d(point).to_bits());
+            assert_eq!(value.to_bits(), second.perlin_3d(point).to_bits());
+            assert_eq!(value.to_bits(), perlin_3d(point, 2024).to_bits());
+            assert_eq!(first.fbm(point, 5, 2.0, 0.5).to_bits(), second.fbm(point, 5, 2.0, 0.5).to_bits());
+        }
+        assert_ne!(PerlinNoise::new(1).perlin_3d(Vector::splat(0.3)), PerlinNoise::new(2).perlin_3d(Vector::splat(0.3)));
+    }
+
+    #[test]
+    fn test_output_range() {
+        let noise = PerlinNoise::new(5);
+        let mut max = [0.0f64; 3];
+        for point in sample_points(20_000) {
+            let values = [noise.perlin_1d(point.x), noise.perlin_2d(Vector2D::new(point.x, point.y)), noise.perlin_3d(point)];
+            for (max, value) in max.iter_mut().zip(values) {
+                assert!((-1.0..=1.0).contains(&value), "{} out of range", value);
+                *max = max.max(value.abs());
+            }
+            assert!((-1.0..=1.0).contains(&noise.fbm(point * 0.01, 6, 2.0, 0.5)));
+        }
+        // Uses a good part of the range rather than a narrow band
+        assert!(max.iter().all(|&max| max > 0.5), "{:?}", max);
+    }
+
+    #[test]
+    fn test_fbm_edge_cases() {
+        let point = Vector::new(1.3, 2.7, -0.4);
+        assert_eq!(fbm(point, 0, 2.0, 0.5, 0), 0.0);
+        assert_eq!(fbm(point, 1, 2.0, 0.5, 0), perlin_3d(point, 0));
+        assert_eq!(fbm(point, 3, 2.0, 0.0, 0), perlin_3d(point, 0));
+    }
+}
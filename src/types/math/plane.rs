@@ -1,9 +1,20 @@
 //! 2D and 3D Plane representation for geometric operations
 
-use crate::vector::{Vector, Vector2D};
+use crate::vector::{Vector, Vector2D, Vector4};
+use crate::transform::Transform;
 use crate::BinarySerializable;
+use super::nearly_equal::max_error;
+use super::{LineSegment, NearlyEqual, Ray};
 use serde::{Deserialize, Serialize};
-use std::fmt;
+use core::fmt;
+#[cfg(not(feature = "std"))]
+use crate::types::math::float_math::FloatMath;
+
+/// Below this magnitude a direction is treated as parallel to a plane
+const PARALLEL_TOLERANCE: f64 = 1e-12;
+
+/// Squared normal length at or below which a plane can't be normalized (UE: `SMALL_NUMBER`)
+const NORMALIZE_TOLERANCE: f64 = 1e-8;
 
 /// 2D Plane representation
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
@@ -25,7 +36,7 @@ impl BinarySerializable for Plane2D {}
 
 impl Plane2D {
     /// Create a new 2D plane
-    pub fn new(normal: Vector2D, distance: f64) -> Self {
+    pub const fn new(normal: Vector2D, distance: f64) -> Self {
         Self { normal, distance }
     }
 
@@ -75,9 +86,16 @@ impl fmt::Display for Plane {
 
 impl BinarySerializable for Plane {}
 
+/// Planes facing opposite ways are different, even though they contain the same points
+impl NearlyEqual for Plane {
+    fn max_component_error(&self, other: &Self) -> f64 {
+        max_error([self.normal.max_component_error(&other.normal), self.distance.max_component_error(&other.distance)])
+    }
+}
+
 impl Plane {
     /// Create a new 3D plane
-    pub fn new(normal: Vector, distance: f64) -> Self {
+    pub const fn new(normal: Vector, distance: f64) -> Self {
         Self { normal, distance }
     }
 
@@ -120,11 +138,128 @@ impl Plane {
     pub fn closest_point_to(self, point: Vector) -> Vector {
         self.project_point(point)
     }
+
+    /// Intersect a ray with the plane
+    ///
+    /// Returns `None` if the ray is parallel to the plane (including rays lying
+    /// in it) or points away from it. A ray starting on the plane hits at its origin.
+    pub fn intersect_ray(self, ray: Ray) -> Option<Vector> {
+        let denominator = self.normal.dot(ray.direction);
+        if denominator.abs() < PARALLEL_TOLERANCE {
+            return None;
+        }
+
+        let t = -self.distance_to_point(ray.origin) / denominator;
+        if t < 0.0 {
+            return None;
+        }
+        Some(ray.point_at_distance(t))
+    }
+
+    /// Intersect a line segment with the plane
+    ///
+    /// Returns `None` if the segment is parallel to the plane or does not reach it
+    /// (intersection parameter outside [0, 1]).
+    pub fn intersect_segment(self, segment: LineSegment) -> Option<Vector> {
+        let direction = segment.direction_vector();
+        let denominator = self.normal.dot(direction);
+        if denominator.abs() < PARALLEL_TOLERANCE {
+            return None;
+        }
+
+        let t = -self.distance_to_point(segment.start) / denominator;
+        if !(0.0..=1.0).contains(&t) {
+            return None;
+        }
+        Some(segment.lerp_unclamped(t))
+    }
+
+    /// Get the line where this plane meets another, as a ray along `self.normal × other.normal`
+    ///
+    /// Returns `None` for parallel or coplanar planes. The ray origin is the
+    /// point on the line closest to the world origin.
+    pub fn intersect_plane(self, other: Plane) -> Option<Ray> {
+        let direction = self.normal.cross(other.normal);
+        let length_squared = direction.length_squared();
+        if length_squared < PARALLEL_TOLERANCE {
+            return None;
+        }
+
+        let origin = (self.distance * other.normal.cross(direction)
+            + other.distance * direction.cross(self.normal))
+            / length_squared;
+        Some(Ray::new(origin, direction))
+    }
+
+    /// Get the single point where three planes meet (UE: `FMath::IntersectPlanes3`)
+    ///
+    /// Returns `None` if any two of the planes are parallel, or all three
+    /// share a line.
+    pub fn intersect_three_planes(a: Plane, b: Plane, c: Plane) -> Option<Vector> {
+        let bc = b.normal.cross(c.normal);
+        let denominator = a.normal.dot(bc);
+        if denominator.abs() < PARALLEL_TOLERANCE {
+            return None;
+        }
+        Some((a.distance * bc + b.distance * c.normal.cross(a.normal) + c.distance * a.normal.cross(b.normal)) / denominator)
+    }
+
+    /// Move the plane by a transform, so points on it stay on it (UE: `FPlane::TransformBy`)
+    ///
+    /// The normal goes through the inverse-transpose of the rotation and
+    /// scale, so non-uniform scale keeps it perpendicular to the plane, and
+    /// the side each point is on is preserved even under mirroring. If the
+    /// scale collapses the normal entirely (two zero scale components), the
+    /// result has a zero normal. The normal need not be unit length; the
+    /// result's always is, with the distance scaled to match.
+    pub fn transform(self, transform: Transform) -> Plane {
+        let length_squared = self.normal.length_squared();
+        let closest_to_origin = if length_squared > 0.0 {
+            self.normal * self.distance / length_squared
+        } else {
+            Vector::ZERO
+        };
+        let point = transform.transform_point(closest_to_origin);
+        let scale = transform.scale;
+        // Adjugate of the scale matrix: the inverse times the determinant, without dividing
+        let adjugate = Vector::new(scale.y * scale.z, scale.x * scale.z, scale.x * scale.y);
+        let determinant_sign = (scale.x * scale.y * scale.z).signum();
+        let normal = (transform.rotation * (self.normal * adjugate * determinant_sign)).normalize_or_zero();
+        Plane::new(normal, normal.dot(point))
+    }
+
+    /// Scale the normal to unit length, and the distance with it (UE: `FPlane::Normalize`)
+    ///
+    /// Returns `None` if the normal is too close to zero to have a direction.
+    pub fn normalized(self) -> Option<Plane> {
+        let length_squared = self.normal.length_squared();
+        if length_squared <= NORMALIZE_TOLERANCE || !length_squared.is_finite() {
+            return None;
+        }
+        let length = length_squared.sqrt();
+        Some(Plane::new(self.normal / length, self.distance / length))
+    }
+
+    /// Get the same plane facing the other way (UE: `FPlane::Flip`)
+    pub fn flip(self) -> Plane {
+        Plane::new(-self.normal, -self.distance)
+    }
+
+    /// Unpack a plane stored as `(nx, ny, nz, d)`, UE's `FPlane` layout
+    pub fn from_vector4(packed: Vector4) -> Self {
+        Self::new(packed.truncate(), packed.w)
+    }
+
+    /// Pack the plane as `(nx, ny, nz, d)`, UE's `FPlane` layout
+    pub fn to_vector4(self) -> Vector4 {
+        self.normal.extend(self.distance)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::assert_nearly_eq;
 
     #[test]
     fn test_plane_creation() {
@@ -168,6 +303,69 @@ mod tests {
         assert!(!plane.is_point_in_front(Vector2D::new(3.0, 0.0)));
     }
 
+    #[test]
+    fn test_plane_ray_intersection() {
+        let plane = Plane::new(Vector::new(0.0, 0.0, 1.0), 2.0);
+
+        let down = Ray::new(Vector::new(1.0, 1.0, 10.0), Vector::new(0.0, 0.0, -1.0));
+        assert_eq!(plane.intersect_ray(down), Some(Vector::new(1.0, 1.0, 2.0)));
+
+        let away = Ray::new(Vector::new(1.0, 1.0, 10.0), Vector::new(0.0, 0.0, 1.0));
+        assert_eq!(plane.intersect_ray(away), None);
+
+        let parallel = Ray::new(Vector::new(0.0, 0.0, 5.0), Vector::new(1.0, 0.0, 0.0));
+        assert_eq!(plane.intersect_ray(parallel), None);
+
+        // Coplanar ray lies in the plane and is treated as parallel
+        let coplanar = Ray::new(Vector::new(0.0, 0.0, 2.0), Vector::new(1.0, 0.0, 0.0));
+        assert_eq!(plane.intersect_ray(coplanar), None);
+
+        // Near-parallel rays still hit, just far away
+        let grazing = Ray::new(Vector::new(0.0, 0.0, 3.0), Vector::new(1.0, 0.0, -1e-6));
+        let hit = plane.intersect_ray(grazing).unwrap();
+        assert!((hit.z - 2.0).abs() < 1e-6);
+        assert!((hit.x - 1.0e6).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_plane_segment_intersection() {
+        let plane = Plane::new(Vector::new(0.0, 0.0, 1.0), 0.0);
+
+        let crossing = LineSegment::new(Vector::new(0.0, 0.0, -1.0), Vector::new(2.0, 0.0, 1.0));
+        assert_eq!(plane.intersect_segment(crossing), Some(Vector::new(1.0, 0.0, 0.0)));
+
+        let short = LineSegment::new(Vector::new(0.0, 0.0, 1.0), Vector::new(0.0, 0.0, 0.5));
+        assert_eq!(plane.intersect_segment(short), None);
+
+        let touching = LineSegment::new(Vector::new(0.0, 0.0, 1.0), Vector::new(0.0, 0.0, 0.0));
+        assert_eq!(plane.intersect_segment(touching), Some(Vector::ZERO));
+
+        let coplanar = LineSegment::new(Vector::ZERO, Vector::new(1.0, 0.0, 0.0));
+        assert_eq!(plane.intersect_segment(coplanar), None);
+    }
+
+    #[test]
+    fn test_plane_plane_intersection() {
+        let floor = Plane::new(Vector::new(0.0, 0.0, 1.0), 1.0);
+        let wall = Plane::new(Vector::new(1.0, 0.0, 0.0), 3.0);
+
+        let line = floor.intersect_plane(wall).unwrap();
+        assert!((line.origin - Vector::new(3.0, 0.0, 1.0)).length() < 1e-9);
+        assert!((line.direction.abs() - Vector::new(0.0, 1.0, 0.0)).length() < 1e-9);
+        assert!(floor.distance_to_point(line.point_at(7.0)).abs() < 1e-9);
+        assert!(wall.distance_to_point(line.point_at(7.0)).abs() < 1e-9);
+
+        let parallel = Plane::new(Vector::new(0.0, 0.0, 1.0), 5.0);
+        assert_eq!(floor.intersect_plane(parallel), None);
+        assert_eq!(floor.intersect_plane(floor), None);
+
+        // Nearly parallel planes intersect far from the origin
+        let tilted = Plane::from_point_normal(Vector::new(0.0, 0.0, 1.0), Vector::new(1e-3, 0.0, 1.0));
+        let shallow = floor.intersect_plane(tilted).unwrap();
+        assert!(floor.distance_to_point(shallow.origin).abs() < 1e-6);
+        assert!(tilted.distance_to_point(shallow.origin).abs() < 1e-6);
+    }
+
     #[test]
     fn test_plane_display() {
         let plane = Plane::new(Vector::new(0.0, 0.0, 1.0), 5.0);
@@ -191,4 +389,85 @@ mod tests {
         let deserialized = Plane::from_binary(&binary).unwrap();
         assert_eq!(plane, deserialized);
     }
+
+    #[test]
+    fn test_plane_transform_non_uniform_scale() {
+        let plane = Plane::from_point_normal(Vector::new(1.0, 2.0, 3.0), Vector::new(1.0, 1.0, 0.5));
+        let transform = Transform::from_location_rotator_scale(
+            Vector::new(10.0, -5.0, 2.0),
+            crate::Rotator::new(30.0, 45.0, -20.0),
+            Vector::new(2.0, 0.5, 3.0),
+        );
+        let moved = plane.transform(transform);
+        assert!((moved.normal.length() - 1.0).abs() < 1e-12);
+
+        // Points on the original plane land on the transformed one
+        let u = plane.normal.any_orthonormal_vector();
+        let v = plane.normal.cross(u);
+        let origin = plane.normal * plane.distance;
+        for (a, b) in [(0.0, 0.0), (3.0, -1.0), (-7.5, 4.0), (100.0, 250.0)] {
+            let point = origin + u * a + v * b;
+            assert!(plane.distance_to_point(point).abs() < 1e-9);
+            assert!(moved.distance_to_point(transform.transform_point(point)).abs() < 1e-9);
+        }
+
+        // Points off the plane keep their side
+        let front = origin + plane.normal * 2.0;
+        assert!(moved.is_point_in_front(transform.transform_point(front)));
+        let mirrored = Transform::from_location_rotator_scale(Vector::ZERO, crate::Rotator::ZERO, Vector::new(-1.0, 2.0, 1.0));
+        assert!(plane.transform(mirrored).is_point_in_front(mirrored.transform_point(front)));
+
+        // Transforming by the identity changes nothing
+        assert_nearly_eq!(plane.transform(Transform::IDENTITY), plane);
+
+        // A non-unit normal comes back unit length with the distance rescaled
+        let unnormalized = Plane::new(Vector::new(0.0, 3.0, 4.0), 10.0).transform(Transform::IDENTITY);
+        assert_nearly_eq!(unnormalized.normal, Vector::new(0.0, 0.6, 0.8));
+        assert!((unnormalized.distance - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_plane_normalize_and_flip() {
+        let plane = Plane::new(Vector::new(0.0, 3.0, 4.0), 10.0);
+        let normalized = plane.normalized().unwrap();
+        assert_nearly_eq!(normalized, Plane::new(Vector::new(0.0, 0.6, 0.8), 2.0));
+        // Same set of points as before
+        let point = Vector::new(7.0, 2.0, 1.0);
+        assert!((normalized.distance_to_point(point) - plane.distance_to_point(point) / 5.0).abs() < 1e-12);
+
+        assert_eq!(Plane::new(Vector::ZERO, 1.0).normalized(), None);
+        assert_eq!(Plane::new(Vector::splat(1e-5), 1.0).normalized(), None);
+        assert_eq!(Plane::new(Vector::new(f64::NAN, 0.0, 1.0), 1.0).normalized(), None);
+
+        let flipped = normalized.flip();
+        assert_eq!(flipped, Plane::new(Vector::new(-0.0, -0.6, -0.8), -2.0));
+        assert_eq!(flipped.distance_to_point(point), -normalized.distance_to_point(point));
+        assert_eq!(flipped.flip(), normalized);
+    }
+
+    #[test]
+    fn test_plane_vector4_packing() {
+        let plane = Plane::new(Vector::new(0.0, 0.0, 1.0), 5.0);
+        assert_eq!(plane.to_vector4(), Vector4::new(0.0, 0.0, 1.0, 5.0));
+        assert_eq!(Plane::from_vector4(plane.to_vector4()), plane);
+    }
+
+    #[test]
+    fn test_intersect_three_planes() {
+        let x = Plane::new(Vector::X, 1.0);
+        let y = Plane::new(Vector::Y, -2.0);
+        let z = Plane::new(Vector::Z, 3.0);
+        assert_eq!(Plane::intersect_three_planes(x, y, z), Some(Vector::new(1.0, -2.0, 3.0)));
+
+        let tilted = Plane::from_point_normal(Vector::new(4.0, 4.0, 4.0), Vector::new(1.0, 1.0, 1.0));
+        let point = Plane::intersect_three_planes(x, y, tilted).unwrap();
+        for plane in [x, y, tilted] {
+            assert!(plane.distance_to_point(point).abs() < 1e-12);
+        }
+
+        // Two parallel planes, and three planes sharing the Z axis
+        assert_eq!(Plane::intersect_three_planes(x, Plane::new(Vector::X, 5.0), z), None);
+        let diagonal = Plane::from_point_normal(Vector::ZERO, Vector::new(1.0, -1.0, 0.0));
+        assert_eq!(Plane::intersect_three_planes(Plane::new(Vector::X, 0.0), Plane::new(Vector::Y, 0.0), diagonal), None);
+    }
 }
\ No newline at end of file
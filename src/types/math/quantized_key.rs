@@ -0,0 +1,105 @@
+//! Quantized integer keys for using float types in hashed containers
+//!
+//! Floats don't implement `Hash`/`Eq`, so `Vector`, `Vector2D`, `Rotator`
+//! and `Transform` offer `to_key(precision)`, which snaps each component to
+//! a grid of cells `precision` wide and returns an integer key usable in a
+//! `TMap` or `TSet`. Values in the same cell share a key; values in
+//! neighboring cells don't, however close they are, so keys deduplicate
+//! values that are already snapped rather than merging near-misses.
+
+#[cfg(not(feature = "std"))]
+use crate::types::math::float_math::FloatMath;
+
+/// Cell index of `value` on a grid of `precision`-wide cells centered on multiples of `precision`
+///
+/// Rounds half away from zero. Cells beyond the `i32` range saturate and NaN
+/// maps to cell 0, so keep `|value| / precision` below 2^31 (1 cm cells
+/// cover ±21,000 km).
+///
+/// # Panics
+///
+/// Panics if `precision` isn't positive and finite.
+pub(crate) fn quantize(value: f64, precision: f64) -> i32 {
+    assert!(precision > 0.0 && precision.is_finite(), "key precision must be positive, got {}", precision);
+    (value / precision).round() as i32
+}
+
+/// Cell index of an angle in degrees, wrapped so equivalent angles share a key
+///
+/// Angles are wrapped into [0, 360) first. When `precision` divides 360
+/// evenly, the cell just below 360° is merged with the one at 0°, so the
+/// wrap-around never splits a cell.
+///
+/// # Panics
+///
+/// Panics if `precision` isn't positive and finite.
+pub(crate) fn quantize_angle(degrees: f64, precision: f64) -> i32 {
+    let cell = quantize(degrees.rem_euclid(360.0), precision);
+    let cells_per_turn = 360.0 / precision;
+    if cells_per_turn.fract() == 0.0 && cell as f64 >= cells_per_turn {
+        cell - cells_per_turn as i32
+    } else {
+        cell
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{IntVector, IntVector2, Rotator, TMap, TSet, Transform, Vector, Vector2D, Vector2DExt, VectorExt};
+
+    #[test]
+    fn test_vector_keys_within_and_across_cells() {
+        // 1 cm cells centered on whole centimeters: [99.995, 100.005) belongs to 100
+        let key = Vector::new(1.0, 2.0, 3.0).to_key(0.01);
+        assert_eq!(key, IntVector::new(100, 200, 300));
+        assert_eq!(Vector::new(1.004, 1.996, 3.0049).to_key(0.01), key);
+        assert_ne!(Vector::new(1.0051, 2.0, 3.0).to_key(0.01), key);
+        assert_ne!(Vector::new(1.0, 1.9949, 3.0).to_key(0.01), key);
+
+        // Rounding is symmetric around zero
+        assert_eq!(Vector::new(-0.49, 0.49, -0.0).to_key(1.0), IntVector::ZERO);
+        assert_eq!(Vector::new(-0.5, 0.5, 1.5).to_key(1.0), IntVector::new(-1, 1, 2));
+
+        assert_eq!(Vector2D::new(10.2, -10.4).to_key(1.0), IntVector2::new(10, -10));
+        assert_eq!(Vector2D::new(10.49, 0.0).to_key(1.0), Vector2D::new(9.51, 0.0).to_key(1.0));
+        assert_ne!(Vector2D::new(10.49, 0.0).to_key(1.0), Vector2D::new(10.51, 0.0).to_key(1.0));
+    }
+
+    #[test]
+    fn test_rotator_keys_wrap() {
+        let key = Rotator::new(179.9, -0.04, 360.0).to_key(0.1);
+        assert_eq!(key, Rotator::new(-180.1, 359.96, 0.0).to_key(0.1));
+        assert_eq!(key, IntVector::new(1799, 0, 0));
+        assert_ne!(Rotator::new(0.0, 0.06, 0.0).to_key(0.1), Rotator::ZERO.to_key(0.1));
+
+        // Precision that doesn't divide 360 still wraps into [0, 360)
+        assert_eq!(Rotator::new(-7.0, 0.0, 0.0).to_key(7.0), IntVector::new(50, 0, 0));
+    }
+
+    #[test]
+    fn test_transform_keys_deduplicate_spawn_points() {
+        let spawn = |x: f64, yaw: f64| Transform::from_location_rotator(Vector::new(x, 250.0, 0.0), Rotator::new(0.0, yaw, 0.0));
+        let mut spawns: TMap<_, Transform> = TMap::new();
+        for transform in [spawn(100.0, 90.0), spawn(100.2, 90.3), spawn(100.0, -270.0), spawn(160.0, 90.0), spawn(100.0, 180.0)] {
+            spawns.find_or_add(transform.to_key(1.0, 1.0, 0.01), transform);
+        }
+        assert_eq!(spawns.len(), 3);
+
+        let unique: TSet<IntVector> = [Vector::new(0.1, 0.0, 0.0), Vector::new(-0.1, 0.0, 0.0), Vector::X]
+            .into_iter()
+            .map(|location| location.to_key(1.0))
+            .collect();
+        assert_eq!(unique.len(), 2);
+
+        let scaled = Transform::from_scale(Vector::splat(1.004));
+        assert_eq!(scaled.to_key(1.0, 1.0, 0.01), Transform::IDENTITY.to_key(1.0, 1.0, 0.01));
+        assert_ne!(scaled.to_key(1.0, 1.0, 0.001), Transform::IDENTITY.to_key(1.0, 1.0, 0.001));
+    }
+
+    #[test]
+    fn test_out_of_range_and_invalid_values() {
+        assert_eq!(Vector::new(f64::NAN, 1e300, -1e300).to_key(1.0), IntVector::new(0, i32::MAX, i32::MIN));
+        assert!(std::panic::catch_unwind(|| Vector::ONE.to_key(0.0)).is_err());
+        assert!(std::panic::catch_unwind(|| Rotator::ZERO.to_key(-1.0)).is_err());
+    }
+}
@@ -0,0 +1,259 @@
+//! Deterministic seeded random numbers (FRandomStream)
+
+use crate::BinarySerializable;
+use crate::types::{BoundingBox, BoundingSphere, Rotator, Vector};
+use serde::{Deserialize, Serialize};
+use core::fmt;
+#[cfg(not(feature = "std"))]
+use crate::types::math::float_math::FloatMath;
+
+/// Reproducible random number stream matching UE's `FRandomStream`
+///
+/// Uses the engine's LCG (`seed = seed * 196314165 + 907633515`) and its
+/// fraction construction, so a stream seeded the same way should produce the
+/// same sequence as on the engine side. Cloning or serializing a stream
+/// captures its position, so a snapshot can be replicated and continued
+/// elsewhere.
+///
+/// The sequences are checked against this crate's own output, not engine
+/// captures.
+///
+/// Fractions have 23 bits of randomness (they are built from an `f32`), also
+/// for the `f64` helpers, as in UE.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RandomStream {
+    initial_seed: i32,
+    seed: u32,
+}
+
+impl fmt::Display for RandomStream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RandomStream(InitialSeed={}, Seed={})", self.initial_seed, self.seed)
+    }
+}
+
+impl BinarySerializable for RandomStream {}
+
+impl RandomStream {
+    /// Smallest squared length accepted by `vrand` before normalizing (UE: `KINDA_SMALL_NUMBER`)
+    const MIN_UNIT_VECTOR_LENGTH_SQUARED: f64 = 1.0e-4;
+
+    /// Create a stream with the given seed
+    pub fn new(seed: i32) -> Self {
+        Self {
+            initial_seed: seed,
+            seed: seed as u32,
+        }
+    }
+
+    /// Re-seed the stream
+    pub fn initialize(&mut self, seed: i32) {
+        *self = Self::new(seed);
+    }
+
+    /// Rewind to the initial seed
+    pub fn reset(&mut self) {
+        self.seed = self.initial_seed as u32;
+    }
+
+    /// The seed the stream was created with
+    pub fn initial_seed(&self) -> i32 {
+        self.initial_seed
+    }
+
+    /// The current position in the sequence
+    pub fn current_seed(&self) -> u32 {
+        self.seed
+    }
+
+    fn mutate_seed(&mut self) {
+        self.seed = self.seed.wrapping_mul(196_314_165).wrapping_add(907_633_515);
+    }
+
+    /// Next raw 32-bit value
+    pub fn get_unsigned_int(&mut self) -> u32 {
+        self.mutate_seed();
+        self.seed
+    }
+
+    /// Next value in [0, 1)
+    pub fn get_fraction(&mut self) -> f32 {
+        self.mutate_seed();
+        // Fill the mantissa of 1.0 with the top 23 bits, giving [1, 2)
+        f32::from_bits(0x3F80_0000 | (self.seed >> 9)) - 1.0
+    }
+
+    /// Next value in [0, 1) (same as `get_fraction`)
+    pub fn frand(&mut self) -> f32 {
+        self.get_fraction()
+    }
+
+    /// Random integer in `[min, max]`, inclusive; returns `min` if `max < min`
+    pub fn rand_range(&mut self, min: i32, max: i32) -> i32 {
+        let range = max as i64 - min as i64 + 1;
+        if range <= 0 {
+            return min;
+        }
+        let offset = (self.get_fraction() * range as f32) as i64;
+        (min as i64 + offset).min(max as i64) as i32
+    }
+
+    /// Random value in `[min, max)`
+    pub fn frand_range(&mut self, min: f32, max: f32) -> f32 {
+        min + (max - min) * self.frand()
+    }
+
+    /// Random value in `[min, max)`, double precision
+    pub fn frand_range_f64(&mut self, min: f64, max: f64) -> f64 {
+        min + (max - min) * self.frand() as f64
+    }
+
+    /// `true` with the given probability (0 never, 1 always)
+    pub fn rand_bool(&mut self, probability: f32) -> bool {
+        self.frand() < probability
+    }
+
+    /// Uniformly distributed unit vector (UE: `VRand`)
+    pub fn vrand(&mut self) -> Vector {
+        loop {
+            let candidate = Vector::new(
+                (self.get_fraction() * 2.0 - 1.0) as f64,
+                (self.get_fraction() * 2.0 - 1.0) as f64,
+                (self.get_fraction() * 2.0 - 1.0) as f64,
+            );
+            let length_squared = candidate.length_squared();
+            if (Self::MIN_UNIT_VECTOR_LENGTH_SQUARED..=1.0).contains(&length_squared) {
+                return candidate / length_squared.sqrt();
+            }
+        }
+    }
+
+    /// Uniformly distributed point inside a box
+    pub fn rand_point_in_box(&mut self, bbox: BoundingBox) -> Vector {
+        Vector::new(
+            self.frand_range_f64(bbox.min.x, bbox.max.x),
+            self.frand_range_f64(bbox.min.y, bbox.max.y),
+            self.frand_range_f64(bbox.min.z, bbox.max.z),
+        )
+    }
+
+    /// Uniformly distributed point inside a sphere
+    pub fn rand_point_in_sphere(&mut self, sphere: BoundingSphere) -> Vector {
+        loop {
+            let candidate = Vector::new(
+                (self.get_fraction() * 2.0 - 1.0) as f64,
+                (self.get_fraction() * 2.0 - 1.0) as f64,
+                (self.get_fraction() * 2.0 - 1.0) as f64,
+            );
+            if candidate.length_squared() <= 1.0 {
+                return sphere.center + candidate * sphere.radius;
+            }
+        }
+    }
+
+    /// Random rotation with pitch, yaw and roll each in [0, 360)
+    pub fn rand_rotator(&mut self) -> Rotator {
+        Rotator::new(
+            self.frand_range_f64(0.0, 360.0),
+            self.frand_range_f64(0.0, 360.0),
+            self.frand_range_f64(0.0, 360.0),
+        )
+    }
+}
+
+impl Default for RandomStream {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pinned_sequence() {
+        // Regression pins computed from the LCG and fraction bit trick above.
+        // They are not captured from a running engine: they catch changes to
+        // this crate's output, not a misreading of FRandomStream.
+        let mut stream = RandomStream::new(42);
+        let raw: Vec<u32> = (0..5).map(|_| stream.get_unsigned_int()).collect();
+        assert_eq!(raw, [562_893_853, 905_216_364, 2_532_826_823, 2_557_353_630, 3_207_148_065]);
+
+        stream.reset();
+        let fractions: Vec<u32> = (0..5).map(|_| stream.frand().to_bits()).collect();
+        assert_eq!(fractions, [0x3e06_3450, 0x3e57_d200, 0x3f16_f7de, 0x3f18_6e1e, 0x3f3f_2932]);
+
+        let mut negative = RandomStream::new(-7);
+        let raw: Vec<u32> = (0..3).map(|_| negative.get_unsigned_int()).collect();
+        assert_eq!(raw, [3_828_401_656, 2_930_655_171, 795_222_730]);
+
+        let mut dice = RandomStream::new(1234);
+        let rolls: Vec<i32> = (0..10).map(|_| dice.rand_range(1, 6)).collect();
+        assert_eq!(rolls, [4, 2, 1, 5, 6, 2, 4, 2, 4, 5]);
+    }
+
+    #[test]
+    fn test_ranges_and_snapshots() {
+        let mut stream = RandomStream::new(7);
+        for _ in 0..1000 {
+            assert!((0.0..1.0).contains(&stream.frand()));
+            assert!((-3..=3).contains(&stream.rand_range(-3, 3)));
+            assert!((2.0..5.0).contains(&stream.frand_range(2.0, 5.0)));
+            assert!((-1.0..1.0).contains(&stream.frand_range_f64(-1.0, 1.0)));
+        }
+        assert_eq!(stream.rand_range(5, 5), 5);
+        assert_eq!(stream.rand_range(5, 1), 5);
+        assert!((i32::MIN..=i32::MAX).contains(&stream.rand_range(i32::MIN, i32::MAX)));
+        assert!(!stream.rand_bool(0.0));
+        assert!(stream.rand_bool(1.0));
+
+        let bbox = BoundingBox::new(Vector::new(-1.0, 0.0, 10.0), Vector::new(1.0, 5.0, 20.0));
+        let sphere = BoundingSphere::new(Vector::new(100.0, 0.0, 0.0), 3.0);
+        for _ in 0..100 {
+            assert!(bbox.contains_point(stream.rand_point_in_box(bbox)));
+            assert!(sphere.contains_point(stream.rand_point_in_sphere(sphere)));
+            let rotator = stream.rand_rotator();
+            assert!((0.0..360.0).contains(&rotator.pitch) && (0.0..360.0).contains(&rotator.roll));
+        }
+
+        // A snapshot continues exactly where the original is
+        let json = serde_json::to_string(&stream).unwrap();
+        let mut restored: RandomStream = serde_json::from_str(&json).unwrap();
+        let mut cloned = stream.clone();
+        let expected: Vec<u32> = (0..10).map(|_| stream.get_unsigned_int()).collect();
+        assert_eq!((0..10).map(|_| restored.get_unsigned_int()).collect::<Vec<_>>(), expected);
+        assert_eq!((0..10).map(|_| cloned.get_unsigned_int()).collect::<Vec<_>>(), expected);
+        assert_eq!(restored.initial_seed(), 7);
+        assert_eq!(format!("{}", RandomStream::new(3)), "RandomStream(InitialSeed=3, Seed=3)");
+    }
+
+    #[test]
+    fn test_vrand_uniformity() {
+        let mut stream = RandomStream::new(2024);
+        let samples = 20_000;
+        let mut sum = Vector::ZERO;
+        let mut squares = Vector::ZERO;
+        let mut octants = [0_u32; 8];
+        for _ in 0..samples {
+            let v = stream.vrand();
+            assert!((v.length() - 1.0).abs() < 1e-9);
+            sum += v;
+            squares += v * v;
+            let octant = (v.x > 0.0) as usize | ((v.y > 0.0) as usize) << 1 | ((v.z > 0.0) as usize) << 2;
+            octants[octant] += 1;
+        }
+
+        // Mean near zero, each axis carries a third of the length, octants evenly hit
+        let mean = sum / samples as f64;
+        assert!(mean.length() < 0.02, "mean {}", mean);
+        let spread = squares / samples as f64;
+        for axis in [spread.x, spread.y, spread.z] {
+            assert!((axis - 1.0 / 3.0).abs() < 0.01, "spread {}", spread);
+        }
+        let expected = samples as f64 / 8.0;
+        for count in octants {
+            assert!((count as f64 - expected).abs() < expected * 0.1, "octants {:?}", octants);
+        }
+    }
+}
@@ -1,9 +1,14 @@
 //! Ray representation for ray casting and intersection tests
 
-use crate::vector::Vector;
+use crate::vector::{Vector, Vector2D};
+use crate::bounds::{BoundingBox, BoundingSphere, Box2D};
 use crate::BinarySerializable;
+use super::nearly_equal::max_error;
+use super::NearlyEqual;
 use serde::{Deserialize, Serialize};
-use std::fmt;
+use core::fmt;
+#[cfg(not(feature = "std"))]
+use crate::types::math::float_math::FloatMath;
 
 /// Ray representation for ray casting and intersection tests
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
@@ -24,6 +29,12 @@ impl fmt::Display for Ray {
 
 impl BinarySerializable for Ray {}
 
+impl NearlyEqual for Ray {
+    fn max_component_error(&self, other: &Self) -> f64 {
+        max_error([self.origin.max_component_error(&other.origin), self.direction.max_component_error(&other.direction)])
+    }
+}
+
 impl Ray {
     /// Create a new ray
     pub fn new(origin: Vector, direction: Vector) -> Self {
@@ -72,6 +83,81 @@ impl Ray {
         self.distance_to_point(point) <= tolerance
     }
 
+    /// Intersect the ray with an axis-aligned box using the slab method
+    ///
+    /// Returns the distance along the ray to the entry point, `Some(0.0)` if the
+    /// origin is already inside the box, or `None` if the ray misses. Touching a
+    /// face or edge counts as a hit. Invalid boxes (min > max on any axis) never hit.
+    pub fn intersects_box(self, bbox: BoundingBox) -> Option<f64> {
+        if !bbox.is_valid() {
+            return None;
+        }
+
+        let mut t_enter = f64::NEG_INFINITY;
+        let mut t_exit = f64::INFINITY;
+
+        for axis in 0..3 {
+            let origin = self.origin[axis];
+            let direction = self.direction[axis];
+            let (min, max) = (bbox.min[axis], bbox.max[axis]);
+
+            if direction == 0.0 {
+                // Parallel to this slab: must already be between its planes
+                if origin < min || origin > max {
+                    return None;
+                }
+                continue;
+            }
+
+            let t1 = (min - origin) / direction;
+            let t2 = (max - origin) / direction;
+            t_enter = t_enter.max(t1.min(t2));
+            t_exit = t_exit.min(t1.max(t2));
+        }
+
+        if t_exit < t_enter.max(0.0) {
+            return None;
+        }
+        Some(t_enter.max(0.0))
+    }
+
+    /// Intersect the ray with a sphere, returning the near and far hit distances
+    ///
+    /// If the origin is inside the sphere the near distance is clamped to 0.0
+    /// (matching `intersects_box`) and the far distance is the exit point.
+    /// Returns `None` if the ray misses or the sphere is entirely behind the
+    /// origin. Uses the numerically stable quadratic formulation so hits stay
+    /// accurate when the sphere is far from the origin.
+    pub fn intersects_sphere(self, sphere: BoundingSphere) -> Option<(f64, f64)> {
+        let a = self.direction.length_squared();
+        if a == 0.0 {
+            return None;
+        }
+
+        let to_origin = self.origin - sphere.center;
+        let b = -to_origin.dot(self.direction);
+        let c = to_origin.length_squared() - sphere.radius * sphere.radius;
+
+        // Discriminant from the perpendicular distance to the center, which
+        // avoids the cancellation in b^2 - ac for distant spheres
+        let perpendicular = to_origin + self.direction * (b / a);
+        let discriminant = a * (sphere.radius * sphere.radius - perpendicular.length_squared());
+        if discriminant < 0.0 {
+            return None;
+        }
+
+        let q = b + b.signum() * discriminant.sqrt();
+        let (mut near, mut far) = if q == 0.0 { (0.0, 0.0) } else { (c / q, q / a) };
+        if near > far {
+            core::mem::swap(&mut near, &mut far);
+        }
+
+        if far < 0.0 {
+            return None;
+        }
+        Some((near.max(0.0), far))
+    }
+
     /// Transform the ray by moving its origin and rotating its direction
     pub fn transform(self, transform: &crate::Transform) -> Self {
         Self {
@@ -81,6 +167,91 @@ impl Ray {
     }
 }
 
+/// 2D ray for top-down queries
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Ray2D {
+    /// Ray origin point
+    pub origin: Vector2D,
+    /// Ray direction (should be normalized)
+    pub direction: Vector2D,
+}
+
+impl fmt::Display for Ray2D {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Ray2D(Origin: ({:.3}, {:.3}), Direction: ({:.3}, {:.3}))",
+               self.origin.x, self.origin.y, self.direction.x, self.direction.y)
+    }
+}
+
+impl BinarySerializable for Ray2D {}
+
+impl Ray2D {
+    /// Create a new ray
+    pub fn new(origin: Vector2D, direction: Vector2D) -> Self {
+        Self {
+            origin,
+            direction: direction.normalize(),
+        }
+    }
+
+    /// Create a ray from origin to target point
+    pub fn from_origin_to_target(origin: Vector2D, target: Vector2D) -> Self {
+        Self::new(origin, target - origin)
+    }
+
+    /// Get a point along the ray at the given distance
+    pub fn point_at_distance(self, distance: f64) -> Vector2D {
+        self.origin + self.direction * distance
+    }
+
+    /// Get the closest point on the ray to a given point
+    pub fn closest_point_to(self, point: Vector2D) -> Vector2D {
+        let distance = (point - self.origin).dot(self.direction).max(0.0);
+        self.point_at_distance(distance)
+    }
+
+    /// Get the shortest distance from the ray to a point
+    pub fn distance_to_point(self, point: Vector2D) -> f64 {
+        (point - self.closest_point_to(point)).length()
+    }
+
+    /// Intersect the ray with a 2D box using the slab method
+    ///
+    /// Same semantics as `Ray::intersects_box`: the entry distance, `Some(0.0)`
+    /// from inside, `None` on a miss. Grazing an edge or corner counts as a hit.
+    pub fn intersects_box(self, bbox: Box2D) -> Option<f64> {
+        if !bbox.is_valid() {
+            return None;
+        }
+
+        let mut t_enter = f64::NEG_INFINITY;
+        let mut t_exit = f64::INFINITY;
+
+        for axis in 0..2 {
+            let origin = self.origin[axis];
+            let direction = self.direction[axis];
+            let (min, max) = (bbox.min[axis], bbox.max[axis]);
+
+            if direction == 0.0 {
+                if origin < min || origin > max {
+                    return None;
+                }
+                continue;
+            }
+
+            let t1 = (min - origin) / direction;
+            let t2 = (max - origin) / direction;
+            t_enter = t_enter.max(t1.min(t2));
+            t_exit = t_exit.min(t1.max(t2));
+        }
+
+        if t_exit < t_enter.max(0.0) {
+            return None;
+        }
+        Some(t_enter.max(0.0))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -136,6 +307,106 @@ mod tests {
         assert!(ray.contains_point(Vector::new(5.0, 0.1, 0.0), 0.5));
     }
 
+    #[test]
+    fn test_ray_box_hit_distance() {
+        let bbox = BoundingBox::new(Vector::new(-1.0, -1.0, -1.0), Vector::new(1.0, 1.0, 1.0));
+
+        let ray = Ray::new(Vector::new(-5.0, 0.0, 0.0), Vector::new(1.0, 0.0, 0.0));
+        assert_eq!(ray.intersects_box(bbox), Some(4.0));
+
+        let diagonal = Ray::new(Vector::new(-3.0, -3.0, 0.0), Vector::new(1.0, 1.0, 0.0));
+        let hit = diagonal.intersects_box(bbox).unwrap();
+        assert!((hit - 2.0 * 2.0_f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_ray_box_origin_inside() {
+        let bbox = BoundingBox::new(Vector::new(-1.0, -1.0, -1.0), Vector::new(1.0, 1.0, 1.0));
+        let ray = Ray::new(Vector::ZERO, Vector::new(0.0, 0.0, 1.0));
+        assert_eq!(ray.intersects_box(bbox), Some(0.0));
+    }
+
+    #[test]
+    fn test_ray_box_grazing_face() {
+        let bbox = BoundingBox::new(Vector::new(-1.0, -1.0, -1.0), Vector::new(1.0, 1.0, 1.0));
+        // Travels exactly along the y = 1 face
+        let ray = Ray::new(Vector::new(-5.0, 1.0, 0.0), Vector::new(1.0, 0.0, 0.0));
+        assert_eq!(ray.intersects_box(bbox), Some(4.0));
+    }
+
+    #[test]
+    fn test_ray_box_misses() {
+        let bbox = BoundingBox::new(Vector::new(-1.0, -1.0, -1.0), Vector::new(1.0, 1.0, 1.0));
+
+        // Pointing away from the box
+        let away = Ray::new(Vector::new(-5.0, 0.0, 0.0), Vector::new(-1.0, 0.0, 0.0));
+        assert_eq!(away.intersects_box(bbox), None);
+
+        // Parallel to the x slab but above the box
+        let parallel = Ray::new(Vector::new(-5.0, 2.0, 0.0), Vector::new(1.0, 0.0, 0.0));
+        assert_eq!(parallel.intersects_box(bbox), None);
+
+        // Passes beside the box diagonally
+        let beside = Ray::new(Vector::new(-5.0, 3.0, 0.0), Vector::new(1.0, 0.1, 0.0));
+        assert_eq!(beside.intersects_box(bbox), None);
+    }
+
+    #[test]
+    fn test_ray_box_degenerate() {
+        let ray = Ray::new(Vector::new(-5.0, 0.0, 0.0), Vector::new(1.0, 0.0, 0.0));
+        assert_eq!(ray.intersects_box(BoundingBox::EMPTY), None);
+
+        // A flat box (zero thickness along x) can still be hit
+        let flat = BoundingBox::new(Vector::new(0.0, -1.0, -1.0), Vector::new(0.0, 1.0, 1.0));
+        assert_eq!(ray.intersects_box(flat), Some(5.0));
+        assert_eq!(flat.intersects_ray(ray), Some(5.0));
+    }
+
+    #[test]
+    fn test_ray_sphere_hits() {
+        let sphere = BoundingSphere::new(Vector::new(10.0, 0.0, 0.0), 2.0);
+        let ray = Ray::new(Vector::ZERO, Vector::new(1.0, 0.0, 0.0));
+        assert_eq!(ray.intersects_sphere(sphere), Some((8.0, 12.0)));
+        assert!(sphere.intersects_ray(ray));
+
+        // Tangent hit touches at a single distance
+        let tangent = Ray::new(Vector::new(0.0, 2.0, 0.0), Vector::new(1.0, 0.0, 0.0));
+        let (near, far) = tangent.intersects_sphere(sphere).unwrap();
+        assert!((near - 10.0).abs() < 1e-9);
+        assert!((far - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_ray_sphere_origin_inside() {
+        let sphere = BoundingSphere::new(Vector::ZERO, 5.0);
+        let ray = Ray::new(Vector::new(1.0, 0.0, 0.0), Vector::new(1.0, 0.0, 0.0));
+        assert_eq!(ray.intersects_sphere(sphere), Some((0.0, 4.0)));
+    }
+
+    #[test]
+    fn test_ray_sphere_misses() {
+        let sphere = BoundingSphere::new(Vector::new(10.0, 0.0, 0.0), 2.0);
+
+        let behind = Ray::new(Vector::ZERO, Vector::new(-1.0, 0.0, 0.0));
+        assert_eq!(behind.intersects_sphere(sphere), None);
+
+        let beside = Ray::new(Vector::new(0.0, 2.5, 0.0), Vector::new(1.0, 0.0, 0.0));
+        assert_eq!(beside.intersects_sphere(sphere), None);
+        assert!(!sphere.intersects_ray(beside));
+    }
+
+    #[test]
+    fn test_ray_sphere_long_distance_precision() {
+        let distance = 1.0e7;
+        let sphere = BoundingSphere::new(Vector::new(distance, 0.5, 0.0), 1.0);
+        let ray = Ray::new(Vector::ZERO, Vector::new(1.0, 0.0, 0.0));
+
+        let (near, far) = ray.intersects_sphere(sphere).unwrap();
+        let half_chord = 0.75_f64.sqrt();
+        assert!((near - (distance - half_chord)).abs() < 1e-6);
+        assert!((far - (distance + half_chord)).abs() < 1e-6);
+    }
+
     #[test]
     fn test_ray_display() {
         let ray = Ray::new(Vector::new(1.0, 2.0, 3.0), Vector::new(0.0, 1.0, 0.0));
@@ -159,4 +430,30 @@ mod tests {
         let deserialized = Ray::from_binary(&binary).unwrap();
         assert_eq!(ray, deserialized);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_ray2d_box_intersection() {
+        let bbox = Box2D::new(Vector2D::new(0.0, 0.0), Vector2D::new(10.0, 10.0));
+
+        let hit = Ray2D::new(Vector2D::new(-5.0, 5.0), Vector2D::new(1.0, 0.0));
+        assert_eq!(hit.intersects_box(bbox), Some(5.0));
+        assert_eq!(bbox.intersects_ray(hit), Some(5.0));
+        let inside = Ray2D::new(Vector2D::new(5.0, 5.0), Vector2D::new(0.0, -1.0));
+        assert_eq!(inside.intersects_box(bbox), Some(0.0));
+        let behind = Ray2D::new(Vector2D::new(15.0, 5.0), Vector2D::new(1.0, 0.0));
+        assert_eq!(behind.intersects_box(bbox), None);
+
+        // Grazing the (10, 10) corner along a diagonal
+        let grazing = Ray2D::from_origin_to_target(Vector2D::new(20.0, 0.0), Vector2D::new(10.0, 10.0));
+        let distance = grazing.intersects_box(bbox).unwrap();
+        assert!((grazing.point_at_distance(distance) - Vector2D::new(10.0, 10.0)).length() < 1e-9);
+        let near_miss = Ray2D::from_origin_to_target(Vector2D::new(20.0, 0.0), Vector2D::new(10.0, 10.001));
+        assert_eq!(near_miss.intersects_box(bbox), None);
+
+        assert_eq!(hit.distance_to_point(Vector2D::new(0.0, 8.0)), 3.0);
+        assert_eq!(format!("{}", hit), "Ray2D(Origin: (-5.000, 5.000), Direction: (1.000, 0.000))");
+        let json = serde_json::to_string(&hit).unwrap();
+        assert_eq!(serde_json::from_str::<Ray2D>(&json).unwrap(), hit);
+        assert_eq!(Ray2D::from_binary(&hit.to_binary().unwrap()).unwrap(), hit);
+    }
+}
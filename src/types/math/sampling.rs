@@ -0,0 +1,248 @@
+//! Seeded shuffling and random selection over TArrays
+
+use crate::containers::TArray;
+use super::RandomStream;
+use core::fmt;
+use alloc::vec::Vec;
+
+/// Reason a weighted selection couldn't be made
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SamplingError {
+    /// The item and weight arrays differ in length
+    LengthMismatch {
+        /// Number of items
+        items: usize,
+        /// Number of weights
+        weights: usize,
+    },
+    /// The weight at this index is negative, NaN or infinite
+    InvalidWeight(usize),
+    /// No weight is above zero (including when there are no items)
+    ZeroTotalWeight,
+}
+
+impl fmt::Display for SamplingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SamplingError::LengthMismatch { items, weights } => {
+                write!(f, "{} items but {} weights", items, weights)
+            }
+            SamplingError::InvalidWeight(index) => {
+                write!(f, "weight {} must be finite and non-negative", index)
+            }
+            SamplingError::ZeroTotalWeight => write!(f, "at least one weight must be above zero"),
+        }
+    }
+}
+
+impl core::error::Error for SamplingError {}
+
+/// Shuffle the array in place (Fisher-Yates)
+///
+/// Every ordering is equally likely, and the same stream state always
+/// produces the same ordering.
+pub fn shuffle<T>(items: &mut TArray<T>, stream: &mut RandomStream) {
+    for last in (1..items.len()).rev() {
+        let pick = random_index(stream, last + 1);
+        items.as_mut_slice().swap(last, pick);
+    }
+}
+
+/// Pick one element uniformly, or `None` if the array is empty
+pub fn choose<'a, T>(items: &'a TArray<T>, stream: &mut RandomStream) -> Option<&'a T> {
+    if items.is_empty() {
+        return None;
+    }
+    items.as_slice().get(random_index(stream, items.len()))
+}
+
+/// Pick one element with probability proportional to its weight
+///
+/// `weights[i]` is the weight of `items[i]`. Weights must be finite and
+/// non-negative, with at least one above zero; zero-weight items are never
+/// picked. Draws one value from the stream.
+pub fn choose_weighted<'a, T>(
+    items: &'a TArray<T>,
+    weights: &TArray<f32>,
+    stream: &mut RandomStream,
+) -> Result<&'a T, SamplingError> {
+    if items.len() != weights.len() {
+        return Err(SamplingError::LengthMismatch { items: items.len(), weights: weights.len() });
+    }
+    let mut total = 0.0_f64;
+    for (index, &weight) in weights.iter().enumerate() {
+        if !weight.is_finite() || weight < 0.0 {
+            return Err(SamplingError::InvalidWeight(index));
+        }
+        total += weight as f64;
+    }
+    if total <= 0.0 {
+        return Err(SamplingError::ZeroTotalWeight);
+    }
+
+    let target = stream.frand() as f64 * total;
+    let mut cumulative = 0.0;
+    let mut chosen = 0;
+    for (index, &weight) in weights.iter().enumerate() {
+        if weight > 0.0 {
+            chosen = index;
+            cumulative += weight as f64;
+            if target < cumulative {
+                break;
+            }
+        }
+    }
+    // Rounding can leave `target` past the sum; `chosen` is then the last positive weight
+    Ok(&items.as_slice()[chosen])
+}
+
+/// Pick `count` distinct elements uniformly, in the order they were drawn
+///
+/// Returns every element, shuffled, if `count` is at least the array's
+/// length.
+pub fn sample_without_replacement<'a, T>(
+    items: &'a TArray<T>,
+    count: usize,
+    stream: &mut RandomStream,
+) -> TArray<&'a T> {
+    let count = count.min(items.len());
+    // Partial Fisher-Yates over indices: the first `count` slots are the sample
+    let mut indices: Vec<usize> = (0..items.len()).collect();
+    for slot in 0..count {
+        let pick = slot + random_index(stream, items.len() - slot);
+        indices.swap(slot, pick);
+    }
+    indices[..count].iter().map(|&index| &items.as_slice()[index]).collect()
+}
+
+/// Uniform index in `0..len`, for `len >= 1`
+fn random_index(stream: &mut RandomStream, len: usize) -> usize {
+    let max = i32::try_from(len - 1).unwrap_or(i32::MAX);
+    stream.rand_range(0, max) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Pearson's chi-square statistic for observed counts against expected probabilities
+    fn chi_square(counts: &[u32], probabilities: &[f64]) -> f64 {
+        let total: u32 = counts.iter().sum();
+        counts
+            .iter()
+            .zip(probabilities)
+            .map(|(&count, &probability)| {
+                let expected = total as f64 * probability;
+                (count as f64 - expected).powi(2) / expected
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_choose_weighted_distribution() {
+        let loot = TArray::from_vec(vec!["common", "uncommon", "never", "rare", "epic"]);
+        let weights = TArray::from_vec(vec![50.0, 30.0, 0.0, 15.0, 5.0]);
+        let mut stream = RandomStream::new(1830);
+
+        let mut counts = [0u32; 5];
+        for _ in 0..100_000 {
+            let item = choose_weighted(&loot, &weights, &mut stream).unwrap();
+            counts[loot.find(item) as usize] += 1;
+        }
+        assert_eq!(counts[2], 0);
+        let observed = [counts[0], counts[1], counts[3], counts[4]];
+        // 99.9th percentile of chi-square with 3 degrees of freedom
+        assert!(chi_square(&observed, &[0.5, 0.3, 0.15, 0.05]) < 16.27, "{:?}", counts);
+    }
+
+    #[test]
+    fn test_choose_weighted_validation() {
+        let items = TArray::from_vec(vec![1, 2, 3]);
+        let mut stream = RandomStream::new(7);
+        let choose_with = |weights: Vec<f32>, stream: &mut RandomStream| {
+            choose_weighted(&items, &TArray::from_vec(weights), stream).copied()
+        };
+
+        assert_eq!(choose_with(vec![1.0, 2.0], &mut stream), Err(SamplingError::LengthMismatch { items: 3, weights: 2 }));
+        assert_eq!(choose_with(vec![1.0, -0.5, 1.0], &mut stream), Err(SamplingError::InvalidWeight(1)));
+        assert_eq!(choose_with(vec![1.0, 1.0, f32::NAN], &mut stream), Err(SamplingError::InvalidWeight(2)));
+        assert_eq!(choose_with(vec![f32::INFINITY, 1.0, 1.0], &mut stream), Err(SamplingError::InvalidWeight(0)));
+        assert_eq!(choose_with(vec![0.0, 0.0, 0.0], &mut stream), Err(SamplingError::ZeroTotalWeight));
+        assert_eq!(choose_with(vec![0.0, 0.0, 2.0], &mut stream), Ok(3));
+        assert_eq!(
+            choose_weighted(&TArray::<i32>::new(), &TArray::new(), &mut stream),
+            Err(SamplingError::ZeroTotalWeight)
+        );
+        assert_eq!(SamplingError::InvalidWeight(1).to_string(), "weight 1 must be finite and non-negative");
+    }
+
+    #[test]
+    fn test_shuffle_is_uniform() {
+        // All 6 orderings of 3 elements should be equally likely
+        let mut stream = RandomStream::new(42);
+        let mut counts = [0u32; 6];
+        for _ in 0..60_000 {
+            let mut items = TArray::from_vec(vec![0, 1, 2]);
+            shuffle(&mut items, &mut stream);
+            let rank = match items.as_slice() {
+                [0, 1, 2] => 0,
+                [0, 2, 1] => 1,
+                [1, 0, 2] => 2,
+                [1, 2, 0] => 3,
+                [2, 0, 1] => 4,
+                _ => 5,
+            };
+            counts[rank] += 1;
+        }
+        // 99.9th percentile of chi-square with 5 degrees of freedom
+        assert!(chi_square(&counts, &[1.0 / 6.0; 6]) < 20.52, "{:?}", counts);
+
+        let mut single = TArray::from_vec(vec![9]);
+        shuffle(&mut single, &mut stream);
+        assert_eq!(single.as_slice(), &[9]);
+        shuffle(&mut TArray::<i32>::new(), &mut stream);
+    }
+
+    #[test]
+    fn test_choose_and_sample() {
+        let mut stream = RandomStream::new(3);
+        assert_eq!(choose(&TArray::<i32>::new(), &mut stream), None);
+
+        let items = TArray::from_vec((0..10).collect::<Vec<i32>>());
+        let mut inclusion = [0u32; 10];
+        for _ in 0..20_000 {
+            let sample = sample_without_replacement(&items, 3, &mut stream);
+            assert_eq!(sample.len(), 3);
+            assert!(sample[0usize] != sample[1usize] && sample[1usize] != sample[2usize] && sample[0usize] != sample[2usize]);
+            for &&value in sample.iter() {
+                inclusion[value as usize] += 1;
+            }
+        }
+        // Each element is in a sample of 3 out of 10 with probability 0.3
+        assert!(chi_square(&inclusion, &[0.1; 10]) < 27.88, "{:?}", inclusion);
+
+        let everything = sample_without_replacement(&items, 50, &mut stream);
+        let mut sorted: Vec<i32> = everything.iter().map(|&&value| value).collect();
+        sorted.sort();
+        assert_eq!(sorted, (0..10).collect::<Vec<i32>>());
+        assert!(sample_without_replacement(&items, 0, &mut stream).is_empty());
+    }
+
+    #[test]
+    fn test_same_seed_same_results() {
+        let items = TArray::from_vec((0..32).collect::<Vec<i32>>());
+        let weights: TArray<f32> = (0..32).map(|i| (i % 5) as f32).collect();
+        let run = |seed: i32| {
+            let mut stream = RandomStream::new(seed);
+            let mut shuffled = items.clone();
+            shuffle(&mut shuffled, &mut stream);
+            let picks: Vec<i32> = (0..20)
+                .map(|_| *choose_weighted(&items, &weights, &mut stream).unwrap() + *choose(&items, &mut stream).unwrap())
+                .collect();
+            let sample: Vec<i32> = sample_without_replacement(&items, 8, &mut stream).iter().map(|&&v| v).collect();
+            (shuffled, picks, sample)
+        };
+        assert_eq!(run(2024), run(2024));
+        assert_ne!(run(2024), run(2025));
+    }
+}
@@ -0,0 +1,289 @@
+//! Conversions between UE world units (centimeters) and metric units
+//!
+//! UE measures distance in centimeters, while physics constants and analytics
+//! are usually in meters. The `Centimeters` and `Meters` newtypes keep the two
+//! apart in signatures; the free functions cover plain `f64` values.
+
+use crate::types::Vector;
+use serde::{Deserialize, Serialize};
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+/// Centimeters in one meter
+pub const CM_PER_M: f64 = 100.0;
+
+/// Centimeters in one kilometer
+pub const CM_PER_KM: f64 = 100_000.0;
+
+/// Seconds in one hour, for km/h conversions
+const SECONDS_PER_HOUR: f64 = 3600.0;
+
+/// Centimeters to meters
+pub fn cm_to_m(cm: f64) -> f64 {
+    cm / CM_PER_M
+}
+
+/// Meters to centimeters
+pub fn m_to_cm(m: f64) -> f64 {
+    m * CM_PER_M
+}
+
+/// Centimeters to kilometers
+pub fn cm_to_km(cm: f64) -> f64 {
+    cm / CM_PER_KM
+}
+
+/// Kilometers to centimeters
+pub fn km_to_cm(km: f64) -> f64 {
+    km * CM_PER_KM
+}
+
+/// Centimeters per second (UE velocities) to meters per second
+pub fn cms_to_mps(cms: f64) -> f64 {
+    cms / CM_PER_M
+}
+
+/// Meters per second to centimeters per second
+pub fn mps_to_cms(mps: f64) -> f64 {
+    mps * CM_PER_M
+}
+
+/// Meters per second to kilometers per hour
+pub fn mps_to_kmh(mps: f64) -> f64 {
+    mps * SECONDS_PER_HOUR / 1000.0
+}
+
+/// Kilometers per hour to meters per second
+pub fn kmh_to_mps(kmh: f64) -> f64 {
+    kmh * 1000.0 / SECONDS_PER_HOUR
+}
+
+/// Centimeters per second to kilometers per hour
+pub fn cms_to_kmh(cms: f64) -> f64 {
+    cms * SECONDS_PER_HOUR / CM_PER_KM
+}
+
+/// Kilometers per hour to centimeters per second
+pub fn kmh_to_cms(kmh: f64) -> f64 {
+    kmh * CM_PER_KM / SECONDS_PER_HOUR
+}
+
+/// Unit conversions for whole vectors, such as UE locations and velocities
+pub trait VectorUnits {
+    /// Scale from centimeters to meters (or cm/s to m/s)
+    fn cm_to_m(self) -> Vector;
+
+    /// Scale from meters to centimeters (or m/s to cm/s)
+    fn m_to_cm(self) -> Vector;
+}
+
+impl VectorUnits for Vector {
+    fn cm_to_m(self) -> Vector {
+        self / CM_PER_M
+    }
+
+    fn m_to_cm(self) -> Vector {
+        self * CM_PER_M
+    }
+}
+
+/// A distance in centimeters, UE's world unit
+///
+/// Serializes as a bare number.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Centimeters(pub f64);
+
+/// A distance in meters
+///
+/// Serializes as a bare number.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Meters(pub f64);
+
+impl Centimeters {
+    /// The same distance in meters
+    pub fn to_meters(self) -> Meters {
+        Meters(cm_to_m(self.0))
+    }
+
+    /// The distance in kilometers
+    pub fn to_kilometers(self) -> f64 {
+        cm_to_km(self.0)
+    }
+}
+
+impl Meters {
+    /// The same distance in centimeters
+    pub fn to_centimeters(self) -> Centimeters {
+        Centimeters(m_to_cm(self.0))
+    }
+}
+
+impl From<Meters> for Centimeters {
+    fn from(meters: Meters) -> Self {
+        meters.to_centimeters()
+    }
+}
+
+impl From<Centimeters> for Meters {
+    fn from(cm: Centimeters) -> Self {
+        cm.to_meters()
+    }
+}
+
+/// Arithmetic, conversion to `f64` and Display with a unit suffix (two decimals unless a precision is given)
+macro_rules! impl_distance_unit {
+    ($t:ident, $suffix:literal) => {
+        impl fmt::Display for $t {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{:.*} {}", f.precision().unwrap_or(2), self.0, $suffix)
+            }
+        }
+
+        impl From<$t> for f64 {
+            fn from(distance: $t) -> Self {
+                distance.0
+            }
+        }
+
+        impl Add for $t {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                Self(self.0 + rhs.0)
+            }
+        }
+
+        impl Sub for $t {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self(self.0 - rhs.0)
+            }
+        }
+
+        impl AddAssign for $t {
+            fn add_assign(&mut self, rhs: Self) {
+                self.0 += rhs.0;
+            }
+        }
+
+        impl SubAssign for $t {
+            fn sub_assign(&mut self, rhs: Self) {
+                self.0 -= rhs.0;
+            }
+        }
+
+        impl Neg for $t {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                Self(-self.0)
+            }
+        }
+
+        impl Mul<f64> for $t {
+            type Output = Self;
+
+            fn mul(self, rhs: f64) -> Self::Output {
+                Self(self.0 * rhs)
+            }
+        }
+
+        impl Div<f64> for $t {
+            type Output = Self;
+
+            fn div(self, rhs: f64) -> Self::Output {
+                Self(self.0 / rhs)
+            }
+        }
+
+        /// Ratio of two distances
+        impl Div for $t {
+            type Output = f64;
+
+            fn div(self, rhs: Self) -> Self::Output {
+                self.0 / rhs.0
+            }
+        }
+    };
+}
+
+impl_distance_unit!(Centimeters, "cm");
+impl_distance_unit!(Meters, "m");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use alloc::format;
+
+    #[test]
+    fn test_distance_conversions() {
+        assert_eq!(cm_to_m(150.0), 1.5);
+        assert_eq!(m_to_cm(1.5), 150.0);
+        assert_eq!(cm_to_km(250_000.0), 2.5);
+        assert_eq!(km_to_cm(2.5), 250_000.0);
+
+        for value in [0.0, 1.0, -37.5, 12_345.678, 1e9] {
+            assert!((m_to_cm(cm_to_m(value)) - value).abs() <= value.abs() * 1e-15);
+            assert!((km_to_cm(cm_to_km(value)) - value).abs() <= value.abs() * 1e-15);
+        }
+    }
+
+    #[test]
+    fn test_speed_conversions() {
+        // 600 cm/s, UE's default max walk speed
+        assert_eq!(cms_to_mps(600.0), 6.0);
+        assert!((cms_to_kmh(600.0) - 21.6).abs() < 1e-12);
+        assert!((mps_to_kmh(10.0) - 36.0).abs() < 1e-12);
+        assert!((kmh_to_mps(36.0) - 10.0).abs() < 1e-12);
+
+        for value in [0.0, 1.0, 600.0, -42.25, 99_999.0] {
+            assert!((mps_to_cms(cms_to_mps(value)) - value).abs() <= value.abs() * 1e-15);
+            assert!((kmh_to_cms(cms_to_kmh(value)) - value).abs() <= value.abs() * 1e-12);
+            assert!((kmh_to_mps(mps_to_kmh(value)) - value).abs() <= value.abs() * 1e-12);
+            assert!((cms_to_kmh(value) - mps_to_kmh(cms_to_mps(value))).abs() <= value.abs() * 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_newtypes() {
+        let cm = Centimeters(150.0);
+        assert_eq!(cm.to_meters(), Meters(1.5));
+        assert_eq!(Centimeters::from(Meters(1.5)), cm);
+        assert_eq!(Meters::from(cm).to_centimeters(), cm);
+        assert_eq!(f64::from(cm), 150.0);
+        assert_eq!(Centimeters(250_000.0).to_kilometers(), 2.5);
+
+        let mut total = cm + Centimeters(50.0) - Centimeters(20.0);
+        total += Centimeters(20.0);
+        total -= Centimeters(50.0);
+        assert_eq!(total, cm);
+        assert_eq!(-cm * 2.0 / 4.0, Centimeters(-75.0));
+        assert_eq!(Meters(3.0) / Meters(1.5), 2.0);
+        assert!(Meters(1.0) < Meters(2.0));
+        assert!(Centimeters(99.0) < Meters(1.0).into());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Centimeters(150.0).to_string(), "150.00 cm");
+        assert_eq!(Meters(1.5).to_string(), "1.50 m");
+        assert_eq!(format!("{:.1}", Meters(2.345)), "2.3 m");
+    }
+
+    #[test]
+    fn test_vector_units() {
+        let location = Vector::new(150.0, -250.0, 1000.0);
+        assert_eq!(location.cm_to_m(), Vector::new(1.5, -2.5, 10.0));
+        assert_eq!(location.cm_to_m().m_to_cm(), location);
+    }
+
+    #[test]
+    fn test_serialization() {
+        assert_eq!(serde_json::to_string(&Centimeters(150.0)).unwrap(), "150.0");
+        assert_eq!(serde_json::from_str::<Meters>("2.5").unwrap(), Meters(2.5));
+    }
+}
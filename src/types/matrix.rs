@@ -0,0 +1,402 @@
+//! UE-style helpers for 4x4 matrices (FMatrix)
+
+use crate::vector::*;
+use crate::transform::Transform;
+use crate::types::Axis;
+use core::fmt;
+#[cfg(not(feature = "std"))]
+use crate::types::math::float_math::FloatMath;
+
+/// Extension trait giving `Matrix4` the FMatrix accessors used by gameplay code
+///
+/// UE stores matrices row-major and multiplies row vectors, so its rows are
+/// glam's columns: `get_scaled_axis(Axis::X)` is `x_axis`, and the origin is
+/// `w_axis`. The layout is the same in memory, only the naming differs.
+pub trait Matrix4Ext: Sized {
+    /// Determinant below which a matrix is treated as singular, relative to the
+    /// product of its axis lengths (UE: `SMALL_NUMBER`)
+    ///
+    /// The product bounds the determinant, so the ratio is 1 for perpendicular
+    /// axes at any scale and only drops toward 0 as an axis collapses.
+    const SINGULAR_DETERMINANT: f64 = 1.0e-8;
+
+    /// Create a matrix from a transform (same as `Transform::to_matrix`)
+    fn from_transform(transform: Transform) -> Self;
+
+    /// Decompose into a transform (same as `Transform::from_matrix`)
+    fn to_transform(&self) -> Transform;
+
+    /// The axis including any scale
+    fn get_scaled_axis(&self, axis: Axis) -> Vector;
+
+    /// The translation part
+    fn get_origin(&self) -> Vector;
+
+    /// Replace the translation part
+    fn set_origin(&mut self, origin: Vector);
+
+    /// Scale along each axis; axes with squared length at or below `tolerance` report 0
+    fn get_scale_vector(&self, tolerance: f64) -> Vector;
+
+    /// Normalize each axis, leaving axes with squared length at or below `tolerance` alone
+    fn remove_scaling(&mut self, tolerance: f64);
+
+    /// Get the transposed matrix
+    fn get_transposed(&self) -> Self;
+
+    /// Get the inverse, or `None` if the matrix is singular or not finite
+    fn inverse_checked(&self) -> Option<Self>;
+
+    /// Transform a position (applies translation)
+    fn transform_position(&self, position: Vector) -> Vector;
+
+    /// Transform a direction (ignores translation)
+    fn transform_vector(&self, vector: Vector) -> Vector;
+
+    /// Display adapter printing one UE row per line
+    fn display(&self) -> MatrixDisplay<'_>;
+}
+
+impl Matrix4Ext for Matrix4 {
+    fn from_transform(transform: Transform) -> Self {
+        transform.to_matrix()
+    }
+
+    fn to_transform(&self) -> Transform {
+        Transform::from_matrix(*self)
+    }
+
+    fn get_scaled_axis(&self, axis: Axis) -> Vector {
+        match axis {
+            Axis::X => self.x_axis.truncate(),
+            Axis::Y => self.y_axis.truncate(),
+            Axis::Z => self.z_axis.truncate(),
+        }
+    }
+
+    fn get_origin(&self) -> Vector {
+        self.w_axis.truncate()
+    }
+
+    fn set_origin(&mut self, origin: Vector) {
+        self.w_axis = origin.extend(self.w_axis.w);
+    }
+
+    fn get_scale_vector(&self, tolerance: f64) -> Vector {
+        let scale = |axis: Vector| {
+            let square_sum = axis.length_squared();
+            if square_sum > tolerance {
+                square_sum.sqrt()
+            } else {
+                0.0
+            }
+        };
+        Vector::new(
+            scale(self.get_scaled_axis(Axis::X)),
+            scale(self.get_scaled_axis(Axis::Y)),
+            scale(self.get_scaled_axis(Axis::Z)),
+        )
+    }
+
+    fn remove_scaling(&mut self, tolerance: f64) {
+        for column in [&mut self.x_axis, &mut self.y_axis, &mut self.z_axis] {
+            let square_sum = column.truncate().length_squared();
+            if square_sum > tolerance {
+                let w = column.w;
+                *column = (column.truncate() / square_sum.sqrt()).extend(w);
+            }
+        }
+    }
+
+    fn get_transposed(&self) -> Self {
+        self.transpose()
+    }
+
+    fn inverse_checked(&self) -> Option<Self> {
+        if !self.determinant().is_finite() || is_singular(self) {
+            return None;
+        }
+        Some(self.inverse())
+    }
+
+    fn transform_position(&self, position: Vector) -> Vector {
+        self.transform_point3(position)
+    }
+
+    fn transform_vector(&self, vector: Vector) -> Vector {
+        self.transform_vector3(vector)
+    }
+
+    fn display(&self) -> MatrixDisplay<'_> {
+        MatrixDisplay(self)
+    }
+}
+
+/// Reason a `Matrix4` can't be represented as a `Transform`
+///
+/// Returned by `Transform::try_from`, which accepts reflections.
+/// `Transform::try_from_matrix` returns the more detailed
+/// [`DecompositionError`] instead, which carries the amount of shear and
+/// also rejects reflections.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatrixDecomposeError {
+    /// An element is NaN or infinite
+    NotFinite,
+    /// The last row isn't (0, 0, 0, 1), so the matrix has a projective part
+    Projective,
+    /// The determinant is (nearly) zero, so an axis has collapsed
+    Singular,
+    /// Two axes aren't perpendicular; a transform can't hold shear
+    Shear,
+}
+
+impl fmt::Display for MatrixDecomposeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MatrixDecomposeError::NotFinite => write!(f, "matrix has NaN or infinite elements"),
+            MatrixDecomposeError::Projective => {
+                write!(f, "matrix has a projective part (last row is not 0, 0, 0, 1)")
+            }
+            MatrixDecomposeError::Singular => {
+                write!(
+                    f,
+                    "matrix determinant is below {} of its axis lengths, an axis has zero scale",
+                    Matrix4::SINGULAR_DETERMINANT
+                )
+            }
+            MatrixDecomposeError::Shear => write!(f, "matrix axes are not perpendicular (shear)"),
+        }
+    }
+}
+
+impl core::error::Error for MatrixDecomposeError {}
+
+/// Reason `Transform::try_from_matrix` rejected a matrix
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DecompositionError {
+    /// An element is NaN or infinite
+    NotFinite,
+    /// The last row isn't (0, 0, 0, 1), so the matrix has a projective part
+    Projective,
+    /// The determinant is (nearly) zero, so an axis has collapsed
+    SingularMatrix,
+    /// Two axes aren't perpendicular; a transform can't hold shear
+    ContainsShear {
+        /// Largest cosine of the angle between two axes (0 when perpendicular)
+        max_shear: f64,
+    },
+    /// The matrix mirrors space
+    NegativeDeterminant,
+}
+
+impl fmt::Display for DecompositionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecompositionError::NotFinite => MatrixDecomposeError::NotFinite.fmt(f),
+            DecompositionError::Projective => MatrixDecomposeError::Projective.fmt(f),
+            DecompositionError::SingularMatrix => MatrixDecomposeError::Singular.fmt(f),
+            DecompositionError::ContainsShear { max_shear } => {
+                write!(f, "matrix axes are not perpendicular (shear {:.6})", max_shear)
+            }
+            DecompositionError::NegativeDeterminant => write!(f, "matrix has a negative determinant (reflection)"),
+        }
+    }
+}
+
+impl core::error::Error for DecompositionError {}
+
+impl From<Transform> for Matrix4 {
+    fn from(transform: Transform) -> Self {
+        transform.to_matrix()
+    }
+}
+
+/// Like `Transform::from_matrix`, but rejects matrices a transform can't represent
+///
+/// Uses `Transform::SHEAR_TOLERANCE`. Mirroring is allowed and comes back as
+/// a negative X scale; use `Transform::try_from_matrix` to reject it.
+impl TryFrom<Matrix4> for Transform {
+    type Error = MatrixDecomposeError;
+
+    fn try_from(matrix: Matrix4) -> Result<Self, Self::Error> {
+        check_decomposable(&matrix, Transform::SHEAR_TOLERANCE).map_err(|error| match error {
+            DecompositionError::NotFinite => MatrixDecomposeError::NotFinite,
+            DecompositionError::Projective => MatrixDecomposeError::Projective,
+            DecompositionError::SingularMatrix => MatrixDecomposeError::Singular,
+            DecompositionError::ContainsShear { .. } => MatrixDecomposeError::Shear,
+            DecompositionError::NegativeDeterminant => unreachable!("check_decomposable accepts reflections"),
+        })?;
+        Ok(Transform::from_matrix(matrix))
+    }
+}
+
+/// Check if the determinant is negligible next to the product of the axis lengths
+///
+/// The translation row is left out so moving a matrix never changes the answer.
+fn is_singular(matrix: &Matrix4) -> bool {
+    let volume_bound = matrix.x_axis.length() * matrix.y_axis.length() * matrix.z_axis.length();
+    matrix.determinant().abs() <= Matrix4::SINGULAR_DETERMINANT * volume_bound
+}
+
+/// Check everything `Transform::from_matrix` needs except a positive determinant
+pub(crate) fn check_decomposable(matrix: &Matrix4, shear_tolerance: f64) -> Result<(), DecompositionError> {
+    if !matrix.is_finite() {
+        return Err(DecompositionError::NotFinite);
+    }
+    let axes = [matrix.x_axis, matrix.y_axis, matrix.z_axis];
+    if axes.iter().any(|axis| axis.w != 0.0) || matrix.w_axis.w != 1.0 {
+        return Err(DecompositionError::Projective);
+    }
+    if is_singular(matrix) {
+        return Err(DecompositionError::SingularMatrix);
+    }
+    let [x, y, z] = axes.map(|axis| axis.truncate());
+    let max_shear = [(x, y), (y, z), (z, x)]
+        .into_iter()
+        .map(|(a, b)| a.dot(b).abs() / (a.length() * b.length()))
+        .fold(0.0, f64::max);
+    if max_shear > shear_tolerance {
+        return Err(DecompositionError::ContainsShear { max_shear });
+    }
+    Ok(())
+}
+
+/// Formats a `Matrix4` row-major as UE prints it: X axis, Y axis, Z axis, origin
+pub struct MatrixDisplay<'a>(&'a Matrix4);
+
+impl fmt::Display for MatrixDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows = self.0.to_cols_array_2d();
+        for (i, row) in rows.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "[{:.4} {:.4} {:.4} {:.4}]", row[0], row[1], row[2], row[3])?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::DQuat;
+
+    #[test]
+    fn test_matrix_transform_round_trip() {
+        let transform = Transform::new(
+            Vector::new(10.0, -20.0, 30.0),
+            DQuat::from_rotation_z(90.0_f64.to_radians()),
+            Vector::new(2.0, 3.0, 4.0),
+        );
+        let mut matrix = Matrix4::from_transform(transform);
+        assert!(matrix.to_transform().is_nearly_equal(transform, 1e-9));
+
+        assert_eq!(matrix.get_origin(), Vector::new(10.0, -20.0, 30.0));
+        assert!((matrix.get_scaled_axis(Axis::X) - Vector::new(0.0, 2.0, 0.0)).length() < 1e-12);
+        assert!((matrix.get_scale_vector(1e-8) - Vector::new(2.0, 3.0, 4.0)).length() < 1e-12);
+
+        let point = Vector::new(1.0, 1.0, 1.0);
+        assert!((matrix.transform_position(point) - transform.transform_point(point)).length() < 1e-9);
+        assert!((matrix.transform_vector(point) - transform.transform_vector(point)).length() < 1e-9);
+
+        let inverse = matrix.inverse_checked().unwrap();
+        assert!((inverse.transform_position(matrix.transform_position(point)) - point).length() < 1e-9);
+        assert_eq!(matrix.get_transposed().get_transposed(), matrix);
+
+        matrix.remove_scaling(1e-8);
+        assert!((matrix.get_scale_vector(1e-8) - Vector::ONE).length() < 1e-12);
+        matrix.set_origin(Vector::ZERO);
+        assert!(matrix.to_transform().is_nearly_equal(Transform::from_rotation(transform.rotation), 1e-9));
+    }
+
+    #[test]
+    fn test_singular_matrices() {
+        let flattened = Matrix4::from_transform(Transform::from_scale(Vector::new(1.0, 1.0, 0.0)));
+        assert!(flattened.inverse_checked().is_none());
+        assert_eq!(flattened.get_scale_vector(1e-8), Vector::new(1.0, 1.0, 0.0));
+
+        // remove_scaling leaves the collapsed axis alone rather than dividing by zero
+        let mut normalized = flattened;
+        normalized.remove_scaling(1e-8);
+        assert!(normalized.is_finite());
+        assert_eq!(normalized.get_scaled_axis(Axis::Z), Vector::ZERO);
+
+        assert!(Matrix4::ZERO.inverse_checked().is_none());
+        assert!(Matrix4::NAN.inverse_checked().is_none());
+        assert_eq!(Matrix4::IDENTITY.inverse_checked(), Some(Matrix4::IDENTITY));
+    }
+
+    #[test]
+    fn test_singular_threshold_is_relative_to_scale() {
+        // A uniformly tiny matrix has a determinant far below 1e-8 but isn't degenerate
+        let tiny = Matrix4::from_transform(Transform::from_scale(Vector::splat(1e-4)));
+        assert!(tiny.determinant().abs() < Matrix4::SINGULAR_DETERMINANT);
+        let inverse = tiny.inverse_checked().unwrap();
+        assert!((inverse * tiny).abs_diff_eq(Matrix4::IDENTITY, 1e-9));
+        assert!(Transform::try_from(tiny).is_ok());
+
+        // A huge matrix whose axes are nearly parallel is, whatever its determinant
+        let mut squashed = Matrix4::from_transform(Transform::from_scale(Vector::splat(1e6)));
+        squashed.y_axis = glam::DVec4::new(1e6, 1e-6, 0.0, 0.0);
+        assert!(squashed.determinant().abs() > Matrix4::SINGULAR_DETERMINANT);
+        assert!(squashed.inverse_checked().is_none());
+        assert_eq!(Transform::try_from(squashed), Err(MatrixDecomposeError::Singular));
+
+        // Translation doesn't count toward the axis lengths
+        let moved = Matrix4::from_transform(Transform::new(
+            Vector::splat(1e9),
+            DQuat::IDENTITY,
+            Vector::splat(1e-3),
+        ));
+        assert!(moved.inverse_checked().is_some());
+    }
+
+    #[test]
+    fn test_matrix_transform_from_impls() {
+        let transform = Transform::new(
+            Vector::new(-5.0, 250.0, 12.5),
+            DQuat::from_euler(glam::EulerRot::ZYX, 0.3, -1.1, 2.0),
+            Vector::new(0.5, 3.0, 1.25),
+        );
+        let matrix = Matrix4::from(transform);
+        assert_eq!(matrix, transform.to_matrix());
+        assert!(Transform::try_from(matrix).unwrap().is_nearly_equal(transform, 1e-9));
+
+        let mirrored = Matrix4::from_scale(Vector::new(-1.0, 1.0, 1.0));
+        let decomposed = Transform::try_from(mirrored).unwrap();
+        assert!(Matrix4::from(decomposed).abs_diff_eq(mirrored, 1e-12));
+
+        let mut sheared = matrix;
+        sheared.y_axis += sheared.x_axis * 0.1;
+        assert_eq!(Transform::try_from(sheared), Err(MatrixDecomposeError::Shear));
+        let flattened = Matrix4::from_transform(Transform::from_scale(Vector::new(1.0, 0.0, 1.0)));
+        assert_eq!(Transform::try_from(flattened), Err(MatrixDecomposeError::Singular));
+        let perspective = glam::DMat4::perspective_rh(1.0, 1.5, 0.1, 100.0);
+        assert_eq!(Transform::try_from(perspective), Err(MatrixDecomposeError::Projective));
+        assert_eq!(Transform::try_from(Matrix4::NAN), Err(MatrixDecomposeError::NotFinite));
+
+        assert_eq!(
+            MatrixDecomposeError::Shear.to_string(),
+            "matrix axes are not perpendicular (shear)"
+        );
+        assert_eq!(
+            MatrixDecomposeError::Singular.to_string(),
+            "matrix determinant is below 0.00000001 of its axis lengths, an axis has zero scale"
+        );
+    }
+
+    #[test]
+    fn test_matrix_display() {
+        let matrix = Matrix4::from_transform(Transform::from_location(Vector::new(1.0, 2.0, 3.0)));
+        assert_eq!(
+            matrix.display().to_string(),
+            "[1.0000 0.0000 0.0000 0.0000]\n\
+             [0.0000 1.0000 0.0000 0.0000]\n\
+             [0.0000 0.0000 1.0000 0.0000]\n\
+             [1.0000 2.0000 3.0000 1.0000]"
+        );
+        let binary = crate::BinarySerializable::to_binary(&matrix).unwrap();
+        assert_eq!(<Matrix4 as crate::BinarySerializable>::from_binary(&binary).unwrap(), matrix);
+    }
+}
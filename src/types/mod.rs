@@ -6,6 +6,8 @@
 // Core math types
 pub mod vector;
 pub mod rotator;
+pub mod quaternion;
+pub mod matrix;
 pub mod transform;
 pub mod math;
 
@@ -21,9 +23,16 @@ pub mod containers;
 // Networking types
 pub mod networking;
 
+// Serialization formats
+pub mod serialization;
+
+// Crate-level error
+pub mod error;
+
 // Utility types
 pub mod time;
 pub mod guid;
+#[cfg(feature = "std")]
 pub mod name;
 pub mod text;
 pub mod version;
@@ -34,14 +43,19 @@ mod integration_tests;
 // Re-export commonly used types for convenience
 pub use vector::*;
 pub use rotator::*;
+pub use quaternion::*;
+pub use matrix::*;
 pub use transform::*;
 pub use math::*;
 pub use color::*;
 pub use bounds::*;
 pub use containers::*;
 pub use networking::*;
+pub use serialization::*;
+pub use error::*;
 pub use time::*;
 pub use guid::*;
+#[cfg(feature = "std")]
 pub use name::*;
 pub use text::*;
 pub use version::*;
@@ -1,69 +1,285 @@
-//! UE5-style Name for fast string comparisons (using string interning concept)
+//! UE5-style Name (FName): interned, case-insensitive identifiers
 
 use crate::BinarySerializable;
-use serde::{Deserialize, Serialize};
-use std::fmt;
+use crate::types::UeTypesError;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use core::cmp::Ordering;
+use crate::types::containers::hash::HashMap;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use std::sync::{OnceLock, RwLock};
 
-/// UE5-style Name for fast string comparisons (using string interning concept)
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+/// Internal number meaning "no number suffix" (UE5: NAME_NO_NUMBER_INTERNAL)
+const NAME_NO_NUMBER: u32 = 0;
+
+/// Longest digit run accepted as a number suffix
+const MAX_SUFFIX_DIGITS: usize = 10;
+
+/// Most string bytes the global name table will hold (16 MiB)
+///
+/// Interned strings are never freed, so this bounds the memory that
+/// deserializing untrusted names can pin.
+pub const MAX_NAME_TABLE_BYTES: usize = 16 * 1024 * 1024;
+
+/// Global interning table shared by every Name
+///
+/// Every distinct spelling gets a display entry so the original casing
+/// survives; spellings that only differ by case share a comparison index.
+struct NameTable {
+    entries: Vec<&'static str>,
+    display_lookup: HashMap<&'static str, u32>,
+    comparison_lookup: HashMap<String, u32>,
+    bytes: usize,
+}
+
+impl NameTable {
+    fn new() -> Self {
+        let mut table = Self {
+            entries: Vec::new(),
+            display_lookup: HashMap::new(),
+            comparison_lookup: HashMap::new(),
+            bytes: 0,
+        };
+        // Index 0 is always "None", like NAME_None in UE
+        let _ = table.intern("None");
+        table
+    }
+
+    fn lookup(&self, plain: &str) -> Option<(u32, u32)> {
+        let display = *self.display_lookup.get(plain)?;
+        let comparison = self.comparison_lookup[&plain.to_lowercase()];
+        Some((comparison, display))
+    }
+
+    fn intern(&mut self, plain: &str) -> Result<(u32, u32), UeTypesError> {
+        if let Some(indices) = self.lookup(plain) {
+            return Ok(indices);
+        }
+        if plain.len() > MAX_NAME_TABLE_BYTES - self.bytes {
+            return Err(UeTypesError::NameTableFull);
+        }
+
+        // Interned strings live for the rest of the program, as in UE
+        self.bytes += plain.len();
+        let stored: &'static str = Box::leak(plain.to_owned().into_boxed_str());
+        let display = self.entries.len() as u32;
+        self.entries.push(stored);
+        self.display_lookup.insert(stored, display);
+        let comparison = *self
+            .comparison_lookup
+            .entry(plain.to_lowercase())
+            .or_insert(display);
+        Ok((comparison, display))
+    }
+}
+
+fn name_table() -> &'static RwLock<NameTable> {
+    static TABLE: OnceLock<RwLock<NameTable>> = OnceLock::new();
+    TABLE.get_or_init(|| RwLock::new(NameTable::new()))
+}
+
+/// Split a trailing `_<digits>` suffix off a name (e.g. "Item_3" -> ("Item", 3))
+///
+/// Follows UE's rules: the suffix must not have leading zeros (except "0"
+/// itself) and the base must not be empty.
+fn split_number(value: &str) -> (&str, u32) {
+    let digits = value.len() - value.trim_end_matches(|c: char| c.is_ascii_digit()).len();
+    if digits == 0 || digits > MAX_SUFFIX_DIGITS || digits + 1 >= value.len() {
+        return (value, NAME_NO_NUMBER);
+    }
+
+    let split = value.len() - digits;
+    let (base, number) = (&value[..split - 1], &value[split..]);
+    if !value[..split].ends_with('_') || (number.len() > 1 && number.starts_with('0')) {
+        return (value, NAME_NO_NUMBER);
+    }
+
+    match number.parse::<u32>() {
+        Ok(number) if number < u32::MAX => (base, number + 1),
+        _ => (value, NAME_NO_NUMBER),
+    }
+}
+
+/// UE5-style Name (equivalent to FName)
+///
+/// Names are interned in a global table, so copies are cheap and comparisons
+/// only look at indices. Equality, hashing and ordering ignore case, while
+/// the casing the name was created with is kept for display and serialization.
+/// A trailing `_<number>` is stored separately as the name's number.
+#[derive(Debug, Clone, Copy)]
 pub struct Name {
-    /// The actual string value
-    pub value: String,
-    /// Cached hash for fast comparisons
-    #[serde(skip)]
-    hash: u64,
+    comparison_index: u32,
+    display_index: u32,
+    number: u32,
 }
 
 impl fmt::Display for Name {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Name(\"{}\")", self.value)
+        write!(f, "Name(\"{}\")", self.to_string())
     }
 }
 
 impl BinarySerializable for Name {}
 
 impl Name {
-    /// Create a new Name
-    pub fn new(value: impl Into<String>) -> Self {
-        let value = value.into();
-        let hash = Self::calculate_hash(&value);
-        Self { value, hash }
+    /// Create a Name, interning the string if it hasn't been seen before
+    ///
+    /// An empty string produces `Name::none()`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the name is new and the table already holds
+    /// `MAX_NAME_TABLE_BYTES`, as UE does when its name table runs out. Use
+    /// `try_new` for names from untrusted input.
+    pub fn new(value: impl AsRef<str>) -> Self {
+        Self::try_new(value).expect("Name table is full")
     }
 
-    /// Get the string value
-    pub fn as_str(&self) -> &str {
-        &self.value
+    /// Create a Name, or fail if interning it would exceed `MAX_NAME_TABLE_BYTES`
+    pub fn try_new(value: impl AsRef<str>) -> Result<Self, UeTypesError> {
+        let value = value.as_ref();
+        if value.is_empty() {
+            return Ok(Self::none());
+        }
+
+        let (plain, number) = split_number(value);
+        let existing = name_table().read().unwrap().lookup(plain);
+        let (comparison_index, display_index) = match existing {
+            Some(indices) => indices,
+            None => name_table().write().unwrap().intern(plain)?,
+        };
+
+        Ok(Self {
+            comparison_index,
+            display_index,
+            number,
+        })
     }
 
-    /// Get the hash value
+    /// The "None" name (UE5: NAME_None)
+    pub const fn none() -> Self {
+        Self {
+            comparison_index: 0,
+            display_index: 0,
+            number: NAME_NO_NUMBER,
+        }
+    }
+
+    /// Check if this is the "None" name
+    pub fn is_none(&self) -> bool {
+        self.comparison_index == 0 && self.number == NAME_NO_NUMBER
+    }
+
+    /// Get the interned string without the number suffix (UE5: GetPlainNameString())
+    pub fn as_str(&self) -> &'static str {
+        name_table().read().unwrap().entries[self.display_index as usize]
+    }
+
+    /// Get the number suffix, if any (e.g. `Some(3)` for "Item_3")
+    pub fn get_number(&self) -> Option<u32> {
+        match self.number {
+            NAME_NO_NUMBER => None,
+            number => Some(number - 1),
+        }
+    }
+
+    /// Set or clear the number suffix
+    pub fn set_number(&mut self, number: Option<u32>) {
+        self.number = number.map_or(NAME_NO_NUMBER, |n| n.saturating_add(1));
+    }
+
+    /// Index shared by every spelling of this name that differs only by case
+    pub fn comparison_index(&self) -> u32 {
+        self.comparison_index
+    }
+
+    /// Index of the exact spelling this name was created with
+    pub fn display_index(&self) -> u32 {
+        self.display_index
+    }
+
+    /// Get the case-insensitive hash value
     pub fn hash(&self) -> u64 {
-        self.hash
+        let mut hasher = crate::types::containers::hash::hash_map::DefaultHasher::new();
+        Hash::hash(self, &mut hasher);
+        hasher.finish()
     }
 
-    /// Check if the name is empty
+    /// Check if the name is empty (same as `is_none`)
     pub fn is_empty(&self) -> bool {
-        self.value.is_empty()
+        self.is_none()
     }
 
-    /// Get the length of the name
+    /// Get the length of the full name string, including any number suffix
     pub fn len(&self) -> usize {
-        self.value.len()
+        self.to_string().len()
     }
 
-    /// Calculate hash for a string (simple FNV-1a hash)
-    fn calculate_hash(s: &str) -> u64 {
-        let mut hash = 0xcbf29ce484222325u64;
-        for byte in s.bytes() {
-            hash ^= byte as u64;
-            hash = hash.wrapping_mul(0x100000001b3);
+    /// Get the full name string, including any number suffix
+    #[allow(clippy::inherent_to_string_shadow_display)]
+    pub fn to_string(&self) -> String {
+        match self.get_number() {
+            Some(number) => format!("{}_{}", self.as_str(), number),
+            None => self.as_str().to_string(),
         }
-        hash
+    }
+}
+
+impl PartialEq for Name {
+    fn eq(&self, other: &Self) -> bool {
+        self.comparison_index == other.comparison_index && self.number == other.number
+    }
+}
+
+impl Eq for Name {}
+
+impl Hash for Name {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.comparison_index.hash(state);
+        self.number.hash(state);
+    }
+}
+
+impl PartialOrd for Name {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Case-insensitive lexical order, then by number
+///
+/// Characters are lowercased one at a time, so comparing allocates nothing.
+/// Names whose strings still tie (a few scripts lowercase differently in
+/// context) fall back to their comparison index to keep the order total.
+impl Ord for Name {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self == other {
+            return Ordering::Equal;
+        }
+        let lowercase = |name: &Name| name.as_str().chars().flat_map(char::to_lowercase);
+        lowercase(self)
+            .cmp(lowercase(other))
+            .then(self.number.cmp(&other.number))
+            .then(self.comparison_index.cmp(&other.comparison_index))
+    }
+}
+
+impl Serialize for Name {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Name {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Self::try_new(value).map_err(de::Error::custom)
     }
 }
 
 impl Default for Name {
     fn default() -> Self {
-        Self::new("")
+        Self::none()
     }
 }
 
@@ -82,16 +298,144 @@ impl From<String> for Name {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::TMap;
 
     #[test]
     fn test_name() {
         let name1 = Name::new("TestName");
         let name2 = Name::new("TestName");
         let name3 = Name::new("DifferentName");
-        
+
         assert_eq!(name1, name2);
         assert_ne!(name1, name3);
         assert_eq!(name1.hash(), name2.hash());
         assert_ne!(name1.hash(), name3.hash());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_name_case_insensitive() {
+        let player = Name::new("Player");
+        let upper = Name::new("PLAYER");
+
+        assert_eq!(player, upper);
+        assert_eq!(player.hash(), upper.hash());
+        assert_eq!(player.cmp(&upper), Ordering::Equal);
+
+        // Each spelling keeps its own casing
+        assert_eq!(player.as_str(), "Player");
+        assert_eq!(upper.as_str(), "PLAYER");
+    }
+
+    #[test]
+    fn test_name_interning() {
+        let a = Name::new("InternedName");
+        let b = Name::new(String::from("InternedName"));
+        let c = Name::new("internedname");
+
+        assert_eq!(a.display_index(), b.display_index());
+        assert_eq!(a.comparison_index(), b.comparison_index());
+        assert_eq!(a.comparison_index(), c.comparison_index());
+        assert_ne!(a.display_index(), c.display_index());
+    }
+
+    #[test]
+    fn test_name_none() {
+        assert!(Name::none().is_none());
+        assert!(Name::new("").is_none());
+        assert_eq!(Name::new("none"), Name::none());
+        assert_eq!(Name::default(), Name::none());
+        assert_eq!(Name::none().to_string(), "None");
+    }
+
+    #[test]
+    fn test_name_number_suffix() {
+        let item = Name::new("Item_3");
+        assert_eq!(item.as_str(), "Item");
+        assert_eq!(item.get_number(), Some(3));
+        assert_eq!(item.to_string(), "Item_3");
+        assert_eq!(item.comparison_index(), Name::new("Item").comparison_index());
+        assert_ne!(item, Name::new("Item"));
+        assert_eq!(item, Name::new("ITEM_3"));
+
+        let mut renamed = Name::new("Item");
+        assert_eq!(renamed.get_number(), None);
+        renamed.set_number(Some(3));
+        assert_eq!(renamed, item);
+        renamed.set_number(None);
+        assert_eq!(renamed, Name::new("Item"));
+
+        assert_eq!(Name::new("Item_0").get_number(), Some(0));
+
+        // Not valid number suffixes
+        for raw in ["Item_03", "_3", "Item3", "Item_", "Item_99999999999"] {
+            let name = Name::new(raw);
+            assert_eq!(name.get_number(), None, "{raw}");
+            assert_eq!(name.to_string(), raw);
+        }
+    }
+
+    #[test]
+    fn test_name_ordering() {
+        let mut names = [
+            Name::new("beta"),
+            Name::new("Alpha"),
+            Name::new("Gamma_2"),
+            Name::new("gamma_1"),
+        ];
+        names.sort();
+        let sorted: Vec<String> = names.iter().map(|n| n.to_string()).collect();
+        assert_eq!(sorted, vec!["Alpha", "beta", "gamma_1", "Gamma_2"]);
+    }
+
+    #[test]
+    fn test_name_ordering_is_total() {
+        // "ΑΣ" lowercases to "ας" as a string but to "ασ" char by char
+        let final_sigma = Name::new("ΑΣ");
+        let sigma = Name::new("ασ");
+        assert_ne!(final_sigma, sigma);
+        assert_ne!(final_sigma.cmp(&sigma), Ordering::Equal);
+        assert_eq!(final_sigma.cmp(&sigma), sigma.cmp(&final_sigma).reverse());
+    }
+
+    #[test]
+    fn test_name_table_budget() {
+        let mut table = NameTable::new();
+        // "None" takes the first 4 bytes, leaving room for 3 more
+        let big = "x".repeat(MAX_NAME_TABLE_BYTES - 7);
+        assert!(table.intern(&big).is_ok());
+        // Already interned strings are still found once the table is full
+        assert_eq!(table.intern(&big), Ok(table.lookup(&big).unwrap()));
+        assert_eq!(table.intern("TooMuch"), Err(UeTypesError::NameTableFull));
+        assert!(table.intern("Fit").is_ok());
+    }
+
+    #[test]
+    fn test_name_as_tmap_key() {
+        let mut map = TMap::new();
+        map.add(Name::new("Health"), 100);
+        map.add(Name::new("HEALTH"), 50);
+
+        assert_eq!(map.num(), 1);
+        assert_eq!(map.find(&Name::new("health")), Some(&50));
+    }
+
+    #[test]
+    fn test_name_serialization() {
+        let name = Name::new("MixedCase_Name_7");
+
+        let json = serde_json::to_string(&name).unwrap();
+        assert_eq!(json, "\"MixedCase_Name_7\"");
+        let deserialized: Name = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, name);
+        assert_eq!(deserialized.to_string(), "MixedCase_Name_7");
+
+        let binary = name.to_binary().unwrap();
+        let deserialized = Name::from_binary(&binary).unwrap();
+        assert_eq!(deserialized.to_string(), "MixedCase_Name_7");
+    }
+
+    #[test]
+    fn test_name_display() {
+        assert_eq!(format!("{}", Name::new("Door_2")), "Name(\"Door_2\")");
+    }
+}
@@ -0,0 +1,406 @@
+//! Actor replication roles, dormancy and flags (UE: `ENetRole`, `ENetDormancy`)
+
+use crate::BinarySerializable;
+use crate::types::SerializedSize;
+use super::NetworkGUID;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use core::fmt;
+use core::ops::{BitAnd, BitOr, BitOrAssign, Not};
+
+/// How much control this machine has over an actor (UE: `ENetRole`)
+///
+/// Ordered by authority, so `role >= NetRole::AutonomousProxy` reads the
+/// same as UE's `GetLocalRole() >= ROLE_AutonomousProxy`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub enum NetRole {
+    /// Not replicated here
+    #[default]
+    None,
+    /// Client copy driven entirely by the server
+    SimulatedProxy,
+    /// Client copy the local player controls and predicts
+    AutonomousProxy,
+    /// The machine that owns the actor's state, usually the server
+    Authority,
+}
+
+impl fmt::Display for NetRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let role_str = match self {
+            NetRole::None => "None",
+            NetRole::SimulatedProxy => "SimulatedProxy",
+            NetRole::AutonomousProxy => "AutonomousProxy",
+            NetRole::Authority => "Authority",
+        };
+        write!(f, "{}", role_str)
+    }
+}
+
+impl NetRole {
+    /// Check whether this machine owns the actor's state (UE: `HasAuthority`)
+    pub fn has_authority(self) -> bool {
+        self == NetRole::Authority
+    }
+
+    /// Check whether this is a client copy of the actor
+    pub fn is_proxy(self) -> bool {
+        matches!(self, NetRole::SimulatedProxy | NetRole::AutonomousProxy)
+    }
+}
+
+/// Whether an actor is currently replicating (UE: `ENetDormancy`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum NetDormancy {
+    /// Never goes dormant
+    Never,
+    /// Replicating now, but may go dormant
+    #[default]
+    Awake,
+    /// Dormant for every connection
+    DormantAll,
+    /// Dormant for some connections, chosen per connection
+    DormantPartial,
+    /// Placed in the level dormant; replicates nothing until woken
+    Initial,
+}
+
+impl fmt::Display for NetDormancy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let dormancy_str = match self {
+            NetDormancy::Never => "Never",
+            NetDormancy::Awake => "Awake",
+            NetDormancy::DormantAll => "DormantAll",
+            NetDormancy::DormantPartial => "DormantPartial",
+            NetDormancy::Initial => "Initial",
+        };
+        write!(f, "{}", dormancy_str)
+    }
+}
+
+impl NetDormancy {
+    /// Check whether the actor is skipped by replication, for some or all connections
+    pub fn is_dormant(self) -> bool {
+        matches!(self, NetDormancy::DormantAll | NetDormancy::DormantPartial | NetDormancy::Initial)
+    }
+}
+
+/// Per-actor replication settings, stored as bits of a `u32`
+///
+/// Combine the associated constants with `|`. Serializes as the bare `u32`
+/// in every format; bit positions are part of the wire format and never
+/// change, and bits no flag uses are rejected on input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ReplicationFlags(u32);
+
+/// Each flag with its name, in bit order
+const FLAG_NAMES: [(ReplicationFlags, &str); 6] = [
+    (ReplicationFlags::REPLICATES, "replicates"),
+    (ReplicationFlags::NET_LOAD_ON_CLIENT, "net_load_on_client"),
+    (ReplicationFlags::ONLY_RELEVANT_TO_OWNER, "only_relevant_to_owner"),
+    (ReplicationFlags::USE_OWNER_RELEVANCY, "use_owner_relevancy"),
+    (ReplicationFlags::NET_USE_OWNER_RELEVANCY, "net_use_owner_relevancy"),
+    (ReplicationFlags::REPLICATE_MOVEMENT, "replicate_movement"),
+];
+
+impl ReplicationFlags {
+    /// The actor replicates at all (UE: `bReplicates`)
+    pub const REPLICATES: Self = Self(1 << 0);
+    /// Level-placed copies load on clients (UE: `bNetLoadOnClient`)
+    pub const NET_LOAD_ON_CLIENT: Self = Self(1 << 1);
+    /// Only the owning connection receives updates (UE: `bOnlyRelevantToOwner`)
+    pub const ONLY_RELEVANT_TO_OWNER: Self = Self(1 << 2);
+    /// Relevancy follows the owner's relevancy
+    pub const USE_OWNER_RELEVANCY: Self = Self(1 << 3);
+    /// Relevancy and priority follow the owner's (UE: `bNetUseOwnerRelevancy`)
+    pub const NET_USE_OWNER_RELEVANCY: Self = Self(1 << 4);
+    /// Location, rotation and velocity replicate (UE: `bReplicateMovement`)
+    pub const REPLICATE_MOVEMENT: Self = Self(1 << 5);
+
+    /// No flags set
+    pub const NONE: Self = Self(0);
+    /// Every defined flag set
+    pub const ALL: Self = Self((1 << 6) - 1);
+
+    /// The raw bits
+    pub const fn bits(self) -> u32 {
+        self.0
+    }
+
+    /// Build from raw bits, or `None` if any bit isn't a defined flag
+    pub const fn from_bits(bits: u32) -> Option<Self> {
+        if bits & !Self::ALL.0 == 0 {
+            Some(Self(bits))
+        } else {
+            None
+        }
+    }
+
+    /// Build from raw bits, dropping any that aren't defined flags
+    pub const fn from_bits_truncate(bits: u32) -> Self {
+        Self(bits & Self::ALL.0)
+    }
+
+    /// Check whether no flags are set
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Check whether every flag in `other` is set
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Set the flags in `other`
+    pub fn insert(&mut self, other: Self) {
+        self.0 |= other.0;
+    }
+
+    /// Clear the flags in `other`
+    pub fn remove(&mut self, other: Self) {
+        self.0 &= !other.0;
+    }
+
+    /// Set or clear the flags in `other`
+    pub fn set(&mut self, other: Self, value: bool) {
+        if value {
+            self.insert(other);
+        } else {
+            self.remove(other);
+        }
+    }
+
+    /// Iterate over the set flags, in bit order
+    pub fn iter(self) -> impl Iterator<Item = Self> {
+        FLAG_NAMES.into_iter().map(|(flag, _)| flag).filter(move |&flag| self.contains(flag))
+    }
+}
+
+impl BitOr for ReplicationFlags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for ReplicationFlags {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl BitAnd for ReplicationFlags {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+}
+
+/// Complement within the defined flags
+impl Not for ReplicationFlags {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Self(!self.0 & Self::ALL.0)
+    }
+}
+
+/// Set flag names joined by `|`, or `none`
+impl fmt::Display for ReplicationFlags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "none");
+        }
+        let mut first = true;
+        for (flag, name) in FLAG_NAMES {
+            if self.contains(flag) {
+                if !first {
+                    write!(f, " | ")?;
+                }
+                write!(f, "{}", name)?;
+                first = false;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Serialize for ReplicationFlags {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for ReplicationFlags {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bits = u32::deserialize(deserializer)?;
+        Self::from_bits(bits)
+            .ok_or_else(|| serde::de::Error::custom(format_args!("unknown replication flag bits {:#x}", bits & !Self::ALL.0)))
+    }
+}
+
+impl BinarySerializable for ReplicationFlags {}
+
+impl SerializedSize for ReplicationFlags {
+    fn serialized_size(&self) -> usize {
+        4
+    }
+}
+
+/// Replication state of one actor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ActorNetInfo {
+    /// Network identity of the actor
+    pub guid: NetworkGUID,
+    /// This machine's role for the actor
+    pub role: NetRole,
+    /// Current dormancy state
+    pub dormancy: NetDormancy,
+    /// Replication settings
+    pub flags: ReplicationFlags,
+    /// Owning actor (usually a player controller), if any
+    pub owner: Option<NetworkGUID>,
+}
+
+impl fmt::Display for ActorNetInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ActorNetInfo({}, Role: {}, Dormancy: {}, Flags: {}", self.guid, self.role, self.dormancy, self.flags)?;
+        if let Some(owner) = self.owner {
+            write!(f, ", Owner: {}", owner)?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl BinarySerializable for ActorNetInfo {}
+
+impl SerializedSize for ActorNetInfo {
+    fn serialized_size(&self) -> usize {
+        self.guid.serialized_size() + 4 + 4 + self.flags.serialized_size() + self.owner.serialized_size()
+    }
+}
+
+impl ActorNetInfo {
+    /// Create info for a replicated, awake actor with no owner
+    pub fn new(guid: NetworkGUID, role: NetRole) -> Self {
+        Self {
+            guid,
+            role,
+            dormancy: NetDormancy::Awake,
+            flags: ReplicationFlags::REPLICATES,
+            owner: None,
+        }
+    }
+
+    /// Check whether the actor should be sent to a connection owning `viewer`
+    ///
+    /// False for non-replicated actors and fully dormant ones; actors only
+    /// relevant to their owner are sent only when `viewer` is that owner.
+    /// Partial dormancy is decided per connection, so it counts as relevant.
+    pub fn is_replicated_to(&self, viewer: NetworkGUID) -> bool {
+        if !self.flags.contains(ReplicationFlags::REPLICATES)
+            || matches!(self.dormancy, NetDormancy::DormantAll | NetDormancy::Initial)
+        {
+            return false;
+        }
+        !self.flags.contains(ReplicationFlags::ONLY_RELEVANT_TO_OWNER) || self.owner == Some(viewer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_role_ordering() {
+        let mut roles = [NetRole::Authority, NetRole::None, NetRole::AutonomousProxy, NetRole::SimulatedProxy];
+        roles.sort();
+        assert_eq!(roles, [NetRole::None, NetRole::SimulatedProxy, NetRole::AutonomousProxy, NetRole::Authority]);
+        assert!(NetRole::Authority > NetRole::AutonomousProxy);
+        assert!(NetRole::AutonomousProxy >= NetRole::AutonomousProxy);
+        assert!(NetRole::SimulatedProxy < NetRole::AutonomousProxy);
+
+        assert!(NetRole::Authority.has_authority());
+        assert!(!NetRole::AutonomousProxy.has_authority());
+        assert!(NetRole::SimulatedProxy.is_proxy() && !NetRole::None.is_proxy());
+        assert_eq!(NetRole::default(), NetRole::None);
+        assert_eq!(NetRole::AutonomousProxy.to_string(), "AutonomousProxy");
+        assert!(NetDormancy::Initial.is_dormant() && !NetDormancy::Awake.is_dormant());
+    }
+
+    #[test]
+    fn test_flag_bits_are_stable() {
+        // These values are on the wire; changing one breaks old data
+        let expected = [1, 2, 4, 8, 16, 32];
+        let bits: Vec<u32> = FLAG_NAMES.iter().map(|(flag, _)| flag.bits()).collect();
+        assert_eq!(bits, expected);
+        assert_eq!(ReplicationFlags::ALL.bits(), 0x3f);
+
+        let flags = ReplicationFlags::REPLICATES | ReplicationFlags::REPLICATE_MOVEMENT;
+        assert_eq!(serde_json::to_string(&flags).unwrap(), "33");
+        assert_eq!(flags.to_binary().unwrap(), 33u32.to_le_bytes());
+        assert_eq!(serde_json::from_str::<ReplicationFlags>("33").unwrap(), flags);
+
+        for bits in 0..=ReplicationFlags::ALL.bits() {
+            let flags = ReplicationFlags::from_bits(bits).unwrap();
+            let restored: ReplicationFlags = serde_json::from_str(&serde_json::to_string(&flags).unwrap()).unwrap();
+            assert_eq!(restored, flags);
+            assert_eq!(ReplicationFlags::from_binary(&flags.to_binary().unwrap()).unwrap(), flags);
+        }
+
+        assert_eq!(ReplicationFlags::from_bits(64), None);
+        assert_eq!(ReplicationFlags::from_bits_truncate(64 | 2), ReplicationFlags::NET_LOAD_ON_CLIENT);
+        assert!(serde_json::from_str::<ReplicationFlags>("64").is_err());
+    }
+
+    #[test]
+    fn test_flag_operations_and_display() {
+        let mut flags = ReplicationFlags::NONE;
+        assert!(flags.is_empty());
+        assert_eq!(flags.to_string(), "none");
+
+        flags.insert(ReplicationFlags::REPLICATES | ReplicationFlags::ONLY_RELEVANT_TO_OWNER);
+        flags |= ReplicationFlags::REPLICATE_MOVEMENT;
+        assert!(flags.contains(ReplicationFlags::REPLICATES | ReplicationFlags::REPLICATE_MOVEMENT));
+        assert!(!flags.contains(ReplicationFlags::NET_LOAD_ON_CLIENT));
+        assert_eq!(flags.to_string(), "replicates | only_relevant_to_owner | replicate_movement");
+        assert_eq!(flags.iter().count(), 3);
+
+        flags.set(ReplicationFlags::ONLY_RELEVANT_TO_OWNER, false);
+        assert_eq!(flags, ReplicationFlags::REPLICATES | ReplicationFlags::REPLICATE_MOVEMENT);
+        assert_eq!(!flags & ReplicationFlags::REPLICATES, ReplicationFlags::NONE);
+        assert_eq!((!ReplicationFlags::NONE), ReplicationFlags::ALL);
+    }
+
+    #[test]
+    fn test_actor_net_info() {
+        let owner = NetworkGUID::new(10);
+        let mut actor = ActorNetInfo::new(NetworkGUID::new(500), NetRole::Authority);
+        assert!(actor.is_replicated_to(NetworkGUID::new(11)));
+
+        actor.flags |= ReplicationFlags::ONLY_RELEVANT_TO_OWNER;
+        actor.owner = Some(owner);
+        assert!(actor.is_replicated_to(owner));
+        assert!(!actor.is_replicated_to(NetworkGUID::new(11)));
+
+        actor.dormancy = NetDormancy::DormantAll;
+        assert!(!actor.is_replicated_to(owner));
+
+        let binary = actor.to_binary().unwrap();
+        assert_eq!(binary.len(), actor.serialized_size());
+        assert_eq!(ActorNetInfo::from_binary(&binary).unwrap(), actor);
+        let json = serde_json::to_string(&actor).unwrap();
+        assert!(json.contains("\"flags\":5"));
+        assert_eq!(serde_json::from_str::<ActorNetInfo>(&json).unwrap(), actor);
+        assert_eq!(
+            actor.to_string(),
+            "ActorNetInfo(NetworkGUID(500), Role: Authority, Dormancy: DormantAll, Flags: replicates | only_relevant_to_owner, Owner: NetworkGUID(10))"
+        );
+
+        let unowned = ActorNetInfo::new(NetworkGUID::new(1), NetRole::SimulatedProxy);
+        assert_eq!(unowned.to_binary().unwrap().len(), unowned.serialized_size());
+    }
+}
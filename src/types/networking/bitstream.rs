@@ -0,0 +1,423 @@
+//! Bit-granular packet I/O (equivalent to FBitWriter / FBitReader)
+//!
+//! Bits are written least-significant first within each byte, matching UE.
+//! Reads never panic: running past the end of the buffer returns
+//! [`BitstreamError::Overrun`], and a failed primitive read consumes no bits.
+
+use crate::types::{Rotator, Vector};
+use super::{NetworkGUID, RotatorQuantization, VectorQuantization};
+use core::fmt;
+use alloc::vec::Vec;
+
+/// Error produced while reading a bitstream
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BitstreamError {
+    /// A read asked for more bits than remain in the buffer
+    Overrun { requested: usize, remaining: usize },
+    /// The bits read do not form a valid value
+    InvalidValue(&'static str),
+}
+
+impl fmt::Display for BitstreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BitstreamError::Overrun { requested, remaining } => {
+                write!(f, "read of {} bits with only {} remaining", requested, remaining)
+            }
+            BitstreamError::InvalidValue(what) => write!(f, "invalid {} in bitstream", what),
+        }
+    }
+}
+
+impl core::error::Error for BitstreamError {}
+
+/// Growable bit writer (equivalent to FBitWriter)
+#[derive(Debug, Clone, Default)]
+pub struct BitWriter {
+    bytes: Vec<u8>,
+    num_bits: usize,
+}
+
+impl BitWriter {
+    /// Create an empty writer
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of bits written so far
+    pub fn num_bits(&self) -> usize {
+        self.num_bits
+    }
+
+    /// Number of bytes needed to hold the written bits
+    pub fn num_bytes(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Write a single bit
+    pub fn write_bit(&mut self, value: bool) {
+        if self.num_bits.is_multiple_of(8) {
+            self.bytes.push(0);
+        }
+        if value {
+            *self.bytes.last_mut().unwrap() |= 1 << (self.num_bits % 8);
+        }
+        self.num_bits += 1;
+    }
+
+    /// Write the low `num_bits` bits of `value` (at most 32)
+    pub fn write_bits(&mut self, value: u32, num_bits: u32) {
+        debug_assert!(num_bits <= 32, "write_bits supports at most 32 bits");
+        for bit in 0..num_bits.min(32) {
+            self.write_bit((value >> bit) & 1 != 0);
+        }
+    }
+
+    /// Write an integer using UE's variable-length packing (SerializeIntPacked)
+    ///
+    /// Each byte carries 7 value bits above a "more bytes follow" flag in bit 0,
+    /// so values below 128 take a single byte.
+    pub fn write_packed_int(&mut self, mut value: u32) {
+        loop {
+            let more = value >= 0x80;
+            self.write_bits(((value & 0x7F) << 1) | more as u32, 8);
+            value >>= 7;
+            if !more {
+                break;
+            }
+        }
+    }
+
+    /// Write an `f32` as its 32 raw bits
+    pub fn write_f32(&mut self, value: f32) {
+        self.write_bits(value.to_bits(), 32);
+    }
+
+    /// Pad with zero bits up to the next byte boundary
+    pub fn align_to_byte(&mut self) {
+        self.num_bits = self.bytes.len() * 8;
+    }
+
+    /// Write a value implementing [`SerializeBits`]
+    pub fn write<T: SerializeBits>(&mut self, value: &T) {
+        value.write_bits(self);
+    }
+
+    /// Finish writing; the last byte is padded with zero bits
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+/// Bit reader over a byte buffer (equivalent to FBitReader)
+#[derive(Debug, Clone)]
+pub struct BitReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    num_bits: usize,
+}
+
+impl<'a> BitReader<'a> {
+    /// Read every bit of `bytes`
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self::with_num_bits(bytes, bytes.len() * 8)
+    }
+
+    /// Read only the first `num_bits` bits of `bytes` (clamped to the buffer)
+    pub fn with_num_bits(bytes: &'a [u8], num_bits: usize) -> Self {
+        Self {
+            bytes,
+            position: 0,
+            num_bits: num_bits.min(bytes.len() * 8),
+        }
+    }
+
+    /// Number of bits consumed so far
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Number of bits left to read
+    pub fn remaining_bits(&self) -> usize {
+        self.num_bits - self.position
+    }
+
+    /// Check whether every bit has been read
+    pub fn at_end(&self) -> bool {
+        self.remaining_bits() == 0
+    }
+
+    fn ensure(&self, requested: usize) -> Result<(), BitstreamError> {
+        let remaining = self.remaining_bits();
+        if requested > remaining {
+            return Err(BitstreamError::Overrun { requested, remaining });
+        }
+        Ok(())
+    }
+
+    /// Read a single bit
+    pub fn read_bit(&mut self) -> Result<bool, BitstreamError> {
+        self.ensure(1)?;
+        let bit = (self.bytes[self.position / 8] >> (self.position % 8)) & 1 != 0;
+        self.position += 1;
+        Ok(bit)
+    }
+
+    /// Read `num_bits` bits (at most 32) into the low bits of a `u32`
+    pub fn read_bits(&mut self, num_bits: u32) -> Result<u32, BitstreamError> {
+        if num_bits > 32 {
+            return Err(BitstreamError::InvalidValue("bit count"));
+        }
+        self.ensure(num_bits as usize)?;
+        let mut value = 0;
+        for bit in 0..num_bits {
+            value |= (self.read_bit()? as u32) << bit;
+        }
+        Ok(value)
+    }
+
+    /// Read an integer written by [`BitWriter::write_packed_int`]
+    pub fn read_packed_int(&mut self) -> Result<u32, BitstreamError> {
+        let start = self.position;
+        let mut value: u64 = 0;
+        for shift in (0..35).step_by(7) {
+            let byte = match self.read_bits(8) {
+                Ok(byte) => byte,
+                Err(err) => {
+                    self.position = start;
+                    return Err(err);
+                }
+            };
+            value |= ((byte >> 1) as u64) << shift;
+            if byte & 1 == 0 {
+                return u32::try_from(value).map_err(|_| {
+                    self.position = start;
+                    BitstreamError::InvalidValue("packed int")
+                });
+            }
+        }
+        self.position = start;
+        Err(BitstreamError::InvalidValue("packed int"))
+    }
+
+    /// Read an `f32` from its 32 raw bits
+    pub fn read_f32(&mut self) -> Result<f32, BitstreamError> {
+        Ok(f32::from_bits(self.read_bits(32)?))
+    }
+
+    /// Skip to the next byte boundary
+    pub fn align_to_byte(&mut self) {
+        self.position = (self.position.div_ceil(8) * 8).min(self.num_bits);
+    }
+
+    /// Read a value implementing [`SerializeBits`]
+    pub fn read<T: SerializeBits>(&mut self) -> Result<T, BitstreamError> {
+        T::read_bits(self)
+    }
+}
+
+/// Types with a compact bit-level network representation (UE5: NetSerialize)
+pub trait SerializeBits: Sized {
+    /// Append this value to the writer
+    fn write_bits(&self, writer: &mut BitWriter);
+
+    /// Read a value from the reader
+    fn read_bits(reader: &mut BitReader<'_>) -> Result<Self, BitstreamError>;
+}
+
+impl SerializeBits for bool {
+    fn write_bits(&self, writer: &mut BitWriter) {
+        writer.write_bit(*self);
+    }
+
+    fn read_bits(reader: &mut BitReader<'_>) -> Result<Self, BitstreamError> {
+        reader.read_bit()
+    }
+}
+
+/// Written as a packed int, so low GUID values stay small
+impl SerializeBits for NetworkGUID {
+    fn write_bits(&self, writer: &mut BitWriter) {
+        writer.write_packed_int(self.value);
+    }
+
+    fn read_bits(reader: &mut BitReader<'_>) -> Result<Self, BitstreamError> {
+        Ok(NetworkGUID::new(reader.read_packed_int()?))
+    }
+}
+
+/// Quantized to two decimal places, like FVector_NetQuantize100
+impl SerializeBits for Vector {
+    fn write_bits(&self, writer: &mut BitWriter) {
+        writer.write_quantized_vector(*self, VectorQuantization::Hundred);
+    }
+
+    fn read_bits(reader: &mut BitReader<'_>) -> Result<Self, BitstreamError> {
+        reader.read_quantized_vector(VectorQuantization::Hundred)
+    }
+}
+
+/// Compressed to 16 bits per non-zero axis, like FRotator::NetSerialize
+impl SerializeBits for Rotator {
+    fn write_bits(&self, writer: &mut BitWriter) {
+        writer.write_compressed_rotator(*self, RotatorQuantization::Short);
+    }
+
+    fn read_bits(reader: &mut BitReader<'_>) -> Result<Self, BitstreamError> {
+        reader.read_compressed_rotator(RotatorQuantization::Short)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mixed_width_fields() {
+        let mut writer = BitWriter::new();
+        writer.write_bit(true);
+        writer.write_bits(0b101, 3);
+        writer.write_bits(0x55, 7);
+        writer.write_bits(0xDEAD_BEEF, 32);
+        writer.write_bit(false);
+        writer.write_bits(0b110, 3);
+        assert_eq!(writer.num_bits(), 47);
+        assert_eq!(writer.num_bytes(), 6);
+
+        let bytes = writer.into_bytes();
+        let mut reader = BitReader::new(&bytes);
+        assert!(reader.read_bit().unwrap());
+        assert_eq!(reader.read_bits(3), Ok(0b101));
+        assert_eq!(reader.read_bits(7), Ok(0x55));
+        assert_eq!(reader.read_bits(32), Ok(0xDEAD_BEEF));
+        assert!(!reader.read_bit().unwrap());
+        assert_eq!(reader.read_bits(3), Ok(0b110));
+        assert_eq!(reader.remaining_bits(), 1);
+    }
+
+    #[test]
+    fn test_lsb_first_layout() {
+        let mut writer = BitWriter::new();
+        writer.write_bit(true);
+        writer.write_bits(0b10, 2);
+        writer.write_bits(0x1F0, 9);
+        assert_eq!(writer.into_bytes(), [0b1000_0101, 0b0000_1111]);
+    }
+
+    #[test]
+    fn test_packed_int() {
+        for (value, bytes) in [(0u32, 1usize), (127, 1), (128, 2), (16_383, 2), (16_384, 3), (u32::MAX, 5)] {
+            let mut writer = BitWriter::new();
+            writer.write_bit(true); // Unaligned on purpose
+            writer.write_packed_int(value);
+            assert_eq!(writer.num_bits(), 1 + bytes * 8, "{value}");
+
+            let data = writer.into_bytes();
+            let mut reader = BitReader::new(&data);
+            reader.read_bit().unwrap();
+            assert_eq!(reader.read_packed_int(), Ok(value));
+        }
+
+        // Continuation flag set on every byte
+        let mut reader = BitReader::new(&[0xFF; 6]);
+        assert_eq!(reader.read_packed_int(), Err(BitstreamError::InvalidValue("packed int")));
+        assert_eq!(reader.position(), 0);
+    }
+
+    #[test]
+    fn test_f32_and_alignment() {
+        let mut writer = BitWriter::new();
+        writer.write_bits(0b101, 3);
+        writer.align_to_byte();
+        writer.write_f32(-1.5);
+        writer.align_to_byte();
+        assert_eq!(writer.num_bits(), 40);
+
+        let bytes = writer.into_bytes();
+        let mut reader = BitReader::new(&bytes);
+        assert_eq!(reader.read_bits(3), Ok(0b101));
+        reader.align_to_byte();
+        assert_eq!(reader.position(), 8);
+        assert_eq!(reader.read_f32(), Ok(-1.5));
+        assert!(reader.at_end());
+    }
+
+    #[test]
+    fn test_serialize_bits_types() {
+        let guid = NetworkGUID::new(300);
+        let location = Vector::new(12.345, -600.0, 0.01);
+        let rotation = Rotator::new(10.0, -90.0, 0.0);
+
+        let mut writer = BitWriter::new();
+        writer.write(&true);
+        writer.write(&guid);
+        writer.write(&location);
+        writer.write(&rotation);
+
+        let bytes = writer.into_bytes();
+        let mut reader = BitReader::new(&bytes);
+        assert!(reader.read::<bool>().unwrap());
+        assert_eq!(reader.read::<NetworkGUID>(), Ok(guid));
+        let read_location: Vector = reader.read().unwrap();
+        assert!((read_location - location).abs().max_element() <= 0.005 + 1e-9);
+        let read_rotation: Rotator = reader.read().unwrap();
+        assert!(read_rotation.is_nearly_equal(rotation, RotatorQuantization::Short.step()));
+    }
+
+    #[test]
+    fn test_overrun_is_an_error() {
+        let mut reader = BitReader::new(&[0xAB]);
+        assert_eq!(reader.read_bits(4), Ok(0xB));
+        assert_eq!(reader.read_bits(5), Err(BitstreamError::Overrun { requested: 5, remaining: 4 }));
+        // A failed read consumes nothing
+        assert_eq!(reader.read_bits(4), Ok(0xA));
+        assert_eq!(reader.read_bit(), Err(BitstreamError::Overrun { requested: 1, remaining: 0 }));
+        assert_eq!(reader.read_bits(33), Err(BitstreamError::InvalidValue("bit count")));
+
+        let mut limited = BitReader::with_num_bits(&[0xFF, 0xFF], 10);
+        assert_eq!(limited.remaining_bits(), 10);
+        assert!(limited.read_bits(11).is_err());
+    }
+
+    #[test]
+    fn test_random_truncation_never_panics() {
+        let mut writer = BitWriter::new();
+        for i in 0..20u32 {
+            writer.write(&(i % 3 == 0));
+            writer.write_bits(i * 7, i % 32 + 1);
+            writer.write(&NetworkGUID::new(i * 1_000));
+            writer.write(&Vector::new(i as f64 * 3.3, -(i as f64), 1e5));
+            writer.write(&Rotator::new(i as f64, 0.0, -45.0));
+            writer.write_f32(i as f32 * 0.5);
+        }
+        let bytes = writer.into_bytes();
+
+        // xorshift keeps the test deterministic without a rand dependency
+        let mut state = 0x2545_F491_4F6C_DD1Du64;
+        for _ in 0..500 {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            let cut = (state % bytes.len() as u64) as usize;
+
+            let mut reader = BitReader::new(&bytes[..cut]);
+            let mut hit_end = false;
+            for i in 0..20u32 {
+                let result = (|| -> Result<(), BitstreamError> {
+                    reader.read::<bool>()?;
+                    reader.read_bits(i % 32 + 1)?;
+                    reader.read::<NetworkGUID>()?;
+                    reader.read::<Vector>()?;
+                    reader.read::<Rotator>()?;
+                    reader.read_f32()?;
+                    Ok(())
+                })();
+                if result.is_err() {
+                    hit_end = true;
+                    break;
+                }
+            }
+            assert!(hit_end, "truncated at {cut} bytes but every field was read");
+        }
+    }
+}
@@ -1,10 +1,18 @@
 //! Game session information for matchmaking and server browser
 
 use crate::BinarySerializable;
-use super::NetworkGUID;
+use crate::types::SerializedSize;
+use crate::types::Timespan;
+use super::{NetworkGUID, Region, SessionPropertyValue, SessionUpdate, ValidationError};
 use serde::{Deserialize, Serialize};
-use std::fmt;
-use std::time::{SystemTime, UNIX_EPOCH};
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "std")]
+use crate::types::time::clock::{since_epoch, Clock, SystemClock};
+#[cfg(feature = "std")]
+use crate::types::UeTypesError;
 
 /// Game session information for matchmaking and server browser
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -31,8 +39,14 @@ pub struct GameSessionInfo {
     pub difficulty: i32,
     /// Session creation time
     pub created_time: u64,
-    /// Additional custom properties
-    pub custom_properties: std::collections::HashMap<String, String>,
+    /// Time of the last heartbeat from the host (Unix seconds)
+    ///
+    /// Sessions saved before heartbeats existed load with 0, which staleness
+    /// checks treat as a heartbeat at `created_time`.
+    #[serde(default)]
+    pub last_heartbeat: u64,
+    /// Additional custom properties, sorted by key
+    pub custom_properties: BTreeMap<String, SessionPropertyValue>,
 }
 
 impl fmt::Display for GameSessionInfo {
@@ -52,11 +66,83 @@ impl fmt::Display for GameSessionInfo {
 
 impl BinarySerializable for GameSessionInfo {}
 
+impl SerializedSize for GameSessionInfo {
+    fn serialized_size(&self) -> usize {
+        self.session_id.serialized_size()
+            + self.session_name.serialized_size()
+            + self.game_mode.serialized_size()
+            + self.map_name.serialized_size()
+            + self.max_players.serialized_size()
+            + self.current_players.serialized_size()
+            + self.is_private.serialized_size()
+            + self.allow_spectators.serialized_size()
+            + self.region.serialized_size()
+            + self.difficulty.serialized_size()
+            + self.created_time.serialized_size()
+            + self.last_heartbeat.serialized_size()
+            + self.custom_properties.serialized_size()
+    }
+}
+
 impl GameSessionInfo {
-    /// Create a new game session
+    /// Create a new game session, created now
+    ///
+    /// A clock set before the Unix epoch gives a creation time of 0; use
+    /// `try_new` to detect that instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `NetworkGUID::generate` has run out of GUIDs.
+    #[cfg(feature = "std")]
     pub fn new(session_name: String, game_mode: String, map_name: String, max_players: i32) -> Self {
+        Self::new_at(current_unix_seconds(), session_name, game_mode, map_name, max_players)
+    }
+
+    /// Create a new game session created now, failing on a pre-epoch clock or exhausted GUIDs
+    #[cfg(feature = "std")]
+    pub fn try_new(session_name: String, game_mode: String, map_name: String, max_players: i32) -> Result<Self, UeTypesError> {
+        Self::try_new_with_clock(&SystemClock, session_name, game_mode, map_name, max_players)
+    }
+
+    /// Create a new game session created at `clock`'s current time
+    #[cfg(feature = "std")]
+    pub fn try_new_with_clock<C: Clock + ?Sized>(
+        clock: &C,
+        session_name: String,
+        game_mode: String,
+        map_name: String,
+        max_players: i32,
+    ) -> Result<Self, UeTypesError> {
+        let now = since_epoch(clock)?.as_secs();
+        let session_id = NetworkGUID::try_generate()?;
+        Ok(Self::with_id(session_id, now, session_name, game_mode, map_name, max_players))
+    }
+
+    /// Start building a session with [`GameSessionInfoBuilder`]
+    pub fn builder() -> GameSessionInfoBuilder {
+        GameSessionInfoBuilder::new()
+    }
+
+    /// Create a new game session created at `now` (Unix seconds)
+    ///
+    /// # Panics
+    ///
+    /// Panics if `NetworkGUID::generate` has run out of GUIDs.
+    pub fn new_at(now: u64, session_name: String, game_mode: String, map_name: String, max_players: i32) -> Self {
+        Self::with_id(NetworkGUID::generate(), now, session_name, game_mode, map_name, max_players)
+    }
+
+    /// Shared by the constructors once the id and creation time are known
+    fn with_id(
+        session_id: NetworkGUID,
+        now: u64,
+        session_name: String,
+        game_mode: String,
+        map_name: String,
+        max_players: i32,
+    ) -> Self {
         Self {
-            session_id: NetworkGUID::generate(),
+            session_id,
             session_name,
             game_mode,
             map_name,
@@ -66,11 +152,9 @@ impl GameSessionInfo {
             allow_spectators: true,
             region: "Unknown".to_string(),
             difficulty: 1,
-            created_time: SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .unwrap()
-                .as_secs(),
-            custom_properties: std::collections::HashMap::new(),
+            created_time: now,
+            last_heartbeat: now,
+            custom_properties: BTreeMap::new(),
         }
     }
 
@@ -84,6 +168,13 @@ impl GameSessionInfo {
         self.current_players >= self.max_players
     }
 
+    /// Number of open slots, negative if over capacity
+    ///
+    /// Computed in `i64` so it cannot overflow whatever the two counts hold.
+    pub fn free_slots(&self) -> i64 {
+        i64::from(self.max_players) - i64::from(self.current_players)
+    }
+
     /// Add a player to the session
     pub fn add_player(&mut self) -> bool {
         if self.has_available_slots() {
@@ -114,29 +205,573 @@ impl GameSessionInfo {
         self.region = region;
     }
 
-    /// Add custom property
-    pub fn set_custom_property(&mut self, key: String, value: String) {
-        self.custom_properties.insert(key, value);
+    /// The region string parsed as a `Region`, accepting aliases like "us-east-1"
+    pub fn region_enum(&self) -> Region {
+        Region::parse(&self.region)
+    }
+
+    /// Set the region from a `Region`, storing its canonical name
+    pub fn set_region_enum(&mut self, region: Region) {
+        self.region = region.into();
+    }
+
+    /// Add or replace a custom property
+    pub fn set_custom_property(&mut self, key: impl Into<String>, value: impl Into<SessionPropertyValue>) {
+        self.custom_properties.insert(key.into(), value.into());
     }
 
     /// Get custom property
-    pub fn get_custom_property(&self, key: &str) -> Option<&String> {
+    pub fn get_custom_property(&self, key: &str) -> Option<&SessionPropertyValue> {
         self.custom_properties.get(key)
     }
 
+    /// Get a custom property as an integer, parsing string values
+    pub fn get_property_int(&self, key: &str) -> Option<i64> {
+        self.get_custom_property(key)?.as_int()
+    }
+
+    /// Get a custom property as a float, parsing string values
+    pub fn get_property_float(&self, key: &str) -> Option<f64> {
+        self.get_custom_property(key)?.as_float()
+    }
+
+    /// Get a custom property as a flag, parsing string values
+    pub fn get_property_bool(&self, key: &str) -> Option<bool> {
+        self.get_custom_property(key)?.as_bool()
+    }
+
+    /// Iterate over custom properties whose key starts with `prefix`, in key order
+    pub fn properties_matching<'a>(
+        &'a self,
+        prefix: &'a str,
+    ) -> impl Iterator<Item = (&'a String, &'a SessionPropertyValue)> + 'a {
+        self.custom_properties
+            .range::<str, _>((core::ops::Bound::Included(prefix), core::ops::Bound::Unbounded))
+            .take_while(move |(key, _)| key.starts_with(prefix))
+    }
+
+    /// Begin a batch of staged changes that are validated and applied together
+    pub fn begin_update(&self) -> SessionUpdate {
+        SessionUpdate::new(self)
+    }
+
     /// Get session age in seconds
+    #[cfg(feature = "std")]
     pub fn age_seconds(&self) -> u64 {
-        SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs()
-            .saturating_sub(self.created_time)
+        self.age_seconds_with_clock(&SystemClock)
+    }
+
+    /// Get session age in seconds according to `clock`
+    #[cfg(feature = "std")]
+    pub fn age_seconds_with_clock<C: Clock + ?Sized>(&self, clock: &C) -> u64 {
+        self.age_seconds_at(clock.now_unix_secs())
+    }
+
+    /// Get session age in seconds at `now` (Unix seconds)
+    pub fn age_seconds_at(&self, now: u64) -> u64 {
+        now.saturating_sub(self.created_time)
+    }
+
+    /// Record a heartbeat from the host now
+    #[cfg(feature = "std")]
+    pub fn heartbeat(&mut self) {
+        self.heartbeat_with_clock(&SystemClock);
+    }
+
+    /// Record a heartbeat from the host at `clock`'s current time
+    #[cfg(feature = "std")]
+    pub fn heartbeat_with_clock<C: Clock + ?Sized>(&mut self, clock: &C) {
+        self.heartbeat_at(clock.now_unix_secs());
+    }
+
+    /// Record a heartbeat from the host at `now` (Unix seconds)
+    pub fn heartbeat_at(&mut self, now: u64) {
+        self.last_heartbeat = self.last_heartbeat.max(now);
+    }
+
+    /// Check if the host has been silent for longer than `timeout`
+    #[cfg(feature = "std")]
+    pub fn is_stale(&self, timeout: Timespan) -> bool {
+        self.is_stale_with_clock(timeout, &SystemClock)
+    }
+
+    /// Check if the host has been silent for longer than `timeout` according to `clock`
+    #[cfg(feature = "std")]
+    pub fn is_stale_with_clock<C: Clock + ?Sized>(&self, timeout: Timespan, clock: &C) -> bool {
+        self.is_stale_at(timeout, clock.now_unix_secs())
+    }
+
+    /// Check if the host has been silent for longer than `timeout`, measured against `now` (Unix seconds)
+    pub fn is_stale_at(&self, timeout: Timespan, now: u64) -> bool {
+        let last_heard = self.last_heartbeat.max(self.created_time);
+        now.saturating_sub(last_heard) as f64 > timeout.total_seconds()
+    }
+}
+
+/// Fluent, deserializable builder for [`GameSessionInfo`]
+///
+/// Unset fields take the same defaults as `GameSessionInfo::new`, with 16
+/// max players. Every field is optional in JSON, so a server can load a
+/// session template and fill in the rest at runtime:
+///
+/// ```
+/// use ue_types::GameSessionInfoBuilder;
+///
+/// let template: GameSessionInfoBuilder =
+///     serde_json::from_str(r#"{"game_mode": "CTF", "max_players": 12, "region": "EU"}"#).unwrap();
+/// let session = template.session_name("Friday CTF").map_name("ctf_fort").build_at(1_000).unwrap();
+/// assert_eq!(session.max_players, 12);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameSessionInfoBuilder {
+    /// Session id; generated at build time when unset
+    pub session_id: Option<NetworkGUID>,
+    /// Session name/title; must not be empty
+    pub session_name: String,
+    /// Game mode being played
+    pub game_mode: String,
+    /// Current map/level
+    pub map_name: String,
+    /// Maximum number of players allowed; must be at least 1
+    pub max_players: i32,
+    /// Current number of connected players; must be within 0..=max_players
+    pub current_players: i32,
+    /// Whether the session is password protected
+    pub is_private: bool,
+    /// Whether the session allows spectators
+    pub allow_spectators: bool,
+    /// Server region/location
+    pub region: String,
+    /// Game difficulty level
+    pub difficulty: i32,
+    /// Creation time (Unix seconds); the build time when unset
+    pub created_time: Option<u64>,
+    /// Time of the last heartbeat (Unix seconds); the creation time when unset
+    pub last_heartbeat: Option<u64>,
+    /// Additional custom properties
+    pub custom_properties: BTreeMap<String, SessionPropertyValue>,
+}
+
+impl GameSessionInfoBuilder {
+    /// Create a builder with the default settings
+    pub fn new() -> Self {
+        Self {
+            session_id: None,
+            session_name: String::new(),
+            game_mode: String::new(),
+            map_name: String::new(),
+            max_players: 16,
+            current_players: 0,
+            is_private: false,
+            allow_spectators: true,
+            region: "Unknown".to_string(),
+            difficulty: 1,
+            created_time: None,
+            last_heartbeat: None,
+            custom_properties: BTreeMap::new(),
+        }
+    }
+
+    /// Use a specific session id instead of generating one
+    pub fn session_id(mut self, session_id: NetworkGUID) -> Self {
+        self.session_id = Some(session_id);
+        self
+    }
+
+    /// Set the session name
+    pub fn session_name(mut self, session_name: impl Into<String>) -> Self {
+        self.session_name = session_name.into();
+        self
+    }
+
+    /// Set the game mode
+    pub fn game_mode(mut self, game_mode: impl Into<String>) -> Self {
+        self.game_mode = game_mode.into();
+        self
+    }
+
+    /// Set the map
+    pub fn map_name(mut self, map_name: impl Into<String>) -> Self {
+        self.map_name = map_name.into();
+        self
+    }
+
+    /// Set the player limit
+    pub fn max_players(mut self, max_players: i32) -> Self {
+        self.max_players = max_players;
+        self
+    }
+
+    /// Set the number of players already connected
+    pub fn current_players(mut self, current_players: i32) -> Self {
+        self.current_players = current_players;
+        self
+    }
+
+    /// Set whether the session is password protected
+    pub fn private(mut self, is_private: bool) -> Self {
+        self.is_private = is_private;
+        self
+    }
+
+    /// Set whether spectators may join
+    pub fn allow_spectators(mut self, allow_spectators: bool) -> Self {
+        self.allow_spectators = allow_spectators;
+        self
+    }
+
+    /// Set the region
+    pub fn region(mut self, region: impl Into<String>) -> Self {
+        self.region = region.into();
+        self
+    }
+
+    /// Set the difficulty level
+    pub fn difficulty(mut self, difficulty: i32) -> Self {
+        self.difficulty = difficulty;
+        self
+    }
+
+    /// Set the creation time (Unix seconds) instead of using the build time
+    pub fn created_time(mut self, created_time: u64) -> Self {
+        self.created_time = Some(created_time);
+        self
+    }
+
+    /// Set the last heartbeat time (Unix seconds)
+    pub fn last_heartbeat(mut self, last_heartbeat: u64) -> Self {
+        self.last_heartbeat = Some(last_heartbeat);
+        self
+    }
+
+    /// Add or replace a custom property
+    pub fn custom_property(mut self, key: impl Into<String>, value: impl Into<SessionPropertyValue>) -> Self {
+        self.custom_properties.insert(key.into(), value.into());
+        self
+    }
+
+    /// Build the session, created now unless a creation time was set
+    #[cfg(feature = "std")]
+    pub fn build(self) -> Result<GameSessionInfo, ValidationError> {
+        self.build_at(current_unix_seconds())
+    }
+
+    /// Build the session, created at `now` (Unix seconds) unless a creation time was set
+    pub fn build_at(self, now: u64) -> Result<GameSessionInfo, ValidationError> {
+        if self.session_name.trim().is_empty() {
+            return Err(ValidationError::new("session_name", "must not be empty"));
+        }
+        if self.max_players < 1 {
+            return Err(ValidationError::new("max_players", "must be at least 1"));
+        }
+        if !(0..=self.max_players).contains(&self.current_players) {
+            return Err(ValidationError::new("current_players", "must be between 0 and max_players"));
+        }
+
+        let created_time = self.created_time.unwrap_or(now);
+        let session_id = self.session_id.unwrap_or_else(NetworkGUID::generate);
+        let mut session =
+            GameSessionInfo::with_id(session_id, created_time, self.session_name, self.game_mode, self.map_name, self.max_players);
+        session.current_players = self.current_players;
+        session.is_private = self.is_private;
+        session.allow_spectators = self.allow_spectators;
+        session.region = self.region;
+        session.difficulty = self.difficulty;
+        session.last_heartbeat = self.last_heartbeat.unwrap_or(created_time);
+        session.custom_properties = self.custom_properties;
+        Ok(session)
+    }
+}
+
+impl Default for GameSessionInfoBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Ordering applied by [`SessionFilter::filter_sessions`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SessionSort {
+    /// Fewest players first
+    PlayersAscending,
+    /// Most players first
+    PlayersDescending,
+    /// Most recently created first
+    NewestFirst,
+    /// Oldest first
+    OldestFirst,
+    /// Session name A-Z (case-insensitive)
+    NameAscending,
+    /// Session name Z-A (case-insensitive)
+    NameDescending,
+}
+
+impl SessionSort {
+    /// Compare two sessions, falling back to the session id so ordering is deterministic
+    pub fn compare(self, a: &GameSessionInfo, b: &GameSessionInfo) -> core::cmp::Ordering {
+        let ordering = match self {
+            SessionSort::PlayersAscending => a.current_players.cmp(&b.current_players),
+            SessionSort::PlayersDescending => b.current_players.cmp(&a.current_players),
+            SessionSort::NewestFirst => b.created_time.cmp(&a.created_time),
+            SessionSort::OldestFirst => a.created_time.cmp(&b.created_time),
+            SessionSort::NameAscending => a.session_name.to_lowercase().cmp(&b.session_name.to_lowercase()),
+            SessionSort::NameDescending => b.session_name.to_lowercase().cmp(&a.session_name.to_lowercase()),
+        };
+        ordering.then(a.session_id.value.cmp(&b.session_id.value))
+    }
+}
+
+/// A required custom property value
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PropertyMatch {
+    /// Property key (always matched case-sensitively)
+    pub key: String,
+    /// Required value
+    pub value: String,
+}
+
+/// Server browser query over GameSessionInfo entries
+///
+/// Unset constraints match everything. Game mode, map and region compare
+/// case-insensitively. Custom property keys are case-sensitive; values are
+/// too unless `case_sensitive_values` is turned off. Missing fields default
+/// when deserializing, so clients only need to send what they filter on.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SessionFilter {
+    /// Required game mode
+    pub game_mode: Option<String>,
+    /// Required map
+    pub map_name: Option<String>,
+    /// Required region
+    pub region: Option<String>,
+    /// Minimum number of free player slots
+    pub min_free_slots: Option<i32>,
+    /// Required privacy setting
+    pub is_private: Option<bool>,
+    /// Required spectator setting
+    pub allow_spectators: Option<bool>,
+    /// Highest accepted difficulty
+    pub max_difficulty: Option<i32>,
+    /// Oldest accepted session age in seconds
+    pub max_age_seconds: Option<u64>,
+    /// Custom properties that must all be present with the given values
+    pub custom_properties: Vec<PropertyMatch>,
+    /// Whether custom property values must match case
+    pub case_sensitive_values: bool,
+    /// Ordering of the results
+    pub sort: Option<SessionSort>,
+    /// Number of matching sessions to skip (for pagination)
+    pub skip: usize,
+    /// Maximum number of sessions to return
+    pub take: Option<usize>,
+}
+
+impl fmt::Display for SessionFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let constraints = [
+            self.game_mode.is_some(),
+            self.map_name.is_some(),
+            self.region.is_some(),
+            self.min_free_slots.is_some(),
+            self.is_private.is_some(),
+            self.allow_spectators.is_some(),
+            self.max_difficulty.is_some(),
+            self.max_age_seconds.is_some(),
+        ]
+        .iter()
+        .filter(|set| **set)
+        .count()
+            + self.custom_properties.len();
+        write!(f, "SessionFilter({} constraints, Skip: {}, Take: ", constraints, self.skip)?;
+        match self.take {
+            Some(take) => write!(f, "{})", take),
+            None => write!(f, "All)"),
+        }
+    }
+}
+
+impl SessionFilter {
+    /// Create a filter that matches every session
+    pub fn new() -> Self {
+        Self {
+            game_mode: None,
+            map_name: None,
+            region: None,
+            min_free_slots: None,
+            is_private: None,
+            allow_spectators: None,
+            max_difficulty: None,
+            max_age_seconds: None,
+            custom_properties: Vec::new(),
+            case_sensitive_values: true,
+            sort: None,
+            skip: 0,
+            take: None,
+        }
+    }
+
+    /// Require a game mode
+    pub fn game_mode(mut self, game_mode: impl Into<String>) -> Self {
+        self.game_mode = Some(game_mode.into());
+        self
+    }
+
+    /// Require a map
+    pub fn map_name(mut self, map_name: impl Into<String>) -> Self {
+        self.map_name = Some(map_name.into());
+        self
+    }
+
+    /// Require a region
+    pub fn region(mut self, region: impl Into<String>) -> Self {
+        self.region = Some(region.into());
+        self
+    }
+
+    /// Require at least `slots` free player slots
+    pub fn min_free_slots(mut self, slots: i32) -> Self {
+        self.min_free_slots = Some(slots);
+        self
+    }
+
+    /// Require private or public sessions
+    pub fn private(mut self, is_private: bool) -> Self {
+        self.is_private = Some(is_private);
+        self
+    }
+
+    /// Require sessions that do or don't allow spectators
+    pub fn spectators_allowed(mut self, allowed: bool) -> Self {
+        self.allow_spectators = Some(allowed);
+        self
+    }
+
+    /// Require a difficulty of at most `difficulty`
+    pub fn max_difficulty(mut self, difficulty: i32) -> Self {
+        self.max_difficulty = Some(difficulty);
+        self
+    }
+
+    /// Require sessions created at most `seconds` ago
+    pub fn max_age_seconds(mut self, seconds: u64) -> Self {
+        self.max_age_seconds = Some(seconds);
+        self
+    }
+
+    /// Require a custom property with the given value
+    pub fn custom_property(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.custom_properties.push(PropertyMatch {
+            key: key.into(),
+            value: value.into(),
+        });
+        self
+    }
+
+    /// Choose whether custom property values must match case (default true)
+    pub fn case_sensitive_values(mut self, case_sensitive: bool) -> Self {
+        self.case_sensitive_values = case_sensitive;
+        self
+    }
+
+    /// Sort the results
+    pub fn sort_by(mut self, sort: SessionSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Skip the first `count` results
+    pub fn skip(mut self, count: usize) -> Self {
+        self.skip = count;
+        self
+    }
+
+    /// Return at most `count` results
+    pub fn take(mut self, count: usize) -> Self {
+        self.take = Some(count);
+        self
     }
+
+    /// Check if a session satisfies every constraint
+    #[cfg(feature = "std")]
+    pub fn matches(&self, session: &GameSessionInfo) -> bool {
+        self.matches_at(session, current_unix_seconds())
+    }
+
+    /// Check if a session satisfies every constraint, measuring age against `now` (Unix seconds)
+    pub fn matches_at(&self, session: &GameSessionInfo, now: u64) -> bool {
+        let text_matches = |wanted: &Option<String>, actual: &str| {
+            wanted.as_ref().is_none_or(|wanted| wanted.eq_ignore_ascii_case(actual))
+        };
+
+        text_matches(&self.game_mode, &session.game_mode)
+            && text_matches(&self.map_name, &session.map_name)
+            && text_matches(&self.region, &session.region)
+            && self
+                .min_free_slots
+                .is_none_or(|slots| session.free_slots() >= i64::from(slots))
+            && self.is_private.is_none_or(|private| session.is_private == private)
+            && self.allow_spectators.is_none_or(|allowed| session.allow_spectators == allowed)
+            && self.max_difficulty.is_none_or(|max| session.difficulty <= max)
+            && self
+                .max_age_seconds
+                .is_none_or(|max| now.saturating_sub(session.created_time) <= max)
+            && self.custom_properties.iter().all(|required| {
+                session.custom_properties.get(&required.key).is_some_and(|value| {
+                    let value = value.to_string();
+                    if self.case_sensitive_values {
+                        value == required.value
+                    } else {
+                        value.to_lowercase() == required.value.to_lowercase()
+                    }
+                })
+            })
+    }
+
+    /// Select, sort and paginate matching sessions
+    #[cfg(feature = "std")]
+    pub fn filter_sessions<'a, I>(&self, sessions: I) -> Vec<&'a GameSessionInfo>
+    where
+        I: IntoIterator<Item = &'a GameSessionInfo>,
+    {
+        self.filter_sessions_at(sessions, current_unix_seconds())
+    }
+
+    /// Select, sort and paginate matching sessions, measuring age against `now` (Unix seconds)
+    pub fn filter_sessions_at<'a, I>(&self, sessions: I, now: u64) -> Vec<&'a GameSessionInfo>
+    where
+        I: IntoIterator<Item = &'a GameSessionInfo>,
+    {
+        let mut matching: Vec<&GameSessionInfo> = sessions.into_iter().filter(|s| self.matches_at(s, now)).collect();
+        if let Some(sort) = self.sort {
+            matching.sort_by(|a, b| sort.compare(a, b));
+        }
+        matching
+            .into_iter()
+            .skip(self.skip)
+            .take(self.take.unwrap_or(usize::MAX))
+            .collect()
+    }
+}
+
+impl Default for SessionFilter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Current Unix time in seconds, or 0 if the clock reads before the epoch
+#[cfg(feature = "std")]
+pub(super) fn current_unix_seconds() -> u64 {
+    SystemClock.now_unix_secs()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::ManualClock;
+    use std::time::Duration;
 
     #[test]
     fn test_game_session_info() {
@@ -161,4 +796,350 @@ mod tests {
         assert!(session.remove_player());
         assert!(session.has_available_slots());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_try_new_with_clock() {
+        let clock = ManualClock::from_unix_secs(1_700_000_000);
+        let session = GameSessionInfo::try_new_with_clock(
+            &clock,
+            "Arena".to_string(),
+            "Deathmatch".to_string(),
+            "Map".to_string(),
+            8,
+        )
+        .unwrap();
+        assert_eq!(session.created_time, 1_700_000_000);
+        assert_eq!(session.last_heartbeat, 1_700_000_000);
+        assert!(session.session_id.is_valid());
+
+        let broken_rtc = ManualClock::from_unix_secs(-3_600);
+        let error = GameSessionInfo::try_new_with_clock(
+            &broken_rtc,
+            "Arena".to_string(),
+            "Deathmatch".to_string(),
+            "Map".to_string(),
+            8,
+        )
+        .unwrap_err();
+        assert_eq!(error, UeTypesError::ClockBeforeEpoch(Duration::from_secs(3_600)));
+        assert_eq!(error.to_string(), "system clock is 3600s before the Unix epoch");
+
+        let session = GameSessionInfo::try_new("Live".to_string(), "CTF".to_string(), "Map".to_string(), 4).unwrap();
+        assert!(session.created_time > 0);
+    }
+
+    #[test]
+    fn test_heartbeat_staleness() {
+        let mut session = browser_sessions().remove(0);
+        session.last_heartbeat = 1_000;
+        let timeout = Timespan::from_seconds(30.0);
+        assert!(!session.is_stale_at(timeout, 1_030));
+        assert!(session.is_stale_at(timeout, 1_031));
+
+        session.heartbeat_at(1_025);
+        assert!(!session.is_stale_at(timeout, 1_055));
+        session.heartbeat_at(900);
+        assert_eq!(session.last_heartbeat, 1_025);
+
+        session.heartbeat();
+        assert!(!session.is_stale(timeout));
+
+        let clock = ManualClock::from_unix_secs(2_000);
+        let mut created = GameSessionInfo::try_new_with_clock(
+            &clock,
+            "Lobby".to_string(),
+            "TDM".to_string(),
+            "arena".to_string(),
+            8,
+        )
+        .unwrap();
+        assert_eq!((created.created_time, created.last_heartbeat), (2_000, 2_000));
+        clock.advance_secs(30);
+        assert_eq!(created.age_seconds_with_clock(&clock), 30);
+        assert!(!created.is_stale_with_clock(timeout, &clock));
+        clock.advance_secs(1);
+        assert!(created.is_stale_with_clock(timeout, &clock));
+        created.heartbeat_with_clock(&clock);
+        assert_eq!(created.last_heartbeat, 2_031);
+        assert!(!created.is_stale_with_clock(timeout, &clock));
+        clock.advance_secs(14);
+        assert_eq!(created.age_seconds_with_clock(&clock), 45);
+        assert_eq!(created.age_seconds_at(2_045), 45);
+    }
+
+    #[test]
+    fn test_builder_defaults() {
+        let built = GameSessionInfo::builder()
+            .session_id(NetworkGUID::new(77))
+            .session_name("Lobby")
+            .game_mode("TDM")
+            .map_name("arena")
+            .max_players(8)
+            .build_at(2_000)
+            .unwrap();
+        let mut expected = GameSessionInfo::new_at(2_000, "Lobby".to_string(), "TDM".to_string(), "arena".to_string(), 8);
+        expected.session_id = NetworkGUID::new(77);
+        assert_eq!(built, expected);
+
+        let full = GameSessionInfo::builder()
+            .session_name("Ranked")
+            .current_players(3)
+            .private(true)
+            .allow_spectators(false)
+            .region("EU")
+            .difficulty(4)
+            .created_time(100)
+            .last_heartbeat(150)
+            .custom_property("ranked", true)
+            .custom_property("score_limit", 50)
+            .build()
+            .unwrap();
+        assert!(full.session_id.is_valid());
+        assert_eq!((full.max_players, full.current_players, full.difficulty), (16, 3, 4));
+        assert!(full.is_private && !full.allow_spectators);
+        assert_eq!((full.created_time, full.last_heartbeat), (100, 150));
+        assert_eq!(full.region, "EU");
+        assert_eq!(full.get_property_int("score_limit"), Some(50));
+        assert_eq!(full.get_custom_property("ranked"), Some(&SessionPropertyValue::from(true)));
+    }
+
+    #[test]
+    fn test_builder_validation() {
+        let error = GameSessionInfo::builder().build_at(0).unwrap_err();
+        assert_eq!(error, ValidationError::new("session_name", "must not be empty"));
+        assert_eq!(error.to_string(), "session_name: must not be empty");
+
+        let named = GameSessionInfo::builder().session_name("Game");
+        assert_eq!(named.clone().max_players(0).build_at(0).unwrap_err().field, "max_players");
+        assert_eq!(named.clone().max_players(4).current_players(5).build_at(0).unwrap_err().field, "current_players");
+        assert_eq!(named.clone().current_players(-1).build_at(0).unwrap_err().field, "current_players");
+        assert!(named.max_players(1).current_players(1).build_at(0).is_ok());
+    }
+
+    #[test]
+    fn test_builder_template_json() {
+        let template: GameSessionInfoBuilder = serde_json::from_str(
+            r#"{
+                "game_mode": "CTF",
+                "map_name": "ctf_fort",
+                "max_players": 12,
+                "region": "NA",
+                "custom_properties": {"rules.score_limit": 3, "rules.friendly_fire": false}
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(template.session_name, "");
+        assert!(template.allow_spectators);
+        assert_eq!(template.difficulty, 1);
+
+        let session = template.clone().session_name("Night CTF").build_at(5_000).unwrap();
+        assert_eq!((session.game_mode.as_str(), session.map_name.as_str()), ("CTF", "ctf_fort"));
+        assert_eq!((session.max_players, session.created_time, session.last_heartbeat), (12, 5_000, 5_000));
+        assert_eq!(session.get_property_int("rules.score_limit"), Some(3));
+
+        // The template alone is incomplete
+        assert_eq!(template.clone().build_at(5_000).unwrap_err().field, "session_name");
+
+        let json = serde_json::to_string(&template).unwrap();
+        assert_eq!(serde_json::from_str::<GameSessionInfoBuilder>(&json).unwrap(), template);
+        assert_eq!(serde_json::from_str::<GameSessionInfoBuilder>("{}").unwrap(), GameSessionInfoBuilder::default());
+    }
+
+    #[test]
+    fn test_json_without_heartbeat() {
+        let mut session = browser_sessions().remove(0);
+        session.last_heartbeat = 5_000;
+        let json = serde_json::to_string(&session).unwrap();
+        assert_eq!(serde_json::from_str::<GameSessionInfo>(&json).unwrap(), session);
+        assert_eq!(GameSessionInfo::from_binary(&session.to_binary().unwrap()).unwrap(), session);
+
+        // JSON saved before heartbeats existed
+        let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        value.as_object_mut().unwrap().remove("last_heartbeat");
+        let old: GameSessionInfo = serde_json::from_value(value).unwrap();
+        assert_eq!(old.last_heartbeat, 0);
+        let timeout = Timespan::from_seconds(30.0);
+        assert!(!old.is_stale_at(timeout, old.created_time + 30));
+        assert!(old.is_stale_at(timeout, old.created_time + 31));
+    }
+
+    #[test]
+    fn test_region_enum() {
+        let mut session = browser_sessions().remove(0);
+        session.region = "Unknown".to_string();
+        assert_eq!(session.region_enum(), Region::Custom("Unknown".to_string()));
+
+        session.set_region_enum(Region::EuCentral);
+        assert_eq!(session.region, "EU_Central");
+        assert_eq!(session.region_enum(), Region::EuCentral);
+
+        // JSON written with a free-form region still loads unchanged
+        let mut value = serde_json::to_value(&session).unwrap();
+        value["region"] = "us-east-1".into();
+        let legacy: GameSessionInfo = serde_json::from_value(value).unwrap();
+        assert_eq!(legacy.region, "us-east-1");
+        assert_eq!(legacy.region_enum(), Region::NaEast);
+    }
+
+    #[test]
+    fn test_typed_custom_properties() {
+        let mut session = browser_sessions().remove(0);
+        session.set_custom_property("rules.score_limit", 25);
+        session.set_custom_property("rules.friendly_fire", true);
+        session.set_custom_property("rules.gravity", 0.5);
+        session.set_custom_property("spawn", crate::types::Vector::new(1.0, 2.0, 3.0));
+        session.set_custom_property("legacy.time_limit", "600");
+        session.set_custom_property("legacy.ranked", "True");
+        session.set_custom_property("rules", "default");
+
+        assert_eq!(session.get_property_int("rules.score_limit"), Some(25));
+        assert_eq!(session.get_property_float("rules.score_limit"), Some(25.0));
+        assert_eq!(session.get_property_bool("rules.friendly_fire"), Some(true));
+        assert_eq!(session.get_property_float("rules.gravity"), Some(0.5));
+        assert_eq!(session.get_property_int("legacy.time_limit"), Some(600));
+        assert_eq!(session.get_property_bool("legacy.ranked"), Some(true));
+        assert_eq!(session.get_property_int("rules.gravity"), None);
+        assert_eq!(session.get_property_int("missing"), None);
+
+        let keys: Vec<&str> = session.properties_matching("rules.").map(|(key, _)| key.as_str()).collect();
+        assert_eq!(keys, ["rules.friendly_fire", "rules.gravity", "rules.score_limit"]);
+        assert_eq!(session.properties_matching("").count(), 7);
+        assert_eq!(session.properties_matching("zzz").count(), 0);
+
+        // Typed values survive both formats
+        let json = serde_json::to_string(&session).unwrap();
+        assert!(json.contains(r#""rules.score_limit":25"#) && json.contains(r#""legacy.time_limit":"600""#));
+        assert_eq!(serde_json::from_str::<GameSessionInfo>(&json).unwrap(), session);
+        assert_eq!(GameSessionInfo::from_binary(&session.to_binary().unwrap()).unwrap(), session);
+
+        // Filters compare against the value's text
+        let filter = SessionFilter::new().custom_property("rules.friendly_fire", "TRUE").case_sensitive_values(false);
+        assert!(filter.matches_at(&session, 1_000));
+    }
+
+    #[test]
+    fn test_string_only_properties_json() {
+        // Written when every property was a String
+        let json = r#"{"session_id":{"value":7},"session_name":"Old","game_mode":"CTF","map_name":"ctf_fort",
+            "max_players":12,"current_players":3,"is_private":false,"allow_spectators":true,"region":"EU",
+            "difficulty":2,"created_time":1000,"custom_properties":{"score_limit":"3","Mutator":"LowGravity"}}"#;
+        let session: GameSessionInfo = serde_json::from_str(json).unwrap();
+        assert_eq!(session.get_custom_property("Mutator"), Some(&SessionPropertyValue::from("LowGravity")));
+        assert_eq!(session.get_property_int("score_limit"), Some(3));
+        assert_eq!(session.last_heartbeat, 0);
+        assert!(!session.is_stale_at(Timespan::from_seconds(60.0), 1_060));
+
+        // Re-saving keeps them as strings
+        let resaved = serde_json::to_string(&session).unwrap();
+        assert!(resaved.contains(r#""custom_properties":{"Mutator":"LowGravity","score_limit":"3"}"#), "{}", resaved);
+    }
+
+    fn browser_sessions() -> Vec<GameSessionInfo> {
+        let specs = [
+            ("Alpha", "Deathmatch", "dm_arena", "EU", 3, 16, false, 1),
+            ("bravo", "Deathmatch", "dm_docks", "EU", 15, 16, false, 2),
+            ("Charlie", "CTF", "ctf_fort", "NA", 8, 12, true, 3),
+            ("Delta", "deathmatch", "dm_arena", "OCE", 0, 8, false, 5),
+        ];
+        specs
+            .iter()
+            .enumerate()
+            .map(|(i, (name, mode, map, region, players, max, private, difficulty))| {
+                let mut session = GameSessionInfo::new(name.to_string(), mode.to_string(), map.to_string(), *max);
+                session.current_players = *players;
+                session.set_private(*private);
+                session.set_region(region.to_string());
+                session.difficulty = *difficulty;
+                session.created_time = 1_000 + i as u64 * 100;
+                session.last_heartbeat = session.created_time;
+                session
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_combined_filters() {
+        let sessions = browser_sessions();
+
+        let filter = SessionFilter::new().game_mode("DEATHMATCH").min_free_slots(2).private(false);
+        let names: Vec<&str> = filter.filter_sessions(&sessions).iter().map(|s| s.session_name.as_str()).collect();
+        assert_eq!(names, ["Alpha", "Delta"]);
+
+        let filter = SessionFilter::new().map_name("dm_arena").max_difficulty(2).region("eu");
+        assert_eq!(filter.filter_sessions(&sessions).len(), 1);
+
+        let filter = SessionFilter::new().spectators_allowed(false);
+        assert!(filter.filter_sessions(&sessions).is_empty());
+
+        // Age is measured against the given time
+        let filter = SessionFilter::new().max_age_seconds(150);
+        let young: Vec<bool> = sessions.iter().map(|s| filter.matches_at(s, 1_300)).collect();
+        assert_eq!(young, [false, false, true, true]);
+
+        assert!(sessions.iter().all(|s| SessionFilter::new().matches(s)));
+    }
+
+    #[test]
+    fn test_free_slots_do_not_overflow() {
+        let mut session = browser_sessions().remove(0);
+        session.max_players = i32::MAX;
+        session.current_players = -1;
+        assert_eq!(session.free_slots(), i64::from(i32::MAX) + 1);
+        assert!(SessionFilter::new().min_free_slots(i32::MAX).matches_at(&session, 0));
+
+        session.max_players = i32::MIN;
+        session.current_players = 1;
+        assert!(!SessionFilter::new().min_free_slots(0).matches_at(&session, 0));
+    }
+
+    #[test]
+    fn test_custom_property_matching() {
+        let mut sessions = browser_sessions();
+        sessions[0].set_custom_property("Mutator".to_string(), "LowGravity".to_string());
+        sessions[1].set_custom_property("mutator".to_string(), "LowGravity".to_string());
+        sessions[2].set_custom_property("Mutator".to_string(), "lowgravity".to_string());
+
+        // Keys are always case-sensitive, values by default too
+        let filter = SessionFilter::new().custom_property("Mutator", "LowGravity");
+        let matched: Vec<&str> = filter.filter_sessions(&sessions).iter().map(|s| s.session_name.as_str()).collect();
+        assert_eq!(matched, ["Alpha"]);
+
+        let filter = filter.case_sensitive_values(false);
+        let matched: Vec<&str> = filter.filter_sessions(&sessions).iter().map(|s| s.session_name.as_str()).collect();
+        assert_eq!(matched, ["Alpha", "Charlie"]);
+    }
+
+    #[test]
+    fn test_sorting_and_pagination() {
+        let sessions = browser_sessions();
+        let names = |filter: SessionFilter| -> Vec<String> {
+            filter.filter_sessions(&sessions).iter().map(|s| s.session_name.clone()).collect()
+        };
+
+        assert_eq!(names(SessionFilter::new().sort_by(SessionSort::PlayersDescending)), ["bravo", "Charlie", "Alpha", "Delta"]);
+        assert_eq!(names(SessionFilter::new().sort_by(SessionSort::NewestFirst)), ["Delta", "Charlie", "bravo", "Alpha"]);
+        assert_eq!(names(SessionFilter::new().sort_by(SessionSort::NameAscending)), ["Alpha", "bravo", "Charlie", "Delta"]);
+
+        let page = SessionFilter::new().sort_by(SessionSort::NameDescending).skip(1).take(2);
+        assert_eq!(names(page), ["Charlie", "bravo"]);
+        assert!(names(SessionFilter::new().skip(10)).is_empty());
+    }
+
+    #[test]
+    fn test_filter_json() {
+        let filter = SessionFilter::new()
+            .game_mode("CTF")
+            .min_free_slots(1)
+            .custom_property("Ranked", "true")
+            .sort_by(SessionSort::PlayersAscending)
+            .take(20);
+        let json = serde_json::to_string(&filter).unwrap();
+        let deserialized: SessionFilter = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, filter);
+
+        // Clients can send only the fields they care about
+        let partial: SessionFilter = serde_json::from_str(r#"{"region":"NA","take":5}"#).unwrap();
+        assert_eq!(partial, SessionFilter::new().region("NA").take(5));
+        assert!(partial.case_sensitive_values);
+    }
+}
@@ -0,0 +1,341 @@
+//! Fixed-size latency histogram for percentile tracking
+
+use crate::BinarySerializable;
+use serde::de::{self, Deserializer};
+use serde::ser::{SerializeSeq, Serializer};
+use serde::{Deserialize, Serialize};
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(not(feature = "std"))]
+use crate::types::math::float_math::FloatMath;
+
+/// Buckets per doubling of latency, each about 9% wider than the last
+const BUCKETS_PER_OCTAVE: usize = 8;
+/// Doublings covered above 1ms, up to 2048ms
+const OCTAVES: usize = 11;
+
+/// 2^(k/8) for k in 0..8: lower bounds of the buckets within one octave
+const OCTAVE_STEPS: [f32; BUCKETS_PER_OCTAVE] = [
+    1.0,
+    1.090_507_7,
+    1.189_207_1,
+    1.296_839_6,
+    core::f32::consts::SQRT_2,
+    1.542_210_8,
+    1.681_792_8,
+    1.834_008_1,
+];
+
+/// Histogram of latencies in milliseconds with fixed exponential buckets
+///
+/// Bucket 0 holds everything under 1ms and the last bucket everything from
+/// `MAX_TRACKED_MS` up. In between there are 8 buckets per doubling, so a
+/// percentile estimate is off by at most about 9% of its value. Only bucket
+/// counts are stored: the histogram is the same size whatever the sample
+/// count, and merging histograms from several servers is exact. Works for
+/// any millisecond timing, such as frame times.
+///
+/// ```
+/// use ue_types::*;
+///
+/// let mut ping = LatencyHistogram::new();
+/// for ms in [20.0, 22.0, 25.0, 24.0, 180.0] {
+///     ping.record(ms);
+/// }
+/// assert!(ping.percentile(50.0) < 30.0);
+/// assert!(ping.percentile(99.0) > 150.0);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LatencyHistogram {
+    buckets: [u64; LatencyHistogram::BUCKET_COUNT],
+    count: u64,
+}
+
+impl Default for LatencyHistogram {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for LatencyHistogram {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "LatencyHistogram(n={}, p50={:.1}ms, p95={:.1}ms, p99={:.1}ms)",
+            self.count,
+            self.percentile(50.0),
+            self.percentile(95.0),
+            self.percentile(99.0)
+        )
+    }
+}
+
+impl BinarySerializable for LatencyHistogram {}
+
+impl LatencyHistogram {
+    /// Number of buckets, including the under-1ms and overflow buckets
+    pub const BUCKET_COUNT: usize = BUCKETS_PER_OCTAVE * OCTAVES + 2;
+
+    /// Lower bound of the overflow bucket
+    pub const MAX_TRACKED_MS: f32 = (1u32 << OCTAVES) as f32;
+
+    /// Create an empty histogram
+    pub fn new() -> Self {
+        Self {
+            buckets: [0; Self::BUCKET_COUNT],
+            count: 0,
+        }
+    }
+
+    /// Record a latency in milliseconds (negative and NaN samples are ignored)
+    pub fn record(&mut self, ms: f32) {
+        if let Some(index) = Self::bucket_index(ms) {
+            self.buckets[index] += 1;
+            self.count += 1;
+        }
+    }
+
+    /// Number of recorded samples
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Check if no samples have been recorded
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Sample count in each bucket
+    pub fn buckets(&self) -> &[u64; Self::BUCKET_COUNT] {
+        &self.buckets
+    }
+
+    /// Range of latencies counted by a bucket, as `(inclusive lower, exclusive upper)`
+    ///
+    /// The overflow bucket's upper bound is infinite.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not below `BUCKET_COUNT`.
+    pub fn bucket_bounds(index: usize) -> (f32, f32) {
+        assert!(index < Self::BUCKET_COUNT, "bucket index {} out of range", index);
+        let upper = if index + 1 == Self::BUCKET_COUNT { f32::INFINITY } else { Self::lower_bound(index + 1) };
+        (Self::lower_bound(index), upper)
+    }
+
+    /// Estimate the latency below which `p` percent of samples fall
+    ///
+    /// `p` is clamped to `[0, 100]`. The estimate interpolates within the
+    /// bucket holding that rank, so it is within one bucket width of the
+    /// true value; ranks in the overflow bucket report `MAX_TRACKED_MS`.
+    /// Returns 0 for an empty histogram.
+    pub fn percentile(&self, p: f32) -> f32 {
+        if self.count == 0 {
+            return 0.0;
+        }
+        let fraction = if p.is_nan() { 0.0 } else { p.clamp(0.0, 100.0) as f64 / 100.0 };
+        // Nearest rank, 1-based
+        let rank = ((fraction * self.count as f64).ceil() as u64).clamp(1, self.count);
+
+        let mut below = 0;
+        for (index, &in_bucket) in self.buckets.iter().enumerate() {
+            if below + in_bucket >= rank {
+                let (lower, upper) = Self::bucket_bounds(index);
+                if upper.is_infinite() {
+                    return lower;
+                }
+                let position = ((rank - below) as f64 - 0.5) / in_bucket as f64;
+                return (lower as f64 + (upper - lower) as f64 * position) as f32;
+            }
+            below += in_bucket;
+        }
+        unreachable!("bucket counts add up to count")
+    }
+
+    /// Add another histogram's samples to this one
+    pub fn merge(&mut self, other: &LatencyHistogram) {
+        for (bucket, &extra) in self.buckets.iter_mut().zip(other.buckets.iter()) {
+            *bucket += extra;
+        }
+        self.count += other.count;
+    }
+
+    /// Discard all samples
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Bucket for a sample, or `None` for negative and NaN samples
+    fn bucket_index(ms: f32) -> Option<usize> {
+        if ms.is_nan() || ms < 0.0 {
+            return None;
+        }
+        if ms < 1.0 {
+            return Some(0);
+        }
+        if ms >= Self::MAX_TRACKED_MS {
+            return Some(Self::BUCKET_COUNT - 1);
+        }
+        // ms is in [1, 2048), so its binary exponent is the octave
+        let octave = ((ms.to_bits() >> 23) & 0xff) as usize - 127;
+        let within = ms / (1u32 << octave) as f32;
+        let step = OCTAVE_STEPS.iter().rposition(|&bound| within >= bound).unwrap_or(0);
+        Some(1 + octave * BUCKETS_PER_OCTAVE + step)
+    }
+
+    /// Inclusive lower bound of a bucket in milliseconds
+    fn lower_bound(index: usize) -> f32 {
+        if index == 0 {
+            return 0.0;
+        }
+        let octave = (index - 1) / BUCKETS_PER_OCTAVE;
+        (1u32 << octave) as f32 * OCTAVE_STEPS[(index - 1) % BUCKETS_PER_OCTAVE]
+    }
+}
+
+/// Serialized as `[bucket index, count]` pairs for the non-empty buckets only
+impl Serialize for LatencyHistogram {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let used = self.buckets.iter().filter(|&&count| count > 0).count();
+        let mut seq = serializer.serialize_seq(Some(used))?;
+        for (index, &count) in self.buckets.iter().enumerate() {
+            if count > 0 {
+                seq.serialize_element(&(index as u32, count))?;
+            }
+        }
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for LatencyHistogram {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let pairs = Vec::<(u32, u64)>::deserialize(deserializer)?;
+        let mut histogram = Self::new();
+        for (index, count) in pairs {
+            let slot = histogram
+                .buckets
+                .get_mut(index as usize)
+                .ok_or_else(|| de::Error::custom(format_args!("bucket index {} out of range", index)))?;
+            if *slot != 0 {
+                return Err(de::Error::custom(format_args!("bucket {} listed twice", index)));
+            }
+            *slot = count;
+            histogram.count = histogram
+                .count
+                .checked_add(count)
+                .ok_or_else(|| de::Error::custom("total sample count overflows"))?;
+        }
+        Ok(histogram)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Width of the bucket holding `ms`
+    fn bucket_width_at(ms: f32) -> f32 {
+        let (lower, upper) = LatencyHistogram::bucket_bounds(LatencyHistogram::bucket_index(ms).unwrap());
+        upper - lower
+    }
+
+    #[test]
+    fn test_bucket_layout() {
+        assert_eq!(LatencyHistogram::BUCKET_COUNT, 90);
+        assert_eq!(LatencyHistogram::bucket_bounds(0), (0.0, 1.0));
+        assert_eq!(LatencyHistogram::bucket_bounds(1).0, 1.0);
+        assert_eq!(LatencyHistogram::bucket_bounds(9).0, 2.0);
+        assert_eq!(LatencyHistogram::bucket_bounds(89), (2048.0, f32::INFINITY));
+
+        // Bounds are contiguous and every sample lands in the bucket that covers it
+        for index in 0..LatencyHistogram::BUCKET_COUNT {
+            let (lower, upper) = LatencyHistogram::bucket_bounds(index);
+            assert_eq!(LatencyHistogram::bucket_index(lower), Some(index));
+            if index + 1 < LatencyHistogram::BUCKET_COUNT {
+                assert_eq!(LatencyHistogram::bucket_bounds(index + 1).0, upper);
+                let inside = lower + (upper - lower) * 0.5;
+                assert_eq!(LatencyHistogram::bucket_index(inside), Some(index));
+            }
+        }
+        assert_eq!(LatencyHistogram::bucket_index(f32::INFINITY), Some(89));
+        assert_eq!(LatencyHistogram::bucket_index(-0.5), None);
+        assert_eq!(LatencyHistogram::bucket_index(f32::NAN), None);
+    }
+
+    #[test]
+    fn test_percentiles_within_one_bucket() {
+        // 1..=1000ms once each, recorded in a scrambled order
+        let mut histogram = LatencyHistogram::new();
+        for i in 0..1000u32 {
+            histogram.record(((i * 617) % 1000 + 1) as f32);
+        }
+        assert_eq!(histogram.count(), 1000);
+        for (p, expected) in [(1.0, 10.0), (50.0, 500.0), (95.0, 950.0), (99.0, 990.0), (100.0, 1000.0)] {
+            let estimate = histogram.percentile(p);
+            assert!((estimate - expected).abs() <= bucket_width_at(expected), "p{} = {}", p, estimate);
+        }
+
+        // A spiky connection: mostly 30ms with 3% at 400ms
+        let mut spiky = LatencyHistogram::new();
+        for i in 0..1000 {
+            spiky.record(if i % 100 < 3 { 400.0 } else { 30.0 });
+        }
+        assert!((spiky.percentile(50.0) - 30.0).abs() <= bucket_width_at(30.0));
+        assert!((spiky.percentile(95.0) - 30.0).abs() <= bucket_width_at(30.0));
+        assert!((spiky.percentile(99.0) - 400.0).abs() <= bucket_width_at(400.0));
+
+        let mut overflow = LatencyHistogram::new();
+        overflow.record(5000.0);
+        assert_eq!(overflow.percentile(50.0), LatencyHistogram::MAX_TRACKED_MS);
+        assert_eq!(LatencyHistogram::new().percentile(95.0), 0.0);
+    }
+
+    #[test]
+    fn test_merge_associativity() {
+        let fill = |values: &[f32]| {
+            let mut histogram = LatencyHistogram::new();
+            for &value in values {
+                histogram.record(value);
+            }
+            histogram
+        };
+        let a = fill(&[0.4, 12.0, 35.0, 35.5]);
+        let b = fill(&[80.0, 3000.0]);
+        let c = fill(&[12.5, 150.0, 150.0, 999.0]);
+
+        let mut left = a.clone();
+        left.merge(&b);
+        left.merge(&c);
+        let mut bc = b.clone();
+        bc.merge(&c);
+        let mut right = a.clone();
+        right.merge(&bc);
+
+        assert_eq!(left, right);
+        assert_eq!(left, fill(&[0.4, 12.0, 35.0, 35.5, 80.0, 3000.0, 12.5, 150.0, 150.0, 999.0]));
+        assert_eq!(left.percentile(95.0), right.percentile(95.0));
+
+        left.reset();
+        assert!(left.is_empty());
+        assert_eq!(left, LatencyHistogram::default());
+    }
+
+    #[test]
+    fn test_compact_serialization() {
+        let mut histogram = LatencyHistogram::new();
+        for ms in [0.5, 1.0, 1.0, 2.0, 5000.0] {
+            histogram.record(ms);
+        }
+        let json = serde_json::to_string(&histogram).unwrap();
+        assert_eq!(json, "[[0,1],[1,2],[9,1],[89,1]]");
+        assert_eq!(serde_json::from_str::<LatencyHistogram>(&json).unwrap(), histogram);
+
+        let binary = histogram.to_binary().unwrap();
+        assert!(binary.len() < 100);
+        assert_eq!(LatencyHistogram::from_binary(&binary).unwrap(), histogram);
+
+        assert!(serde_json::from_str::<LatencyHistogram>("[[90,1]]").is_err());
+        assert!(serde_json::from_str::<LatencyHistogram>("[[3,1],[3,2]]").is_err());
+        assert_eq!(serde_json::from_str::<LatencyHistogram>("[]").unwrap(), LatencyHistogram::new());
+    }
+}
@@ -1,14 +1,50 @@
 //! UE5 networking types for game server development
 
 pub mod network_guid;
+pub mod net_guid_registry;
 pub mod network_stats;
+pub mod latency_histogram;
 pub mod rep_movement;
+pub mod quantization;
+pub mod bitstream;
+pub mod packet_header;
+pub mod rep_movement_delta;
+pub mod movement_history;
 pub mod player_net_info;
+pub mod player_roster;
 pub mod game_session_info;
+pub mod session_property;
+pub mod session_registry;
+pub mod prediction_error_stats;
+pub mod session_update;
+pub mod validation;
+pub mod relevancy;
+pub mod transform_hierarchy;
+pub mod actor_net_info;
+pub mod world_snapshot;
+pub mod region;
 
 // Re-export all types for convenience
 pub use network_guid::*;
+pub use net_guid_registry::*;
 pub use network_stats::*;
+pub use latency_histogram::*;
 pub use rep_movement::*;
+pub use quantization::*;
+pub use bitstream::*;
+pub use packet_header::*;
+pub use rep_movement_delta::*;
+pub use movement_history::*;
 pub use player_net_info::*;
-pub use game_session_info::*;
\ No newline at end of file
+pub use player_roster::*;
+pub use game_session_info::*;
+pub use session_property::*;
+pub use session_registry::*;
+pub use prediction_error_stats::*;
+pub use session_update::*;
+pub use validation::*;
+pub use relevancy::*;
+pub use transform_hierarchy::*;
+pub use actor_net_info::*;
+pub use world_snapshot::*;
+pub use region::*;
\ No newline at end of file
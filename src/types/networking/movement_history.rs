@@ -0,0 +1,283 @@
+//! Timestamped RepMovement history for client-side smoothing
+
+use crate::types::lerp_rotator;
+use super::RepMovement;
+use alloc::collections::VecDeque;
+use core::fmt;
+
+/// A RepMovement received at a given time (in seconds)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MovementSnapshot {
+    /// Time the movement applies to
+    pub time: f64,
+    /// The replicated movement
+    pub movement: RepMovement,
+}
+
+/// Ring buffer of movement snapshots that can be sampled at any time
+///
+/// Snapshots are kept sorted by time, so out-of-order arrivals are slotted
+/// into place. Sampling between two snapshots interpolates; sampling past the
+/// newest extrapolates along its velocity for at most `max_extrapolation`
+/// seconds.
+#[derive(Debug, Clone)]
+pub struct MovementHistory {
+    snapshots: VecDeque<MovementSnapshot>,
+    capacity: usize,
+    max_extrapolation: f64,
+}
+
+impl fmt::Display for MovementHistory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "MovementHistory({}/{} snapshots", self.len(), self.capacity)?;
+        if let (Some(oldest), Some(newest)) = (self.oldest(), self.newest()) {
+            write!(f, ", {:.3}s..{:.3}s", oldest.time, newest.time)?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl MovementHistory {
+    /// Default limit on how far past the newest snapshot to extrapolate, in seconds
+    pub const DEFAULT_MAX_EXTRAPOLATION: f64 = 0.25;
+
+    /// Create a history holding at most `capacity` snapshots (at least 1)
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            snapshots: VecDeque::with_capacity(capacity),
+            capacity,
+            max_extrapolation: Self::DEFAULT_MAX_EXTRAPOLATION,
+        }
+    }
+
+    /// Set the maximum extrapolation duration in seconds (negative values become 0)
+    pub fn with_max_extrapolation(mut self, seconds: f64) -> Self {
+        self.set_max_extrapolation(seconds);
+        self
+    }
+
+    /// Set the maximum extrapolation duration in seconds (negative values become 0)
+    pub fn set_max_extrapolation(&mut self, seconds: f64) {
+        self.max_extrapolation = seconds.max(0.0);
+    }
+
+    /// Maximum extrapolation duration in seconds
+    pub fn max_extrapolation(&self) -> f64 {
+        self.max_extrapolation
+    }
+
+    /// Maximum number of snapshots kept
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of snapshots stored
+    pub fn len(&self) -> usize {
+        self.snapshots.len()
+    }
+
+    /// Check if no snapshots are stored
+    pub fn is_empty(&self) -> bool {
+        self.snapshots.is_empty()
+    }
+
+    /// Remove every snapshot
+    pub fn clear(&mut self) {
+        self.snapshots.clear();
+    }
+
+    /// Oldest stored snapshot
+    pub fn oldest(&self) -> Option<&MovementSnapshot> {
+        self.snapshots.front()
+    }
+
+    /// Newest stored snapshot
+    pub fn newest(&self) -> Option<&MovementSnapshot> {
+        self.snapshots.back()
+    }
+
+    /// Iterate over snapshots from oldest to newest
+    pub fn iter(&self) -> impl Iterator<Item = &MovementSnapshot> {
+        self.snapshots.iter()
+    }
+
+    /// Add a snapshot, returning whether it was stored
+    ///
+    /// A snapshot at the same time as an existing one replaces it. Snapshots
+    /// repeating a non-zero `server_frame` already in the buffer, non-finite
+    /// times, and snapshots older than everything in a full buffer are ignored.
+    pub fn add_snapshot(&mut self, time: f64, movement: RepMovement) -> bool {
+        if !time.is_finite() {
+            return false;
+        }
+        if movement.server_frame != 0
+            && self
+                .snapshots
+                .iter()
+                .any(|s| s.movement.server_frame == movement.server_frame && s.time != time)
+        {
+            return false;
+        }
+
+        let index = self.snapshots.partition_point(|s| s.time < time);
+        let snapshot = MovementSnapshot { time, movement };
+        if self.snapshots.get(index).is_some_and(|s| s.time == time) {
+            self.snapshots[index] = snapshot;
+            return true;
+        }
+        if self.snapshots.len() == self.capacity {
+            if index == 0 {
+                return false;
+            }
+            self.snapshots.pop_front();
+            self.snapshots.insert(index - 1, snapshot);
+        } else {
+            self.snapshots.insert(index, snapshot);
+        }
+        true
+    }
+
+    /// Sample the movement at `time`
+    ///
+    /// Times before the oldest snapshot return the oldest snapshot; times
+    /// after the newest are extrapolated. An empty history returns the default
+    /// movement. Discrete fields (frame, base, flags) come from the earlier of
+    /// the two surrounding snapshots.
+    pub fn sample(&self, time: f64) -> RepMovement {
+        let (Some(oldest), Some(newest)) = (self.oldest(), self.newest()) else {
+            return RepMovement::default();
+        };
+        if time <= oldest.time {
+            return oldest.movement;
+        }
+        if time >= newest.time {
+            return self.extrapolate(time);
+        }
+
+        let index = self.snapshots.partition_point(|s| s.time <= time);
+        let (from, to) = (&self.snapshots[index - 1], &self.snapshots[index]);
+        let alpha = (time - from.time) / (to.time - from.time);
+
+        let mut result = from.movement;
+        result.location = from.movement.location.lerp(to.movement.location, alpha);
+        result.rotation = lerp_rotator(from.movement.rotation, to.movement.rotation, alpha);
+        result.linear_velocity = from.movement.linear_velocity.lerp(to.movement.linear_velocity, alpha);
+        result.angular_velocity = from.movement.angular_velocity.lerp(to.movement.angular_velocity, alpha);
+        result.relative_location = from.movement.relative_location.lerp(to.movement.relative_location, alpha);
+        result
+    }
+
+    /// Predict the movement at `time` from the newest snapshot
+    ///
+    /// Steps the newest movement with [`RepMovement::step`], so the angular
+    /// velocity is degrees per second about the world X, Y and Z axes (Z is
+    /// yaw). The extrapolated duration is clamped to `[0, max_extrapolation]`.
+    pub fn extrapolate(&self, time: f64) -> RepMovement {
+        let Some(newest) = self.newest() else {
+            return RepMovement::default();
+        };
+        let delta = (time - newest.time).clamp(0.0, self.max_extrapolation);
+        newest.movement.step(delta)
+    }
+}
+
+impl Default for MovementHistory {
+    /// Holds 32 snapshots, about half a second at 60 updates per second
+    fn default() -> Self {
+        Self::new(32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Rotator, Vector};
+
+    fn movement(frame: u32, x: f64, yaw: f64, velocity_x: f64) -> RepMovement {
+        let mut movement = RepMovement::from_transform(
+            Vector::new(x, 0.0, 0.0),
+            Rotator::new(0.0, yaw, 0.0),
+            Vector::new(velocity_x, 0.0, 0.0),
+        );
+        movement.set_server_frame(frame);
+        movement
+    }
+
+    #[test]
+    fn test_interpolation_midway() {
+        let mut history = MovementHistory::new(8);
+        assert!(history.add_snapshot(1.0, movement(1, 0.0, 170.0, 100.0)));
+        assert!(history.add_snapshot(2.0, movement(2, 100.0, -170.0, 300.0)));
+
+        let sampled = history.sample(1.5);
+        assert!((sampled.location.x - 50.0).abs() < 1e-9);
+        assert!((sampled.linear_velocity.x - 200.0).abs() < 1e-9);
+        // Rotation takes the short way across +-180
+        assert!((sampled.rotation.yaw.abs() - 180.0).abs() < 1e-9);
+        assert_eq!(sampled.server_frame, 1);
+
+        // Outside the range clamps to the oldest snapshot
+        assert_eq!(history.sample(0.0), movement(1, 0.0, 170.0, 100.0));
+    }
+
+    #[test]
+    fn test_clamped_extrapolation() {
+        let mut history = MovementHistory::new(4).with_max_extrapolation(0.1);
+        history.add_snapshot(1.0, movement(1, 0.0, 0.0, 100.0));
+        let mut spinning = movement(2, 10.0, 0.0, 100.0);
+        spinning.angular_velocity = Vector::new(0.0, 0.0, 90.0);
+        history.add_snapshot(1.1, spinning);
+
+        let near = history.sample(1.15);
+        assert!((near.location.x - 15.0).abs() < 1e-9);
+        assert!((near.rotation.yaw - 4.5).abs() < 0.01);
+        assert!(near.rotation.pitch.abs() < 1e-9 && near.rotation.roll.abs() < 1e-9);
+
+        // Never further than max_extrapolation past the newest snapshot
+        let far = history.sample(5.0);
+        assert!((far.location.x - 20.0).abs() < 1e-9);
+        assert!((far.rotation.yaw - 9.0).abs() < 0.05);
+        assert_eq!(history.extrapolate(0.5), spinning);
+
+        // Y is the pitch rate, not yaw
+        let mut pitching = movement(3, 0.0, 0.0, 0.0);
+        pitching.angular_velocity = Vector::new(0.0, 90.0, 0.0);
+        history.add_snapshot(2.0, pitching);
+        let pitched = history.sample(2.1);
+        assert!((pitched.rotation.pitch.abs() - 9.0).abs() < 0.05);
+        assert!(pitched.rotation.yaw.abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_out_of_order_and_duplicates() {
+        let mut history = MovementHistory::new(3);
+        assert!(history.add_snapshot(2.0, movement(2, 20.0, 0.0, 0.0)));
+        assert!(history.add_snapshot(1.0, movement(1, 10.0, 0.0, 0.0)));
+        assert!(history.add_snapshot(3.0, movement(3, 30.0, 0.0, 0.0)));
+        let times: Vec<f64> = history.iter().map(|s| s.time).collect();
+        assert_eq!(times, [1.0, 2.0, 3.0]);
+
+        // Same server frame at another time is a duplicate
+        assert!(!history.add_snapshot(3.5, movement(3, 99.0, 0.0, 0.0)));
+        // Same time replaces the stored snapshot
+        assert!(history.add_snapshot(3.0, movement(3, 31.0, 0.0, 0.0)));
+        assert_eq!(history.newest().unwrap().movement.location.x, 31.0);
+
+        // Full buffer drops the oldest, and rejects anything older than it
+        assert!(history.add_snapshot(2.5, movement(4, 25.0, 0.0, 0.0)));
+        let frames: Vec<u32> = history.iter().map(|s| s.movement.server_frame).collect();
+        assert_eq!(frames, [2, 4, 3]);
+        assert!(!history.add_snapshot(0.5, movement(5, 5.0, 0.0, 0.0)));
+        assert!(!history.add_snapshot(f64::NAN, movement(6, 0.0, 0.0, 0.0)));
+        assert_eq!(history.len(), 3);
+    }
+
+    #[test]
+    fn test_empty_history() {
+        let history = MovementHistory::default();
+        assert!(history.is_empty());
+        assert_eq!(history.sample(1.0), RepMovement::default());
+        assert_eq!(format!("{}", history), "MovementHistory(0/32 snapshots)");
+    }
+}
@@ -0,0 +1,464 @@
+//! NetworkGUID allocation and per-connection ack tracking (FNetGUIDCache)
+
+use crate::BinarySerializable;
+use crate::types::{DateTime, TMap, Timespan};
+use super::NetworkGUID;
+use serde::{Deserialize, Serialize};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Reason a registry operation failed or a saved registry could not be restored
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NetGuidRegistryError {
+    /// Every dynamic GUID is in use
+    DynamicGuidsExhausted,
+    /// The saved dynamic counter is odd, so it would hand out static GUIDs
+    InvalidNextDynamic(u32),
+    /// Two entries share a GUID
+    DuplicateGuid(NetworkGUID),
+    /// Two entries were registered from the same static path hash
+    DuplicateStaticHash(u64),
+    /// The GUID is 0, or its parity doesn't match static/dynamic
+    InvalidGuid(NetworkGUID),
+}
+
+impl fmt::Display for NetGuidRegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NetGuidRegistryError::DynamicGuidsExhausted => write!(f, "every dynamic NetworkGUID is in use"),
+            NetGuidRegistryError::InvalidNextDynamic(next) => {
+                write!(f, "next dynamic NetworkGUID {} is odd, dynamic GUIDs are even", next)
+            }
+            NetGuidRegistryError::DuplicateGuid(guid) => write!(f, "{} is registered twice", guid),
+            NetGuidRegistryError::DuplicateStaticHash(hash) => {
+                write!(f, "static path hash {:#018x} is registered twice", hash)
+            }
+            NetGuidRegistryError::InvalidGuid(guid) => write!(f, "{} is not valid for its entry kind", guid),
+        }
+    }
+}
+
+impl core::error::Error for NetGuidRegistryError {}
+
+/// An object known to a `NetworkGuidRegistry`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ObjectEntry {
+    /// The GUID assigned to the object
+    pub guid: NetworkGUID,
+    /// When the GUID was assigned
+    pub created: DateTime,
+    /// Optional name for logs and debugging
+    pub debug_name: Option<String>,
+    /// Path hash the GUID was derived from, for static objects
+    pub static_hash: Option<u64>,
+    /// Connections that have acknowledged the GUID, sorted
+    pub acked_by: Vec<u32>,
+}
+
+impl ObjectEntry {
+    fn new(guid: NetworkGUID, static_hash: Option<u64>, created: DateTime) -> Self {
+        Self {
+            guid,
+            created,
+            debug_name: None,
+            static_hash,
+            acked_by: Vec::new(),
+        }
+    }
+
+    /// Check if the object was registered from a static path hash
+    pub fn is_static(&self) -> bool {
+        self.static_hash.is_some()
+    }
+
+    /// Check if a connection has acknowledged the GUID
+    pub fn is_acked_by(&self, connection_id: u32) -> bool {
+        self.acked_by.binary_search(&connection_id).is_ok()
+    }
+}
+
+/// Serialized form of a registry: entries ordered by GUID
+#[derive(Serialize, Deserialize)]
+struct RegistrySnapshot {
+    next_dynamic: u32,
+    entries: Vec<ObjectEntry>,
+}
+
+/// Assigns NetworkGUIDs to replicated objects and tracks which connections know them
+///
+/// Follows `FNetGUIDCache`'s split: dynamic GUIDs (spawned objects) are even
+/// and handed out by a counter, static GUIDs (objects loaded from a package)
+/// are odd. Static GUIDs are derived from the object's path hash instead of
+/// a counter, so every server assigns the same GUID to the same object.
+/// Serializes as a list of entries, so it works with JSON.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(into = "RegistrySnapshot", try_from = "RegistrySnapshot")]
+pub struct NetworkGuidRegistry {
+    entries: TMap<NetworkGUID, ObjectEntry>,
+    static_hashes: TMap<u64, NetworkGUID>,
+    next_dynamic: u32,
+}
+
+impl fmt::Display for NetworkGuidRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "NetworkGuidRegistry({} objects, {} static)",
+            self.len(),
+            self.static_hashes.len()
+        )
+    }
+}
+
+impl BinarySerializable for NetworkGuidRegistry {}
+
+impl NetworkGuidRegistry {
+    /// Number of GUIDs of each kind (every other u32, minus 0 for dynamic)
+    const DYNAMIC_CAPACITY: usize = (u32::MAX / 2) as usize;
+    const STATIC_CAPACITY: usize = (u32::MAX / 2) as usize + 1;
+
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self {
+            entries: TMap::new(),
+            static_hashes: TMap::new(),
+            next_dynamic: 2,
+        }
+    }
+
+    /// Number of registered objects
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if no objects are registered
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Assign a GUID to a dynamic object
+    ///
+    /// GUIDs increase monotonically until the counter wraps past `u32::MAX`;
+    /// after that, 0 and GUIDs still in use are skipped. Fails with
+    /// `DynamicGuidsExhausted` if every dynamic GUID is in use.
+    #[cfg(feature = "std")]
+    pub fn assign_new(&mut self) -> Result<NetworkGUID, NetGuidRegistryError> {
+        self.assign_new_at(DateTime::now())
+    }
+
+    /// Assign a GUID to a dynamic object created at `now`
+    pub fn assign_new_at(&mut self, now: DateTime) -> Result<NetworkGUID, NetGuidRegistryError> {
+        let dynamic_count = self.entries.len() - self.static_hashes.len();
+        if dynamic_count >= Self::DYNAMIC_CAPACITY {
+            return Err(NetGuidRegistryError::DynamicGuidsExhausted);
+        }
+
+        loop {
+            let guid = NetworkGUID::new(self.next_dynamic);
+            self.next_dynamic = self.next_dynamic.wrapping_add(2);
+            if guid.is_valid() && !self.entries.contains(&guid) {
+                self.entries.add(guid, ObjectEntry::new(guid, None, now));
+                return Ok(guid);
+            }
+        }
+    }
+
+    /// Get the GUID for a static object, registering it on first use
+    ///
+    /// The GUID is derived from the path hash, so registering the same hash
+    /// again returns the same GUID. When two hashes fold to the same value
+    /// the later one probes for the next free static GUID, which depends on
+    /// registration order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if every static GUID is in use.
+    #[cfg(feature = "std")]
+    pub fn register_static(&mut self, path_hash: u64) -> NetworkGUID {
+        self.register_static_at(path_hash, DateTime::now())
+    }
+
+    /// Get the GUID for a static object, registering it at `now` on first use
+    ///
+    /// # Panics
+    ///
+    /// Panics if every static GUID is in use.
+    pub fn register_static_at(&mut self, path_hash: u64, now: DateTime) -> NetworkGUID {
+        if let Some(&guid) = self.static_hashes.find(&path_hash) {
+            return guid;
+        }
+        assert!(self.static_hashes.len() < Self::STATIC_CAPACITY, "every static NetworkGUID is in use");
+
+        let mut value = (path_hash ^ (path_hash >> 32)) as u32 | 1;
+        while self.entries.contains(&NetworkGUID::new(value)) {
+            value = value.wrapping_add(2);
+        }
+        let guid = NetworkGUID::new(value);
+        self.entries.add(guid, ObjectEntry::new(guid, Some(path_hash), now));
+        self.static_hashes.add(path_hash, guid);
+        guid
+    }
+
+    /// Get the GUID registered for a static path hash
+    pub fn find_static(&self, path_hash: u64) -> Option<NetworkGUID> {
+        self.static_hashes.find(&path_hash).copied()
+    }
+
+    /// Get the entry for a GUID
+    pub fn lookup(&self, guid: NetworkGUID) -> Option<&ObjectEntry> {
+        self.entries.find(&guid)
+    }
+
+    /// Set the debug name of an entry; returns false if the GUID isn't registered
+    pub fn set_debug_name(&mut self, guid: NetworkGUID, name: impl Into<String>) -> bool {
+        match self.entries.find_mut(&guid) {
+            Some(entry) => {
+                entry.debug_name = Some(name.into());
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Unregister a GUID, returning its entry
+    pub fn remove(&mut self, guid: NetworkGUID) -> Option<ObjectEntry> {
+        let entry = self.entries.remove(&guid)?;
+        if let Some(hash) = entry.static_hash {
+            self.static_hashes.remove(&hash);
+        }
+        Some(entry)
+    }
+
+    /// Record that a connection acknowledged a GUID
+    ///
+    /// Returns false if the GUID isn't registered.
+    pub fn mark_acked(&mut self, guid: NetworkGUID, connection_id: u32) -> bool {
+        match self.entries.find_mut(&guid) {
+            Some(entry) => {
+                if let Err(index) = entry.acked_by.binary_search(&connection_id) {
+                    entry.acked_by.insert(index, connection_id);
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Check if a connection has acknowledged a GUID
+    pub fn is_acked(&self, guid: NetworkGUID, connection_id: u32) -> bool {
+        self.lookup(guid).is_some_and(|entry| entry.is_acked_by(connection_id))
+    }
+
+    /// Forget every ack from a connection, e.g. when it closes
+    pub fn remove_connection(&mut self, connection_id: u32) {
+        for entry in self.entries.values_mut() {
+            if let Ok(index) = entry.acked_by.binary_search(&connection_id) {
+                entry.acked_by.remove(index);
+            }
+        }
+    }
+
+    /// Unregister GUIDs no connection has acknowledged within `max_age`
+    ///
+    /// Returns the removed GUIDs in ascending order.
+    #[cfg(feature = "std")]
+    pub fn purge_unacked_older_than(&mut self, max_age: Timespan) -> Vec<NetworkGUID> {
+        self.purge_unacked_older_than_at(max_age, DateTime::now())
+    }
+
+    /// Unregister GUIDs no connection has acknowledged within `max_age` of `now`
+    pub fn purge_unacked_older_than_at(&mut self, max_age: Timespan, now: DateTime) -> Vec<NetworkGUID> {
+        self.purge_unacked_created_before(now - max_age)
+    }
+
+    fn purge_unacked_created_before(&mut self, cutoff: DateTime) -> Vec<NetworkGUID> {
+        let mut stale: Vec<NetworkGUID> = self
+            .entries
+            .values()
+            .filter(|entry| entry.acked_by.is_empty() && entry.created < cutoff)
+            .map(|entry| entry.guid)
+            .collect();
+        stale.sort_by_key(|guid| guid.value);
+        for &guid in &stale {
+            self.remove(guid);
+        }
+        stale
+    }
+
+    /// Iterate over entries in GUID order
+    pub fn iter(&self) -> impl Iterator<Item = &ObjectEntry> {
+        let mut entries: Vec<&ObjectEntry> = self.entries.values().collect();
+        entries.sort_by_key(|entry| entry.guid.value);
+        entries.into_iter()
+    }
+}
+
+impl Default for NetworkGuidRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<NetworkGuidRegistry> for RegistrySnapshot {
+    fn from(registry: NetworkGuidRegistry) -> Self {
+        Self {
+            next_dynamic: registry.next_dynamic,
+            entries: registry.iter().cloned().collect(),
+        }
+    }
+}
+
+impl TryFrom<RegistrySnapshot> for NetworkGuidRegistry {
+    type Error = NetGuidRegistryError;
+
+    fn try_from(snapshot: RegistrySnapshot) -> Result<Self, Self::Error> {
+        if snapshot.next_dynamic % 2 == 1 {
+            return Err(NetGuidRegistryError::InvalidNextDynamic(snapshot.next_dynamic));
+        }
+        let mut registry = Self::new();
+        registry.next_dynamic = snapshot.next_dynamic;
+        for mut entry in snapshot.entries {
+            let odd = entry.guid.value % 2 == 1;
+            if !entry.guid.is_valid() || odd != entry.is_static() {
+                return Err(NetGuidRegistryError::InvalidGuid(entry.guid));
+            }
+            if registry.entries.contains(&entry.guid) {
+                return Err(NetGuidRegistryError::DuplicateGuid(entry.guid));
+            }
+            if let Some(hash) = entry.static_hash {
+                if registry.static_hashes.add(hash, entry.guid).is_some() {
+                    return Err(NetGuidRegistryError::DuplicateStaticHash(hash));
+                }
+            }
+            entry.acked_by.sort_unstable();
+            entry.acked_by.dedup();
+            registry.entries.add(entry.guid, entry);
+        }
+        Ok(registry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dynamic_guids_wrap_and_skip_in_use() {
+        let mut registry = NetworkGuidRegistry::new();
+        let first = registry.assign_new().unwrap();
+        let second = registry.assign_new().unwrap();
+        assert_eq!((first.value, second.value), (2, 4));
+        assert!(!registry.lookup(first).unwrap().is_static());
+
+        // Jump to the end of the range: 0 is skipped and 2 and 4 are still in use
+        registry.next_dynamic = u32::MAX - 3;
+        let tail: Vec<u32> = (0..3).map(|_| registry.assign_new().unwrap().value).collect();
+        assert_eq!(tail, [u32::MAX - 3, u32::MAX - 1, 6]);
+
+        registry.remove(first);
+        registry.next_dynamic = 0;
+        assert_eq!(registry.assign_new().unwrap(), first);
+        assert_eq!(registry.len(), 5);
+    }
+
+    #[test]
+    fn test_static_guids_are_deterministic() {
+        let mut registry = NetworkGuidRegistry::new();
+        let door = registry.register_static(0xDEAD_BEEF_0000_0001);
+        assert_eq!(door.value % 2, 1);
+        assert_eq!(registry.register_static(0xDEAD_BEEF_0000_0001), door);
+        assert_eq!(registry.len(), 1);
+        assert_eq!(registry.find_static(0xDEAD_BEEF_0000_0001), Some(door));
+
+        // Another server registering in a different order agrees
+        let mut other = NetworkGuidRegistry::new();
+        other.assign_new().unwrap();
+        other.register_static(42);
+        assert_eq!(other.register_static(0xDEAD_BEEF_0000_0001), door);
+
+        // A hash folding to the same value probes to the next odd GUID
+        let colliding = registry.register_static(0xDEAD_BEEF_0000_0000);
+        assert_eq!(colliding.value, door.value.wrapping_add(2));
+        assert!(registry.lookup(colliding).unwrap().is_static());
+
+        registry.remove(door);
+        assert_eq!(registry.find_static(0xDEAD_BEEF_0000_0001), None);
+    }
+
+    #[test]
+    fn test_acks_across_connections() {
+        let mut registry = NetworkGuidRegistry::new();
+        let pawn = registry.assign_new().unwrap();
+        let level = registry.register_static(7);
+        assert!(registry.set_debug_name(pawn, "BP_Pawn_C_0"));
+
+        assert!(registry.mark_acked(pawn, 1));
+        assert!(registry.mark_acked(pawn, 1));
+        assert!(registry.mark_acked(level, 2));
+        assert!(!registry.mark_acked(NetworkGUID::new(99), 1));
+
+        assert!(registry.is_acked(pawn, 1));
+        assert!(!registry.is_acked(pawn, 2));
+        assert!(registry.is_acked(level, 2));
+        assert!(!registry.is_acked(level, 1));
+        assert_eq!(registry.lookup(pawn).unwrap().acked_by, [1]);
+
+        registry.mark_acked(pawn, 2);
+        registry.remove_connection(1);
+        assert!(!registry.is_acked(pawn, 1));
+        assert!(registry.is_acked(pawn, 2));
+    }
+
+    #[test]
+    fn test_purge_unacked() {
+        let mut registry = NetworkGuidRegistry::new();
+        let acked = registry.assign_new().unwrap();
+        let stale = registry.assign_new().unwrap();
+        let fresh = registry.assign_new().unwrap();
+        registry.mark_acked(acked, 3);
+
+        let now = DateTime::now();
+        for guid in [acked, stale] {
+            registry.entries.find_mut(&guid).unwrap().created = now - Timespan::from_minutes(10.0);
+        }
+        assert_eq!(registry.purge_unacked_created_before(now - Timespan::from_minutes(5.0)), [stale]);
+        assert!(registry.lookup(acked).is_some() && registry.lookup(fresh).is_some());
+        assert!(registry.purge_unacked_older_than(Timespan::from_hours(1.0)).is_empty());
+
+        // Explicit clocks, as used without std
+        let then = now - Timespan::from_hours(2.0);
+        let old = registry.assign_new_at(then).unwrap();
+        assert_eq!(registry.lookup(old).unwrap().created, then);
+        assert_eq!(registry.purge_unacked_older_than_at(Timespan::from_hours(1.0), now), [old]);
+    }
+
+    #[test]
+    fn test_registry_serialization() {
+        let mut registry = NetworkGuidRegistry::new();
+        let pawn = registry.assign_new().unwrap();
+        let level = registry.register_static(0x1234_5678_9ABC_DEF0);
+        registry.set_debug_name(level, "PersistentLevel");
+        registry.mark_acked(pawn, 5);
+
+        let json = serde_json::to_string(&registry).unwrap();
+        let mut restored: NetworkGuidRegistry = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, registry);
+        assert_eq!(NetworkGuidRegistry::from_binary(&registry.to_binary().unwrap()).unwrap(), registry);
+        assert_eq!(restored.assign_new().unwrap(), registry.assign_new().unwrap());
+        assert_eq!(format!("{}", restored), "NetworkGuidRegistry(3 objects, 1 static)");
+
+        let tampered = json.replacen(&format!("\"value\":{}", level.value), "\"value\":2", 1);
+        let error = serde_json::from_str::<NetworkGuidRegistry>(&tampered).unwrap_err();
+        assert!(error.to_string().starts_with("NetworkGUID(2) is not valid"), "{}", error);
+
+        let mut odd_counter = serde_json::to_value(&registry).unwrap();
+        odd_counter["next_dynamic"] = 7.into();
+        let error = serde_json::from_value::<NetworkGuidRegistry>(odd_counter).unwrap_err();
+        assert!(error.to_string().starts_with("next dynamic NetworkGUID 7 is odd"), "{}", error);
+        assert_eq!(
+            NetGuidRegistryError::DynamicGuidsExhausted.to_string(),
+            "every dynamic NetworkGUID is in use"
+        );
+    }
+}
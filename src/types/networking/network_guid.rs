@@ -1,12 +1,25 @@
 //! Network GUID for identifying objects across the network
 
 use crate::BinarySerializable;
+use crate::types::UeTypesError;
 use serde::{Deserialize, Serialize};
-use std::fmt;
-use std::time::{SystemTime, UNIX_EPOCH};
+use core::fmt;
+use core::sync::atomic::{AtomicU32, Ordering};
+
+/// Next value handed out by `NetworkGUID::generate`
+static NEXT_NETWORK_GUID: AtomicU32 = AtomicU32::new(1);
+
+/// Claim the next value from `counter`, refusing to wrap back to 0
+fn take_next(counter: &AtomicU32) -> Result<u32, UeTypesError> {
+    counter
+        .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |value| value.checked_add(1))
+        .map_err(|_| UeTypesError::NetworkGuidsExhausted)
+}
 
 /// Network GUID for identifying objects across the network
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(feature = "rkyv", archive(check_bytes, compare(PartialEq)), archive_attr(derive(Debug)))]
 pub struct NetworkGUID {
     /// The unique identifier value
     pub value: u32,
@@ -20,6 +33,15 @@ impl fmt::Display for NetworkGUID {
 
 impl BinarySerializable for NetworkGUID {}
 
+impl crate::types::SerializedSize for NetworkGUID {
+    fn serialized_size(&self) -> usize {
+        4
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl crate::types::ZeroCopy for NetworkGUID {}
+
 impl NetworkGUID {
     /// Invalid/null network GUID
     pub const INVALID: Self = Self { value: 0 };
@@ -34,13 +56,23 @@ impl NetworkGUID {
         self.value != 0
     }
 
-    /// Generate a new GUID based on current time (simple implementation)
+    /// Generate a new process-unique GUID
+    ///
+    /// Values come from a process-wide atomic counter, so consecutive calls
+    /// never collide.
+    ///
+    /// # Panics
+    ///
+    /// Panics once all `u32::MAX - 1` values have been handed out, rather
+    /// than returning a GUID that is already in use. Use `try_generate` to
+    /// handle that case.
     pub fn generate() -> Self {
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_nanos() as u32;
-        Self::new(now)
+        Self::try_generate().expect("NetworkGUID::generate: every NetworkGUID value has been handed out")
+    }
+
+    /// Generate a new process-unique GUID, failing once the counter is used up
+    pub fn try_generate() -> Result<Self, UeTypesError> {
+        take_next(&NEXT_NETWORK_GUID).map(Self::new)
     }
 }
 
@@ -65,6 +97,30 @@ mod tests {
         assert_ne!(guid1, guid2);
     }
 
+    #[test]
+    fn test_generate_never_collides() {
+        let mut seen = std::collections::HashSet::new();
+        let mut previous = NetworkGUID::generate();
+        for _ in 0..1_000 {
+            let next = NetworkGUID::generate();
+            assert!(next.is_valid());
+            assert_ne!(next, previous);
+            assert!(seen.insert(next));
+            previous = next;
+        }
+    }
+
+    #[test]
+    fn test_exhausted_counter() {
+        let counter = AtomicU32::new(u32::MAX - 1);
+        assert_eq!(take_next(&counter), Ok(u32::MAX - 1));
+        assert_eq!(take_next(&counter), Err(UeTypesError::NetworkGuidsExhausted));
+        assert_eq!(take_next(&counter), Err(UeTypesError::NetworkGuidsExhausted));
+        assert_eq!(counter.load(Ordering::Relaxed), u32::MAX);
+
+        assert!(NetworkGUID::try_generate().unwrap().is_valid());
+    }
+
     #[test]
     fn test_json_serialization() {
         let guid = NetworkGUID::new(12345);
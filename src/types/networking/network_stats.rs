@@ -1,11 +1,28 @@
 //! Network statistics for monitoring connection quality
 
 use crate::BinarySerializable;
+use super::LatencyHistogram;
 use serde::{Deserialize, Serialize};
-use std::fmt;
+use alloc::collections::VecDeque;
+use core::fmt;
+#[cfg(not(feature = "std"))]
+use crate::types::math::float_math::FloatMath;
+
+/// Scale for packet loss stored as basis points (1 bp = 0.01%)
+pub(crate) const LOSS_SCALE: u32 = 10_000;
+/// Scale for ping/jitter stored as tenths of a millisecond
+const MS_SCALE: u32 = 10;
 
 /// Network statistics for monitoring connection quality
+///
+/// Packet loss, ping and jitter are stored as integer fixed-point values
+/// (basis points and tenths of a millisecond) so that aggregation is exact
+/// and serialized output is free of `f32` representation noise. The
+/// fixed-point fields are public like the rest; use the accessor/setter
+/// methods to work with them as floats.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(feature = "rkyv", archive(check_bytes, compare(PartialEq)), archive_attr(derive(Debug)))]
 pub struct NetworkStats {
     /// Packets sent per second
     pub packets_out_per_second: f32,
@@ -15,12 +32,15 @@ pub struct NetworkStats {
     pub bytes_out_per_second: f32,
     /// Bytes received per second
     pub bytes_in_per_second: f32,
-    /// Round trip time in milliseconds
-    pub ping: f32,
-    /// Packet loss percentage (0.0 to 1.0)
-    pub packet_loss: f32,
-    /// Network jitter in milliseconds
-    pub jitter: f32,
+    /// Round trip time in tenths of a millisecond
+    #[serde(rename = "ping", with = "tenths")]
+    pub ping_tenths: u32,
+    /// Packet loss in basis points (0 to 10000)
+    #[serde(rename = "packet_loss", with = "basis_points")]
+    pub packet_loss_bps: u32,
+    /// Network jitter in tenths of a millisecond
+    #[serde(rename = "jitter", with = "tenths")]
+    pub jitter_tenths: u32,
     /// Connection uptime in seconds
     pub uptime: f32,
 }
@@ -30,9 +50,9 @@ impl fmt::Display for NetworkStats {
         write!(
             f,
             "NetworkStats(Ping: {:.1}ms, Loss: {:.2}%, Jitter: {:.1}ms, Out: {:.1} B/s, In: {:.1} B/s)",
-            self.ping,
-            self.packet_loss * 100.0,
-            self.jitter,
+            self.ping(),
+            self.packet_loss() * 100.0,
+            self.jitter(),
             self.bytes_out_per_second,
             self.bytes_in_per_second
         )
@@ -41,6 +61,34 @@ impl fmt::Display for NetworkStats {
 
 impl BinarySerializable for NetworkStats {}
 
+/// Four `f32` rates, the three fixed-point integers and the `f32` uptime
+impl crate::types::SerializedSize for NetworkStats {
+    fn serialized_size(&self) -> usize {
+        32
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl crate::types::ZeroCopy for NetworkStats {}
+
+#[cfg(feature = "rkyv")]
+impl ArchivedNetworkStats {
+    /// Round trip time in milliseconds
+    pub fn ping(&self) -> f32 {
+        self.ping_tenths as f32 / MS_SCALE as f32
+    }
+
+    /// Packet loss as a fraction (0.0 to 1.0)
+    pub fn packet_loss(&self) -> f32 {
+        self.packet_loss_bps as f32 / LOSS_SCALE as f32
+    }
+
+    /// Network jitter in milliseconds
+    pub fn jitter(&self) -> f32 {
+        self.jitter_tenths as f32 / MS_SCALE as f32
+    }
+}
+
 impl NetworkStats {
     /// Create new network stats with default values
     pub fn new() -> Self {
@@ -49,25 +97,119 @@ impl NetworkStats {
             packets_in_per_second: 0.0,
             bytes_out_per_second: 0.0,
             bytes_in_per_second: 0.0,
-            ping: 0.0,
-            packet_loss: 0.0,
-            jitter: 0.0,
+            ping_tenths: 0,
+            packet_loss_bps: 0,
+            jitter_tenths: 0,
             uptime: 0.0,
         }
     }
 
-    /// Check if the connection quality is good
+    /// Round trip time in milliseconds
+    pub fn ping(&self) -> f32 {
+        self.ping_tenths as f32 / MS_SCALE as f32
+    }
+
+    /// Set the round trip time in milliseconds (rounded to 0.1ms)
+    pub fn set_ping(&mut self, ping_ms: f32) {
+        self.ping_tenths = to_fixed(ping_ms as f64, MS_SCALE);
+    }
+
+    /// Packet loss as a fraction (0.0 to 1.0)
+    pub fn packet_loss(&self) -> f32 {
+        self.packet_loss_bps as f32 / LOSS_SCALE as f32
+    }
+
+    /// Set the packet loss as a fraction (clamped to 0.0..=1.0, rounded to 1 bp)
+    pub fn set_packet_loss(&mut self, loss: f32) {
+        self.packet_loss_bps = to_fixed(loss as f64, LOSS_SCALE).min(LOSS_SCALE);
+    }
+
+    /// Network jitter in milliseconds
+    pub fn jitter(&self) -> f32 {
+        self.jitter_tenths as f32 / MS_SCALE as f32
+    }
+
+    /// Set the network jitter in milliseconds (rounded to 0.1ms)
+    pub fn set_jitter(&mut self, jitter_ms: f32) {
+        self.jitter_tenths = to_fixed(jitter_ms as f64, MS_SCALE);
+    }
+
+    /// Check if the connection quality is good (Good or better with the default thresholds)
     pub fn is_connection_good(&self) -> bool {
-        self.ping < 100.0 && self.packet_loss < 0.05 && self.jitter < 50.0
+        self.quality() >= ConnectionQuality::Good
+    }
+
+    /// Classify the connection using the default thresholds
+    pub fn quality(&self) -> ConnectionQuality {
+        self.quality_with(&ConnectionQualityThresholds::default())
+    }
+
+    /// Classify the connection using custom thresholds
+    pub fn quality_with(&self, thresholds: &ConnectionQualityThresholds) -> ConnectionQuality {
+        let tiers = [
+            (&thresholds.excellent, ConnectionQuality::Excellent),
+            (&thresholds.good, ConnectionQuality::Good),
+            (&thresholds.fair, ConnectionQuality::Fair),
+            (&thresholds.poor, ConnectionQuality::Poor),
+        ];
+        tiers
+            .into_iter()
+            .find(|(tier, _)| tier.accepts(self))
+            .map_or(ConnectionQuality::Bad, |(_, quality)| quality)
     }
 
     /// Get connection quality as a value from 0.0 (worst) to 1.0 (best)
     pub fn connection_quality(&self) -> f32 {
-        let ping_score = (1.0 - (self.ping / 200.0).min(1.0)).max(0.0);
-        let loss_score = (1.0 - (self.packet_loss * 20.0).min(1.0)).max(0.0);
-        let jitter_score = (1.0 - (self.jitter / 100.0).min(1.0)).max(0.0);
-        
-        (ping_score + loss_score + jitter_score) / 3.0
+        self.connection_quality_weighted(&ConnectionQualityWeights::default())
+    }
+
+    /// Get connection quality as a weighted value from 0.0 (worst) to 1.0 (best)
+    ///
+    /// Ping scores 0 at 200ms, packet loss at 5% and jitter at 100ms.
+    pub fn connection_quality_weighted(&self, weights: &ConnectionQualityWeights) -> f32 {
+        let ping_score = (1.0 - (self.ping() / 200.0).min(1.0)).max(0.0);
+        let loss_score = (1.0 - (self.packet_loss() * 20.0).min(1.0)).max(0.0);
+        let jitter_score = (1.0 - (self.jitter() / 100.0).min(1.0)).max(0.0);
+
+        let weights = weights.normalized();
+        ping_score * weights.ping + loss_score * weights.packet_loss + jitter_score * weights.jitter
+    }
+
+    /// Average a set of snapshots
+    ///
+    /// Ping, jitter and packet loss are averaged exactly in integer space and
+    /// rounded once; rate and uptime fields are averaged in `f64`.
+    /// Returns default stats for an empty slice.
+    pub fn average(samples: &[NetworkStats]) -> Self {
+        if samples.is_empty() {
+            return Self::new();
+        }
+
+        let count = samples.len() as u64;
+        let mean_fixed = |field: fn(&NetworkStats) -> u32| -> u32 {
+            let sum: u64 = samples.iter().map(|s| field(s) as u64).sum();
+            ((sum + count / 2) / count) as u32
+        };
+        let mean_float = |field: fn(&NetworkStats) -> f32| -> f32 {
+            let sum: f64 = samples.iter().map(|s| field(s) as f64).sum();
+            (sum / count as f64) as f32
+        };
+
+        Self {
+            packets_out_per_second: mean_float(|s| s.packets_out_per_second),
+            packets_in_per_second: mean_float(|s| s.packets_in_per_second),
+            bytes_out_per_second: mean_float(|s| s.bytes_out_per_second),
+            bytes_in_per_second: mean_float(|s| s.bytes_in_per_second),
+            ping_tenths: mean_fixed(|s| s.ping_tenths),
+            packet_loss_bps: mean_fixed(|s| s.packet_loss_bps),
+            jitter_tenths: mean_fixed(|s| s.jitter_tenths),
+            uptime: mean_float(|s| s.uptime),
+        }
+    }
+
+    /// Mean packet loss across a set of snapshots as a fraction
+    pub fn mean_packet_loss(samples: &[NetworkStats]) -> f32 {
+        Self::average(samples).packet_loss()
     }
 }
 
@@ -77,6 +219,470 @@ impl Default for NetworkStats {
     }
 }
 
+/// Connection quality tier, ordered from `Bad` (lowest) to `Excellent` (highest)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum ConnectionQuality {
+    /// Worse than every configured tier
+    Bad,
+    /// Within the poor tier limits
+    Poor,
+    /// Within the fair tier limits
+    Fair,
+    /// Within the good tier limits
+    Good,
+    /// Within the excellent tier limits
+    Excellent,
+}
+
+impl fmt::Display for ConnectionQuality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let quality_str = match self {
+            ConnectionQuality::Bad => "Bad",
+            ConnectionQuality::Poor => "Poor",
+            ConnectionQuality::Fair => "Fair",
+            ConnectionQuality::Good => "Good",
+            ConnectionQuality::Excellent => "Excellent",
+        };
+        write!(f, "{}", quality_str)
+    }
+}
+
+/// Exclusive upper limits a connection must stay under to reach a quality tier
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct QualityTier {
+    /// Ping must be below this many milliseconds
+    pub max_ping_ms: f32,
+    /// Packet loss must be below this fraction
+    pub max_packet_loss: f32,
+    /// Jitter must be below this many milliseconds
+    pub max_jitter_ms: f32,
+}
+
+impl QualityTier {
+    /// Create a tier from ping, packet loss and jitter limits
+    pub fn new(max_ping_ms: f32, max_packet_loss: f32, max_jitter_ms: f32) -> Self {
+        Self {
+            max_ping_ms,
+            max_packet_loss,
+            max_jitter_ms,
+        }
+    }
+
+    /// Check if every metric is below this tier's limits
+    pub fn accepts(&self, stats: &NetworkStats) -> bool {
+        stats.ping() < self.max_ping_ms
+            && stats.packet_loss() < self.max_packet_loss
+            && stats.jitter() < self.max_jitter_ms
+    }
+}
+
+/// Limits for each connection quality tier
+///
+/// Tiers are checked from excellent to poor and the first one whose limits
+/// are all met wins; anything else is `Bad`. The default `good` tier is the
+/// original `is_connection_good()` rule (100ms ping, 5% loss, 50ms jitter).
+/// Missing fields fall back to their defaults when deserializing.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConnectionQualityThresholds {
+    /// Limits for `ConnectionQuality::Excellent`
+    pub excellent: QualityTier,
+    /// Limits for `ConnectionQuality::Good`
+    pub good: QualityTier,
+    /// Limits for `ConnectionQuality::Fair`
+    pub fair: QualityTier,
+    /// Limits for `ConnectionQuality::Poor`
+    pub poor: QualityTier,
+}
+
+impl Default for ConnectionQualityThresholds {
+    fn default() -> Self {
+        Self {
+            excellent: QualityTier::new(50.0, 0.01, 20.0),
+            good: QualityTier::new(100.0, 0.05, 50.0),
+            fair: QualityTier::new(150.0, 0.10, 75.0),
+            poor: QualityTier::new(250.0, 0.20, 100.0),
+        }
+    }
+}
+
+/// Relative importance of ping, packet loss and jitter in `connection_quality_weighted`
+///
+/// Weights are normalized to sum to 1. Negative weights count as 0, and if
+/// every weight is 0 they are treated as equal.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConnectionQualityWeights {
+    /// Weight of the ping score
+    pub ping: f32,
+    /// Weight of the packet loss score
+    pub packet_loss: f32,
+    /// Weight of the jitter score
+    pub jitter: f32,
+}
+
+impl ConnectionQualityWeights {
+    /// Create weights for ping, packet loss and jitter
+    pub fn new(ping: f32, packet_loss: f32, jitter: f32) -> Self {
+        Self {
+            ping,
+            packet_loss,
+            jitter,
+        }
+    }
+
+    fn normalized(&self) -> Self {
+        let clean = |weight: f32| if weight > 0.0 { weight } else { 0.0 };
+        let (ping, packet_loss, jitter) = (clean(self.ping), clean(self.packet_loss), clean(self.jitter));
+        let total = ping + packet_loss + jitter;
+        if total <= 0.0 || !total.is_finite() {
+            return Self::new(1.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0);
+        }
+        Self::new(ping / total, packet_loss / total, jitter / total)
+    }
+}
+
+impl Default for ConnectionQualityWeights {
+    fn default() -> Self {
+        Self::new(1.0, 1.0, 1.0)
+    }
+}
+
+/// Builder for [`NetworkStatsTracker`]
+///
+/// Smoothing factors are the weight given to each new sample and are clamped
+/// to `(0, 1]`; 1 disables smoothing.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NetworkStatsTrackerBuilder {
+    ping_smoothing: f64,
+    jitter_smoothing: f64,
+    rate_smoothing: f64,
+    loss_window: usize,
+}
+
+impl NetworkStatsTrackerBuilder {
+    /// Start from the default settings
+    pub fn new() -> Self {
+        Self {
+            ping_smoothing: 0.125,
+            jitter_smoothing: 1.0 / 16.0,
+            rate_smoothing: 0.25,
+            loss_window: 100,
+        }
+    }
+
+    /// Weight of each ping sample in the smoothed ping (default 1/8, as TCP's SRTT)
+    pub fn ping_smoothing(mut self, factor: f64) -> Self {
+        self.ping_smoothing = factor;
+        self
+    }
+
+    /// Weight of each ping deviation in the jitter estimate (default 1/16, per RFC 3550)
+    pub fn jitter_smoothing(mut self, factor: f64) -> Self {
+        self.jitter_smoothing = factor;
+        self
+    }
+
+    /// Weight of each tick's packet and byte rates (default 1/4)
+    pub fn rate_smoothing(mut self, factor: f64) -> Self {
+        self.rate_smoothing = factor;
+        self
+    }
+
+    /// Number of most recent packets packet loss is measured over (default 100, at least 1)
+    pub fn loss_window(mut self, packets: usize) -> Self {
+        self.loss_window = packets;
+        self
+    }
+
+    /// Create the tracker
+    pub fn build(self) -> NetworkStatsTracker {
+        let clamp = |factor: f64| if factor.is_nan() { 1.0 } else { factor.clamp(f64::EPSILON, 1.0) };
+        NetworkStatsTracker {
+            ping_smoothing: clamp(self.ping_smoothing),
+            jitter_smoothing: clamp(self.jitter_smoothing),
+            rate_smoothing: clamp(self.rate_smoothing),
+            loss_window: self.loss_window.max(1),
+            ping: None,
+            last_ping_sample: None,
+            jitter: 0.0,
+            pending: RateCounters::default(),
+            rates: None,
+            recent_packets: VecDeque::new(),
+            lost_in_window: 0,
+            uptime: 0.0,
+            ping_histogram: LatencyHistogram::new(),
+            jitter_histogram: LatencyHistogram::new(),
+        }
+    }
+}
+
+impl Default for NetworkStatsTrackerBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Packet and byte counts, either accumulated or per second
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct RateCounters {
+    packets_out: f64,
+    packets_in: f64,
+    bytes_out: f64,
+    bytes_in: f64,
+}
+
+/// Accumulates raw connection events into smoothed [`NetworkStats`]
+///
+/// Ping and per-second rates are exponentially weighted moving averages,
+/// jitter is the smoothed ping deviation from RFC 3550, and packet loss is
+/// `lost / (lost + received)` over the most recent packets. Every ping
+/// sample and ping deviation also goes into a [`LatencyHistogram`], for
+/// percentiles that averages would hide.
+#[derive(Debug, Clone)]
+pub struct NetworkStatsTracker {
+    ping_smoothing: f64,
+    jitter_smoothing: f64,
+    rate_smoothing: f64,
+    loss_window: usize,
+    ping: Option<f64>,
+    last_ping_sample: Option<f64>,
+    jitter: f64,
+    pending: RateCounters,
+    rates: Option<RateCounters>,
+    /// Most recent packet outcomes, `true` for lost
+    recent_packets: VecDeque<bool>,
+    lost_in_window: usize,
+    uptime: f64,
+    ping_histogram: LatencyHistogram,
+    jitter_histogram: LatencyHistogram,
+}
+
+impl NetworkStatsTracker {
+    /// Create a tracker with default settings
+    pub fn new() -> Self {
+        NetworkStatsTrackerBuilder::new().build()
+    }
+
+    /// Configure a tracker
+    pub fn builder() -> NetworkStatsTrackerBuilder {
+        NetworkStatsTrackerBuilder::new()
+    }
+
+    /// Record an outgoing packet of `bytes` bytes
+    pub fn record_packet_sent(&mut self, bytes: usize) {
+        self.pending.packets_out += 1.0;
+        self.pending.bytes_out += bytes as f64;
+    }
+
+    /// Record an incoming packet of `bytes` bytes
+    pub fn record_packet_received(&mut self, bytes: usize) {
+        self.pending.packets_in += 1.0;
+        self.pending.bytes_in += bytes as f64;
+        self.push_packet_outcome(false);
+    }
+
+    /// Record a packet that was detected as lost
+    pub fn record_packet_lost(&mut self) {
+        self.push_packet_outcome(true);
+    }
+
+    /// Record a round trip time sample in milliseconds (negative or non-finite samples are ignored)
+    pub fn record_ping_sample(&mut self, ping_ms: f64) {
+        if !ping_ms.is_finite() || ping_ms < 0.0 {
+            return;
+        }
+        self.ping = Some(match self.ping {
+            Some(ping) => ping + self.ping_smoothing * (ping_ms - ping),
+            None => ping_ms,
+        });
+        self.ping_histogram.record(ping_ms as f32);
+        if let Some(last) = self.last_ping_sample {
+            let deviation = (ping_ms - last).abs();
+            self.jitter += self.jitter_smoothing * (deviation - self.jitter);
+            self.jitter_histogram.record(deviation as f32);
+        }
+        self.last_ping_sample = Some(ping_ms);
+    }
+
+    /// Every ping sample recorded since creation or the last reset
+    pub fn ping_histogram(&self) -> &LatencyHistogram {
+        &self.ping_histogram
+    }
+
+    /// Every difference between consecutive ping samples, the raw input to jitter
+    pub fn jitter_histogram(&self) -> &LatencyHistogram {
+        &self.jitter_histogram
+    }
+
+    /// 95th percentile ping in milliseconds (0 with no samples yet)
+    pub fn p95_ping(&self) -> f32 {
+        self.ping_histogram.percentile(95.0)
+    }
+
+    /// Advance time, folding the packets recorded since the last tick into the rates
+    ///
+    /// Non-positive or non-finite deltas are ignored.
+    pub fn tick(&mut self, delta_seconds: f64) {
+        if !delta_seconds.is_finite() || delta_seconds <= 0.0 {
+            return;
+        }
+        let pending = core::mem::take(&mut self.pending);
+        let current = RateCounters {
+            packets_out: pending.packets_out / delta_seconds,
+            packets_in: pending.packets_in / delta_seconds,
+            bytes_out: pending.bytes_out / delta_seconds,
+            bytes_in: pending.bytes_in / delta_seconds,
+        };
+        let alpha = self.rate_smoothing;
+        self.rates = Some(match self.rates {
+            Some(rates) => RateCounters {
+                packets_out: rates.packets_out + alpha * (current.packets_out - rates.packets_out),
+                packets_in: rates.packets_in + alpha * (current.packets_in - rates.packets_in),
+                bytes_out: rates.bytes_out + alpha * (current.bytes_out - rates.bytes_out),
+                bytes_in: rates.bytes_in + alpha * (current.bytes_in - rates.bytes_in),
+            },
+            None => current,
+        });
+        self.uptime += delta_seconds;
+    }
+
+    /// Packet loss over the current window as a fraction (0 with no packets yet)
+    pub fn packet_loss(&self) -> f64 {
+        if self.recent_packets.is_empty() {
+            return 0.0;
+        }
+        self.lost_in_window as f64 / self.recent_packets.len() as f64
+    }
+
+    /// Produce the current smoothed statistics
+    pub fn snapshot(&self) -> NetworkStats {
+        let rates = self.rates.unwrap_or_default();
+        let mut stats = NetworkStats {
+            packets_out_per_second: rates.packets_out as f32,
+            packets_in_per_second: rates.packets_in as f32,
+            bytes_out_per_second: rates.bytes_out as f32,
+            bytes_in_per_second: rates.bytes_in as f32,
+            uptime: self.uptime as f32,
+            ..NetworkStats::new()
+        };
+        stats.set_ping(self.ping.unwrap_or(0.0) as f32);
+        stats.set_jitter(self.jitter as f32);
+        stats.set_packet_loss(self.packet_loss() as f32);
+        stats
+    }
+
+    /// Forget every recorded sample, keeping the configuration
+    pub fn reset(&mut self) {
+        *self = NetworkStatsTrackerBuilder {
+            ping_smoothing: self.ping_smoothing,
+            jitter_smoothing: self.jitter_smoothing,
+            rate_smoothing: self.rate_smoothing,
+            loss_window: self.loss_window,
+        }
+        .build();
+    }
+
+    fn push_packet_outcome(&mut self, lost: bool) {
+        if self.recent_packets.len() == self.loss_window && self.recent_packets.pop_front() == Some(true) {
+            self.lost_in_window -= 1;
+        }
+        self.recent_packets.push_back(lost);
+        self.lost_in_window += lost as usize;
+    }
+}
+
+impl Default for NetworkStatsTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Convert a float value to fixed-point with the given scale (negative and NaN become 0)
+fn to_fixed(value: f64, scale: u32) -> u32 {
+    if value.is_nan() || value <= 0.0 {
+        return 0;
+    }
+    (value * scale as f64).round().min(u32::MAX as f64) as u32
+}
+
+/// Serde helpers for fixed-point fields
+///
+/// Human-readable formats (JSON) use the scaled float under the original
+/// field name and accept either integer or float numbers on input. Binary
+/// formats store the raw integer.
+mod fixed_point {
+    use serde::de::{self, Visitor};
+    use serde::{Deserialize, Deserializer, Serializer};
+    use core::fmt;
+
+    pub fn serialize<S: Serializer>(value: u32, scale: u32, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_f64(value as f64 / scale as f64)
+        } else {
+            serializer.serialize_u32(value)
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(scale: u32, deserializer: D) -> Result<u32, D::Error> {
+        if !deserializer.is_human_readable() {
+            return u32::deserialize(deserializer);
+        }
+
+        struct NumberVisitor;
+
+        impl Visitor<'_> for NumberVisitor {
+            type Value = f64;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "an integer or floating point number")
+            }
+
+            fn visit_f64<E: de::Error>(self, v: f64) -> Result<f64, E> {
+                Ok(v)
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<f64, E> {
+                Ok(v as f64)
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<f64, E> {
+                Ok(v as f64)
+            }
+        }
+
+        let value = deserializer.deserialize_f64(NumberVisitor)?;
+        Ok(super::to_fixed(value, scale))
+    }
+}
+
+/// Basis-point (1/10000) fixed-point serde for packet loss
+mod basis_points {
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &u32, serializer: S) -> Result<S::Ok, S::Error> {
+        super::fixed_point::serialize(*value, super::LOSS_SCALE, serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u32, D::Error> {
+        super::fixed_point::deserialize(super::LOSS_SCALE, deserializer)
+            .map(|bps| bps.min(super::LOSS_SCALE))
+    }
+}
+
+/// Tenths-of-a-millisecond fixed-point serde for ping and jitter
+mod tenths {
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &u32, serializer: S) -> Result<S::Ok, S::Error> {
+        super::fixed_point::serialize(*value, super::MS_SCALE, serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u32, D::Error> {
+        super::fixed_point::deserialize(super::MS_SCALE, deserializer)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -84,32 +690,319 @@ mod tests {
     #[test]
     fn test_network_stats() {
         let mut stats = NetworkStats::new();
-        stats.ping = 40.0;
-        stats.packet_loss = 0.01; // 1%
-        stats.jitter = 20.0;
+        stats.set_ping(40.0);
+        stats.set_packet_loss(0.01); // 1%
+        stats.set_jitter(20.0);
         
         assert!(stats.is_connection_good());
         assert!(stats.connection_quality() > 0.7);
         
-        stats.ping = 150.0;
-        stats.packet_loss = 0.1; // 10%
+        stats.set_ping(150.0);
+        stats.set_packet_loss(0.1); // 10%
         assert!(!stats.is_connection_good());
     }
 
     #[test]
     fn test_display_formatting() {
-        let stats = NetworkStats {
-            ping: 50.0,
-            packet_loss: 0.05,
-            jitter: 25.0,
+        let mut stats = NetworkStats {
             bytes_out_per_second: 1024.0,
             bytes_in_per_second: 2048.0,
             ..Default::default()
         };
+        stats.set_ping(50.0);
+        stats.set_packet_loss(0.05);
+        stats.set_jitter(25.0);
         
         let display_str = format!("{}", stats);
         assert!(display_str.contains("50.0ms"));
         assert!(display_str.contains("5.00%"));
         assert!(display_str.contains("25.0ms"));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_fixed_point_accessors() {
+        let mut stats = NetworkStats::new();
+        stats.set_packet_loss(0.0123);
+        stats.set_ping(42.37);
+        stats.set_jitter(-5.0);
+
+        assert_eq!(stats.packet_loss_bps, 123);
+        assert_eq!(stats.ping_tenths, 424);
+        assert_eq!(stats.jitter_tenths, 0);
+
+        stats.set_packet_loss(2.0);
+        assert_eq!(stats.packet_loss(), 1.0);
+    }
+
+    #[test]
+    fn test_struct_literal_with_fixed_point_fields() {
+        let stats = NetworkStats {
+            ping_tenths: 455,
+            packet_loss_bps: 250,
+            jitter_tenths: 120,
+            ..NetworkStats::new()
+        };
+        assert_eq!(stats.ping(), 45.5);
+        assert_eq!(stats.packet_loss(), 0.025);
+        assert_eq!(stats.jitter(), 12.0);
+    }
+
+    #[test]
+    fn test_old_format_json_deserializes() {
+        // Output of the previous f32-field representation
+        let json = r#"{"packets_out_per_second":30.0,"packets_in_per_second":30.0,"bytes_out_per_second":1024.0,"bytes_in_per_second":2048.0,"ping":45.5,"packet_loss":0.010000000707805157,"jitter":12,"uptime":60.0}"#;
+        let stats: NetworkStats = serde_json::from_str(json).unwrap();
+
+        assert_eq!(stats.ping_tenths, 455);
+        assert_eq!(stats.packet_loss_bps, 100);
+        assert_eq!(stats.jitter_tenths, 120);
+        assert_eq!(stats.packet_loss(), 0.01);
+    }
+
+    #[test]
+    fn test_json_output_is_clean() {
+        let mut stats = NetworkStats {
+            packets_out_per_second: 30.0,
+            packets_in_per_second: 30.0,
+            bytes_out_per_second: 1024.0,
+            bytes_in_per_second: 2048.0,
+            uptime: 60.0,
+            ..Default::default()
+        };
+        stats.set_ping(45.5);
+        stats.set_packet_loss(0.01);
+        stats.set_jitter(12.0);
+
+        let json = serde_json::to_string(&stats).unwrap();
+        assert_eq!(
+            json,
+            r#"{"packets_out_per_second":30.0,"packets_in_per_second":30.0,"bytes_out_per_second":1024.0,"bytes_in_per_second":2048.0,"ping":45.5,"packet_loss":0.01,"jitter":12.0,"uptime":60.0}"#
+        );
+
+        let deserialized: NetworkStats = serde_json::from_str(&json).unwrap();
+        assert_eq!(stats, deserialized);
+    }
+
+    #[test]
+    fn test_binary_serialization() {
+        let mut stats = NetworkStats::new();
+        stats.set_ping(33.3);
+        stats.set_packet_loss(0.0042);
+        stats.set_jitter(7.5);
+
+        let binary = stats.to_binary().unwrap();
+        let deserialized = NetworkStats::from_binary(&binary).unwrap();
+        assert_eq!(stats, deserialized);
+    }
+
+    #[test]
+    fn test_aggregation_is_exact() {
+        let mut stats = NetworkStats::new();
+        stats.set_ping(42.1);
+        stats.set_packet_loss(0.01);
+        stats.set_jitter(3.3);
+
+        let samples = vec![stats; 10_000];
+        let average = NetworkStats::average(&samples);
+
+        assert_eq!(NetworkStats::mean_packet_loss(&samples), 0.01);
+        assert_eq!(average.packet_loss_bps, 100);
+        assert_eq!(average.ping_tenths, 421);
+        assert_eq!(average.jitter_tenths, 33);
+        assert_eq!(NetworkStats::average(&[]), NetworkStats::new());
+    }
+
+    #[test]
+    fn test_tracker_ping_and_jitter_converge() {
+        let mut tracker = NetworkStatsTracker::new();
+        for _ in 0..200 {
+            tracker.record_ping_sample(50.0);
+        }
+        let stats = tracker.snapshot();
+        assert_eq!(stats.ping(), 50.0);
+        assert_eq!(stats.jitter(), 0.0);
+
+        // Alternating samples deviate by 20ms every time
+        for i in 0..400 {
+            tracker.record_ping_sample(if i % 2 == 0 { 40.0 } else { 60.0 });
+        }
+        let stats = tracker.snapshot();
+        assert!((stats.jitter() - 20.0).abs() <= 0.1);
+        assert!((stats.ping() - 50.0).abs() <= 1.5);
+
+        // Invalid samples are ignored
+        tracker.record_ping_sample(f64::NAN);
+        tracker.record_ping_sample(-5.0);
+        assert_eq!(tracker.snapshot(), stats);
+    }
+
+    #[test]
+    fn test_tracker_rates_converge() {
+        let mut tracker = NetworkStatsTracker::builder().rate_smoothing(0.5).build();
+        for _ in 0..40 {
+            for _ in 0..30 {
+                tracker.record_packet_sent(100);
+                tracker.record_packet_received(250);
+            }
+            tracker.tick(0.5);
+        }
+        let stats = tracker.snapshot();
+        assert!((stats.packets_out_per_second - 60.0).abs() < 1e-3);
+        assert!((stats.bytes_out_per_second - 6_000.0).abs() < 1e-2);
+        assert!((stats.bytes_in_per_second - 15_000.0).abs() < 1e-2);
+        assert_eq!(stats.uptime, 20.0);
+
+        // A silent tick halves the rates with a 0.5 smoothing factor
+        tracker.tick(0.5);
+        assert!((tracker.snapshot().packets_in_per_second - 30.0).abs() < 1e-3);
+        tracker.tick(0.0);
+        assert_eq!(tracker.snapshot().uptime, 20.5);
+    }
+
+    #[test]
+    fn test_tracker_loss_window() {
+        let mut tracker = NetworkStatsTracker::builder().loss_window(100).build();
+        for i in 0..100 {
+            if i % 20 == 0 {
+                tracker.record_packet_lost();
+            } else {
+                tracker.record_packet_received(64);
+            }
+        }
+        assert_eq!(tracker.snapshot().packet_loss_bps, 500);
+
+        // Losses slide out of the window
+        for _ in 0..100 {
+            tracker.record_packet_received(64);
+        }
+        assert_eq!(tracker.packet_loss(), 0.0);
+    }
+
+    #[test]
+    fn test_tracker_connection_quality_reacts() {
+        let mut tracker = NetworkStatsTracker::builder().loss_window(50).build();
+        for _ in 0..100 {
+            tracker.record_ping_sample(30.0);
+            tracker.record_packet_received(100);
+        }
+        let good = tracker.snapshot();
+        assert!(good.is_connection_good());
+        assert!(good.connection_quality() > 0.9);
+
+        for i in 0..100 {
+            tracker.record_ping_sample(if i % 2 == 0 { 150.0 } else { 250.0 });
+            if i % 4 == 0 {
+                tracker.record_packet_lost();
+            } else {
+                tracker.record_packet_received(100);
+            }
+        }
+        let bad = tracker.snapshot();
+        assert!(!bad.is_connection_good());
+        assert!(bad.connection_quality() < 0.3);
+
+        tracker.reset();
+        assert_eq!(tracker.snapshot(), NetworkStats::new());
+    }
+
+    fn stats_with(ping: f32, loss: f32, jitter: f32) -> NetworkStats {
+        let mut stats = NetworkStats::new();
+        stats.set_ping(ping);
+        stats.set_packet_loss(loss);
+        stats.set_jitter(jitter);
+        stats
+    }
+
+    #[test]
+    fn test_quality_tier_boundaries() {
+        let thresholds = ConnectionQualityThresholds::default();
+        let tiers = [
+            (thresholds.excellent, ConnectionQuality::Excellent, ConnectionQuality::Good),
+            (thresholds.good, ConnectionQuality::Good, ConnectionQuality::Fair),
+            (thresholds.fair, ConnectionQuality::Fair, ConnectionQuality::Poor),
+            (thresholds.poor, ConnectionQuality::Poor, ConnectionQuality::Bad),
+        ];
+
+        for (tier, inside, outside) in tiers {
+            let (ping, loss, jitter) = (tier.max_ping_ms, tier.max_packet_loss, tier.max_jitter_ms);
+            // Just inside on every metric
+            assert_eq!(stats_with(ping - 0.1, loss - 0.0001, jitter - 0.1).quality(), inside);
+            // Exactly at any single limit drops to the next tier
+            assert_eq!(stats_with(ping, loss - 0.0001, jitter - 0.1).quality(), outside);
+            assert_eq!(stats_with(ping - 0.1, loss, jitter - 0.1).quality(), outside);
+            assert_eq!(stats_with(ping - 0.1, loss - 0.0001, jitter).quality(), outside);
+        }
+    }
+
+    #[test]
+    fn test_is_connection_good_matches_original_rule() {
+        assert!(stats_with(99.9, 0.0499, 49.9).is_connection_good());
+        assert!(!stats_with(100.0, 0.0, 0.0).is_connection_good());
+        assert!(!stats_with(0.0, 0.05, 0.0).is_connection_good());
+        assert!(!stats_with(0.0, 0.0, 50.0).is_connection_good());
+        assert!(stats_with(10.0, 0.0, 1.0).is_connection_good());
+    }
+
+    #[test]
+    fn test_custom_thresholds() {
+        // A region where 150ms is still considered good
+        let oce = ConnectionQualityThresholds {
+            good: QualityTier::new(160.0, 0.05, 50.0),
+            ..Default::default()
+        };
+        let stats = stats_with(150.0, 0.01, 10.0);
+        assert_eq!(stats.quality(), ConnectionQuality::Poor);
+        assert_eq!(stats.quality_with(&oce), ConnectionQuality::Good);
+        assert!(ConnectionQuality::Good > ConnectionQuality::Fair);
+        assert_eq!(format!("{}", ConnectionQuality::Excellent), "Excellent");
+
+        let json = r#"{"good":{"max_ping_ms":160.0,"max_packet_loss":0.05,"max_jitter_ms":50.0}}"#;
+        let loaded: ConnectionQualityThresholds = serde_json::from_str(json).unwrap();
+        assert_eq!(loaded, oce);
+        let round_trip: ConnectionQualityThresholds =
+            serde_json::from_str(&serde_json::to_string(&oce).unwrap()).unwrap();
+        assert_eq!(round_trip, oce);
+    }
+
+    #[test]
+    fn test_weighted_connection_quality() {
+        let stats = stats_with(200.0, 0.0, 0.0);
+        assert!((stats.connection_quality() - 2.0 / 3.0).abs() < 1e-6);
+
+        let ping_only = ConnectionQualityWeights::new(1.0, 0.0, 0.0);
+        assert_eq!(stats.connection_quality_weighted(&ping_only), 0.0);
+        let ignore_ping = ConnectionQualityWeights::new(0.0, 2.0, 2.0);
+        assert_eq!(stats.connection_quality_weighted(&ignore_ping), 1.0);
+
+        // Degenerate weights fall back to equal weighting
+        let zero = ConnectionQualityWeights::new(0.0, -1.0, 0.0);
+        assert_eq!(stats.connection_quality_weighted(&zero), stats.connection_quality());
+    }
+
+    #[test]
+    fn test_tracker_ping_percentiles() {
+        let mut tracker = NetworkStatsTracker::new();
+        // Steady 40ms with a 250ms spike every 20th sample
+        for i in 0..200 {
+            tracker.record_ping_sample(if i % 20 == 19 { 250.0 } else { 40.0 });
+        }
+        tracker.record_ping_sample(f64::NAN);
+
+        assert_eq!(tracker.ping_histogram().count(), 200);
+        assert!((tracker.ping_histogram().percentile(50.0) - 40.0).abs() < 4.0);
+        assert!((tracker.p95_ping() - 40.0).abs() < 4.0);
+        assert!((tracker.ping_histogram().percentile(99.0) - 250.0).abs() < 25.0);
+        // The smoothed ping sits well below the spikes
+        assert!(tracker.snapshot().ping() < 100.0);
+
+        // Each spike is a 210ms jump up and then down again
+        let jitter = tracker.jitter_histogram();
+        assert_eq!(jitter.count(), 199);
+        assert!(jitter.percentile(50.0) < 1.0);
+        assert!((jitter.percentile(99.0) - 210.0).abs() < 21.0);
+
+        tracker.reset();
+        assert!(tracker.ping_histogram().is_empty() && tracker.jitter_histogram().is_empty());
+        assert_eq!(tracker.p95_ping(), 0.0);
+    }
+}
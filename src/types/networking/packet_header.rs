@@ -0,0 +1,417 @@
+//! UDP packet headers and wrap-aware sequence number tracking
+//!
+//! Sequence numbers are 16 bits and wrap around; `a` counts as newer than `b`
+//! when it is ahead by less than half the range (`sequence_greater_than`).
+
+use crate::BinarySerializable;
+use super::{BitReader, BitWriter, BitstreamError, SerializeBits};
+use serde::{Deserialize, Serialize};
+use core::fmt;
+
+/// Number of older packets acknowledged by `PacketHeader::ack_bits`
+pub const ACK_BITS_WINDOW: u16 = 32;
+
+/// Check if sequence `a` is newer than `b`, allowing for wrap-around (65535 < 0)
+///
+/// Sequences exactly half the range apart are neither newer nor older.
+pub fn sequence_greater_than(a: u16, b: u16) -> bool {
+    sequence_difference(a, b) > 0
+}
+
+/// Check if sequence `a` is older than `b`, allowing for wrap-around
+pub fn sequence_less_than(a: u16, b: u16) -> bool {
+    sequence_greater_than(b, a)
+}
+
+/// Signed distance from `b` to `a`, in [-32768, 32767]
+pub fn sequence_difference(a: u16, b: u16) -> i32 {
+    a.wrapping_sub(b) as i16 as i32
+}
+
+/// Header prepended to every packet
+///
+/// `ack` is the newest sequence received from the other side; bit `n` of
+/// `ack_bits` acknowledges `ack - 1 - n`. Until the first packet arrives
+/// `has_ack` is false and the header acknowledges nothing. Has a fixed
+/// 10-byte little-endian layout (`to_bytes`), which is also what
+/// `SerializeBits` and bincode produce.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct PacketHeader {
+    /// Sequence number of this packet
+    pub sequence: u16,
+    /// Newest sequence number received from the remote side
+    pub ack: u16,
+    /// Which of the 32 packets before `ack` were received
+    pub ack_bits: u32,
+    /// Channel the packet belongs to
+    pub channel: u8,
+    /// Whether anything has been received; `ack` and `ack_bits` mean nothing without it
+    pub has_ack: bool,
+}
+
+impl fmt::Display for PacketHeader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.has_ack {
+            return write!(f, "PacketHeader(Seq: {}, Ack: None, Channel: {})", self.sequence, self.channel);
+        }
+        write!(
+            f,
+            "PacketHeader(Seq: {}, Ack: {}, AckBits: {:#010x}, Channel: {})",
+            self.sequence, self.ack, self.ack_bits, self.channel
+        )
+    }
+}
+
+impl BinarySerializable for PacketHeader {}
+
+impl PacketHeader {
+    /// Size of the encoded header in bytes
+    pub const SIZE: usize = 10;
+
+    /// Create a header acknowledging `ack` and the packets set in `ack_bits`
+    pub fn new(sequence: u16, ack: u16, ack_bits: u32, channel: u8) -> Self {
+        Self {
+            sequence,
+            ack,
+            ack_bits,
+            channel,
+            has_ack: true,
+        }
+    }
+
+    /// Create a header that acknowledges nothing, for before any packet arrived
+    pub fn without_acks(sequence: u16, channel: u8) -> Self {
+        Self {
+            sequence,
+            channel,
+            ..Self::default()
+        }
+    }
+
+    /// Create a header acknowledging everything `received` has seen
+    pub fn with_acks(sequence: u16, channel: u8, received: &SequenceBuffer) -> Self {
+        match received.latest() {
+            Some(latest) => Self::new(sequence, latest, received.ack_bits(), channel),
+            None => Self::without_acks(sequence, channel),
+        }
+    }
+
+    /// Check if this header acknowledges `sequence`
+    pub fn acknowledges(&self, sequence: u16) -> bool {
+        if !self.has_ack {
+            return false;
+        }
+        if sequence == self.ack {
+            return true;
+        }
+        let behind = self.ack.wrapping_sub(sequence);
+        (1..=ACK_BITS_WINDOW).contains(&behind) && self.ack_bits & (1 << (behind - 1)) != 0
+    }
+
+    /// Encode as sequence, ack, ack_bits, channel, has_ack, little-endian
+    pub fn to_bytes(&self) -> [u8; Self::SIZE] {
+        let mut bytes = [0; Self::SIZE];
+        bytes[0..2].copy_from_slice(&self.sequence.to_le_bytes());
+        bytes[2..4].copy_from_slice(&self.ack.to_le_bytes());
+        bytes[4..8].copy_from_slice(&self.ack_bits.to_le_bytes());
+        bytes[8] = self.channel;
+        bytes[9] = self.has_ack as u8;
+        bytes
+    }
+
+    /// Decode from the start of a packet
+    ///
+    /// Returns `None` if it is shorter than `SIZE` or the `has_ack` byte isn't 0 or 1.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let bytes: &[u8; Self::SIZE] = bytes.get(..Self::SIZE)?.try_into().ok()?;
+        let has_ack = match bytes[9] {
+            0 => false,
+            1 => true,
+            _ => return None,
+        };
+        Some(Self {
+            sequence: u16::from_le_bytes([bytes[0], bytes[1]]),
+            ack: u16::from_le_bytes([bytes[2], bytes[3]]),
+            ack_bits: u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]),
+            channel: bytes[8],
+            has_ack,
+        })
+    }
+}
+
+impl SerializeBits for PacketHeader {
+    fn write_bits(&self, writer: &mut BitWriter) {
+        writer.write_bits(self.sequence as u32, 16);
+        writer.write_bits(self.ack as u32, 16);
+        writer.write_bits(self.ack_bits, 32);
+        writer.write_bits(self.channel as u32, 8);
+        writer.write_bits(self.has_ack as u32, 8);
+    }
+
+    fn read_bits(reader: &mut BitReader<'_>) -> Result<Self, BitstreamError> {
+        if reader.remaining_bits() < Self::SIZE * 8 {
+            return Err(BitstreamError::Overrun {
+                requested: Self::SIZE * 8,
+                remaining: reader.remaining_bits(),
+            });
+        }
+        let sequence = reader.read_bits(16)? as u16;
+        let ack = reader.read_bits(16)? as u16;
+        let ack_bits = reader.read_bits(32)?;
+        let channel = reader.read_bits(8)? as u8;
+        let has_ack = match reader.read_bits(8)? {
+            0 => false,
+            1 => true,
+            _ => return Err(BitstreamError::InvalidValue("packet header ack flag")),
+        };
+        Ok(Self {
+            sequence,
+            ack,
+            ack_bits,
+            channel,
+            has_ack,
+        })
+    }
+}
+
+/// What `SequenceBuffer::insert` made of a sequence number
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SequenceStatus {
+    /// First time this sequence was seen; process the packet
+    New,
+    /// Already received; drop the packet
+    Duplicate,
+    /// Too far behind the newest sequence to tell; drop the packet
+    Stale,
+}
+
+/// Receive-side record of which sequence numbers arrived, for building acks
+///
+/// Remembers the newest sequence and the 32 before it, which is exactly
+/// what a `PacketHeader` can acknowledge. Anything older is reported as
+/// `Stale`, since it can no longer be told apart from a duplicate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct SequenceBuffer {
+    latest: Option<u16>,
+    received_bits: u32,
+}
+
+impl fmt::Display for SequenceBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.latest {
+            Some(latest) => write!(f, "SequenceBuffer(Latest: {}, Bits: {:#010x})", latest, self.received_bits),
+            None => write!(f, "SequenceBuffer(Empty)"),
+        }
+    }
+}
+
+impl SequenceBuffer {
+    /// Create a buffer that hasn't received anything
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Newest sequence received, if any
+    pub fn latest(&self) -> Option<u16> {
+        self.latest
+    }
+
+    /// Record a received sequence number
+    pub fn insert(&mut self, sequence: u16) -> SequenceStatus {
+        let Some(latest) = self.latest else {
+            self.latest = Some(sequence);
+            return SequenceStatus::New;
+        };
+
+        if sequence_greater_than(sequence, latest) {
+            // The old latest becomes bit `ahead - 1`; older history shifts along with it
+            let ahead = sequence.wrapping_sub(latest);
+            self.received_bits = if ahead > ACK_BITS_WINDOW {
+                0
+            } else {
+                (((self.received_bits as u64) << ahead) | (1 << (ahead - 1))) as u32
+            };
+            self.latest = Some(sequence);
+            return SequenceStatus::New;
+        }
+
+        let behind = latest.wrapping_sub(sequence);
+        if behind == 0 {
+            return SequenceStatus::Duplicate;
+        }
+        if behind > ACK_BITS_WINDOW {
+            return SequenceStatus::Stale;
+        }
+        let bit = 1 << (behind - 1);
+        if self.received_bits & bit != 0 {
+            return SequenceStatus::Duplicate;
+        }
+        self.received_bits |= bit;
+        SequenceStatus::New
+    }
+
+    /// Check if a sequence within the ack window was received
+    pub fn is_received(&self, sequence: u16) -> bool {
+        PacketHeader::with_acks(0, 0, self).acknowledges(sequence)
+    }
+
+    /// Value for `PacketHeader::ack`, `None` before anything arrived
+    pub fn ack(&self) -> Option<u16> {
+        self.latest
+    }
+
+    /// Value for `PacketHeader::ack_bits`
+    pub fn ack_bits(&self) -> u32 {
+        self.received_bits
+    }
+
+    /// Forget everything received
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::RandomStream;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_wraparound_comparisons() {
+        assert!(sequence_greater_than(1, 0));
+        assert!(sequence_greater_than(0, 65535));
+        assert!(sequence_greater_than(10, 65530));
+        assert!(!sequence_greater_than(65535, 0));
+        assert!(!sequence_greater_than(7, 7));
+        assert!(sequence_less_than(65535, 0));
+        assert!(sequence_greater_than(32767, 0) && !sequence_greater_than(32768, 0));
+        assert!(!sequence_greater_than(0, 32768));
+
+        assert_eq!(sequence_difference(0, 65535), 1);
+        assert_eq!(sequence_difference(65530, 4), -10);
+        assert_eq!(sequence_difference(100, 100), 0);
+    }
+
+    #[test]
+    fn test_insert_across_wrap() {
+        let mut buffer = SequenceBuffer::new();
+        assert!(!buffer.is_received(0));
+        assert_eq!(buffer.insert(65534), SequenceStatus::New);
+        assert_eq!(buffer.insert(0), SequenceStatus::New);
+        assert_eq!(buffer.insert(65535), SequenceStatus::New);
+        assert_eq!(buffer.insert(65535), SequenceStatus::Duplicate);
+        assert_eq!(buffer.insert(0), SequenceStatus::Duplicate);
+        assert_eq!((buffer.ack(), buffer.ack_bits()), (Some(0), 0b11));
+
+        assert_eq!(buffer.insert(3), SequenceStatus::New);
+        assert_eq!((buffer.ack(), buffer.ack_bits()), (Some(3), 0b11100));
+        assert!(buffer.is_received(65534) && !buffer.is_received(1));
+
+        // Exactly 32 behind still fits, 33 is stale
+        assert_eq!(buffer.insert(3u16.wrapping_sub(32)), SequenceStatus::New);
+        assert_eq!(buffer.insert(3u16.wrapping_sub(33)), SequenceStatus::Stale);
+        assert_eq!(buffer.ack_bits() >> 31, 1);
+
+        // A jump of a whole window keeps only the old latest; a longer one clears the history
+        assert_eq!(buffer.insert(35), SequenceStatus::New);
+        assert_eq!(buffer.ack_bits(), 1 << 31);
+        assert_eq!(buffer.insert(100), SequenceStatus::New);
+        assert_eq!(buffer.ack_bits(), 0);
+        assert_eq!(buffer.insert(40), SequenceStatus::Stale);
+    }
+
+    #[test]
+    fn test_header_layout() {
+        let header = PacketHeader::new(0xBEEF, 0x1234, 0x8000_0001, 3);
+        let bytes = header.to_bytes();
+        assert_eq!(bytes, [0xEF, 0xBE, 0x34, 0x12, 0x01, 0x00, 0x00, 0x80, 3, 1]);
+        assert_eq!(PacketHeader::from_bytes(&bytes), Some(header));
+        assert_eq!(PacketHeader::from_bytes(&bytes[..9]), None);
+        assert_eq!(header.to_binary().unwrap(), bytes);
+
+        let mut writer = BitWriter::new();
+        writer.write(&header);
+        assert_eq!(writer.into_bytes(), bytes);
+        assert_eq!(BitReader::new(&bytes).read::<PacketHeader>().unwrap(), header);
+        assert!(matches!(
+            BitReader::new(&bytes[..4]).read::<PacketHeader>(),
+            Err(BitstreamError::Overrun { requested: 80, remaining: 32 })
+        ));
+
+        assert!(header.acknowledges(0x1234));
+        assert!(header.acknowledges(0x1233));
+        assert!(header.acknowledges(0x1234 - 32));
+        assert!(!header.acknowledges(0x1232));
+        assert!(!header.acknowledges(0x1235));
+        assert_eq!(
+            format!("{}", header),
+            "PacketHeader(Seq: 48879, Ack: 4660, AckBits: 0x80000001, Channel: 3)"
+        );
+    }
+
+    #[test]
+    fn test_empty_buffer_acknowledges_nothing() {
+        let buffer = SequenceBuffer::new();
+        assert_eq!(buffer.ack(), None);
+        assert!(!buffer.is_received(0));
+
+        let header = PacketHeader::with_acks(5, 2, &buffer);
+        assert_eq!(header, PacketHeader::without_acks(5, 2));
+        assert!(!header.has_ack);
+        assert!((0..=u16::MAX).all(|sequence| !header.acknowledges(sequence)));
+        assert_eq!(format!("{}", header), "PacketHeader(Seq: 5, Ack: None, Channel: 2)");
+
+        // The flag survives every encoding, and a corrupt flag byte is rejected
+        let bytes = header.to_bytes();
+        assert_eq!(bytes, [5, 0, 0, 0, 0, 0, 0, 0, 2, 0]);
+        assert_eq!(PacketHeader::from_bytes(&bytes), Some(header));
+        assert_eq!(header.to_binary().unwrap(), bytes);
+        assert_eq!(BitReader::new(&bytes).read::<PacketHeader>().unwrap(), header);
+        let mut corrupt = bytes;
+        corrupt[9] = 2;
+        assert_eq!(PacketHeader::from_bytes(&corrupt), None);
+        assert!(BitReader::new(&corrupt).read::<PacketHeader>().is_err());
+
+        // Once sequence 0 arrives it is acknowledged
+        let mut buffer = buffer;
+        buffer.insert(0);
+        assert!(PacketHeader::with_acks(6, 2, &buffer).acknowledges(0));
+    }
+
+    #[test]
+    fn test_shuffled_delivery_reconstructs_acks() {
+        // 10k packets starting near the top of the range, so they wrap
+        let first: u16 = 60_000;
+        let count = 10_000;
+        let mut stream = RandomStream::new(1807);
+        for _ in 0..4 {
+            let mut order: Vec<u16> = (0..count).map(|i| first.wrapping_add(i)).collect();
+            for i in (1..order.len()).rev() {
+                order.swap(i, stream.rand_range(0, i as i32) as usize);
+            }
+
+            let mut buffer = SequenceBuffer::new();
+            let mut delivered = HashSet::new();
+            for &sequence in &order {
+                match buffer.insert(sequence) {
+                    SequenceStatus::New => assert!(delivered.insert(sequence)),
+                    SequenceStatus::Stale => {}
+                    SequenceStatus::Duplicate => panic!("{} reported as duplicate", sequence),
+                }
+                // A replay is never accepted twice
+                assert_ne!(buffer.insert(sequence), SequenceStatus::New);
+
+                // Every bit in the window matches what actually arrived
+                let header = PacketHeader::with_acks(0, 0, &buffer);
+                for behind in 1..=ACK_BITS_WINDOW {
+                    let older = header.ack.wrapping_sub(behind);
+                    assert_eq!(header.acknowledges(older), delivered.contains(&older), "{}", buffer);
+                }
+            }
+
+            assert_eq!(buffer.ack(), Some(first.wrapping_add(count - 1)));
+            assert_eq!(buffer.ack_bits(), u32::MAX);
+        }
+    }
+}
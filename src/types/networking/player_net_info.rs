@@ -1,10 +1,19 @@
 //! Player network information for game servers
 
 use crate::BinarySerializable;
-use super::{NetworkGUID, NetworkStats};
+use crate::types::{SerializedSize, VARIANT_TAG_SIZE};
+use super::{NetworkGUID, NetworkStats, ValidationError};
 use serde::{Deserialize, Serialize};
-use std::fmt;
-use std::time::{SystemTime, UNIX_EPOCH};
+use core::fmt;
+use core::net::{IpAddr, Ipv4Addr, SocketAddr};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use alloc::format;
+#[cfg(feature = "std")]
+use super::game_session_info::current_unix_seconds;
+#[cfg(feature = "std")]
+use crate::types::{Clock, SystemClock};
 
 /// Player network role enumeration
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -52,18 +61,33 @@ pub struct PlayerNetInfo {
     /// Time when the player joined (Unix timestamp)
     pub join_time: u64,
     /// Player's IP address (for admin purposes)
-    pub ip_address: String,
+    ///
+    /// Serialized as a plain string such as `"192.168.1.100"` or `"::1"`, so
+    /// payloads from before this was typed still load; invalid addresses are
+    /// a deserialization error.
+    pub ip_address: IpAddr,
+    /// Player's remote port, if known
+    #[serde(default)]
+    pub port: Option<u16>,
 }
 
+/// Formats the address in full, or with the alternate flag (`{:#}`) in
+/// privacy mode, masking the last octet (or IPv6 group) for logs.
 impl fmt::Display for PlayerNetInfo {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let address = if f.alternate() {
+            self.masked_ip_address()
+        } else {
+            self.ip_address.to_string()
+        };
         write!(
             f,
-            "PlayerNetInfo(ID: {}, Name: \"{}\", Role: {}, Connected: {}, {})",
+            "PlayerNetInfo(ID: {}, Name: \"{}\", Role: {}, Connected: {}, IP: {}, {})",
             self.player_id,
             self.player_name,
             self.role,
             self.is_connected,
+            address,
             self.net_stats
         )
     }
@@ -71,9 +95,54 @@ impl fmt::Display for PlayerNetInfo {
 
 impl BinarySerializable for PlayerNetInfo {}
 
+impl SerializedSize for PlayerNetInfo {
+    fn serialized_size(&self) -> usize {
+        self.player_id.serialized_size()
+            + self.player_name.serialized_size()
+            + self.net_stats.serialized_size()
+            + self.is_connected.serialized_size()
+            + self.team_id.serialized_size()
+            + VARIANT_TAG_SIZE // role
+            + self.join_time.serialized_size()
+            + self.ip_address.serialized_size()
+            + self.port.serialized_size()
+    }
+}
+
 impl PlayerNetInfo {
-    /// Create new player network information
+    /// Create new player network information from an address string
+    ///
+    /// An address that doesn't parse is stored as the unspecified address
+    /// `0.0.0.0`, which `Validate` rejects.
+    #[cfg(feature = "std")]
+    #[deprecated(note = "stores an unparseable address as 0.0.0.0; use `try_new` or `new_with_addr`")]
     pub fn new(player_id: NetworkGUID, player_name: String, ip_address: String) -> Self {
+        let addr = ip_address.trim().parse().unwrap_or(IpAddr::V4(Ipv4Addr::UNSPECIFIED));
+        Self::new_with_addr(player_id, player_name, addr)
+    }
+
+    /// Create new player network information from an address string, joining now
+    ///
+    /// Surrounding whitespace is ignored. Use `new_with_addr` when the
+    /// address is already parsed.
+    #[cfg(feature = "std")]
+    pub fn try_new(player_id: NetworkGUID, player_name: String, ip_address: &str) -> Result<Self, core::net::AddrParseError> {
+        Ok(Self::new_with_addr(player_id, player_name, ip_address.trim().parse()?))
+    }
+
+    /// Create new player network information, joining now
+    #[cfg(feature = "std")]
+    pub fn new_with_addr(player_id: NetworkGUID, player_name: String, ip_address: IpAddr) -> Self {
+        Self::new_with_addr_at(player_id, player_name, ip_address, current_unix_seconds())
+    }
+
+    /// Start building a player with [`PlayerNetInfoBuilder`]
+    pub fn builder() -> PlayerNetInfoBuilder {
+        PlayerNetInfoBuilder::new()
+    }
+
+    /// Create new player network information that joined at `join_time` (Unix seconds)
+    pub fn new_with_addr_at(player_id: NetworkGUID, player_name: String, ip_address: IpAddr, join_time: u64) -> Self {
         Self {
             player_id,
             player_name,
@@ -81,11 +150,53 @@ impl PlayerNetInfo {
             is_connected: true,
             team_id: -1, // No team
             role: PlayerRole::Player,
-            join_time: SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .unwrap()
-                .as_secs(),
+            join_time,
             ip_address,
+            port: None,
+        }
+    }
+
+    /// Set the remote port
+    pub fn set_port(&mut self, port: Option<u16>) {
+        self.port = port;
+    }
+
+    /// Full socket address, if the port is known
+    pub fn socket_addr(&self) -> Option<SocketAddr> {
+        self.port.map(|port| SocketAddr::new(self.ip_address, port))
+    }
+
+    /// Check if the player connects from a private or link-local network
+    pub fn is_lan(&self) -> bool {
+        match self.ip_address {
+            IpAddr::V4(v4) => v4.is_private() || v4.is_link_local(),
+            IpAddr::V6(v6) => match v6.to_ipv4_mapped() {
+                Some(v4) => v4.is_private() || v4.is_link_local(),
+                // fc00::/7 unique local and fe80::/10 link-local
+                None => (v6.segments()[0] & 0xfe00) == 0xfc00 || (v6.segments()[0] & 0xffc0) == 0xfe80,
+            },
+        }
+    }
+
+    /// Check if the player connects from this machine
+    pub fn is_loopback(&self) -> bool {
+        match self.ip_address {
+            IpAddr::V6(v6) => v6.is_loopback() || v6.to_ipv4_mapped().is_some_and(|v4| v4.is_loopback()),
+            IpAddr::V4(v4) => v4.is_loopback(),
+        }
+    }
+
+    /// The address with its last octet (IPv4) or group (IPv6) replaced by `*`
+    pub fn masked_ip_address(&self) -> String {
+        match self.ip_address {
+            IpAddr::V4(v4) => {
+                let [a, b, c, _] = v4.octets();
+                format!("{}.{}.{}.*", a, b, c)
+            }
+            IpAddr::V6(v6) => {
+                let groups: Vec<String> = v6.segments()[..7].iter().map(|g| format!("{:x}", g)).collect();
+                format!("{}:*", groups.join(":"))
+            }
         }
     }
 
@@ -115,13 +226,21 @@ impl PlayerNetInfo {
     }
 
     /// Get how long the player has been connected (in seconds)
+    #[cfg(feature = "std")]
     pub fn connection_duration(&self) -> u64 {
+        self.connection_duration_with_clock(&SystemClock)
+    }
+
+    /// Get how long the player has been connected according to `clock`
+    #[cfg(feature = "std")]
+    pub fn connection_duration_with_clock<C: Clock + ?Sized>(&self, clock: &C) -> u64 {
+        self.connection_duration_at(clock.now_unix_secs())
+    }
+
+    /// Get how long the player has been connected at `now` (Unix seconds)
+    pub fn connection_duration_at(&self, now: u64) -> u64 {
         if self.is_connected {
-            SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .unwrap()
-                .as_secs()
-                .saturating_sub(self.join_time)
+            now.saturating_sub(self.join_time)
         } else {
             0
         }
@@ -138,6 +257,150 @@ impl PlayerNetInfo {
     }
 }
 
+/// Fluent, deserializable builder for [`PlayerNetInfo`]
+///
+/// Unset fields take the same defaults as `PlayerNetInfo::new`: connected,
+/// no team, the `Player` role and fresh stats. The address is kept as text
+/// so templates can carry it, and is checked at build time; without one the
+/// player gets the unspecified address.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PlayerNetInfoBuilder {
+    /// Player id; generated at build time when unset
+    pub player_id: Option<NetworkGUID>,
+    /// Display name; must not be empty
+    pub player_name: String,
+    /// Network connection statistics
+    pub net_stats: NetworkStats,
+    /// Whether the player is connected
+    pub is_connected: bool,
+    /// Team index, or -1 for no team
+    pub team_id: i32,
+    /// Network role
+    pub role: PlayerRole,
+    /// Join time (Unix seconds); the build time when unset
+    pub join_time: Option<u64>,
+    /// IP address text; must parse when set
+    pub ip_address: Option<String>,
+    /// Remote port, if known
+    pub port: Option<u16>,
+}
+
+impl PlayerNetInfoBuilder {
+    /// Create a builder with the default settings
+    pub fn new() -> Self {
+        Self {
+            player_id: None,
+            player_name: String::new(),
+            net_stats: NetworkStats::new(),
+            is_connected: true,
+            team_id: -1,
+            role: PlayerRole::Player,
+            join_time: None,
+            ip_address: None,
+            port: None,
+        }
+    }
+
+    /// Use a specific player id instead of generating one
+    pub fn player_id(mut self, player_id: NetworkGUID) -> Self {
+        self.player_id = Some(player_id);
+        self
+    }
+
+    /// Set the display name
+    pub fn player_name(mut self, player_name: impl Into<String>) -> Self {
+        self.player_name = player_name.into();
+        self
+    }
+
+    /// Set the starting network statistics
+    pub fn net_stats(mut self, net_stats: NetworkStats) -> Self {
+        self.net_stats = net_stats;
+        self
+    }
+
+    /// Set whether the player is connected
+    pub fn connected(mut self, is_connected: bool) -> Self {
+        self.is_connected = is_connected;
+        self
+    }
+
+    /// Set the team index (-1 for no team)
+    pub fn team_id(mut self, team_id: i32) -> Self {
+        self.team_id = team_id;
+        self
+    }
+
+    /// Set the network role
+    pub fn role(mut self, role: PlayerRole) -> Self {
+        self.role = role;
+        self
+    }
+
+    /// Set the join time (Unix seconds) instead of using the build time
+    pub fn join_time(mut self, join_time: u64) -> Self {
+        self.join_time = Some(join_time);
+        self
+    }
+
+    /// Set the address from text, checked when building
+    pub fn ip_address(mut self, ip_address: impl Into<String>) -> Self {
+        self.ip_address = Some(ip_address.into());
+        self
+    }
+
+    /// Set an already parsed address
+    pub fn ip_addr(mut self, ip_address: IpAddr) -> Self {
+        self.ip_address = Some(ip_address.to_string());
+        self
+    }
+
+    /// Set the remote port
+    pub fn port(mut self, port: u16) -> Self {
+        self.port = Some(port);
+        self
+    }
+
+    /// Build the player, joining now unless a join time was set
+    #[cfg(feature = "std")]
+    pub fn build(self) -> Result<PlayerNetInfo, ValidationError> {
+        self.build_at(current_unix_seconds())
+    }
+
+    /// Build the player, joining at `now` (Unix seconds) unless a join time was set
+    pub fn build_at(self, now: u64) -> Result<PlayerNetInfo, ValidationError> {
+        if self.player_name.trim().is_empty() {
+            return Err(ValidationError::new("player_name", "must not be empty"));
+        }
+        if self.team_id < -1 {
+            return Err(ValidationError::new("team_id", "must be -1 (no team) or a team index"));
+        }
+        let ip_address = match &self.ip_address {
+            Some(text) => text
+                .trim()
+                .parse()
+                .map_err(|_| ValidationError::new("ip_address", format!("\"{}\" is not an IP address", text)))?,
+            None => IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+        };
+
+        let player_id = self.player_id.unwrap_or_else(NetworkGUID::generate);
+        let mut player = PlayerNetInfo::new_with_addr_at(player_id, self.player_name, ip_address, self.join_time.unwrap_or(now));
+        player.net_stats = self.net_stats;
+        player.is_connected = self.is_connected;
+        player.team_id = self.team_id;
+        player.role = self.role;
+        player.port = self.port;
+        Ok(player)
+    }
+}
+
+impl Default for PlayerNetInfoBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -145,11 +408,12 @@ mod tests {
     #[test]
     fn test_player_net_info() {
         let player_id = NetworkGUID::new(12345);
-        let mut player = PlayerNetInfo::new(
+        let mut player = PlayerNetInfo::try_new(
             player_id,
             "TestPlayer".to_string(),
-            "192.168.1.100".to_string()
-        );
+            "192.168.1.100",
+        )
+        .unwrap();
         
         assert!(player.is_connected);
         assert!(player.can_play());
@@ -163,6 +427,87 @@ mod tests {
         assert!(!player.can_play());
     }
 
+    #[test]
+    fn test_explicit_join_time() {
+        let addr = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+        let mut player = PlayerNetInfo::new_with_addr_at(NetworkGUID::new(3), "Late".to_string(), addr, 1_000);
+        assert_eq!(player.join_time, 1_000);
+        assert_eq!(player.connection_duration_at(1_090), 90);
+        assert_eq!(player.connection_duration_at(500), 0);
+        player.disconnect();
+        assert_eq!(player.connection_duration_at(1_090), 0);
+    }
+
+    #[test]
+    fn test_connection_duration_with_clock() {
+        use crate::types::{DateTime, ManualClock};
+
+        let clock = ManualClock::from_unix_secs(50_000);
+        let addr = IpAddr::V4(Ipv4Addr::LOCALHOST);
+        let mut player = PlayerNetInfo::new_with_addr_at(NetworkGUID::new(9), "Replay".to_string(), addr, clock.now_unix_secs());
+        assert_eq!(player.connection_duration_with_clock(&clock), 0);
+        for expected in [1, 2, 3] {
+            clock.advance_secs(1);
+            assert_eq!(player.connection_duration_with_clock(&clock), expected);
+        }
+        clock.advance_secs(3_600);
+        assert_eq!(player.connection_duration_with_clock(&clock), 3_603);
+
+        // A clock behind the join time reads as zero, not an underflow
+        clock.set(DateTime::from_unix_timestamp(40_000));
+        assert_eq!(player.connection_duration_with_clock(&clock), 0);
+        player.disconnect();
+        clock.advance_secs(60_000);
+        assert_eq!(player.connection_duration_with_clock(&clock), 0);
+    }
+
+    #[test]
+    fn test_builder_defaults_and_validation() {
+        let built = PlayerNetInfo::builder()
+            .player_id(NetworkGUID::new(7))
+            .player_name("Builder")
+            .ip_address("192.168.1.100")
+            .build_at(1_000)
+            .unwrap();
+        let addr = "192.168.1.100".parse().unwrap();
+        assert_eq!(built, PlayerNetInfo::new_with_addr_at(NetworkGUID::new(7), "Builder".to_string(), addr, 1_000));
+
+        let admin = PlayerNetInfo::builder()
+            .player_name("Ops")
+            .ip_addr(IpAddr::V6(core::net::Ipv6Addr::LOCALHOST))
+            .port(7777)
+            .role(PlayerRole::Admin)
+            .team_id(1)
+            .connected(false)
+            .join_time(500)
+            .build()
+            .unwrap();
+        assert!(admin.player_id.is_valid());
+        assert_eq!(admin.socket_addr(), Some("[::1]:7777".parse().unwrap()));
+        assert_eq!((admin.role, admin.team_id, admin.is_connected, admin.join_time), (PlayerRole::Admin, 1, false, 500));
+
+        // Without an address the player gets the unspecified one, which Validate rejects later
+        let anonymous = PlayerNetInfo::builder().player_name("Anon").build_at(0).unwrap();
+        assert!(anonymous.ip_address.is_unspecified());
+
+        let error = PlayerNetInfo::builder().player_name("Bad").ip_address("999.1.1.1").build_at(0).unwrap_err();
+        assert_eq!(error, ValidationError::new("ip_address", "\"999.1.1.1\" is not an IP address"));
+        assert_eq!(PlayerNetInfo::builder().player_name("  ").build_at(0).unwrap_err().field, "player_name");
+        assert_eq!(PlayerNetInfo::builder().player_name("X").team_id(-2).build_at(0).unwrap_err().field, "team_id");
+    }
+
+    #[test]
+    fn test_builder_template_json() {
+        let template: PlayerNetInfoBuilder = serde_json::from_str(r#"{"role": "Bot", "team_id": 0, "ip_address": "127.0.0.1"}"#).unwrap();
+        let bot = template.clone().player_name("Bot 1").build_at(2_000).unwrap();
+        assert_eq!((bot.role, bot.team_id, bot.join_time), (PlayerRole::Bot, 0, 2_000));
+        assert!(bot.is_loopback() && bot.is_connected);
+
+        let json = serde_json::to_string(&template).unwrap();
+        assert_eq!(serde_json::from_str::<PlayerNetInfoBuilder>(&json).unwrap(), template);
+        assert!(serde_json::from_str::<PlayerNetInfoBuilder>(r#"{"team_id": "red"}"#).is_err());
+    }
+
     #[test]
     fn test_player_role_display() {
         let role = PlayerRole::Admin;
@@ -171,14 +516,82 @@ mod tests {
 
     #[test]
     fn test_binary_serialization() {
-        let player = PlayerNetInfo::new(
+        let player = PlayerNetInfo::try_new(
             NetworkGUID::new(12345),
             "TestPlayer".to_string(),
-            "192.168.1.100".to_string()
-        );
+            "192.168.1.100",
+        )
+        .unwrap();
         
         let binary = player.to_binary().unwrap();
         let deserialized = PlayerNetInfo::from_binary(&binary).unwrap();
         assert_eq!(player, deserialized);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_ipv4_address() {
+        let mut player = PlayerNetInfo::new_with_addr(
+            NetworkGUID::new(1),
+            "Lan".to_string(),
+            "192.168.1.100".parse().unwrap(),
+        );
+        assert!(player.is_lan());
+        assert!(!player.is_loopback());
+        assert_eq!(player.socket_addr(), None);
+        player.set_port(Some(7777));
+        assert_eq!(player.socket_addr(), Some("192.168.1.100:7777".parse().unwrap()));
+
+        let remote = PlayerNetInfo::try_new(NetworkGUID::new(2), "Remote".to_string(), "203.0.113.9").unwrap();
+        assert!(!remote.is_lan());
+        assert!(PlayerNetInfo::try_new(NetworkGUID::new(3), "Me".to_string(), "127.0.0.1").unwrap().is_loopback());
+
+        assert!(PlayerNetInfo::try_new(NetworkGUID::new(4), "Bad".to_string(), "not-an-ip").is_err());
+        let padded = PlayerNetInfo::try_new(NetworkGUID::new(5), "Padded".to_string(), " 10.0.0.1\n").unwrap();
+        assert!(padded.is_lan());
+
+        // The deprecated constructor stores unparseable strings as the unspecified address
+        #[allow(deprecated)]
+        let garbage = PlayerNetInfo::new(NetworkGUID::new(4), "Bad".to_string(), "not-an-ip".to_string());
+        assert!(garbage.ip_address.is_unspecified());
+    }
+
+    #[test]
+    fn test_ipv6_address() {
+        let player = PlayerNetInfo::try_new(NetworkGUID::new(1), "V6".to_string(), "2001:db8::42").unwrap();
+        assert_eq!(player.ip_address, "2001:db8::42".parse::<IpAddr>().unwrap());
+        assert!(!player.is_lan());
+        assert_eq!(player.masked_ip_address(), "2001:db8:0:0:0:0:0:*");
+
+        let local = PlayerNetInfo::try_new(NetworkGUID::new(2), "V6".to_string(), "fd12:3456::1").unwrap();
+        assert!(local.is_lan());
+        let loopback = PlayerNetInfo::try_new(NetworkGUID::new(3), "V6".to_string(), "::1").unwrap();
+        assert!(loopback.is_loopback());
+        let mapped = PlayerNetInfo::try_new(NetworkGUID::new(4), "V6".to_string(), "::ffff:10.0.0.5").unwrap();
+        assert!(mapped.is_lan());
+
+        let json = serde_json::to_string(&player).unwrap();
+        assert!(json.contains(r#""ip_address":"2001:db8::42""#));
+        let deserialized: PlayerNetInfo = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, player);
+    }
+
+    #[test]
+    fn test_old_json_payload_migrates() {
+        let json = r#"{"player_id":{"value":7},"player_name":"Old","net_stats":{"packets_out_per_second":0.0,"packets_in_per_second":0.0,"bytes_out_per_second":0.0,"bytes_in_per_second":0.0,"ping":0.0,"packet_loss":0.0,"jitter":0.0,"uptime":0.0},"is_connected":true,"team_id":-1,"role":"Player","join_time":0,"ip_address":"10.1.2.3"}"#;
+        let player: PlayerNetInfo = serde_json::from_str(json).unwrap();
+        assert_eq!(player.ip_address, IpAddr::V4(Ipv4Addr::new(10, 1, 2, 3)));
+        assert_eq!(player.port, None);
+
+        let invalid = json.replace("10.1.2.3", "not-an-ip");
+        assert!(serde_json::from_str::<PlayerNetInfo>(&invalid).is_err());
+    }
+
+    #[test]
+    fn test_display_privacy_mode() {
+        let player = PlayerNetInfo::try_new(NetworkGUID::new(1), "Anon".to_string(), "198.51.100.23").unwrap();
+        assert!(format!("{}", player).contains("IP: 198.51.100.23,"));
+        let masked = format!("{:#}", player);
+        assert!(masked.contains("IP: 198.51.100.*,"));
+        assert!(!masked.contains("23"));
+    }
+}
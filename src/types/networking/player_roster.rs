@@ -0,0 +1,347 @@
+//! Player roster management for game servers
+
+use crate::BinarySerializable;
+use crate::types::{TArray, TMap, INDEX_NONE};
+use super::{NetworkGUID, PlayerNetInfo};
+use serde::{Deserialize, Serialize};
+use core::fmt;
+use alloc::string::String;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+/// Reason a roster operation was rejected
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RosterError {
+    /// A player with this GUID is already on the roster
+    DuplicatePlayer(NetworkGUID),
+    /// Another player already uses this name (names are compared case-insensitively)
+    DuplicateName(String),
+}
+
+impl fmt::Display for RosterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RosterError::DuplicatePlayer(id) => write!(f, "player {} is already on the roster", id),
+            RosterError::DuplicateName(name) => write!(f, "player name \"{}\" is already taken", name),
+        }
+    }
+}
+
+impl core::error::Error for RosterError {}
+
+/// Serialized form of a roster: players ordered by join time
+#[derive(Serialize, Deserialize)]
+struct RosterSnapshot {
+    unique_names: bool,
+    players: Vec<PlayerNetInfo>,
+}
+
+/// Every player on a server, keyed by NetworkGUID
+///
+/// Players are iterated in join order (ties broken by GUID). Names are
+/// unique (case-insensitively) unless disabled; renaming through `get_mut`
+/// is not checked. Serializes as a list of players, so it works with JSON.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(into = "RosterSnapshot", try_from = "RosterSnapshot")]
+pub struct PlayerRoster {
+    players: TMap<NetworkGUID, PlayerNetInfo>,
+    unique_names: bool,
+}
+
+impl fmt::Display for PlayerRoster {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "PlayerRoster({} players, {} connected)", self.len(), self.connected_count())
+    }
+}
+
+impl BinarySerializable for PlayerRoster {}
+
+impl PlayerRoster {
+    /// Create an empty roster that enforces unique names
+    pub fn new() -> Self {
+        Self {
+            players: TMap::new(),
+            unique_names: true,
+        }
+    }
+
+    /// Create an empty roster, choosing whether names must be unique
+    pub fn with_unique_names(unique_names: bool) -> Self {
+        Self {
+            players: TMap::new(),
+            unique_names,
+        }
+    }
+
+    /// Whether names must be unique
+    pub fn requires_unique_names(&self) -> bool {
+        self.unique_names
+    }
+
+    /// Number of players on the roster, connected or not
+    pub fn len(&self) -> usize {
+        self.players.len()
+    }
+
+    /// Check if the roster has no players
+    pub fn is_empty(&self) -> bool {
+        self.players.is_empty()
+    }
+
+    /// Add a player
+    pub fn add_player(&mut self, player: PlayerNetInfo) -> Result<(), RosterError> {
+        if self.players.contains(&player.player_id) {
+            return Err(RosterError::DuplicatePlayer(player.player_id));
+        }
+        if self.unique_names && self.find_by_name(&player.player_name).is_some() {
+            return Err(RosterError::DuplicateName(player.player_name));
+        }
+        self.players.add(player.player_id, player);
+        Ok(())
+    }
+
+    /// Remove a player, returning their info
+    pub fn remove_player(&mut self, player_id: NetworkGUID) -> Option<PlayerNetInfo> {
+        self.players.remove(&player_id)
+    }
+
+    /// Get a player
+    pub fn get(&self, player_id: NetworkGUID) -> Option<&PlayerNetInfo> {
+        self.players.find(&player_id)
+    }
+
+    /// Get a player mutably
+    pub fn get_mut(&mut self, player_id: NetworkGUID) -> Option<&mut PlayerNetInfo> {
+        self.players.find_mut(&player_id)
+    }
+
+    /// Check if a player is on the roster
+    pub fn contains(&self, player_id: NetworkGUID) -> bool {
+        self.players.contains(&player_id)
+    }
+
+    /// Find a player by name (case-insensitive)
+    pub fn find_by_name(&self, name: &str) -> Option<&PlayerNetInfo> {
+        let name = name.to_lowercase();
+        // The earliest joiner wins when names are not unique
+        self.iter_unordered()
+            .filter(|player| player.player_name.to_lowercase() == name)
+            .min_by_key(|player| (player.join_time, player.player_id.value))
+    }
+
+    /// Players on a team, in join order
+    pub fn players_on_team(&self, team_id: i32) -> TArray<&PlayerNetInfo> {
+        self.iter().filter(|player| player.team_id == team_id).collect()
+    }
+
+    /// Number of connected players
+    pub fn connected_count(&self) -> usize {
+        self.iter_unordered().filter(|player| player.is_connected).count()
+    }
+
+    /// Move a player to the team (0..team_count) with the fewest connected players
+    ///
+    /// The player's own current team doesn't count toward its size, and ties
+    /// go to the lowest team id. Returns the chosen team, or `INDEX_NONE` if
+    /// the player isn't on the roster or `team_count` is not positive.
+    pub fn assign_to_smallest_team(&mut self, player_id: NetworkGUID, team_count: i32) -> i32 {
+        if team_count <= 0 || !self.contains(player_id) {
+            return INDEX_NONE;
+        }
+
+        // Only occupied teams are counted, so a huge team_count costs nothing
+        let mut sizes = BTreeMap::new();
+        for player in self.iter_unordered() {
+            if player.player_id != player_id && player.is_connected && (0..team_count).contains(&player.team_id) {
+                *sizes.entry(player.team_id).or_insert(0usize) += 1;
+            }
+        }
+        // Any empty team beats every occupied one, and the lowest empty id is
+        // the first gap in the sorted occupied ids
+        let mut lowest_empty = 0;
+        for &team in sizes.keys() {
+            if team != lowest_empty {
+                break;
+            }
+            lowest_empty += 1;
+        }
+        let team = if lowest_empty < team_count {
+            lowest_empty
+        } else {
+            // Every team is occupied; min_by_key keeps the first (lowest id) on ties
+            sizes.iter().min_by_key(|(_, size)| **size).map(|(team, _)| *team).unwrap()
+        };
+
+        if let Some(player) = self.get_mut(player_id) {
+            player.set_team(team);
+        }
+        team
+    }
+
+    /// Mark a player as disconnected, keeping them on the roster
+    ///
+    /// Returns false if the player isn't on the roster.
+    pub fn kick(&mut self, player_id: NetworkGUID) -> bool {
+        match self.get_mut(player_id) {
+            Some(player) => {
+                player.disconnect();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Iterate over players in join order
+    ///
+    /// Sorts the players on every call (O(n log n) plus an allocation);
+    /// use `iter_unordered` when order doesn't matter.
+    pub fn iter(&self) -> impl Iterator<Item = &PlayerNetInfo> {
+        let mut players: Vec<&PlayerNetInfo> = self.players.values().collect();
+        players.sort_by_key(|player| (player.join_time, player.player_id.value));
+        players.into_iter()
+    }
+
+    /// Iterate over players in no particular order, without sorting
+    pub fn iter_unordered(&self) -> impl Iterator<Item = &PlayerNetInfo> {
+        self.players.values()
+    }
+}
+
+impl Default for PlayerRoster {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<PlayerRoster> for RosterSnapshot {
+    fn from(roster: PlayerRoster) -> Self {
+        Self {
+            unique_names: roster.unique_names,
+            players: roster.iter().cloned().collect(),
+        }
+    }
+}
+
+impl TryFrom<RosterSnapshot> for PlayerRoster {
+    type Error = RosterError;
+
+    fn try_from(snapshot: RosterSnapshot) -> Result<Self, Self::Error> {
+        let mut roster = Self::with_unique_names(snapshot.unique_names);
+        for player in snapshot.players {
+            roster.add_player(player)?;
+        }
+        Ok(roster)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn player(id: u32, name: &str, join_time: u64) -> PlayerNetInfo {
+        let mut player = PlayerNetInfo::try_new(NetworkGUID::new(id), name.to_string(), "10.0.0.1").unwrap();
+        player.join_time = join_time;
+        player
+    }
+
+    fn team_sizes(roster: &PlayerRoster, team_count: i32) -> Vec<usize> {
+        (0..team_count).map(|team| roster.players_on_team(team).len()).collect()
+    }
+
+    #[test]
+    fn test_add_remove_and_lookup() {
+        let mut roster = PlayerRoster::new();
+        roster.add_player(player(1, "Alice", 30)).unwrap();
+        roster.add_player(player(2, "Bob", 10)).unwrap();
+
+        assert_eq!(
+            roster.add_player(player(1, "Carol", 40)),
+            Err(RosterError::DuplicatePlayer(NetworkGUID::new(1)))
+        );
+        assert_eq!(
+            roster.add_player(player(3, "ALICE", 40)),
+            Err(RosterError::DuplicateName("ALICE".to_string()))
+        );
+
+        assert_eq!(roster.find_by_name("bob").unwrap().player_id, NetworkGUID::new(2));
+        assert!(roster.find_by_name("Dave").is_none());
+
+        let order: Vec<&str> = roster.iter().map(|p| p.player_name.as_str()).collect();
+        assert_eq!(order, ["Bob", "Alice"]);
+        let mut unordered: Vec<&str> = roster.iter_unordered().map(|p| p.player_name.as_str()).collect();
+        unordered.sort_unstable();
+        assert_eq!(unordered, ["Alice", "Bob"]);
+
+        assert!(roster.kick(NetworkGUID::new(1)));
+        assert!(!roster.kick(NetworkGUID::new(9)));
+        assert_eq!(roster.connected_count(), 1);
+        assert_eq!(roster.len(), 2);
+
+        assert_eq!(roster.remove_player(NetworkGUID::new(2)).unwrap().player_name, "Bob");
+        assert!(roster.get(NetworkGUID::new(2)).is_none());
+
+        // Duplicate names allowed when configured
+        let mut relaxed = PlayerRoster::with_unique_names(false);
+        relaxed.add_player(player(1, "Bot", 0)).unwrap();
+        relaxed.add_player(player(2, "Bot", 0)).unwrap();
+        assert_eq!(relaxed.len(), 2);
+        assert_eq!(relaxed.find_by_name("bot").unwrap().player_id, NetworkGUID::new(1));
+    }
+
+    #[test]
+    fn test_team_balancing_uneven_roster() {
+        let mut roster = PlayerRoster::new();
+        for id in 1..=7 {
+            roster.add_player(player(id, &format!("Player{}", id), id as u64)).unwrap();
+        }
+        for id in 1..=7 {
+            roster.assign_to_smallest_team(NetworkGUID::new(id), 3);
+        }
+        assert_eq!(team_sizes(&roster, 3), [3, 2, 2]);
+
+        // Disconnected players don't count toward team size
+        roster.kick(NetworkGUID::new(1));
+        roster.kick(NetworkGUID::new(4));
+        roster.add_player(player(8, "Late", 100)).unwrap();
+        assert_eq!(roster.assign_to_smallest_team(NetworkGUID::new(8), 3), 0);
+
+        // Re-balancing a player ignores their current team
+        let mut roster = PlayerRoster::new();
+        roster.add_player(player(1, "A", 1)).unwrap();
+        roster.add_player(player(2, "B", 2)).unwrap();
+        roster.get_mut(NetworkGUID::new(1)).unwrap().set_team(1);
+        roster.get_mut(NetworkGUID::new(2)).unwrap().set_team(1);
+        assert_eq!(roster.assign_to_smallest_team(NetworkGUID::new(2), 2), 0);
+        assert_eq!(roster.assign_to_smallest_team(NetworkGUID::new(2), 2), 0);
+
+        assert_eq!(roster.assign_to_smallest_team(NetworkGUID::new(99), 2), INDEX_NONE);
+        assert_eq!(roster.assign_to_smallest_team(NetworkGUID::new(1), 0), INDEX_NONE);
+
+        // The team count only bounds the ids; it isn't used to size a buffer
+        assert_eq!(roster.assign_to_smallest_team(NetworkGUID::new(2), i32::MAX), 0);
+        assert_eq!(roster.assign_to_smallest_team(NetworkGUID::new(1), i32::MAX), 1);
+
+        let team_one: Vec<u32> = roster.players_on_team(1).iter().map(|p| p.player_id.value).collect();
+        assert_eq!(team_one, [1]);
+    }
+
+    #[test]
+    fn test_roster_serialization() {
+        let mut roster = PlayerRoster::new();
+        roster.add_player(player(5, "Zed", 20)).unwrap();
+        roster.add_player(player(6, "Amy", 10)).unwrap();
+        roster.kick(NetworkGUID::new(5));
+
+        let json = serde_json::to_string(&roster).unwrap();
+        let deserialized: PlayerRoster = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, roster);
+
+        let binary = roster.to_binary().unwrap();
+        let deserialized = PlayerRoster::from_binary(&binary).unwrap();
+        assert_eq!(deserialized, roster);
+
+        // Snapshots with duplicate players are rejected
+        let duplicated = json.replace("\"value\":6", "\"value\":5");
+        assert!(serde_json::from_str::<PlayerRoster>(&duplicated).is_err());
+    }
+}
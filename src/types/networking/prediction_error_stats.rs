@@ -0,0 +1,479 @@
+//! Client prediction error telemetry aggregated per player
+
+use crate::BinarySerializable;
+use super::RepMovement;
+use serde::{Deserialize, Serialize};
+use core::fmt;
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+use crate::types::math::float_math::FloatMath;
+
+/// Ping band used to bucket prediction error samples
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PingBand {
+    /// Ping below 50ms
+    Low,
+    /// Ping from 50ms up to (not including) 100ms
+    Medium,
+    /// Ping from 100ms up to (not including) 200ms
+    High,
+    /// Ping of 200ms or more
+    VeryHigh,
+}
+
+impl fmt::Display for PingBand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let band_str = match self {
+            PingBand::Low => "Low (<50ms)",
+            PingBand::Medium => "Medium (50-100ms)",
+            PingBand::High => "High (100-200ms)",
+            PingBand::VeryHigh => "VeryHigh (>=200ms)",
+        };
+        write!(f, "{}", band_str)
+    }
+}
+
+impl PingBand {
+    /// All bands in ascending ping order
+    pub const ALL: [PingBand; 4] = [PingBand::Low, PingBand::Medium, PingBand::High, PingBand::VeryHigh];
+
+    /// Get the band a ping value (in milliseconds) falls into
+    ///
+    /// Lower bounds are inclusive; NaN and negative values fall into `Low`.
+    pub fn from_ping(ping_ms: f32) -> Self {
+        if ping_ms >= 200.0 {
+            PingBand::VeryHigh
+        } else if ping_ms >= 100.0 {
+            PingBand::High
+        } else if ping_ms >= 50.0 {
+            PingBand::Medium
+        } else {
+            PingBand::Low
+        }
+    }
+}
+
+/// A single prediction error measurement
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PredictionErrorSample {
+    /// Distance between predicted and authoritative location
+    pub position_error: f64,
+    /// Angle between predicted and authoritative rotation in degrees
+    pub rotation_error_deg: f64,
+    /// Client ping when the sample was taken (milliseconds)
+    pub at_ping_ms: f32,
+}
+
+impl PredictionErrorSample {
+    /// Create a new sample
+    pub fn new(position_error: f64, rotation_error_deg: f64, at_ping_ms: f32) -> Self {
+        Self { position_error, rotation_error_deg, at_ping_ms }
+    }
+
+    /// Measure the error between a predicted and an authoritative movement state
+    pub fn from_movements(predicted: &RepMovement, actual: &RepMovement, ping_ms: f32) -> Self {
+        let position_error = predicted.location.distance(actual.location);
+        let rotation_error_deg = predicted
+            .rotation
+            .to_quaternion()
+            .angle_between(actual.rotation.to_quaternion())
+            .to_degrees();
+        Self::new(position_error, rotation_error_deg, ping_ms)
+    }
+
+    /// Get the ping band this sample belongs to
+    pub fn ping_band(&self) -> PingBand {
+        PingBand::from_ping(self.at_ping_ms)
+    }
+}
+
+/// Aggregate statistics over a set of prediction error samples
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct ErrorSummary {
+    /// Number of samples summarized
+    pub count: usize,
+    /// Mean position error
+    pub mean_position_error: f64,
+    /// 95th percentile position error
+    pub p95_position_error: f64,
+    /// Maximum position error
+    pub max_position_error: f64,
+    /// Mean rotation error in degrees
+    pub mean_rotation_error_deg: f64,
+    /// 95th percentile rotation error in degrees
+    pub p95_rotation_error_deg: f64,
+    /// Maximum rotation error in degrees
+    pub max_rotation_error_deg: f64,
+}
+
+impl fmt::Display for ErrorSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "ErrorSummary(n={}, Pos: mean {:.2} p95 {:.2} max {:.2}, Rot: mean {:.2}° p95 {:.2}° max {:.2}°)",
+            self.count,
+            self.mean_position_error,
+            self.p95_position_error,
+            self.max_position_error,
+            self.mean_rotation_error_deg,
+            self.p95_rotation_error_deg,
+            self.max_rotation_error_deg
+        )
+    }
+}
+
+impl BinarySerializable for ErrorSummary {}
+
+impl ErrorSummary {
+    /// Summarize a set of samples (all zero for an empty set)
+    pub fn from_samples<'a>(samples: impl IntoIterator<Item = &'a PredictionErrorSample>) -> Self {
+        let (mut positions, mut rotations): (Vec<f64>, Vec<f64>) = samples
+            .into_iter()
+            .map(|s| (s.position_error, s.rotation_error_deg))
+            .unzip();
+        if positions.is_empty() {
+            return Self::default();
+        }
+
+        let (mean_position_error, p95_position_error, max_position_error) = mean_p95_max(&mut positions);
+        let (mean_rotation_error_deg, p95_rotation_error_deg, max_rotation_error_deg) = mean_p95_max(&mut rotations);
+
+        Self {
+            count: positions.len(),
+            mean_position_error,
+            p95_position_error,
+            max_position_error,
+            mean_rotation_error_deg,
+            p95_rotation_error_deg,
+            max_rotation_error_deg,
+        }
+    }
+}
+
+/// Mean, nearest-rank 95th percentile and maximum of a non-empty set of values
+fn mean_p95_max(values: &mut [f64]) -> (f64, f64, f64) {
+    values.sort_by(|a, b| a.total_cmp(b));
+    let mean = values.iter().sum::<f64>() / values.len() as f64;
+    let rank = ((values.len() as f64) * 0.95).ceil() as usize;
+    let p95 = values[rank.saturating_sub(1)];
+    let max = values[values.len() - 1];
+    (mean, p95, max)
+}
+
+/// Serialized form of a PredictionErrorStats
+///
+/// `capacity` and `recorded` default for data written before the window
+/// was bounded, which held only the samples.
+#[derive(Serialize, Deserialize)]
+struct PredictionErrorStatsRepr {
+    #[serde(default = "default_capacity")]
+    capacity: usize,
+    #[serde(default)]
+    recorded: u64,
+    samples: VecDeque<PredictionErrorSample>,
+}
+
+fn default_capacity() -> usize {
+    PredictionErrorStats::DEFAULT_CAPACITY
+}
+
+/// Per-player accumulator of client prediction errors
+///
+/// Keeps the most recent `capacity()` samples in a ring buffer, so memory
+/// stays bounded however long a player is connected; older samples are
+/// dropped and summaries cover the retained window. Within the window
+/// percentiles are exact. Call `reset()` after shipping a batch to
+/// analytics.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(into = "PredictionErrorStatsRepr", from = "PredictionErrorStatsRepr")]
+pub struct PredictionErrorStats {
+    samples: VecDeque<PredictionErrorSample>,
+    capacity: usize,
+    recorded: u64,
+}
+
+impl fmt::Display for PredictionErrorStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "PredictionErrorStats({})", self.summary())
+    }
+}
+
+impl BinarySerializable for PredictionErrorStats {}
+
+impl Default for PredictionErrorStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PredictionErrorStats {
+    /// Number of samples kept by `new()`
+    pub const DEFAULT_CAPACITY: usize = 4096;
+
+    /// Create an empty accumulator keeping the last `DEFAULT_CAPACITY` samples
+    pub fn new() -> Self {
+        Self::with_capacity(Self::DEFAULT_CAPACITY)
+    }
+
+    /// Create an empty accumulator keeping the last `capacity` samples
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn with_capacity(capacity: usize) -> Self {
+        assert!(capacity > 0, "PredictionErrorStats capacity must be non-zero");
+        Self {
+            samples: VecDeque::new(),
+            capacity,
+            recorded: 0,
+        }
+    }
+
+    /// Record a sample, dropping the oldest one if the window is full
+    pub fn record(&mut self, sample: PredictionErrorSample) {
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample);
+        self.recorded += 1;
+    }
+
+    /// Measure and record the error between a predicted and an authoritative movement
+    pub fn record_from(&mut self, predicted: &RepMovement, actual: &RepMovement, ping_ms: f32) {
+        self.record(PredictionErrorSample::from_movements(predicted, actual, ping_ms));
+    }
+
+    /// Get the number of retained samples
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Check if no samples are retained
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Get the maximum number of retained samples
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Get the number of samples recorded since the last reset, including dropped ones
+    pub fn total_recorded(&self) -> u64 {
+        self.recorded
+    }
+
+    /// Get the retained samples, oldest first
+    pub fn samples(&self) -> &VecDeque<PredictionErrorSample> {
+        &self.samples
+    }
+
+    /// Summarize the retained samples
+    pub fn summary(&self) -> ErrorSummary {
+        ErrorSummary::from_samples(&self.samples)
+    }
+
+    /// Summarize the retained samples within a ping band
+    pub fn stats_for_ping_band(&self, band: PingBand) -> ErrorSummary {
+        ErrorSummary::from_samples(self.samples.iter().filter(|s| s.ping_band() == band))
+    }
+
+    /// Merge samples from another accumulator (e.g. another server shard)
+    ///
+    /// The other samples count as recorded after this one's, so when the
+    /// window overflows this accumulator's oldest samples go first. Keeps
+    /// this accumulator's capacity.
+    pub fn merge(&mut self, other: &PredictionErrorStats) {
+        let recorded = self.recorded + other.recorded;
+        for &sample in &other.samples {
+            self.record(sample);
+        }
+        self.recorded = recorded;
+    }
+
+    /// Discard all recorded samples
+    pub fn reset(&mut self) {
+        self.samples.clear();
+        self.recorded = 0;
+    }
+}
+
+impl From<PredictionErrorStats> for PredictionErrorStatsRepr {
+    fn from(stats: PredictionErrorStats) -> Self {
+        Self {
+            capacity: stats.capacity,
+            recorded: stats.recorded,
+            samples: stats.samples,
+        }
+    }
+}
+
+/// A zero capacity is raised to one, and a window longer than its capacity
+/// keeps only its newest samples
+impl From<PredictionErrorStatsRepr> for PredictionErrorStats {
+    fn from(mut repr: PredictionErrorStatsRepr) -> Self {
+        let capacity = repr.capacity.max(1);
+        let excess = repr.samples.len().saturating_sub(capacity);
+        repr.samples.drain(..excess);
+        Self {
+            recorded: repr.recorded.max(repr.samples.len() as u64 + excess as u64),
+            samples: repr.samples,
+            capacity,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Rotator, Vector};
+
+    fn stats_from(samples: &[(f64, f64, f32)]) -> PredictionErrorStats {
+        let mut stats = PredictionErrorStats::new();
+        for &(position, rotation, ping) in samples {
+            stats.record(PredictionErrorSample::new(position, rotation, ping));
+        }
+        stats
+    }
+
+    #[test]
+    fn test_ping_band_edges() {
+        assert_eq!(PingBand::from_ping(0.0), PingBand::Low);
+        assert_eq!(PingBand::from_ping(49.99), PingBand::Low);
+        assert_eq!(PingBand::from_ping(50.0), PingBand::Medium);
+        assert_eq!(PingBand::from_ping(99.99), PingBand::Medium);
+        assert_eq!(PingBand::from_ping(100.0), PingBand::High);
+        assert_eq!(PingBand::from_ping(200.0), PingBand::VeryHigh);
+        assert_eq!(PingBand::from_ping(f32::NAN), PingBand::Low);
+    }
+
+    #[test]
+    fn test_stats_for_ping_band() {
+        let stats = stats_from(&[(1.0, 1.0, 20.0), (3.0, 2.0, 40.0), (10.0, 5.0, 150.0)]);
+
+        let low = stats.stats_for_ping_band(PingBand::Low);
+        assert_eq!(low.count, 2);
+        assert_eq!(low.mean_position_error, 2.0);
+        assert_eq!(low.max_rotation_error_deg, 2.0);
+
+        let high = stats.stats_for_ping_band(PingBand::High);
+        assert_eq!(high.count, 1);
+        assert_eq!(high.max_position_error, 10.0);
+
+        assert_eq!(stats.stats_for_ping_band(PingBand::Medium), ErrorSummary::default());
+    }
+
+    #[test]
+    fn test_percentile_matches_sorted_reference() {
+        let mut stats = PredictionErrorStats::new();
+        // Insert values 1..=200 in a scrambled order
+        for i in 0..200u32 {
+            let value = ((i * 37) % 200 + 1) as f64;
+            stats.record(PredictionErrorSample::new(value, value / 10.0, 30.0));
+        }
+
+        let mut reference: Vec<f64> = stats.samples().iter().map(|s| s.position_error).collect();
+        reference.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let summary = stats.summary();
+        assert_eq!(summary.count, 200);
+        assert_eq!(summary.p95_position_error, reference[189]);
+        assert_eq!(summary.p95_position_error, 190.0);
+        assert_eq!(summary.max_position_error, 200.0);
+        assert!((summary.mean_position_error - 100.5).abs() < 1e-9);
+        assert!((summary.p95_rotation_error_deg - 19.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_merge_associativity() {
+        let a = stats_from(&[(1.0, 0.5, 10.0), (2.0, 1.5, 80.0)]);
+        let b = stats_from(&[(4.0, 3.0, 120.0)]);
+        let c = stats_from(&[(0.5, 0.1, 250.0), (8.0, 9.0, 60.0)]);
+
+        let mut left = a.clone();
+        left.merge(&b);
+        left.merge(&c);
+
+        let mut bc = b.clone();
+        bc.merge(&c);
+        let mut right = a.clone();
+        right.merge(&bc);
+
+        assert_eq!(left.summary(), right.summary());
+        for band in PingBand::ALL {
+            assert_eq!(left.stats_for_ping_band(band), right.stats_for_ping_band(band));
+        }
+
+        left.reset();
+        assert!(left.is_empty());
+    }
+
+    #[test]
+    fn test_record_from_movements() {
+        let predicted = RepMovement::from_transform(Vector::ZERO, Rotator::from_yaw(0.0), Vector::ZERO);
+        let actual = RepMovement::from_transform(Vector::new(3.0, 4.0, 0.0), Rotator::from_yaw(30.0), Vector::ZERO);
+
+        let mut stats = PredictionErrorStats::new();
+        stats.record_from(&predicted, &actual, 75.0);
+
+        let sample = stats.samples()[0];
+        assert!((sample.position_error - 5.0).abs() < 1e-9);
+        assert!((sample.rotation_error_deg - 30.0).abs() < 1e-6);
+        assert_eq!(sample.ping_band(), PingBand::Medium);
+    }
+
+    #[test]
+    fn test_serialization_round_trip() {
+        let stats = stats_from(&[(1.25, 0.5, 10.0), (2.5, 1.5, 80.0)]);
+
+        let json = serde_json::to_string(&stats).unwrap();
+        let from_json: PredictionErrorStats = serde_json::from_str(&json).unwrap();
+        assert_eq!(stats, from_json);
+
+        let binary = stats.to_binary().unwrap();
+        let from_binary = PredictionErrorStats::from_binary(&binary).unwrap();
+        assert_eq!(stats, from_binary);
+    }
+
+    #[test]
+    fn test_window_is_bounded() {
+        let mut stats = PredictionErrorStats::with_capacity(3);
+        for i in 1..=5 {
+            stats.record(PredictionErrorSample::new(i as f64, 0.0, 30.0));
+        }
+
+        assert_eq!(stats.len(), 3);
+        assert_eq!(stats.total_recorded(), 5);
+        let kept: Vec<f64> = stats.samples().iter().map(|s| s.position_error).collect();
+        assert_eq!(kept, [3.0, 4.0, 5.0]);
+        assert_eq!(stats.summary().mean_position_error, 4.0);
+
+        // Merged samples are newer, so they push out the oldest local ones
+        let mut other = PredictionErrorStats::with_capacity(8);
+        other.record(PredictionErrorSample::new(6.0, 0.0, 30.0));
+        stats.merge(&other);
+        assert_eq!(stats.samples()[0].position_error, 4.0);
+        assert_eq!(stats.total_recorded(), 6);
+        assert_eq!(stats.capacity(), 3);
+
+        stats.reset();
+        assert_eq!(stats.total_recorded(), 0);
+    }
+
+    #[test]
+    fn test_unbounded_format_deserializes() {
+        // Written before the window was bounded
+        let json = r#"{"samples":[{"position_error":1.0,"rotation_error_deg":0.5,"at_ping_ms":10.0}]}"#;
+        let stats: PredictionErrorStats = serde_json::from_str(json).unwrap();
+        assert_eq!(stats.len(), 1);
+        assert_eq!(stats.total_recorded(), 1);
+        assert_eq!(stats.capacity(), PredictionErrorStats::DEFAULT_CAPACITY);
+
+        let json = r#"{"capacity":0,"recorded":0,"samples":[{"position_error":1.0,"rotation_error_deg":0.5,"at_ping_ms":10.0},{"position_error":2.0,"rotation_error_deg":0.5,"at_ping_ms":10.0}]}"#;
+        let stats: PredictionErrorStats = serde_json::from_str(json).unwrap();
+        assert_eq!(stats.capacity(), 1);
+        assert_eq!(stats.samples()[0].position_error, 2.0);
+        assert_eq!(stats.total_recorded(), 2);
+    }
+}
@@ -0,0 +1,247 @@
+//! Quantized vector and rotator packing for movement replication
+//!
+//! Mirrors UE's `FVector_NetQuantize*` and `FRotator::SerializeCompressed*`:
+//!
+//! * Vectors are scaled, rounded to integers and written with a 5-bit header
+//!   holding the per-component bit count, followed by three biased
+//!   components of that many bits. Values beyond the range of the maximum
+//!   bit count are clamped.
+//! * Rotators write one "non-zero" bit per axis followed, when set, by the
+//!   axis compressed to a byte (360/256 degree steps) or short (360/65536
+//!   degree steps).
+//!
+//! Both are exposed as [`BitWriter`] / [`BitReader`] methods.
+
+use crate::types::{normalize_angle, Rotator, Vector};
+use super::{BitReader, BitWriter, BitstreamError};
+#[cfg(not(feature = "std"))]
+use crate::types::math::float_math::FloatMath;
+
+/// Vector quantization levels (UE5: FVector_NetQuantize / 10 / 100)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VectorQuantization {
+    /// Whole units, up to 20 bits per component (FVector_NetQuantize)
+    Whole,
+    /// One decimal place, up to 24 bits per component (FVector_NetQuantize10)
+    Ten,
+    /// Two decimal places, up to 30 bits per component (FVector_NetQuantize100)
+    Hundred,
+}
+
+impl VectorQuantization {
+    /// Factor components are multiplied by before rounding
+    pub fn scale(self) -> f64 {
+        match self {
+            VectorQuantization::Whole => 1.0,
+            VectorQuantization::Ten => 10.0,
+            VectorQuantization::Hundred => 100.0,
+        }
+    }
+
+    /// Smallest representable difference between two values
+    ///
+    /// In-range components round-trip within half of this step.
+    pub fn step(self) -> f64 {
+        1.0 / self.scale()
+    }
+
+    /// Maximum number of bits used for a single component
+    pub fn max_bits_per_component(self) -> u32 {
+        match self {
+            VectorQuantization::Whole => 20,
+            VectorQuantization::Ten => 24,
+            VectorQuantization::Hundred => 30,
+        }
+    }
+
+    /// Largest component magnitude that survives without clamping
+    pub fn max_value(self) -> f64 {
+        self.max_scaled() as f64 / self.scale()
+    }
+
+    fn max_scaled(self) -> i64 {
+        (1i64 << (self.max_bits_per_component() - 1)) - 1
+    }
+}
+
+/// Rotator compression levels (UE5: FRotator::SerializeCompressed / SerializeCompressedShort)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RotatorQuantization {
+    /// 8 bits per axis
+    Byte,
+    /// 16 bits per axis
+    Short,
+}
+
+impl RotatorQuantization {
+    /// Number of bits used for a non-zero axis
+    pub fn bits_per_axis(self) -> u32 {
+        match self {
+            RotatorQuantization::Byte => 8,
+            RotatorQuantization::Short => 16,
+        }
+    }
+
+    /// Size of one quantization step in degrees
+    pub fn step(self) -> f64 {
+        360.0 / (1u64 << self.bits_per_axis()) as f64
+    }
+
+    /// Compress an angle in degrees to an integer axis value
+    pub fn compress_axis(self, angle: f64) -> u32 {
+        if !angle.is_finite() {
+            return 0;
+        }
+        let steps = (angle / self.step()).round() as i64;
+        steps.rem_euclid(1i64 << self.bits_per_axis()) as u32
+    }
+
+    /// Decompress an integer axis value to an angle in [-180, 180]
+    pub fn decompress_axis(self, value: u32) -> f64 {
+        normalize_angle(value as f64 * self.step())
+    }
+}
+
+/// Number of bits in the per-vector component bit count header
+const COMPONENT_BITS_HEADER: u32 = 5;
+
+impl BitWriter {
+    /// Write a vector scaled and packed for the given quantization
+    pub fn write_quantized_vector(&mut self, vector: Vector, quantization: VectorQuantization) {
+        let max = quantization.max_scaled();
+        let scaled = [vector.x, vector.y, vector.z].map(|component| {
+            let value = (component * quantization.scale()).round();
+            if value.is_nan() {
+                0
+            } else {
+                (value as i64).clamp(-max, max)
+            }
+        });
+
+        // Smallest signed width that fits every component
+        let largest = scaled.iter().map(|c| c.unsigned_abs()).max().unwrap();
+        let bits = (u64::BITS - largest.leading_zeros() + 1).min(quantization.max_bits_per_component());
+        let bias = 1i64 << (bits - 1);
+
+        self.write_bits(bits - 1, COMPONENT_BITS_HEADER);
+        for component in scaled {
+            self.write_bits((component + bias) as u32, bits);
+        }
+    }
+
+    /// Write a rotator with each non-zero axis compressed
+    pub fn write_compressed_rotator(&mut self, rotator: Rotator, quantization: RotatorQuantization) {
+        for angle in [rotator.pitch, rotator.yaw, rotator.roll] {
+            let value = quantization.compress_axis(angle);
+            self.write_bit(value != 0);
+            if value != 0 {
+                self.write_bits(value, quantization.bits_per_axis());
+            }
+        }
+    }
+}
+
+impl BitReader<'_> {
+    /// Read a vector written by [`BitWriter::write_quantized_vector`]
+    pub fn read_quantized_vector(&mut self, quantization: VectorQuantization) -> Result<Vector, BitstreamError> {
+        let bits = self.read_bits(COMPONENT_BITS_HEADER)? + 1;
+        if bits > quantization.max_bits_per_component() {
+            return Err(BitstreamError::InvalidValue("vector component bit count"));
+        }
+        let bias = 1i64 << (bits - 1);
+        let mut component = || -> Result<f64, BitstreamError> {
+            let raw = self.read_bits(bits)? as i64 - bias;
+            Ok(raw as f64 / quantization.scale())
+        };
+        Ok(Vector::new(component()?, component()?, component()?))
+    }
+
+    /// Read a rotator written by [`BitWriter::write_compressed_rotator`]
+    pub fn read_compressed_rotator(&mut self, quantization: RotatorQuantization) -> Result<Rotator, BitstreamError> {
+        let mut axis = || -> Result<f64, BitstreamError> {
+            if self.read_bit()? {
+                Ok(quantization.decompress_axis(self.read_bits(quantization.bits_per_axis())?))
+            } else {
+                Ok(0.0)
+            }
+        };
+        Ok(Rotator::new(axis()?, axis()?, axis()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vector_quantization_round_trip() {
+        let vectors = [
+            Vector::ZERO,
+            Vector::new(1.26, -3.17, 250.0),
+            Vector::new(-12345.678, 0.004, 9999.999),
+        ];
+        for quantization in [VectorQuantization::Whole, VectorQuantization::Ten, VectorQuantization::Hundred] {
+            for vector in vectors {
+                let mut writer = BitWriter::new();
+                writer.write_quantized_vector(vector, quantization);
+                let bytes = writer.into_bytes();
+                let restored = BitReader::new(&bytes).read_quantized_vector(quantization).unwrap();
+                let error = (restored - vector).abs().max_element();
+                assert!(error <= quantization.step() * 0.5 + 1e-9, "{:?} {:?}", quantization, vector);
+            }
+        }
+    }
+
+    #[test]
+    fn test_vector_quantization_clamps() {
+        let quantization = VectorQuantization::Whole;
+        let mut writer = BitWriter::new();
+        writer.write_quantized_vector(Vector::new(1e9, -1e9, f64::NAN), quantization);
+        let bytes = writer.into_bytes();
+        let restored = BitReader::new(&bytes).read_quantized_vector(quantization).unwrap();
+        assert_eq!(restored, Vector::new(quantization.max_value(), -quantization.max_value(), 0.0));
+    }
+
+    #[test]
+    fn test_rotator_compression() {
+        assert_eq!(RotatorQuantization::Byte.compress_axis(90.0), 64);
+        assert_eq!(RotatorQuantization::Byte.compress_axis(-90.0), 192);
+        assert_eq!(RotatorQuantization::Short.compress_axis(180.0), 32768);
+        assert_eq!(RotatorQuantization::Byte.decompress_axis(192), -90.0);
+
+        let rotator = Rotator::new(12.3, -170.0, 359.0);
+        for quantization in [RotatorQuantization::Byte, RotatorQuantization::Short] {
+            let mut writer = BitWriter::new();
+            writer.write_compressed_rotator(rotator, quantization);
+            let bytes = writer.into_bytes();
+            let restored = BitReader::new(&bytes).read_compressed_rotator(quantization).unwrap();
+            for (a, b) in [(rotator.pitch, restored.pitch), (rotator.yaw, restored.yaw), (rotator.roll, restored.roll)] {
+                assert!(normalize_angle(a - b).abs() <= quantization.step() * 0.5 + 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_zero_rotator_is_three_bits() {
+        let mut writer = BitWriter::new();
+        writer.write_compressed_rotator(Rotator::ZERO, RotatorQuantization::Short);
+        assert_eq!(writer.num_bits(), 3);
+    }
+
+    #[test]
+    fn test_truncated_and_invalid_input() {
+        let mut writer = BitWriter::new();
+        writer.write_quantized_vector(Vector::new(100.0, 200.0, 300.0), VectorQuantization::Hundred);
+        let bytes = writer.into_bytes();
+        assert_eq!(
+            BitReader::new(&bytes[..2]).read_quantized_vector(VectorQuantization::Hundred),
+            Err(BitstreamError::Overrun { requested: 16, remaining: 11 })
+        );
+
+        // A 30-bit header is not valid for whole-unit vectors
+        assert_eq!(
+            BitReader::new(&[29, 0, 0, 0]).read_quantized_vector(VectorQuantization::Whole),
+            Err(BitstreamError::InvalidValue("vector component bit count"))
+        );
+    }
+}
@@ -0,0 +1,325 @@
+//! Server regions and latency-based region selection for matchmaking
+
+use crate::BinarySerializable;
+use crate::types::{SerializedSize, TMap};
+use serde::{Deserialize, Serialize};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::convert::Infallible;
+use core::fmt;
+use core::str::FromStr;
+
+/// Matchmaking region of a game server
+///
+/// Serializes as its name (`"NA_East"`, `"EU_West"`, ...). Parsing is
+/// case-insensitive and accepts common cloud region codes such as
+/// `"us-east-1"`; anything else, including legacy free-form strings, becomes
+/// `Custom` with the text kept as written.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum Region {
+    /// Eastern North America
+    NaEast,
+    /// Western North America
+    NaWest,
+    /// Western Europe and the UK
+    EuWest,
+    /// Central and northern Europe
+    EuCentral,
+    /// East, South-East and South Asia
+    Apac,
+    /// Australia and New Zealand
+    Oce,
+    /// South America
+    Sa,
+    /// Middle East and Africa
+    Mea,
+    /// Any other region name
+    Custom(String),
+}
+
+impl Region {
+    /// Every predefined region
+    pub const ALL: [Region; 8] = [
+        Region::NaEast,
+        Region::NaWest,
+        Region::EuWest,
+        Region::EuCentral,
+        Region::Apac,
+        Region::Oce,
+        Region::Sa,
+        Region::Mea,
+    ];
+
+    /// Region name as written when serialized
+    pub fn as_str(&self) -> &str {
+        match self {
+            Region::NaEast => "NA_East",
+            Region::NaWest => "NA_West",
+            Region::EuWest => "EU_West",
+            Region::EuCentral => "EU_Central",
+            Region::Apac => "APAC",
+            Region::Oce => "OCE",
+            Region::Sa => "SA",
+            Region::Mea => "MEA",
+            Region::Custom(name) => name,
+        }
+    }
+
+    /// Check if this is a `Custom` region
+    pub fn is_custom(&self) -> bool {
+        matches!(self, Region::Custom(_))
+    }
+
+    /// Parse a region name or alias, falling back to `Custom`
+    pub fn parse(text: &str) -> Self {
+        let key = text.trim().to_ascii_lowercase().replace(['_', ' '], "-");
+        match key.as_str() {
+            "na-east" | "us-east" | "us-east-1" | "us-east-2" | "ca-central-1" | "eastus" | "eastus2" => Region::NaEast,
+            "na-west" | "us-west" | "us-west-1" | "us-west-2" | "westus" | "westus2" => Region::NaWest,
+            "eu-west" | "eu-west-1" | "eu-west-2" | "eu-west-3" | "westeurope" | "uksouth" => Region::EuWest,
+            "eu-central" | "eu-central-1" | "eu-north-1" | "northeurope" | "germanywestcentral" => Region::EuCentral,
+            "apac" | "asia" | "ap-northeast-1" | "ap-northeast-2" | "ap-northeast-3" | "ap-southeast-1" | "ap-south-1"
+            | "ap-east-1" | "eastasia" | "southeastasia" | "japaneast" => Region::Apac,
+            "oce" | "oceania" | "ap-southeast-2" | "australiaeast" => Region::Oce,
+            "sa" | "south-america" | "sa-east-1" | "brazilsouth" => Region::Sa,
+            "mea" | "middle-east" | "me-south-1" | "me-central-1" | "af-south-1" | "uaenorth" | "southafricanorth" => {
+                Region::Mea
+            }
+            _ => Region::Custom(text.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for Region {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Never fails; unknown names become `Region::Custom`
+impl FromStr for Region {
+    type Err = Infallible;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Ok(Self::parse(text))
+    }
+}
+
+impl From<String> for Region {
+    fn from(text: String) -> Self {
+        match Self::parse(&text) {
+            Region::Custom(_) => Region::Custom(text),
+            region => region,
+        }
+    }
+}
+
+impl From<Region> for String {
+    fn from(region: Region) -> Self {
+        match region {
+            Region::Custom(name) => name,
+            region => region.as_str().to_string(),
+        }
+    }
+}
+
+impl BinarySerializable for Region {}
+
+impl SerializedSize for Region {
+    fn serialized_size(&self) -> usize {
+        self.as_str().serialized_size()
+    }
+}
+
+/// Picks the lowest-ping region, with hysteresis
+///
+/// Once a region is chosen, `select` only moves to another region when its
+/// ping beats the current one by more than `switch_threshold_ms`, so small
+/// fluctuations don't bounce a player between regions. Negative and NaN
+/// pings are ignored.
+///
+/// ```
+/// use ue_types::*;
+///
+/// let mut selector = RegionSelector::new(20.0);
+/// let mut pings = TMap::new();
+/// pings.add(Region::NaEast, 40.0);
+/// pings.add(Region::NaWest, 70.0);
+/// assert_eq!(selector.select(&pings), Some(&Region::NaEast));
+///
+/// // 10 ms better is within the threshold, so the choice sticks
+/// pings.add(Region::NaWest, 30.0);
+/// assert_eq!(selector.select(&pings), Some(&Region::NaEast));
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RegionSelector {
+    current: Option<Region>,
+    switch_threshold_ms: f32,
+}
+
+impl fmt::Display for RegionSelector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.current {
+            Some(region) => write!(f, "RegionSelector(Current: {}, Threshold: {:.1}ms)", region, self.switch_threshold_ms),
+            None => write!(f, "RegionSelector(Current: none, Threshold: {:.1}ms)", self.switch_threshold_ms),
+        }
+    }
+}
+
+impl RegionSelector {
+    /// Ping improvement required to switch regions by default
+    pub const DEFAULT_SWITCH_THRESHOLD_MS: f32 = 20.0;
+
+    /// Create a selector with no current region
+    pub fn new(switch_threshold_ms: f32) -> Self {
+        Self {
+            current: None,
+            switch_threshold_ms,
+        }
+    }
+
+    /// Ping improvement required to switch regions
+    pub fn switch_threshold_ms(&self) -> f32 {
+        self.switch_threshold_ms
+    }
+
+    /// Region chosen by the last `select`
+    pub fn current(&self) -> Option<&Region> {
+        self.current.as_ref()
+    }
+
+    /// Forget the current region, so the next `select` picks the best outright
+    pub fn reset(&mut self) {
+        self.current = None;
+    }
+
+    /// Regions with a valid ping, lowest first (ties in `Region` order)
+    pub fn preferences(pings: &TMap<Region, f32>) -> Vec<(Region, f32)> {
+        let mut sorted: Vec<(Region, f32)> = pings
+            .iter()
+            .filter(|(_, ping)| **ping >= 0.0)
+            .map(|(region, ping)| (region.clone(), *ping))
+            .collect();
+        sorted.sort_by(|a, b| a.1.total_cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+        sorted
+    }
+
+    /// Lowest-ping region, ignoring hysteresis
+    pub fn best(pings: &TMap<Region, f32>) -> Option<Region> {
+        Self::preferences(pings).into_iter().next().map(|(region, _)| region)
+    }
+
+    /// Update and return the current region from new ping measurements
+    ///
+    /// Switches when there is no current region, the current region has no
+    /// valid ping, or another region is faster by more than the threshold.
+    /// Without any valid pings the current region is kept.
+    pub fn select(&mut self, pings: &TMap<Region, f32>) -> Option<&Region> {
+        if let Some((best, best_ping)) = Self::preferences(pings).into_iter().next() {
+            let current_ping = self.current.as_ref().and_then(|region| pings.find(region)).filter(|ping| **ping >= 0.0);
+            let switch = match current_ping {
+                Some(current_ping) => current_ping - best_ping > self.switch_threshold_ms,
+                None => true,
+            };
+            if switch {
+                self.current = Some(best);
+            }
+        }
+        self.current.as_ref()
+    }
+}
+
+impl Default for RegionSelector {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_SWITCH_THRESHOLD_MS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pings(entries: &[(Region, f32)]) -> TMap<Region, f32> {
+        let mut map = TMap::new();
+        for (region, ping) in entries {
+            map.add(region.clone(), *ping);
+        }
+        map
+    }
+
+    #[test]
+    fn test_parse_aliases() {
+        assert_eq!("us-east-1".parse::<Region>(), Ok(Region::NaEast));
+        assert_eq!(Region::parse("US_WEST_2"), Region::NaWest);
+        assert_eq!(Region::parse(" eu central "), Region::EuCentral);
+        assert_eq!(Region::parse("ap-southeast-2"), Region::Oce);
+        assert_eq!(Region::parse("sa-east-1"), Region::Sa);
+        assert_eq!(Region::parse("Unknown"), Region::Custom("Unknown".to_string()));
+        assert_eq!(Region::parse("Moon Base"), Region::Custom("Moon Base".to_string()));
+
+        for region in Region::ALL {
+            assert_eq!(Region::parse(&region.to_string()), region);
+            assert_eq!(Region::parse(&region.to_string().to_lowercase()), region);
+        }
+    }
+
+    #[test]
+    fn test_serialization() {
+        assert_eq!(serde_json::to_string(&Region::EuWest).unwrap(), "\"EU_West\"");
+        assert_eq!(serde_json::to_string(&Region::Custom("Mars".to_string())).unwrap(), "\"Mars\"");
+
+        // Legacy free-form strings load as Custom, keeping their text
+        assert_eq!(serde_json::from_str::<Region>("\"APAC\"").unwrap(), Region::Apac);
+        assert_eq!(serde_json::from_str::<Region>("\"us-west-1\"").unwrap(), Region::NaWest);
+        assert_eq!(serde_json::from_str::<Region>("\"Unknown\"").unwrap(), Region::Custom("Unknown".to_string()));
+
+        for region in [Region::Mea, Region::Custom("Lunar-1".to_string())] {
+            assert_eq!(region.serialized_size(), region.to_binary().unwrap().len());
+            assert_eq!(Region::from_binary(&region.to_binary().unwrap()).unwrap(), region);
+        }
+    }
+
+    #[test]
+    fn test_preferences_sorted() {
+        let measured = pings(&[
+            (Region::EuWest, 90.0),
+            (Region::NaEast, 35.5),
+            (Region::Sa, f32::NAN),
+            (Region::NaWest, 35.5),
+            (Region::Oce, -1.0),
+            (Region::Apac, 180.0),
+        ]);
+        assert_eq!(
+            RegionSelector::preferences(&measured),
+            vec![(Region::NaEast, 35.5), (Region::NaWest, 35.5), (Region::EuWest, 90.0), (Region::Apac, 180.0)]
+        );
+        assert_eq!(RegionSelector::best(&measured), Some(Region::NaEast));
+        assert_eq!(RegionSelector::best(&TMap::new()), None);
+    }
+
+    #[test]
+    fn test_select_hysteresis() {
+        let mut selector = RegionSelector::new(20.0);
+        assert_eq!(selector.select(&TMap::new()), None);
+        assert_eq!(selector.select(&pings(&[(Region::EuWest, 50.0), (Region::EuCentral, 60.0)])), Some(&Region::EuWest));
+
+        // Better, but not by more than the threshold
+        assert_eq!(selector.select(&pings(&[(Region::EuWest, 50.0), (Region::EuCentral, 30.0)])), Some(&Region::EuWest));
+        assert_eq!(selector.select(&pings(&[(Region::EuWest, 50.0), (Region::EuCentral, 31.0)])), Some(&Region::EuWest));
+
+        // Past the threshold
+        assert_eq!(selector.select(&pings(&[(Region::EuWest, 50.0), (Region::EuCentral, 29.0)])), Some(&Region::EuCentral));
+
+        // Current region no longer measured, or measured as invalid
+        assert_eq!(selector.select(&pings(&[(Region::EuWest, 80.0)])), Some(&Region::EuWest));
+        assert_eq!(selector.select(&pings(&[(Region::EuWest, f32::NAN), (Region::Mea, 95.0)])), Some(&Region::Mea));
+
+        // Nothing measured keeps the current choice
+        assert_eq!(selector.select(&TMap::new()), Some(&Region::Mea));
+
+        selector.reset();
+        assert_eq!(selector.current(), None);
+        assert_eq!(selector.select(&pings(&[(Region::Sa, 120.0), (Region::Oce, 110.0)])), Some(&Region::Oce));
+    }
+}
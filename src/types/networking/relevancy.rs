@@ -0,0 +1,253 @@
+//! Per-viewer replication relevancy scoring (interest management)
+
+use crate::types::{BoundingSphere, TArray, Transform};
+use super::{NetworkGUID, NetworkStats};
+use serde::{Deserialize, Serialize};
+#[cfg(not(feature = "std"))]
+use crate::types::math::float_math::FloatMath;
+
+/// Tuning for `RelevancyEvaluator`
+///
+/// Missing fields fall back to their defaults when deserializing.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RelevancyWeights {
+    /// Distance beyond which objects are not relevant (UE5: NetCullDistanceSquared, unsquared)
+    pub cull_distance: f64,
+    /// Exponent of the distance falloff; 1 is linear, higher values favor nearby objects more
+    pub distance_falloff: f64,
+    /// Half angle of the view cone in degrees
+    pub view_cone_half_angle: f64,
+    /// Score multiplier for objects outside the view cone, in [0, 1]
+    pub out_of_view_scale: f32,
+    /// Fraction of the byte budget kept on the worst connection, in [0, 1]
+    pub min_bandwidth_scale: f32,
+}
+
+impl Default for RelevancyWeights {
+    fn default() -> Self {
+        Self {
+            cull_distance: 15_000.0,
+            distance_falloff: 1.0,
+            view_cone_half_angle: 60.0,
+            out_of_view_scale: 0.25,
+            min_bandwidth_scale: 0.25,
+        }
+    }
+}
+
+/// Ranks objects by how relevant they are to one viewer
+///
+/// A score is the object's priority times a distance falloff (1 at the
+/// viewer, 0 at `cull_distance`) times `out_of_view_scale` when it is
+/// outside the view cone. Objects past the cull distance are dropped. The
+/// viewer's connection quality scales the byte budget of `take_top_n`, so
+/// players on bad connections get fewer, more relevant updates. The score
+/// buffer is reused between calls, so keep one evaluator per connection.
+#[derive(Debug, Clone)]
+pub struct RelevancyEvaluator {
+    weights: RelevancyWeights,
+    scores: TArray<(NetworkGUID, f32)>,
+    bandwidth_scale: f32,
+}
+
+impl RelevancyEvaluator {
+    /// Create an evaluator with the given weights
+    pub fn new(weights: RelevancyWeights) -> Self {
+        Self {
+            weights,
+            scores: TArray::new(),
+            bandwidth_scale: 1.0,
+        }
+    }
+
+    /// The weights in use
+    pub fn weights(&self) -> &RelevancyWeights {
+        &self.weights
+    }
+
+    /// Score every candidate for a viewer, highest first
+    ///
+    /// Candidates are `(guid, bounds, priority)`. Ties are ordered by GUID so
+    /// the result is deterministic. Without `stats` the connection is
+    /// treated as perfect.
+    pub fn evaluate(
+        &mut self,
+        viewer: &Transform,
+        candidates: &[(NetworkGUID, BoundingSphere, f32)],
+        stats: Option<&NetworkStats>,
+    ) -> &TArray<(NetworkGUID, f32)> {
+        let quality = stats.map_or(1.0, |stats| stats.connection_quality().clamp(0.0, 1.0));
+        let min_scale = self.weights.min_bandwidth_scale.clamp(0.0, 1.0);
+        self.bandwidth_scale = min_scale + (1.0 - min_scale) * quality;
+
+        let forward = viewer.get_forward_vector();
+        let half_angle = self.weights.view_cone_half_angle.to_radians();
+        let cull_distance = self.weights.cull_distance.max(f64::EPSILON);
+
+        self.scores.clear();
+        for &(guid, bounds, priority) in candidates {
+            let to_center = bounds.center - viewer.location;
+            let center_distance = to_center.length();
+            let distance = (center_distance - bounds.radius).max(0.0);
+            if distance >= cull_distance {
+                continue;
+            }
+            let distance_factor = (1.0 - distance / cull_distance).powf(self.weights.distance_falloff.max(0.0));
+
+            // Widen the cone by the sphere's angular radius, so large objects count as soon as an edge is visible
+            let in_view = distance == 0.0 || {
+                let angle = forward.dot(to_center / center_distance).clamp(-1.0, 1.0).acos();
+                let angular_radius = (bounds.radius / center_distance).clamp(0.0, 1.0).asin();
+                angle - angular_radius <= half_angle
+            };
+            let view_factor = if in_view { 1.0 } else { self.weights.out_of_view_scale };
+
+            self.scores.push((guid, priority * distance_factor as f32 * view_factor));
+        }
+        self.scores.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.value.cmp(&b.0.value)));
+        &self.scores
+    }
+
+    /// Scores from the last `evaluate`, highest first
+    pub fn scores(&self) -> &TArray<(NetworkGUID, f32)> {
+        &self.scores
+    }
+
+    /// Fraction of the byte budget granted by the last evaluated connection quality
+    pub fn bandwidth_scale(&self) -> f32 {
+        self.bandwidth_scale
+    }
+
+    /// The highest scoring objects whose estimated sizes fit in the budget
+    ///
+    /// The budget is first scaled by `bandwidth_scale`. Objects are taken in
+    /// score order and selection stops at the first one that doesn't fit, so
+    /// a smaller, less relevant object never displaces a more relevant one.
+    pub fn take_top_n<F>(&self, budget_bytes: usize, mut estimated_size: F) -> TArray<(NetworkGUID, f32)>
+    where
+        F: FnMut(NetworkGUID) -> usize,
+    {
+        let mut remaining = (budget_bytes as f64 * self.bandwidth_scale as f64) as usize;
+        let mut selected = TArray::new();
+        for &(guid, score) in self.scores.iter() {
+            let size = estimated_size(guid);
+            if size > remaining {
+                break;
+            }
+            remaining -= size;
+            selected.push((guid, score));
+        }
+        selected
+    }
+}
+
+impl Default for RelevancyEvaluator {
+    fn default() -> Self {
+        Self::new(RelevancyWeights::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{RandomStream, Rotator, Vector};
+
+    fn viewer() -> Transform {
+        // Looking down +X from the origin
+        Transform::from_location_rotator(Vector::ZERO, Rotator::ZERO)
+    }
+
+    #[test]
+    fn test_closer_in_view_outranks_farther_out_of_view() {
+        let mut evaluator = RelevancyEvaluator::new(RelevancyWeights::default());
+        let mut stream = RandomStream::new(1808);
+        let mut candidates = Vec::new();
+        for value in 1..=400 {
+            let center = stream.vrand() * stream.frand_range_f64(0.0, 14_000.0);
+            let radius = stream.frand_range_f64(0.0, 200.0);
+            let priority = stream.frand_range(0.5, 3.0);
+            candidates.push((NetworkGUID::new(value), BoundingSphere::new(center, radius), priority));
+        }
+        let scores: Vec<(NetworkGUID, f32)> = evaluator.evaluate(&viewer(), &candidates, None).to_vec();
+        assert!(scores.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+
+        let score_of = |guid: NetworkGUID| scores.iter().find(|entry| entry.0 == guid).map(|entry| entry.1);
+        let in_view = |bounds: &BoundingSphere| {
+            let direction = (bounds.center - Vector::ZERO).normalize();
+            let angle = direction.x.clamp(-1.0, 1.0).acos() - (bounds.radius / bounds.center.length()).asin();
+            angle.to_degrees() <= 60.0
+        };
+        let mut compared = 0;
+        for a in &candidates {
+            for b in &candidates {
+                let closer = a.1.center.length() - a.1.radius < b.1.center.length() - b.1.radius;
+                if closer && in_view(&a.1) && !in_view(&b.1) && a.2 >= b.2 {
+                    if let (Some(score_a), Some(score_b)) = (score_of(a.0), score_of(b.0)) {
+                        assert!(score_a > score_b, "{:?} vs {:?}", a, b);
+                        compared += 1;
+                    }
+                }
+            }
+        }
+        assert!(compared > 1_000);
+    }
+
+    #[test]
+    fn test_scoring_components() {
+        let weights = RelevancyWeights { cull_distance: 1000.0, out_of_view_scale: 0.5, ..Default::default() };
+        let mut evaluator = RelevancyEvaluator::new(weights);
+        let sphere = |x: f64, y: f64, radius: f64| BoundingSphere::new(Vector::new(x, y, 0.0), radius);
+        let candidates = [
+            (NetworkGUID::new(1), sphere(500.0, 0.0, 0.0), 1.0),
+            (NetworkGUID::new(2), sphere(-500.0, 0.0, 0.0), 1.0),
+            (NetworkGUID::new(3), sphere(1500.0, 0.0, 0.0), 10.0),
+            (NetworkGUID::new(4), sphere(1500.0, 0.0, 600.0), 1.0),
+            (NetworkGUID::new(5), sphere(0.0, 0.0, 50.0), 2.0),
+            // Center outside the cone but the edge reaches into it
+            (NetworkGUID::new(6), sphere(300.0, 600.0, 300.0), 1.0),
+        ];
+        let scores = evaluator.evaluate(&viewer(), &candidates, None).clone();
+        let expected = [(5, 2.0), (6, 1.0 - (450_000.0_f64.sqrt() - 300.0) / 1000.0), (1, 0.5), (2, 0.25), (4, 0.1)];
+        assert_eq!(scores.len(), expected.len());
+        for (&(guid, score), &(value, expected)) in scores.iter().zip(&expected) {
+            assert_eq!(guid.value, value);
+            assert!((score as f64 - expected).abs() < 1e-5, "{} scored {}", value, score);
+        }
+    }
+
+    #[test]
+    fn test_default_matches_new() {
+        let evaluator = RelevancyEvaluator::default();
+        assert_eq!(evaluator.bandwidth_scale(), 1.0);
+        assert_eq!(evaluator.bandwidth_scale(), RelevancyEvaluator::new(RelevancyWeights::default()).bandwidth_scale());
+    }
+
+    #[test]
+    fn test_degraded_connection_shrinks_selection() {
+        let mut evaluator = RelevancyEvaluator::new(RelevancyWeights::default());
+        let candidates: Vec<_> = (1..=50)
+            .map(|i| (NetworkGUID::new(i), BoundingSphere::new(Vector::new(i as f64 * 100.0, 0.0, 0.0), 10.0), 1.0))
+            .collect();
+        let size = |guid: NetworkGUID| 40 + guid.value as usize;
+
+        let mut good = NetworkStats::new();
+        good.set_ping(20.0);
+        evaluator.evaluate(&viewer(), &candidates, Some(&good));
+        let good_selection = evaluator.take_top_n(1_000, size);
+
+        let mut bad = NetworkStats::new();
+        bad.set_ping(180.0);
+        bad.set_packet_loss(0.04);
+        bad.set_jitter(60.0);
+        evaluator.evaluate(&viewer(), &candidates, Some(&bad));
+        let bad_selection = evaluator.take_top_n(1_000, size);
+
+        assert!(evaluator.bandwidth_scale() < 0.5);
+        assert!(bad_selection.len() < good_selection.len());
+        assert_eq!(good_selection.as_slice()[..bad_selection.len()], *bad_selection.as_slice());
+        let used: usize = good_selection.iter().map(|entry| size(entry.0)).sum();
+        assert!(used <= 1_000);
+        assert!(evaluator.take_top_n(0, size).is_empty());
+    }
+}
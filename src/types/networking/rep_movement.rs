@@ -1,13 +1,16 @@
 //! Replication information for network movement
 
 use crate::BinarySerializable;
-use crate::types::{Vector, Rotator};
-use super::NetworkGUID;
+use crate::types::{DateTime, FrameTime, Vector, Rotator};
+use super::{BitReader, BitWriter, BitstreamError, NetworkGUID, RotatorQuantization, ValidationLimits, VectorQuantization};
 use serde::{Deserialize, Serialize};
-use std::fmt;
+use core::fmt;
+use alloc::vec::Vec;
 
 /// Replication information for network movement
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(feature = "rkyv", archive(check_bytes, compare(PartialEq)), archive_attr(derive(Debug)))]
 pub struct RepMovement {
     /// Current location
     pub location: Vector,
@@ -15,7 +18,7 @@ pub struct RepMovement {
     pub rotation: Rotator,
     /// Current linear velocity
     pub linear_velocity: Vector,
-    /// Current angular velocity (pitch, yaw, roll rates)
+    /// Current angular velocity in degrees per second about the world X, Y and Z axes
     pub angular_velocity: Vector,
     /// Location base (for relative movement)
     pub location_base: Option<NetworkGUID>,
@@ -44,6 +47,16 @@ impl fmt::Display for RepMovement {
 
 impl BinarySerializable for RepMovement {}
 
+/// Five vectors/rotators, the optional base, the frame and two flags
+impl crate::types::SerializedSize for RepMovement {
+    fn serialized_size(&self) -> usize {
+        5 * 24 + self.location_base.serialized_size() + 4 + 2
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl crate::types::ZeroCopy for RepMovement {}
+
 impl RepMovement {
     /// Create a new RepMovement with default values
     pub fn new() -> Self {
@@ -108,10 +121,138 @@ impl RepMovement {
         self.server_frame = frame;
     }
 
+    /// Wall-clock time of `server_frame` under `frame_time`'s tick rate and anchor
+    pub fn timestamp(&self, frame_time: &FrameTime) -> DateTime {
+        frame_time.frame_to_datetime(self.server_frame)
+    }
+
     /// Mark as simulated physics movement
     pub fn set_simulated(&mut self, simulated: bool) {
         self.is_simulated = simulated;
     }
+
+    /// Check that every vector and rotation axis is finite and within the default limits
+    pub fn is_valid(&self) -> bool {
+        self.is_valid_with(&ValidationLimits::default())
+    }
+
+    /// Check that every vector and rotation axis is finite and within the movement caps in `limits`
+    ///
+    /// For a field-by-field report use [`Validate`](super::Validate).
+    pub fn is_valid_with(&self, limits: &ValidationLimits) -> bool {
+        let within = |value: Vector, max_component: f64| value.is_finite() && value.abs().max_element() <= max_component;
+        within(self.location, limits.max_world_coordinate)
+            && within(self.relative_location, limits.max_world_coordinate)
+            && within(self.angular_velocity, limits.max_angular_speed)
+            && self.linear_velocity.is_finite()
+            && self.linear_velocity.length() <= limits.max_speed
+            && [self.rotation.pitch, self.rotation.yaw, self.rotation.roll].iter().all(|axis| axis.is_finite())
+    }
+
+    /// Dead-reckon this movement forward by `delta_time` seconds
+    ///
+    /// Moves the location along `linear_velocity` and turns the rotation by
+    /// `angular_velocity` (see [`Rotator::integrate_angular_velocity`]).
+    /// With a location base, `relative_location` moves too, treating the
+    /// base as static. Velocities and the server frame are unchanged, and a
+    /// non-finite `delta_time` returns the movement as is.
+    pub fn step(&self, delta_time: f64) -> RepMovement {
+        let mut next = *self;
+        if !delta_time.is_finite() {
+            return next;
+        }
+        let offset = self.linear_velocity * delta_time;
+        next.location += offset;
+        if self.has_location_base {
+            next.relative_location += offset;
+        }
+        next.rotation = self.rotation.integrate_angular_velocity(self.angular_velocity, delta_time);
+        next
+    }
+
+    /// This movement with its position change since `previous` limited to `max_speed * delta_time`
+    ///
+    /// Server-side speed-hack guard: the location moves toward the reported
+    /// one by at most the distance allowed for the tick, and the velocity is
+    /// capped at `max_speed`. A non-finite reported location, or a negative
+    /// or non-finite `delta_time`, keeps the previous location.
+    pub fn clamped_delta_from(&self, previous: &RepMovement, max_speed: f64, delta_time: f64) -> RepMovement {
+        let max_speed = if max_speed.is_finite() { max_speed.max(0.0) } else { 0.0 };
+        let delta_time = if delta_time.is_finite() { delta_time.max(0.0) } else { 0.0 };
+        let delta = self.location - previous.location;
+
+        let mut clamped = *self;
+        clamped.location = if delta.is_finite() {
+            previous.location + delta.clamp_length_max(max_speed * delta_time)
+        } else {
+            previous.location
+        };
+        if self.linear_velocity.is_finite() {
+            clamped.linear_velocity = self.linear_velocity.clamp_length_max(max_speed);
+        } else {
+            clamped.linear_velocity = Vector::ZERO;
+        }
+        clamped
+    }
+
+    /// Serialize with UE-style quantization (FRepMovement::NetSerialize)
+    ///
+    /// Layout, bit-packed LSB first:
+    /// `is_simulated` bit, location-base bit, location, rotation, linear
+    /// velocity, angular velocity (only when simulated), base GUID (32 bits)
+    /// and relative location (only with a location base), server frame
+    /// (32 bits). Vectors use `quantization` and the rotation uses
+    /// `rotation`. As in UE, angular velocity is dropped for non-simulated
+    /// movement and reads back as zero.
+    pub fn serialize_compressed(&self, quantization: VectorQuantization, rotation: RotatorQuantization) -> Vec<u8> {
+        let has_base = self.has_location_base && self.location_base.is_some();
+
+        let mut writer = BitWriter::new();
+        writer.write_bit(self.is_simulated);
+        writer.write_bit(has_base);
+        writer.write_quantized_vector(self.location, quantization);
+        writer.write_compressed_rotator(self.rotation, rotation);
+        writer.write_quantized_vector(self.linear_velocity, quantization);
+        if self.is_simulated {
+            writer.write_quantized_vector(self.angular_velocity, quantization);
+        }
+        if let (true, Some(base)) = (has_base, self.location_base) {
+            writer.write_bits(base.value, 32);
+            writer.write_quantized_vector(self.relative_location, quantization);
+        }
+        writer.write_bits(self.server_frame, 32);
+        writer.into_bytes()
+    }
+
+    /// Read movement written by [`RepMovement::serialize_compressed`] with the same quantization
+    pub fn deserialize_compressed(
+        data: &[u8],
+        quantization: VectorQuantization,
+        rotation: RotatorQuantization,
+    ) -> Result<Self, BitstreamError> {
+        let mut reader = BitReader::new(data);
+        let mut movement = Self::new();
+        movement.is_simulated = reader.read_bit()?;
+        let has_base = reader.read_bit()?;
+        movement.location = reader.read_quantized_vector(quantization)?;
+        movement.rotation = reader.read_compressed_rotator(rotation)?;
+        movement.linear_velocity = reader.read_quantized_vector(quantization)?;
+        if movement.is_simulated {
+            movement.angular_velocity = reader.read_quantized_vector(quantization)?;
+        }
+        if has_base {
+            let base = NetworkGUID::new(reader.read_bits(32)?);
+            let relative = reader.read_quantized_vector(quantization)?;
+            movement.set_location_base(base, relative);
+        }
+        movement.server_frame = reader.read_bits(32)?;
+        Ok(movement)
+    }
+
+    /// Size in bytes of the compressed representation, for bandwidth budgeting
+    pub fn compressed_size(&self, quantization: VectorQuantization, rotation: RotatorQuantization) -> usize {
+        self.serialize_compressed(quantization, rotation).len()
+    }
 }
 
 impl Default for RepMovement {
@@ -123,6 +264,10 @@ impl Default for RepMovement {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::Validate;
+
+    /// Location (1, -2, 3), yaw 90, frame 7 with whole-unit vectors and byte rotation
+    const GOLDEN_COMPRESSED: [u8; 9] = [0x88, 0xEA, 0x02, 0x01, 0x3F, 0x00, 0x00, 0x00, 0x00];
 
     #[test]
     fn test_rep_movement() {
@@ -154,4 +299,164 @@ mod tests {
         let deserialized: RepMovement = serde_json::from_str(&json).unwrap();
         assert_eq!(rep_movement, deserialized);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_compressed_round_trip() {
+        let mut movement = RepMovement::from_transform(
+            Vector::new(1234.567, -89.01, 20.5),
+            Rotator::new(-15.0, 135.5, 2.0),
+            Vector::new(600.0, -0.25, 0.0),
+        );
+        movement.set_simulated(true);
+        movement.angular_velocity = Vector::new(0.0, 45.0, -3.3);
+        movement.set_location_base(NetworkGUID::new(77), Vector::new(5.5, -6.25, 0.01));
+        movement.set_server_frame(4242);
+
+        for quantization in [VectorQuantization::Whole, VectorQuantization::Ten, VectorQuantization::Hundred] {
+            for rotation in [RotatorQuantization::Byte, RotatorQuantization::Short] {
+                let bytes = movement.serialize_compressed(quantization, rotation);
+                assert_eq!(bytes.len(), movement.compressed_size(quantization, rotation));
+
+                let restored = RepMovement::deserialize_compressed(&bytes, quantization, rotation).unwrap();
+                let max_error = quantization.step() * 0.5 + 1e-9;
+                for (a, b) in [
+                    (movement.location, restored.location),
+                    (movement.linear_velocity, restored.linear_velocity),
+                    (movement.angular_velocity, restored.angular_velocity),
+                    (movement.relative_location, restored.relative_location),
+                ] {
+                    assert!((a - b).abs().max_element() <= max_error);
+                }
+                assert!(movement.rotation.is_nearly_equal(restored.rotation, rotation.step() * 0.5 + 1e-9));
+                assert_eq!(restored.location_base, Some(NetworkGUID::new(77)));
+                assert!(restored.is_simulated && restored.has_location_base);
+                assert_eq!(restored.server_frame, 4242);
+            }
+        }
+    }
+
+    #[test]
+    fn test_compressed_is_smaller_and_drops_unsimulated_angular_velocity() {
+        let mut movement = RepMovement::from_transform(
+            Vector::new(100.0, 200.0, 300.0),
+            Rotator::new(0.0, 90.0, 0.0),
+            Vector::new(5.0, 0.0, 0.0),
+        );
+        movement.angular_velocity = Vector::new(1.0, 2.0, 3.0);
+
+        let bytes = movement.serialize_compressed(VectorQuantization::Ten, RotatorQuantization::Byte);
+        assert!(bytes.len() < movement.to_binary().unwrap().len() / 4);
+
+        let restored =
+            RepMovement::deserialize_compressed(&bytes, VectorQuantization::Ten, RotatorQuantization::Byte).unwrap();
+        assert_eq!(restored.angular_velocity, Vector::ZERO);
+        assert_eq!(restored.location, movement.location);
+        assert_eq!(restored.rotation, movement.rotation);
+    }
+
+    #[test]
+    fn test_compressed_format_is_stable() {
+        let mut movement = RepMovement::from_transform(
+            Vector::new(1.0, -2.0, 3.0),
+            Rotator::new(0.0, 90.0, 0.0),
+            Vector::ZERO,
+        );
+        movement.set_server_frame(7);
+
+        let bytes = movement.serialize_compressed(VectorQuantization::Whole, RotatorQuantization::Byte);
+        assert_eq!(bytes, GOLDEN_COMPRESSED);
+
+        let truncated = &bytes[..bytes.len() - 1];
+        assert_eq!(
+            RepMovement::deserialize_compressed(truncated, VectorQuantization::Whole, RotatorQuantization::Byte),
+            Err(BitstreamError::Overrun { requested: 32, remaining: 29 })
+        );
+    }
+
+    #[test]
+    fn test_validity_and_limits() {
+        let legit = RepMovement::from_transform(Vector::new(100.0, 0.0, 0.0), Rotator::new(0.0, 45.0, 0.0), Vector::new(600.0, 0.0, 0.0));
+        assert!(legit.is_valid());
+
+        for corrupt in [
+            RepMovement::from_transform(Vector::new(f64::NAN, 0.0, 0.0), Rotator::ZERO, Vector::ZERO),
+            RepMovement::from_transform(Vector::ZERO, Rotator::new(0.0, f64::INFINITY, 0.0), Vector::ZERO),
+            RepMovement::from_transform(Vector::ZERO, Rotator::ZERO, Vector::new(0.0, f64::NAN, 0.0)),
+            RepMovement::from_transform(Vector::new(0.0, 0.0, 1e13), Rotator::ZERO, Vector::ZERO),
+            RepMovement::from_transform(Vector::ZERO, Rotator::ZERO, Vector::new(1e8, 0.0, 0.0)),
+        ] {
+            assert!(!corrupt.is_valid(), "{}", corrupt);
+        }
+
+        let limits = ValidationLimits { max_speed: 1200.0, max_angular_speed: 720.0, ..Default::default() };
+        let mut spinning = legit;
+        spinning.linear_velocity = Vector::new(3000.0, 4000.0, 0.0);
+        spinning.angular_velocity = Vector::new(0.0, f64::NAN, -5000.0);
+        assert!(!spinning.is_valid_with(&limits));
+        assert_eq!(spinning.sanitize_with(&limits), Ok(()));
+        assert!(spinning.is_valid_with(&limits));
+        assert!((spinning.linear_velocity - Vector::new(720.0, 960.0, 0.0)).length() < 1e-9);
+        assert_eq!(spinning.angular_velocity, Vector::new(0.0, 0.0, -720.0));
+
+        // A NaN location stays invalid after sanitizing
+        let mut lost = RepMovement::from_transform(Vector::new(f64::NAN, 0.0, 0.0), Rotator::ZERO, Vector::ZERO);
+        assert!(lost.sanitize_with(&limits).is_err());
+        assert!(!lost.is_valid_with(&limits));
+
+        let config: ValidationLimits = serde_json::from_str(r#"{"max_speed": 900.0}"#).unwrap();
+        assert_eq!(config, ValidationLimits { max_speed: 900.0, ..Default::default() });
+    }
+
+    #[test]
+    fn test_clamped_delta_from() {
+        let previous = RepMovement::from_transform(Vector::ZERO, Rotator::ZERO, Vector::ZERO);
+        let (max_speed, delta_time) = (600.0, 0.1);
+
+        // Fast but legitimate: just under 60 units this tick
+        let fast = RepMovement::from_transform(Vector::new(35.0, 47.9, 0.0), Rotator::ZERO, Vector::new(599.0, 0.0, 0.0));
+        assert_eq!(fast.clamped_delta_from(&previous, max_speed, delta_time), fast);
+
+        // Teleport across the map is pulled back along the same direction
+        let mut teleport = RepMovement::from_transform(Vector::new(3e7, 4e7, 0.0), Rotator::new(0.0, 90.0, 0.0), Vector::new(1e8, 0.0, 0.0));
+        teleport.set_server_frame(12);
+        let clamped = teleport.clamped_delta_from(&previous, max_speed, delta_time);
+        assert!((clamped.location - Vector::new(36.0, 48.0, 0.0)).length() < 1e-9);
+        assert_eq!(clamped.linear_velocity, Vector::new(600.0, 0.0, 0.0));
+        assert_eq!((clamped.rotation, clamped.server_frame), (teleport.rotation, 12));
+
+        let corrupt = RepMovement::from_transform(Vector::new(f64::NAN, 0.0, 0.0), Rotator::ZERO, Vector::new(f64::NAN, 0.0, 0.0));
+        let held = corrupt.clamped_delta_from(&previous, max_speed, delta_time);
+        assert_eq!((held.location, held.linear_velocity), (Vector::ZERO, Vector::ZERO));
+        assert_eq!(teleport.clamped_delta_from(&previous, max_speed, -1.0).location, Vector::ZERO);
+    }
+
+    #[test]
+    fn test_step_dead_reckoning() {
+        let mut movement = RepMovement::from_transform(Vector::new(100.0, 0.0, 50.0), Rotator::ZERO, Vector::new(600.0, 0.0, -20.0));
+        movement.angular_velocity = Vector::new(0.0, 0.0, 90.0);
+        movement.set_server_frame(12);
+
+        let mut predicted = movement;
+        for _ in 0..60 {
+            predicted = predicted.step(1.0 / 60.0);
+        }
+        assert!((predicted.location - Vector::new(700.0, 0.0, 30.0)).length() < 1e-9);
+        assert!((predicted.rotation.yaw - 90.0).abs() < 0.01);
+        assert_eq!((predicted.linear_velocity, predicted.angular_velocity), (movement.linear_velocity, movement.angular_velocity));
+        assert_eq!(predicted.server_frame, 12);
+
+        movement.set_location_base(NetworkGUID::new(5), Vector::new(1.0, 0.0, 0.0));
+        let based = movement.step(0.5);
+        assert_eq!(based.relative_location, Vector::new(301.0, 0.0, -10.0));
+        assert_eq!(movement.step(f64::NAN), movement);
+    }
+
+    #[test]
+    fn test_timestamp() {
+        let start = DateTime::from_unix_timestamp(1_700_000_000);
+        let frames = FrameTime::new(30.0).unwrap().with_anchor(start, u32::MAX);
+        let mut movement = RepMovement::new();
+        movement.set_server_frame(59);
+        assert_eq!(movement.timestamp(&frames), start + crate::types::Timespan::from_seconds(2.0));
+    }
+}
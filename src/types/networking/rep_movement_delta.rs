@@ -0,0 +1,396 @@
+//! Delta compression between two RepMovement snapshots
+
+use crate::BinarySerializable;
+use crate::types::{normalize_angle, Rotator, Vector};
+use super::{BitReader, BitWriter, BitstreamError, NetworkGUID, RepMovement, SerializeBits};
+use serde::{Deserialize, Serialize};
+use core::fmt;
+
+/// Tolerances below which a field is considered unchanged
+///
+/// Keeps micro-jitter from defeating delta compression. Because small changes
+/// are dropped, deltas should be taken against the last acknowledged state
+/// rather than chained from the previous delta.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MovementDeltaTolerances {
+    /// Per-component tolerance for location and relative location
+    pub location: f64,
+    /// Per-axis tolerance for rotation, in degrees
+    pub rotation: f64,
+    /// Per-component tolerance for linear and angular velocity
+    pub velocity: f64,
+}
+
+impl MovementDeltaTolerances {
+    /// Every difference counts as a change
+    pub const EXACT: Self = Self {
+        location: 0.0,
+        rotation: 0.0,
+        velocity: 0.0,
+    };
+
+    /// Create tolerances for location, rotation and velocity
+    pub fn new(location: f64, rotation: f64, velocity: f64) -> Self {
+        Self {
+            location,
+            rotation,
+            velocity,
+        }
+    }
+}
+
+impl Default for MovementDeltaTolerances {
+    /// Matches the precision of FVector_NetQuantize100 and short rotator compression
+    fn default() -> Self {
+        Self::new(0.01, 0.01, 0.01)
+    }
+}
+
+/// Changed fields between a baseline RepMovement and a newer one
+///
+/// `changed_fields()` is a bitmask of the `RepMovementDelta::*` flags, and
+/// only the flagged fields carry a value.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct RepMovementDelta {
+    changed: u16,
+    location: Option<Vector>,
+    rotation: Option<Rotator>,
+    linear_velocity: Option<Vector>,
+    angular_velocity: Option<Vector>,
+    location_base: Option<Option<NetworkGUID>>,
+    relative_location: Option<Vector>,
+    server_frame: Option<u32>,
+    is_simulated: Option<bool>,
+    has_location_base: Option<bool>,
+}
+
+impl fmt::Display for RepMovementDelta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RepMovementDelta(Changed: {:#05x}, Fields: {})", self.changed, self.num_changed())
+    }
+}
+
+impl BinarySerializable for RepMovementDelta {}
+
+fn vector_changed(a: Vector, b: Vector, tolerance: f64) -> bool {
+    (a - b).abs().max_element() > tolerance
+}
+
+fn rotator_changed(a: Rotator, b: Rotator, tolerance: f64) -> bool {
+    [a.pitch - b.pitch, a.yaw - b.yaw, a.roll - b.roll]
+        .iter()
+        .any(|delta| normalize_angle(*delta).abs() > tolerance)
+}
+
+impl RepMovementDelta {
+    /// Location changed
+    pub const LOCATION: u16 = 1 << 0;
+    /// Rotation changed
+    pub const ROTATION: u16 = 1 << 1;
+    /// Linear velocity changed
+    pub const LINEAR_VELOCITY: u16 = 1 << 2;
+    /// Angular velocity changed
+    pub const ANGULAR_VELOCITY: u16 = 1 << 3;
+    /// Location base GUID changed
+    pub const LOCATION_BASE: u16 = 1 << 4;
+    /// Relative location changed
+    pub const RELATIVE_LOCATION: u16 = 1 << 5;
+    /// Server frame changed
+    pub const SERVER_FRAME: u16 = 1 << 6;
+    /// Simulated physics flag changed
+    pub const IS_SIMULATED: u16 = 1 << 7;
+    /// Location base validity flag changed
+    pub const HAS_LOCATION_BASE: u16 = 1 << 8;
+
+    /// Number of bits used for the changed-field mask on the wire
+    const MASK_BITS: u32 = 9;
+
+    /// Compute the delta from `baseline` to `current` using the given tolerances
+    pub fn between(baseline: &RepMovement, current: &RepMovement, tolerances: &MovementDeltaTolerances) -> Self {
+        let mut delta = Self::default();
+        if vector_changed(current.location, baseline.location, tolerances.location) {
+            delta.changed |= Self::LOCATION;
+            delta.location = Some(current.location);
+        }
+        if rotator_changed(current.rotation, baseline.rotation, tolerances.rotation) {
+            delta.changed |= Self::ROTATION;
+            delta.rotation = Some(current.rotation);
+        }
+        if vector_changed(current.linear_velocity, baseline.linear_velocity, tolerances.velocity) {
+            delta.changed |= Self::LINEAR_VELOCITY;
+            delta.linear_velocity = Some(current.linear_velocity);
+        }
+        if vector_changed(current.angular_velocity, baseline.angular_velocity, tolerances.velocity) {
+            delta.changed |= Self::ANGULAR_VELOCITY;
+            delta.angular_velocity = Some(current.angular_velocity);
+        }
+        if current.location_base != baseline.location_base {
+            delta.changed |= Self::LOCATION_BASE;
+            delta.location_base = Some(current.location_base);
+        }
+        if vector_changed(current.relative_location, baseline.relative_location, tolerances.location) {
+            delta.changed |= Self::RELATIVE_LOCATION;
+            delta.relative_location = Some(current.relative_location);
+        }
+        if current.server_frame != baseline.server_frame {
+            delta.changed |= Self::SERVER_FRAME;
+            delta.server_frame = Some(current.server_frame);
+        }
+        if current.is_simulated != baseline.is_simulated {
+            delta.changed |= Self::IS_SIMULATED;
+            delta.is_simulated = Some(current.is_simulated);
+        }
+        if current.has_location_base != baseline.has_location_base {
+            delta.changed |= Self::HAS_LOCATION_BASE;
+            delta.has_location_base = Some(current.has_location_base);
+        }
+        delta
+    }
+
+    /// Rebuild the newer movement by applying this delta to `baseline`
+    pub fn apply(&self, baseline: &RepMovement) -> RepMovement {
+        let mut result = *baseline;
+        if let Some(location) = self.location {
+            result.location = location;
+        }
+        if let Some(rotation) = self.rotation {
+            result.rotation = rotation;
+        }
+        if let Some(velocity) = self.linear_velocity {
+            result.linear_velocity = velocity;
+        }
+        if let Some(velocity) = self.angular_velocity {
+            result.angular_velocity = velocity;
+        }
+        if let Some(base) = self.location_base {
+            result.location_base = base;
+        }
+        if let Some(relative) = self.relative_location {
+            result.relative_location = relative;
+        }
+        if let Some(frame) = self.server_frame {
+            result.server_frame = frame;
+        }
+        if let Some(simulated) = self.is_simulated {
+            result.is_simulated = simulated;
+        }
+        if let Some(has_base) = self.has_location_base {
+            result.has_location_base = has_base;
+        }
+        result
+    }
+
+    /// Bitmask of the changed fields
+    pub fn changed_fields(&self) -> u16 {
+        self.changed
+    }
+
+    /// Check whether every field in `flags` changed
+    pub fn has_changed(&self, flags: u16) -> bool {
+        self.changed & flags == flags
+    }
+
+    /// Check if nothing changed
+    pub fn is_empty(&self) -> bool {
+        self.changed == 0
+    }
+
+    /// Number of changed fields
+    pub fn num_changed(&self) -> u32 {
+        self.changed.count_ones()
+    }
+}
+
+impl RepMovement {
+    /// Compute the delta from `baseline` to this movement with default tolerances
+    pub fn diff(&self, baseline: &RepMovement) -> RepMovementDelta {
+        RepMovementDelta::between(baseline, self, &MovementDeltaTolerances::default())
+    }
+
+    /// Compute the delta from `baseline` to this movement with custom tolerances
+    pub fn diff_with_tolerances(&self, baseline: &RepMovement, tolerances: &MovementDeltaTolerances) -> RepMovementDelta {
+        RepMovementDelta::between(baseline, self, tolerances)
+    }
+}
+
+/// Written as the changed-field mask followed by each changed field
+///
+/// Vectors use NetQuantize100 and the rotation short compression, so applied
+/// values match the originals within those quantization steps.
+impl SerializeBits for RepMovementDelta {
+    fn write_bits(&self, writer: &mut BitWriter) {
+        writer.write_bits(self.changed as u32, Self::MASK_BITS);
+        if let Some(location) = self.location {
+            writer.write(&location);
+        }
+        if let Some(rotation) = self.rotation {
+            writer.write(&rotation);
+        }
+        for vector in [self.linear_velocity, self.angular_velocity].into_iter().flatten() {
+            writer.write(&vector);
+        }
+        if let Some(base) = self.location_base {
+            writer.write(&base.unwrap_or(NetworkGUID::INVALID));
+        }
+        if let Some(relative) = self.relative_location {
+            writer.write(&relative);
+        }
+        if let Some(frame) = self.server_frame {
+            writer.write_bits(frame, 32);
+        }
+        for flag in [self.is_simulated, self.has_location_base].into_iter().flatten() {
+            writer.write(&flag);
+        }
+    }
+
+    fn read_bits(reader: &mut BitReader<'_>) -> Result<Self, BitstreamError> {
+        let changed = reader.read_bits(Self::MASK_BITS)? as u16;
+        let mut delta = Self {
+            changed,
+            ..Self::default()
+        };
+        if changed & Self::LOCATION != 0 {
+            delta.location = Some(reader.read()?);
+        }
+        if changed & Self::ROTATION != 0 {
+            delta.rotation = Some(reader.read()?);
+        }
+        if changed & Self::LINEAR_VELOCITY != 0 {
+            delta.linear_velocity = Some(reader.read()?);
+        }
+        if changed & Self::ANGULAR_VELOCITY != 0 {
+            delta.angular_velocity = Some(reader.read()?);
+        }
+        if changed & Self::LOCATION_BASE != 0 {
+            let guid: NetworkGUID = reader.read()?;
+            delta.location_base = Some(Some(guid).filter(|guid| guid.is_valid()));
+        }
+        if changed & Self::RELATIVE_LOCATION != 0 {
+            delta.relative_location = Some(reader.read()?);
+        }
+        if changed & Self::SERVER_FRAME != 0 {
+            delta.server_frame = Some(reader.read_bits(32)?);
+        }
+        if changed & Self::IS_SIMULATED != 0 {
+            delta.is_simulated = Some(reader.read()?);
+        }
+        if changed & Self::HAS_LOCATION_BASE != 0 {
+            delta.has_location_base = Some(reader.read()?);
+        }
+        Ok(delta)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_movement() -> RepMovement {
+        let mut movement = RepMovement::from_transform(
+            Vector::new(100.0, -250.5, 30.0),
+            Rotator::new(0.0, 45.0, 0.0),
+            Vector::new(600.0, 0.0, -10.0),
+        );
+        movement.set_server_frame(100);
+        movement
+    }
+
+    fn encode(delta: &RepMovementDelta) -> Vec<u8> {
+        let mut writer = BitWriter::new();
+        writer.write(delta);
+        writer.into_bytes()
+    }
+
+    #[test]
+    fn test_identical_snapshots_produce_empty_delta() {
+        let movement = sample_movement();
+        let delta = movement.diff(&movement);
+
+        assert!(delta.is_empty());
+        assert_eq!(delta.apply(&movement), movement);
+        assert_eq!(encode(&delta).len(), 2);
+    }
+
+    #[test]
+    fn test_single_field_change() {
+        let baseline = sample_movement();
+        let mut current = baseline;
+        current.rotation.yaw = 90.0;
+
+        let delta = current.diff(&baseline);
+        assert_eq!(delta.changed_fields(), RepMovementDelta::ROTATION);
+        assert_eq!(delta.num_changed(), 1);
+        assert_eq!(delta.apply(&baseline), current);
+
+        // Mask, three rotator flag bits and one 16-bit axis
+        assert_eq!(encode(&delta).len(), 4);
+    }
+
+    #[test]
+    fn test_tolerances_ignore_jitter() {
+        let baseline = sample_movement();
+        let mut current = baseline;
+        current.location.x += 0.004;
+        current.rotation.yaw += 0.005;
+        current.linear_velocity.z -= 0.009;
+        assert!(current.diff(&baseline).is_empty());
+
+        let exact = current.diff_with_tolerances(&baseline, &MovementDeltaTolerances::EXACT);
+        assert!(exact.has_changed(
+            RepMovementDelta::LOCATION | RepMovementDelta::ROTATION | RepMovementDelta::LINEAR_VELOCITY
+        ));
+
+        // Rotation wrap-around is not a change
+        let mut wrapped = baseline;
+        wrapped.rotation.yaw = 45.0 - 360.0;
+        assert!(wrapped.diff(&baseline).is_empty());
+    }
+
+    #[test]
+    fn test_apply_diff_round_trip() {
+        let baseline = sample_movement();
+        let mut current = baseline;
+        current.location = Vector::new(112.25, -250.5, 31.0);
+        current.angular_velocity = Vector::new(0.0, 90.0, 0.0);
+        current.set_simulated(true);
+        current.set_location_base(NetworkGUID::new(55), Vector::new(1.0, 2.0, 3.0));
+        current.set_server_frame(101);
+
+        let delta = current.diff(&baseline);
+        assert!(delta.has_changed(RepMovementDelta::LOCATION_BASE | RepMovementDelta::HAS_LOCATION_BASE));
+        assert!(!delta.has_changed(RepMovementDelta::ROTATION));
+        assert_eq!(delta.apply(&baseline), current);
+
+        // Through the bitstream, values survive within quantization
+        let bytes = encode(&delta);
+        let decoded: RepMovementDelta = BitReader::new(&bytes).read().unwrap();
+        assert_eq!(decoded.changed_fields(), delta.changed_fields());
+        let applied = decoded.apply(&baseline);
+        assert!(applied.diff(&current).is_empty());
+        assert_eq!(applied.location_base, Some(NetworkGUID::new(55)));
+
+        // Clearing the base round-trips as None
+        let cleared = baseline.diff(&current);
+        let bytes = encode(&cleared);
+        let decoded: RepMovementDelta = BitReader::new(&bytes).read().unwrap();
+        assert_eq!(decoded.apply(&current).location_base, None);
+    }
+
+    #[test]
+    fn test_delta_serialization() {
+        let baseline = sample_movement();
+        let mut current = baseline;
+        current.linear_velocity = Vector::ZERO;
+
+        let delta = current.diff(&baseline);
+        let json = serde_json::to_string(&delta).unwrap();
+        let deserialized: RepMovementDelta = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, delta);
+
+        let binary = delta.to_binary().unwrap();
+        assert_eq!(RepMovementDelta::from_binary(&binary).unwrap(), delta);
+
+        let bytes = encode(&delta);
+        assert!(BitReader::new(&bytes[..bytes.len() - 1]).read::<RepMovementDelta>().is_err());
+    }
+}
@@ -0,0 +1,300 @@
+//! Typed values for game session custom properties
+
+use crate::types::Vector;
+use serde::{ser, Deserialize, Deserializer, Serialize, Serializer};
+use core::fmt;
+use alloc::string::{String, ToString};
+
+/// Value of a `GameSessionInfo` custom property
+///
+/// Human-readable formats (JSON) store the bare value, so `"CTF"`, `25`,
+/// `0.5`, `true` and `[1.0, 2.0, 3.0]` all load, including JSON written when
+/// every property was a string. Binary formats store a variant tag, since
+/// they can't tell the variants apart otherwise.
+///
+/// JSON has no NaN or infinity (serde_json writes them as `null`, which
+/// wouldn't load back), so human-readable formats refuse to serialize a
+/// non-finite `Float` or `Vector`. Binary formats store them exactly.
+///
+/// The `as_*` getters also parse the `String` variant, so properties set by
+/// older servers as text still read as numbers and flags.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SessionPropertyValue {
+    /// Free-form text
+    String(String),
+    /// Integer value
+    Int(i64),
+    /// Floating-point value
+    Float(f64),
+    /// Flag
+    Bool(bool),
+    /// Location or direction
+    Vector(Vector),
+}
+
+impl fmt::Display for SessionPropertyValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SessionPropertyValue::String(value) => write!(f, "{}", value),
+            SessionPropertyValue::Int(value) => write!(f, "{}", value),
+            SessionPropertyValue::Float(value) => write!(f, "{}", value),
+            SessionPropertyValue::Bool(value) => write!(f, "{}", value),
+            SessionPropertyValue::Vector(value) => write!(f, "X={} Y={} Z={}", value.x, value.y, value.z),
+        }
+    }
+}
+
+impl SessionPropertyValue {
+    /// The text of a `String` value
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            SessionPropertyValue::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// The value as an integer; strings are parsed
+    pub fn as_int(&self) -> Option<i64> {
+        match self {
+            SessionPropertyValue::Int(value) => Some(*value),
+            SessionPropertyValue::String(value) => value.trim().parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// The value as a float; integers are widened and strings are parsed
+    pub fn as_float(&self) -> Option<f64> {
+        match self {
+            SessionPropertyValue::Float(value) => Some(*value),
+            SessionPropertyValue::Int(value) => Some(*value as f64),
+            SessionPropertyValue::String(value) => value.trim().parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// The value as a flag; strings "true" and "false" are accepted in any case
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            SessionPropertyValue::Bool(value) => Some(*value),
+            SessionPropertyValue::String(value) => {
+                let value = value.trim();
+                if value.eq_ignore_ascii_case("true") {
+                    Some(true)
+                } else if value.eq_ignore_ascii_case("false") {
+                    Some(false)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// The value as a vector
+    pub fn as_vector(&self) -> Option<Vector> {
+        match self {
+            SessionPropertyValue::Vector(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+impl From<String> for SessionPropertyValue {
+    fn from(value: String) -> Self {
+        SessionPropertyValue::String(value)
+    }
+}
+
+impl From<&str> for SessionPropertyValue {
+    fn from(value: &str) -> Self {
+        SessionPropertyValue::String(value.to_string())
+    }
+}
+
+impl From<i64> for SessionPropertyValue {
+    fn from(value: i64) -> Self {
+        SessionPropertyValue::Int(value)
+    }
+}
+
+impl From<i32> for SessionPropertyValue {
+    fn from(value: i32) -> Self {
+        SessionPropertyValue::Int(value as i64)
+    }
+}
+
+impl From<f64> for SessionPropertyValue {
+    fn from(value: f64) -> Self {
+        SessionPropertyValue::Float(value)
+    }
+}
+
+impl From<bool> for SessionPropertyValue {
+    fn from(value: bool) -> Self {
+        SessionPropertyValue::Bool(value)
+    }
+}
+
+impl From<Vector> for SessionPropertyValue {
+    fn from(value: Vector) -> Self {
+        SessionPropertyValue::Vector(value)
+    }
+}
+
+/// Bare-value form read from human-readable formats
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum UntaggedValue {
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    String(String),
+    Vector(Vector),
+}
+
+/// Tagged form read from binary formats
+#[derive(Deserialize)]
+enum TaggedValue {
+    String(String),
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+    Vector(Vector),
+}
+
+impl Serialize for SessionPropertyValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            let finite = match self {
+                SessionPropertyValue::Float(value) => value.is_finite(),
+                SessionPropertyValue::Vector(value) => value.is_finite(),
+                _ => true,
+            };
+            if !finite {
+                return Err(ser::Error::custom(format_args!(
+                    "session property {} is not finite and has no JSON form",
+                    self
+                )));
+            }
+            return match self {
+                SessionPropertyValue::String(value) => serializer.serialize_str(value),
+                SessionPropertyValue::Int(value) => serializer.serialize_i64(*value),
+                SessionPropertyValue::Float(value) => serializer.serialize_f64(*value),
+                SessionPropertyValue::Bool(value) => serializer.serialize_bool(*value),
+                SessionPropertyValue::Vector(value) => value.serialize(serializer),
+            };
+        }
+        // Same layout as deriving Serialize on `TaggedValue`
+        const NAME: &str = "TaggedValue";
+        match self {
+            SessionPropertyValue::String(value) => serializer.serialize_newtype_variant(NAME, 0, "String", value),
+            SessionPropertyValue::Int(value) => serializer.serialize_newtype_variant(NAME, 1, "Int", value),
+            SessionPropertyValue::Float(value) => serializer.serialize_newtype_variant(NAME, 2, "Float", value),
+            SessionPropertyValue::Bool(value) => serializer.serialize_newtype_variant(NAME, 3, "Bool", value),
+            SessionPropertyValue::Vector(value) => serializer.serialize_newtype_variant(NAME, 4, "Vector", value),
+        }
+    }
+}
+
+/// Sized as the binary (tagged) encoding
+impl crate::types::SerializedSize for SessionPropertyValue {
+    fn serialized_size(&self) -> usize {
+        crate::types::VARIANT_TAG_SIZE
+            + match self {
+                SessionPropertyValue::String(value) => value.serialized_size(),
+                SessionPropertyValue::Int(value) => value.serialized_size(),
+                SessionPropertyValue::Float(value) => value.serialized_size(),
+                SessionPropertyValue::Bool(value) => value.serialized_size(),
+                SessionPropertyValue::Vector(value) => value.serialized_size(),
+            }
+    }
+}
+
+impl<'de> Deserialize<'de> for SessionPropertyValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            Ok(match UntaggedValue::deserialize(deserializer)? {
+                UntaggedValue::String(value) => SessionPropertyValue::String(value),
+                UntaggedValue::Int(value) => SessionPropertyValue::Int(value),
+                UntaggedValue::Float(value) => SessionPropertyValue::Float(value),
+                UntaggedValue::Bool(value) => SessionPropertyValue::Bool(value),
+                UntaggedValue::Vector(value) => SessionPropertyValue::Vector(value),
+            })
+        } else {
+            Ok(match TaggedValue::deserialize(deserializer)? {
+                TaggedValue::String(value) => SessionPropertyValue::String(value),
+                TaggedValue::Int(value) => SessionPropertyValue::Int(value),
+                TaggedValue::Float(value) => SessionPropertyValue::Float(value),
+                TaggedValue::Bool(value) => SessionPropertyValue::Bool(value),
+                TaggedValue::Vector(value) => SessionPropertyValue::Vector(value),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_typed_getters() {
+        assert_eq!(SessionPropertyValue::from(" 25 ").as_int(), Some(25));
+        assert_eq!(SessionPropertyValue::from("2.5").as_float(), Some(2.5));
+        assert_eq!(SessionPropertyValue::from(7).as_float(), Some(7.0));
+        assert_eq!(SessionPropertyValue::from("TRUE").as_bool(), Some(true));
+        assert_eq!(SessionPropertyValue::from("yes").as_bool(), None);
+        assert_eq!(SessionPropertyValue::from(2.5).as_int(), None);
+        assert_eq!(SessionPropertyValue::from(true).as_str(), None);
+        assert_eq!(SessionPropertyValue::from("CTF").as_str(), Some("CTF"));
+        assert_eq!(SessionPropertyValue::from(Vector::X).as_vector(), Some(Vector::X));
+
+        let shown: Vec<String> = [
+            SessionPropertyValue::from("CTF"),
+            SessionPropertyValue::from(-3),
+            SessionPropertyValue::from(0.25),
+            SessionPropertyValue::from(false),
+            SessionPropertyValue::from(Vector::new(1.0, 2.5, -3.0)),
+        ]
+        .iter()
+        .map(|value| value.to_string())
+        .collect();
+        assert_eq!(shown, ["CTF", "-3", "0.25", "false", "X=1 Y=2.5 Z=-3"]);
+    }
+
+    #[test]
+    fn test_non_finite_values_rejected_by_json() {
+        for value in [
+            SessionPropertyValue::from(f64::NAN),
+            SessionPropertyValue::from(f64::INFINITY),
+            SessionPropertyValue::from(Vector::new(0.0, f64::NEG_INFINITY, 0.0)),
+        ] {
+            let error = serde_json::to_string(&value).unwrap_err();
+            assert!(error.to_string().contains("is not finite"), "{}", error);
+        }
+
+        // Binary keeps them exactly
+        let nan = SessionPropertyValue::from(f64::NAN);
+        let bytes = bincode::serialize(&nan).unwrap();
+        let restored: SessionPropertyValue = bincode::deserialize(&bytes).unwrap();
+        assert!(restored.as_float().unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_serialized_forms() {
+        let values = [
+            SessionPropertyValue::from("25"),
+            SessionPropertyValue::from(25),
+            SessionPropertyValue::from(25.0),
+            SessionPropertyValue::from(true),
+            SessionPropertyValue::from(Vector::new(1.0, 2.0, 3.0)),
+        ];
+        let json: Vec<String> = values.iter().map(|value| serde_json::to_string(value).unwrap()).collect();
+        assert_eq!(json, ["\"25\"", "25", "25.0", "true", "[1.0,2.0,3.0]"]);
+
+        for (value, json) in values.iter().zip(&json) {
+            assert_eq!(&serde_json::from_str::<SessionPropertyValue>(json).unwrap(), value);
+            let binary = bincode::serialize(value).unwrap();
+            assert_eq!(&bincode::deserialize::<SessionPropertyValue>(&binary).unwrap(), value);
+        }
+    }
+}
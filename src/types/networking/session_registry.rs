@@ -0,0 +1,219 @@
+//! Master server registry of advertised game sessions
+
+use crate::types::{TArray, TMap, Timespan};
+#[cfg(feature = "std")]
+use super::game_session_info::current_unix_seconds;
+use super::{GameSessionInfo, NetworkGUID, SessionFilter};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Every session advertised to a master server, keyed by session id
+///
+/// Hosts `upsert` their session info and `heartbeat` periodically;
+/// `prune_stale` drops sessions whose host went quiet. Iteration and query
+/// results are ordered by session id unless the filter sorts them.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SessionRegistry {
+    sessions: TMap<NetworkGUID, GameSessionInfo>,
+}
+
+impl fmt::Display for SessionRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SessionRegistry({} sessions, {} players)", self.len(), self.total_players())
+    }
+}
+
+impl SessionRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of registered sessions
+    pub fn len(&self) -> usize {
+        self.sessions.len()
+    }
+
+    /// Check if no sessions are registered
+    pub fn is_empty(&self) -> bool {
+        self.sessions.is_empty()
+    }
+
+    /// Add or replace a session, returning the previous entry
+    ///
+    /// The session's `last_heartbeat` is stored as given.
+    pub fn upsert(&mut self, session: GameSessionInfo) -> Option<GameSessionInfo> {
+        self.sessions.add(session.session_id, session)
+    }
+
+    /// Remove a session, returning it
+    pub fn remove(&mut self, session_id: NetworkGUID) -> Option<GameSessionInfo> {
+        self.sessions.remove(&session_id)
+    }
+
+    /// Get a session
+    pub fn get(&self, session_id: NetworkGUID) -> Option<&GameSessionInfo> {
+        self.sessions.find(&session_id)
+    }
+
+    /// Get a session mutably
+    pub fn get_mut(&mut self, session_id: NetworkGUID) -> Option<&mut GameSessionInfo> {
+        self.sessions.find_mut(&session_id)
+    }
+
+    /// Record a heartbeat for a session now; returns false if it isn't registered
+    #[cfg(feature = "std")]
+    pub fn heartbeat(&mut self, session_id: NetworkGUID) -> bool {
+        self.heartbeat_at(session_id, current_unix_seconds())
+    }
+
+    /// Record a heartbeat for a session at `now` (Unix seconds); returns false if it isn't registered
+    pub fn heartbeat_at(&mut self, session_id: NetworkGUID, now: u64) -> bool {
+        match self.sessions.find_mut(&session_id) {
+            Some(session) => {
+                session.heartbeat_at(now);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Remove sessions that haven't heartbeated within `timeout`, returning their ids in order
+    #[cfg(feature = "std")]
+    pub fn prune_stale(&mut self, timeout: Timespan) -> TArray<NetworkGUID> {
+        self.prune_stale_at(timeout, current_unix_seconds())
+    }
+
+    /// Remove sessions that haven't heartbeated within `timeout` of `now` (Unix seconds)
+    pub fn prune_stale_at(&mut self, timeout: Timespan, now: u64) -> TArray<NetworkGUID> {
+        let mut stale: TArray<NetworkGUID> = self
+            .sessions
+            .values()
+            .filter(|session| session.is_stale_at(timeout, now))
+            .map(|session| session.session_id)
+            .collect();
+        stale.sort_by_key(|id| id.value);
+        for id in stale.iter() {
+            self.sessions.remove(id);
+        }
+        stale
+    }
+
+    /// Select, sort and paginate sessions with a server browser filter
+    #[cfg(feature = "std")]
+    pub fn find(&self, filter: &SessionFilter) -> Vec<&GameSessionInfo> {
+        filter.filter_sessions(self.iter())
+    }
+
+    /// Select, sort and paginate sessions, measuring age against `now` (Unix seconds)
+    pub fn find_at(&self, filter: &SessionFilter, now: u64) -> Vec<&GameSessionInfo> {
+        filter.filter_sessions_at(self.iter(), now)
+    }
+
+    /// Total players across all sessions, saturating at `i32::MAX`
+    pub fn total_players(&self) -> i32 {
+        self.sessions
+            .values()
+            .fold(0_i32, |total, session| total.saturating_add(session.current_players))
+    }
+
+    /// Number of sessions in each region
+    pub fn total_sessions_by_region(&self) -> TMap<String, i32> {
+        let mut totals = TMap::new();
+        for session in self.sessions.values() {
+            *totals.find_or_add(session.region.clone(), 0) += 1;
+        }
+        totals
+    }
+
+    /// Iterate over sessions ordered by session id
+    pub fn iter(&self) -> impl Iterator<Item = &GameSessionInfo> {
+        let mut sessions: Vec<&GameSessionInfo> = self.sessions.values().collect();
+        sessions.sort_by_key(|session| session.session_id.value);
+        sessions.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::SessionSort;
+
+    fn session(id: u32, region: &str, players: i32, now: u64) -> GameSessionInfo {
+        let mut session = GameSessionInfo::new(format!("Server {}", id), "CTF".to_string(), "ctf_fort".to_string(), 16);
+        session.session_id = NetworkGUID::new(id);
+        session.current_players = players;
+        session.set_region(region.to_string());
+        session.created_time = now;
+        session.last_heartbeat = now;
+        session
+    }
+
+    #[test]
+    fn test_heartbeats_and_pruning() {
+        let timeout = Timespan::from_seconds(60.0);
+        let mut registry = SessionRegistry::new();
+        let mut now = 10_000;
+        for id in 1..=4 {
+            assert!(registry.upsert(session(id, "EU", 4, now)).is_none());
+        }
+
+        // Hosts 1 and 3 keep heartbeating every 20s; 2 stops after 40s, 4 never does
+        for step in 1..=6 {
+            now += 20;
+            registry.heartbeat_at(NetworkGUID::new(1), now);
+            registry.heartbeat_at(NetworkGUID::new(3), now);
+            if step <= 2 {
+                registry.heartbeat_at(NetworkGUID::new(2), now);
+            }
+            let pruned = registry.prune_stale_at(timeout, now);
+            match step {
+                4 => assert_eq!(pruned.as_slice(), [NetworkGUID::new(4)]),
+                6 => assert_eq!(pruned.as_slice(), [NetworkGUID::new(2)]),
+                _ => assert!(pruned.is_empty(), "step {}: {:?}", step, pruned),
+            }
+        }
+        let ids: Vec<u32> = registry.iter().map(|session| session.session_id.value).collect();
+        assert_eq!(ids, [1, 3]);
+        assert!(!registry.heartbeat_at(NetworkGUID::new(4), now));
+
+        // Upserting replaces the stored info
+        let previous = registry.upsert(session(3, "NA", 9, now)).unwrap();
+        assert_eq!(previous.region, "EU");
+        assert_eq!(registry.get(NetworkGUID::new(3)).unwrap().current_players, 9);
+        assert!(registry.remove(NetworkGUID::new(3)).is_some());
+        assert_eq!(registry.len(), 1);
+
+        // Against the real clock, a heartbeat from 1970 is long stale
+        assert_eq!(registry.prune_stale(Timespan::from_days(1.0)).as_slice(), [NetworkGUID::new(1)]);
+        assert!(registry.is_empty());
+    }
+
+    #[test]
+    fn test_region_totals_and_queries() {
+        let mut registry = SessionRegistry::new();
+        for (id, region, players) in [(1, "EU", 10), (2, "NA", 3), (3, "EU", 0), (4, "OCE", 7), (5, "EU", 5)] {
+            registry.upsert(session(id, region, players, 1_000));
+        }
+
+        assert_eq!(registry.total_players(), 25);
+        let totals = registry.total_sessions_by_region();
+        assert_eq!(totals.len(), 3);
+        assert_eq!(totals.find(&"EU".to_string()), Some(&3));
+        assert_eq!(totals.find(&"NA".to_string()), Some(&1));
+        assert_eq!(totals.find(&"OCE".to_string()), Some(&1));
+
+        let filter = SessionFilter::new().region("eu").min_free_slots(8);
+        let ids: Vec<u32> = registry.find(&filter).iter().map(|session| session.session_id.value).collect();
+        assert_eq!(ids, [3, 5]);
+
+        let busiest = SessionFilter::new().sort_by(SessionSort::PlayersDescending).take(2);
+        let ids: Vec<u32> = registry.find(&busiest).iter().map(|session| session.session_id.value).collect();
+        assert_eq!(ids, [1, 4]);
+        assert_eq!(format!("{}", registry), "SessionRegistry(5 sessions, 25 players)");
+        // Corrupt player counts can't overflow the total
+        registry.upsert(session(6, "EU", i32::MAX, 1_000));
+        assert_eq!(registry.total_players(), i32::MAX);
+    }
+}
@@ -0,0 +1,509 @@
+//! Staged, validated multi-field updates for game sessions
+
+use crate::BinarySerializable;
+use super::{GameSessionInfo, SessionPropertyValue};
+use serde::{Deserialize, Serialize};
+use alloc::collections::BTreeMap;
+use crate::types::containers::hash::HashSet;
+use core::fmt;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Rules a staged session update must satisfy before it is committed
+///
+/// `None` for an allow-list means any value is accepted.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionRules {
+    /// Smallest allowed value for `max_players`
+    pub min_max_players: i32,
+    /// Largest allowed value for `max_players`
+    pub max_max_players: i32,
+    /// Maps that may be selected
+    pub allowed_maps: Option<HashSet<String>>,
+    /// Game modes that may be selected
+    pub allowed_game_modes: Option<HashSet<String>>,
+    /// Regions that may be selected
+    pub allowed_regions: Option<HashSet<String>>,
+}
+
+impl SessionRules {
+    /// Create permissive rules (any map/mode/region, 1 to i32::MAX players)
+    pub fn new() -> Self {
+        Self {
+            min_max_players: 1,
+            max_max_players: i32::MAX,
+            allowed_maps: None,
+            allowed_game_modes: None,
+            allowed_regions: None,
+        }
+    }
+
+    /// Check if a map is allowed
+    pub fn is_map_allowed(&self, map_name: &str) -> bool {
+        self.allowed_maps.as_ref().is_none_or(|maps| maps.contains(map_name))
+    }
+
+    /// Check if a game mode is allowed
+    pub fn is_game_mode_allowed(&self, game_mode: &str) -> bool {
+        self.allowed_game_modes.as_ref().is_none_or(|modes| modes.contains(game_mode))
+    }
+
+    /// Check if a region is allowed
+    pub fn is_region_allowed(&self, region: &str) -> bool {
+        self.allowed_regions.as_ref().is_none_or(|regions| regions.contains(region))
+    }
+}
+
+impl Default for SessionRules {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A single rule violation found while validating a session update
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SessionValidationError {
+    /// Requested max players is lower than the number of connected players
+    MaxPlayersBelowCurrent { requested: i32, current: i32 },
+    /// Requested max players is outside the allowed range
+    MaxPlayersOutOfRange { requested: i32, min: i32, max: i32 },
+    /// Map is not in the allowed map registry
+    MapNotAllowed(String),
+    /// Game mode is not in the allowed mode registry
+    GameModeNotAllowed(String),
+    /// Region is not in the region allow-list
+    RegionNotAllowed(String),
+    /// Session name is empty
+    EmptySessionName,
+}
+
+impl fmt::Display for SessionValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SessionValidationError::MaxPlayersBelowCurrent { requested, current } => {
+                write!(f, "max_players {} is below current player count {}", requested, current)
+            }
+            SessionValidationError::MaxPlayersOutOfRange { requested, min, max } => {
+                write!(f, "max_players {} is outside allowed range {}..={}", requested, min, max)
+            }
+            SessionValidationError::MapNotAllowed(map) => write!(f, "map \"{}\" is not allowed", map),
+            SessionValidationError::GameModeNotAllowed(mode) => write!(f, "game mode \"{}\" is not allowed", mode),
+            SessionValidationError::RegionNotAllowed(region) => write!(f, "region \"{}\" is not allowed", region),
+            SessionValidationError::EmptySessionName => write!(f, "session name must not be empty"),
+        }
+    }
+}
+
+impl core::error::Error for SessionValidationError {}
+
+/// One changed field recorded by a committed session update
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SessionChange {
+    SessionName { old: String, new: String },
+    GameMode { old: String, new: String },
+    MapName { old: String, new: String },
+    MaxPlayers { old: i32, new: i32 },
+    IsPrivate { old: bool, new: bool },
+    AllowSpectators { old: bool, new: bool },
+    Region { old: String, new: String },
+    Difficulty { old: i32, new: i32 },
+    /// A custom property was added (`old` is None), changed, or removed (`new` is None)
+    CustomProperty {
+        key: String,
+        old: Option<SessionPropertyValue>,
+        new: Option<SessionPropertyValue>,
+    },
+}
+
+/// Audit record of every field changed by a committed session update
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct SessionChangeSet {
+    /// Changes in field declaration order, custom properties sorted by key
+    pub changes: Vec<SessionChange>,
+}
+
+impl fmt::Display for SessionChangeSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SessionChangeSet({} changes)", self.changes.len())
+    }
+}
+
+impl BinarySerializable for SessionChangeSet {}
+
+impl SessionChangeSet {
+    /// Check if nothing changed
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Get the number of changed fields
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// Get an iterator over the changes
+    pub fn iter(&self) -> core::slice::Iter<'_, SessionChange> {
+        self.changes.iter()
+    }
+}
+
+/// Staged changes to a `GameSessionInfo`
+///
+/// Created by `GameSessionInfo::begin_update()`. Setters only record the
+/// new values; nothing is applied until `commit`, so a batch that fails
+/// validation leaves the session untouched.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SessionUpdate {
+    current_players: i32,
+    session_name: Option<String>,
+    game_mode: Option<String>,
+    map_name: Option<String>,
+    max_players: Option<i32>,
+    is_private: Option<bool>,
+    allow_spectators: Option<bool>,
+    region: Option<String>,
+    difficulty: Option<i32>,
+    custom_properties: BTreeMap<String, Option<SessionPropertyValue>>,
+}
+
+impl SessionUpdate {
+    /// Create an empty update staged against the given session
+    pub fn new(session: &GameSessionInfo) -> Self {
+        Self {
+            current_players: session.current_players,
+            ..Self::default()
+        }
+    }
+
+    /// Stage a new session name
+    pub fn set_session_name(&mut self, session_name: String) -> &mut Self {
+        self.session_name = Some(session_name);
+        self
+    }
+
+    /// Stage a new game mode
+    pub fn set_game_mode(&mut self, game_mode: String) -> &mut Self {
+        self.game_mode = Some(game_mode);
+        self
+    }
+
+    /// Stage a new map
+    pub fn set_map_name(&mut self, map_name: String) -> &mut Self {
+        self.map_name = Some(map_name);
+        self
+    }
+
+    /// Stage a new maximum player count
+    pub fn set_max_players(&mut self, max_players: i32) -> &mut Self {
+        self.max_players = Some(max_players);
+        self
+    }
+
+    /// Stage session privacy
+    pub fn set_private(&mut self, is_private: bool) -> &mut Self {
+        self.is_private = Some(is_private);
+        self
+    }
+
+    /// Stage whether spectators are allowed
+    pub fn set_allow_spectators(&mut self, allow_spectators: bool) -> &mut Self {
+        self.allow_spectators = Some(allow_spectators);
+        self
+    }
+
+    /// Stage a new region
+    pub fn set_region(&mut self, region: String) -> &mut Self {
+        self.region = Some(region);
+        self
+    }
+
+    /// Stage a new difficulty level
+    pub fn set_difficulty(&mut self, difficulty: i32) -> &mut Self {
+        self.difficulty = Some(difficulty);
+        self
+    }
+
+    /// Stage adding or changing a custom property
+    pub fn set_custom_property(&mut self, key: String, value: impl Into<SessionPropertyValue>) -> &mut Self {
+        self.custom_properties.insert(key, Some(value.into()));
+        self
+    }
+
+    /// Stage removing a custom property
+    pub fn remove_custom_property(&mut self, key: String) -> &mut Self {
+        self.custom_properties.insert(key, None);
+        self
+    }
+
+    /// Check if no changes have been staged
+    pub fn is_empty(&self) -> bool {
+        self.session_name.is_none()
+            && self.game_mode.is_none()
+            && self.map_name.is_none()
+            && self.max_players.is_none()
+            && self.is_private.is_none()
+            && self.allow_spectators.is_none()
+            && self.region.is_none()
+            && self.difficulty.is_none()
+            && self.custom_properties.is_empty()
+    }
+
+    /// Validate the staged changes, collecting every violation
+    ///
+    /// The player count checked against a new `max_players` is the one
+    /// captured by `begin_update`. Use `validate_for` to check against the
+    /// session as it is now.
+    pub fn validate(&self, rules: &SessionRules) -> Result<(), Vec<SessionValidationError>> {
+        self.validate_with_players(rules, self.current_players)
+    }
+
+    /// Validate the staged changes against `session`'s current player count
+    pub fn validate_for(&self, session: &GameSessionInfo, rules: &SessionRules) -> Result<(), Vec<SessionValidationError>> {
+        self.validate_with_players(rules, session.current_players)
+    }
+
+    fn validate_with_players(&self, rules: &SessionRules, current_players: i32) -> Result<(), Vec<SessionValidationError>> {
+        let mut errors = Vec::new();
+
+        if self.session_name.as_deref().is_some_and(str::is_empty) {
+            errors.push(SessionValidationError::EmptySessionName);
+        }
+        if let Some(max_players) = self.max_players {
+            if max_players < rules.min_max_players || max_players > rules.max_max_players {
+                errors.push(SessionValidationError::MaxPlayersOutOfRange {
+                    requested: max_players,
+                    min: rules.min_max_players,
+                    max: rules.max_max_players,
+                });
+            }
+            if max_players < current_players {
+                errors.push(SessionValidationError::MaxPlayersBelowCurrent {
+                    requested: max_players,
+                    current: current_players,
+                });
+            }
+        }
+        if let Some(map_name) = &self.map_name {
+            if !rules.is_map_allowed(map_name) {
+                errors.push(SessionValidationError::MapNotAllowed(map_name.clone()));
+            }
+        }
+        if let Some(game_mode) = &self.game_mode {
+            if !rules.is_game_mode_allowed(game_mode) {
+                errors.push(SessionValidationError::GameModeNotAllowed(game_mode.clone()));
+            }
+        }
+        if let Some(region) = &self.region {
+            if !rules.is_region_allowed(region) {
+                errors.push(SessionValidationError::RegionNotAllowed(region.clone()));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Apply all staged changes to the session and return what changed
+    ///
+    /// Fields staged with their current value are not reported.
+    pub fn commit(self, session: &mut GameSessionInfo) -> SessionChangeSet {
+        fn apply<T: Clone + PartialEq>(
+            staged: Option<T>,
+            field: &mut T,
+            make: impl FnOnce(T, T) -> SessionChange,
+            changes: &mut Vec<SessionChange>,
+        ) {
+            if let Some(new) = staged {
+                if *field != new {
+                    let old = core::mem::replace(field, new.clone());
+                    changes.push(make(old, new));
+                }
+            }
+        }
+
+        let mut changes = Vec::new();
+        apply(self.session_name, &mut session.session_name, |old, new| SessionChange::SessionName { old, new }, &mut changes);
+        apply(self.game_mode, &mut session.game_mode, |old, new| SessionChange::GameMode { old, new }, &mut changes);
+        apply(self.map_name, &mut session.map_name, |old, new| SessionChange::MapName { old, new }, &mut changes);
+        apply(self.max_players, &mut session.max_players, |old, new| SessionChange::MaxPlayers { old, new }, &mut changes);
+        apply(self.is_private, &mut session.is_private, |old, new| SessionChange::IsPrivate { old, new }, &mut changes);
+        apply(self.allow_spectators, &mut session.allow_spectators, |old, new| SessionChange::AllowSpectators { old, new }, &mut changes);
+        apply(self.region, &mut session.region, |old, new| SessionChange::Region { old, new }, &mut changes);
+        apply(self.difficulty, &mut session.difficulty, |old, new| SessionChange::Difficulty { old, new }, &mut changes);
+
+        for (key, new) in self.custom_properties {
+            let old = match &new {
+                Some(value) => session.custom_properties.insert(key.clone(), value.clone()),
+                None => session.custom_properties.remove(&key),
+            };
+            if old != new {
+                changes.push(SessionChange::CustomProperty { key, old, new });
+            }
+        }
+
+        SessionChangeSet { changes }
+    }
+
+    /// Validate against the rules and commit only if every check passes
+    ///
+    /// Checks against `session` as it is at commit time, so players who
+    /// joined since `begin_update` are counted.
+    pub fn try_commit(
+        self,
+        session: &mut GameSessionInfo,
+        rules: &SessionRules,
+    ) -> Result<SessionChangeSet, Vec<SessionValidationError>> {
+        self.validate_for(session, rules)?;
+        Ok(self.commit(session))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_session() -> GameSessionInfo {
+        let mut session = GameSessionInfo::new(
+            "Friday Night".to_string(),
+            "Deathmatch".to_string(),
+            "dm_arena".to_string(),
+            8,
+        );
+        for _ in 0..5 {
+            session.add_player();
+        }
+        session.set_region("EU".to_string());
+        session.set_custom_property("mutators".to_string(), "lowgrav".to_string());
+        session
+    }
+
+    fn strict_rules() -> SessionRules {
+        SessionRules {
+            min_max_players: 2,
+            max_max_players: 16,
+            allowed_maps: Some(["dm_arena", "ctf_canyon"].iter().map(|s| s.to_string()).collect()),
+            allowed_game_modes: Some(["Deathmatch", "CaptureTheFlag"].iter().map(|s| s.to_string()).collect()),
+            allowed_regions: Some(["EU", "NA"].iter().map(|s| s.to_string()).collect()),
+        }
+    }
+
+    #[test]
+    fn test_failed_validation_leaves_session_untouched() {
+        let mut session = test_session();
+        let original = session.clone();
+
+        let mut update = session.begin_update();
+        update
+            .set_map_name("ctf_canyon".to_string())
+            .set_game_mode("CaptureTheFlag".to_string())
+            .set_region("MOON".to_string());
+
+        assert!(update.try_commit(&mut session, &strict_rules()).is_err());
+        assert_eq!(session, original);
+    }
+
+    #[test]
+    fn test_collects_all_errors() {
+        let session = test_session();
+        let mut update = session.begin_update();
+        update
+            .set_map_name("unknown_map".to_string())
+            .set_game_mode("Racing".to_string())
+            .set_region("MOON".to_string())
+            .set_max_players(64);
+
+        let errors = update.validate(&strict_rules()).unwrap_err();
+        assert_eq!(errors.len(), 4);
+        assert!(errors.contains(&SessionValidationError::MapNotAllowed("unknown_map".to_string())));
+        assert!(errors.contains(&SessionValidationError::GameModeNotAllowed("Racing".to_string())));
+        assert!(errors.contains(&SessionValidationError::RegionNotAllowed("MOON".to_string())));
+        assert!(errors.contains(&SessionValidationError::MaxPlayersOutOfRange { requested: 64, min: 2, max: 16 }));
+    }
+
+    #[test]
+    fn test_max_players_below_current_rejected() {
+        let session = test_session();
+        let mut update = session.begin_update();
+        update.set_max_players(4);
+
+        let errors = update.validate(&SessionRules::new()).unwrap_err();
+        assert_eq!(errors, vec![SessionValidationError::MaxPlayersBelowCurrent { requested: 4, current: 5 }]);
+
+        let mut update = session.begin_update();
+        update.set_max_players(5);
+        assert!(update.validate(&SessionRules::new()).is_ok());
+    }
+
+    #[test]
+    fn test_players_joining_before_commit_are_counted() {
+        let mut session = test_session();
+        let mut update = session.begin_update();
+        update.set_max_players(5);
+
+        session.add_player();
+        let original = session.clone();
+        assert!(update.validate(&SessionRules::new()).is_ok());
+        let errors = update.clone().try_commit(&mut session, &SessionRules::new()).unwrap_err();
+        assert_eq!(errors, vec![SessionValidationError::MaxPlayersBelowCurrent { requested: 5, current: 6 }]);
+        assert_eq!(session, original);
+
+        session.remove_player();
+        assert!(update.try_commit(&mut session, &SessionRules::new()).is_ok());
+        assert_eq!(session.max_players, 5);
+    }
+
+    #[test]
+    fn test_changeset_matches_staged_edits() {
+        let mut session = test_session();
+        let mut update = session.begin_update();
+        update
+            .set_map_name("ctf_canyon".to_string())
+            .set_game_mode("CaptureTheFlag".to_string())
+            .set_max_players(12)
+            .set_region("EU".to_string()) // unchanged, not reported
+            .set_custom_property("score_limit".to_string(), "3".to_string())
+            .remove_custom_property("mutators".to_string());
+
+        let changes = update.try_commit(&mut session, &strict_rules()).unwrap();
+        assert_eq!(
+            changes.changes,
+            vec![
+                SessionChange::GameMode { old: "Deathmatch".to_string(), new: "CaptureTheFlag".to_string() },
+                SessionChange::MapName { old: "dm_arena".to_string(), new: "ctf_canyon".to_string() },
+                SessionChange::MaxPlayers { old: 8, new: 12 },
+                SessionChange::CustomProperty {
+                    key: "mutators".to_string(),
+                    old: Some("lowgrav".into()),
+                    new: None,
+                },
+                SessionChange::CustomProperty {
+                    key: "score_limit".to_string(),
+                    old: None,
+                    new: Some("3".into()),
+                },
+            ]
+        );
+
+        assert_eq!(session.map_name, "ctf_canyon");
+        assert_eq!(session.max_players, 12);
+        assert_eq!(session.get_custom_property("score_limit"), Some(&"3".into()));
+        assert_eq!(session.get_property_int("score_limit"), Some(3));
+        assert_eq!(session.get_custom_property("mutators"), None);
+    }
+
+    #[test]
+    fn test_changeset_serialization() {
+        let mut session = test_session();
+        let mut update = session.begin_update();
+        update.set_private(true).set_difficulty(3);
+        let changes = update.commit(&mut session);
+
+        let json = serde_json::to_string(&changes).unwrap();
+        let deserialized: SessionChangeSet = serde_json::from_str(&json).unwrap();
+        assert_eq!(changes, deserialized);
+        assert_eq!(changes.len(), 2);
+    }
+}
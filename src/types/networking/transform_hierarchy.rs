@@ -0,0 +1,226 @@
+//! Parent/child attachment of replicated actors
+
+use crate::types::{TArray, TMap, Transform};
+use super::NetworkGUID;
+use core::fmt;
+use alloc::vec::Vec;
+
+/// Reason a hierarchy operation failed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HierarchyError {
+    /// No node with this GUID is in the hierarchy
+    UnknownNode(NetworkGUID),
+    /// Following parent links from this node leads back to it
+    Cycle(NetworkGUID),
+}
+
+impl fmt::Display for HierarchyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HierarchyError::UnknownNode(id) => write!(f, "node {} is not in the hierarchy", id),
+            HierarchyError::Cycle(id) => write!(f, "attaching node {} would create a cycle", id),
+        }
+    }
+}
+
+impl core::error::Error for HierarchyError {}
+
+#[derive(Debug, Clone, PartialEq)]
+struct HierarchyNode {
+    relative: Transform,
+    parent: Option<NetworkGUID>,
+}
+
+/// Transforms of attached actors, keyed by NetworkGUID
+///
+/// Each node stores its transform relative to its parent (or to the world
+/// when it has none). World transforms are resolved by walking the parent
+/// chain iteratively, so deep hierarchies can't overflow the stack. Parent
+/// links are composed with [`Transform::make_absolute`] and share its shear
+/// limitation for non-uniformly scaled parents.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TransformHierarchy {
+    nodes: TMap<NetworkGUID, HierarchyNode>,
+}
+
+impl fmt::Display for TransformHierarchy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let attached = self.nodes.values().filter(|node| node.parent.is_some()).count();
+        write!(f, "TransformHierarchy({} nodes, {} attached)", self.len(), attached)
+    }
+}
+
+impl TransformHierarchy {
+    /// Create an empty hierarchy
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of nodes
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Check if the hierarchy has no nodes
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Check if a node is in the hierarchy
+    pub fn contains(&self, id: NetworkGUID) -> bool {
+        self.nodes.contains(&id)
+    }
+
+    /// Set a node's transform relative to its parent, adding it as a root if new
+    pub fn set_relative_transform(&mut self, id: NetworkGUID, relative: Transform) {
+        self.nodes.find_or_add(id, HierarchyNode { relative, parent: None }).relative = relative;
+    }
+
+    /// A node's transform relative to its parent
+    pub fn relative_transform(&self, id: NetworkGUID) -> Option<Transform> {
+        self.nodes.find(&id).map(|node| node.relative)
+    }
+
+    /// A node's parent, if attached
+    pub fn parent(&self, id: NetworkGUID) -> Option<NetworkGUID> {
+        self.nodes.find(&id).and_then(|node| node.parent)
+    }
+
+    /// Nodes directly attached to `id`
+    pub fn children(&self, id: NetworkGUID) -> TArray<NetworkGUID> {
+        let mut children: TArray<NetworkGUID> = self
+            .nodes
+            .iter()
+            .filter(|(_, node)| node.parent == Some(id))
+            .map(|(child, _)| *child)
+            .collect();
+        children.sort_by_key(|child| child.value);
+        children
+    }
+
+    /// Attach `id` to `parent`, or detach it with `None`
+    ///
+    /// The node keeps its relative transform, so it moves with the new parent
+    /// (UE's `KeepRelativeTransform` rule). Fails without changing anything if
+    /// either node is unknown or the attachment would create a cycle.
+    pub fn set_parent(&mut self, id: NetworkGUID, parent: Option<NetworkGUID>) -> Result<(), HierarchyError> {
+        if !self.contains(id) {
+            return Err(HierarchyError::UnknownNode(id));
+        }
+        if let Some(parent) = parent {
+            if !self.contains(parent) {
+                return Err(HierarchyError::UnknownNode(parent));
+            }
+            let mut ancestor = Some(parent);
+            while let Some(current) = ancestor {
+                if current == id {
+                    return Err(HierarchyError::Cycle(id));
+                }
+                ancestor = self.parent(current);
+            }
+        }
+        if let Some(node) = self.nodes.find_mut(&id) {
+            node.parent = parent;
+        }
+        Ok(())
+    }
+
+    /// Resolve a node's world transform through its parent chain
+    pub fn world_transform(&self, id: NetworkGUID) -> Result<Transform, HierarchyError> {
+        let mut chain = Vec::new();
+        let mut current = Some(id);
+        while let Some(node_id) = current {
+            let node = self.nodes.find(&node_id).ok_or(HierarchyError::UnknownNode(node_id))?;
+            // set_parent rejects cycles; this guards against links made any other way
+            if chain.len() > self.nodes.len() {
+                return Err(HierarchyError::Cycle(id));
+            }
+            chain.push(node.relative);
+            current = node.parent;
+        }
+        Ok(chain
+            .into_iter()
+            .rev()
+            .fold(Transform::IDENTITY, |parent, relative| Transform::make_absolute(relative, parent)))
+    }
+
+    /// Remove a node, returning its relative transform
+    ///
+    /// Its children are detached and keep their world transforms.
+    pub fn remove(&mut self, id: NetworkGUID) -> Option<Transform> {
+        for child in self.children(id) {
+            if let Ok(world) = self.world_transform(child) {
+                if let Some(node) = self.nodes.find_mut(&child) {
+                    node.relative = world;
+                    node.parent = None;
+                }
+            }
+        }
+        self.nodes.remove(&id).map(|node| node.relative)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Vector;
+    use glam::DQuat;
+
+    fn guid(id: u32) -> NetworkGUID {
+        NetworkGUID::new(id)
+    }
+
+    #[test]
+    fn test_three_level_hierarchy() {
+        let mut hierarchy = TransformHierarchy::new();
+        let vehicle = Transform::new(
+            Vector::new(1000.0, 0.0, 0.0),
+            DQuat::from_rotation_z(90.0_f64.to_radians()),
+            Vector::ONE,
+        );
+        let seat = Transform::from_location(Vector::new(100.0, 0.0, 50.0));
+        let rider = Transform::from_location(Vector::new(0.0, 0.0, 10.0));
+        hierarchy.set_relative_transform(guid(1), vehicle);
+        hierarchy.set_relative_transform(guid(2), seat);
+        hierarchy.set_relative_transform(guid(3), rider);
+        hierarchy.set_parent(guid(2), Some(guid(1))).unwrap();
+        hierarchy.set_parent(guid(3), Some(guid(2))).unwrap();
+
+        let world = hierarchy.world_transform(guid(3)).unwrap();
+        assert!((world.location - Vector::new(1000.0, 100.0, 60.0)).length() < 1e-9);
+        let expected = Transform::make_absolute(Transform::make_absolute(rider, seat), vehicle);
+        assert!(world.is_nearly_equal(expected, 1e-9));
+        assert_eq!(hierarchy.children(guid(1)), TArray::from_vec(vec![guid(2)]));
+
+        // Removing the middle node keeps the rider where it was
+        hierarchy.remove(guid(2));
+        assert_eq!(hierarchy.parent(guid(3)), None);
+        assert!(hierarchy.world_transform(guid(3)).unwrap().is_nearly_equal(world, 1e-9));
+    }
+
+    #[test]
+    fn test_cycles_and_unknown_nodes() {
+        let mut hierarchy = TransformHierarchy::new();
+        for id in 1..=3 {
+            hierarchy.set_relative_transform(guid(id), Transform::IDENTITY);
+        }
+        hierarchy.set_parent(guid(2), Some(guid(1))).unwrap();
+        hierarchy.set_parent(guid(3), Some(guid(2))).unwrap();
+
+        assert_eq!(hierarchy.set_parent(guid(1), Some(guid(3))), Err(HierarchyError::Cycle(guid(1))));
+        assert_eq!(hierarchy.set_parent(guid(1), Some(guid(1))), Err(HierarchyError::Cycle(guid(1))));
+        assert_eq!(hierarchy.parent(guid(1)), None);
+
+        assert_eq!(hierarchy.set_parent(guid(9), None), Err(HierarchyError::UnknownNode(guid(9))));
+        assert_eq!(hierarchy.set_parent(guid(1), Some(guid(9))), Err(HierarchyError::UnknownNode(guid(9))));
+        assert_eq!(hierarchy.world_transform(guid(9)), Err(HierarchyError::UnknownNode(guid(9))));
+
+        // A cycle created behind set_parent's back is still caught
+        hierarchy.nodes.find_mut(&guid(1)).unwrap().parent = Some(guid(3));
+        assert_eq!(hierarchy.world_transform(guid(3)), Err(HierarchyError::Cycle(guid(3))));
+
+        hierarchy.set_parent(guid(1), None).unwrap();
+        assert!(hierarchy.world_transform(guid(3)).is_ok());
+        assert_eq!(format!("{}", hierarchy), "TransformHierarchy(3 nodes, 2 attached)");
+    }
+}
@@ -0,0 +1,475 @@
+//! Validation of networking types received from untrusted clients
+
+use crate::types::Vector;
+use super::network_stats::LOSS_SCALE;
+use super::{GameSessionInfo, NetworkStats, PlayerNetInfo, RepMovement, SessionPropertyValue};
+use serde::{Deserialize, Serialize};
+use core::fmt;
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::format;
+
+/// A field that failed validation and why
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidationError {
+    /// Field path, e.g. `player_name` or `net_stats.ping`
+    pub field: String,
+    /// Human-readable reason
+    pub reason: String,
+}
+
+impl ValidationError {
+    /// Create an error for a field
+    pub fn new(field: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            reason: reason.into(),
+        }
+    }
+
+    fn nested(self, parent: &str) -> Self {
+        Self::new(format!("{}.{}", parent, self.field), self.reason)
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.reason)
+    }
+}
+
+impl core::error::Error for ValidationError {}
+
+/// Bounds applied by [`Validate`]
+///
+/// Lengths are counted in characters. Missing fields fall back to their
+/// defaults when deserializing, so servers can load partial configs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ValidationLimits {
+    /// Longest player name
+    pub max_player_name_len: usize,
+    /// Longest session name, game mode, map name or region
+    pub max_session_text_len: usize,
+    /// Smallest allowed `max_players`
+    pub min_max_players: i32,
+    /// Largest allowed `max_players`
+    pub max_max_players: i32,
+    /// Most custom properties on a session
+    pub max_custom_properties: usize,
+    /// Longest custom property key or value
+    pub max_custom_property_len: usize,
+    /// Highest believable ping in milliseconds
+    pub max_ping_ms: f32,
+    /// Fastest allowed linear speed in units per second
+    pub max_speed: f64,
+    /// Fastest allowed angular speed in degrees per second, per axis
+    pub max_angular_speed: f64,
+    /// Largest allowed absolute location component (UE5: UE_LARGE_WORLD_MAX)
+    pub max_world_coordinate: f64,
+}
+
+impl Default for ValidationLimits {
+    fn default() -> Self {
+        Self {
+            max_player_name_len: 32,
+            max_session_text_len: 64,
+            min_max_players: 1,
+            max_max_players: 1024,
+            max_custom_properties: 64,
+            max_custom_property_len: 256,
+            max_ping_ms: 10_000.0,
+            max_speed: 100_000.0,
+            max_angular_speed: 36_000.0,
+            max_world_coordinate: 8.796_093_022_208e12,
+        }
+    }
+}
+
+/// Checks for values received from untrusted sources
+///
+/// `validate` reports every problem at once. `sanitize` repairs what can be
+/// repaired (clamping ranges, truncating text, zeroing non-finite rates) and
+/// reports only what is left, such as an empty name or an unspecified address.
+pub trait Validate {
+    /// Validate against custom limits
+    fn validate_with(&self, limits: &ValidationLimits) -> Result<(), Vec<ValidationError>>;
+
+    /// Repair what can be repaired under custom limits, then validate
+    fn sanitize_with(&mut self, limits: &ValidationLimits) -> Result<(), Vec<ValidationError>>;
+
+    /// Validate against the default limits
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        self.validate_with(&ValidationLimits::default())
+    }
+
+    /// Repair what can be repaired under the default limits, then validate
+    fn sanitize(&mut self) -> Result<(), Vec<ValidationError>> {
+        self.sanitize_with(&ValidationLimits::default())
+    }
+}
+
+fn into_result(errors: Vec<ValidationError>) -> Result<(), Vec<ValidationError>> {
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn check_text(errors: &mut Vec<ValidationError>, field: &str, value: &str, max_len: usize) {
+    let len = value.chars().count();
+    if value.trim().is_empty() {
+        errors.push(ValidationError::new(field, "must not be empty"));
+    } else if len > max_len {
+        errors.push(ValidationError::new(field, format!("is {} characters, limit is {}", len, max_len)));
+    }
+    if value.chars().any(char::is_control) {
+        errors.push(ValidationError::new(field, "contains control characters"));
+    }
+}
+
+fn sanitize_text(value: &mut String, max_len: usize) {
+    let cleaned: String = value.chars().filter(|c| !c.is_control()).collect();
+    *value = cleaned.trim().chars().take(max_len).collect();
+}
+
+fn check_vector(errors: &mut Vec<ValidationError>, field: &str, value: Vector, max_component: f64) {
+    if !value.is_finite() {
+        errors.push(ValidationError::new(field, "is not finite"));
+    } else if value.abs().max_element() > max_component {
+        errors.push(ValidationError::new(field, format!("exceeds {}", max_component)));
+    }
+}
+
+fn check_rate(errors: &mut Vec<ValidationError>, field: &str, value: f32) {
+    if !value.is_finite() || value < 0.0 {
+        errors.push(ValidationError::new(field, "must be a finite, non-negative number"));
+    }
+}
+
+fn sanitize_rate(value: &mut f32) {
+    if !value.is_finite() || *value < 0.0 {
+        *value = 0.0;
+    }
+}
+
+impl Validate for NetworkStats {
+    fn validate_with(&self, limits: &ValidationLimits) -> Result<(), Vec<ValidationError>> {
+        let mut errors = Vec::new();
+        check_rate(&mut errors, "packets_out_per_second", self.packets_out_per_second);
+        check_rate(&mut errors, "packets_in_per_second", self.packets_in_per_second);
+        check_rate(&mut errors, "bytes_out_per_second", self.bytes_out_per_second);
+        check_rate(&mut errors, "bytes_in_per_second", self.bytes_in_per_second);
+        check_rate(&mut errors, "uptime", self.uptime);
+        if self.ping() > limits.max_ping_ms {
+            errors.push(ValidationError::new("ping", format!("exceeds {}ms", limits.max_ping_ms)));
+        }
+        if self.packet_loss_bps > LOSS_SCALE {
+            errors.push(ValidationError::new("packet_loss", format!("exceeds {} basis points (100%)", LOSS_SCALE)));
+        }
+        if self.jitter() > limits.max_ping_ms {
+            errors.push(ValidationError::new("jitter", format!("exceeds {}ms", limits.max_ping_ms)));
+        }
+        into_result(errors)
+    }
+
+    fn sanitize_with(&mut self, limits: &ValidationLimits) -> Result<(), Vec<ValidationError>> {
+        sanitize_rate(&mut self.packets_out_per_second);
+        sanitize_rate(&mut self.packets_in_per_second);
+        sanitize_rate(&mut self.bytes_out_per_second);
+        sanitize_rate(&mut self.bytes_in_per_second);
+        sanitize_rate(&mut self.uptime);
+        self.set_ping(self.ping().min(limits.max_ping_ms));
+        self.packet_loss_bps = self.packet_loss_bps.min(LOSS_SCALE);
+        self.set_jitter(self.jitter().min(limits.max_ping_ms));
+        self.validate_with(limits)
+    }
+}
+
+impl Validate for PlayerNetInfo {
+    fn validate_with(&self, limits: &ValidationLimits) -> Result<(), Vec<ValidationError>> {
+        let mut errors = Vec::new();
+        check_text(&mut errors, "player_name", &self.player_name, limits.max_player_name_len);
+        if self.ip_address.is_unspecified() {
+            errors.push(ValidationError::new("ip_address", "must not be the unspecified address"));
+        }
+        if self.team_id < -1 {
+            errors.push(ValidationError::new("team_id", "must be -1 (no team) or a team index"));
+        }
+        if let Err(stats_errors) = self.net_stats.validate_with(limits) {
+            errors.extend(stats_errors.into_iter().map(|e| e.nested("net_stats")));
+        }
+        into_result(errors)
+    }
+
+    fn sanitize_with(&mut self, limits: &ValidationLimits) -> Result<(), Vec<ValidationError>> {
+        sanitize_text(&mut self.player_name, limits.max_player_name_len);
+        self.team_id = self.team_id.max(-1);
+        // Errors are reported for the whole player below
+        let _ = self.net_stats.sanitize_with(limits);
+        self.validate_with(limits)
+    }
+}
+
+impl Validate for GameSessionInfo {
+    fn validate_with(&self, limits: &ValidationLimits) -> Result<(), Vec<ValidationError>> {
+        let mut errors = Vec::new();
+        check_text(&mut errors, "session_name", &self.session_name, limits.max_session_text_len);
+        check_text(&mut errors, "game_mode", &self.game_mode, limits.max_session_text_len);
+        check_text(&mut errors, "map_name", &self.map_name, limits.max_session_text_len);
+        check_text(&mut errors, "region", &self.region, limits.max_session_text_len);
+
+        if !(limits.min_max_players..=limits.max_max_players).contains(&self.max_players) {
+            errors.push(ValidationError::new(
+                "max_players",
+                format!("must be in {}..={}", limits.min_max_players, limits.max_max_players),
+            ));
+        }
+        if self.current_players < 0 || self.current_players > self.max_players {
+            errors.push(ValidationError::new("current_players", "must be between 0 and max_players"));
+        }
+
+        if self.custom_properties.len() > limits.max_custom_properties {
+            errors.push(ValidationError::new(
+                "custom_properties",
+                format!("has {} entries, limit is {}", self.custom_properties.len(), limits.max_custom_properties),
+            ));
+        }
+        let oversized = self.custom_properties.iter().any(|(key, value)| {
+            let value_len = value.as_str().map_or(0, |text| text.chars().count());
+            key.chars().count() > limits.max_custom_property_len || value_len > limits.max_custom_property_len
+        });
+        if oversized {
+            errors.push(ValidationError::new(
+                "custom_properties",
+                format!("keys and values are limited to {} characters", limits.max_custom_property_len),
+            ));
+        }
+        into_result(errors)
+    }
+
+    fn sanitize_with(&mut self, limits: &ValidationLimits) -> Result<(), Vec<ValidationError>> {
+        for text in [&mut self.session_name, &mut self.game_mode, &mut self.map_name, &mut self.region] {
+            sanitize_text(text, limits.max_session_text_len);
+        }
+        self.max_players = self.max_players.clamp(limits.min_max_players, limits.max_max_players);
+        self.current_players = self.current_players.clamp(0, self.max_players);
+
+        // Oversized keys can't be shortened without colliding, so drop them
+        let max_len = limits.max_custom_property_len;
+        self.custom_properties.retain(|key, _| key.chars().count() <= max_len);
+        for value in self.custom_properties.values_mut() {
+            if let SessionPropertyValue::String(text) = value {
+                if text.chars().count() > max_len {
+                    *text = text.chars().take(max_len).collect();
+                }
+            }
+        }
+        self.validate_with(limits)
+    }
+}
+
+impl Validate for RepMovement {
+    fn validate_with(&self, limits: &ValidationLimits) -> Result<(), Vec<ValidationError>> {
+        let mut errors = Vec::new();
+        check_vector(&mut errors, "location", self.location, limits.max_world_coordinate);
+        check_vector(&mut errors, "relative_location", self.relative_location, limits.max_world_coordinate);
+        check_vector(&mut errors, "angular_velocity", self.angular_velocity, limits.max_angular_speed);
+
+        if !self.linear_velocity.is_finite() {
+            errors.push(ValidationError::new("linear_velocity", "is not finite"));
+        } else if self.linear_velocity.length() > limits.max_speed {
+            errors.push(ValidationError::new("linear_velocity", format!("speed exceeds {}", limits.max_speed)));
+        }
+
+        let rotation = self.rotation;
+        if ![rotation.pitch, rotation.yaw, rotation.roll].iter().all(|axis| axis.is_finite()) {
+            errors.push(ValidationError::new("rotation", "is not finite"));
+        }
+        if self.has_location_base && !self.location_base.is_some_and(|base| base.is_valid()) {
+            errors.push(ValidationError::new("location_base", "must be a valid GUID when has_location_base is set"));
+        }
+        into_result(errors)
+    }
+
+    /// Non-finite velocities and rotation axes become zero, speeds are
+    /// clamped, and locations are clamped to the world bounds. Linear
+    /// velocity keeps its direction; angular velocity is clamped per axis.
+    /// A non-finite location can't be repaired.
+    fn sanitize_with(&mut self, limits: &ValidationLimits) -> Result<(), Vec<ValidationError>> {
+        let finite_or_zero = |value: Vector| {
+            let component = |c: f64| if c.is_finite() { c } else { 0.0 };
+            Vector::new(component(value.x), component(value.y), component(value.z))
+        };
+        let world = Vector::splat(limits.max_world_coordinate.max(0.0));
+
+        if self.location.is_finite() {
+            self.location = self.location.clamp(-world, world);
+        }
+        self.relative_location = finite_or_zero(self.relative_location).clamp(-world, world);
+        self.linear_velocity = finite_or_zero(self.linear_velocity).clamp_length_max(limits.max_speed.max(0.0));
+        let max_angular = Vector::splat(limits.max_angular_speed.max(0.0));
+        self.angular_velocity = finite_or_zero(self.angular_velocity).clamp(-max_angular, max_angular);
+
+        for axis in [&mut self.rotation.pitch, &mut self.rotation.yaw, &mut self.rotation.roll] {
+            if !axis.is_finite() {
+                *axis = 0.0;
+            }
+        }
+        self.rotation = self.rotation.normalize();
+        if self.has_location_base && !self.location_base.is_some_and(|base| base.is_valid()) {
+            self.clear_location_base();
+        }
+        self.validate_with(limits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{NetworkGUID, Rotator};
+
+    fn fields(errors: &[ValidationError]) -> Vec<&str> {
+        errors.iter().map(|e| e.field.as_str()).collect()
+    }
+
+    #[test]
+    fn test_malicious_player_payload() {
+        let json = format!(
+            r#"{{"player_id":{{"value":1}},"player_name":"{}","net_stats":{{"packets_out_per_second":-1.0,"packets_in_per_second":0.0,"bytes_out_per_second":0.0,"bytes_in_per_second":0.0,"ping":99999.0,"packet_loss":7.0,"jitter":0.0,"uptime":0.0}},"is_connected":true,"team_id":-7,"role":"Admin","join_time":0,"ip_address":"0.0.0.0"}}"#,
+            "A".repeat(10_000)
+        );
+        let mut player: PlayerNetInfo = serde_json::from_str(&json).unwrap();
+
+        let errors = player.validate().unwrap_err();
+        assert_eq!(
+            fields(&errors),
+            ["player_name", "ip_address", "team_id", "net_stats.packets_out_per_second", "net_stats.ping"]
+        );
+
+        // Everything but the address can be repaired
+        let remaining = player.sanitize().unwrap_err();
+        assert_eq!(fields(&remaining), ["ip_address"]);
+        assert_eq!(player.player_name.len(), 32);
+        assert_eq!(player.team_id, -1);
+        assert_eq!(player.net_stats.ping(), 10_000.0);
+        assert!(player.net_stats.packet_loss() <= 1.0);
+
+        player.ip_address = "203.0.113.7".parse().unwrap();
+        assert_eq!(player.validate(), Ok(()));
+
+        let mut blank = PlayerNetInfo::try_new(NetworkGUID::new(2), " \u{7}\n".to_string(), "::1").unwrap();
+        assert_eq!(fields(&blank.sanitize().unwrap_err()), ["player_name"]);
+    }
+
+    #[test]
+    fn test_malicious_session_payload() {
+        let mut session = GameSessionInfo::new("Lobby".to_string(), "DM".to_string(), "arena".to_string(), -5);
+        session.current_players = 40;
+        session.session_name = "x".repeat(500);
+        session.region = String::new();
+        for i in 0..3 {
+            session.set_custom_property(format!("key{}", i), "v".repeat(1_000));
+        }
+        session.set_custom_property("k".repeat(1_000), "value".to_string());
+
+        let json = serde_json::to_string(&session).unwrap();
+        let mut session: GameSessionInfo = serde_json::from_str(&json).unwrap();
+        let errors = session.validate().unwrap_err();
+        assert_eq!(
+            fields(&errors),
+            ["session_name", "region", "max_players", "current_players", "custom_properties"]
+        );
+
+        let remaining = session.sanitize().unwrap_err();
+        assert_eq!(fields(&remaining), ["region"]);
+        assert_eq!(session.max_players, 1);
+        assert_eq!(session.current_players, 1);
+        assert_eq!(session.session_name.chars().count(), 64);
+        assert_eq!(session.custom_properties.len(), 3);
+        assert!(session.custom_properties.values().all(|v| v.as_str().unwrap().len() == 256));
+
+        let strict = ValidationLimits {
+            max_custom_properties: 2,
+            ..Default::default()
+        };
+        session.region = "EU".to_string();
+        assert_eq!(session.validate(), Ok(()));
+        assert_eq!(fields(&session.validate_with(&strict).unwrap_err()), ["custom_properties"]);
+    }
+
+    #[test]
+    fn test_malicious_movement_payload() {
+        let mut movement = RepMovement::from_transform(
+            Vector::new(f64::NAN, 0.0, 0.0),
+            Rotator::new(f64::INFINITY, 0.0, 0.0),
+            Vector::new(1e9, 0.0, 0.0),
+        );
+        movement.angular_velocity = Vector::new(0.0, f64::NEG_INFINITY, 0.0);
+        movement.has_location_base = true;
+
+        let errors = movement.validate().unwrap_err();
+        assert_eq!(
+            fields(&errors),
+            ["location", "angular_velocity", "linear_velocity", "rotation", "location_base"]
+        );
+
+        let remaining = movement.sanitize().unwrap_err();
+        assert_eq!(fields(&remaining), ["location"]);
+        assert_eq!(movement.linear_velocity, Vector::new(100_000.0, 0.0, 0.0));
+        assert_eq!(movement.angular_velocity, Vector::ZERO);
+        assert_eq!(movement.rotation, Rotator::ZERO);
+        assert!(!movement.has_location_base);
+
+        // Configurable speed cap
+        let mut legit = RepMovement::from_transform(Vector::ZERO, Rotator::ZERO, Vector::new(0.0, 800.0, 0.0));
+        assert_eq!(legit.validate(), Ok(()));
+        let walking = ValidationLimits {
+            max_speed: 600.0,
+            ..Default::default()
+        };
+        assert_eq!(fields(&legit.validate_with(&walking).unwrap_err()), ["linear_velocity"]);
+        assert_eq!(legit.sanitize_with(&walking), Ok(()));
+        assert!((legit.linear_velocity.length() - 600.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_stats_payload_and_error_display() {
+        let json = r#"{"packets_out_per_second":1e39,"packets_in_per_second":0.0,"bytes_out_per_second":0.0,"bytes_in_per_second":0.0,"ping":20.0,"packet_loss":0.0,"jitter":1e12,"uptime":-3.0}"#;
+        let mut stats: NetworkStats = serde_json::from_str(json).unwrap();
+        let errors = stats.validate().unwrap_err();
+        assert_eq!(fields(&errors), ["packets_out_per_second", "uptime", "jitter"]);
+        assert_eq!(errors[1].to_string(), "uptime: must be a finite, non-negative number");
+
+        assert_eq!(stats.sanitize(), Ok(()));
+        assert_eq!(stats.packets_out_per_second, 0.0);
+        assert_eq!(stats.uptime, 0.0);
+    }
+
+    #[test]
+    fn test_packet_loss_out_of_range_rejected() {
+        // Struct literals and zero-copy archives skip the serde clamp
+        let mut stats = NetworkStats {
+            packet_loss_bps: 65_000,
+            ..NetworkStats::new()
+        };
+        let errors = stats.validate().unwrap_err();
+        assert_eq!(fields(&errors), ["packet_loss"]);
+        assert_eq!(errors[0].to_string(), "packet_loss: exceeds 10000 basis points (100%)");
+
+        stats.packet_loss_bps = 10_000;
+        assert_eq!(stats.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_packet_loss_out_of_range_sanitized() {
+        let mut stats = NetworkStats {
+            packet_loss_bps: u32::MAX,
+            ..NetworkStats::new()
+        };
+        assert_eq!(stats.sanitize(), Ok(()));
+        assert_eq!(stats.packet_loss_bps, 10_000);
+        assert_eq!(stats.packet_loss(), 1.0);
+    }
+}
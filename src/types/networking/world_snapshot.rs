@@ -0,0 +1,369 @@
+//! Keyed movement snapshots of every replicated actor, with diffing
+
+use crate::BinarySerializable;
+use crate::types::{SerializedSize, TMap};
+use super::{MovementDeltaTolerances, NetworkGUID, RepMovement, RepMovementDelta};
+use serde::{Deserialize, Serialize};
+use core::fmt;
+use alloc::vec::Vec;
+
+/// Reason a snapshot could not be loaded or a delta applied
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SnapshotError {
+    /// The same GUID appears twice in serialized entries
+    DuplicateEntry(NetworkGUID),
+    /// A delta changes an actor the snapshot doesn't have
+    MissingEntry(NetworkGUID),
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SnapshotError::DuplicateEntry(guid) => write!(f, "snapshot has more than one entry for {}", guid),
+            SnapshotError::MissingEntry(guid) => write!(f, "delta changes {}, which is not in the snapshot", guid),
+        }
+    }
+}
+
+impl core::error::Error for SnapshotError {}
+
+/// Serialized form of a snapshot: entries ordered by GUID
+#[derive(Serialize, Deserialize)]
+struct SnapshotEntries {
+    entries: Vec<(NetworkGUID, RepMovement)>,
+}
+
+/// Movement state of every actor in a world, keyed by NetworkGUID
+///
+/// Iterates and serializes in GUID order, so equal snapshots always produce
+/// the same bytes. Use `diff` to send or save only what changed since a
+/// baseline.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(into = "SnapshotEntries", try_from = "SnapshotEntries")]
+pub struct WorldSnapshot {
+    entries: TMap<NetworkGUID, RepMovement>,
+}
+
+impl fmt::Display for WorldSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "WorldSnapshot({} actors)", self.len())
+    }
+}
+
+impl BinarySerializable for WorldSnapshot {}
+
+impl SerializedSize for WorldSnapshot {
+    fn serialized_size(&self) -> usize {
+        self.entries.serialized_size()
+    }
+}
+
+impl WorldSnapshot {
+    /// Create an empty snapshot
+    pub fn new() -> Self {
+        Self { entries: TMap::new() }
+    }
+
+    /// Number of actors in the snapshot
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if the snapshot has no actors
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Set an actor's movement, returning the previous one
+    pub fn set(&mut self, guid: NetworkGUID, movement: RepMovement) -> Option<RepMovement> {
+        self.entries.insert(guid, movement)
+    }
+
+    /// Get an actor's movement
+    pub fn get(&self, guid: NetworkGUID) -> Option<&RepMovement> {
+        self.entries.find(&guid)
+    }
+
+    /// Get an actor's movement mutably
+    pub fn get_mut(&mut self, guid: NetworkGUID) -> Option<&mut RepMovement> {
+        self.entries.find_mut(&guid)
+    }
+
+    /// Remove an actor, returning its movement
+    pub fn remove(&mut self, guid: NetworkGUID) -> Option<RepMovement> {
+        self.entries.remove(&guid)
+    }
+
+    /// Check if an actor is in the snapshot
+    pub fn contains(&self, guid: NetworkGUID) -> bool {
+        self.entries.contains(&guid)
+    }
+
+    /// Iterate over actors in GUID order
+    pub fn iter(&self) -> impl Iterator<Item = (NetworkGUID, &RepMovement)> {
+        let mut entries: Vec<_> = self.entries.iter().map(|(guid, movement)| (*guid, movement)).collect();
+        entries.sort_unstable_by_key(|(guid, _)| guid.value);
+        entries.into_iter()
+    }
+
+    /// Changes from `baseline` to this snapshot with default tolerances
+    pub fn diff(&self, baseline: &WorldSnapshot) -> SnapshotDelta {
+        self.diff_with_tolerances(baseline, &MovementDeltaTolerances::default())
+    }
+
+    /// Changes from `baseline` to this snapshot with custom tolerances
+    ///
+    /// Actors whose movement stayed within `tolerances` are left out.
+    pub fn diff_with_tolerances(&self, baseline: &WorldSnapshot, tolerances: &MovementDeltaTolerances) -> SnapshotDelta {
+        let mut delta = SnapshotDelta::default();
+        for (guid, movement) in self.iter() {
+            match baseline.get(guid) {
+                None => delta.added.push((guid, *movement)),
+                Some(previous) => {
+                    let changes = RepMovementDelta::between(previous, movement, tolerances);
+                    if !changes.is_empty() {
+                        delta.changed.push((guid, changes));
+                    }
+                }
+            }
+        }
+        delta.removed = baseline.iter().map(|(guid, _)| guid).filter(|guid| !self.contains(*guid)).collect();
+        delta
+    }
+
+    /// Apply a delta taken against this snapshot
+    ///
+    /// Fails without modifying the snapshot if the delta changes an actor
+    /// that isn't present. Removing an absent actor is not an error.
+    pub fn apply_delta(&mut self, delta: &SnapshotDelta) -> Result<(), SnapshotError> {
+        if let Some((guid, _)) = delta.changed.iter().find(|(guid, _)| !self.contains(*guid)) {
+            return Err(SnapshotError::MissingEntry(*guid));
+        }
+        for guid in &delta.removed {
+            self.entries.remove(guid);
+        }
+        for (guid, changes) in &delta.changed {
+            if let Some(movement) = self.entries.find_mut(guid) {
+                *movement = changes.apply(movement);
+            }
+        }
+        for (guid, movement) in &delta.added {
+            self.entries.insert(*guid, *movement);
+        }
+        Ok(())
+    }
+}
+
+impl Default for WorldSnapshot {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<WorldSnapshot> for SnapshotEntries {
+    fn from(snapshot: WorldSnapshot) -> Self {
+        Self {
+            entries: snapshot.iter().map(|(guid, movement)| (guid, *movement)).collect(),
+        }
+    }
+}
+
+impl TryFrom<SnapshotEntries> for WorldSnapshot {
+    type Error = SnapshotError;
+
+    fn try_from(serialized: SnapshotEntries) -> Result<Self, Self::Error> {
+        let mut snapshot = Self::new();
+        for (guid, movement) in serialized.entries {
+            if snapshot.set(guid, movement).is_some() {
+                return Err(SnapshotError::DuplicateEntry(guid));
+            }
+        }
+        Ok(snapshot)
+    }
+}
+
+/// Actors added, removed and changed between two snapshots
+///
+/// Every list is in GUID order. Added actors carry their full movement;
+/// changed actors carry only the fields that moved past the tolerances.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct SnapshotDelta {
+    added: Vec<(NetworkGUID, RepMovement)>,
+    removed: Vec<NetworkGUID>,
+    changed: Vec<(NetworkGUID, RepMovementDelta)>,
+}
+
+impl fmt::Display for SnapshotDelta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "SnapshotDelta(Added: {}, Removed: {}, Changed: {})",
+            self.added.len(),
+            self.removed.len(),
+            self.changed.len()
+        )
+    }
+}
+
+impl BinarySerializable for SnapshotDelta {}
+
+impl SnapshotDelta {
+    /// Actors in the newer snapshot only
+    pub fn added(&self) -> &[(NetworkGUID, RepMovement)] {
+        &self.added
+    }
+
+    /// Actors in the baseline only
+    pub fn removed(&self) -> &[NetworkGUID] {
+        &self.removed
+    }
+
+    /// Actors in both whose movement changed
+    pub fn changed(&self) -> &[(NetworkGUID, RepMovementDelta)] {
+        &self.changed
+    }
+
+    /// Number of actors added, removed or changed
+    pub fn len(&self) -> usize {
+        self.added.len() + self.removed.len() + self.changed.len()
+    }
+
+    /// Check if the snapshots matched within tolerance
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Rotator, Vector};
+
+    fn movement(i: u32) -> RepMovement {
+        let offset = f64::from(i);
+        RepMovement::from_transform(Vector::new(offset, offset * 2.0, 100.0), Rotator::from_yaw(offset), Vector::X * offset)
+    }
+
+    fn snapshot(count: u32) -> WorldSnapshot {
+        let mut snapshot = WorldSnapshot::new();
+        for i in 1..=count {
+            snapshot.set(NetworkGUID::new(i), movement(i));
+        }
+        snapshot
+    }
+
+    #[test]
+    fn test_set_get_remove() {
+        let mut snapshot = WorldSnapshot::new();
+        assert!(snapshot.is_empty());
+        assert_eq!(snapshot.set(NetworkGUID::new(5), movement(5)), None);
+        assert_eq!(snapshot.set(NetworkGUID::new(5), movement(6)), Some(movement(5)));
+        assert_eq!(snapshot.get(NetworkGUID::new(5)), Some(&movement(6)));
+        assert_eq!(snapshot.len(), 1);
+
+        assert_eq!(snapshot.remove(NetworkGUID::new(5)), Some(movement(6)));
+        assert_eq!(snapshot.remove(NetworkGUID::new(5)), None);
+        assert!(!snapshot.contains(NetworkGUID::new(5)));
+    }
+
+    #[test]
+    fn test_iteration_is_sorted_by_guid() {
+        let mut snapshot = WorldSnapshot::new();
+        for value in [40, 3, 17, 1, 1000, 8] {
+            snapshot.set(NetworkGUID::new(value), movement(value));
+        }
+        let guids: Vec<u32> = snapshot.iter().map(|(guid, _)| guid.value).collect();
+        assert_eq!(guids, vec![1, 3, 8, 17, 40, 1000]);
+
+        // Same contents in a different insertion order give the same bytes
+        let mut reversed = WorldSnapshot::new();
+        for value in [8, 1000, 1, 17, 3, 40] {
+            reversed.set(NetworkGUID::new(value), movement(value));
+        }
+        assert_eq!(snapshot.to_binary().unwrap(), reversed.to_binary().unwrap());
+    }
+
+    #[test]
+    fn test_diff_and_apply() {
+        let baseline = snapshot(5);
+        let mut current = baseline.clone();
+        current.remove(NetworkGUID::new(2));
+        current.set(NetworkGUID::new(9), movement(9));
+        current.get_mut(NetworkGUID::new(4)).unwrap().location.z = 250.0;
+        // Below the default tolerance, so not a change
+        current.get_mut(NetworkGUID::new(5)).unwrap().location.x += 0.001;
+
+        let delta = current.diff(&baseline);
+        assert_eq!(delta.added(), &[(NetworkGUID::new(9), movement(9))]);
+        assert_eq!(delta.removed(), &[NetworkGUID::new(2)]);
+        assert_eq!(delta.changed().len(), 1);
+        assert_eq!(delta.changed()[0].0, NetworkGUID::new(4));
+        assert_eq!(delta.len(), 3);
+
+        let mut rebuilt = baseline.clone();
+        rebuilt.apply_delta(&delta).unwrap();
+        assert_eq!(rebuilt.len(), current.len());
+        assert_eq!(rebuilt.get(NetworkGUID::new(4)), current.get(NetworkGUID::new(4)));
+        assert_eq!(rebuilt.get(NetworkGUID::new(5)), baseline.get(NetworkGUID::new(5)));
+
+        let exact = current.diff_with_tolerances(&baseline, &MovementDeltaTolerances::EXACT);
+        assert_eq!(exact.changed().len(), 2);
+        let mut rebuilt = baseline.clone();
+        rebuilt.apply_delta(&exact).unwrap();
+        assert_eq!(rebuilt, current);
+
+        assert!(current.diff(&current).is_empty());
+    }
+
+    #[test]
+    fn test_apply_delta_missing_entry() {
+        let baseline = snapshot(3);
+        let mut current = baseline.clone();
+        current.get_mut(NetworkGUID::new(3)).unwrap().location.x = -50.0;
+        current.set(NetworkGUID::new(4), movement(4));
+        let delta = current.diff(&baseline);
+
+        let mut other = snapshot(2);
+        let before = other.clone();
+        assert_eq!(other.apply_delta(&delta), Err(SnapshotError::MissingEntry(NetworkGUID::new(3))));
+        assert_eq!(other, before);
+    }
+
+    #[test]
+    fn test_delta_is_small_when_few_entries_change() {
+        let baseline = snapshot(1000);
+        let mut current = baseline.clone();
+        for i in (1..=1000).step_by(100) {
+            let entry = current.get_mut(NetworkGUID::new(i)).unwrap();
+            entry.location += Vector::new(10.0, 0.0, 5.0);
+            entry.linear_velocity = Vector::Y * 300.0;
+        }
+
+        let delta = current.diff(&baseline);
+        assert_eq!(delta.changed().len(), 10);
+        let full_size = current.to_binary().unwrap().len();
+        let delta_size = delta.to_binary().unwrap().len();
+        assert!(delta_size * 10 < full_size, "delta {} bytes, full {} bytes", delta_size, full_size);
+
+        let mut rebuilt = baseline;
+        rebuilt.apply_delta(&SnapshotDelta::from_binary(&delta.to_binary().unwrap()).unwrap()).unwrap();
+        assert_eq!(rebuilt, current);
+    }
+
+    #[test]
+    fn test_serialization() {
+        let snapshot = snapshot(4);
+        assert_eq!(snapshot.serialized_size(), snapshot.to_binary().unwrap().len());
+        assert_eq!(WorldSnapshot::from_binary(&snapshot.to_binary().unwrap()).unwrap(), snapshot);
+
+        let json = serde_json::to_string(&snapshot).unwrap();
+        assert_eq!(serde_json::from_str::<WorldSnapshot>(&json).unwrap(), snapshot);
+
+        let duplicated = SnapshotEntries {
+            entries: vec![(NetworkGUID::new(1), movement(1)), (NetworkGUID::new(1), movement(2))],
+        };
+        let bytes = bincode::serialize(&duplicated).unwrap();
+        assert!(WorldSnapshot::from_binary(&bytes).is_err());
+        assert_eq!(WorldSnapshot::try_from(duplicated), Err(SnapshotError::DuplicateEntry(NetworkGUID::new(1))));
+    }
+}
@@ -0,0 +1,359 @@
+//! Quaternion type with UE-style helpers
+
+use crate::vector::*;
+use crate::rotator::*;
+use crate::types::NearlyEqual;
+use crate::BinarySerializable;
+use glam::DQuat;
+use serde::{Deserialize, Serialize};
+use core::fmt;
+use core::ops::{Deref, DerefMut, Mul, MulAssign, Neg};
+#[cfg(not(feature = "std"))]
+use crate::types::math::float_math::FloatMath;
+
+/// Unreal Engine style quaternion (FQuat), double precision
+///
+/// A thin wrapper over `glam::DQuat`: every glam method is available through
+/// `Deref`, and `From`/`Into` convert both ways. Angles in the UE-style
+/// helpers are in degrees, like [`Rotator`]. Serializes exactly like `DQuat`
+/// (`[x, y, z, w]`).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Quaternion(pub DQuat);
+
+impl fmt::Display for Quaternion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Quaternion(X={:.6}, Y={:.6}, Z={:.6}, W={:.6})",
+            self.0.x, self.0.y, self.0.z, self.0.w
+        )
+    }
+}
+
+impl BinarySerializable for Quaternion {}
+
+impl Quaternion {
+    /// Identity rotation
+    pub const IDENTITY: Self = Self(DQuat::IDENTITY);
+
+    /// Tolerance used by `is_normalized` (UE: `THRESH_QUAT_NORMALIZED`)
+    pub const NORMALIZED_TOLERANCE: f64 = 0.01;
+
+    /// Create a quaternion from raw components (not normalized)
+    pub fn new(x: f64, y: f64, z: f64, w: f64) -> Self {
+        Self(DQuat::from_xyzw(x, y, z, w))
+    }
+
+    /// Create a rotation of `angle_degrees` around `axis`
+    ///
+    /// The axis doesn't need to be normalized. A zero-length axis gives the identity.
+    pub fn from_axis_angle(axis: Vector, angle_degrees: f64) -> Self {
+        let axis = axis.normalize_or_zero();
+        if axis == Vector::ZERO {
+            return Self::IDENTITY;
+        }
+        Self(DQuat::from_axis_angle(axis, angle_degrees.to_radians()))
+    }
+
+    /// Axis and angle (degrees, in [0, 360]) of this rotation
+    ///
+    /// The identity returns the X axis and 0.
+    pub fn to_axis_angle(self) -> (Vector, f64) {
+        let (axis, angle) = self.get_normalized().0.to_axis_angle();
+        if angle == 0.0 || !axis.is_finite() {
+            return (Vector::X, 0.0);
+        }
+        (axis, angle.to_degrees())
+    }
+
+    /// Create a quaternion from a rotator
+    pub fn from_rotator(rotator: Rotator) -> Self {
+        Self(rotator.to_quaternion())
+    }
+
+    /// Convert to a rotator
+    pub fn to_rotator(self) -> Rotator {
+        Rotator::from_quaternion(self.0)
+    }
+
+    /// Angle in degrees of the smallest rotation taking this one to `other`
+    ///
+    /// `q` and `-q` are the same rotation, so their distance is 0.
+    pub fn angular_distance(self, other: Quaternion) -> f64 {
+        let dot = self.0.dot(other.0).abs().min(1.0);
+        (2.0 * dot.acos()).to_degrees()
+    }
+
+    /// Rotate a vector by this quaternion
+    pub fn rotate_vector(self, v: Vector) -> Vector {
+        self.0 * v
+    }
+
+    /// Rotate a vector by the inverse of this quaternion
+    pub fn unrotate_vector(self, v: Vector) -> Vector {
+        self.0.conjugate() * v
+    }
+
+    /// Check if the length is within `NORMALIZED_TOLERANCE` of one
+    pub fn is_normalized(self) -> bool {
+        (1.0 - self.0.length_squared()).abs() < Self::NORMALIZED_TOLERANCE
+    }
+
+    /// Get a normalized copy, or the identity if the length is nearly zero
+    pub fn get_normalized(self) -> Self {
+        let length_squared = self.0.length_squared();
+        if length_squared < 1e-8 || !length_squared.is_finite() {
+            Self::IDENTITY
+        } else {
+            Self(self.0 / length_squared.sqrt())
+        }
+    }
+
+    /// Advance this orientation by an angular velocity for `delta_time` seconds
+    ///
+    /// `angular_velocity` is in radians per second about the world X, Y and
+    /// Z axes, in the same sense as `from_axis_angle`, so for an upright
+    /// actor `z` is the yaw rate. Uses the first-order step
+    /// `q += 0.5 * (ω ⊗ q) * dt` followed by renormalization, as physics
+    /// engines do; the error per step grows with the cube of the angle
+    /// turned, so keep steps to a few degrees. Non-finite input returns
+    /// the orientation unchanged.
+    pub fn integrate(self, angular_velocity: Vector, delta_time: f64) -> Self {
+        let half_step = 0.5 * delta_time;
+        let spin = angular_velocity * half_step;
+        if !spin.is_finite() {
+            return self;
+        }
+        let derivative = DQuat::from_xyzw(spin.x, spin.y, spin.z, 0.0) * self.0;
+        Self(self.0 + derivative).get_normalized()
+    }
+
+    /// Negate this quaternion if needed so it is on the same hemisphere as `other`
+    ///
+    /// The rotation is unchanged; interpolating between the two afterwards
+    /// takes the shortest arc.
+    pub fn enforce_shortest_arc_with(&mut self, other: Quaternion) {
+        if self.0.dot(other.0) < 0.0 {
+            self.0 = -self.0;
+        }
+    }
+
+    /// Get the inverse rotation (the conjugate, for a unit quaternion)
+    pub fn inverse(self) -> Self {
+        Self(self.0.inverse())
+    }
+}
+
+impl Default for Quaternion {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Deref for Quaternion {
+    type Target = DQuat;
+
+    fn deref(&self) -> &DQuat {
+        &self.0
+    }
+}
+
+impl DerefMut for Quaternion {
+    fn deref_mut(&mut self) -> &mut DQuat {
+        &mut self.0
+    }
+}
+
+impl From<DQuat> for Quaternion {
+    fn from(quat: DQuat) -> Self {
+        Self(quat)
+    }
+}
+
+impl From<Quaternion> for DQuat {
+    fn from(quat: Quaternion) -> Self {
+        quat.0
+    }
+}
+
+impl From<glam::Quat> for Quaternion {
+    fn from(quat: glam::Quat) -> Self {
+        Self::new(quat.x as f64, quat.y as f64, quat.z as f64, quat.w as f64)
+    }
+}
+
+impl From<Quaternion> for glam::Quat {
+    fn from(quat: Quaternion) -> Self {
+        glam::Quat::from_xyzw(quat.0.x as f32, quat.0.y as f32, quat.0.z as f32, quat.0.w as f32)
+    }
+}
+
+/// `q` and `-q` are the same rotation, so they are nearly equal
+impl NearlyEqual for Quaternion {
+    fn max_component_error(&self, other: &Self) -> f64 {
+        self.0.max_component_error(&other.0)
+    }
+}
+
+impl From<Rotator> for Quaternion {
+    fn from(rotator: Rotator) -> Self {
+        Self::from_rotator(rotator)
+    }
+}
+
+impl From<Quaternion> for Rotator {
+    fn from(quat: Quaternion) -> Self {
+        quat.to_rotator()
+    }
+}
+
+/// Compose rotations: `a * b` applies `b` first, then `a` (same as UE)
+impl Mul for Quaternion {
+    type Output = Quaternion;
+
+    fn mul(self, rhs: Quaternion) -> Quaternion {
+        Self(self.0 * rhs.0)
+    }
+}
+
+impl MulAssign for Quaternion {
+    fn mul_assign(&mut self, rhs: Quaternion) {
+        self.0 *= rhs.0;
+    }
+}
+
+impl Mul<Vector> for Quaternion {
+    type Output = Vector;
+
+    fn mul(self, rhs: Vector) -> Vector {
+        self.rotate_vector(rhs)
+    }
+}
+
+impl Neg for Quaternion {
+    type Output = Quaternion;
+
+    fn neg(self) -> Quaternion {
+        Self(-self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_axis_angle_round_trip() {
+        let cases = [
+            (Vector::Z, 90.0),
+            (Vector::new(1.0, 1.0, 0.0), 45.0),
+            (Vector::new(-2.0, 0.5, 3.0), 200.0),
+        ];
+        for (axis, angle) in cases {
+            let quat = Quaternion::from_axis_angle(axis, angle);
+            assert!(quat.is_normalized());
+            let (round_axis, round_angle) = quat.to_axis_angle();
+            assert!((round_axis - axis.normalize()).length() < 1e-9);
+            assert!((round_angle - angle).abs() < 1e-9);
+        }
+
+        assert_eq!(Quaternion::from_axis_angle(Vector::ZERO, 90.0), Quaternion::IDENTITY);
+        assert_eq!(Quaternion::IDENTITY.to_axis_angle(), (Vector::X, 0.0));
+
+        let yaw = Quaternion::from_axis_angle(Vector::Z, 90.0);
+        assert!((yaw.rotate_vector(Vector::X) - Vector::Y).length() < 1e-12);
+        assert!((yaw.unrotate_vector(Vector::Y) - Vector::X).length() < 1e-12);
+        assert!(yaw.to_rotator().is_nearly_equal(Rotator::new(0.0, 90.0, 0.0), 1e-9));
+        assert!((Quaternion::from_rotator(yaw.to_rotator()).angular_distance(yaw)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_shortest_arc_enforcement() {
+        let a = Quaternion::from_axis_angle(Vector::Z, 170.0);
+        let mut b = Quaternion::from_axis_angle(Vector::Z, -170.0);
+        assert!(a.dot(*b) < 0.0);
+        assert!((a.angular_distance(b) - 20.0).abs() < 1e-9);
+
+        let before = b.rotate_vector(Vector::X);
+        b.enforce_shortest_arc_with(a);
+        assert!(a.dot(*b) > 0.0);
+        assert!((b.rotate_vector(Vector::X) - before).length() < 1e-12);
+        assert!((a.angular_distance(-a)).abs() < 1e-6);
+
+        let midway = Quaternion(a.slerp(*b, 0.5));
+        assert!((midway.rotate_vector(Vector::X) - Vector::new(-1.0, 0.0, 0.0)).length() < 1e-9);
+    }
+
+    #[test]
+    fn test_normalization_and_conversion() {
+        let unnormalized = Quaternion::new(0.0, 0.0, 2.0, 0.0);
+        assert!(!unnormalized.is_normalized());
+        assert!(unnormalized.get_normalized().is_normalized());
+        assert_eq!(Quaternion::new(0.0, 0.0, 0.0, 0.0).get_normalized(), Quaternion::IDENTITY);
+
+        let quat = Quaternion::from_axis_angle(Vector::X, 30.0);
+        let glam_quat: DQuat = quat.into();
+        assert_eq!(Quaternion::from(glam_quat), quat);
+        let single: glam::Quat = quat.into();
+        assert!(Quaternion::from(single).angular_distance(quat) < 0.1);
+        assert_eq!(quat * Quaternion::IDENTITY, quat);
+        assert!((quat * quat.inverse()).angular_distance(Quaternion::IDENTITY) < 1e-6);
+
+        let rotator = Rotator::new(-30.0, 135.0, 60.0);
+        let from_rotator = Quaternion::from(rotator);
+        assert_eq!(from_rotator, Quaternion::from_rotator(rotator));
+        assert!(Rotator::from(from_rotator).is_nearly_equal(rotator, 1e-9));
+
+        // Serializes like DQuat
+        let json = serde_json::to_string(&quat).unwrap();
+        assert_eq!(json, serde_json::to_string(&glam_quat).unwrap());
+        assert!(serde_json::from_str::<Quaternion>(&json).unwrap().abs_diff_eq(*quat, 1e-12));
+        assert_eq!(Quaternion::from_binary(&quat.to_binary().unwrap()).unwrap(), quat);
+        assert_eq!(
+            format!("{}", Quaternion::IDENTITY),
+            "Quaternion(X=0.000000, Y=0.000000, Z=0.000000, W=1.000000)"
+        );
+    }
+
+    #[test]
+    fn test_integrate_yaw_rate() {
+        // 90 degrees per second about Z for one second, at 60 Hz
+        let rate = Vector::new(0.0, 0.0, 90.0_f64.to_radians());
+        let mut rotation = Quaternion::IDENTITY;
+        for _ in 0..60 {
+            rotation = rotation.integrate(rate, 1.0 / 60.0);
+        }
+        assert!(rotation.is_normalized());
+        assert!(rotation.angular_distance(Quaternion::from_axis_angle(Vector::Z, 90.0)) < 0.01);
+
+        // Rates are about world axes: a pitched actor spinning about Z keeps its pitch
+        let pitched = Quaternion::from_rotator(Rotator::new(30.0, 0.0, 0.0));
+        let mut spun = pitched;
+        for _ in 0..60 {
+            spun = spun.integrate(rate, 1.0 / 60.0);
+        }
+        let expected = Quaternion::from_axis_angle(Vector::Z, 90.0) * pitched;
+        assert!(spun.angular_distance(expected) < 0.01);
+    }
+
+    #[test]
+    fn test_integrate_full_turn_returns_to_start() {
+        let start = Quaternion::from_rotator(Rotator::new(10.0, -40.0, 25.0));
+        let axis = Vector::new(1.0, -2.0, 0.5).normalize();
+        // One full turn in 2 seconds, 360 steps of 1 degree
+        let rate = axis * core::f64::consts::PI;
+        let mut rotation = start;
+        for step in 0..360 {
+            rotation = rotation.integrate(rate, 2.0 / 360.0);
+            if step == 179 {
+                assert!(rotation.angular_distance(start) > 179.0);
+            }
+        }
+        assert!(rotation.angular_distance(start) < 0.05);
+
+        assert_eq!(start.integrate(Vector::ZERO, 1.0), start);
+        assert_eq!(start.integrate(Vector::new(f64::NAN, 0.0, 0.0), 1.0), start);
+        assert_eq!(start.integrate(Vector::Z, f64::INFINITY), start);
+    }
+}
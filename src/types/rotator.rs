@@ -1,11 +1,15 @@
 //! Rotator type and rotation utilities
 
 use crate::vector::*;
+use crate::types::math::nearly_equal::max_error;
+use crate::types::math::quantized_key::quantize_angle;
+use crate::types::{Axis, IntVector, NearlyEqual};
 use crate::BinarySerializable;
 use glam::DQuat;
-use glam::Quat;
 use serde::{Deserialize, Serialize};
-use std::fmt;
+use core::fmt;
+#[cfg(not(feature = "std"))]
+use crate::types::math::float_math::FloatMath;
 
 /// Unreal Engine style Rotator (Pitch, Yaw, Roll in degrees)
 /// 
@@ -14,6 +18,8 @@ use std::fmt;
 /// - Yaw: Rotation around Z axis (left/right)  
 /// - Roll: Rotation around X axis (banking)
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(feature = "rkyv", archive(check_bytes, compare(PartialEq)), archive_attr(derive(Debug)))]
 pub struct Rotator {
     /// Rotation around Y axis (degrees)
     pub pitch: f64,
@@ -31,6 +37,15 @@ impl fmt::Display for Rotator {
 
 impl BinarySerializable for Rotator {}
 
+impl crate::types::SerializedSize for Rotator {
+    fn serialized_size(&self) -> usize {
+        24
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl crate::types::ZeroCopy for Rotator {}
+
 impl Rotator {
     /// Zero rotation constant
     pub const ZERO: Self = Self { 
@@ -39,8 +54,12 @@ impl Rotator {
         roll: 0.0 
     };
 
+    /// Half-sine of pitch beyond which `from_quaternion` treats a rotation as
+    /// gimbal locked (UE: `SINGULARITY_THRESHOLD`, about 0.08° from ±90°)
+    pub const GIMBAL_SINGULARITY_THRESHOLD: f64 = 0.4999995;
+
     /// Create a new rotator with the given pitch, yaw, and roll (in degrees)
-    pub fn new(pitch: f64, yaw: f64, roll: f64) -> Self {
+    pub const fn new(pitch: f64, yaw: f64, roll: f64) -> Self {
         Self { pitch, yaw, roll }
     }
 
@@ -62,6 +81,22 @@ impl Rotator {
         }
     }
 
+    /// Create a rotator whose forward vector points along the given direction
+    ///
+    /// Roll is always zero. Positive pitch looks down, matching `get_forward_vector`.
+    /// A zero-length direction gives `Rotator::ZERO`.
+    pub fn from_direction(direction: Vector) -> Self {
+        if direction.length_squared() == 0.0 || !direction.is_finite() {
+            return Self::ZERO;
+        }
+        let horizontal = (direction.x * direction.x + direction.y * direction.y).sqrt();
+        Self {
+            pitch: -direction.z.atan2(horizontal).to_degrees(),
+            yaw: direction.y.atan2(direction.x).to_degrees(),
+            roll: 0.0,
+        }
+    }
+
     /// Returns the rotator with angles normalized to [-180, 180] range
     pub fn get_normalized(&self) -> Self {
         Self {
@@ -92,18 +127,46 @@ impl Rotator {
     }
 
     /// Create from quaternion
+    ///
+    /// Near pitch = ±90° (gimbal lock) yaw and roll describe the same axis,
+    /// so only their combination is recoverable. Like UE's `FQuat::Rotator`,
+    /// quaternions within `GIMBAL_SINGULARITY_THRESHOLD` of the singularity
+    /// snap pitch to exactly ±90°; the combined angle is then folded into yaw
+    /// and roll is zero. Use [`Rotator::equivalent_to`] to compare such
+    /// rotators by orientation.
     pub fn from_quaternion(quat: DQuat) -> Self {
-        // To reverse the composition: yaw_quat * pitch_quat * roll_quat
-        // We need to extract in the reverse order
-        // Use ZYX order to match our composition order
-        let (z_rad, y_rad, x_rad) = quat.to_euler(glam::EulerRot::ZYX);
+        let DQuat { x, y, z, w } = quat;
+        // sin(pitch) / 2 for our Z (yaw), Y (pitch), X (roll) composition
+        let singularity_test = w * y - z * x;
+
+        if singularity_test.abs() > Self::GIMBAL_SINGULARITY_THRESHOLD {
+            // The quaternion is yaw_quat * pitch_quat(±90) up to rounding;
+            // roll about X became rotation about -+Z and merged into yaw
+            return Self {
+                pitch: 90.0_f64.copysign(singularity_test),
+                yaw: normalize_angle((2.0 * z.atan2(w)).to_degrees()),
+                roll: 0.0,
+            };
+        }
+
         Self {
-            pitch: y_rad.to_degrees(),  // Y rotation = Pitch  
-            yaw: z_rad.to_degrees(),    // Z rotation = Yaw
-            roll: x_rad.to_degrees(),   // X rotation = Roll
+            pitch: (2.0 * singularity_test).asin().to_degrees(),
+            yaw: (2.0 * (w * z + x * y)).atan2(1.0 - 2.0 * (y * y + z * z)).to_degrees(),
+            roll: (2.0 * (w * x + y * z)).atan2(1.0 - 2.0 * (x * x + y * y)).to_degrees(),
         }
     }
 
+    /// Check if two rotators describe the same orientation, within `tolerance` degrees
+    ///
+    /// Unlike `is_nearly_equal`, this ignores how the orientation is split
+    /// into pitch, yaw and roll, so `(90, 30, 45)` matches `(90, -15, 0)` and
+    /// `(0, 180, 0)` matches `(180, 0, 180)`.
+    pub fn equivalent_to(self, other: Rotator, tolerance: f64) -> bool {
+        let delta = self.to_quaternion().conjugate() * other.to_quaternion();
+        let angle = 2.0 * delta.xyz().length().atan2(delta.w.abs());
+        angle.to_degrees() <= tolerance
+    }
+
     /// Normalize angles to [-180, 180] range
     pub fn normalize(mut self) -> Self {
         self.pitch = normalize_angle(self.pitch);
@@ -130,6 +193,26 @@ impl Rotator {
         Vector::new(vec3.x, vec3.y, vec3.z)
     }
 
+    /// Get the rotated basis vector for `axis`: forward for X, right for Y and up for Z
+    pub fn get_axis_vector(self, axis: Axis) -> Vector {
+        self.to_quaternion() * axis.to_vector()
+    }
+
+    /// Rotate a vector by this rotation (UE: RotateVector)
+    pub fn rotate_vector(self, v: Vector) -> Vector {
+        self.to_quaternion() * v
+    }
+
+    /// Rotate a vector by the inverse of this rotation (UE: UnrotateVector)
+    pub fn unrotate_vector(self, v: Vector) -> Vector {
+        self.to_quaternion().inverse() * v
+    }
+
+    /// Get the rotator that undoes this rotation (UE: GetInverse)
+    pub fn get_inverse(self) -> Self {
+        Self::from_quaternion(self.to_quaternion().inverse())
+    }
+
     /// Check if this rotator is nearly zero
     pub fn is_nearly_zero(self, tolerance: f64) -> bool {
         self.pitch.abs() <= tolerance
@@ -137,14 +220,30 @@ impl Rotator {
             && self.roll.abs() <= tolerance
     }
 
-    /// Check if two rotators are nearly equal
+    /// Check if two rotators are nearly equal, comparing angles modulo 360° (see [`NearlyEqual`])
     pub fn is_nearly_equal(self, other: Rotator, tolerance: f64) -> bool {
-        (self.pitch - other.pitch).abs() <= tolerance
-            && (self.yaw - other.yaw).abs() <= tolerance
-            && (self.roll - other.roll).abs() <= tolerance
+        self.nearly_equal(&other, tolerance)
+    }
+
+    /// Hashable key snapping each angle to `precision`-degree cells, as (pitch, yaw, roll)
+    ///
+    /// Angles are wrapped first, so equivalent angles such as 179.9° and
+    /// -180.1° share a key (see [`crate::types::math::quantized_key`]).
+    /// Different Euler angles for the same orientation still get different keys.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `precision` isn't positive and finite.
+    pub fn to_key(self, precision: f64) -> IntVector {
+        IntVector::new(
+            quantize_angle(self.pitch, precision),
+            quantize_angle(self.yaw, precision),
+            quantize_angle(self.roll, precision),
+        )
     }
 
     /// Add rotators component-wise
+    #[allow(clippy::should_implement_trait)]
     pub fn add(self, other: Rotator) -> Self {
         Self {
             pitch: self.pitch + other.pitch,
@@ -154,6 +253,7 @@ impl Rotator {
     }
 
     /// Subtract rotators component-wise
+    #[allow(clippy::should_implement_trait)]
     pub fn sub(self, other: Rotator) -> Self {
         Self {
             pitch: self.pitch - other.pitch,
@@ -170,6 +270,30 @@ impl Rotator {
             roll: self.roll * factor,
         }
     }
+
+    /// Advance this rotation by an angular velocity for `delta_time` seconds
+    ///
+    /// `angular_velocity` is in degrees per second about the world X, Y and
+    /// Z axes, like UE's `FRepMovement::AngularVelocity`: for a level actor
+    /// `x` is the roll rate, `y` the pitch rate and `z` the yaw rate. The
+    /// step goes through [`Quaternion::integrate`](crate::Quaternion::integrate),
+    /// so it is free of gimbal lock and the result is normalized to
+    /// (-180, 180].
+    pub fn integrate_angular_velocity(self, angular_velocity_deg_per_sec: Vector, delta_time: f64) -> Rotator {
+        let rate = angular_velocity_deg_per_sec * (core::f64::consts::PI / 180.0);
+        crate::Quaternion::from_rotator(self).integrate(rate, delta_time).to_rotator()
+    }
+}
+
+/// Angles are compared modulo 360°, so 179.9° is 0.2° from -180.1°
+impl NearlyEqual for Rotator {
+    fn max_component_error(&self, other: &Self) -> f64 {
+        max_error([
+            normalize_angle(self.pitch - other.pitch).abs(),
+            normalize_angle(self.yaw - other.yaw).abs(),
+            normalize_angle(self.roll - other.roll).abs(),
+        ])
+    }
 }
 
 impl Default for Rotator {
@@ -178,6 +302,91 @@ impl Default for Rotator {
     }
 }
 
+// Operator overloads (component-wise, matching add/sub/scale)
+impl core::ops::Add for Rotator {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.add(rhs)
+    }
+}
+
+impl core::ops::Sub for Rotator {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.sub(rhs)
+    }
+}
+
+impl core::ops::Neg for Rotator {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.pitch, -self.yaw, -self.roll)
+    }
+}
+
+impl core::ops::Mul<f64> for Rotator {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        self.scale(rhs)
+    }
+}
+
+impl core::ops::Mul<f32> for Rotator {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        self.scale(rhs as f64)
+    }
+}
+
+impl core::ops::Mul<Rotator> for f64 {
+    type Output = Rotator;
+
+    fn mul(self, rhs: Rotator) -> Self::Output {
+        rhs.scale(self)
+    }
+}
+
+impl core::ops::AddAssign for Rotator {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl core::ops::SubAssign for Rotator {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl core::ops::MulAssign<f64> for Rotator {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self = *self * rhs;
+    }
+}
+
+impl core::ops::MulAssign<f32> for Rotator {
+    fn mul_assign(&mut self, rhs: f32) {
+        *self = *self * rhs;
+    }
+}
+
+impl core::iter::Sum for Rotator {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, rot| acc + rot)
+    }
+}
+
+impl<'a> core::iter::Sum<&'a Rotator> for Rotator {
+    fn sum<I: Iterator<Item = &'a Rotator>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
 /// Normalize an angle to the range [-180, 180] degrees
 pub fn normalize_angle(angle: f64) -> f64 {
     let mut result = angle % 360.0;
@@ -195,6 +404,13 @@ pub fn angle_difference(angle1: f64, angle2: f64) -> f64 {
     normalize_angle(angle2 - angle1)
 }
 
+/// Get the rotation that points the forward (+X) axis from one location toward another
+///
+/// Returns the identity rotation when `from == to`.
+pub fn find_look_at_rotation(from: Vector, to: Vector) -> DQuat {
+    Rotator::from_direction(to - from).to_quaternion()
+}
+
 /// Linearly interpolate between two rotators
 pub fn lerp_rotator(a: Rotator, b: Rotator, alpha: f64) -> Rotator {
     Rotator {
@@ -304,6 +520,122 @@ mod tests {
         assert!((original.roll - back_to_rot.roll).abs() < 0.01);
     }
 
+    #[test]
+    fn test_rotate_vector_matches_direction_vectors() {
+        let rot = Rotator::new(30.0, 45.0, 60.0);
+        assert!((rot.rotate_vector(Vector::X) - rot.get_forward_vector()).length() < 1e-9);
+        assert!((rot.rotate_vector(Vector::Y) - rot.get_right_vector()).length() < 1e-9);
+        assert!((rot.rotate_vector(Vector::Z) - rot.get_up_vector()).length() < 1e-9);
+
+        let yaw = Rotator::from_yaw(90.0);
+        assert!((yaw.rotate_vector(Vector::new(1.0, 0.0, 0.0)) - Vector::new(0.0, 1.0, 0.0)).length() < 1e-9);
+        assert!((yaw.unrotate_vector(Vector::new(0.0, 1.0, 0.0)) - Vector::new(1.0, 0.0, 0.0)).length() < 1e-9);
+    }
+
+    #[test]
+    fn test_unrotate_inverts_rotate() {
+        let v = Vector::new(3.0, -7.5, 12.25);
+        let angles = [-180.0, -135.0, -90.0, -45.0, 0.0, 30.0, 90.0, 150.0];
+
+        for &pitch in &angles {
+            for &yaw in &angles {
+                for &roll in &angles {
+                    let rot = Rotator::new(pitch, yaw, roll);
+                    let round_trip = rot.unrotate_vector(rot.rotate_vector(v));
+                    assert!(
+                        (round_trip - v).length() < 1e-9,
+                        "round trip failed for {}", rot
+                    );
+
+                    let inverse = rot.get_inverse();
+                    let via_inverse = inverse.rotate_vector(rot.rotate_vector(v));
+                    assert!(
+                        (via_inverse - v).length() < 1e-6,
+                        "inverse failed for {}", rot
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_direction() {
+        let directions = [
+            Vector::new(1.0, 0.0, 0.0),
+            Vector::new(0.0, -3.0, 0.0),
+            Vector::new(1.0, 1.0, 1.0),
+            Vector::new(-2.0, 0.5, -4.0),
+            Vector::new(0.0, 0.0, 1.0),
+            Vector::new(0.0, 0.0, -1.0),
+        ];
+        for direction in directions {
+            let forward = Rotator::from_direction(direction).get_forward_vector();
+            assert!((forward - direction.normalize()).length() < 1e-9, "direction {}", direction);
+        }
+
+        // Looking straight down is positive pitch in this crate's convention
+        assert!((Rotator::from_direction(Vector::new(0.0, 0.0, -1.0)).pitch - 90.0).abs() < 1e-9);
+        assert_eq!(Rotator::from_direction(Vector::ZERO), Rotator::ZERO);
+    }
+
+    #[test]
+    fn test_find_look_at_rotation() {
+        let from = Vector::new(10.0, 0.0, 5.0);
+        let to = Vector::new(10.0, 20.0, 5.0);
+        let forward = find_look_at_rotation(from, to) * Vector::X;
+        assert!((forward - Vector::Y).length() < 1e-9);
+
+        assert_eq!(find_look_at_rotation(from, from), DQuat::IDENTITY);
+    }
+
+    #[test]
+    fn test_rotator_operators() {
+        let a = Rotator::new(10.0, 20.0, 30.0);
+        let b = Rotator::new(-5.0, 45.0, 90.0);
+
+        assert_eq!(a + b, a.add(b));
+        assert_eq!(a - b, a.sub(b));
+        assert_eq!(a * 2.0, a.scale(2.0));
+        assert_eq!(a * 2.0_f32, a.scale(2.0));
+        assert_eq!(2.0 * a, a.scale(2.0));
+        assert_eq!(-a, Rotator::new(-10.0, -20.0, -30.0));
+
+        let mut c = a;
+        c += b;
+        assert_eq!(c, a + b);
+        c -= b;
+        assert_eq!(c, a);
+        c *= 0.5;
+        assert_eq!(c, Rotator::new(5.0, 10.0, 15.0));
+        c *= 2.0_f32;
+        assert_eq!(c, a);
+    }
+
+    #[test]
+    fn test_rotator_negation_cancels() {
+        let r = Rotator::new(170.0, -350.0, 725.0);
+        let zero = (-r + r).get_normalized();
+        assert!(zero.is_nearly_zero(1e-9));
+    }
+
+    #[test]
+    fn test_rotator_sum() {
+        let rotators = [
+            Rotator::new(10.0, 0.0, 0.0),
+            Rotator::new(20.0, 30.0, 0.0),
+            Rotator::new(30.0, 60.0, 9.0),
+        ];
+
+        let total: Rotator = rotators.iter().copied().sum();
+        assert_eq!(total, Rotator::new(60.0, 90.0, 9.0));
+
+        let average = rotators.iter().sum::<Rotator>() * (1.0 / rotators.len() as f64);
+        assert!(average.is_nearly_equal(Rotator::new(20.0, 30.0, 3.0), 1e-9));
+
+        let empty: [Rotator; 0] = [];
+        assert_eq!(empty.iter().sum::<Rotator>(), Rotator::ZERO);
+    }
+
     #[test]
     fn test_rotator_display() {
         let rot = Rotator::new(45.0, 90.0, -30.0);
@@ -377,4 +709,78 @@ mod tests {
         // At -90° pitch, forward should point up (positive Z)
         assert!((forward_neg.z - 1.0).abs() < 0.1);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_gimbal_lock_round_trip_is_deterministic() {
+        for pitch in [90.0, 89.999, 90.001] {
+            let original = Rotator::new(pitch, 30.0, 45.0);
+            let back = Rotator::from_quaternion(original.to_quaternion());
+            assert_eq!(back.pitch, 90.0);
+            assert_eq!(back.roll, 0.0);
+            // Roll about X at +90 pitch turns into negative yaw
+            assert!((back.yaw - -15.0).abs() < 0.01, "pitch {}: {}", pitch, back);
+            assert!(back.equivalent_to(original, 0.01));
+            assert!(!back.is_nearly_equal(original, 0.01));
+        }
+
+        for pitch in [-90.0, -89.999, -90.001] {
+            let original = Rotator::new(pitch, 30.0, 45.0);
+            let back = Rotator::from_quaternion(original.to_quaternion());
+            assert_eq!(back.pitch, -90.0);
+            assert_eq!(back.roll, 0.0);
+            assert!((back.yaw - 75.0).abs() < 0.01, "pitch {}: {}", pitch, back);
+            assert!(back.equivalent_to(original, 0.01));
+        }
+
+        // Same orientation always gives the same components
+        let a = Rotator::from_quaternion(Rotator::new(90.0, 30.0, 45.0).to_quaternion());
+        let b = Rotator::from_quaternion(Rotator::new(90.0, -15.0, 0.0).to_quaternion());
+        assert!(a.is_nearly_equal(b, 1e-9));
+    }
+
+    #[test]
+    fn test_near_gimbal_lock_keeps_components() {
+        // Outside the singularity threshold the components round-trip
+        for pitch in [89.9, -89.9, 85.0, -60.0] {
+            let original = Rotator::new(pitch, 30.0, 45.0);
+            let back = Rotator::from_quaternion(original.to_quaternion());
+            assert!(back.is_nearly_equal(original, 1e-6), "{} -> {}", original, back);
+        }
+    }
+
+    #[test]
+    fn test_rotator_equivalent_to() {
+        assert!(Rotator::new(0.0, 180.0, 0.0).equivalent_to(Rotator::new(180.0, 0.0, 180.0), 1e-9));
+        assert!(Rotator::new(0.0, 359.0, 0.0).equivalent_to(Rotator::new(0.0, -1.0, 0.0), 1e-9));
+        assert!(Rotator::new(10.0, 20.0, 30.0).equivalent_to(Rotator::new(10.0, 20.0, 30.5), 0.6));
+        assert!(!Rotator::new(10.0, 20.0, 30.0).equivalent_to(Rotator::new(10.0, 20.0, 31.0), 0.5));
+    }
+
+    #[test]
+    fn test_integrate_angular_velocity() {
+        // 45 degrees per second of yaw for one second at 30 Hz
+        let mut heading = Rotator::new(0.0, 10.0, 0.0);
+        for _ in 0..30 {
+            heading = heading.integrate_angular_velocity(Vector::new(0.0, 0.0, 45.0), 1.0 / 30.0);
+        }
+        assert!((heading.yaw - 55.0).abs() < 0.01);
+        assert!(heading.pitch.abs() < 1e-9 && heading.roll.abs() < 1e-9);
+
+        // A full turn wraps back to the starting rotator
+        let start = Rotator::new(20.0, 170.0, -5.0);
+        let mut rotation = start;
+        for _ in 0..240 {
+            rotation = rotation.integrate_angular_velocity(Vector::new(0.0, 0.0, 180.0), 2.0 / 240.0);
+        }
+        assert!(rotation.equivalent_to(start, 0.05), "{}", rotation);
+    }
+
+    #[test]
+    fn test_get_axis_vector() {
+        let rotation = Rotator::new(30.0, -60.0, 15.0);
+        assert!((rotation.get_axis_vector(Axis::X) - rotation.get_forward_vector()).length() < 1e-12);
+        assert!((rotation.get_axis_vector(Axis::Y) - rotation.get_right_vector()).length() < 1e-12);
+        assert!((rotation.get_axis_vector(Axis::Z) - rotation.get_up_vector()).length() < 1e-12);
+        assert!((Rotator::from_yaw(90.0).get_axis_vector(Axis::X) - Vector::Y).length() < 1e-12);
+    }
+}
@@ -0,0 +1,18 @@
+//! Serialization formats beyond plain serde/bincode
+
+pub mod serde_ue_json;
+pub mod serialized_size;
+pub mod stable_binary;
+pub mod ue_archive;
+pub mod ue_string;
+#[cfg(feature = "rkyv")]
+pub mod zero_copy;
+
+// Re-export all types for convenience
+pub use serde_ue_json::{UeColor, UeLinearColor, UeRotator, UeTransform, UeVector};
+pub use serialized_size::*;
+pub use stable_binary::*;
+pub use ue_archive::*;
+pub use ue_string::*;
+#[cfg(feature = "rkyv")]
+pub use zero_copy::*;
@@ -0,0 +1,345 @@
+//! Serde adapters for the JSON Unreal Engine's `FJsonObjectConverter` reads and writes
+//!
+//! The crate's own types serialize with lowercase keys (and `Vector` as an
+//! array), while UE uses its property names:
+//!
+//! | Type          | UE type        | JSON                                                   |
+//! |---------------|----------------|--------------------------------------------------------|
+//! | `Vector`      | `FVector`      | `{"X":1,"Y":2,"Z":3}`                                  |
+//! | `Rotator`     | `FRotator`     | `{"Pitch":0,"Yaw":90,"Roll":0}`                        |
+//! | `Color`       | `FColor`       | `{"B":0,"G":128,"R":255,"A":255}`                      |
+//! | `LinearColor` | `FLinearColor` | `{"R":1,"G":0.5,"B":0,"A":1}`                          |
+//! | `Transform`   | `FTransform`   | `{"Rotation":{"X":..,"W":..},"Translation":{..},"Scale3D":{..}}` |
+//!
+//! Output uses UE's exact names. Input also accepts the first letter
+//! lowercased (`"x"`, `"pitch"`, `"scale3D"`), which is what
+//! `FJsonObjectConverter` writes unless `SkipStandardizeCase` is set.
+//!
+//! Wrap a value in `UeVector`, `UeRotator`, ... or put the matching module on
+//! a field:
+//!
+//! ```
+//! use ue_types::*;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct SpawnPoint {
+//!     #[serde(rename = "Location", with = "serde_ue_json::vector")]
+//!     location: Vector,
+//!     #[serde(rename = "Rotation", with = "serde_ue_json::rotator")]
+//!     rotation: Rotator,
+//! }
+//!
+//! let json = r#"{"Location":{"X":100,"Y":-20.5,"Z":0},"Rotation":{"pitch":0,"yaw":90,"roll":0}}"#;
+//! let spawn: SpawnPoint = serde_json::from_str(json).unwrap();
+//! assert_eq!(spawn.location, Vector::new(100.0, -20.5, 0.0));
+//! assert_eq!(spawn.rotation.yaw, 90.0);
+//! ```
+
+use crate::types::{Color, LinearColor, Rotator, Transform, Vector};
+use glam::DQuat;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Serialize, Deserialize)]
+struct VectorRepr {
+    #[serde(rename = "X", alias = "x")]
+    x: f64,
+    #[serde(rename = "Y", alias = "y")]
+    y: f64,
+    #[serde(rename = "Z", alias = "z")]
+    z: f64,
+}
+
+#[derive(Serialize, Deserialize)]
+struct QuatRepr {
+    #[serde(rename = "X", alias = "x")]
+    x: f64,
+    #[serde(rename = "Y", alias = "y")]
+    y: f64,
+    #[serde(rename = "Z", alias = "z")]
+    z: f64,
+    #[serde(rename = "W", alias = "w")]
+    w: f64,
+}
+
+#[derive(Serialize, Deserialize)]
+struct RotatorRepr {
+    #[serde(rename = "Pitch", alias = "pitch")]
+    pitch: f64,
+    #[serde(rename = "Yaw", alias = "yaw")]
+    yaw: f64,
+    #[serde(rename = "Roll", alias = "roll")]
+    roll: f64,
+}
+
+/// `FColor` declares its channels in memory order, so UE writes B, G, R, A
+#[derive(Serialize, Deserialize)]
+struct ColorRepr {
+    #[serde(rename = "B", alias = "b")]
+    b: u8,
+    #[serde(rename = "G", alias = "g")]
+    g: u8,
+    #[serde(rename = "R", alias = "r")]
+    r: u8,
+    #[serde(rename = "A", alias = "a")]
+    a: u8,
+}
+
+#[derive(Serialize, Deserialize)]
+struct LinearColorRepr {
+    #[serde(rename = "R", alias = "r")]
+    r: f32,
+    #[serde(rename = "G", alias = "g")]
+    g: f32,
+    #[serde(rename = "B", alias = "b")]
+    b: f32,
+    #[serde(rename = "A", alias = "a")]
+    a: f32,
+}
+
+/// Fields in `FTransform`'s declaration order
+#[derive(Serialize, Deserialize)]
+struct TransformRepr {
+    #[serde(rename = "Rotation", alias = "rotation")]
+    rotation: QuatRepr,
+    #[serde(rename = "Translation", alias = "translation")]
+    translation: VectorRepr,
+    #[serde(rename = "Scale3D", alias = "scale3D")]
+    scale: VectorRepr,
+}
+
+impl From<Vector> for VectorRepr {
+    fn from(v: Vector) -> Self {
+        Self { x: v.x, y: v.y, z: v.z }
+    }
+}
+
+impl From<VectorRepr> for Vector {
+    fn from(repr: VectorRepr) -> Self {
+        Vector::new(repr.x, repr.y, repr.z)
+    }
+}
+
+impl From<DQuat> for QuatRepr {
+    fn from(q: DQuat) -> Self {
+        Self { x: q.x, y: q.y, z: q.z, w: q.w }
+    }
+}
+
+impl From<QuatRepr> for DQuat {
+    fn from(repr: QuatRepr) -> Self {
+        DQuat::from_xyzw(repr.x, repr.y, repr.z, repr.w)
+    }
+}
+
+/// Define a newtype that (de)serializes its inner value through a repr
+/// struct, plus the module of `with` helpers for plain fields
+macro_rules! ue_json_wrapper {
+    ($(#[$meta:meta])* $wrapper:ident, $module:ident, $inner:ty, $repr:ty, $to_repr:expr, $from_repr:expr) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub struct $wrapper(pub $inner);
+
+        impl From<$inner> for $wrapper {
+            fn from(value: $inner) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<$wrapper> for $inner {
+            fn from(wrapper: $wrapper) -> Self {
+                wrapper.0
+            }
+        }
+
+        impl Serialize for $wrapper {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let to_repr: fn(&$inner) -> $repr = $to_repr;
+                to_repr(&self.0).serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $wrapper {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let from_repr: fn($repr) -> $inner = $from_repr;
+                <$repr>::deserialize(deserializer).map(|repr| Self(from_repr(repr)))
+            }
+        }
+
+        #[doc = concat!("`#[serde(with)]` helpers that read and write a field like [`", stringify!($wrapper), "`]")]
+        pub mod $module {
+            use super::*;
+
+            /// Serialize with UE's field names
+            pub fn serialize<S: Serializer>(value: &$inner, serializer: S) -> Result<S::Ok, S::Error> {
+                $wrapper(*value).serialize(serializer)
+            }
+
+            /// Deserialize from UE's field names, in either case
+            pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<$inner, D::Error> {
+                $wrapper::deserialize(deserializer).map(|wrapper| wrapper.0)
+            }
+        }
+    };
+}
+
+ue_json_wrapper!(
+    /// `Vector` as an `FVector` JSON object: `{"X":1,"Y":2,"Z":3}`
+    UeVector, vector, Vector, VectorRepr,
+    |v| VectorRepr::from(*v),
+    Vector::from
+);
+
+ue_json_wrapper!(
+    /// `Rotator` as an `FRotator` JSON object: `{"Pitch":0,"Yaw":90,"Roll":0}`
+    UeRotator, rotator, Rotator, RotatorRepr,
+    |r| RotatorRepr { pitch: r.pitch, yaw: r.yaw, roll: r.roll },
+    |repr| Rotator::new(repr.pitch, repr.yaw, repr.roll)
+);
+
+ue_json_wrapper!(
+    /// `Color` as an `FColor` JSON object: `{"B":0,"G":128,"R":255,"A":255}`
+    UeColor, color, Color, ColorRepr,
+    |c| ColorRepr { b: c.b, g: c.g, r: c.r, a: c.a },
+    |repr| Color::new(repr.r, repr.g, repr.b, repr.a)
+);
+
+ue_json_wrapper!(
+    /// `LinearColor` as an `FLinearColor` JSON object: `{"R":1,"G":0.5,"B":0,"A":1}`
+    UeLinearColor, linear_color, LinearColor, LinearColorRepr,
+    |c| LinearColorRepr { r: c.r, g: c.g, b: c.b, a: c.a },
+    |repr| LinearColor::new(repr.r, repr.g, repr.b, repr.a)
+);
+
+ue_json_wrapper!(
+    /// `Transform` as an `FTransform` JSON object with `Rotation` (a
+    /// quaternion), `Translation` and `Scale3D`
+    UeTransform, transform, Transform, TransformRepr,
+    |t| TransformRepr {
+        rotation: t.rotation.into(),
+        translation: t.location.into(),
+        scale: t.scale.into(),
+    },
+    |repr| Transform::new(repr.translation.into(), repr.rotation.into(), repr.scale.into())
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::String;
+
+    // Regression pins written by hand in the layout we read from
+    // FJsonObjectConverter::UStructToJsonObjectString: tab-indented, keys in
+    // declaration order, integral doubles without a fraction. They are not
+    // captured from an engine: they catch changes to this crate's output,
+    // not a misreading of the engine's format.
+    const PINNED_FVECTOR_JSON: &str = "{\n\t\"X\": 100.5,\n\t\"Y\": -20,\n\t\"Z\": 0\n}";
+    const PINNED_FROTATOR_JSON: &str = "{\n\t\"Pitch\": 0,\n\t\"Yaw\": 90,\n\t\"Roll\": -12.5\n}";
+    const PINNED_FCOLOR_JSON: &str = "{\n\t\"B\": 0,\n\t\"G\": 128,\n\t\"R\": 255,\n\t\"A\": 255\n}";
+    const PINNED_FLINEARCOLOR_JSON: &str = "{\n\t\"R\": 1,\n\t\"G\": 0.5,\n\t\"B\": 0.25,\n\t\"A\": 1\n}";
+    const PINNED_FTRANSFORM_JSON: &str = "{\n\t\"Rotation\":\n\t{\n\t\t\"X\": 0,\n\t\t\"Y\": 0,\n\t\t\"Z\": 0.70710678118654746,\n\t\t\"W\": 0.70710678118654757\n\t},\n\t\"Translation\":\n\t{\n\t\t\"X\": 10,\n\t\t\"Y\": 20,\n\t\t\"Z\": 30\n\t},\n\t\"Scale3D\":\n\t{\n\t\t\"X\": 1,\n\t\t\"Y\": 1,\n\t\t\"Z\": 2\n\t}\n}";
+
+    /// Compare the keys and values of two JSON documents, ignoring whitespace
+    /// and whether a number has a fraction (`90` vs `90.0`)
+    fn assert_same_json(actual: &str, expected: &str) {
+        fn as_floats(value: serde_json::Value) -> serde_json::Value {
+            match value {
+                serde_json::Value::Number(n) => serde_json::json!(n.as_f64().unwrap()),
+                serde_json::Value::Object(map) => map.into_iter().map(|(key, value)| (key, as_floats(value))).collect(),
+                other => other,
+            }
+        }
+        let actual = as_floats(serde_json::from_str(actual).unwrap());
+        let expected = as_floats(serde_json::from_str(expected).unwrap());
+        assert_eq!(actual, expected);
+    }
+
+    /// Lowercase the first letter of every key, as UE's StandardizeCase does
+    fn standardize_case(json: &str) -> String {
+        json.replace("\"X\"", "\"x\"")
+            .replace("\"Y\"", "\"y\"")
+            .replace("\"Z\"", "\"z\"")
+            .replace("\"W\"", "\"w\"")
+            .replace("\"Pitch\"", "\"pitch\"")
+            .replace("\"Yaw\"", "\"yaw\"")
+            .replace("\"Roll\"", "\"roll\"")
+            .replace("\"R\"", "\"r\"")
+            .replace("\"G\"", "\"g\"")
+            .replace("\"B\"", "\"b\"")
+            .replace("\"A\"", "\"a\"")
+            .replace("\"Rotation\"", "\"rotation\"")
+            .replace("\"Translation\"", "\"translation\"")
+            .replace("\"Scale3D\"", "\"scale3D\"")
+    }
+
+    #[test]
+    fn test_pinned_vector_and_rotator() {
+        let UeVector(vector) = serde_json::from_str(PINNED_FVECTOR_JSON).unwrap();
+        assert_eq!(vector, Vector::new(100.5, -20.0, 0.0));
+        assert_same_json(&serde_json::to_string(&UeVector(vector)).unwrap(), PINNED_FVECTOR_JSON);
+        assert_eq!(serde_json::to_string(&UeVector(vector)).unwrap(), r#"{"X":100.5,"Y":-20.0,"Z":0.0}"#);
+
+        let UeRotator(rotator) = serde_json::from_str(PINNED_FROTATOR_JSON).unwrap();
+        assert_eq!(rotator, Rotator::new(0.0, 90.0, -12.5));
+        assert_same_json(&serde_json::to_string(&UeRotator(rotator)).unwrap(), PINNED_FROTATOR_JSON);
+
+        let lower: UeVector = serde_json::from_str(&standardize_case(PINNED_FVECTOR_JSON)).unwrap();
+        assert_eq!(lower.0, vector);
+        let lower: UeRotator = serde_json::from_str(&standardize_case(PINNED_FROTATOR_JSON)).unwrap();
+        assert_eq!(lower.0, rotator);
+    }
+
+    #[test]
+    fn test_pinned_colors() {
+        let UeColor(color) = serde_json::from_str(PINNED_FCOLOR_JSON).unwrap();
+        assert_eq!(color, Color::new(255, 128, 0, 255));
+        // Channel order matters when comparing against UE output as text
+        assert_eq!(serde_json::to_string(&UeColor(color)).unwrap(), r#"{"B":0,"G":128,"R":255,"A":255}"#);
+
+        let UeLinearColor(linear) = serde_json::from_str(PINNED_FLINEARCOLOR_JSON).unwrap();
+        assert_eq!(linear, LinearColor::new(1.0, 0.5, 0.25, 1.0));
+        assert_same_json(&serde_json::to_string(&UeLinearColor(linear)).unwrap(), PINNED_FLINEARCOLOR_JSON);
+
+        let lower: UeColor = serde_json::from_str(&standardize_case(PINNED_FCOLOR_JSON)).unwrap();
+        assert_eq!(lower.0, color);
+        let lower: UeLinearColor = serde_json::from_str(&standardize_case(PINNED_FLINEARCOLOR_JSON)).unwrap();
+        assert_eq!(lower.0, linear);
+        assert!(serde_json::from_str::<UeColor>(r#"{"B":0,"G":0,"R":256,"A":255}"#).is_err());
+    }
+
+    #[test]
+    fn test_pinned_transform() {
+        let UeTransform(transform) = serde_json::from_str(PINNED_FTRANSFORM_JSON).unwrap();
+        assert_eq!(transform.location, Vector::new(10.0, 20.0, 30.0));
+        assert_eq!(transform.scale, Vector::new(1.0, 1.0, 2.0));
+        assert!((transform.get_rotator().yaw - 90.0).abs() < 1e-9);
+        assert_same_json(&serde_json::to_string(&UeTransform(transform)).unwrap(), PINNED_FTRANSFORM_JSON);
+
+        let lower: UeTransform = serde_json::from_str(&standardize_case(PINNED_FTRANSFORM_JSON)).unwrap();
+        assert_eq!(lower.0, transform);
+        assert!(serde_json::from_str::<UeTransform>(r#"{"Translation":{"X":0,"Y":0,"Z":0}}"#).is_err());
+    }
+
+    #[test]
+    fn test_with_helpers_on_fields() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct ActorRecord {
+            #[serde(rename = "Transform", with = "transform")]
+            transform: Transform,
+            #[serde(rename = "Tint", with = "color")]
+            tint: Color,
+            #[serde(rename = "Velocity", with = "vector")]
+            velocity: Vector,
+        }
+
+        let record = ActorRecord {
+            transform: Transform::new(Vector::new(1.0, 2.0, 3.0), DQuat::from_xyzw(0.0, 0.0, 0.6, 0.8), Vector::ONE),
+            tint: Color::ORANGE,
+            velocity: Vector::new(0.0, 600.0, 0.0),
+        };
+        let json = serde_json::to_string(&record).unwrap();
+        assert!(json.contains(r#""Velocity":{"X":0.0,"Y":600.0,"Z":0.0}"#));
+        assert!(json.contains(r#""Scale3D":{"X":1.0,"Y":1.0,"Z":1.0}"#));
+        assert_eq!(serde_json::from_str::<ActorRecord>(&json).unwrap(), record);
+    }
+}
@@ -0,0 +1,233 @@
+//! Wire sizes of the `BinarySerializable` encoding, without serializing
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use glam::{DQuat, DVec2, DVec3, DVec4, Quat, Vec2, Vec3, Vec4};
+
+/// Bytes bincode writes before a string, sequence or map (a `u64` length)
+pub const LENGTH_PREFIX_SIZE: usize = 8;
+
+/// Bytes bincode writes to select an enum variant (a `u32` index)
+pub const VARIANT_TAG_SIZE: usize = 4;
+
+/// Exact number of bytes `BinarySerializable::to_binary` produces for a value
+///
+/// Lets servers check a per-connection byte budget before serializing
+/// anything. Types with a fixed layout return a constant; strings and
+/// containers add up their contents plus bincode's length prefixes and
+/// enum tags. Every implementation matches `to_binary().len()`.
+///
+/// ```
+/// use ue_types::*;
+///
+/// let movement = RepMovement::new();
+/// let budget = 1200;
+/// assert!(movement.serialized_size() <= budget);
+/// ```
+pub trait SerializedSize {
+    /// Number of bytes in the binary encoding of this value
+    fn serialized_size(&self) -> usize;
+}
+
+/// Fixed-size types whose encoding is exactly their in-memory size
+macro_rules! impl_fixed_serialized_size {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl SerializedSize for $t {
+                fn serialized_size(&self) -> usize {
+                    core::mem::size_of::<$t>()
+                }
+            }
+        )*
+    };
+}
+
+impl_fixed_serialized_size!(u8, u16, u32, u64, i8, i16, i32, i64, f32, f64);
+impl_fixed_serialized_size!(DVec2, DVec3, DVec4, DQuat, Vec2, Vec3, Vec4, Quat);
+
+impl SerializedSize for bool {
+    fn serialized_size(&self) -> usize {
+        1
+    }
+}
+
+impl SerializedSize for str {
+    fn serialized_size(&self) -> usize {
+        LENGTH_PREFIX_SIZE + self.len()
+    }
+}
+
+impl SerializedSize for String {
+    fn serialized_size(&self) -> usize {
+        self.as_str().serialized_size()
+    }
+}
+
+impl<T: SerializedSize + ?Sized> SerializedSize for &T {
+    fn serialized_size(&self) -> usize {
+        (**self).serialized_size()
+    }
+}
+
+/// A one-byte tag, then the value if present
+impl<T: SerializedSize> SerializedSize for Option<T> {
+    fn serialized_size(&self) -> usize {
+        1 + self.as_ref().map_or(0, SerializedSize::serialized_size)
+    }
+}
+
+impl<T: SerializedSize> SerializedSize for [T] {
+    fn serialized_size(&self) -> usize {
+        LENGTH_PREFIX_SIZE + self.iter().map(SerializedSize::serialized_size).sum::<usize>()
+    }
+}
+
+impl<T: SerializedSize> SerializedSize for Vec<T> {
+    fn serialized_size(&self) -> usize {
+        self.as_slice().serialized_size()
+    }
+}
+
+impl<K: SerializedSize, V: SerializedSize> SerializedSize for BTreeMap<K, V> {
+    fn serialized_size(&self) -> usize {
+        map_serialized_size(self.iter())
+    }
+}
+
+/// serde writes addresses as a variant tag and the raw octets in binary formats
+impl SerializedSize for IpAddr {
+    fn serialized_size(&self) -> usize {
+        VARIANT_TAG_SIZE
+            + match self {
+                IpAddr::V4(address) => address.serialized_size(),
+                IpAddr::V6(address) => address.serialized_size(),
+            }
+    }
+}
+
+impl SerializedSize for Ipv4Addr {
+    fn serialized_size(&self) -> usize {
+        4
+    }
+}
+
+impl SerializedSize for Ipv6Addr {
+    fn serialized_size(&self) -> usize {
+        16
+    }
+}
+
+/// Length prefix plus every key and value
+pub(crate) fn map_serialized_size<'a, K, V>(entries: impl Iterator<Item = (&'a K, &'a V)>) -> usize
+where
+    K: SerializedSize + 'a,
+    V: SerializedSize + 'a,
+{
+    LENGTH_PREFIX_SIZE + entries.map(|(key, value)| key.serialized_size() + value.serialized_size()).sum::<usize>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::*;
+    use crate::BinarySerializable;
+    use alloc::string::ToString;
+
+    /// Check the reported size against what bincode actually writes
+    fn assert_exact<T: SerializedSize + BinarySerializable + serde::Serialize>(value: &T) {
+        assert_eq!(value.serialized_size(), value.to_binary().unwrap().len());
+    }
+
+    #[test]
+    fn test_fixed_size_types() {
+        assert_exact(&Vector::new(1.0, 2.0, 3.0));
+        assert_exact(&Rotator::new(10.0, 20.0, 30.0));
+        assert_exact(&Transform::from_location_rotator(Vector::splat(5.0), Rotator::from_yaw(45.0)));
+        assert_exact(&Color::ORANGE);
+        assert_exact(&LinearColor::PURPLE);
+        assert_exact(&NetworkGUID::new(42));
+        assert_exact(&BoundingBox::new(Vector::ZERO, Vector::ONE));
+        assert_exact(&BoundingBox::EMPTY);
+        assert_exact(&BoundingSphere::new(Vector::ONE, 2.0));
+        assert_exact(&NetworkStats::new());
+
+        assert_eq!(Vector::ZERO.serialized_size(), 24);
+        assert_eq!(Transform::IDENTITY.serialized_size(), 80);
+        assert_eq!(Color::WHITE.serialized_size(), 4);
+    }
+
+    #[test]
+    fn test_rep_movement_size() {
+        let mut movement = RepMovement::from_transform(Vector::new(1.0, 2.0, 3.0), Rotator::from_yaw(90.0), Vector::X);
+        assert_exact(&movement);
+        let unbased = movement.serialized_size();
+
+        movement.set_location_base(NetworkGUID::new(7), Vector::ONE);
+        assert_exact(&movement);
+        assert_eq!(movement.serialized_size(), unbased + NetworkGUID::new(7).serialized_size());
+    }
+
+    #[test]
+    fn test_container_sizes() {
+        assert_exact(&TArray::<i32>::new());
+        assert_exact(&TArray::from_vec(vec![1u8, 2, 3]));
+        assert_exact(&TArray::from_vec(vec![RepMovement::new(); 5]));
+        assert_exact(&TArray::from_vec(vec!["a".to_string(), String::new(), "longer text".to_string()]));
+        assert_exact(&TArray::from_vec(vec![Some(NetworkGUID::new(1)), None]));
+
+        let mut map = TMap::new();
+        assert_exact(&map);
+        map.add("spawn".to_string(), Vector::new(1.0, 2.0, 3.0));
+        map.add("flag".to_string(), Vector::ZERO);
+        assert_exact(&map);
+        let mut nested: TMap<i32, TArray<Color>> = TMap::new();
+        nested.add(3, TArray::from_vec(vec![Color::RED, Color::BLUE]));
+        assert_exact(&nested);
+    }
+
+    #[test]
+    fn test_player_and_session_sizes() {
+        let mut player = PlayerNetInfo::new_with_addr_at(
+            NetworkGUID::new(9),
+            "Player One".to_string(),
+            "10.0.0.5".parse().unwrap(),
+            1_700_000_000,
+        );
+        assert_exact(&player);
+        player.ip_address = "2001:db8::1".parse().unwrap();
+        player.port = Some(7777);
+        assert_exact(&player);
+
+        let mut session = GameSessionInfo::new_at(1_000, "Friday CTF".to_string(), "CTF".to_string(), "ctf_fort".to_string(), 16);
+        assert_exact(&session);
+        session.set_custom_property("ranked", true);
+        session.set_custom_property("season", 4i64);
+        session.set_custom_property("motd", "Welcome");
+        session.set_custom_property("gravity", -980.0);
+        session.set_custom_property("spawn", Vector::new(1.0, 2.0, 3.0));
+        assert_exact(&session);
+    }
+
+    #[test]
+    fn test_to_binary_into_reuses_buffer() {
+        let first = RepMovement::from_transform(Vector::ONE, Rotator::ZERO, Vector::ZERO);
+        let second = NetworkGUID::new(77);
+
+        let mut buffer = Vec::with_capacity(256);
+        first.to_binary_into(&mut buffer).unwrap();
+        assert_eq!(buffer, first.to_binary().unwrap());
+
+        // Appends, so several values can share one packet buffer
+        second.to_binary_into(&mut buffer).unwrap();
+        assert_eq!(buffer.len(), first.serialized_size() + second.serialized_size());
+        assert_eq!(RepMovement::from_binary(&buffer).unwrap(), first);
+        assert_eq!(NetworkGUID::from_binary(&buffer[first.serialized_size()..]).unwrap(), second);
+
+        let capacity = buffer.capacity();
+        buffer.clear();
+        first.to_binary_into(&mut buffer).unwrap();
+        assert_eq!(buffer.capacity(), capacity);
+    }
+}
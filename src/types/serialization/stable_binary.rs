@@ -0,0 +1,638 @@
+//! Versioned, endianness-stable binary format
+//!
+//! Unlike `BinarySerializable` (plain bincode), every buffer starts with a
+//! fixed header so it can be validated before decoding:
+//!
+//! | Offset | Size | Field                                   |
+//! |--------|------|-----------------------------------------|
+//! | 0      | 4    | Magic `b"UESB"`                         |
+//! | 4      | 2    | Format version (u16 LE)                 |
+//! | 6      | 2    | Type tag (u16 LE)                       |
+//! | 8      | 4    | Payload length in bytes (u32 LE)        |
+//! | 12     | 4    | FNV-1a checksum of the payload (u32 LE) |
+//!
+//! Payload fields use fixed-width little-endian encodings: floats as IEEE-754
+//! bits, `bool` as one byte (0 or 1) and `Option<T>` as a one-byte tag
+//! followed by the value when present.
+
+use crate::types::{BoundingBox, BoundingSphere, NetworkGUID, RepMovement, Rotator, Transform, Vector};
+use glam::DQuat;
+use core::fmt;
+use alloc::vec::Vec;
+
+/// Magic bytes at the start of every stable binary buffer
+pub const STABLE_BINARY_MAGIC: [u8; 4] = *b"UESB";
+
+/// Current payload format version
+pub const STABLE_BINARY_VERSION: u16 = 1;
+
+/// Size of the header preceding the payload
+pub const STABLE_BINARY_HEADER_SIZE: usize = 16;
+
+/// Error produced while decoding a stable binary buffer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StableBinaryError {
+    /// The buffer is shorter than the header
+    TruncatedHeader(usize),
+    /// The buffer does not start with `STABLE_BINARY_MAGIC`
+    BadMagic([u8; 4]),
+    /// The format version is newer (or older) than this build understands
+    UnsupportedVersion(u16),
+    /// The buffer holds a different type than the one requested
+    TypeMismatch { expected: u16, found: u16 },
+    /// The header's payload length doesn't match the bytes present
+    LengthMismatch { expected: usize, found: usize },
+    /// The payload checksum doesn't match the header
+    ChecksumMismatch { expected: u32, found: u32 },
+    /// The payload is structurally invalid (e.g. ran out of bytes, bad tag)
+    CorruptPayload(&'static str),
+}
+
+impl fmt::Display for StableBinaryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StableBinaryError::TruncatedHeader(len) => {
+                write!(f, "buffer of {} bytes is too short for the {}-byte header", len, STABLE_BINARY_HEADER_SIZE)
+            }
+            StableBinaryError::BadMagic(magic) => write!(f, "bad magic bytes {:02X?}", magic),
+            StableBinaryError::UnsupportedVersion(version) => {
+                write!(f, "unsupported format version {} (expected {})", version, STABLE_BINARY_VERSION)
+            }
+            StableBinaryError::TypeMismatch { expected, found } => {
+                write!(f, "type tag {} does not match expected tag {}", found, expected)
+            }
+            StableBinaryError::LengthMismatch { expected, found } => {
+                write!(f, "payload length {} does not match header length {}", found, expected)
+            }
+            StableBinaryError::ChecksumMismatch { expected, found } => {
+                write!(f, "payload checksum {:08X} does not match header checksum {:08X}", found, expected)
+            }
+            StableBinaryError::CorruptPayload(reason) => write!(f, "corrupt payload: {}", reason),
+        }
+    }
+}
+
+impl core::error::Error for StableBinaryError {}
+
+/// 32-bit FNV-1a hash used as the payload checksum
+fn checksum(payload: &[u8]) -> u32 {
+    payload.iter().fold(0x811C_9DC5u32, |hash, &byte| {
+        (hash ^ byte as u32).wrapping_mul(0x0100_0193)
+    })
+}
+
+/// Little-endian payload writer
+#[derive(Debug, Default)]
+pub struct StableWriter {
+    buffer: Vec<u8>,
+}
+
+impl StableWriter {
+    /// Create an empty writer
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Consume the writer, returning the encoded bytes
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.buffer
+    }
+
+    /// Write a single byte
+    pub fn write_u8(&mut self, value: u8) {
+        self.buffer.push(value);
+    }
+
+    /// Write a u16 in little-endian order
+    pub fn write_u16(&mut self, value: u16) {
+        self.buffer.extend_from_slice(&value.to_le_bytes());
+    }
+
+    /// Write a u32 in little-endian order
+    pub fn write_u32(&mut self, value: u32) {
+        self.buffer.extend_from_slice(&value.to_le_bytes());
+    }
+
+    /// Write an f64 as little-endian IEEE-754 bits
+    pub fn write_f64(&mut self, value: f64) {
+        self.buffer.extend_from_slice(&value.to_le_bytes());
+    }
+
+    /// Write a bool as a single 0/1 byte
+    pub fn write_bool(&mut self, value: bool) {
+        self.write_u8(value as u8);
+    }
+
+    /// Write a vector as three f64 components (x, y, z)
+    pub fn write_vector(&mut self, value: Vector) {
+        self.write_f64(value.x);
+        self.write_f64(value.y);
+        self.write_f64(value.z);
+    }
+}
+
+/// Little-endian payload reader
+#[derive(Debug)]
+pub struct StableReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> StableReader<'a> {
+    /// Create a reader over a payload
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    /// Number of bytes not yet consumed
+    pub fn remaining(&self) -> usize {
+        self.data.len()
+    }
+
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], StableBinaryError> {
+        if self.data.len() < N {
+            return Err(StableBinaryError::CorruptPayload("unexpected end of payload"));
+        }
+        let (head, tail) = self.data.split_at(N);
+        self.data = tail;
+        Ok(head.try_into().unwrap())
+    }
+
+    /// Read a single byte
+    pub fn read_u8(&mut self) -> Result<u8, StableBinaryError> {
+        Ok(self.take::<1>()?[0])
+    }
+
+    /// Read a little-endian u16
+    pub fn read_u16(&mut self) -> Result<u16, StableBinaryError> {
+        Ok(u16::from_le_bytes(self.take()?))
+    }
+
+    /// Read a little-endian u32
+    pub fn read_u32(&mut self) -> Result<u32, StableBinaryError> {
+        Ok(u32::from_le_bytes(self.take()?))
+    }
+
+    /// Read a little-endian f64
+    pub fn read_f64(&mut self) -> Result<f64, StableBinaryError> {
+        Ok(f64::from_le_bytes(self.take()?))
+    }
+
+    /// Read a bool, rejecting bytes other than 0 and 1
+    pub fn read_bool(&mut self) -> Result<bool, StableBinaryError> {
+        match self.read_u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(StableBinaryError::CorruptPayload("invalid bool byte")),
+        }
+    }
+
+    /// Read a vector written by `StableWriter::write_vector`
+    pub fn read_vector(&mut self) -> Result<Vector, StableBinaryError> {
+        Ok(Vector::new(self.read_f64()?, self.read_f64()?, self.read_f64()?))
+    }
+}
+
+/// Types with a versioned, endianness-stable binary encoding
+///
+/// Implementors provide a unique `TYPE_TAG` and the payload encoding; the
+/// header, checksum and validation are handled by the provided methods.
+/// Type tags and payload layouts must never change for a given format
+/// version — add a new version instead.
+pub trait StableBinary: Sized {
+    /// Unique tag identifying the type in the header
+    const TYPE_TAG: u16;
+
+    /// Write the payload (without header)
+    fn write_payload(&self, writer: &mut StableWriter);
+
+    /// Read the payload (without header)
+    fn read_payload(reader: &mut StableReader<'_>) -> Result<Self, StableBinaryError>;
+
+    /// Serialize with header to the stable binary format
+    fn to_stable_binary(&self) -> Vec<u8> {
+        let mut writer = StableWriter::new();
+        self.write_payload(&mut writer);
+        let payload = writer.into_bytes();
+
+        let mut out = Vec::with_capacity(STABLE_BINARY_HEADER_SIZE + payload.len());
+        out.extend_from_slice(&STABLE_BINARY_MAGIC);
+        out.extend_from_slice(&STABLE_BINARY_VERSION.to_le_bytes());
+        out.extend_from_slice(&Self::TYPE_TAG.to_le_bytes());
+        out.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+        out.extend_from_slice(&checksum(&payload).to_le_bytes());
+        out.extend_from_slice(&payload);
+        out
+    }
+
+    /// Validate the header and deserialize from the stable binary format
+    fn from_stable_binary(data: &[u8]) -> Result<Self, StableBinaryError> {
+        if data.len() < STABLE_BINARY_HEADER_SIZE {
+            return Err(StableBinaryError::TruncatedHeader(data.len()));
+        }
+        let (header, payload) = data.split_at(STABLE_BINARY_HEADER_SIZE);
+        let mut reader = StableReader::new(header);
+
+        let magic = [reader.read_u8()?, reader.read_u8()?, reader.read_u8()?, reader.read_u8()?];
+        if magic != STABLE_BINARY_MAGIC {
+            return Err(StableBinaryError::BadMagic(magic));
+        }
+        let version = reader.read_u16()?;
+        if version != STABLE_BINARY_VERSION {
+            return Err(StableBinaryError::UnsupportedVersion(version));
+        }
+        let tag = reader.read_u16()?;
+        if tag != Self::TYPE_TAG {
+            return Err(StableBinaryError::TypeMismatch { expected: Self::TYPE_TAG, found: tag });
+        }
+        let length = reader.read_u32()? as usize;
+        if length != payload.len() {
+            return Err(StableBinaryError::LengthMismatch { expected: length, found: payload.len() });
+        }
+        let expected = reader.read_u32()?;
+        let found = checksum(payload);
+        if expected != found {
+            return Err(StableBinaryError::ChecksumMismatch { expected, found });
+        }
+
+        let mut reader = StableReader::new(payload);
+        let value = Self::read_payload(&mut reader)?;
+        if reader.remaining() != 0 {
+            return Err(StableBinaryError::CorruptPayload("trailing bytes after payload"));
+        }
+        Ok(value)
+    }
+}
+
+impl StableBinary for Vector {
+    const TYPE_TAG: u16 = 1;
+
+    fn write_payload(&self, writer: &mut StableWriter) {
+        writer.write_vector(*self);
+    }
+
+    fn read_payload(reader: &mut StableReader<'_>) -> Result<Self, StableBinaryError> {
+        reader.read_vector()
+    }
+}
+
+impl StableBinary for Rotator {
+    const TYPE_TAG: u16 = 2;
+
+    fn write_payload(&self, writer: &mut StableWriter) {
+        writer.write_f64(self.pitch);
+        writer.write_f64(self.yaw);
+        writer.write_f64(self.roll);
+    }
+
+    fn read_payload(reader: &mut StableReader<'_>) -> Result<Self, StableBinaryError> {
+        Ok(Rotator::new(reader.read_f64()?, reader.read_f64()?, reader.read_f64()?))
+    }
+}
+
+impl StableBinary for Transform {
+    const TYPE_TAG: u16 = 3;
+
+    /// Location, rotation quaternion (x, y, z, w), then scale
+    fn write_payload(&self, writer: &mut StableWriter) {
+        writer.write_vector(self.location);
+        writer.write_f64(self.rotation.x);
+        writer.write_f64(self.rotation.y);
+        writer.write_f64(self.rotation.z);
+        writer.write_f64(self.rotation.w);
+        writer.write_vector(self.scale);
+    }
+
+    fn read_payload(reader: &mut StableReader<'_>) -> Result<Self, StableBinaryError> {
+        let location = reader.read_vector()?;
+        let rotation = DQuat::from_xyzw(reader.read_f64()?, reader.read_f64()?, reader.read_f64()?, reader.read_f64()?);
+        let scale = reader.read_vector()?;
+        Ok(Transform::new(location, rotation, scale))
+    }
+}
+
+impl StableBinary for BoundingBox {
+    const TYPE_TAG: u16 = 4;
+
+    fn write_payload(&self, writer: &mut StableWriter) {
+        writer.write_vector(self.min);
+        writer.write_vector(self.max);
+    }
+
+    fn read_payload(reader: &mut StableReader<'_>) -> Result<Self, StableBinaryError> {
+        Ok(BoundingBox::new(reader.read_vector()?, reader.read_vector()?))
+    }
+}
+
+impl StableBinary for BoundingSphere {
+    const TYPE_TAG: u16 = 5;
+
+    fn write_payload(&self, writer: &mut StableWriter) {
+        writer.write_vector(self.center);
+        writer.write_f64(self.radius);
+    }
+
+    fn read_payload(reader: &mut StableReader<'_>) -> Result<Self, StableBinaryError> {
+        Ok(BoundingSphere::new(reader.read_vector()?, reader.read_f64()?))
+    }
+}
+
+impl StableBinary for RepMovement {
+    const TYPE_TAG: u16 = 6;
+
+    fn write_payload(&self, writer: &mut StableWriter) {
+        writer.write_vector(self.location);
+        Rotator::write_payload(&self.rotation, writer);
+        writer.write_vector(self.linear_velocity);
+        writer.write_vector(self.angular_velocity);
+        match self.location_base {
+            Some(base) => {
+                writer.write_u8(1);
+                writer.write_u32(base.value);
+            }
+            None => writer.write_u8(0),
+        }
+        writer.write_vector(self.relative_location);
+        writer.write_u32(self.server_frame);
+        writer.write_bool(self.is_simulated);
+        writer.write_bool(self.has_location_base);
+    }
+
+    fn read_payload(reader: &mut StableReader<'_>) -> Result<Self, StableBinaryError> {
+        let location = reader.read_vector()?;
+        let rotation = Rotator::read_payload(reader)?;
+        let linear_velocity = reader.read_vector()?;
+        let angular_velocity = reader.read_vector()?;
+        let location_base = match reader.read_u8()? {
+            0 => None,
+            1 => Some(NetworkGUID::new(reader.read_u32()?)),
+            _ => return Err(StableBinaryError::CorruptPayload("invalid option tag")),
+        };
+
+        Ok(RepMovement {
+            location,
+            rotation,
+            linear_velocity,
+            angular_velocity,
+            location_base,
+            relative_location: reader.read_vector()?,
+            server_frame: reader.read_u32()?,
+            is_simulated: reader.read_bool()?,
+            has_location_base: reader.read_bool()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn header(tag: u16, payload_len: u32, checksum: u32) -> Vec<u8> {
+        let mut out = STABLE_BINARY_MAGIC.to_vec();
+        out.extend_from_slice(&STABLE_BINARY_VERSION.to_le_bytes());
+        out.extend_from_slice(&tag.to_le_bytes());
+        out.extend_from_slice(&payload_len.to_le_bytes());
+        out.extend_from_slice(&checksum.to_le_bytes());
+        out
+    }
+
+    /// Version 1 encoding of `Vector::new(1.0, -2.0, 0.5)`
+    const VECTOR_V1_FIXTURE: [u8; 40] = [
+        0x55, 0x45, 0x53, 0x42, // "UESB"
+        0x01, 0x00, // version 1
+        0x01, 0x00, // tag 1 (Vector)
+        0x18, 0x00, 0x00, 0x00, // 24 payload bytes
+        0xD5, 0x97, 0xCA, 0xF5, // checksum
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0x3F, // 1.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, // -2.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xE0, 0x3F, // 0.5
+    ];
+
+    /// Version 1 encoding of `Rotator::new(90.0, -45.0, 0.0)`
+    const ROTATOR_V1_FIXTURE: [u8; 40] = [
+        0x55, 0x45, 0x53, 0x42, 0x01, 0x00, 0x02, 0x00, 0x18, 0x00, 0x00, 0x00, 0x55, 0xE3, 0xAA, 0xD8,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x56, 0x40, // 90.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x46, 0xC0, // -45.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 0.0
+    ];
+
+    /// Version 1 encoding of a transform with location (100, -50, 25), rotation
+    /// `DQuat::from_xyzw(0.0, 0.0, 0.6, 0.8)` and scale (1, 2, 0.5)
+    const TRANSFORM_V1_FIXTURE: [u8; 96] = [
+        0x55, 0x45, 0x53, 0x42, // "UESB"
+        0x01, 0x00, // version 1
+        0x03, 0x00, // tag 3 (Transform)
+        0x50, 0x00, 0x00, 0x00, // 80 payload bytes
+        0xB5, 0x40, 0x8B, 0xB5, // checksum
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x59, 0x40, // location.x = 100.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x49, 0xC0, // location.y = -50.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x39, 0x40, // location.z = 25.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rotation.x = 0.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rotation.y = 0.0
+        0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0xE3, 0x3F, // rotation.z = 0.6
+        0x9A, 0x99, 0x99, 0x99, 0x99, 0x99, 0xE9, 0x3F, // rotation.w = 0.8
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0x3F, // scale.x = 1.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, // scale.y = 2.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xE0, 0x3F, // scale.z = 0.5
+    ];
+
+    /// Version 1 encoding of `BoundingBox::new(Vector::splat(-1.0), Vector::new(2.0, 3.0, 4.0))`
+    const BOUNDING_BOX_V1_FIXTURE: [u8; 64] = [
+        0x55, 0x45, 0x53, 0x42, // "UESB"
+        0x01, 0x00, // version 1
+        0x04, 0x00, // tag 4 (BoundingBox)
+        0x30, 0x00, 0x00, 0x00, // 48 payload bytes
+        0x40, 0xBD, 0x50, 0x1B, // checksum
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xBF, // min.x = -1.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xBF, // min.y = -1.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xBF, // min.z = -1.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, // max.x = 2.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x40, // max.y = 3.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x40, // max.z = 4.0
+    ];
+
+    /// Version 1 encoding of `BoundingSphere::new(Vector::new(1.0, 2.0, 3.0), 7.5)`
+    const BOUNDING_SPHERE_V1_FIXTURE: [u8; 48] = [
+        0x55, 0x45, 0x53, 0x42, // "UESB"
+        0x01, 0x00, // version 1
+        0x05, 0x00, // tag 5 (BoundingSphere)
+        0x20, 0x00, 0x00, 0x00, // 32 payload bytes
+        0x9E, 0x87, 0x28, 0x05, // checksum
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0x3F, // center.x = 1.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, // center.y = 2.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x40, // center.z = 3.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1E, 0x40, // radius = 7.5
+    ];
+
+    /// Version 1 encoding of the simulated, based movement in `test_rep_movement_fixture`
+    const REP_MOVEMENT_V1_FIXTURE: [u8; 147] = [
+        0x55, 0x45, 0x53, 0x42, // "UESB"
+        0x01, 0x00, // version 1
+        0x06, 0x00, // tag 6 (RepMovement)
+        0x83, 0x00, 0x00, 0x00, // 131 payload bytes
+        0x4E, 0x19, 0xB2, 0x13, // checksum
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0x3F, // location.x = 1.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, // location.y = 2.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x40, // location.z = 3.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rotation.pitch = 0.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x56, 0x40, // rotation.yaw = 90.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rotation.roll = 0.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0x82, 0x40, // linear_velocity.x = 600.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // linear_velocity.y = 0.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // linear_velocity.z = 0.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // angular_velocity.x = 0.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // angular_velocity.y = 0.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // angular_velocity.z = 0.0
+        0x01, // location_base: Some
+        0x4D, 0x00, 0x00, 0x00, // NetworkGUID 77
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // relative_location.x = 0.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // relative_location.y = 0.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x24, 0x40, // relative_location.z = 10.0
+        0xD2, 0x04, 0x00, 0x00, // server_frame = 1234
+        0x01, // is_simulated = true
+        0x01, // has_location_base = true
+    ];
+
+
+    #[test]
+    fn test_vector_fixture() {
+        let vector = Vector::new(1.0, -2.0, 0.5);
+        assert_eq!(vector.to_stable_binary(), VECTOR_V1_FIXTURE);
+        assert_eq!(Vector::from_stable_binary(&VECTOR_V1_FIXTURE), Ok(vector));
+    }
+
+    #[test]
+    fn test_rotator_fixture() {
+        let rotator = Rotator::new(90.0, -45.0, 0.0);
+        assert_eq!(rotator.to_stable_binary(), ROTATOR_V1_FIXTURE);
+        assert_eq!(Rotator::from_stable_binary(&ROTATOR_V1_FIXTURE), Ok(rotator));
+    }
+
+    #[test]
+    fn test_transform_fixture() {
+        let transform = Transform::new(
+            Vector::new(100.0, -50.0, 25.0),
+            DQuat::from_xyzw(0.0, 0.0, 0.6, 0.8),
+            Vector::new(1.0, 2.0, 0.5),
+        );
+        assert_eq!(transform.to_stable_binary(), TRANSFORM_V1_FIXTURE);
+        assert_eq!(Transform::from_stable_binary(&TRANSFORM_V1_FIXTURE), Ok(transform));
+    }
+
+    #[test]
+    fn test_bounds_fixtures() {
+        let bbox = BoundingBox::new(Vector::splat(-1.0), Vector::new(2.0, 3.0, 4.0));
+        assert_eq!(bbox.to_stable_binary(), BOUNDING_BOX_V1_FIXTURE);
+        assert_eq!(BoundingBox::from_stable_binary(&BOUNDING_BOX_V1_FIXTURE), Ok(bbox));
+
+        let sphere = BoundingSphere::new(Vector::new(1.0, 2.0, 3.0), 7.5);
+        assert_eq!(sphere.to_stable_binary(), BOUNDING_SPHERE_V1_FIXTURE);
+        assert_eq!(BoundingSphere::from_stable_binary(&BOUNDING_SPHERE_V1_FIXTURE), Ok(sphere));
+    }
+
+    #[test]
+    fn test_rep_movement_fixture() {
+        let movement = RepMovement {
+            location: Vector::new(1.0, 2.0, 3.0),
+            rotation: Rotator::new(0.0, 90.0, 0.0),
+            linear_velocity: Vector::new(600.0, 0.0, 0.0),
+            angular_velocity: Vector::ZERO,
+            location_base: Some(NetworkGUID::new(77)),
+            relative_location: Vector::new(0.0, 0.0, 10.0),
+            server_frame: 1234,
+            is_simulated: true,
+            has_location_base: true,
+        };
+        assert_eq!(movement.to_stable_binary(), REP_MOVEMENT_V1_FIXTURE);
+        assert_eq!(RepMovement::from_stable_binary(&REP_MOVEMENT_V1_FIXTURE), Ok(movement));
+    }
+
+    #[test]
+    fn test_round_trips() {
+        let transform = Transform::new(
+            Vector::new(100.0, -50.0, 25.0),
+            Rotator::new(10.0, 20.0, 30.0).to_quaternion(),
+            Vector::new(1.0, 2.0, 0.5),
+        );
+        let bytes = transform.to_stable_binary();
+        assert_eq!(bytes.len(), STABLE_BINARY_HEADER_SIZE + 10 * 8);
+        assert_eq!(Transform::from_stable_binary(&bytes), Ok(transform));
+
+        let bbox = BoundingBox::new(Vector::splat(-1.0), Vector::new(2.0, 3.0, 4.0));
+        assert_eq!(BoundingBox::from_stable_binary(&bbox.to_stable_binary()), Ok(bbox));
+
+        let sphere = BoundingSphere::new(Vector::new(1.0, 2.0, 3.0), 7.5);
+        assert_eq!(BoundingSphere::from_stable_binary(&sphere.to_stable_binary()), Ok(sphere));
+
+        let mut movement = RepMovement::from_transform(
+            Vector::new(1.0, 2.0, 3.0),
+            Rotator::new(0.0, 90.0, 0.0),
+            Vector::new(600.0, 0.0, 0.0),
+        );
+        movement.server_frame = 1234;
+        movement.is_simulated = true;
+        assert_eq!(RepMovement::from_stable_binary(&movement.to_stable_binary()), Ok(movement));
+
+        movement.set_location_base(NetworkGUID::new(77), Vector::new(0.0, 0.0, 10.0));
+        assert_eq!(RepMovement::from_stable_binary(&movement.to_stable_binary()), Ok(movement));
+    }
+
+    #[test]
+    fn test_bad_magic() {
+        let mut bytes = VECTOR_V1_FIXTURE;
+        bytes[0] = b'X';
+        assert_eq!(Vector::from_stable_binary(&bytes), Err(StableBinaryError::BadMagic(*b"XESB")));
+        assert_eq!(Vector::from_stable_binary(&bytes[..4]), Err(StableBinaryError::TruncatedHeader(4)));
+    }
+
+    #[test]
+    fn test_unsupported_version() {
+        let mut bytes = VECTOR_V1_FIXTURE;
+        bytes[4] = 2;
+        assert_eq!(Vector::from_stable_binary(&bytes), Err(StableBinaryError::UnsupportedVersion(2)));
+    }
+
+    #[test]
+    fn test_type_mismatch() {
+        assert_eq!(
+            Rotator::from_stable_binary(&VECTOR_V1_FIXTURE),
+            Err(StableBinaryError::TypeMismatch { expected: 2, found: 1 })
+        );
+    }
+
+    #[test]
+    fn test_payload_corruption() {
+        // Flipped payload bit
+        let mut bytes = VECTOR_V1_FIXTURE;
+        bytes[20] ^= 0x01;
+        assert!(matches!(
+            Vector::from_stable_binary(&bytes),
+            Err(StableBinaryError::ChecksumMismatch { .. })
+        ));
+
+        // Truncated payload
+        assert_eq!(
+            Vector::from_stable_binary(&VECTOR_V1_FIXTURE[..39]),
+            Err(StableBinaryError::LengthMismatch { expected: 24, found: 23 })
+        );
+
+        // Consistent header but payload too short for the type
+        let payload = [0u8; 16];
+        let mut bytes = header(Vector::TYPE_TAG, 16, checksum(&payload));
+        bytes.extend_from_slice(&payload);
+        assert_eq!(
+            Vector::from_stable_binary(&bytes),
+            Err(StableBinaryError::CorruptPayload("unexpected end of payload"))
+        );
+
+        // Consistent header but invalid option tag
+        let mut movement = RepMovement::new().to_stable_binary();
+        let tag_offset = STABLE_BINARY_HEADER_SIZE + 12 * 8;
+        movement[tag_offset] = 7;
+        let fixed = checksum(&movement[STABLE_BINARY_HEADER_SIZE..]);
+        movement[12..16].copy_from_slice(&fixed.to_le_bytes());
+        assert_eq!(
+            RepMovement::from_stable_binary(&movement),
+            Err(StableBinaryError::CorruptPayload("invalid option tag"))
+        );
+    }
+}
@@ -0,0 +1,381 @@
+//! Byte layouts compatible with Unreal Engine's `FArchive` serialization
+//!
+//! Buffers written here can be read on the UE side with an `FMemoryReader`
+//! (and vice versa). All values are little-endian, which is what UE uses on
+//! every shipping platform. Layouts follow the UE 5.x `operator<<`
+//! implementations:
+//!
+//! | Type          | UE type        | Layout                                           |
+//! |---------------|----------------|--------------------------------------------------|
+//! | `Vector`      | `FVector`      | X, Y, Z                                          |
+//! | `Vector2D`    | `FVector2D`    | X, Y                                             |
+//! | `Vector4`     | `FVector4`     | X, Y, Z, W                                       |
+//! | `Rotator`     | `FRotator`     | Pitch, Yaw, Roll                                 |
+//! | `DQuat`/`Quat`| `FQuat`        | X, Y, Z, W                                       |
+//! | `Transform`   | `FTransform`   | Rotation (FQuat), Translation, Scale3D           |
+//! | `Color`       | `FColor`       | B, G, R, A bytes (one little-endian `uint32`)    |
+//! | `LinearColor` | `FLinearColor` | R, G, B, A as `f32`                              |
+//! | `BoundingBox` | `FBox`         | Min, Max, IsValid (`uint8`)                      |
+//!
+//! Floating point components are `f64` with UE5 large world coordinates
+//! (`UePrecision::Double`) or `f32` for UE4-era archives
+//! (`UePrecision::Single`). `FLinearColor` is always `f32`.
+
+use crate::types::{BoundingBox, Color, LinearColor, Rotator, Transform, Vector, Vector2D, Vector4};
+use glam::{DQuat, Quat};
+use core::fmt;
+use alloc::vec::Vec;
+
+/// Floating point width used for math types in an archive
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UePrecision {
+    /// UE5 large world coordinates (8-byte doubles)
+    #[default]
+    Double,
+    /// UE4 / non-LWC archives (4-byte floats)
+    Single,
+}
+
+impl UePrecision {
+    /// Size in bytes of one floating point component
+    pub fn component_size(self) -> usize {
+        match self {
+            UePrecision::Double => 8,
+            UePrecision::Single => 4,
+        }
+    }
+}
+
+/// Error produced while reading an archive buffer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArchiveError {
+    /// The buffer ended before the value was complete
+    UnexpectedEof { needed: usize, available: usize },
+    /// A field held a value UE would never write
+    InvalidValue(&'static str),
+}
+
+impl fmt::Display for ArchiveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArchiveError::UnexpectedEof { needed, available } => {
+                write!(f, "archive needs {} bytes but only {} are available", needed, available)
+            }
+            ArchiveError::InvalidValue(field) => write!(f, "invalid value for {}", field),
+        }
+    }
+}
+
+impl core::error::Error for ArchiveError {}
+
+/// Cursor over an archive buffer
+struct ArchiveReader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> ArchiveReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, offset: 0 }
+    }
+
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], ArchiveError> {
+        let available = self.data.len() - self.offset;
+        if available < N {
+            return Err(ArchiveError::UnexpectedEof { needed: N, available });
+        }
+        let bytes = self.data[self.offset..self.offset + N].try_into().unwrap();
+        self.offset += N;
+        Ok(bytes)
+    }
+
+    fn read_u8(&mut self) -> Result<u8, ArchiveError> {
+        Ok(self.take::<1>()?[0])
+    }
+
+    fn read_f32(&mut self) -> Result<f32, ArchiveError> {
+        Ok(f32::from_le_bytes(self.take()?))
+    }
+
+    fn read_real(&mut self, precision: UePrecision) -> Result<f64, ArchiveError> {
+        match precision {
+            UePrecision::Double => Ok(f64::from_le_bytes(self.take()?)),
+            UePrecision::Single => Ok(self.read_f32()? as f64),
+        }
+    }
+
+    fn read_value<T: UeArchive>(&mut self, precision: UePrecision) -> Result<T, ArchiveError> {
+        let (value, used) = T::read_ue_with(&self.data[self.offset..], precision)?;
+        self.offset += used;
+        Ok(value)
+    }
+}
+
+fn write_real(out: &mut Vec<u8>, value: f64, precision: UePrecision) {
+    match precision {
+        UePrecision::Double => out.extend_from_slice(&value.to_le_bytes()),
+        UePrecision::Single => out.extend_from_slice(&(value as f32).to_le_bytes()),
+    }
+}
+
+/// Types that can be written and read in UE's `FArchive` byte layout
+pub trait UeArchive: Sized {
+    /// Append this value to `out` using the given float precision
+    fn write_ue_with(&self, out: &mut Vec<u8>, precision: UePrecision);
+
+    /// Read a value from the start of `data`, returning it and the bytes consumed
+    fn read_ue_with(data: &[u8], precision: UePrecision) -> Result<(Self, usize), ArchiveError>;
+
+    /// Append this value to `out` using UE5 double precision
+    fn write_ue(&self, out: &mut Vec<u8>) {
+        self.write_ue_with(out, UePrecision::Double);
+    }
+
+    /// Read a UE5 double precision value from the start of `data`
+    fn read_ue(data: &[u8]) -> Result<(Self, usize), ArchiveError> {
+        Self::read_ue_with(data, UePrecision::Double)
+    }
+}
+
+/// Implement `UeArchive` for a type serialized as a fixed list of real components
+macro_rules! impl_real_components {
+    ($ty:ty, [$($field:ident),+], $build:expr) => {
+        impl UeArchive for $ty {
+            fn write_ue_with(&self, out: &mut Vec<u8>, precision: UePrecision) {
+                $(write_real(out, self.$field as f64, precision);)+
+            }
+
+            fn read_ue_with(data: &[u8], precision: UePrecision) -> Result<(Self, usize), ArchiveError> {
+                let mut reader = ArchiveReader::new(data);
+                $(let $field = reader.read_real(precision)?;)+
+                Ok(($build($($field),+), reader.offset))
+            }
+        }
+    };
+}
+
+impl_real_components!(Vector, [x, y, z], Vector::new);
+impl_real_components!(Vector2D, [x, y], Vector2D::new);
+impl_real_components!(Vector4, [x, y, z, w], Vector4::new);
+impl_real_components!(Rotator, [pitch, yaw, roll], Rotator::new);
+impl_real_components!(DQuat, [x, y, z, w], DQuat::from_xyzw);
+impl_real_components!(Quat, [x, y, z, w], |x: f64, y: f64, z: f64, w: f64| {
+    Quat::from_xyzw(x as f32, y as f32, z as f32, w as f32)
+});
+
+impl UeArchive for Transform {
+    fn write_ue_with(&self, out: &mut Vec<u8>, precision: UePrecision) {
+        self.rotation.write_ue_with(out, precision);
+        self.location.write_ue_with(out, precision);
+        self.scale.write_ue_with(out, precision);
+    }
+
+    fn read_ue_with(data: &[u8], precision: UePrecision) -> Result<(Self, usize), ArchiveError> {
+        let mut reader = ArchiveReader::new(data);
+        let rotation: DQuat = reader.read_value(precision)?;
+        let location: Vector = reader.read_value(precision)?;
+        let scale: Vector = reader.read_value(precision)?;
+        Ok((Transform::new(location, rotation, scale), reader.offset))
+    }
+}
+
+impl UeArchive for Color {
+    fn write_ue_with(&self, out: &mut Vec<u8>, _precision: UePrecision) {
+        out.extend_from_slice(&[self.b, self.g, self.r, self.a]);
+    }
+
+    fn read_ue_with(data: &[u8], _precision: UePrecision) -> Result<(Self, usize), ArchiveError> {
+        let [b, g, r, a] = ArchiveReader::new(data).take::<4>()?;
+        Ok((Color::new(r, g, b, a), 4))
+    }
+}
+
+impl UeArchive for LinearColor {
+    fn write_ue_with(&self, out: &mut Vec<u8>, _precision: UePrecision) {
+        for component in [self.r, self.g, self.b, self.a] {
+            out.extend_from_slice(&component.to_le_bytes());
+        }
+    }
+
+    fn read_ue_with(data: &[u8], _precision: UePrecision) -> Result<(Self, usize), ArchiveError> {
+        let mut reader = ArchiveReader::new(data);
+        let color = LinearColor::new(reader.read_f32()?, reader.read_f32()?, reader.read_f32()?, reader.read_f32()?);
+        Ok((color, reader.offset))
+    }
+}
+
+impl UeArchive for BoundingBox {
+    /// Invalid boxes are written like UE's default `FBox`: zero corners with IsValid = 0
+    fn write_ue_with(&self, out: &mut Vec<u8>, precision: UePrecision) {
+        let valid = self.is_valid();
+        let (min, max) = if valid { (self.min, self.max) } else { (Vector::ZERO, Vector::ZERO) };
+        min.write_ue_with(out, precision);
+        max.write_ue_with(out, precision);
+        out.push(valid as u8);
+    }
+
+    fn read_ue_with(data: &[u8], precision: UePrecision) -> Result<(Self, usize), ArchiveError> {
+        let mut reader = ArchiveReader::new(data);
+        let min: Vector = reader.read_value(precision)?;
+        let max: Vector = reader.read_value(precision)?;
+        let bbox = match reader.read_u8()? {
+            0 => BoundingBox::EMPTY,
+            1 => BoundingBox::new(min, max),
+            _ => return Err(ArchiveError::InvalidValue("FBox::IsValid")),
+        };
+        Ok((bbox, reader.offset))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Regression pins written by hand from the UE 5.x FArchive operator<<
+    // layouts documented above (little-endian, LWC doubles unless noted).
+    // They are not captured from an engine dump: they catch changes to this
+    // crate's output, not a misreading of the engine's layout.
+
+    /// FVector(1, -2, 0.5)
+    const FVECTOR_PIN: [u8; 24] = [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0x3F, // X = 1.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, // Y = -2.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xE0, 0x3F, // Z = 0.5
+    ];
+
+    /// FVector(1, -2, 0.5) without large world coordinates
+    const FVECTOR_FLOAT_PIN: [u8; 12] = [
+        0x00, 0x00, 0x80, 0x3F, // X = 1.0f
+        0x00, 0x00, 0x00, 0xC0, // Y = -2.0f
+        0x00, 0x00, 0x00, 0x3F, // Z = 0.5f
+    ];
+
+    /// FRotator(Pitch=90, Yaw=-45, Roll=0)
+    const FROTATOR_PIN: [u8; 24] = [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x56, 0x40, // Pitch = 90.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x46, 0xC0, // Yaw = -45.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Roll = 0.0
+    ];
+
+    /// FTransform(Identity rotation, Translation=(1, 0, 0), Scale3D=(1, 1, 1))
+    const FTRANSFORM_PIN: [u8; 80] = [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Rotation.X
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Rotation.Y
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Rotation.Z
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0x3F, // Rotation.W = 1.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0x3F, // Translation.X = 1.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Translation.Y
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Translation.Z
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0x3F, // Scale3D.X = 1.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0x3F, // Scale3D.Y = 1.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0x3F, // Scale3D.Z = 1.0
+    ];
+
+    /// FColor(R=0x11, G=0x22, B=0x33, A=0xFF) as its BGRA DWORD
+    const FCOLOR_PIN: [u8; 4] = [0x33, 0x22, 0x11, 0xFF];
+
+    /// FLinearColor(1, 0.5, 0, 1)
+    const FLINEARCOLOR_PIN: [u8; 16] = [
+        0x00, 0x00, 0x80, 0x3F, 0x00, 0x00, 0x00, 0x3F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x3F,
+    ];
+
+    #[test]
+    fn test_vector_pinned_bytes() {
+        let vector = Vector::new(1.0, -2.0, 0.5);
+
+        let mut out = Vec::new();
+        vector.write_ue(&mut out);
+        assert_eq!(out, FVECTOR_PIN);
+        assert_eq!(Vector::read_ue(&FVECTOR_PIN), Ok((vector, 24)));
+
+        let mut out = Vec::new();
+        vector.write_ue_with(&mut out, UePrecision::Single);
+        assert_eq!(out, FVECTOR_FLOAT_PIN);
+        assert_eq!(Vector::read_ue_with(&FVECTOR_FLOAT_PIN, UePrecision::Single), Ok((vector, 12)));
+    }
+
+    #[test]
+    fn test_rotator_pinned_bytes() {
+        let rotator = Rotator::new(90.0, -45.0, 0.0);
+        let mut out = Vec::new();
+        rotator.write_ue(&mut out);
+        assert_eq!(out, FROTATOR_PIN);
+        assert_eq!(Rotator::read_ue(&FROTATOR_PIN), Ok((rotator, 24)));
+    }
+
+    #[test]
+    fn test_transform_pinned_bytes() {
+        let transform = Transform::from_location(Vector::new(1.0, 0.0, 0.0));
+        let mut out = Vec::new();
+        transform.write_ue(&mut out);
+        assert_eq!(out, FTRANSFORM_PIN);
+        assert_eq!(Transform::read_ue(&FTRANSFORM_PIN), Ok((transform, 80)));
+    }
+
+    #[test]
+    fn test_color_pinned_bytes() {
+        let color = Color::new(0x11, 0x22, 0x33, 0xFF);
+        let mut out = Vec::new();
+        color.write_ue(&mut out);
+        assert_eq!(out, FCOLOR_PIN);
+        assert_eq!(Color::read_ue(&FCOLOR_PIN), Ok((color, 4)));
+
+        let linear = LinearColor::new(1.0, 0.5, 0.0, 1.0);
+        let mut out = Vec::new();
+        // Precision does not affect FLinearColor
+        linear.write_ue_with(&mut out, UePrecision::Double);
+        assert_eq!(out, FLINEARCOLOR_PIN);
+        assert_eq!(LinearColor::read_ue(&FLINEARCOLOR_PIN), Ok((linear, 16)));
+    }
+
+    #[test]
+    fn test_bounding_box_layout() {
+        let bbox = BoundingBox::new(Vector::new(-1.0, -2.0, -3.0), Vector::new(1.0, 2.0, 3.0));
+        let mut out = Vec::new();
+        bbox.write_ue(&mut out);
+        assert_eq!(out.len(), 49);
+        assert_eq!(out[48], 1);
+        assert_eq!(BoundingBox::read_ue(&out), Ok((bbox, 49)));
+
+        // Invalid boxes match UE's default FBox
+        let mut out = Vec::new();
+        BoundingBox::EMPTY.write_ue_with(&mut out, UePrecision::Single);
+        assert_eq!(out, [0u8; 25]);
+        assert_eq!(BoundingBox::read_ue_with(&out, UePrecision::Single), Ok((BoundingBox::EMPTY, 25)));
+
+        out[24] = 2;
+        assert_eq!(
+            BoundingBox::read_ue_with(&out, UePrecision::Single),
+            Err(ArchiveError::InvalidValue("FBox::IsValid"))
+        );
+    }
+
+    #[test]
+    fn test_sequential_reads() {
+        let mut out = Vec::new();
+        Vector2D::new(3.0, 4.0).write_ue(&mut out);
+        Vector4::new(1.0, 2.0, 3.0, 4.0).write_ue(&mut out);
+        Quat::from_rotation_z(1.0).write_ue_with(&mut out, UePrecision::Single);
+
+        let (v2, used) = Vector2D::read_ue(&out).unwrap();
+        assert_eq!(v2, Vector2D::new(3.0, 4.0));
+        let (v4, used4) = Vector4::read_ue(&out[used..]).unwrap();
+        assert_eq!(v4, Vector4::new(1.0, 2.0, 3.0, 4.0));
+        let (quat, used_q) = Quat::read_ue_with(&out[used + used4..], UePrecision::Single).unwrap();
+        assert_eq!(quat, Quat::from_rotation_z(1.0));
+        assert_eq!(used + used4 + used_q, out.len());
+    }
+
+    #[test]
+    fn test_truncated_input() {
+        assert_eq!(
+            Vector::read_ue(&FVECTOR_PIN[..20]),
+            Err(ArchiveError::UnexpectedEof { needed: 8, available: 4 })
+        );
+        assert_eq!(
+            Transform::read_ue(&FTRANSFORM_PIN[..40]),
+            Err(ArchiveError::UnexpectedEof { needed: 8, available: 0 })
+        );
+        assert_eq!(Color::read_ue(&[1, 2]), Err(ArchiveError::UnexpectedEof { needed: 4, available: 2 }));
+    }
+}
@@ -0,0 +1,187 @@
+//! Zero-copy archives through rkyv (`rkyv` feature)
+//!
+//! The plain-data types (`Rotator`, `Transform`, `RepMovement`, ...) derive
+//! rkyv's `Archive`, which generates an `Archived*` twin (`ArchivedRepMovement`)
+//! with the same fields laid out so it can be read straight from the bytes.
+//! glam's vectors and quaternions archive as themselves, so an archived
+//! `location` is still a `Vector`.
+//!
+//! Reading an archive validates it with bytecheck and then borrows from the
+//! buffer: no allocation and no copying, which suits snapshot reads on the
+//! hot path. Archives use native endianness and 32-bit relative pointers,
+//! so they are meant for caches and same-platform IPC. Use
+//! [`StableBinary`](super::StableBinary) for anything that crosses machines
+//! or is stored long term.
+
+use rkyv::ser::serializers::AllocSerializer;
+use rkyv::validation::validators::DefaultValidator;
+use rkyv::{AlignedVec, Archive, CheckBytes, Deserialize, Infallible, Serialize};
+use alloc::string::{String, ToString};
+use core::fmt;
+
+/// Scratch space the serializer reserves on the stack before spilling to the heap
+pub const ZERO_COPY_SCRATCH_SIZE: usize = 256;
+
+/// Serializer used by [`ZeroCopy::to_archive_bytes`]
+pub type ZeroCopySerializer = AllocSerializer<ZERO_COPY_SCRATCH_SIZE>;
+
+/// Error produced while writing or reading an rkyv archive
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ZeroCopyError {
+    /// The serializer failed (only possible if scratch space can't be allocated)
+    Serialize(String),
+    /// The bytes are not a valid archive of the requested type: truncated,
+    /// misaligned, corrupt or holding a different type
+    Invalid(String),
+}
+
+impl fmt::Display for ZeroCopyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ZeroCopyError::Serialize(message) => write!(f, "failed to archive value: {}", message),
+            ZeroCopyError::Invalid(message) => write!(f, "invalid archive: {}", message),
+        }
+    }
+}
+
+impl core::error::Error for ZeroCopyError {}
+
+/// Types that can be archived with rkyv and read back without copying
+///
+/// Like `BinarySerializable`, the methods are provided: implement it with
+/// `impl ZeroCopy for MyType {}` on a type deriving rkyv's traits with
+/// `#[archive(check_bytes)]`.
+///
+/// Archives must be read from a buffer aligned to 16 bytes, which
+/// `AlignedVec` guarantees. A misaligned slice fails validation.
+pub trait ZeroCopy: Archive + Sized {
+    /// Archive the value into an aligned buffer
+    fn to_archive_bytes(&self) -> Result<AlignedVec, ZeroCopyError>
+    where
+        Self: Serialize<ZeroCopySerializer>,
+    {
+        rkyv::to_bytes::<_, ZERO_COPY_SCRATCH_SIZE>(self).map_err(|error| ZeroCopyError::Serialize(error.to_string()))
+    }
+
+    /// Validate an archive and borrow the archived value from it, without allocating
+    fn as_archived(bytes: &[u8]) -> Result<&Self::Archived, ZeroCopyError>
+    where
+        Self::Archived: for<'a> CheckBytes<DefaultValidator<'a>>,
+    {
+        rkyv::check_archived_root::<Self>(bytes).map_err(|error| ZeroCopyError::Invalid(error.to_string()))
+    }
+
+    /// Borrow the archived value without validating it
+    ///
+    /// # Safety
+    ///
+    /// `bytes` must be an archive of `Self` produced by `to_archive_bytes`
+    /// (or already checked with `as_archived`) and suitably aligned.
+    unsafe fn as_archived_unchecked(bytes: &[u8]) -> &Self::Archived {
+        rkyv::archived_root::<Self>(bytes)
+    }
+
+    /// Validate an archive and deserialize an owned value from it
+    fn from_archive_bytes(bytes: &[u8]) -> Result<Self, ZeroCopyError>
+    where
+        Self::Archived: for<'a> CheckBytes<DefaultValidator<'a>> + Deserialize<Self, Infallible>,
+    {
+        match Self::as_archived(bytes)?.deserialize(&mut Infallible) {
+            Ok(value) => Ok(value),
+            Err(never) => match never {},
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{
+        BoundingBox, BoundingSphere, Color, LinearColor, NetworkGUID, NetworkStats, RepMovement, Rotator, Transform,
+        Vector,
+    };
+    use glam::DQuat;
+
+    fn movement() -> RepMovement {
+        let mut movement = RepMovement::from_transform(
+            Vector::new(1200.5, -340.25, 88.0),
+            Rotator::new(-5.0, 90.0, 2.5),
+            Vector::new(600.0, 0.0, -12.0),
+        );
+        movement.angular_velocity = Vector::new(0.0, 45.0, 0.0);
+        movement.set_location_base(NetworkGUID::new(42), Vector::new(0.0, 0.0, 50.0));
+        movement.server_frame = 9_001;
+        movement.is_simulated = true;
+        movement
+    }
+
+    fn round_trip<T>(value: T)
+    where
+        T: ZeroCopy + Serialize<ZeroCopySerializer> + PartialEq + fmt::Debug,
+        T::Archived: for<'a> CheckBytes<DefaultValidator<'a>> + Deserialize<T, Infallible>,
+    {
+        let bytes = value.to_archive_bytes().unwrap();
+        assert_eq!(T::from_archive_bytes(&bytes).unwrap(), value);
+    }
+
+    #[test]
+    fn test_round_trips() {
+        round_trip(movement());
+        round_trip(RepMovement::new());
+        round_trip(Rotator::new(10.0, -170.5, 359.0));
+        round_trip(Transform::new(
+            Vector::new(1.0, 2.0, 3.0),
+            DQuat::from_rotation_z(1.25),
+            Vector::new(1.0, 2.0, 0.5),
+        ));
+        round_trip(BoundingBox::new(Vector::splat(-4.0), Vector::new(1.0, 2.0, 3.0)));
+        round_trip(BoundingSphere::new(Vector::X, 12.5));
+        round_trip(NetworkGUID::new(7));
+        round_trip(LinearColor::new(0.25, 0.5, 1.0, 0.75));
+        round_trip(Color::new(12, 34, 56, 78));
+
+        let mut stats = NetworkStats::new();
+        stats.set_ping(42.3);
+        stats.set_packet_loss(0.015);
+        stats.set_jitter(3.1);
+        stats.bytes_in_per_second = 12_000.0;
+        round_trip(stats);
+    }
+
+    #[test]
+    fn test_archived_fields_match() {
+        let movement = movement();
+        let bytes = movement.to_archive_bytes().unwrap();
+        let archived = RepMovement::as_archived(&bytes).unwrap();
+        assert_eq!(*archived, movement);
+        assert_eq!(archived.location, movement.location);
+        assert_eq!(archived.rotation.yaw, 90.0);
+        assert_eq!(archived.location_base.as_ref().map(|guid| guid.value), Some(42));
+        assert_eq!(unsafe { RepMovement::as_archived_unchecked(&bytes) }.server_frame, 9_001);
+
+        let mut stats = NetworkStats::new();
+        stats.set_ping(80.5);
+        stats.set_packet_loss(0.02);
+        stats.set_jitter(12.0);
+        let bytes = stats.to_archive_bytes().unwrap();
+        let archived = NetworkStats::as_archived(&bytes).unwrap();
+        assert_eq!((archived.ping(), archived.packet_loss(), archived.jitter()), (80.5, 0.02, 12.0));
+        assert_eq!(*archived, stats);
+    }
+
+    #[test]
+    fn test_invalid_archives_are_rejected() {
+        let bytes = movement().to_archive_bytes().unwrap();
+
+        // Too short for the root
+        assert!(matches!(RepMovement::as_archived(&bytes[..8]), Err(ZeroCopyError::Invalid(_))));
+
+        // A bool other than 0 or 1 fails bytecheck
+        let archived = RepMovement::as_archived(&bytes).unwrap();
+        let offset = &archived.is_simulated as *const bool as usize - bytes.as_ptr() as usize;
+        let mut corrupt = bytes.clone();
+        corrupt[offset] = 7;
+        let error = RepMovement::as_archived(&corrupt).unwrap_err();
+        assert!(error.to_string().starts_with("invalid archive: "), "{}", error);
+    }
+}
@@ -1,11 +1,18 @@
 //! UE5-style Text for localized strings
 
 use crate::BinarySerializable;
+use crate::types::TMap;
 use serde::{Deserialize, Serialize};
-use std::fmt;
+use core::fmt;
+use alloc::string::{String, ToString};
 
 /// UE5-style Text for localized strings
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// Localizable texts (namespace and key) are equal when they share that
+/// identity; other texts are equal when their source strings match. A
+/// localizable text never equals one that is not, which keeps equality
+/// transitive.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Text {
     /// The display string
     pub display_string: String,
@@ -36,6 +43,16 @@ impl Text {
         }
     }
 
+    /// Create a culture-invariant Text from a plain string (UE5: FText::FromString())
+    pub fn from_string(value: impl Into<String>) -> Self {
+        Self::new(value)
+    }
+
+    /// Create a localized Text (UE5: NSLOCTEXT())
+    pub fn localized(namespace: impl Into<String>, key: impl Into<String>, source: impl Into<String>) -> Self {
+        Self::from_key(namespace, key, source)
+    }
+
     /// Create a localized Text
     pub fn from_key(namespace: impl Into<String>, key: impl Into<String>, source: impl Into<String>) -> Self {
         let source_string = source.into();
@@ -66,8 +83,104 @@ impl Text {
     pub fn set_display_string(&mut self, display_string: impl Into<String>) {
         self.display_string = display_string.into();
     }
+
+    /// Get the display string as an owned String
+    #[allow(clippy::inherent_to_string_shadow_display)]
+    pub fn to_string(&self) -> String {
+        self.display_string.clone()
+    }
+
+    /// Get the source string, falling back to the display string
+    pub fn source(&self) -> &str {
+        self.source_string.as_deref().unwrap_or(&self.display_string)
+    }
+
+    /// The key equality compares: namespace and key, or the source string
+    fn identity(&self) -> TextIdentity<'_> {
+        match (&self.namespace, &self.key) {
+            (Some(namespace), Some(key)) => TextIdentity::Localized(namespace, key),
+            _ => TextIdentity::Source(self.source()),
+        }
+    }
+
+    /// Format a pattern with named arguments (UE5: FText::Format())
+    ///
+    /// `{name}` is replaced by the matching argument; unknown placeholders
+    /// are left intact. `{{` and `}}` produce literal braces.
+    pub fn format(pattern: &str, args: &TMap<String, String>) -> Self {
+        Self::new(substitute(pattern, |name| args.find(&name.to_string()).cloned()))
+    }
+
+    /// Format a pattern with positional arguments (`{0}`, `{1}`, ...)
+    ///
+    /// Follows the same rules as [`Text::format`].
+    pub fn format_ordered<T: fmt::Display>(pattern: &str, args: &[T]) -> Self {
+        Self::new(substitute(pattern, |name| {
+            let index: usize = name.parse().ok()?;
+            args.get(index).map(|arg| arg.to_string())
+        }))
+    }
+}
+
+/// Expand `{placeholder}`s in `pattern` using `lookup`
+fn substitute(pattern: &str, lookup: impl Fn(&str) -> Option<String>) -> String {
+    let mut result = String::with_capacity(pattern.len());
+    let mut rest = pattern;
+
+    while let Some(pos) = rest.find(['{', '}']) {
+        result.push_str(&rest[..pos]);
+        let brace = &rest[pos..pos + 1];
+        rest = &rest[pos + 1..];
+
+        // Escaped brace
+        if rest.starts_with(brace) {
+            result.push_str(brace);
+            rest = &rest[1..];
+            continue;
+        }
+
+        if brace == "}" {
+            result.push('}');
+            continue;
+        }
+
+        match rest.find(['{', '}']) {
+            Some(end) if rest.as_bytes()[end] == b'}' => {
+                let name = &rest[..end];
+                match lookup(name) {
+                    Some(value) => result.push_str(&value),
+                    None => {
+                        result.push('{');
+                        result.push_str(name);
+                        result.push('}');
+                    }
+                }
+                rest = &rest[end + 1..];
+            }
+            // Unterminated placeholder: keep the brace as written
+            _ => result.push('{'),
+        }
+    }
+
+    result.push_str(rest);
+    result
+}
+
+/// What makes two texts equal, see `Text`
+#[derive(PartialEq)]
+enum TextIdentity<'a> {
+    Localized(&'a str, &'a str),
+    Source(&'a str),
+}
+
+impl PartialEq for Text {
+    fn eq(&self, other: &Self) -> bool {
+        self.identity() == other.identity()
+    }
 }
 
+impl Eq for Text {}
+
 impl Default for Text {
     fn default() -> Self {
         Self::new("")
@@ -100,4 +213,90 @@ mod tests {
         assert!(text2.is_localizable());
         assert_eq!(text2.as_str(), "Hello World");
     }
+
+    fn args(pairs: &[(&str, &str)]) -> TMap<String, String> {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn test_text_format_named() {
+        let text = Text::format(
+            "{Player} scored {Points} points",
+            &args(&[("Player", "Alice"), ("Points", "42")]),
+        );
+        assert_eq!(text.to_string(), "Alice scored 42 points");
+        assert!(!text.is_localizable());
+
+        // The same argument can appear more than once
+        let text = Text::format("{a}-{a}", &args(&[("a", "x")]));
+        assert_eq!(text.as_str(), "x-x");
+    }
+
+    #[test]
+    fn test_text_format_missing_argument() {
+        let text = Text::format("Hello {Name}, welcome to {Map}", &args(&[("Name", "Bob")]));
+        assert_eq!(text.as_str(), "Hello Bob, welcome to {Map}");
+    }
+
+    #[test]
+    fn test_text_format_escaped_braces() {
+        let text = Text::format("{{Name}} is {Name}}}", &args(&[("Name", "Bob")]));
+        assert_eq!(text.as_str(), "{Name} is Bob}");
+
+        // Unterminated and stray braces are kept as written
+        let text = Text::format("open { brace and } close {Name", &args(&[("Name", "Bob")]));
+        assert_eq!(text.as_str(), "open { brace and } close {Name");
+    }
+
+    #[test]
+    fn test_text_format_ordered() {
+        let text = Text::format_ordered("{0} killed {1} with {0}'s {2}", &["Alice", "Bob"]);
+        assert_eq!(text.as_str(), "Alice killed Bob with Alice's {2}");
+
+        let text = Text::format_ordered("{0}/{1}", &[3, 10]);
+        assert_eq!(text.as_str(), "3/10");
+    }
+
+    #[test]
+    fn test_text_equality() {
+        let a = Text::localized("UI", "Greeting", "Hello");
+        let b = Text::localized("UI", "Greeting", "Hello there");
+        let c = Text::localized("UI", "Farewell", "Hello");
+
+        // Identity wins when both texts are localized
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+
+        // Otherwise sources are compared
+        assert_eq!(Text::from_string("Hi"), Text::new("Hi"));
+        assert_ne!(Text::from_string("Hi"), Text::new("Hello"));
+
+        // Localized and plain texts never match, or equality would not be
+        // transitive: "Hello" would equal a, and a equals b, but not b
+        assert_ne!(Text::from_string("Hello"), a);
+        assert_ne!(Text::from_string("Hello there"), b);
+
+        // A key without a namespace is not a localization identity
+        let mut keyed = Text::new("Hi");
+        keyed.key = Some("Greeting".to_string());
+        assert_eq!(keyed, Text::new("Hi"));
+    }
+
+    #[test]
+    fn test_text_serialization() {
+        let mut text = Text::localized("UI", "Greeting", "Hello");
+        text.set_display_string("Bonjour");
+
+        let json = serde_json::to_string(&text).unwrap();
+        let deserialized: Text = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.display_string, "Bonjour");
+        assert_eq!(deserialized.namespace.as_deref(), Some("UI"));
+        assert_eq!(deserialized.key.as_deref(), Some("Greeting"));
+        assert_eq!(deserialized.source_string.as_deref(), Some("Hello"));
+
+        let binary = text.to_binary().unwrap();
+        let deserialized = Text::from_binary(&binary).unwrap();
+        assert_eq!(deserialized.display_string, "Bonjour");
+        assert_eq!(deserialized, text);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,154 @@
+//! Injectable wall-clock source
+
+use crate::types::{DateTime, Timespan, UeTypesError};
+use core::sync::atomic::{AtomicI64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Source of wall-clock time
+///
+/// Everything that reads "now" has a variant taking a `Clock`, so tests and
+/// replays can substitute a `ManualClock` or a fixed `SystemTime` for the
+/// system clock. Only `system_time` is required; the other methods derive
+/// from it.
+pub trait Clock {
+    /// Current wall-clock time
+    fn system_time(&self) -> SystemTime;
+
+    /// Current Unix time in whole seconds, or 0 if the clock reads before the epoch
+    fn now_unix_secs(&self) -> u64 {
+        since_epoch(self).map_or(0, |duration| duration.as_secs())
+    }
+
+    /// Current time as a `DateTime`, or the epoch if the clock reads before it
+    fn now(&self) -> DateTime {
+        DateTime::try_now_with_clock(self).unwrap_or(DateTime::from_ticks(0))
+    }
+}
+
+/// The operating system's real-time clock
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn system_time(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// Clock that only moves when told to, for tests and deterministic replays
+///
+/// The time is held in an atomic, so one clock can be shared by reference
+/// and advanced while other code reads it. It may be set before the Unix
+/// epoch to exercise the pre-epoch paths.
+#[derive(Debug, Default)]
+pub struct ManualClock {
+    ticks: AtomicI64,
+}
+
+impl ManualClock {
+    /// Create a clock stopped at `start`
+    pub fn new(start: DateTime) -> Self {
+        Self { ticks: AtomicI64::new(start.ticks) }
+    }
+
+    /// Create a clock stopped at `seconds` after the Unix epoch (negative for before)
+    pub fn from_unix_secs(seconds: i64) -> Self {
+        Self::new(DateTime::from_unix_timestamp(seconds))
+    }
+
+    /// Jump to `time`
+    pub fn set(&self, time: DateTime) {
+        self.ticks.store(time.ticks, Ordering::Relaxed);
+    }
+
+    /// Move the clock by `delta`, which may be negative, saturating at `DateTime::MIN`/`MAX`
+    pub fn advance(&self, delta: Timespan) {
+        let _ = self.ticks.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |ticks| {
+            Some(ticks.saturating_add(delta.ticks))
+        });
+    }
+
+    /// Move the clock forward by whole seconds
+    pub fn advance_secs(&self, seconds: u64) {
+        let seconds = i64::try_from(seconds).unwrap_or(i64::MAX);
+        self.advance(Timespan::from_ticks(seconds.saturating_mul(DateTime::TICKS_PER_SECOND)));
+    }
+}
+
+impl Clock for ManualClock {
+    fn system_time(&self) -> SystemTime {
+        self.now().to_system_time()
+    }
+
+    fn now_unix_secs(&self) -> u64 {
+        self.now().to_unix_timestamp().max(0) as u64
+    }
+
+    /// The stored time exactly, including times before the epoch
+    fn now(&self) -> DateTime {
+        DateTime::from_ticks(self.ticks.load(Ordering::Relaxed))
+    }
+}
+
+/// Time since the Unix epoch on `clock`, or how far before it the clock is
+pub(crate) fn since_epoch<C: Clock + ?Sized>(clock: &C) -> Result<Duration, UeTypesError> {
+    clock
+        .system_time()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|error| UeTypesError::ClockBeforeEpoch(error.duration()))
+}
+
+/// A fixed instant, for callers that already know what time it is
+impl Clock for SystemTime {
+    fn system_time(&self) -> SystemTime {
+        *self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_since_epoch() {
+        let clock = ManualClock::from_unix_secs(1_700_000_000);
+        assert_eq!(since_epoch(&clock), Ok(Duration::from_secs(1_700_000_000)));
+
+        let broken_rtc = ManualClock::from_unix_secs(-90);
+        assert_eq!(since_epoch(&broken_rtc), Err(UeTypesError::ClockBeforeEpoch(Duration::from_secs(90))));
+
+        let dynamic: &dyn Clock = &SystemClock;
+        assert!(since_epoch(dynamic).is_ok());
+        assert!(dynamic.now_unix_secs() > 1_700_000_000);
+    }
+
+    #[test]
+    fn test_manual_clock_advances_exactly() {
+        let clock = ManualClock::from_unix_secs(1_000);
+        assert_eq!(clock.now_unix_secs(), 1_000);
+        assert_eq!(clock.now(), DateTime::from_unix_timestamp(1_000));
+
+        clock.advance_secs(45);
+        assert_eq!(clock.now_unix_secs(), 1_045);
+        clock.advance(Timespan::from_milliseconds(1_500.0));
+        assert_eq!(clock.now().to_unix_timestamp_millis(), 1_046_500);
+        assert_eq!(clock.now_unix_secs(), 1_046);
+        assert_eq!(clock.system_time(), UNIX_EPOCH + Duration::from_millis(1_046_500));
+
+        clock.advance(Timespan::from_seconds(-2_000.0));
+        assert_eq!(clock.now().to_unix_timestamp_millis(), -953_500);
+        assert_eq!(clock.now_unix_secs(), 0);
+
+        clock.set(DateTime::MAX);
+        clock.advance_secs(u64::MAX);
+        assert_eq!(clock.now(), DateTime::MAX);
+        assert_eq!(ManualClock::default().now(), DateTime::from_ticks(0));
+    }
+
+    #[test]
+    fn test_system_clock_defaults() {
+        let now = SystemClock.now();
+        assert!(now > DateTime::from_unix_timestamp(1_700_000_000));
+        assert!(SystemClock.now_unix_secs().abs_diff(now.to_unix_timestamp() as u64) <= 1);
+    }
+}
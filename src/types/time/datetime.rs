@@ -2,22 +2,89 @@
 
 use crate::BinarySerializable;
 use serde::{Deserialize, Serialize};
-use std::fmt;
+use super::Timespan;
+use core::fmt;
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+use alloc::string::{String, ToString};
+#[cfg(feature = "std")]
+use super::clock::{since_epoch, Clock, SystemClock};
+#[cfg(feature = "std")]
+use crate::types::UeTypesError;
+#[cfg(feature = "std")]
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
-use crate::types::time::timespan::Timespan;
+
+/// Reason a date or time couldn't be built or parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DateTimeError {
+    /// The day doesn't exist in that month, or the year/month is out of range
+    InvalidDate { year: i32, month: u32, day: u32 },
+    /// Hour, minute or second out of range
+    InvalidTime { hour: u32, minute: u32, second: u32 },
+    /// The string isn't an ISO 8601 timestamp this parser understands
+    InvalidFormat(String),
+}
+
+impl fmt::Display for DateTimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DateTimeError::InvalidDate { year, month, day } => {
+                write!(f, "invalid date {:04}-{:02}-{:02}", year, month, day)
+            }
+            DateTimeError::InvalidTime { hour, minute, second } => {
+                write!(f, "invalid time {:02}:{:02}:{:02}", hour, minute, second)
+            }
+            DateTimeError::InvalidFormat(text) => write!(f, "invalid ISO 8601 timestamp \"{}\"", text),
+        }
+    }
+}
+
+impl core::error::Error for DateTimeError {}
+
+/// Day of the week (EDayOfWeek)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DayOfWeek {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl fmt::Display for DayOfWeek {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
 
 /// UE5-style DateTime for timestamps and scheduling
+///
+/// Ticks count from the Unix epoch (1970-01-01 00:00:00 UTC), not from
+/// 0001-01-01 like UE's `FDateTime`; use `to_ue_ticks`/`from_ue_ticks` when
+/// exchanging raw ticks with the engine. Calendar accessors use the
+/// proleptic Gregorian calendar in UTC.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct DateTime {
     /// Ticks since epoch (100-nanosecond intervals)
     pub ticks: i64,
 }
 
+/// Formats as ISO 8601, like `to_iso8601`
 impl fmt::Display for DateTime {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let system_time = self.to_system_time();
-        let datetime = chrono::DateTime::<chrono::Utc>::from(system_time);
-        write!(f, "DateTime({})", datetime.format("%Y-%m-%d %H:%M:%S UTC"))
+        let (year, month, day) = self.ymd();
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+            year,
+            month,
+            day,
+            self.hour(),
+            self.minute(),
+            self.second(),
+            self.millisecond()
+        )
     }
 }
 
@@ -30,7 +97,15 @@ impl DateTime {
     pub const TICKS_PER_MILLISECOND: i64 = 10_000;
     /// Ticks per microsecond
     pub const TICKS_PER_MICROSECOND: i64 = 10;
-    
+    /// Ticks per minute
+    pub const TICKS_PER_MINUTE: i64 = Self::TICKS_PER_SECOND * 60;
+    /// Ticks per hour
+    pub const TICKS_PER_HOUR: i64 = Self::TICKS_PER_MINUTE * 60;
+    /// Ticks per day
+    pub const TICKS_PER_DAY: i64 = Self::TICKS_PER_HOUR * 24;
+    /// The Unix epoch in UE `FDateTime` ticks (since 0001-01-01)
+    pub const UNIX_EPOCH_UE_TICKS: i64 = 621_355_968_000_000_000;
+
     /// Minimum DateTime value
     pub const MIN: Self = Self { ticks: i64::MIN };
     /// Maximum DateTime value
@@ -41,13 +116,66 @@ impl DateTime {
         Self { ticks }
     }
 
-    /// Get current DateTime
+    /// Create a DateTime from a calendar date and time of day (UTC)
+    ///
+    /// Years must be within 1..=9999, like `FDateTime::Validate`. Days are
+    /// checked against the month, so February 30 is rejected.
+    pub fn from_ymd_hms(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+        second: u32,
+    ) -> Result<Self, DateTimeError> {
+        if !(1..=9999).contains(&year) || !(1..=12).contains(&month) || day < 1 || day > Self::days_in_month(year, month) {
+            return Err(DateTimeError::InvalidDate { year, month, day });
+        }
+        if hour > 23 || minute > 59 || second > 59 {
+            return Err(DateTimeError::InvalidTime { hour, minute, second });
+        }
+        let days = days_from_civil(year as i64, month, day);
+        Ok(Self::from_ticks(
+            days * Self::TICKS_PER_DAY
+                + hour as i64 * Self::TICKS_PER_HOUR
+                + minute as i64 * Self::TICKS_PER_MINUTE
+                + second as i64 * Self::TICKS_PER_SECOND,
+        ))
+    }
+
+    /// Check if a year is a Gregorian leap year
+    pub fn is_leap_year(year: i32) -> bool {
+        (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+    }
+
+    /// Number of days in a month (1-12), or 0 for an invalid month
+    pub fn days_in_month(year: i32, month: u32) -> u32 {
+        match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if Self::is_leap_year(year) => 29,
+            2 => 28,
+            _ => 0,
+        }
+    }
+
+    /// Get current DateTime, or the Unix epoch if the clock reads earlier
+    #[cfg(feature = "std")]
     pub fn now() -> Self {
-        let duration = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or(Duration::ZERO);
-        let ticks = duration.as_nanos() as i64 / 100; // Convert to 100ns ticks
-        Self::from_ticks(ticks)
+        Self::try_now().unwrap_or(Self::from_ticks(0))
+    }
+
+    /// Get current DateTime, failing if the clock reads before the Unix epoch
+    #[cfg(feature = "std")]
+    pub fn try_now() -> Result<Self, UeTypesError> {
+        Self::try_now_with_clock(&SystemClock)
+    }
+
+    /// Read the current DateTime from `clock`
+    #[cfg(feature = "std")]
+    pub fn try_now_with_clock<C: Clock + ?Sized>(clock: &C) -> Result<Self, UeTypesError> {
+        let duration = since_epoch(clock)?;
+        Ok(Self::from_ticks(duration.as_nanos() as i64 / 100)) // Convert to 100ns ticks
     }
 
     /// Create DateTime from Unix timestamp (seconds)
@@ -70,40 +198,292 @@ impl DateTime {
         self.ticks / Self::TICKS_PER_MILLISECOND
     }
 
+    /// Create from UE `FDateTime` ticks (100ns since 0001-01-01), saturating
+    pub fn from_ue_ticks(ue_ticks: i64) -> Self {
+        Self::from_ticks(ue_ticks.saturating_sub(Self::UNIX_EPOCH_UE_TICKS))
+    }
+
+    /// Convert to UE `FDateTime` ticks (100ns since 0001-01-01), saturating
+    pub fn to_ue_ticks(self) -> i64 {
+        self.ticks.saturating_add(Self::UNIX_EPOCH_UE_TICKS)
+    }
+
     /// Convert to SystemTime
+    #[cfg(feature = "std")]
     pub fn to_system_time(self) -> SystemTime {
-        let duration = Duration::from_nanos((self.ticks * 100) as u64);
-        UNIX_EPOCH + duration
+        let duration = Duration::new(
+            self.ticks.unsigned_abs() / Self::TICKS_PER_SECOND as u64,
+            (self.ticks.unsigned_abs() % Self::TICKS_PER_SECOND as u64) as u32 * 100,
+        );
+        if self.ticks < 0 {
+            UNIX_EPOCH - duration
+        } else {
+            UNIX_EPOCH + duration
+        }
+    }
+
+    /// Add a timespan, saturating at `MIN`/`MAX` (same as `+`)
+    pub fn add_timespan(self, timespan: Timespan) -> Self {
+        Self::from_ticks(self.ticks.saturating_add(timespan.ticks))
+    }
+
+    /// Subtract a timespan, saturating at `MIN`/`MAX` (same as `-`)
+    pub fn sub_timespan(self, timespan: Timespan) -> Self {
+        Self::from_ticks(self.ticks.saturating_sub(timespan.ticks))
+    }
+
+    /// Add a timespan, or `None` on overflow
+    pub fn checked_add_timespan(self, timespan: Timespan) -> Option<Self> {
+        self.ticks.checked_add(timespan.ticks).map(Self::from_ticks)
     }
 
-    /// Add a timespan
-    pub fn add_timespan(self, timespan: super::Timespan) -> Self {
-        Self::from_ticks(self.ticks + timespan.ticks)
+    /// Subtract a timespan, or `None` on overflow
+    pub fn checked_sub_timespan(self, timespan: Timespan) -> Option<Self> {
+        self.ticks.checked_sub(timespan.ticks).map(Self::from_ticks)
     }
 
-    /// Subtract a timespan
-    pub fn sub_timespan(self, timespan: super::Timespan) -> Self {
-        Self::from_ticks(self.ticks - timespan.ticks)
+    /// Get the difference between two DateTimes as a Timespan, saturating (same as `-`)
+    pub fn difference(self, other: DateTime) -> Timespan {
+        Timespan::from_ticks(self.ticks.saturating_sub(other.ticks))
     }
 
-    /// Get the difference between two DateTimes as a Timespan
-    pub fn difference(self, other: DateTime) -> super::Timespan {
-        super::Timespan::from_ticks(self.ticks - other.ticks)
+    /// Restrict to `[min, max]`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max`, like `Ord::clamp`.
+    pub fn clamp(self, min: DateTime, max: DateTime) -> Self {
+        Ord::clamp(self, min, max)
     }
 
     /// Get the date part (time set to midnight)
     pub fn date(self) -> Self {
-        let days = self.ticks / (Self::TICKS_PER_SECOND * 86400);
-        Self::from_ticks(days * Self::TICKS_PER_SECOND * 86400)
+        Self::from_ticks(self.days_since_epoch() * Self::TICKS_PER_DAY)
     }
 
     /// Get time of day as Timespan since midnight
-    pub fn time_of_day(self) -> super::Timespan {
-        let ticks_in_day = self.ticks % (Self::TICKS_PER_SECOND * 86400);
-        super::Timespan::from_ticks(ticks_in_day)
+    pub fn time_of_day(self) -> Timespan {
+        Timespan::from_ticks(self.ticks.rem_euclid(Self::TICKS_PER_DAY))
+    }
+
+    /// Calendar year
+    pub fn year(self) -> i32 {
+        self.ymd().0
+    }
+
+    /// Month of the year (1-12)
+    pub fn month(self) -> u32 {
+        self.ymd().1
+    }
+
+    /// Day of the month (1-31)
+    pub fn day(self) -> u32 {
+        self.ymd().2
+    }
+
+    /// Hour of the day (0-23)
+    pub fn hour(self) -> u32 {
+        (self.ticks.rem_euclid(Self::TICKS_PER_DAY) / Self::TICKS_PER_HOUR) as u32
+    }
+
+    /// Minute of the hour (0-59)
+    pub fn minute(self) -> u32 {
+        (self.ticks.rem_euclid(Self::TICKS_PER_HOUR) / Self::TICKS_PER_MINUTE) as u32
+    }
+
+    /// Second of the minute (0-59)
+    pub fn second(self) -> u32 {
+        (self.ticks.rem_euclid(Self::TICKS_PER_MINUTE) / Self::TICKS_PER_SECOND) as u32
+    }
+
+    /// Millisecond of the second (0-999)
+    pub fn millisecond(self) -> u32 {
+        (self.ticks.rem_euclid(Self::TICKS_PER_SECOND) / Self::TICKS_PER_MILLISECOND) as u32
+    }
+
+    /// Day of the week
+    pub fn day_of_week(self) -> DayOfWeek {
+        // 1970-01-01 was a Thursday
+        match (self.days_since_epoch() + 3).rem_euclid(7) {
+            0 => DayOfWeek::Monday,
+            1 => DayOfWeek::Tuesday,
+            2 => DayOfWeek::Wednesday,
+            3 => DayOfWeek::Thursday,
+            4 => DayOfWeek::Friday,
+            5 => DayOfWeek::Saturday,
+            _ => DayOfWeek::Sunday,
+        }
+    }
+
+    /// Day of the year (1-366)
+    pub fn day_of_year(self) -> u32 {
+        let (year, _, _) = self.ymd();
+        (self.days_since_epoch() - days_from_civil(year as i64, 1, 1)) as u32 + 1
+    }
+
+    /// Format as ISO 8601 in UTC with milliseconds, e.g. `2024-02-29T13:45:30.250Z`
+    pub fn to_iso8601(self) -> String {
+        self.to_string()
+    }
+
+    /// Parse an ISO 8601 timestamp
+    ///
+    /// Accepts `YYYY-MM-DD`, optionally followed by `THH:MM:SS`, a fraction
+    /// of a second (down to ticks), and `Z` or a `+HH:MM`/`-HH:MM`/`+HHMM`/`+HH`
+    /// offset. Timestamps without an offset are taken as UTC. Offsets are
+    /// applied, so the result is always UTC.
+    pub fn parse_iso8601(text: &str) -> Result<Self, DateTimeError> {
+        let invalid = || DateTimeError::InvalidFormat(text.to_string());
+        let trimmed = text.trim();
+        if !trimmed.is_ascii() {
+            return Err(invalid());
+        }
+        let bytes = trimmed.as_bytes();
+        let number = |start: usize, len: usize| -> Result<u32, DateTimeError> {
+            let digits = bytes.get(start..start + len).ok_or_else(invalid)?;
+            if !digits.iter().all(u8::is_ascii_digit) {
+                return Err(invalid());
+            }
+            Ok(digits.iter().fold(0, |value, digit| value * 10 + (digit - b'0') as u32))
+        };
+        let expect = |index: usize, separator: u8| {
+            if bytes.get(index) == Some(&separator) {
+                Ok(())
+            } else {
+                Err(invalid())
+            }
+        };
+
+        let year = number(0, 4)? as i32;
+        expect(4, b'-')?;
+        let month = number(5, 2)?;
+        expect(7, b'-')?;
+        let day = number(8, 2)?;
+        let mut index = 10;
+
+        let (mut hour, mut minute, mut second, mut fraction_ticks) = (0, 0, 0, 0);
+        if matches!(bytes.get(index), Some(b'T' | b't' | b' ')) {
+            hour = number(11, 2)?;
+            expect(13, b':')?;
+            minute = number(14, 2)?;
+            expect(16, b':')?;
+            second = number(17, 2)?;
+            index = 19;
+
+            if matches!(bytes.get(index), Some(b'.' | b',')) {
+                index += 1;
+                let start = index;
+                while bytes.get(index).is_some_and(u8::is_ascii_digit) {
+                    index += 1;
+                }
+                if index == start {
+                    return Err(invalid());
+                }
+                // Seven digits are ticks; further digits are below tick precision
+                for position in 0..7 {
+                    let digit = bytes.get(start + position).filter(|_| start + position < index);
+                    fraction_ticks = fraction_ticks * 10 + digit.map_or(0, |digit| (digit - b'0') as i64);
+                }
+            }
+        }
+
+        let offset_ticks = match &bytes[index..] {
+            [] | [b'Z' | b'z'] => 0,
+            [sign @ (b'+' | b'-'), rest @ ..] => {
+                let (hours, minutes) = match rest.len() {
+                    2 => (number(index + 1, 2)?, 0),
+                    4 => (number(index + 1, 2)?, number(index + 3, 2)?),
+                    5 if rest[2] == b':' => (number(index + 1, 2)?, number(index + 4, 2)?),
+                    _ => return Err(invalid()),
+                };
+                if hours > 23 || minutes > 59 {
+                    return Err(invalid());
+                }
+                let offset = hours as i64 * Self::TICKS_PER_HOUR + minutes as i64 * Self::TICKS_PER_MINUTE;
+                if *sign == b'-' {
+                    -offset
+                } else {
+                    offset
+                }
+            }
+            _ => return Err(invalid()),
+        };
+
+        let local = Self::from_ymd_hms(year, month, day, hour, minute, second)?;
+        Ok(Self::from_ticks(local.ticks + fraction_ticks - offset_ticks))
+    }
+
+    fn days_since_epoch(self) -> i64 {
+        self.ticks.div_euclid(Self::TICKS_PER_DAY)
+    }
+
+    fn ymd(self) -> (i32, u32, u32) {
+        civil_from_days(self.days_since_epoch())
+    }
+}
+
+impl Add<Timespan> for DateTime {
+    type Output = DateTime;
+
+    fn add(self, rhs: Timespan) -> DateTime {
+        self.add_timespan(rhs)
+    }
+}
+
+impl AddAssign<Timespan> for DateTime {
+    fn add_assign(&mut self, rhs: Timespan) {
+        *self = self.add_timespan(rhs);
+    }
+}
+
+impl Sub<Timespan> for DateTime {
+    type Output = DateTime;
+
+    fn sub(self, rhs: Timespan) -> DateTime {
+        self.sub_timespan(rhs)
     }
 }
 
+impl SubAssign<Timespan> for DateTime {
+    fn sub_assign(&mut self, rhs: Timespan) {
+        *self = self.sub_timespan(rhs);
+    }
+}
+
+impl Sub for DateTime {
+    type Output = Timespan;
+
+    fn sub(self, rhs: DateTime) -> Timespan {
+        self.difference(rhs)
+    }
+}
+
+/// Days since the Unix epoch of a proleptic Gregorian date (Howard Hinnant's algorithm)
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = month as i64;
+    let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Proleptic Gregorian date of a day count since the Unix epoch, the inverse of `days_from_civil`
+fn civil_from_days(days: i64) -> (i32, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 } as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year as i32, month, day)
+}
+
+#[cfg(feature = "std")]
 impl Default for DateTime {
     fn default() -> Self {
         Self::now()
@@ -118,11 +498,11 @@ mod tests {
     fn test_datetime() {
         let dt1 = DateTime::from_unix_timestamp(1000);
         let dt2 = DateTime::from_unix_timestamp(2000);
-        
+
         let diff = dt2.difference(dt1);
         assert_eq!(diff.total_seconds(), 1000.0);
-        
-        let dt3 = dt1.add_timespan(super::Timespan::from_seconds(500.0));
+
+        let dt3 = dt1.add_timespan(Timespan::from_seconds(500.0));
         assert_eq!(dt3.to_unix_timestamp(), 1500);
     }
 
@@ -133,4 +513,185 @@ mod tests {
         let deserialized: DateTime = serde_json::from_str(&json).unwrap();
         assert_eq!(dt, deserialized);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_calendar_accessors() {
+        let dt = DateTime::from_ymd_hms(2024, 2, 29, 13, 45, 30).unwrap();
+        assert_eq!((dt.year(), dt.month(), dt.day()), (2024, 2, 29));
+        assert_eq!((dt.hour(), dt.minute(), dt.second(), dt.millisecond()), (13, 45, 30, 0));
+        assert_eq!(dt.day_of_week(), DayOfWeek::Thursday);
+        assert_eq!(dt.day_of_year(), 60);
+        assert_eq!(dt.to_unix_timestamp(), 1_709_214_330);
+
+        assert_eq!(DateTime::from_ymd_hms(2024, 12, 31, 0, 0, 0).unwrap().day_of_year(), 366);
+        assert_eq!(DateTime::from_ymd_hms(2023, 12, 31, 0, 0, 0).unwrap().day_of_year(), 365);
+        assert_eq!(DateTime::from_ticks(0).day_of_week(), DayOfWeek::Thursday);
+        assert_eq!(DateTime::from_ymd_hms(2000, 1, 1, 0, 0, 0).unwrap().day_of_week(), DayOfWeek::Saturday);
+    }
+
+    #[test]
+    fn test_leap_years_and_validation() {
+        assert!(DateTime::is_leap_year(2000));
+        assert!(DateTime::is_leap_year(2024));
+        assert!(!DateTime::is_leap_year(1900));
+        assert!(!DateTime::is_leap_year(2023));
+
+        assert!(DateTime::from_ymd_hms(2000, 2, 29, 0, 0, 0).is_ok());
+        assert_eq!(
+            DateTime::from_ymd_hms(1900, 2, 29, 0, 0, 0),
+            Err(DateTimeError::InvalidDate { year: 1900, month: 2, day: 29 })
+        );
+        assert!(DateTime::from_ymd_hms(2024, 2, 30, 0, 0, 0).is_err());
+        assert!(DateTime::from_ymd_hms(2024, 4, 31, 0, 0, 0).is_err());
+        assert!(DateTime::from_ymd_hms(2024, 13, 1, 0, 0, 0).is_err());
+        assert!(DateTime::from_ymd_hms(2024, 1, 0, 0, 0, 0).is_err());
+        assert!(DateTime::from_ymd_hms(0, 1, 1, 0, 0, 0).is_err());
+        assert_eq!(
+            DateTime::from_ymd_hms(2024, 1, 1, 24, 0, 0),
+            Err(DateTimeError::InvalidTime { hour: 24, minute: 0, second: 0 })
+        );
+        assert!(DateTime::from_ymd_hms(2024, 1, 1, 23, 60, 0).is_err());
+        assert!(DateTime::from_ymd_hms(2024, 1, 1, 23, 59, 60).is_err());
+    }
+
+    #[test]
+    fn test_try_now_with_clock() {
+        use super::super::ManualClock;
+
+        let clock = ManualClock::new(DateTime::from_unix_timestamp_millis(1_700_000_000_123));
+        let now = DateTime::try_now_with_clock(&clock).unwrap();
+        assert_eq!(now.to_unix_timestamp_millis(), 1_700_000_000_123);
+
+        let broken_rtc = ManualClock::from_unix_secs(-5);
+        assert_eq!(
+            DateTime::try_now_with_clock(&broken_rtc),
+            Err(UeTypesError::ClockBeforeEpoch(Duration::from_secs(5)))
+        );
+        assert!(DateTime::try_now().unwrap() > DateTime::from_ticks(0));
+    }
+
+    #[test]
+    fn test_epoch_boundaries() {
+        let before = DateTime::from_ticks(-DateTime::TICKS_PER_MILLISECOND);
+        assert_eq!((before.year(), before.month(), before.day()), (1969, 12, 31));
+        assert_eq!((before.hour(), before.minute(), before.second(), before.millisecond()), (23, 59, 59, 999));
+        assert_eq!(before.date(), DateTime::from_ymd_hms(1969, 12, 31, 0, 0, 0).unwrap());
+        assert_eq!(before.day_of_week(), DayOfWeek::Wednesday);
+        assert_eq!(before.to_iso8601(), "1969-12-31T23:59:59.999Z");
+        assert_eq!(DateTime::from_ticks(0).to_iso8601(), "1970-01-01T00:00:00.000Z");
+        assert_eq!(
+            before.to_system_time(),
+            UNIX_EPOCH - Duration::from_millis(1)
+        );
+
+        // UE ticks count from 0001-01-01
+        let first_day = DateTime::from_ymd_hms(1, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(first_day.to_ue_ticks(), 0);
+        assert_eq!(DateTime::from_ue_ticks(0), first_day);
+        assert_eq!(DateTime::from_ticks(0).to_ue_ticks(), DateTime::UNIX_EPOCH_UE_TICKS);
+        assert_eq!(DateTime::MAX.to_ue_ticks(), i64::MAX);
+        let last_second = DateTime::from_ymd_hms(9999, 12, 31, 23, 59, 59).unwrap();
+        assert_eq!(DateTime::from_ue_ticks(last_second.to_ue_ticks()), last_second);
+    }
+
+    #[test]
+    fn test_iso8601_round_trip() {
+        let dt = DateTime::from_ymd_hms(2024, 2, 29, 13, 45, 30).unwrap();
+        let with_millis = DateTime::from_ticks(dt.ticks + 250 * DateTime::TICKS_PER_MILLISECOND);
+        assert_eq!(with_millis.to_iso8601(), "2024-02-29T13:45:30.250Z");
+        assert_eq!(format!("{}", with_millis), "2024-02-29T13:45:30.250Z");
+        assert_eq!(DateTime::parse_iso8601(&with_millis.to_iso8601()), Ok(with_millis));
+
+        assert_eq!(DateTime::parse_iso8601("2024-02-29T13:45:30Z"), Ok(dt));
+        assert_eq!(DateTime::parse_iso8601("2024-02-29T13:45:30"), Ok(dt));
+        assert_eq!(DateTime::parse_iso8601("2024-02-29 13:45:30"), Ok(dt));
+        assert_eq!(DateTime::parse_iso8601("2024-02-29T15:45:30+02:00"), Ok(dt));
+        assert_eq!(DateTime::parse_iso8601("2024-02-29T08:15:30-0530"), Ok(dt));
+        assert_eq!(DateTime::parse_iso8601("2024-02-29T14:45:30+01"), Ok(dt));
+        assert_eq!(DateTime::parse_iso8601("2024-02-29"), Ok(dt.date()));
+        assert_eq!(
+            DateTime::parse_iso8601("2024-02-29T13:45:30.12345678Z"),
+            Ok(DateTime::from_ticks(dt.ticks + 1_234_567))
+        );
+        // The offset can move the date across midnight
+        assert_eq!(
+            DateTime::parse_iso8601("2024-03-01T01:00:00+02:00").unwrap().to_iso8601(),
+            "2024-02-29T23:00:00.000Z"
+        );
+    }
+
+    #[test]
+    fn test_iso8601_parse_failures() {
+        let malformed = [
+            "",
+            "2024",
+            "2024-2-29",
+            "2024/02/29",
+            "2024-02-29T",
+            "2024-02-29T13:45",
+            "2024-02-29T13:45:30.",
+            "2024-02-29T13:45:30Q",
+            "2024-02-29T13:45:30+2",
+            "2024-02-29T13:45:30+24:00",
+            "2024-02-29T13:45:30Z junk",
+            "２０２４-02-29",
+        ];
+        for text in malformed {
+            assert_eq!(
+                DateTime::parse_iso8601(text),
+                Err(DateTimeError::InvalidFormat(text.to_string())),
+                "{}",
+                text
+            );
+        }
+        assert_eq!(
+            DateTime::parse_iso8601("2023-02-29T00:00:00Z"),
+            Err(DateTimeError::InvalidDate { year: 2023, month: 2, day: 29 })
+        );
+        assert!(DateTime::parse_iso8601("2024-02-29T25:00:00Z").is_err());
+        assert_eq!(
+            DateTimeError::InvalidDate { year: 2023, month: 2, day: 30 }.to_string(),
+            "invalid date 2023-02-30"
+        );
+    }
+
+    #[test]
+    fn test_timespan_operators() {
+        let start = DateTime::from_ymd_hms(2024, 6, 1, 12, 0, 0).unwrap();
+        let delay = Timespan::from_seconds(30.0);
+        let deadline = start + delay;
+        assert_eq!(deadline, start.add_timespan(delay));
+        assert_eq!(deadline - delay, deadline.sub_timespan(delay));
+        assert_eq!(deadline - start, deadline.difference(start));
+        assert_eq!(deadline - start, delay);
+        assert_eq!(start - deadline, -delay);
+
+        let mut cursor = start;
+        cursor += delay;
+        cursor += delay;
+        cursor -= delay;
+        assert_eq!(cursor, deadline);
+        assert_eq!(start.checked_add_timespan(delay), Some(deadline));
+        assert_eq!(deadline.checked_sub_timespan(delay), Some(start));
+
+        assert_eq!(start.clamp(deadline, DateTime::MAX), deadline);
+        assert_eq!(deadline.clamp(start, start), start);
+        assert_eq!(cursor.clamp(start, deadline), cursor);
+    }
+
+    #[test]
+    fn test_overflow_saturates_or_fails() {
+        let near_max = DateTime::from_ticks(i64::MAX - 10);
+        let hour = Timespan::from_hours(1.0);
+        assert_eq!(near_max + hour, DateTime::MAX);
+        assert_eq!(near_max.add_timespan(hour), DateTime::MAX);
+        assert_eq!(DateTime::MIN - hour, DateTime::MIN);
+        assert_eq!(DateTime::MIN + -hour, DateTime::MIN);
+        assert_eq!(near_max.checked_add_timespan(hour), None);
+        assert_eq!(DateTime::MIN.checked_sub_timespan(hour), None);
+        assert_eq!(near_max.checked_add_timespan(Timespan::from_ticks(10)), Some(DateTime::MAX));
+
+        assert_eq!(DateTime::MAX - DateTime::MIN, Timespan::MAX);
+        assert_eq!(DateTime::MIN - DateTime::MAX, Timespan::MIN);
+    }
+}
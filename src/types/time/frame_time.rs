@@ -0,0 +1,297 @@
+//! Conversions between server frame numbers and Timespan/DateTime
+
+use crate::BinarySerializable;
+use super::{DateTime, Timespan};
+use serde::{Deserialize, Serialize};
+use core::fmt;
+#[cfg(not(feature = "std"))]
+use crate::types::math::float_math::FloatMath;
+
+/// Reason a `FrameTime` could not be created
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FrameTimeError {
+    /// Tick rates must be positive and finite
+    InvalidTickRate(f64),
+}
+
+impl fmt::Display for FrameTimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FrameTimeError::InvalidTickRate(rate) => write!(f, "tick rate {} must be positive and finite", rate),
+        }
+    }
+}
+
+impl core::error::Error for FrameTimeError {}
+
+/// How a time between two frames is turned into a frame number
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum FrameRounding {
+    /// The frame at or before the time
+    Floor,
+    /// The closest frame
+    #[default]
+    Nearest,
+    /// The frame at or after the time
+    Ceil,
+}
+
+/// Serialized form of a FrameTime
+#[derive(Serialize, Deserialize)]
+struct FrameTimeRepr {
+    tick_rate: f64,
+    anchor_time: DateTime,
+    anchor_frame: u32,
+}
+
+/// Maps `u32` server frames to times at a fixed tick rate
+///
+/// Frame 0 is time zero for `frame_to_time`. For wall-clock times, `anchor`
+/// pins one frame to a `DateTime`; until then frame 0 is the Unix epoch.
+/// Frame counters wrap at `u32::MAX`, so anchored conversions treat frames
+/// within 2^31 of the anchor as the nearest occurrence of that number, as
+/// `frame_delta` does.
+///
+/// ```
+/// use ue_types::*;
+///
+/// let mut frames = FrameTime::new(30.0).unwrap();
+/// assert_eq!(frames.frame_to_time(45), Timespan::from_seconds(1.5));
+///
+/// let start = DateTime::from_unix_timestamp(1_700_000_000);
+/// frames.anchor(start, 1000);
+/// assert_eq!(frames.frame_to_datetime(1030), start + Timespan::from_seconds(1.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(into = "FrameTimeRepr", try_from = "FrameTimeRepr")]
+pub struct FrameTime {
+    tick_rate: f64,
+    anchor_time: DateTime,
+    anchor_frame: u32,
+}
+
+impl fmt::Display for FrameTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "FrameTime({} fps, Frame {} at {})", self.tick_rate, self.anchor_frame, self.anchor_time)
+    }
+}
+
+impl BinarySerializable for FrameTime {}
+
+impl FrameTime {
+    /// Create a converter for `tick_rate` frames per second, anchored at the Unix epoch
+    pub fn new(tick_rate: f64) -> Result<Self, FrameTimeError> {
+        if !(tick_rate.is_finite() && tick_rate > 0.0) {
+            return Err(FrameTimeError::InvalidTickRate(tick_rate));
+        }
+        Ok(Self {
+            tick_rate,
+            anchor_time: DateTime::from_ticks(0),
+            anchor_frame: 0,
+        })
+    }
+
+    /// Frames per second
+    pub fn tick_rate(&self) -> f64 {
+        self.tick_rate
+    }
+
+    /// Length of one frame, rounded to the nearest tick
+    pub fn frame_duration(&self) -> Timespan {
+        self.frames_to_timespan(1)
+    }
+
+    /// Time from frame 0 to `frame`
+    pub fn frame_to_time(&self, frame: u32) -> Timespan {
+        self.frames_to_timespan(i64::from(frame))
+    }
+
+    /// Frame at `time` after frame 0, wrapping at `u32::MAX` like the frame counter
+    ///
+    /// Times within one tick of a frame count as on it, so converting the
+    /// result of `frame_to_time` back gives the same frame for every rounding.
+    pub fn time_to_frame(&self, time: Timespan, rounding: FrameRounding) -> u32 {
+        self.timespan_to_frames(time, rounding) as u32
+    }
+
+    /// Signed number of frames from `from` to `to`, across wraparound
+    ///
+    /// Differences are taken modulo 2^32 and read as the shortest way round,
+    /// so frame 1 is 2 frames after `u32::MAX`.
+    pub fn frame_delta(from: u32, to: u32) -> i64 {
+        i64::from(to.wrapping_sub(from) as i32)
+    }
+
+    /// Time from frame `from` to frame `to`, across wraparound
+    pub fn frame_delta_time(&self, from: u32, to: u32) -> Timespan {
+        self.frames_to_timespan(Self::frame_delta(from, to))
+    }
+
+    /// Pin `frame` to the wall-clock time `time`
+    pub fn anchor(&mut self, time: DateTime, frame: u32) {
+        self.anchor_time = time;
+        self.anchor_frame = frame;
+    }
+
+    /// Set the anchor, returning the converter
+    pub fn with_anchor(mut self, time: DateTime, frame: u32) -> Self {
+        self.anchor(time, frame);
+        self
+    }
+
+    /// The anchored time and frame
+    pub fn anchor_point(&self) -> (DateTime, u32) {
+        (self.anchor_time, self.anchor_frame)
+    }
+
+    /// Wall-clock time of `frame`
+    pub fn frame_to_datetime(&self, frame: u32) -> DateTime {
+        self.anchor_time.add_timespan(self.frame_delta_time(self.anchor_frame, frame))
+    }
+
+    /// Frame at wall-clock time `time`
+    pub fn datetime_to_frame(&self, time: DateTime, rounding: FrameRounding) -> u32 {
+        let frames = self.timespan_to_frames(time.difference(self.anchor_time), rounding);
+        self.anchor_frame.wrapping_add(frames as u32)
+    }
+
+    fn frames_to_timespan(&self, frames: i64) -> Timespan {
+        let ticks = frames as f64 * DateTime::TICKS_PER_SECOND as f64 / self.tick_rate;
+        Timespan::from_ticks(ticks.round() as i64)
+    }
+
+    fn timespan_to_frames(&self, time: Timespan, rounding: FrameRounding) -> i64 {
+        let frames = time.ticks as f64 * self.tick_rate / DateTime::TICKS_PER_SECOND as f64;
+        let nearest = frames.round();
+        // One tick, in frames: how far off frame_to_time's rounding can leave a frame boundary
+        if (frames - nearest).abs() <= self.tick_rate / DateTime::TICKS_PER_SECOND as f64 {
+            return nearest as i64;
+        }
+        let rounded = match rounding {
+            FrameRounding::Floor => frames.floor(),
+            FrameRounding::Nearest => nearest,
+            FrameRounding::Ceil => frames.ceil(),
+        };
+        rounded as i64
+    }
+}
+
+impl From<FrameTime> for FrameTimeRepr {
+    fn from(frame_time: FrameTime) -> Self {
+        Self {
+            tick_rate: frame_time.tick_rate,
+            anchor_time: frame_time.anchor_time,
+            anchor_frame: frame_time.anchor_frame,
+        }
+    }
+}
+
+impl TryFrom<FrameTimeRepr> for FrameTime {
+    type Error = FrameTimeError;
+
+    fn try_from(repr: FrameTimeRepr) -> Result<Self, Self::Error> {
+        Ok(Self::new(repr.tick_rate)?.with_anchor(repr.anchor_time, repr.anchor_frame))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_frame_time_conversion() {
+        let frames = FrameTime::new(60.0).unwrap();
+        assert_eq!(frames.frame_to_time(0), Timespan::ZERO);
+        assert_eq!(frames.frame_to_time(90), Timespan::from_seconds(1.5));
+        assert_eq!(frames.frame_duration(), Timespan::from_ticks(166_667));
+
+        let time = Timespan::from_milliseconds(1010.0);
+        assert_eq!(frames.time_to_frame(time, FrameRounding::Floor), 60);
+        assert_eq!(frames.time_to_frame(time, FrameRounding::Nearest), 61);
+        assert_eq!(frames.time_to_frame(time, FrameRounding::Ceil), 61);
+        assert_eq!(frames.time_to_frame(Timespan::from_milliseconds(1005.0), FrameRounding::Nearest), 60);
+
+        // Exactly on a frame, every rounding agrees
+        for rounding in [FrameRounding::Floor, FrameRounding::Nearest, FrameRounding::Ceil] {
+            assert_eq!(frames.time_to_frame(Timespan::from_seconds(2.0), rounding), 120);
+        }
+    }
+
+    #[test]
+    fn test_fractional_tick_rate() {
+        let frames = FrameTime::new(29.97).unwrap();
+        assert_eq!(frames.frame_to_time(2997), Timespan::from_seconds(100.0));
+        assert_eq!(frames.frame_to_time(1), Timespan::from_ticks(333_667));
+
+        // frame_to_time rounds to whole ticks; converting back still lands on the frame
+        for frame in [1, 2, 7, 29, 30, 1_000, 123_456, u32::MAX] {
+            let time = frames.frame_to_time(frame);
+            for rounding in [FrameRounding::Floor, FrameRounding::Nearest, FrameRounding::Ceil] {
+                assert_eq!(frames.time_to_frame(time, rounding), frame, "frame {} {:?}", frame, rounding);
+            }
+        }
+
+        let one_second = Timespan::from_seconds(1.0);
+        assert_eq!(frames.time_to_frame(one_second, FrameRounding::Floor), 29);
+        assert_eq!(frames.time_to_frame(one_second, FrameRounding::Nearest), 30);
+        assert_eq!(frames.time_to_frame(one_second, FrameRounding::Ceil), 30);
+    }
+
+    #[test]
+    fn test_frame_delta_wraparound() {
+        assert_eq!(FrameTime::frame_delta(10, 25), 15);
+        assert_eq!(FrameTime::frame_delta(25, 10), -15);
+        assert_eq!(FrameTime::frame_delta(u32::MAX, 1), 2);
+        assert_eq!(FrameTime::frame_delta(1, u32::MAX), -2);
+        assert_eq!(FrameTime::frame_delta(u32::MAX - 4, u32::MAX), 4);
+
+        let frames = FrameTime::new(30.0).unwrap();
+        assert_eq!(frames.frame_delta_time(u32::MAX - 29, 0), Timespan::from_seconds(1.0));
+        assert_eq!(frames.frame_delta_time(0, u32::MAX - 29), Timespan::from_seconds(-1.0));
+
+        // Times past the end of the counter wrap like it does
+        let past_end = frames.frame_to_time(u32::MAX) + frames.frame_duration() * 3.0;
+        assert_eq!(frames.time_to_frame(past_end, FrameRounding::Nearest), 2);
+    }
+
+    #[test]
+    fn test_datetime_anchor() {
+        let start = DateTime::from_unix_timestamp(1_700_000_000);
+        let mut frames = FrameTime::new(20.0).unwrap();
+        assert_eq!(frames.frame_to_datetime(20), DateTime::from_unix_timestamp(1));
+
+        frames.anchor(start, 500);
+        assert_eq!(frames.anchor_point(), (start, 500));
+        assert_eq!(frames.frame_to_datetime(500), start);
+        assert_eq!(frames.frame_to_datetime(540), start + Timespan::from_seconds(2.0));
+        assert_eq!(frames.frame_to_datetime(480), start - Timespan::from_seconds(1.0));
+        assert_eq!(frames.datetime_to_frame(start + Timespan::from_milliseconds(1520.0), FrameRounding::Floor), 530);
+        assert_eq!(frames.datetime_to_frame(start + Timespan::from_milliseconds(1520.0), FrameRounding::Ceil), 531);
+
+        // Anchored just before the counter wraps
+        let wrapping = FrameTime::new(20.0).unwrap().with_anchor(start, u32::MAX - 9);
+        assert_eq!(wrapping.frame_to_datetime(10), start + Timespan::from_seconds(1.0));
+        assert_eq!(wrapping.datetime_to_frame(start + Timespan::from_seconds(1.0), FrameRounding::Nearest), 10);
+        assert_eq!(wrapping.datetime_to_frame(start - Timespan::from_seconds(1.0), FrameRounding::Nearest), u32::MAX - 29);
+    }
+
+    #[test]
+    fn test_invalid_tick_rate() {
+        for rate in [0.0, -30.0, f64::NAN, f64::INFINITY] {
+            assert!(FrameTime::new(rate).is_err());
+        }
+        assert_eq!(FrameTime::new(0.0).unwrap_err().to_string(), "tick rate 0 must be positive and finite");
+    }
+
+    #[test]
+    fn test_serialization() {
+        let frames = FrameTime::new(29.97).unwrap().with_anchor(DateTime::from_unix_timestamp(1_000), 77);
+        let json = serde_json::to_string(&frames).unwrap();
+        assert_eq!(serde_json::from_str::<FrameTime>(&json).unwrap(), frames);
+        assert_eq!(FrameTime::from_binary(&frames.to_binary().unwrap()).unwrap(), frames);
+
+        let invalid = json.replace("29.97", "-1.0");
+        assert!(serde_json::from_str::<FrameTime>(&invalid).is_err());
+    }
+}
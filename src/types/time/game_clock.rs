@@ -0,0 +1,453 @@
+//! Server tick clock with pause, time dilation and fixed-timestep accumulation
+
+use crate::BinarySerializable;
+use super::Timespan;
+use serde::{Deserialize, Serialize};
+use core::fmt;
+
+/// Reason a `GameClock` can't be built or loaded
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GameClockError {
+    /// Fixed timesteps must be positive
+    InvalidFixedTimestep(Timespan),
+    /// Time dilation must be finite and not negative
+    InvalidTimeDilation(f64),
+    /// The fixed-step accumulator can't be negative
+    NegativeAccumulator(Timespan),
+}
+
+impl fmt::Display for GameClockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameClockError::InvalidFixedTimestep(step) => write!(f, "fixed timestep {} must be positive", step),
+            GameClockError::InvalidTimeDilation(dilation) => {
+                write!(f, "time dilation {} must be finite and not negative", dilation)
+            }
+            GameClockError::NegativeAccumulator(accumulator) => {
+                write!(f, "fixed-step accumulator {} is negative", accumulator)
+            }
+        }
+    }
+}
+
+impl core::error::Error for GameClockError {}
+
+/// Serialized form of `GameClock`, checked on load
+#[derive(Serialize, Deserialize)]
+struct GameClockRepr {
+    running: bool,
+    paused: bool,
+    time_dilation: f64,
+    real_time: Timespan,
+    game_time: Timespan,
+    delta_time: Timespan,
+    tick_count: u64,
+    fixed_timestep: Option<Timespan>,
+    max_fixed_steps: u32,
+    accumulator: Timespan,
+}
+
+/// Tick clock for a game server loop
+///
+/// Feed it the measured real frame time with `tick`; it tracks real time,
+/// dilated game time and, when a fixed timestep is set, how many fixed
+/// updates are due. The clock only advances when told to, so it is
+/// deterministic and can be saved and restored with the rest of the game.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(into = "GameClockRepr", try_from = "GameClockRepr")]
+pub struct GameClock {
+    running: bool,
+    paused: bool,
+    time_dilation: f64,
+    real_time: Timespan,
+    game_time: Timespan,
+    delta_time: Timespan,
+    tick_count: u64,
+    fixed_timestep: Option<Timespan>,
+    max_fixed_steps: u32,
+    accumulator: Timespan,
+}
+
+impl fmt::Display for GameClock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "GameClock(Ticks: {}, Game: {}, Real: {}, Dilation: {:.2})",
+            self.tick_count,
+            self.game_time.to_string_hms(),
+            self.real_time.to_string_hms(),
+            self.time_dilation
+        )?;
+        if self.paused {
+            write!(f, " [Paused]")?;
+        }
+        Ok(())
+    }
+}
+
+impl BinarySerializable for GameClock {}
+
+impl GameClock {
+    /// Default cap on fixed updates per frame
+    pub const DEFAULT_MAX_FIXED_STEPS: u32 = 8;
+
+    /// Create a stopped clock at zero with no dilation and no fixed timestep
+    pub fn new() -> Self {
+        Self {
+            running: false,
+            paused: false,
+            time_dilation: 1.0,
+            real_time: Timespan::ZERO,
+            game_time: Timespan::ZERO,
+            delta_time: Timespan::ZERO,
+            tick_count: 0,
+            fixed_timestep: None,
+            max_fixed_steps: Self::DEFAULT_MAX_FIXED_STEPS,
+            accumulator: Timespan::ZERO,
+        }
+    }
+
+    /// Create a stopped clock that runs fixed updates of length `step`
+    pub fn with_fixed_timestep(step: Timespan) -> Result<Self, GameClockError> {
+        if !step.is_positive() {
+            return Err(GameClockError::InvalidFixedTimestep(step));
+        }
+        let mut clock = Self::new();
+        clock.fixed_timestep = Some(step);
+        Ok(clock)
+    }
+
+    /// Start the clock; ticks before this are ignored
+    pub fn start(&mut self) {
+        self.running = true;
+        self.paused = false;
+    }
+
+    /// Pause game time; real time keeps running
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Resume game time after `pause`
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    /// Check if the clock has been started
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+
+    /// Check if game time is paused
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Advance by one frame of real time
+    ///
+    /// Game time advances by `real_delta` times the time dilation, unless
+    /// paused. Negative deltas are treated as zero. Does nothing until
+    /// `start` is called.
+    pub fn tick(&mut self, real_delta: Timespan) {
+        if !self.running {
+            return;
+        }
+        let real_delta = real_delta.max(Timespan::ZERO);
+        self.real_time += real_delta;
+        self.tick_count += 1;
+
+        self.delta_time = if self.paused {
+            Timespan::ZERO
+        } else {
+            real_delta * self.time_dilation
+        };
+        self.game_time += self.delta_time;
+        if self.fixed_timestep.is_some() {
+            self.accumulator += self.delta_time;
+        }
+    }
+
+    /// Game time elapsed since start (dilated, excluding pauses)
+    pub fn game_time(&self) -> Timespan {
+        self.game_time
+    }
+
+    /// Real time elapsed since start
+    pub fn real_time(&self) -> Timespan {
+        self.real_time
+    }
+
+    /// Game time advanced by the last tick
+    pub fn delta_time(&self) -> Timespan {
+        self.delta_time
+    }
+
+    /// Frames ticked since start, including paused ones
+    pub fn tick_count(&self) -> u64 {
+        self.tick_count
+    }
+
+    /// Game seconds per real second
+    pub fn time_dilation(&self) -> f64 {
+        self.time_dilation
+    }
+
+    /// Set game seconds per real second; negative values become 0 and non-finite ones are ignored
+    pub fn set_time_dilation(&mut self, dilation: f64) {
+        if dilation.is_finite() {
+            self.time_dilation = dilation.max(0.0);
+        }
+    }
+
+    /// Length of a fixed update, if fixed stepping is enabled
+    pub fn fixed_timestep(&self) -> Option<Timespan> {
+        self.fixed_timestep
+    }
+
+    /// Enable fixed stepping; a zero or negative step disables it
+    pub fn set_fixed_timestep(&mut self, step: Timespan) {
+        if step.is_positive() {
+            self.fixed_timestep = Some(step);
+        } else {
+            self.fixed_timestep = None;
+            self.accumulator = Timespan::ZERO;
+        }
+    }
+
+    /// Most fixed updates `consume_fixed_steps` returns in one frame
+    pub fn max_fixed_steps(&self) -> u32 {
+        self.max_fixed_steps
+    }
+
+    /// Set the most fixed updates run in one frame
+    pub fn set_max_fixed_steps(&mut self, max_steps: u32) {
+        self.max_fixed_steps = max_steps;
+    }
+
+    /// Number of fixed updates to run this frame
+    ///
+    /// Removes that many steps from the accumulated game time. When more
+    /// than `max_fixed_steps` are due (after a hitch), the backlog beyond
+    /// the cap is dropped instead of carried over, so a slow frame can't
+    /// snowball into ever slower frames. Returns 0 without a fixed timestep.
+    pub fn consume_fixed_steps(&mut self) -> u32 {
+        let Some(step) = self.fixed_timestep.filter(|step| step.is_positive()) else {
+            return 0;
+        };
+        let due = self.accumulator.ticks / step.ticks;
+        if due > self.max_fixed_steps as i64 {
+            self.accumulator = Timespan::from_ticks(self.accumulator.ticks % step.ticks);
+            return self.max_fixed_steps;
+        }
+        self.accumulator -= Timespan::from_ticks(due * step.ticks);
+        due as u32
+    }
+
+    /// How far into the next fixed step the accumulated time is, in [0, 1)
+    ///
+    /// Useful for interpolating rendered or replicated state between fixed updates.
+    pub fn fixed_step_alpha(&self) -> f64 {
+        match self.fixed_timestep.filter(|step| step.is_positive()) {
+            Some(step) => self.accumulator.ticks as f64 / step.ticks as f64,
+            None => 0.0,
+        }
+    }
+}
+
+impl Default for GameClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<GameClock> for GameClockRepr {
+    fn from(clock: GameClock) -> Self {
+        Self {
+            running: clock.running,
+            paused: clock.paused,
+            time_dilation: clock.time_dilation,
+            real_time: clock.real_time,
+            game_time: clock.game_time,
+            delta_time: clock.delta_time,
+            tick_count: clock.tick_count,
+            fixed_timestep: clock.fixed_timestep,
+            max_fixed_steps: clock.max_fixed_steps,
+            accumulator: clock.accumulator,
+        }
+    }
+}
+
+impl TryFrom<GameClockRepr> for GameClock {
+    type Error = GameClockError;
+
+    fn try_from(repr: GameClockRepr) -> Result<Self, Self::Error> {
+        if let Some(step) = repr.fixed_timestep.filter(|step| !step.is_positive()) {
+            return Err(GameClockError::InvalidFixedTimestep(step));
+        }
+        if !(repr.time_dilation.is_finite() && repr.time_dilation >= 0.0) {
+            return Err(GameClockError::InvalidTimeDilation(repr.time_dilation));
+        }
+        if repr.accumulator < Timespan::ZERO {
+            return Err(GameClockError::NegativeAccumulator(repr.accumulator));
+        }
+        Ok(Self {
+            running: repr.running,
+            paused: repr.paused,
+            time_dilation: repr.time_dilation,
+            real_time: repr.real_time,
+            game_time: repr.game_time,
+            delta_time: repr.delta_time,
+            tick_count: repr.tick_count,
+            fixed_timestep: repr.fixed_timestep,
+            max_fixed_steps: repr.max_fixed_steps,
+            accumulator: repr.accumulator,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Irregular frame times in milliseconds, including hitches
+    const FRAME_TIMES_MS: [f64; 8] = [16.7, 33.3, 8.1, 16.6, 50.0, 1.2, 16.7, 24.9];
+
+    #[test]
+    fn test_irregular_frames_accumulate_fixed_steps() {
+        let step = Timespan::from_seconds(1.0 / 60.0);
+        let mut clock = GameClock::new();
+        clock.set_fixed_timestep(step);
+        clock.start();
+
+        let mut real_total = Timespan::ZERO;
+        let mut total_steps = 0_i64;
+        for frame in 0..10_000 {
+            let delta = Timespan::from_milliseconds(FRAME_TIMES_MS[frame % FRAME_TIMES_MS.len()]);
+            real_total += delta;
+            clock.tick(delta);
+            total_steps += clock.consume_fixed_steps() as i64;
+
+            // Fixed updates never run ahead of game time and lag it by less than one step
+            let simulated = Timespan::from_ticks(total_steps * step.ticks);
+            assert!(simulated <= clock.game_time());
+            assert!(clock.game_time() - simulated < step);
+            assert!((0.0..1.0).contains(&clock.fixed_step_alpha()));
+        }
+
+        assert_eq!(clock.game_time(), real_total);
+        assert_eq!(clock.real_time(), real_total);
+        assert_eq!(clock.tick_count(), 10_000);
+    }
+
+    #[test]
+    fn test_max_steps_drop_backlog() {
+        let step = Timespan::from_milliseconds(10.0);
+        let mut clock = GameClock::new();
+        clock.set_fixed_timestep(step);
+        clock.set_max_fixed_steps(5);
+        clock.start();
+
+        clock.tick(Timespan::from_milliseconds(2005.0));
+        assert_eq!(clock.consume_fixed_steps(), 5);
+        assert!((clock.fixed_step_alpha() - 0.5).abs() < 1e-9);
+        assert_eq!(clock.consume_fixed_steps(), 0);
+
+        clock.tick(Timespan::from_milliseconds(25.0));
+        assert_eq!(clock.consume_fixed_steps(), 3);
+
+        clock.set_fixed_timestep(Timespan::ZERO);
+        assert_eq!(clock.fixed_timestep(), None);
+        clock.tick(Timespan::from_milliseconds(100.0));
+        assert_eq!(clock.consume_fixed_steps(), 0);
+    }
+
+    #[test]
+    fn test_pause_and_dilation() {
+        let frame = Timespan::from_milliseconds(20.0);
+        let mut clock = GameClock::new();
+        clock.tick(frame);
+        assert_eq!(clock.tick_count(), 0);
+        assert!(!clock.is_running());
+
+        clock.start();
+        clock.tick(frame);
+        clock.pause();
+        clock.tick(frame);
+        assert!(clock.is_paused());
+        assert_eq!(clock.delta_time(), Timespan::ZERO);
+        assert_eq!(clock.game_time(), frame);
+        assert_eq!(clock.real_time(), frame * 2.0);
+
+        clock.resume();
+        clock.set_time_dilation(0.5);
+        clock.tick(frame);
+        assert_eq!(clock.delta_time(), frame / 2.0);
+        assert_eq!(clock.game_time(), frame * 1.5);
+        assert_eq!(clock.tick_count(), 3);
+
+        clock.tick(-frame);
+        assert_eq!(clock.real_time(), frame * 3.0);
+        clock.set_time_dilation(f64::NAN);
+        assert_eq!(clock.time_dilation(), 0.5);
+        clock.set_time_dilation(-2.0);
+        assert_eq!(clock.time_dilation(), 0.0);
+    }
+
+    #[test]
+    fn test_saved_clock_resumes_identically() {
+        let mut clock = GameClock::new();
+        clock.set_fixed_timestep(Timespan::from_seconds(1.0 / 30.0));
+        clock.set_time_dilation(1.25);
+        clock.start();
+        for &ms in &FRAME_TIMES_MS[..5] {
+            clock.tick(Timespan::from_milliseconds(ms));
+        }
+        clock.consume_fixed_steps();
+        clock.pause();
+
+        let json = serde_json::to_string(&clock).unwrap();
+        let mut from_json: GameClock = serde_json::from_str(&json).unwrap();
+        let mut from_binary = GameClock::from_binary(&clock.to_binary().unwrap()).unwrap();
+        assert_eq!(from_json, clock);
+        assert_eq!(from_binary, clock);
+
+        for restored in [&mut clock, &mut from_json, &mut from_binary] {
+            restored.resume();
+            restored.tick(Timespan::from_milliseconds(70.0));
+        }
+        assert_eq!(from_json.consume_fixed_steps(), clock.consume_fixed_steps());
+        assert_eq!(from_json, clock);
+        assert_eq!(from_binary.game_time(), clock.game_time());
+        assert!(format!("{}", clock).starts_with("GameClock(Ticks: 6, Game: 00:00:00, Real: 00:00:00"));
+    }
+
+    #[test]
+    fn test_invalid_fixed_timestep_rejected() {
+        assert_eq!(
+            GameClock::with_fixed_timestep(Timespan::ZERO),
+            Err(GameClockError::InvalidFixedTimestep(Timespan::ZERO))
+        );
+        let step = Timespan::from_milliseconds(10.0);
+        assert_eq!(GameClock::with_fixed_timestep(step).unwrap().fixed_timestep(), Some(step));
+
+        let mut json = serde_json::to_value(GameClock::with_fixed_timestep(step).unwrap()).unwrap();
+        json["fixed_timestep"] = serde_json::to_value(Timespan::ZERO).unwrap();
+        let error = serde_json::from_value::<GameClock>(json.clone()).unwrap_err();
+        assert!(error.to_string().contains("must be positive"));
+        json["fixed_timestep"] = serde_json::to_value(-step).unwrap();
+        assert!(serde_json::from_value::<GameClock>(json.clone()).is_err());
+        json["fixed_timestep"] = serde_json::to_value(step).unwrap();
+        json["time_dilation"] = serde_json::json!(-1.0);
+        assert!(serde_json::from_value::<GameClock>(json.clone()).is_err());
+        json["time_dilation"] = serde_json::json!(1.0);
+        json["accumulator"] = serde_json::to_value(-step).unwrap();
+        assert!(serde_json::from_value::<GameClock>(json).is_err());
+
+        // A clock that got a bad step some other way still terminates
+        let mut clock = GameClock::new();
+        clock.fixed_timestep = Some(Timespan::ZERO);
+        clock.accumulator = step;
+        assert_eq!(clock.consume_fixed_steps(), 0);
+        assert_eq!(clock.fixed_step_alpha(), 0.0);
+    }
+}
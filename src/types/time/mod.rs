@@ -2,10 +2,15 @@
 
 pub mod datetime;
 pub mod timespan;
+pub mod game_clock;
+pub mod frame_time;
+#[cfg(feature = "std")]
+pub mod clock;
 
 // Re-export all types for convenience
 pub use datetime::*;
 pub use timespan::*;
-
-
-pub use timespan::Timespan;
\ No newline at end of file
+pub use game_clock::*;
+pub use frame_time::*;
+#[cfg(feature = "std")]
+pub use clock::*;
\ No newline at end of file
@@ -1,8 +1,47 @@
 //! UE5-style Timespan for durations and time intervals
 
 use crate::BinarySerializable;
+use super::DateTime;
 use serde::{Deserialize, Serialize};
-use std::fmt;
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+use core::time::Duration;
+use alloc::string::{String, ToString};
+use alloc::format;
+#[cfg(not(feature = "std"))]
+use crate::types::math::float_math::FloatMath;
+
+/// Reason a timespan string couldn't be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimespanParseError {
+    /// Nothing to parse
+    Empty,
+    /// Not `[-][d.]hh:mm:ss[.fffffff]` or a unit list like `2h30m`
+    InvalidFormat(String),
+    /// A clock component is too large, e.g. 75 minutes in `01:75:00`
+    ComponentOutOfRange { unit: &'static str, value: u64 },
+    /// The total doesn't fit in a Timespan
+    Overflow,
+}
+
+impl fmt::Display for TimespanParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TimespanParseError::Empty => write!(f, "empty timespan"),
+            TimespanParseError::InvalidFormat(text) => write!(
+                f,
+                "invalid timespan \"{}\" (expected [-][d.]hh:mm:ss[.fff] or units like 2h30m)",
+                text
+            ),
+            TimespanParseError::ComponentOutOfRange { unit, value } => {
+                write!(f, "{} component {} is out of range", unit, value)
+            }
+            TimespanParseError::Overflow => write!(f, "timespan is too large"),
+        }
+    }
+}
+
+impl core::error::Error for TimespanParseError {}
 
 /// UE5-style Timespan for durations and time intervals
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
@@ -48,67 +87,154 @@ impl Timespan {
 
     /// Create Timespan from days
     pub fn from_days(days: f64) -> Self {
-        Self::from_ticks((days * 86400.0 * super::DateTime::TICKS_PER_SECOND as f64) as i64)
+        Self::from_ticks((days * 86400.0 * DateTime::TICKS_PER_SECOND as f64) as i64)
     }
 
     /// Create Timespan from hours
     pub fn from_hours(hours: f64) -> Self {
-        Self::from_ticks((hours * 3600.0 * super::DateTime::TICKS_PER_SECOND as f64) as i64)
+        Self::from_ticks((hours * 3600.0 * DateTime::TICKS_PER_SECOND as f64) as i64)
     }
 
     /// Create Timespan from minutes
     pub fn from_minutes(minutes: f64) -> Self {
-        Self::from_ticks((minutes * 60.0 * super::DateTime::TICKS_PER_SECOND as f64) as i64)
+        Self::from_ticks((minutes * 60.0 * DateTime::TICKS_PER_SECOND as f64) as i64)
     }
 
     /// Create Timespan from seconds
     pub fn from_seconds(seconds: f64) -> Self {
-        Self::from_ticks((seconds * super::DateTime::TICKS_PER_SECOND as f64) as i64)
+        Self::from_ticks((seconds * DateTime::TICKS_PER_SECOND as f64) as i64)
     }
 
     /// Create Timespan from milliseconds
     pub fn from_milliseconds(milliseconds: f64) -> Self {
-        Self::from_ticks((milliseconds * super::DateTime::TICKS_PER_MILLISECOND as f64) as i64)
+        Self::from_ticks((milliseconds * DateTime::TICKS_PER_MILLISECOND as f64) as i64)
     }
 
     /// Get total days as floating point
     pub fn total_days(self) -> f64 {
-        self.ticks as f64 / (super::DateTime::TICKS_PER_SECOND as f64 * 86400.0)
+        self.ticks as f64 / (DateTime::TICKS_PER_SECOND as f64 * 86400.0)
     }
 
     /// Get total hours as floating point
     pub fn total_hours(self) -> f64 {
-        self.ticks as f64 / (super::DateTime::TICKS_PER_SECOND as f64 * 3600.0)
+        self.ticks as f64 / (DateTime::TICKS_PER_SECOND as f64 * 3600.0)
     }
 
     /// Get total minutes as floating point
     pub fn total_minutes(self) -> f64 {
-        self.ticks as f64 / (super::DateTime::TICKS_PER_SECOND as f64 * 60.0)
+        self.ticks as f64 / (DateTime::TICKS_PER_SECOND as f64 * 60.0)
     }
 
     /// Get total seconds as floating point
     pub fn total_seconds(self) -> f64 {
-        self.ticks as f64 / super::DateTime::TICKS_PER_SECOND as f64
+        self.ticks as f64 / DateTime::TICKS_PER_SECOND as f64
     }
 
     /// Get total milliseconds as floating point
     pub fn total_milliseconds(self) -> f64 {
-        self.ticks as f64 / super::DateTime::TICKS_PER_MILLISECOND as f64
+        self.ticks as f64 / DateTime::TICKS_PER_MILLISECOND as f64
+    }
+
+    /// Create from a std Duration, saturating at `MAX`
+    pub fn from_duration(duration: Duration) -> Self {
+        let ticks = duration.as_nanos() / 100;
+        Self::from_ticks(i64::try_from(ticks).unwrap_or(i64::MAX))
+    }
+
+    /// Convert to a std Duration; negative timespans give `Duration::ZERO`
+    pub fn to_duration(self) -> Duration {
+        if self.ticks <= 0 {
+            return Duration::ZERO;
+        }
+        let ticks = self.ticks as u64;
+        Duration::new(
+            ticks / DateTime::TICKS_PER_SECOND as u64,
+            (ticks % DateTime::TICKS_PER_SECOND as u64) as u32 * 100,
+        )
+    }
+
+    /// Whole days (UE: `GetDays`)
+    pub fn days(self) -> i32 {
+        (self.ticks / DateTime::TICKS_PER_DAY) as i32
+    }
+
+    /// Hours within the day, -23..=23 (UE: `GetHours`)
+    pub fn hours(self) -> i32 {
+        ((self.ticks / DateTime::TICKS_PER_HOUR) % 24) as i32
+    }
+
+    /// Minutes within the hour, -59..=59 (UE: `GetMinutes`)
+    pub fn minutes(self) -> i32 {
+        ((self.ticks / DateTime::TICKS_PER_MINUTE) % 60) as i32
+    }
+
+    /// Seconds within the minute, -59..=59 (UE: `GetSeconds`)
+    pub fn seconds(self) -> i32 {
+        ((self.ticks / DateTime::TICKS_PER_SECOND) % 60) as i32
+    }
+
+    /// Milliseconds within the second, -999..=999 (UE: `GetFractionMilli`)
+    pub fn milliseconds(self) -> i32 {
+        ((self.ticks / DateTime::TICKS_PER_MILLISECOND) % 1000) as i32
+    }
+
+    /// Format as zero-padded `hh:mm:ss`, e.g. `01:30:00` or `-00:00:05`
+    ///
+    /// Hours are total hours, so a timespan over a day prints `36:00:00`.
+    /// Fractions of a second are dropped. `parse` reads the result back.
+    pub fn to_string_hms(self) -> String {
+        let total_seconds = (self.ticks / DateTime::TICKS_PER_SECOND).unsigned_abs();
+        format!(
+            "{}{:02}:{:02}:{:02}",
+            if self.ticks <= -DateTime::TICKS_PER_SECOND { "-" } else { "" },
+            total_seconds / 3600,
+            total_seconds / 60 % 60,
+            total_seconds % 60
+        )
+    }
+
+    /// Parse a timespan from clock or unit notation
+    ///
+    /// Clock notation is `[-][d.]hh:mm:ss[.fffffff]`, like UE's
+    /// `FTimespan::Parse`; hours may exceed 23 when there is no day part.
+    /// Unit notation is a list of numbers with `d`, `h`, `m`, `s` or `ms`
+    /// suffixes, such as `90s`, `2h30m` or `-1.5h`.
+    pub fn parse(text: &str) -> Result<Self, TimespanParseError> {
+        let trimmed = text.trim();
+        let (negative, body) = match trimmed.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, trimmed.strip_prefix('+').unwrap_or(trimmed)),
+        };
+        if body.is_empty() {
+            return Err(TimespanParseError::Empty);
+        }
+        let invalid = || TimespanParseError::InvalidFormat(text.to_string());
+        if !body.is_ascii() {
+            return Err(invalid());
+        }
+
+        let ticks = if body.contains(':') {
+            parse_clock(body).ok_or_else(invalid)??
+        } else {
+            parse_units(body).ok_or_else(invalid)??
+        };
+        Ok(Self::from_ticks(if negative { -ticks } else { ticks }))
     }
 
-    /// Add another timespan
+    /// Add another timespan (saturating, same as `+`)
+    #[allow(clippy::should_implement_trait)]
     pub fn add(self, other: Timespan) -> Self {
-        Self::from_ticks(self.ticks + other.ticks)
+        self + other
     }
 
-    /// Subtract another timespan
+    /// Subtract another timespan (saturating, same as `-`)
     pub fn subtract(self, other: Timespan) -> Self {
-        Self::from_ticks(self.ticks - other.ticks)
+        self - other
     }
 
-    /// Get absolute value
+    /// Get absolute value (saturating, so `MIN` gives `MAX`)
     pub fn abs(self) -> Self {
-        Self::from_ticks(self.ticks.abs())
+        Self::from_ticks(self.ticks.saturating_abs())
     }
 
     /// Get duration (always positive)
@@ -116,6 +242,15 @@ impl Timespan {
         self.abs()
     }
 
+    /// Restrict to `[min, max]`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max`, like `Ord::clamp`.
+    pub fn clamp(self, min: Timespan, max: Timespan) -> Self {
+        Ord::clamp(self, min, max)
+    }
+
     /// Check if timespan is negative
     pub fn is_negative(self) -> bool {
         self.ticks < 0
@@ -138,6 +273,159 @@ impl Default for Timespan {
     }
 }
 
+/// Parse `[d.]hh:mm:ss[.fffffff]` into ticks; `None` for a malformed string
+fn parse_clock(body: &str) -> Option<Result<i64, TimespanParseError>> {
+    let number = |digits: &str| -> Option<u64> {
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        digits.parse().ok()
+    };
+
+    // A dot after the last colon starts the fraction; one before the first colon ends the days
+    let last_colon = body.rfind(':')?;
+    let (head, fraction) = match body[last_colon..].find('.') {
+        Some(dot) => (&body[..last_colon + dot], Some(&body[last_colon + dot + 1..])),
+        None => (body, None),
+    };
+    let (days, clock) = match head.split_once('.') {
+        Some((days, clock)) => (Some(number(days)?), clock),
+        None => (None, head),
+    };
+    let mut parts = clock.split(':');
+    let (hours, minutes, seconds) = (number(parts.next()?)?, number(parts.next()?)?, number(parts.next()?)?);
+    if parts.next().is_some() {
+        return None;
+    }
+    let fraction_ticks = match fraction {
+        Some(digits) => {
+            number(digits)?;
+            // Seven digits are ticks; further digits are below tick precision
+            digits.bytes().chain(core::iter::repeat(b'0')).take(7).fold(0, |ticks, b| ticks * 10 + (b - b'0') as i64)
+        }
+        None => 0,
+    };
+
+    for (unit, value, limit) in [
+        ("hours", hours, if days.is_some() { 23 } else { u64::MAX }),
+        ("minutes", minutes, 59),
+        ("seconds", seconds, 59),
+    ] {
+        if value > limit {
+            return Some(Err(TimespanParseError::ComponentOutOfRange { unit, value }));
+        }
+    }
+
+    let total = [
+        (days.unwrap_or(0), DateTime::TICKS_PER_DAY),
+        (hours, DateTime::TICKS_PER_HOUR),
+        (minutes, DateTime::TICKS_PER_MINUTE),
+        (seconds, DateTime::TICKS_PER_SECOND),
+    ]
+    .iter()
+    .try_fold(fraction_ticks, |total, &(value, ticks_per_unit)| {
+        i64::try_from(value).ok()?.checked_mul(ticks_per_unit)?.checked_add(total)
+    });
+    Some(total.ok_or(TimespanParseError::Overflow))
+}
+
+/// Parse a unit list like `2h30m` or `1.5d` into ticks; `None` for a malformed string
+fn parse_units(body: &str) -> Option<Result<i64, TimespanParseError>> {
+    let mut total: i64 = 0;
+    let mut rest = body.trim_start();
+    while !rest.is_empty() {
+        let number_len = rest.find(|c: char| !c.is_ascii_digit() && c != '.')?;
+        let unit_len = rest[number_len..].find(|c: char| !c.is_ascii_alphabetic()).unwrap_or(rest.len() - number_len);
+        let (number, unit) = (&rest[..number_len], &rest[number_len..number_len + unit_len]);
+        let ticks_per_unit = match unit {
+            "d" => DateTime::TICKS_PER_DAY,
+            "h" => DateTime::TICKS_PER_HOUR,
+            "m" => DateTime::TICKS_PER_MINUTE,
+            "s" => DateTime::TICKS_PER_SECOND,
+            "ms" => DateTime::TICKS_PER_MILLISECOND,
+            _ => return None,
+        };
+        if number.is_empty() || number.starts_with('.') || number.ends_with('.') {
+            return None;
+        }
+
+        let ticks = if number.contains('.') {
+            let ticks = number.parse::<f64>().ok()? * ticks_per_unit as f64;
+            if ticks >= i64::MAX as f64 {
+                return Some(Err(TimespanParseError::Overflow));
+            }
+            ticks.round() as i64
+        } else {
+            match number.parse::<i64>().ok().and_then(|value| value.checked_mul(ticks_per_unit)) {
+                Some(ticks) => ticks,
+                None => return Some(Err(TimespanParseError::Overflow)),
+            }
+        };
+        total = match total.checked_add(ticks) {
+            Some(total) => total,
+            None => return Some(Err(TimespanParseError::Overflow)),
+        };
+        rest = rest[number_len + unit_len..].trim_start();
+    }
+    Some(Ok(total))
+}
+
+// Arithmetic saturates at MIN/MAX rather than wrapping or panicking
+
+impl Add for Timespan {
+    type Output = Timespan;
+
+    fn add(self, rhs: Timespan) -> Timespan {
+        Timespan::from_ticks(self.ticks.saturating_add(rhs.ticks))
+    }
+}
+
+impl AddAssign for Timespan {
+    fn add_assign(&mut self, rhs: Timespan) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Timespan {
+    type Output = Timespan;
+
+    fn sub(self, rhs: Timespan) -> Timespan {
+        Timespan::from_ticks(self.ticks.saturating_sub(rhs.ticks))
+    }
+}
+
+impl SubAssign for Timespan {
+    fn sub_assign(&mut self, rhs: Timespan) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Timespan {
+    type Output = Timespan;
+
+    fn neg(self) -> Timespan {
+        Timespan::from_ticks(self.ticks.saturating_neg())
+    }
+}
+
+/// Scale by a factor; NaN gives `ZERO`
+impl Mul<f64> for Timespan {
+    type Output = Timespan;
+
+    fn mul(self, rhs: f64) -> Timespan {
+        Timespan::from_ticks((self.ticks as f64 * rhs).round() as i64)
+    }
+}
+
+/// Divide by a factor; dividing by zero saturates, and NaN gives `ZERO`
+impl Div<f64> for Timespan {
+    type Output = Timespan;
+
+    fn div(self, rhs: f64) -> Timespan {
+        Timespan::from_ticks((self.ticks as f64 / rhs).round() as i64)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -152,4 +440,102 @@ mod tests {
         let sum = ts.add(ts2);
         assert_eq!(sum.total_hours(), 3.0);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_timespan_components() {
+        let ts = Timespan::parse("1.02:03:04.567").unwrap();
+        assert_eq!((ts.days(), ts.hours(), ts.minutes(), ts.seconds(), ts.milliseconds()), (1, 2, 3, 4, 567));
+        assert_eq!(ts.to_string_hms(), "26:03:04");
+
+        // Negative timespans have negative components, like UE
+        let negative = -ts;
+        assert_eq!(
+            (negative.days(), negative.hours(), negative.minutes(), negative.seconds(), negative.milliseconds()),
+            (-1, -2, -3, -4, -567)
+        );
+        assert_eq!(negative.to_string_hms(), "-26:03:04");
+        assert_eq!(Timespan::from_milliseconds(-500.0).to_string_hms(), "00:00:00");
+        assert_eq!(Timespan::from_seconds(-5.0).to_string_hms(), "-00:00:05");
+    }
+
+    #[test]
+    fn test_timespan_parse() {
+        assert_eq!(Timespan::parse("01:30:00"), Ok(Timespan::from_minutes(90.0)));
+        assert_eq!(Timespan::parse("90s"), Ok(Timespan::from_seconds(90.0)));
+        assert_eq!(Timespan::parse("2h30m"), Ok(Timespan::from_minutes(150.0)));
+        assert_eq!(Timespan::parse("1d 2h 500ms"), Ok(Timespan::from_hours(26.0) + Timespan::from_milliseconds(500.0)));
+        assert_eq!(Timespan::parse("-1.5h"), Ok(Timespan::from_minutes(-90.0)));
+        assert_eq!(Timespan::parse("-00:00:01.5"), Ok(Timespan::from_seconds(-1.5)));
+        assert_eq!(Timespan::parse("00:00:00.12345678"), Ok(Timespan::from_ticks(1_234_567)));
+        assert_eq!(Timespan::parse("36:00:00"), Ok(Timespan::from_hours(36.0)));
+
+        for ts in [Timespan::from_hours(26.5), Timespan::from_seconds(-3725.0), Timespan::ZERO] {
+            assert_eq!(Timespan::parse(&ts.to_string_hms()), Ok(ts));
+        }
+
+        assert_eq!(Timespan::parse("  "), Err(TimespanParseError::Empty));
+        assert_eq!(Timespan::parse("-"), Err(TimespanParseError::Empty));
+        for malformed in ["1:2", "1:2:3:4", "aa:00:00", "01:30:00.", "1.2.3:00:00", "90", "2x", "h", "1.h", "2h30", "5 m s"] {
+            assert_eq!(
+                Timespan::parse(malformed),
+                Err(TimespanParseError::InvalidFormat(malformed.to_string())),
+                "{}",
+                malformed
+            );
+        }
+        assert_eq!(
+            Timespan::parse("01:75:00"),
+            Err(TimespanParseError::ComponentOutOfRange { unit: "minutes", value: 75 })
+        );
+        assert_eq!(
+            Timespan::parse("1.24:00:00"),
+            Err(TimespanParseError::ComponentOutOfRange { unit: "hours", value: 24 })
+        );
+        assert!(TimespanParseError::Empty.to_string().contains("empty"));
+    }
+
+    #[test]
+    fn test_timespan_operators_and_overflow() {
+        let a = Timespan::from_seconds(90.0);
+        let b = Timespan::from_seconds(30.0);
+        assert_eq!(a + b, a.add(b));
+        assert_eq!(a - b, a.subtract(b));
+        assert_eq!(-a, Timespan::from_seconds(-90.0));
+        assert_eq!(a * 2.0, Timespan::from_minutes(3.0));
+        assert_eq!(a / 3.0, b);
+        assert!(b < a);
+
+        let mut total = Timespan::ZERO;
+        total += a;
+        total -= b;
+        assert_eq!(total, Timespan::from_minutes(1.0));
+        assert_eq!(a.clamp(Timespan::ZERO, b), b);
+        assert_eq!((-a).clamp(Timespan::ZERO, b), Timespan::ZERO);
+
+        // Saturates instead of wrapping
+        let near_max = Timespan::from_ticks(i64::MAX - 1);
+        assert_eq!(near_max + b, Timespan::MAX);
+        assert_eq!(-near_max - b, Timespan::MIN);
+        assert_eq!(-Timespan::MIN, Timespan::MAX);
+        assert_eq!(Timespan::MIN.abs(), Timespan::MAX);
+        assert_eq!(Timespan::MIN.duration(), Timespan::MAX);
+        assert_eq!(near_max * 4.0, Timespan::MAX);
+        assert_eq!(a / 0.0, Timespan::MAX);
+        assert_eq!(a * f64::NAN, Timespan::ZERO);
+        assert_eq!(Timespan::parse("10675200d"), Err(TimespanParseError::Overflow));
+        assert_eq!(Timespan::parse("10675199d 1000h"), Err(TimespanParseError::Overflow));
+        assert_eq!(Timespan::parse("9999999999999999999:00:00"), Err(TimespanParseError::Overflow));
+        assert_eq!(Timespan::parse("1e300d"), Err(TimespanParseError::InvalidFormat("1e300d".to_string())));
+    }
+
+    #[test]
+    fn test_timespan_duration_conversion() {
+        let duration = Duration::new(5, 123_456_700);
+        let ts = Timespan::from_duration(duration);
+        assert_eq!(ts.ticks, 51_234_567);
+        assert_eq!(ts.to_duration(), duration);
+        assert_eq!(Timespan::from_seconds(-1.0).to_duration(), Duration::ZERO);
+        assert_eq!(Timespan::from_duration(Duration::MAX), Timespan::MAX);
+        assert_eq!(Timespan::MAX.to_duration().as_nanos(), i64::MAX as u128 * 100);
+    }
+}
@@ -2,13 +2,32 @@
 
 use crate::vector::*;
 use crate::rotator::*;
+use crate::types::math::nearly_equal::max_error;
+use crate::types::matrix::check_decomposable;
+use crate::types::{DecompositionError, IntVector, NearlyEqual};
 use crate::BinarySerializable;
+use glam::DMat3;
 use glam::DMat4;
 use glam::DQuat;
 use glam::DVec3;
 use glam::Mat4;
+use glam::Quat;
+use glam::Vec3;
 use serde::{Deserialize, Serialize};
-use std::fmt;
+use core::cell::OnceCell;
+use core::fmt;
+
+// Counts `Transform::to_matrix` calls so tests can check batch paths build the matrix once
+#[cfg(test)]
+std::thread_local! {
+    static TO_MATRIX_CALLS: core::cell::Cell<usize> = const { core::cell::Cell::new(0) };
+}
+
+/// Number of `Transform::to_matrix` calls made so far on this thread
+#[cfg(test)]
+pub(crate) fn to_matrix_calls() -> usize {
+    TO_MATRIX_CALLS.with(|calls| calls.get())
+}
 
 /// Transform containing Location, Rotation, and Scale
 /// 
@@ -17,6 +36,8 @@ use std::fmt;
 /// - Rotation: 3D rotation as a quaternion
 /// - Scale: 3D scale factors
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(feature = "rkyv", archive(check_bytes, compare(PartialEq)), archive_attr(derive(Debug)))]
 pub struct Transform {
     /// 3D position/location
     pub location: Vector,
@@ -40,6 +61,15 @@ impl fmt::Display for Transform {
 
 impl BinarySerializable for Transform {}
 
+impl crate::types::SerializedSize for Transform {
+    fn serialized_size(&self) -> usize {
+        80
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl crate::types::ZeroCopy for Transform {}
+
 impl Transform {
     /// Identity transform (no translation, rotation, or scaling)
     pub const IDENTITY: Self = Self {
@@ -49,7 +79,7 @@ impl Transform {
     };
 
     /// Create a new transform with the given location, rotation, and scale
-    pub fn new(location: Vector, rotation: DQuat, scale: Vector) -> Self {
+    pub const fn new(location: Vector, rotation: DQuat, scale: Vector) -> Self {
         Self { location, rotation, scale }
     }
 
@@ -78,9 +108,9 @@ impl Transform {
     }
 
     /// Create a transform with uniform scale
-    pub fn from_uniform_scale(scale: f32) -> Self {
+    pub fn from_uniform_scale(scale: f64) -> Self {
         Self {
-            scale: Vector::splat(scale as f64),
+            scale: Vector::splat(scale),
             ..Self::IDENTITY
         }
     }
@@ -103,19 +133,79 @@ impl Transform {
         }
     }
 
+    /// Create a transform at `location` whose forward (+X) axis faces `target`
+    ///
+    /// The up (+Z) axis is kept as close to `up` as possible. If the view
+    /// direction is parallel to `up` the rotation falls back to zero roll, and
+    /// if `location == target` the rotation is the identity. Scale is one.
+    pub fn look_at(location: Vector, target: Vector, up: Vector) -> Self {
+        let forward = (target - location).normalize_or_zero();
+        if forward == Vector::ZERO {
+            return Self::from_location(location);
+        }
+
+        let right = up.cross(forward);
+        if right.length_squared() < 1e-12 {
+            return Self::from_location_rotator(location, Rotator::from_direction(forward));
+        }
+        let right = right.normalize();
+        let new_up = forward.cross(right);
+
+        let rotation = DQuat::from_mat3(&DMat3::from_cols(forward, right, new_up));
+        Self {
+            location,
+            rotation,
+            scale: Vector::ONE,
+        }
+    }
+
     /// Convert to 4x4 transformation matrix
     pub fn to_matrix(self) -> DMat4 {
-        let scale_f64 = glam::DVec3::new(self.scale.x as f64, self.scale.y as f64, self.scale.z as f64);
-        let location_f64 = glam::DVec3::new(self.location.x as f64, self.location.y as f64, self.location.z as f64);
-        DMat4::from_scale_rotation_translation(scale_f64, self.rotation.into(), location_f64)
+        debug_assert!(!self.contains_nan(), "Transform::to_matrix on a transform with NaN: {:?}", self);
+        #[cfg(test)]
+        TO_MATRIX_CALLS.with(|calls| calls.set(calls.get() + 1));
+        DMat4::from_scale_rotation_translation(self.scale, self.rotation, self.location)
     }
 
     /// Create transform from a 4x4 matrix
+    ///
+    /// Lenient: shear, projection and collapsed axes are decomposed anyway and
+    /// give meaningless scale and rotation. See [`Transform::try_from_matrix`].
     pub fn from_matrix(matrix: DMat4) -> Self {
         let (scale, rotation, location) = matrix.to_scale_rotation_translation();
-        let location_f64 = Vector::new(location.x as f64, location.y as f64, location.z as f64);
-        let scale_f64 = Vector::new(scale.x as f64, scale.y as f64, scale.z as f64);
-        Self { location: location_f64, rotation, scale: scale_f64 }
+        Self { location, rotation, scale }
+    }
+
+    /// Largest cosine between two matrix axes still treated as perpendicular
+    pub const SHEAR_TOLERANCE: f64 = 1.0e-6;
+
+    /// Create a transform from a matrix, rejecting anything it can't represent exactly
+    ///
+    /// Fails on NaN or infinite elements, a projective part, a (nearly) zero
+    /// determinant, axes whose angle cosine exceeds `shear_tolerance`, and
+    /// reflections. `Transform::try_from` is the same check with
+    /// `SHEAR_TOLERANCE` but accepts reflections.
+    pub fn try_from_matrix(matrix: DMat4, shear_tolerance: f64) -> Result<Self, DecompositionError> {
+        check_decomposable(&matrix, shear_tolerance)?;
+        if matrix.determinant() < 0.0 {
+            return Err(DecompositionError::NegativeDeterminant);
+        }
+        Ok(Self::from_matrix(matrix))
+    }
+
+    /// Check whether any component is NaN (UE: `FTransform::ContainsNaN`)
+    pub fn contains_nan(self) -> bool {
+        self.location.is_nan() || self.rotation.is_nan() || self.scale.is_nan()
+    }
+
+    /// Check that every component is finite and the rotation is normalized (UE: `FTransform::IsValid`)
+    pub fn is_valid(self) -> bool {
+        self.location.is_finite() && self.rotation.is_finite() && self.scale.is_finite() && self.rotation.is_normalized()
+    }
+
+    /// Check whether any scale component is negative, so the transform mirrors space
+    pub fn has_negative_scale(self) -> bool {
+        self.scale.cmplt(Vector::ZERO).any()
     }
 
     /// Get the rotator representation of the rotation
@@ -130,9 +220,34 @@ impl Transform {
 
     /// Transform a point by this transform (applies scale, rotation, and translation)
     pub fn transform_point(self, point: DVec3) -> DVec3 {
+        debug_assert!(!point.is_nan(), "Transform::transform_point on a NaN point");
         self.to_matrix().transform_point3(point)
     }
 
+    /// Transform every point in place, building the matrix once
+    ///
+    /// Same result as calling [`Transform::transform_point`] on each point.
+    /// Use a [`CachedTransform`] when points arrive one at a time.
+    pub fn transform_points(self, points: &mut [Vector]) {
+        let matrix = self.to_matrix();
+        for point in points {
+            *point = matrix.transform_point3(*point);
+        }
+    }
+
+    /// Transform `src` into `dst`, building the matrix once
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` and `dst` have different lengths.
+    pub fn transform_points_into(self, src: &[Vector], dst: &mut [Vector]) {
+        assert_eq!(src.len(), dst.len(), "source and destination lengths differ");
+        let matrix = self.to_matrix();
+        for (point, out) in src.iter().zip(dst.iter_mut()) {
+            *out = matrix.transform_point3(*point);
+        }
+    }
+
     /// Transform a vector by this transform (applies scale and rotation, ignores translation)
     pub fn transform_vector(self, vector: Vector) -> Vector {
         self.rotation * (vector * self.scale)
@@ -143,16 +258,105 @@ impl Transform {
         self.rotation * direction
     }
 
-    /// Get the inverse of this transform
+    /// Scale components at or below this magnitude have no usable reciprocal (UE: `SMALL_NUMBER`)
+    pub const SMALL_SCALE: f64 = 1.0e-8;
+
+    /// Component-wise `1 / scale`, with 0 for components within `SMALL_SCALE` of zero
+    /// (UE: `GetSafeScaleReciprocal`)
+    pub fn safe_scale_reciprocal(self) -> Vector {
+        let reciprocal = |component: f64| {
+            if component.abs() <= Self::SMALL_SCALE {
+                0.0
+            } else {
+                1.0 / component
+            }
+        };
+        Vector::new(reciprocal(self.scale.x), reciprocal(self.scale.y), reciprocal(self.scale.z))
+    }
+
+    /// Get the inverse of this transform (UE: `FTransform::Inverse`)
+    ///
+    /// Computed directly: the inverse rotation, the reciprocal scale and a
+    /// location of `-(inv_rotation * (inv_scale * location))`.
+    ///
+    /// A transform applies scale before rotation, so the true inverse applies
+    /// the inverse rotation before the inverse scale. Swapping them is only
+    /// exact when the scale is uniform or the rotation maps each axis onto
+    /// itself; otherwise the true inverse has shear, which a `Transform`
+    /// can't hold, and this is the same approximation UE makes. Use
+    /// [`Transform::inverse_transform_point`] to map points back exactly.
+    ///
+    /// A scale component within `SMALL_SCALE` of zero has no inverse; its
+    /// reciprocal is taken as 0, so that axis stays collapsed instead of
+    /// producing infinities.
     pub fn inverse(self) -> Self {
-        let inv_matrix = self.to_matrix().inverse();
-        Self::from_matrix(inv_matrix)
+        let inv_rotation = self.rotation.inverse();
+        let inv_scale = self.safe_scale_reciprocal();
+        Self {
+            location: -(inv_rotation * (inv_scale * self.location)),
+            rotation: inv_rotation,
+            scale: inv_scale,
+        }
+    }
+
+    /// Combine with a child transform: the result applies `child`, then `self`
+    ///
+    /// `self` acts as the parent, so `parent.combine(child)` takes a point
+    /// from the child's local space to the parent's space, the same as
+    /// `parent.to_matrix() * child.to_matrix()` and UE's `Child * Parent`.
+    /// The parts compose directly, without a matrix:
+    ///
+    /// - rotation: `self.rotation * child.rotation`
+    /// - scale: `self.scale * child.scale`, component-wise
+    /// - location: `child.location` scaled, rotated and moved by `self`
+    ///
+    /// The location is always exact. The rotation and scale are exact when
+    /// the parent's scale is uniform or the child's rotation keeps the axes
+    /// aligned; otherwise the parent's non-uniform scale would shear the
+    /// rotated child, and (like UE's "scale then rotate" `FTransform`) the
+    /// shear is dropped and the scale stays in the child's local axes.
+    pub fn combine(self, child: Transform) -> Self {
+        Self {
+            location: self.location + self.rotation * (self.scale * child.location),
+            rotation: self.rotation * child.rotation,
+            scale: self.scale * child.scale,
+        }
     }
 
-    /// Combine this transform with another (this transform is applied first)
-    pub fn combine(self, other: Transform) -> Self {
-        let combined_matrix = self.to_matrix() * other.to_matrix();
-        Self::from_matrix(combined_matrix)
+    /// Map a point from this transform's space back to local space (UE: `InverseTransformPosition`)
+    ///
+    /// Exact for any scale: translation, rotation and scale are undone in
+    /// reverse order. Axes with a zero scale map to 0.
+    pub fn inverse_transform_point(self, point: Vector) -> Vector {
+        self.rotation.inverse() * (point - self.location) * self.safe_scale_reciprocal()
+    }
+
+    /// Undo rotation and scale on a vector, ignoring translation (UE: `InverseTransformVector`)
+    pub fn inverse_transform_vector(self, vector: Vector) -> Vector {
+        self.rotation.inverse() * vector * self.safe_scale_reciprocal()
+    }
+
+    /// Undo only the rotation on a direction (UE: `InverseTransformVectorNoScale`)
+    pub fn inverse_transform_direction(self, direction: Vector) -> Vector {
+        self.rotation.inverse() * direction
+    }
+
+    /// Get this transform relative to `parent` (UE: `GetRelativeTransform`)
+    ///
+    /// The result is the transform such that `parent.combine(result)` equals
+    /// `self`. Like FTransform, a transform can't represent shear: if `parent`
+    /// has non-uniform scale and the result is rotated relative to it, the
+    /// shear is dropped when the matrix is decomposed, so the round-trip only
+    /// holds exactly for uniform parent scale or axis-aligned children.
+    pub fn relative_to(self, parent: Transform) -> Self {
+        Self::from_matrix(parent.to_matrix().inverse() * self.to_matrix())
+    }
+
+    /// Get the world transform of a child given its transform relative to `parent`
+    ///
+    /// The inverse of [`Transform::relative_to`], with the same shear limitation.
+    pub fn make_absolute(child_relative: Transform, parent: Transform) -> Self {
+        parent.combine(child_relative)
     }
 
     /// Get the forward vector for this transform
@@ -170,27 +374,93 @@ impl Transform {
         self.transform_direction(VectorConstants::UP)
     }
 
+    /// Hashable key snapping location, rotation (in degrees, via the rotator) and scale to cells
+    ///
+    /// See `VectorExt::to_key` and `Rotator::to_key` for the rounding rules.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any precision isn't positive and finite.
+    pub fn to_key(self, location_precision: f64, rotation_precision: f64, scale_precision: f64) -> TransformKey {
+        TransformKey {
+            location: self.location.to_key(location_precision),
+            rotation: self.get_rotator().to_key(rotation_precision),
+            scale: self.scale.to_key(scale_precision),
+        }
+    }
+
     /// Check if this transform is nearly equal to another
+    ///
+    /// Location and scale are compared by the length of their difference,
+    /// and the rotation quaternions component-wise without treating `q` and
+    /// `-q` as equal. For the per-component check used by [`NearlyEqual`],
+    /// see [`is_nearly_equal_per_component`](Self::is_nearly_equal_per_component).
     pub fn is_nearly_equal(self, other: Transform, tolerance: f64) -> bool {
         (self.location - other.location).length() <= tolerance
             && self.rotation.abs_diff_eq(other.rotation, tolerance)
             && (self.scale - other.scale).length() <= tolerance
     }
 
+    /// Check if every location, rotation and scale component is within `tolerance` (see [`NearlyEqual`])
+    ///
+    /// Unlike `is_nearly_equal`, a rotation `q` matches `-q`.
+    pub fn is_nearly_equal_per_component(self, other: Transform, tolerance: f64) -> bool {
+        self.nearly_equal(&other, tolerance)
+    }
+
     /// Check if this transform is nearly the identity transform
     pub fn is_nearly_identity(self, tolerance: f64) -> bool {
         self.is_nearly_equal(Self::IDENTITY, tolerance)
     }
 
     /// Linearly interpolate between two transforms
+    ///
+    /// The rotation is slerped along the shortest path: `q` and `-q` are the
+    /// same rotation, so when the quaternions are in opposite hemispheres one
+    /// is negated first, and the result is renormalized.
     pub fn lerp(self, other: Transform, alpha: f64) -> Self {
+        let target = if self.rotation.dot(other.rotation) < 0.0 {
+            -other.rotation
+        } else {
+            other.rotation
+        };
         Self {
             location: self.location.lerp(other.location, alpha),
-            rotation: self.rotation.slerp(other.rotation, alpha),
+            rotation: self.rotation.slerp(target, alpha).normalize(),
             scale: self.scale.lerp(other.scale, alpha),
         }
     }
 
+    /// Weight below which a blend is ignored (UE: `ZERO_ANIMWEIGHT_THRESH`)
+    pub const ZERO_BLEND_WEIGHT: f64 = 0.00001;
+
+    /// Blend towards `other` by `weight` (UE: `BlendWith`)
+    ///
+    /// Weights within `ZERO_BLEND_WEIGHT` of 0 or 1 snap to this transform or
+    /// `other`; anything in between is a shortest-path [`Transform::lerp`].
+    pub fn blend_with(&mut self, other: Transform, weight: f64) {
+        if weight > Self::ZERO_BLEND_WEIGHT {
+            if weight >= 1.0 - Self::ZERO_BLEND_WEIGHT {
+                *self = other;
+            } else {
+                *self = self.lerp(other, weight);
+            }
+        }
+    }
+
+    /// Add an additive delta on top of this transform (UE: `Accumulate`)
+    ///
+    /// Translations add, scales multiply, and the delta rotation is applied
+    /// on top of this one unless it is negligibly small.
+    pub fn accumulate(&mut self, delta: Transform) {
+        const DELTA: f64 = 0.00001;
+        if delta.rotation.w * delta.rotation.w < 1.0 - DELTA * DELTA {
+            self.rotation = delta.rotation * self.rotation;
+        }
+        self.location += delta.location;
+        self.scale *= delta.scale;
+    }
+
     /// Add translation to this transform
     pub fn add_location(mut self, delta: Vector) -> Self {
         self.location += delta;
@@ -210,15 +480,182 @@ impl Transform {
     }
 }
 
+/// A [`Transform`] with its matrix and inverse matrix built on first use
+///
+/// For transforming many points one call at a time: `Transform::transform_point`
+/// rebuilds the matrix on every call, this builds it once. The inverse is
+/// only computed if `inverse_transform_point` is used; with a zero scale
+/// component it is not finite.
+#[derive(Debug, Clone, Default)]
+pub struct CachedTransform {
+    transform: Transform,
+    matrix: OnceCell<DMat4>,
+    inverse: OnceCell<DMat4>,
+}
+
+impl CachedTransform {
+    /// Wrap a transform; nothing is computed until needed
+    pub fn new(transform: Transform) -> Self {
+        Self {
+            transform,
+            matrix: OnceCell::new(),
+            inverse: OnceCell::new(),
+        }
+    }
+
+    /// The wrapped transform
+    pub fn transform(&self) -> Transform {
+        self.transform
+    }
+
+    /// Replace the transform, dropping the cached matrices
+    pub fn set_transform(&mut self, transform: Transform) {
+        *self = Self::new(transform);
+    }
+
+    /// The transformation matrix
+    pub fn matrix(&self) -> &DMat4 {
+        self.matrix.get_or_init(|| self.transform.to_matrix())
+    }
+
+    /// The inverse transformation matrix
+    pub fn inverse_matrix(&self) -> &DMat4 {
+        self.inverse.get_or_init(|| self.matrix().inverse())
+    }
+
+    /// Transform a point (scale, rotation, then translation)
+    pub fn transform_point(&self, point: Vector) -> Vector {
+        self.matrix().transform_point3(point)
+    }
+
+    /// Map a point from world space back into the transform's local space
+    pub fn inverse_transform_point(&self, point: Vector) -> Vector {
+        self.inverse_matrix().transform_point3(point)
+    }
+
+    /// Transform every point in place
+    pub fn transform_points(&self, points: &mut [Vector]) {
+        let matrix = self.matrix();
+        for point in points {
+            *point = matrix.transform_point3(*point);
+        }
+    }
+
+    /// Map every point back into local space in place
+    pub fn inverse_transform_points(&self, points: &mut [Vector]) {
+        let inverse = self.inverse_matrix();
+        for point in points {
+            *point = inverse.transform_point3(*point);
+        }
+    }
+}
+
+impl From<Transform> for CachedTransform {
+    fn from(transform: Transform) -> Self {
+        Self::new(transform)
+    }
+}
+
+/// Hashable key for a `Transform`, from `Transform::to_key`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TransformKey {
+    /// Location cells
+    pub location: IntVector,
+    /// Rotation cells, as (pitch, yaw, roll)
+    pub rotation: IntVector,
+    /// Scale cells
+    pub scale: IntVector,
+}
+
+/// Location, rotation (`q` ≈ `-q`) and scale components are all compared against the same tolerance
+impl NearlyEqual for Transform {
+    fn max_component_error(&self, other: &Self) -> f64 {
+        max_error([
+            self.location.max_component_error(&other.location),
+            self.rotation.max_component_error(&other.rotation),
+            self.scale.max_component_error(&other.scale),
+        ])
+    }
+}
+
 impl Default for Transform {
     fn default() -> Self {
         Self::IDENTITY
     }
 }
 
+/// Single precision copy of a [`Transform`] for GPU upload or compact payloads
+///
+/// Locations beyond a few kilometres lose sub-centimetre precision in f32, so
+/// convert at the boundary and keep gameplay math in [`Transform`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Transform32 {
+    /// 3D position/location
+    pub location: Vec3,
+    /// 3D rotation as quaternion
+    pub rotation: Quat,
+    /// 3D scale factors
+    pub scale: Vec3,
+}
+
+impl fmt::Display for Transform32 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Transform32({})", self.to_f64())
+    }
+}
+
+impl BinarySerializable for Transform32 {}
+
+impl Transform32 {
+    /// Identity transform (no translation, rotation, or scaling)
+    pub const IDENTITY: Self = Self {
+        location: Vec3::ZERO,
+        rotation: Quat::IDENTITY,
+        scale: Vec3::ONE,
+    };
+
+    /// Widen to a double precision [`Transform`]
+    pub fn to_f64(self) -> Transform {
+        Transform {
+            location: self.location.as_dvec3(),
+            rotation: DQuat::from_xyzw(self.rotation.x as f64, self.rotation.y as f64, self.rotation.z as f64, self.rotation.w as f64),
+            scale: self.scale.as_dvec3(),
+        }
+    }
+
+    /// Convert to a single precision 4x4 transformation matrix
+    pub fn to_matrix(self) -> Mat4 {
+        Mat4::from_scale_rotation_translation(self.scale, self.rotation, self.location)
+    }
+}
+
+impl Transform {
+    /// Narrow to a single precision [`Transform32`]
+    pub fn to_f32(self) -> Transform32 {
+        Transform32 {
+            location: self.location.as_vec3(),
+            rotation: Quat::from_xyzw(self.rotation.x as f32, self.rotation.y as f32, self.rotation.z as f32, self.rotation.w as f32),
+            scale: self.scale.as_vec3(),
+        }
+    }
+}
+
+impl From<Transform> for Transform32 {
+    fn from(transform: Transform) -> Self {
+        transform.to_f32()
+    }
+}
+
+impl From<Transform32> for Transform {
+    fn from(transform: Transform32) -> Self {
+        transform.to_f64()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::assert_nearly_eq;
 
     #[test]
     fn test_transform_identity() {
@@ -279,6 +716,39 @@ mod tests {
         assert!(combined.is_nearly_identity(0.001));
     }
 
+    #[test]
+    fn test_transform_look_at() {
+        let location = Vector::new(5.0, -2.0, 3.0);
+        let targets = [
+            Vector::new(50.0, 10.0, 3.0),
+            Vector::new(-10.0, -40.0, 20.0),
+            Vector::new(5.0, 30.0, -7.0),
+        ];
+
+        for target in targets {
+            let transform = Transform::look_at(location, target, Vector::Z);
+            let expected = (target - location).normalize();
+            assert!((transform.get_forward_vector() - expected).length() < 1e-9);
+            assert!(transform.get_up_vector().dot(Vector::Z) > 0.0);
+            assert!(transform.get_right_vector().z.abs() < 1e-9); // no roll
+            assert_eq!(transform.location, location);
+        }
+    }
+
+    #[test]
+    fn test_transform_look_at_degenerate() {
+        let location = Vector::new(1.0, 2.0, 3.0);
+
+        let same = Transform::look_at(location, location, Vector::Z);
+        assert_eq!(same.rotation, DQuat::IDENTITY);
+        assert_eq!(same.location, location);
+
+        let straight_up = Transform::look_at(location, location + Vector::new(0.0, 0.0, 10.0), Vector::Z);
+        assert!((straight_up.get_forward_vector() - Vector::Z).length() < 1e-9);
+        assert!(straight_up.rotation.is_finite());
+        assert_eq!(straight_up.location, location);
+    }
+
     #[test]
     fn test_transform_display() {
         let transform = Transform::new(
@@ -389,4 +859,343 @@ mod tests {
         assert!(from_binary.rotation.is_finite());
         assert!(from_binary.scale.is_finite());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_transform_relative_to_round_trip() {
+        let parent = Transform::new(
+            Vector::new(100.0, -50.0, 20.0),
+            DQuat::from_rotation_z(30.0_f64.to_radians()),
+            Vector::splat(2.0),
+        );
+        let world = Transform::new(
+            Vector::new(10.0, 20.0, 30.0),
+            DQuat::from_rotation_x(45.0_f64.to_radians()),
+            Vector::new(1.0, 2.0, 3.0),
+        );
+
+        let relative = world.relative_to(parent);
+        assert!(parent.combine(relative).is_nearly_equal(world, 1e-9));
+        assert!(Transform::make_absolute(relative, parent).is_nearly_equal(world, 1e-9));
+
+        let point = Vector::new(1.0, 2.0, 3.0);
+        let expected = world.transform_point(point);
+        assert!((parent.transform_point(relative.transform_point(point)) - expected).length() < 1e-9);
+
+        // Relative to itself is the identity
+        assert!(world.relative_to(world).is_nearly_identity(1e-9));
+    }
+
+    #[test]
+    fn test_transform_lerp_shortest_path() {
+        // 170 and -170 degrees of yaw are 20 degrees apart, but their quaternions are in opposite hemispheres
+        let from = Transform::from_rotation(DQuat::from_rotation_z(170.0_f64.to_radians()));
+        let to = Transform::from_rotation(DQuat::from_rotation_z(-170.0_f64.to_radians()));
+        assert!(from.rotation.dot(to.rotation) < 0.0);
+
+        let mut travelled = 0.0;
+        let mut previous = from.rotation;
+        for step in 1..=10 {
+            let sample = from.lerp(to, step as f64 / 10.0).rotation;
+            assert!((sample.length() - 1.0).abs() < 1e-12);
+            travelled += previous.angle_between(sample).to_degrees();
+            previous = sample;
+        }
+        assert!(travelled <= 180.0);
+        assert!((travelled - 20.0).abs() < 1e-6);
+
+        let midway = from.lerp(to, 0.5);
+        assert!((midway.get_forward_vector() - Vector::new(-1.0, 0.0, 0.0)).length() < 1e-9);
+
+        // Also across the hemisphere boundary with the quaternions stored negated
+        let flipped = Transform::from_rotation(-DQuat::from_rotation_z(10.0_f64.to_radians()));
+        let start = Transform::from_rotation(DQuat::from_rotation_z(-10.0_f64.to_radians()));
+        let angle = start.rotation.angle_between(start.lerp(flipped, 0.5).rotation).to_degrees();
+        assert!((angle - 10.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_transform_blend_with_and_accumulate() {
+        let a = Transform::from_location(Vector::new(0.0, 0.0, 0.0));
+        let b = Transform::new(
+            Vector::new(10.0, 0.0, 0.0),
+            DQuat::from_rotation_z(90.0_f64.to_radians()),
+            Vector::splat(3.0),
+        );
+
+        let mut blended = a;
+        blended.blend_with(b, 0.0);
+        assert_eq!(blended, a);
+        blended.blend_with(b, 0.5);
+        assert!((blended.location.x - 5.0).abs() < 1e-12);
+        assert!((blended.scale - Vector::splat(2.0)).length() < 1e-12);
+        let mut snapped = a;
+        snapped.blend_with(b, 1.0 - 1e-7);
+        assert_eq!(snapped, b);
+
+        let mut base = Transform::new(Vector::new(1.0, 2.0, 3.0), DQuat::IDENTITY, Vector::splat(2.0));
+        base.accumulate(b);
+        assert_eq!(base.location, Vector::new(11.0, 2.0, 3.0));
+        assert_eq!(base.scale, Vector::splat(6.0));
+        assert!(base.rotation.abs_diff_eq(b.rotation, 1e-12));
+
+        // A negligible delta rotation is ignored
+        let mut still = Transform::IDENTITY;
+        still.accumulate(Transform::from_rotation(DQuat::from_rotation_z(1e-9)));
+        assert_eq!(still.rotation, DQuat::IDENTITY);
+    }
+
+    #[test]
+    fn test_transform_precision_conversion() {
+        let transform = Transform::new(
+            Vector::new(1024.5, -2.25, 300.0),
+            DQuat::from_rotation_z(30.0_f64.to_radians()),
+            Vector::new(1.0, 2.0, 0.5),
+        );
+        let single = transform.to_f32();
+        assert_eq!(single.location, Vec3::new(1024.5, -2.25, 300.0));
+        assert!(single.to_f64().is_nearly_equal(transform, 1e-6));
+        assert_eq!(Transform::from(single), single.to_f64());
+        assert_eq!(Transform32::from(transform), single);
+        assert_eq!(Transform32::IDENTITY.to_f64(), Transform::IDENTITY);
+
+        let point = Vec3::new(1.0, 2.0, 3.0);
+        let narrow = single.to_matrix().transform_point3(point);
+        let wide = transform.transform_point(point.as_dvec3());
+        assert!((narrow.as_dvec3() - wide).length() < 1e-3);
+
+        // f32 can't hold large-world locations exactly
+        let far = Transform::from_location(Vector::new(5_000_000.123, 0.0, 0.0));
+        assert!((far.to_f32().to_f64().location.x - far.location.x).abs() > 1e-3);
+
+        let binary = single.to_binary().unwrap();
+        assert_eq!(Transform32::from_binary(&binary).unwrap(), single);
+    }
+
+    fn point_cloud(count: usize) -> Vec<Vector> {
+        let mut stream = crate::types::RandomStream::new(1818);
+        (0..count).map(|_| stream.vrand() * stream.frand_range_f64(0.0, 500.0)).collect()
+    }
+
+    #[test]
+    fn test_batch_transform_matches_per_point() {
+        let transform = Transform::new(
+            Vector::new(100.0, -20.0, 5.0),
+            Rotator::new(15.0, -70.0, 30.0).to_quaternion(),
+            Vector::new(1.5, 0.5, 2.0),
+        );
+        let points = point_cloud(10_000);
+        let expected: Vec<Vector> = points.iter().map(|&point| transform.transform_point(point)).collect();
+
+        let calls = to_matrix_calls();
+        let mut in_place = points.clone();
+        transform.transform_points(&mut in_place);
+        assert_eq!(to_matrix_calls(), calls + 1);
+        assert_eq!(in_place, expected);
+
+        let mut into = vec![Vector::ZERO; points.len()];
+        transform.transform_points_into(&points, &mut into);
+        assert_eq!(into, expected);
+        assert_eq!(to_matrix_calls(), calls + 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "lengths differ")]
+    fn test_batch_transform_length_mismatch() {
+        Transform::IDENTITY.transform_points_into(&[Vector::ZERO; 3], &mut [Vector::ZERO; 2]);
+    }
+
+    #[test]
+    fn test_cached_transform() {
+        let transform = Transform::new(
+            Vector::new(-3.0, 8.0, 1.0),
+            DQuat::from_axis_angle(Vector::new(1.0, 1.0, 0.0).normalize(), 0.8),
+            Vector::new(2.0, 3.0, 0.5),
+        );
+        let calls = to_matrix_calls();
+        let cached = CachedTransform::from(transform);
+        assert_eq!(to_matrix_calls(), calls, "nothing is built up front");
+
+        let points = point_cloud(500);
+        for &point in &points {
+            let world = cached.transform_point(point);
+            assert_eq!(world, transform.to_matrix().transform_point3(point));
+            assert!(cached.inverse_transform_point(world).abs_diff_eq(point, 1e-9));
+        }
+        // One for the cache, one per comparison above
+        assert_eq!(to_matrix_calls(), calls + 1 + points.len());
+
+        let mut batch = points.clone();
+        cached.transform_points(&mut batch);
+        cached.inverse_transform_points(&mut batch);
+        assert!(batch.iter().zip(&points).all(|(a, b)| a.abs_diff_eq(*b, 1e-9)));
+        assert_eq!(to_matrix_calls(), calls + 1 + points.len());
+
+        let mut cached = cached;
+        cached.set_transform(Transform::from_location(Vector::X));
+        assert_eq!(cached.transform_point(Vector::ZERO), Vector::X);
+        assert_eq!(cached.transform(), Transform::from_location(Vector::X));
+    }
+
+    fn random_transform(stream: &mut crate::types::RandomStream, scale: Vector) -> Transform {
+        let rotation = Rotator::new(
+            stream.frand_range_f64(-89.0, 89.0),
+            stream.frand_range_f64(-180.0, 180.0),
+            stream.frand_range_f64(-180.0, 180.0),
+        );
+        Transform::new(stream.vrand() * 100.0, rotation.to_quaternion(), scale)
+    }
+
+    #[test]
+    fn test_inverse_and_combine_match_matrix_for_uniform_scale() {
+        let mut stream = crate::types::RandomStream::new(1819);
+        for _ in 0..200 {
+            let (parent_scale, child_scale) = (stream.frand_range_f64(0.1, 5.0), stream.frand_range_f64(0.1, 5.0));
+            let parent = random_transform(&mut stream, Vector::splat(parent_scale));
+            let child = random_transform(&mut stream, Vector::splat(child_scale));
+
+            let calls = to_matrix_calls();
+            let inverse = parent.inverse();
+            let combined = parent.combine(child);
+            assert_eq!(to_matrix_calls(), calls, "no matrices on the analytical path");
+
+            assert_nearly_eq!(inverse, Transform::from_matrix(parent.to_matrix().inverse()), 1e-9);
+            assert_nearly_eq!(combined, Transform::from_matrix(parent.to_matrix() * child.to_matrix()), 1e-9);
+            assert_nearly_eq!(parent.combine(inverse), Transform::IDENTITY, 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_non_uniform_scale_composition() {
+        let mut stream = crate::types::RandomStream::new(5);
+        let parent = random_transform(&mut stream, Vector::new(2.0, 0.5, 3.0));
+        let point = Vector::new(3.0, -4.0, 5.0);
+
+        // Inverse-transforming a point is exact for any scale
+        let world = parent.transform_point(point);
+        assert_nearly_eq!(parent.inverse_transform_point(world), point, 1e-9);
+        let vector = parent.transform_vector(point);
+        assert_nearly_eq!(parent.inverse_transform_vector(vector), point, 1e-9);
+        let direction = parent.transform_direction(Vector::X);
+        assert_nearly_eq!(parent.inverse_transform_direction(direction), Vector::X, 1e-12);
+
+        // An unrotated child composes exactly
+        let child = Transform::new(Vector::new(1.0, 2.0, 3.0), DQuat::IDENTITY, Vector::new(0.5, 4.0, 1.0));
+        assert_nearly_eq!(parent.combine(child).transform_point(point), parent.transform_point(child.transform_point(point)), 1e-9);
+
+        // A rotated child would be sheared by the parent's scale; only its origin stays exact
+        let child = Transform::new(Vector::new(1.0, 2.0, 3.0), DQuat::from_rotation_z(0.7), Vector::ONE);
+        let combined = parent.combine(child);
+        assert_nearly_eq!(combined.location, parent.transform_point(child.location), 1e-9);
+        assert_eq!(combined.scale, parent.scale);
+        let nested = parent.transform_point(child.transform_point(point));
+        assert!(!combined.transform_point(point).nearly_equal(&nested, 1e-3));
+
+        // Likewise the inverse transform swaps rotation and scale, so it's exact only without rotation
+        let unrotated = Transform::new(Vector::new(5.0, 6.0, 7.0), DQuat::IDENTITY, parent.scale);
+        assert_nearly_eq!(unrotated.inverse().transform_point(unrotated.transform_point(point)), point, 1e-9);
+        assert!(!parent.inverse().transform_point(world).nearly_equal(&point, 1e-3));
+    }
+
+    #[test]
+    fn test_zero_scale_inverse() {
+        let transform = Transform::new(Vector::new(10.0, 20.0, 30.0), DQuat::from_rotation_z(1.0), Vector::new(2.0, 0.0, 1e-9));
+        assert_eq!(transform.safe_scale_reciprocal(), Vector::new(0.5, 0.0, 0.0));
+
+        let inverse = transform.inverse();
+        assert!(inverse.location.is_finite() && inverse.scale.is_finite());
+        assert_eq!(inverse.scale, Vector::new(0.5, 0.0, 0.0));
+
+        // Collapsed axes come back as 0 instead of infinity
+        let local = transform.inverse_transform_point(transform.transform_point(Vector::new(4.0, 5.0, 6.0)));
+        assert_nearly_eq!(local, Vector::new(4.0, 0.0, 0.0), 1e-9);
+        assert!(transform.inverse_transform_vector(Vector::ONE).is_finite());
+    }
+
+    #[test]
+    fn test_try_from_matrix_classification() {
+        let trs = Transform::from_location_rotator_scale(
+            Vector::new(100.0, -50.0, 25.0),
+            Rotator::new(10.0, 75.0, -30.0),
+            Vector::new(1.5, 0.5, 3.0),
+        );
+        let decomposed = Transform::try_from_matrix(trs.to_matrix(), Transform::SHEAR_TOLERANCE).unwrap();
+        assert_nearly_eq!(decomposed, trs, 1e-9);
+
+        // Skewing Y toward X by 0.25 of X's length: cos = 0.25 * 1.5 / |Y + 0.25X|
+        let mut sheared = Transform::from_scale(Vector::new(1.5, 0.5, 3.0)).to_matrix();
+        sheared.y_axis += sheared.x_axis * 0.25;
+        let expected_shear = 0.375 * 1.5 / (1.5 * (0.375_f64.powi(2) + 0.25).sqrt());
+        match Transform::try_from_matrix(sheared, Transform::SHEAR_TOLERANCE) {
+            Err(DecompositionError::ContainsShear { max_shear }) => assert!((max_shear - expected_shear).abs() < 1e-12),
+            other => panic!("expected shear, got {:?}", other),
+        }
+        // A loose enough tolerance lets it through
+        assert!(Transform::try_from_matrix(sheared, 0.9).is_ok());
+
+        let mirror = Transform::from_scale(Vector::new(1.0, -1.0, 1.0)).to_matrix();
+        assert_eq!(
+            Transform::try_from_matrix(mirror, Transform::SHEAR_TOLERANCE),
+            Err(DecompositionError::NegativeDeterminant)
+        );
+        assert!(Transform::try_from(mirror).is_ok());
+
+        let flattened = Transform::from_scale(Vector::new(1.0, 1.0, 0.0)).to_matrix();
+        assert_eq!(
+            Transform::try_from_matrix(flattened, Transform::SHEAR_TOLERANCE),
+            Err(DecompositionError::SingularMatrix)
+        );
+        assert_eq!(
+            DecompositionError::NegativeDeterminant.to_string(),
+            "matrix has a negative determinant (reflection)"
+        );
+        assert_eq!(
+            DecompositionError::ContainsShear { max_shear: 0.1 }.to_string(),
+            "matrix axes are not perpendicular (shear 0.100000)"
+        );
+    }
+
+    #[test]
+    fn test_validity_checks() {
+        let transform = Transform::from_location_rotator(Vector::new(1.0, 2.0, 3.0), Rotator::from_yaw(30.0));
+        assert!(transform.is_valid());
+        assert!(!transform.contains_nan());
+        assert!(!transform.has_negative_scale());
+
+        let nan = Transform { location: Vector::new(f64::NAN, 0.0, 0.0), ..transform };
+        assert!(nan.contains_nan());
+        assert!(!nan.is_valid());
+
+        let infinite = Transform { scale: Vector::splat(f64::INFINITY), ..transform };
+        assert!(!infinite.contains_nan());
+        assert!(!infinite.is_valid());
+
+        let unnormalized = Transform { rotation: DQuat::from_xyzw(0.0, 0.0, 1.0, 1.0), ..transform };
+        assert!(!unnormalized.is_valid());
+
+        let mirrored = Transform { scale: Vector::new(1.0, 1.0, -2.0), ..transform };
+        assert!(mirrored.has_negative_scale());
+        assert!(mirrored.is_valid());
+    }
+
+    #[test]
+    fn test_nearly_equal_euclidean_and_per_component() {
+        let transform = Transform::new(Vector::new(10.0, 0.0, 0.0), DQuat::from_rotation_z(0.5), Vector::ONE);
+
+        // 0.8 off on two axes: within 1.0 per component, but 1.13 away
+        let shifted = Transform { location: transform.location + Vector::new(0.8, 0.8, 0.0), ..transform };
+        assert!(!transform.is_nearly_equal(shifted, 1.0));
+        assert!(transform.is_nearly_equal_per_component(shifted, 1.0));
+
+        // The same rotation written as -q
+        let negated = Transform { rotation: -transform.rotation, ..transform };
+        assert!(!transform.is_nearly_equal(negated, 1e-9));
+        assert!(transform.is_nearly_equal_per_component(negated, 1e-9));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "NaN")]
+    fn test_to_matrix_debug_asserts_on_nan() {
+        Transform::from_scale(Vector::new(1.0, f64::NAN, 1.0)).to_matrix();
+    }
+}
@@ -1,9 +1,18 @@
 //! Vector types and utilities
+//!
+//! The crate commits to double precision, like UE5's Large World
+//! Coordinates: every geometric type (vectors, rotators, quaternions,
+//! matrices, transforms, bounds, rays, planes) is built on f64. Single
+//! precision copies for GPU upload or compact network payloads are explicit,
+//! via `to_f32()` and the `*32` structs such as [`crate::Transform32`].
 
+use crate::types::math::fmath::grid_snap;
+use crate::types::math::quantized_key::quantize;
+use crate::types::{Axis, IntVector, IntVector2};
 use crate::BinarySerializable;
-use glam::{DVec2, DVec3, DVec4};
-use serde::{Deserialize, Serialize};
-use std::fmt;
+use glam::DVec3;
+#[cfg(not(feature = "std"))]
+use crate::types::math::float_math::FloatMath;
 
 /// 3D Vector representing position, velocity, direction, etc.
 /// This is the most commonly used vector type in UE.
@@ -15,23 +24,26 @@ pub type Vector2D = glam::DVec2;
 /// 4D Vector for homogeneous coordinates, RGBA colors, etc.
 pub type Vector4 = glam::DVec4;
 
-/// Quaternion for rotations (preferred over Rotator for math operations)
-pub type Quaternion = glam::Quat;
-
 /// 3x3 Matrix
-pub type Matrix3 = glam::Mat3;
+pub type Matrix3 = glam::DMat3;
 
 /// 4x4 Matrix for transformations
-pub type Matrix4 = glam::Mat4;
+pub type Matrix4 = glam::DMat4;
 
 // Implement BinarySerializable for glam types
 impl BinarySerializable for Vector {}
 impl BinarySerializable for Vector2D {}
 impl BinarySerializable for Vector4 {}
-impl BinarySerializable for Quaternion {}
+impl BinarySerializable for glam::DQuat {}
 impl BinarySerializable for Matrix3 {}
 impl BinarySerializable for Matrix4 {}
 
+// Single precision glam types, for GPU and network copies
+impl BinarySerializable for glam::Vec3 {}
+impl BinarySerializable for glam::Quat {}
+impl BinarySerializable for glam::Mat3 {}
+impl BinarySerializable for glam::Mat4 {}
+
 /// UE-style vector constants and utility functions
 pub struct VectorConstants;
 
@@ -53,6 +65,7 @@ impl VectorConstants {
 }
 
 /// Extension trait for Vector operations common in UE
+#[allow(clippy::wrong_self_convention)]
 pub trait VectorExt {
     /// Get the size (magnitude) of the vector
     fn size(self) -> f64;
@@ -68,6 +81,284 @@ pub trait VectorExt {
     
     /// Get a normalized copy of the vector
     fn get_safe_normal(self, tolerance: f64) -> Vector;
+
+    /// Interpolate towards `target`, moving faster the further away it is (UE `VInterpTo`)
+    fn vinterp_to(self, target: Vector, delta_time: f64, interp_speed: f64) -> Vector;
+
+    /// Interpolate towards `target` at a constant speed (UE `VInterpConstantTo`)
+    fn vinterp_constant_to(self, target: Vector, delta_time: f64, interp_speed: f64) -> Vector;
+
+    /// Critically damped spring towards `target` that never overshoots.
+    /// `velocity` carries the spring state between calls.
+    fn smooth_damp(
+        self,
+        target: Vector,
+        velocity: &mut Vector,
+        smooth_time: f64,
+        max_speed: f64,
+        delta_time: f64,
+    ) -> Vector;
+
+    /// Copy with every component made non-negative (UE `GetAbs`)
+    fn get_abs(self) -> Vector;
+
+    /// Largest component (UE `GetMax`)
+    fn get_max(self) -> f64;
+
+    /// Smallest component (UE `GetMin`)
+    fn get_min(self) -> f64;
+
+    /// Largest component magnitude (UE `GetAbsMax`)
+    fn get_abs_max(self) -> f64;
+
+    /// Per-component maximum of the two vectors (UE `ComponentMax`)
+    fn component_max(self, other: Vector) -> Vector;
+
+    /// Per-component minimum of the two vectors (UE `ComponentMin`)
+    fn component_min(self, other: Vector) -> Vector;
+
+    /// Projection onto `other`, which need not be normalized (UE `ProjectOnTo`).
+    /// Projecting onto a zero vector gives zero rather than NaN.
+    fn project_on_to(self, other: Vector) -> Vector;
+
+    /// Projection onto a unit-length `normal` (UE `ProjectOnToNormal`)
+    fn project_on_to_normal(self, normal: Vector) -> Vector;
+
+    /// Reflection across the plane with unit-length `mirror_normal` (UE `MirrorByVector`)
+    fn mirror_by_vector(self, mirror_normal: Vector) -> Vector;
+
+    /// Rotate by `angle_degrees` about a unit-length `axis`, counter-clockwise
+    /// looking down the axis (UE `RotateAngleAxis`)
+    fn rotate_angle_axis(self, angle_degrees: f64, axis: Vector) -> Vector;
+
+    /// Cosine of the angle between the two vectors after dropping Z (UE
+    /// `CosineAngle2D`). Zero if either is zero in XY.
+    fn cos_angle_2d(self, other: Vector) -> f64;
+
+    /// Snap each component to the nearest multiple of `grid` (UE `GridSnap`).
+    /// A zero or non-finite grid leaves the vector unchanged.
+    fn grid_snap(self, grid: f64) -> Vector;
+
+    /// Check whether all components are within `tolerance` of each other (UE `IsUniform`)
+    fn is_uniform(self, tolerance: f64) -> bool;
+
+    /// The X and Y components, dropping Z
+    fn to_2d(self) -> Vector2D;
+
+    /// Hashable key snapping each component to `precision`-wide cells (see [`crate::types::math::quantized_key`])
+    ///
+    /// # Panics
+    ///
+    /// Panics if `precision` isn't positive and finite.
+    fn to_key(self, precision: f64) -> IntVector;
+
+    /// The component along `axis` (UE `GetComponentForAxis`)
+    fn get_component(self, axis: Axis) -> f64;
+
+    /// Set the component along `axis` (UE `SetComponentForAxis`)
+    fn set_component(&mut self, axis: Axis, value: f64);
+}
+
+/// Interpolation and component helpers for single precision vectors (`glam::Vec3`)
+#[allow(clippy::wrong_self_convention)]
+pub trait Vector3fExt {
+    /// Interpolate towards `target`, moving faster the further away it is (UE `VInterpTo`)
+    fn vinterp_to(self, target: glam::Vec3, delta_time: f32, interp_speed: f32) -> glam::Vec3;
+
+    /// Interpolate towards `target` at a constant speed (UE `VInterpConstantTo`)
+    fn vinterp_constant_to(self, target: glam::Vec3, delta_time: f32, interp_speed: f32) -> glam::Vec3;
+
+    /// Critically damped spring towards `target` that never overshoots.
+    /// `velocity` carries the spring state between calls.
+    fn smooth_damp(
+        self,
+        target: glam::Vec3,
+        velocity: &mut glam::Vec3,
+        smooth_time: f32,
+        max_speed: f32,
+        delta_time: f32,
+    ) -> glam::Vec3;
+
+    /// Copy with every component made non-negative (UE `GetAbs`)
+    fn get_abs(self) -> glam::Vec3;
+
+    /// Largest component (UE `GetMax`)
+    fn get_max(self) -> f32;
+
+    /// Smallest component (UE `GetMin`)
+    fn get_min(self) -> f32;
+
+    /// Largest component magnitude (UE `GetAbsMax`)
+    fn get_abs_max(self) -> f32;
+
+    /// Per-component maximum of the two vectors (UE `ComponentMax`)
+    fn component_max(self, other: glam::Vec3) -> glam::Vec3;
+
+    /// Per-component minimum of the two vectors (UE `ComponentMin`)
+    fn component_min(self, other: glam::Vec3) -> glam::Vec3;
+
+    /// Projection onto `other`, which need not be normalized (UE `ProjectOnTo`).
+    /// Projecting onto a zero vector gives zero rather than NaN.
+    fn project_on_to(self, other: glam::Vec3) -> glam::Vec3;
+
+    /// Projection onto a unit-length `normal` (UE `ProjectOnToNormal`)
+    fn project_on_to_normal(self, normal: glam::Vec3) -> glam::Vec3;
+
+    /// Reflection across the plane with unit-length `mirror_normal` (UE `MirrorByVector`)
+    fn mirror_by_vector(self, mirror_normal: glam::Vec3) -> glam::Vec3;
+
+    /// Rotate by `angle_degrees` about a unit-length `axis`, counter-clockwise
+    /// looking down the axis (UE `RotateAngleAxis`)
+    fn rotate_angle_axis(self, angle_degrees: f32, axis: glam::Vec3) -> glam::Vec3;
+
+    /// Cosine of the angle between the two vectors after dropping Z (UE
+    /// `CosineAngle2D`). Zero if either is zero in XY.
+    fn cos_angle_2d(self, other: glam::Vec3) -> f32;
+
+    /// Snap each component to the nearest multiple of `grid` (UE `GridSnap`).
+    /// A zero or non-finite grid leaves the vector unchanged.
+    fn grid_snap(self, grid: f32) -> glam::Vec3;
+
+    /// Check whether all components are within `tolerance` of each other (UE `IsUniform`)
+    fn is_uniform(self, tolerance: f32) -> bool;
+
+    /// The X and Y components, dropping Z
+    fn to_2d(self) -> glam::Vec2;
+}
+
+/// Shared implementation of the interpolation helpers for both vector precisions
+macro_rules! impl_vector_interp {
+    ($vec:ty, $scalar:ty) => {
+        fn vinterp_to(self, target: $vec, delta_time: $scalar, interp_speed: $scalar) -> $vec {
+            if interp_speed <= 0.0 {
+                return target;
+            }
+            let dist = target - self;
+            // UE's KINDA_SMALL_NUMBER
+            if dist.length_squared() < 1.0e-4 {
+                return target;
+            }
+            self + dist * (delta_time * interp_speed).clamp(0.0, 1.0)
+        }
+
+        fn vinterp_constant_to(self, target: $vec, delta_time: $scalar, interp_speed: $scalar) -> $vec {
+            let delta = target - self;
+            let delta_size = delta.length();
+            let max_step = interp_speed * delta_time;
+
+            if delta_size > max_step {
+                if max_step > 0.0 {
+                    self + delta / delta_size * max_step
+                } else {
+                    self
+                }
+            } else {
+                target
+            }
+        }
+
+        fn smooth_damp(
+            self,
+            target: $vec,
+            velocity: &mut $vec,
+            smooth_time: $scalar,
+            max_speed: $scalar,
+            delta_time: $scalar,
+        ) -> $vec {
+            if delta_time <= 0.0 {
+                return self;
+            }
+
+            let smooth_time = smooth_time.max(1.0e-4);
+            let omega = 2.0 / smooth_time;
+            let x = omega * delta_time;
+            let exp = 1.0 / (1.0 + x + 0.48 * x * x + 0.235 * x * x * x);
+
+            // Limit how far the spring is allowed to pull in one step
+            let max_change = max_speed * smooth_time;
+            let change = (self - target).clamp_length_max(max_change.max(0.0));
+            let clamped_target = self - change;
+
+            let temp = (*velocity + change * omega) * delta_time;
+            *velocity = (*velocity - temp * omega) * exp;
+            let mut output = clamped_target + (change + temp) * exp;
+
+            // Never overshoot the real target
+            if (target - self).dot(output - target) > 0.0 {
+                output = target;
+                *velocity = <$vec>::ZERO;
+            }
+
+            output
+        }
+    };
+}
+
+/// Shared implementation of the UE component and projection helpers for both vector precisions
+macro_rules! impl_vector_helpers {
+    ($vec:ty, $vec2:ty, $quat:ty, $scalar:ty) => {
+        fn get_abs(self) -> $vec {
+            self.abs()
+        }
+
+        fn get_max(self) -> $scalar {
+            self.max_element()
+        }
+
+        fn get_min(self) -> $scalar {
+            self.min_element()
+        }
+
+        fn get_abs_max(self) -> $scalar {
+            self.abs().max_element()
+        }
+
+        fn component_max(self, other: $vec) -> $vec {
+            self.max(other)
+        }
+
+        fn component_min(self, other: $vec) -> $vec {
+            self.min(other)
+        }
+
+        fn project_on_to(self, other: $vec) -> $vec {
+            let length_squared = other.length_squared();
+            if length_squared == 0.0 {
+                return <$vec>::ZERO;
+            }
+            other * (self.dot(other) / length_squared)
+        }
+
+        fn project_on_to_normal(self, normal: $vec) -> $vec {
+            normal * self.dot(normal)
+        }
+
+        fn mirror_by_vector(self, mirror_normal: $vec) -> $vec {
+            self - mirror_normal * (2.0 * self.dot(mirror_normal))
+        }
+
+        fn rotate_angle_axis(self, angle_degrees: $scalar, axis: $vec) -> $vec {
+            <$quat>::from_axis_angle(axis, angle_degrees.to_radians()) * self
+        }
+
+        fn cos_angle_2d(self, other: $vec) -> $scalar {
+            self.to_2d().normalize_or_zero().dot(other.to_2d().normalize_or_zero())
+        }
+
+        fn grid_snap(self, grid: $scalar) -> $vec {
+            <$vec>::new(grid_snap(self.x, grid), grid_snap(self.y, grid), grid_snap(self.z, grid))
+        }
+
+        fn is_uniform(self, tolerance: $scalar) -> bool {
+            (self.x - self.y).abs() <= tolerance
+                && (self.x - self.z).abs() <= tolerance
+                && (self.y - self.z).abs() <= tolerance
+        }
+
+        fn to_2d(self) -> $vec2 {
+            self.truncate()
+        }
+    };
 }
 
 impl VectorExt for Vector {
@@ -91,9 +382,7 @@ impl VectorExt for Vector {
         let square_sum = self.length_squared();
         if square_sum == 1.0 {
             return self;
-        } else if square_sum < tolerance * tolerance {
-            return DVec3::ZERO;
-        } else if !square_sum.is_finite() || square_sum.is_nan() {
+        } else if square_sum < tolerance * tolerance || !square_sum.is_finite() {
             return DVec3::ZERO;
         }
         let len = square_sum.sqrt();
@@ -107,9 +396,32 @@ impl VectorExt for Vector {
         }
         norm
     }
+
+    impl_vector_interp!(Vector, f64);
+
+    impl_vector_helpers!(Vector, Vector2D, glam::DQuat, f64);
+
+    fn to_key(self, precision: f64) -> IntVector {
+        IntVector::new(quantize(self.x, precision), quantize(self.y, precision), quantize(self.z, precision))
+    }
+
+    fn get_component(self, axis: Axis) -> f64 {
+        self[axis.index()]
+    }
+
+    fn set_component(&mut self, axis: Axis, value: f64) {
+        self[axis.index()] = value;
+    }
+}
+
+impl Vector3fExt for glam::Vec3 {
+    impl_vector_interp!(glam::Vec3, f32);
+
+    impl_vector_helpers!(glam::Vec3, glam::Vec2, glam::Quat, f32);
 }
 
 /// Extension trait for Vector2D operations
+#[allow(clippy::wrong_self_convention)]
 pub trait Vector2DExt {
     /// Get the size (magnitude) of the 2D vector
     fn size(self) -> f64;
@@ -119,6 +431,16 @@ pub trait Vector2DExt {
     
     /// Check if the vector is nearly zero
     fn is_nearly_zero(self, tolerance: f64) -> bool;
+
+    /// Hashable key snapping each component to `precision`-wide cells (see [`crate::types::math::quantized_key`])
+    ///
+    /// # Panics
+    ///
+    /// Panics if `precision` isn't positive and finite.
+    fn to_key(self, precision: f64) -> IntVector2;
+
+    /// Vector with these X and Y components and the given Z
+    fn to_3d(self, z: f64) -> Vector;
 }
 
 impl Vector2DExt for Vector2D {
@@ -133,6 +455,14 @@ impl Vector2DExt for Vector2D {
     fn is_nearly_zero(self, tolerance: f64) -> bool {
         self.length_squared() <= tolerance * tolerance
     }
+
+    fn to_key(self, precision: f64) -> IntVector2 {
+        IntVector2::new(quantize(self.x, precision), quantize(self.y, precision))
+    }
+
+    fn to_3d(self, z: f64) -> Vector {
+        self.extend(z)
+    }
 }
 
 #[cfg(test)]
@@ -157,6 +487,83 @@ mod tests {
         assert!(normalized.is_normalized());
     }
 
+    #[test]
+    fn test_vinterp_to_converges() {
+        let target = Vector::new(100.0, 0.0, 0.0);
+        let mut current = Vector::ZERO;
+        let mut frames = 0;
+        while current != target {
+            current = current.vinterp_to(target, 1.0 / 60.0, 6.0);
+            frames += 1;
+            assert!(frames < 200, "vinterp_to did not converge");
+        }
+        // 10% of the remaining distance per frame until within the 0.01 snap
+        // radius: ln(1e-4) / ln(0.9) ~= 88 frames
+        assert!((85..=90).contains(&frames));
+
+        // Non-positive speed snaps straight to the target
+        assert_eq!(Vector::ZERO.vinterp_to(target, 0.1, 0.0), target);
+    }
+
+    #[test]
+    fn test_vinterp_constant_to() {
+        let target = Vector::new(10.0, 0.0, 0.0);
+        let mut current = Vector::ZERO;
+        for frame in 1..=4 {
+            current = current.vinterp_constant_to(target, 0.5, 5.0);
+            let expected = (frame as f64 * 2.5).min(10.0);
+            assert!((current.x - expected).abs() < 1e-12);
+        }
+        assert_eq!(current, target);
+
+        // Exact arrival when the remaining distance is below the step
+        let near = Vector::new(9.9, 0.0, 0.0);
+        assert_eq!(near.vinterp_constant_to(target, 0.5, 5.0), target);
+
+        // Zero speed never moves
+        assert_eq!(Vector::ZERO.vinterp_constant_to(target, 0.5, 0.0), Vector::ZERO);
+    }
+
+    #[test]
+    fn test_smooth_damp_no_overshoot() {
+        let target = Vector::new(0.0, 50.0, -20.0);
+        let mut current = Vector::ZERO;
+        let mut velocity = Vector::ZERO;
+        let initial_dist = (target - current).length();
+        let mut previous_dist = initial_dist;
+
+        for _ in 0..120 {
+            current = current.smooth_damp(target, &mut velocity, 0.3, f64::INFINITY, 1.0 / 60.0);
+            let dist = (target - current).length();
+            assert!(dist <= previous_dist + 1e-12);
+            // Never passes the target along the travel direction
+            assert!((target - current).dot(target) >= -1e-9);
+            previous_dist = dist;
+        }
+        assert!(previous_dist < initial_dist * 0.01);
+
+        // max_speed caps the distance travelled per step
+        let mut velocity = Vector::ZERO;
+        let next = Vector::ZERO.smooth_damp(target, &mut velocity, 0.1, 10.0, 0.1);
+        assert!(next.length() <= 10.0 * 0.1 * 2.0);
+    }
+
+    #[test]
+    fn test_vector3f_interp() {
+        let target = glam::Vec3::new(1.0, 2.0, 3.0);
+        assert_eq!(glam::Vec3::ZERO.vinterp_constant_to(target, 1.0, 100.0), target);
+
+        let halfway = glam::Vec3::ZERO.vinterp_to(target, 0.5, 1.0);
+        assert!((halfway - target * 0.5).length() < 1e-6);
+
+        let mut velocity = glam::Vec3::ZERO;
+        let mut current = glam::Vec3::ZERO;
+        for _ in 0..300 {
+            current = current.smooth_damp(target, &mut velocity, 0.2, f32::INFINITY, 1.0 / 60.0);
+        }
+        assert!((current - target).length() < 1e-3);
+    }
+
     #[test]
     fn test_vector_edge_cases() {
         // Test with NaN values
@@ -218,4 +625,87 @@ mod tests {
         assert!(cross1.dot(v1).abs() < 0.001); // Perpendicular to both vectors
         assert!(cross1.dot(v2).abs() < 0.001);
     }
+
+    #[test]
+    fn test_component_helpers() {
+        let v = Vector::new(-7.0, 2.0, 5.0);
+        assert_eq!(v.get_abs(), Vector::new(7.0, 2.0, 5.0));
+        assert_eq!((v.get_max(), v.get_min(), v.get_abs_max()), (5.0, -7.0, 7.0));
+        let other = Vector::new(1.0, -3.0, 5.5);
+        assert_eq!(v.component_max(other), Vector::new(1.0, 2.0, 5.5));
+        assert_eq!(v.component_min(other), Vector::new(-7.0, -3.0, 5.0));
+
+        assert_eq!(Vector::new(12.0, -12.0, 13.0).grid_snap(5.0), Vector::new(10.0, -10.0, 15.0));
+        assert_eq!(v.grid_snap(0.0), v);
+        assert!(Vector::new(2.0, 2.05, 1.95).is_uniform(0.1));
+        assert!(!Vector::new(2.0, 2.05, 1.85).is_uniform(0.1));
+
+        assert_eq!(v.to_2d(), Vector2D::new(-7.0, 2.0));
+        assert_eq!(v.to_2d().to_3d(5.0), v);
+    }
+
+    #[test]
+    fn test_projection_is_idempotent() {
+        let v = Vector::new(3.0, -4.0, 12.0);
+        let onto = Vector::new(2.0, 1.0, -2.0);
+        let projected = v.project_on_to(onto);
+        assert!((projected.project_on_to(onto) - projected).length() < 1e-12);
+        // What's left over is perpendicular to the target
+        assert!((v - projected).dot(onto).abs() < 1e-12);
+        assert_eq!(v.project_on_to(Vector::ZERO), Vector::ZERO);
+
+        let normal = onto.normalize();
+        assert!((v.project_on_to_normal(normal) - projected).length() < 1e-12);
+        let twice = v.project_on_to_normal(normal).project_on_to_normal(normal);
+        assert!((twice - projected).length() < 1e-12);
+    }
+
+    #[test]
+    fn test_mirror_by_vector() {
+        // Bouncing off a floor flips Z only
+        assert_eq!(Vector::new(3.0, 4.0, -5.0).mirror_by_vector(Vector::Z), Vector::new(3.0, 4.0, 5.0));
+
+        let normal = Vector::new(1.0, -2.0, 0.5).normalize();
+        for v in [Vector::new(3.0, -4.0, 12.0), Vector::new(-0.1, 0.0, 7.0), Vector::splat(-2.5)] {
+            let mirrored = v.mirror_by_vector(normal);
+            assert!((mirrored.length() - v.length()).abs() < 1e-12);
+            assert!((mirrored.dot(normal) + v.dot(normal)).abs() < 1e-12);
+            assert!((mirrored.mirror_by_vector(normal) - v).length() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_rotate_angle_axis_and_cos_angle() {
+        assert!((Vector::X.rotate_angle_axis(90.0, Vector::Z) - Vector::Y).length() < 1e-12);
+        assert!((Vector::Y.rotate_angle_axis(90.0, Vector::X) - Vector::Z).length() < 1e-12);
+        let axis = Vector::new(1.0, 1.0, 1.0).normalize();
+        // A third of a turn about the diagonal cycles the axes
+        assert!((Vector::X.rotate_angle_axis(120.0, axis) - Vector::Y).length() < 1e-12);
+        let v = Vector::new(3.0, -4.0, 12.0);
+        assert!((v.rotate_angle_axis(37.0, axis).length() - v.length()).abs() < 1e-12);
+
+        assert!((Vector::new(1.0, 0.0, 50.0).cos_angle_2d(Vector::new(1.0, 1.0, -3.0)) - core::f64::consts::FRAC_1_SQRT_2).abs() < 1e-12);
+        assert_eq!(Vector::X.cos_angle_2d(Vector::new(-4.0, 0.0, 0.0)), -1.0);
+        assert_eq!(Vector::Z.cos_angle_2d(Vector::X), 0.0);
+    }
+
+    #[test]
+    fn test_vector3f_helpers() {
+        let v = glam::Vec3::new(-7.0, 2.0, 5.0);
+        assert_eq!((v.get_max(), v.get_min(), v.get_abs_max()), (5.0, -7.0, 7.0));
+        assert_eq!(v.mirror_by_vector(glam::Vec3::X), glam::Vec3::new(7.0, 2.0, 5.0));
+        assert!((glam::Vec3::X.rotate_angle_axis(90.0, glam::Vec3::Z) - glam::Vec3::Y).length() < 1e-6);
+        assert_eq!(v.grid_snap(4.0), glam::Vec3::new(-8.0, 4.0, 4.0));
+        assert_eq!(v.to_2d(), glam::Vec2::new(-7.0, 2.0));
+        assert!(glam::Vec3::splat(1.5).is_uniform(0.0));
+    }
+
+    #[test]
+    fn test_axis_components() {
+        let mut v = Vector::new(1.0, 2.0, 3.0);
+        assert_eq!(Axis::ALL.map(|axis| v.get_component(axis)), [1.0, 2.0, 3.0]);
+        v.set_component(Axis::Y, -8.0);
+        assert_eq!(v, Vector::new(1.0, -8.0, 3.0));
+        assert_eq!(v.get_component(Axis::Y), v.dot(Axis::Y.to_vector()));
+    }
 }
\ No newline at end of file
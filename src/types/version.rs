@@ -2,10 +2,19 @@
 
 use crate::BinarySerializable;
 use serde::{Deserialize, Serialize};
-use std::fmt;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::format;
 
-/// UE5-style Version information
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+/// UE5-style engine version (equivalent to FEngineVersion)
+///
+/// Formatted as `major.minor.patch[-changelist][+branch]`, for example
+/// `5.3.2-29314046+++UE5+Release-5.3`. Equality, hashing and ordering
+/// ignore the branch name.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Version {
     /// Major version number
     pub major: u16,
@@ -13,66 +22,190 @@ pub struct Version {
     pub minor: u16,
     /// Patch version number
     pub patch: u16,
-    /// Build number
-    pub build: u16,
+    /// Source control changelist (0 if unknown)
+    pub changelist: u32,
+    /// Branch name (empty if unknown)
+    pub branch: String,
 }
 
+/// Reason a version string could not be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VersionParseError {
+    /// The input was empty
+    Empty,
+    /// A required numeric component was missing
+    MissingComponent(&'static str),
+    /// A numeric component was not a valid number
+    InvalidComponent { component: &'static str, value: String },
+    /// More than major.minor.patch was given
+    TooManyComponents(usize),
+    /// The changelist after `-` was not a valid number
+    InvalidChangelist(String),
+}
+
+impl fmt::Display for VersionParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VersionParseError::Empty => write!(f, "version string is empty"),
+            VersionParseError::MissingComponent(component) => {
+                write!(f, "version is missing the {} component", component)
+            }
+            VersionParseError::InvalidComponent { component, value } => {
+                write!(f, "invalid {} version \"{}\"", component, value)
+            }
+            VersionParseError::TooManyComponents(count) => {
+                write!(f, "expected major.minor.patch but found {} components", count)
+            }
+            VersionParseError::InvalidChangelist(value) => write!(f, "invalid changelist \"{}\"", value),
+        }
+    }
+}
+
+impl core::error::Error for VersionParseError {}
+
 impl fmt::Display for Version {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Version({}.{}.{}.{})", self.major, self.minor, self.patch, self.build)
+        write!(f, "Version({})", self.to_string())
     }
 }
 
 impl BinarySerializable for Version {}
 
 impl Version {
-    /// Create a new version
-    pub fn new(major: u16, minor: u16, patch: u16, build: u16) -> Self {
-        Self { major, minor, patch, build }
+    /// Create a new major.minor.patch version with no changelist or branch
+    pub fn new(major: u16, minor: u16, patch: u16) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+            changelist: 0,
+            branch: String::new(),
+        }
+    }
+
+    /// Create a version with every field set
+    pub fn with_details(major: u16, minor: u16, patch: u16, changelist: u32, branch: impl Into<String>) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+            changelist,
+            branch: branch.into(),
+        }
     }
 
     /// Create version with just major.minor.patch
     pub fn from_semver(major: u16, minor: u16, patch: u16) -> Self {
-        Self::new(major, minor, patch, 0)
+        Self::new(major, minor, patch)
     }
 
-    /// Parse version string (format: "major.minor.patch.build" or "major.minor.patch")
-    pub fn parse(s: &str) -> Result<Self, &'static str> {
-        let parts: Vec<&str> = s.split('.').collect();
-        if parts.len() < 3 || parts.len() > 4 {
-            return Err("Invalid version format");
+    /// Parse a version string (format: "major.minor.patch[-changelist][+branch]")
+    pub fn parse(s: &str) -> Result<Self, VersionParseError> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(VersionParseError::Empty);
         }
 
-        let major = parts[0].parse().map_err(|_| "Invalid major version")?;
-        let minor = parts[1].parse().map_err(|_| "Invalid minor version")?;
-        let patch = parts[2].parse().map_err(|_| "Invalid patch version")?;
-        let build = if parts.len() > 3 {
-            parts[3].parse().map_err(|_| "Invalid build number")?
-        } else {
-            0
+        let (head, branch) = match s.split_once('+') {
+            Some((head, branch)) => (head, branch),
+            None => (s, ""),
+        };
+        let (numbers, changelist) = match head.split_once('-') {
+            Some((numbers, changelist)) => {
+                let parsed = changelist
+                    .parse()
+                    .map_err(|_| VersionParseError::InvalidChangelist(changelist.to_string()))?;
+                (numbers, parsed)
+            }
+            None => (head, 0),
         };
 
-        Ok(Self::new(major, minor, patch, build))
+        let parts: Vec<&str> = numbers.split('.').collect();
+        if parts.len() > 3 {
+            return Err(VersionParseError::TooManyComponents(parts.len()));
+        }
+
+        let component = |index: usize, name: &'static str| -> Result<u16, VersionParseError> {
+            let value = parts
+                .get(index)
+                .filter(|part| !part.is_empty())
+                .ok_or(VersionParseError::MissingComponent(name))?;
+            value.parse().map_err(|_| VersionParseError::InvalidComponent {
+                component: name,
+                value: value.to_string(),
+            })
+        };
+
+        Ok(Self::with_details(
+            component(0, "major")?,
+            component(1, "minor")?,
+            component(2, "patch")?,
+            changelist,
+            branch,
+        ))
     }
 
     /// Convert to string representation
-    pub fn to_string(self) -> String {
-        if self.build == 0 {
-            format!("{}.{}.{}", self.major, self.minor, self.patch)
-        } else {
-            format!("{}.{}.{}.{}", self.major, self.minor, self.patch, self.build)
+    #[allow(clippy::inherent_to_string_shadow_display)]
+    pub fn to_string(&self) -> String {
+        let mut result = format!("{}.{}.{}", self.major, self.minor, self.patch);
+        if self.changelist != 0 {
+            result.push_str(&format!("-{}", self.changelist));
+        }
+        if !self.branch.is_empty() {
+            result.push('+');
+            result.push_str(&self.branch);
         }
+        result
+    }
+
+    /// Check if this version is compatible with another (same major and minor version)
+    pub fn is_compatible_with(&self, other: &Version) -> bool {
+        self.major == other.major && self.minor == other.minor
+    }
+
+    fn ordering_key(&self) -> (u16, u16, u16, u32) {
+        (self.major, self.minor, self.patch, self.changelist)
+    }
+}
+
+impl PartialEq for Version {
+    fn eq(&self, other: &Self) -> bool {
+        self.ordering_key() == other.ordering_key()
+    }
+}
+
+impl Eq for Version {}
+
+impl Hash for Version {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.ordering_key().hash(state);
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.ordering_key().cmp(&other.ordering_key())
     }
+}
+
+impl core::str::FromStr for Version {
+    type Err = VersionParseError;
 
-    /// Check if this version is compatible with another (same major version)
-    pub fn is_compatible_with(self, other: Version) -> bool {
-        self.major == other.major
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
     }
 }
 
 impl Default for Version {
     fn default() -> Self {
-        Self::new(1, 0, 0, 0)
+        Self::new(1, 0, 0)
     }
 }
 
@@ -82,15 +215,109 @@ mod tests {
 
     #[test]
     fn test_version() {
-        let v1 = Version::new(1, 2, 3, 4);
-        assert_eq!(v1.to_string(), "1.2.3.4");
-        
+        let v1 = Version::with_details(1, 2, 3, 4, "");
+        assert_eq!(v1.to_string(), "1.2.3-4");
+
         let v2 = Version::from_semver(1, 2, 3);
         assert_eq!(v2.to_string(), "1.2.3");
-        
-        let parsed = Version::parse("1.2.3.4").unwrap();
+
+        let parsed = Version::parse("1.2.3-4").unwrap();
         assert_eq!(parsed, v1);
-        
-        assert!(v1.is_compatible_with(v2));
+
+        assert!(v1.is_compatible_with(&v2));
+    }
+
+    #[test]
+    fn test_version_parse_engine_format() {
+        let version = Version::parse("5.3.2-29314046+++UE5+Release-5.3").unwrap();
+        assert_eq!((version.major, version.minor, version.patch), (5, 3, 2));
+        assert_eq!(version.changelist, 29314046);
+        assert_eq!(version.branch, "++UE5+Release-5.3");
+        assert_eq!(version.to_string(), "5.3.2-29314046+++UE5+Release-5.3");
+
+        let parsed: Version = "5.4.0".parse().unwrap();
+        assert_eq!(parsed, Version::new(5, 4, 0));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_version_parse_missing_changelist() {
+        let version = Version::parse("5.3.2+++UE5+Release-5.3").unwrap();
+        assert_eq!(version.changelist, 0);
+        assert_eq!(version.branch, "++UE5+Release-5.3");
+
+        let version = Version::parse("5.3.2").unwrap();
+        assert_eq!(version.changelist, 0);
+        assert!(version.branch.is_empty());
+    }
+
+    #[test]
+    fn test_version_parse_errors() {
+        assert_eq!(Version::parse(""), Err(VersionParseError::Empty));
+        assert_eq!(Version::parse("5.3"), Err(VersionParseError::MissingComponent("patch")));
+        assert_eq!(Version::parse("5..1"), Err(VersionParseError::MissingComponent("minor")));
+        assert_eq!(Version::parse("5.3.2.1"), Err(VersionParseError::TooManyComponents(4)));
+        assert_eq!(
+            Version::parse("5.x.2"),
+            Err(VersionParseError::InvalidComponent { component: "minor", value: "x".to_string() })
+        );
+        assert_eq!(
+            Version::parse("5.3.2-abc+Main"),
+            Err(VersionParseError::InvalidChangelist("abc".to_string()))
+        );
+        assert_eq!(
+            Version::parse("70000.0.0"),
+            Err(VersionParseError::InvalidComponent { component: "major", value: "70000".to_string() })
+        );
+
+        let message = Version::parse("5.3").unwrap_err().to_string();
+        assert!(message.contains("patch"));
+    }
+
+    #[test]
+    fn test_version_ordering() {
+        let base = Version::parse("5.3.1-200").unwrap();
+        let newer_patch = Version::parse("5.3.2-100").unwrap();
+        let newer_changelist = Version::parse("5.3.1-300").unwrap();
+        let other_branch = Version::parse("5.3.1-200+Dev-Main").unwrap();
+
+        assert!(base < newer_patch);
+        assert!(base < newer_changelist);
+        assert!(newer_changelist < newer_patch);
+        assert!(Version::new(4, 27, 9) < Version::new(5, 0, 0));
+
+        // Branch is ignored for comparisons
+        assert_eq!(base, other_branch);
+        assert_eq!(base.cmp(&other_branch), Ordering::Equal);
+
+        let mut versions = [newer_patch.clone(), base.clone(), newer_changelist.clone()];
+        versions.sort();
+        assert_eq!(versions, [base, newer_changelist, newer_patch]);
+    }
+
+    #[test]
+    fn test_version_compatibility() {
+        let server = Version::parse("5.3.2-29314046").unwrap();
+        assert!(server.is_compatible_with(&Version::new(5, 3, 0)));
+        assert!(!server.is_compatible_with(&Version::new(5, 4, 2)));
+        assert!(!server.is_compatible_with(&Version::new(4, 3, 2)));
+    }
+
+    #[test]
+    fn test_version_serialization() {
+        let version = Version::parse("5.3.2-29314046+++UE5+Release-5.3").unwrap();
+
+        let json = serde_json::to_string(&version).unwrap();
+        let deserialized: Version = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, version);
+        assert_eq!(deserialized.branch, version.branch);
+
+        let binary = version.to_binary().unwrap();
+        let deserialized = Version::from_binary(&binary).unwrap();
+        assert_eq!(deserialized.to_string(), version.to_string());
+    }
+
+    #[test]
+    fn test_version_display() {
+        assert_eq!(format!("{}", Version::parse("5.3.2-1").unwrap()), "Version(5.3.2-1)");
+    }
+}
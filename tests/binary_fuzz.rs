@@ -0,0 +1,115 @@
+//! Feeds random and corrupted bytes into `from_binary`
+//!
+//! These stand in for cargo-fuzz targets: any panic or OOM abort fails the
+//! test run. Random input may happen to decode (every 80-byte buffer is a
+//! valid `Transform`), so those cases only require that decoding returns.
+//! Truncated and oversized inputs must return `Err`.
+
+use proptest::prelude::*;
+use ue_types::{BinarySerializable, GameSessionInfo, NetworkGUID, RepMovement, Rotator, TArray, Transform, Vector};
+
+fn sample_array() -> TArray<Vector> {
+    TArray::from_vec((0..8).map(|i| Vector::new(i as f64, -2.5 * i as f64, 100.0)).collect())
+}
+
+fn sample_movement() -> RepMovement {
+    RepMovement::from_transform(Vector::new(1200.5, -340.25, 88.0), Rotator::new(-5.0, 90.0, 2.5), Vector::new(600.0, 0.0, -12.0))
+}
+
+fn sample_session() -> GameSessionInfo {
+    let mut session = GameSessionInfo::new_at(
+        1_700_000_000,
+        "Friday Night Frags".to_string(),
+        "Deathmatch".to_string(),
+        "/Game/Maps/Arena".to_string(),
+        16,
+    );
+    session.session_id = NetworkGUID::new(4242);
+    session.set_region("eu-west".to_string());
+    session.set_custom_property("ranked", true);
+    session.set_custom_property("score_limit", 50);
+    session
+}
+
+/// Encodings of each target type, for mutation-based cases
+fn seeds() -> Vec<Vec<u8>> {
+    vec![
+        sample_array().to_binary().unwrap(),
+        sample_movement().to_binary().unwrap(),
+        Transform::from_location(Vector::new(1.0, 2.0, 3.0)).to_binary().unwrap(),
+        sample_session().to_binary().unwrap(),
+    ]
+}
+
+/// Decode `bytes` as every target type, discarding the results
+fn decode_all(bytes: &[u8]) {
+    let _ = TArray::<Vector>::from_binary(bytes);
+    let _ = RepMovement::from_binary(bytes);
+    let _ = Transform::from_binary(bytes);
+    let _ = GameSessionInfo::from_binary(bytes);
+}
+
+proptest! {
+    #[test]
+    fn random_bytes_never_panic(bytes in proptest::collection::vec(any::<u8>(), 0..512)) {
+        decode_all(&bytes);
+    }
+
+    #[test]
+    fn mutated_encodings_never_panic(
+        seed in 0usize..4,
+        flips in proptest::collection::vec((any::<usize>(), any::<u8>()), 1..8),
+    ) {
+        let mut bytes = seeds().swap_remove(seed);
+        for (index, value) in flips {
+            let len = bytes.len();
+            bytes[index % len] = value;
+        }
+        decode_all(&bytes);
+    }
+
+    #[test]
+    fn truncated_encodings_are_errors(cut in 0.0f64..1.0) {
+        let at = |bytes: &[u8]| (bytes.len() as f64 * cut) as usize;
+
+        let bytes = sample_array().to_binary().unwrap();
+        prop_assert!(TArray::<Vector>::from_binary(&bytes[..at(&bytes)]).is_err());
+        let bytes = sample_movement().to_binary().unwrap();
+        prop_assert!(RepMovement::from_binary(&bytes[..at(&bytes)]).is_err());
+        let bytes = Transform::IDENTITY.to_binary().unwrap();
+        prop_assert!(Transform::from_binary(&bytes[..at(&bytes)]).is_err());
+        let bytes = sample_session().to_binary().unwrap();
+        prop_assert!(GameSessionInfo::from_binary(&bytes[..at(&bytes)]).is_err());
+    }
+
+    #[test]
+    fn huge_length_prefixes_are_errors(len in (1u64 << 24)..=u64::MAX, tail in proptest::collection::vec(any::<u8>(), 0..64)) {
+        let mut bytes = len.to_le_bytes().to_vec();
+        bytes.extend_from_slice(&tail);
+        prop_assert!(TArray::<Vector>::from_binary(&bytes).is_err());
+        prop_assert!(TArray::<u8>::from_binary(&bytes).is_err());
+    }
+}
+
+#[test]
+fn seeds_round_trip() {
+    assert_eq!(TArray::<Vector>::from_binary(&seeds()[0]).unwrap(), sample_array());
+    assert_eq!(RepMovement::from_binary(&seeds()[1]).unwrap(), sample_movement());
+    assert_eq!(GameSessionInfo::from_binary(&seeds()[3]).unwrap(), sample_session());
+}
+
+#[test]
+fn session_with_bogus_string_length_is_an_error() {
+    // The 4-byte session id is followed by the name's length prefix
+    let mut bytes = sample_session().to_binary().unwrap();
+    assert_eq!(bytes[4..12], 18u64.to_le_bytes());
+    bytes[4..12].copy_from_slice(&u64::MAX.to_le_bytes());
+    assert!(GameSessionInfo::from_binary(&bytes).is_err());
+}
+
+#[test]
+fn limit_is_overridable() {
+    let bytes = sample_session().to_binary().unwrap();
+    assert!(GameSessionInfo::from_binary_with_limit(&bytes, bytes.len() as u64).is_ok());
+    assert!(GameSessionInfo::from_binary_with_limit(&bytes, bytes.len() as u64 / 2).is_err());
+}
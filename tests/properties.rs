@@ -0,0 +1,97 @@
+//! Property tests for `Transform`, `Vector` and `Rotator` math
+
+use proptest::prelude::*;
+use ue_types::{BinarySerializable, DQuat, Rotator, Transform, Vector, VectorExt};
+
+const TOLERANCE: f64 = 1e-6;
+
+fn vector(range: f64) -> impl Strategy<Value = Vector> {
+    (-range..range, -range..range, -range..range).prop_map(|(x, y, z)| Vector::new(x, y, z))
+}
+
+fn rotation() -> impl Strategy<Value = DQuat> {
+    (-180.0f64..180.0, -180.0f64..180.0, -180.0f64..180.0)
+        .prop_map(|(pitch, yaw, roll)| Rotator::new(pitch, yaw, roll).to_quaternion())
+}
+
+/// Transforms with uniform scale, where `combine` and `inverse` are exact
+fn uniform_transform() -> impl Strategy<Value = Transform> {
+    (vector(1000.0), rotation(), 0.1f64..10.0)
+        .prop_map(|(location, rotation, scale)| Transform::new(location, rotation, Vector::splat(scale)))
+}
+
+/// Transforms with independent, non-zero scale per axis
+fn transform() -> impl Strategy<Value = Transform> {
+    (vector(1000.0), rotation(), vector(10.0))
+        .prop_filter("scale too close to zero", |(_, _, scale)| scale.abs().min_element() > 0.1)
+        .prop_map(|(location, rotation, scale)| Transform::new(location, rotation, scale))
+}
+
+fn nearly_equal(a: Vector, b: Vector) -> bool {
+    (a - b).length() <= TOLERANCE * (1.0 + a.length().max(b.length()))
+}
+
+proptest! {
+    #[test]
+    fn inverse_transform_point_round_trips(transform in transform(), point in vector(1000.0)) {
+        let back = transform.inverse_transform_point(transform.transform_point(point));
+        prop_assert!(nearly_equal(back, point), "{:?} != {:?}", back, point);
+    }
+
+    #[test]
+    fn inverse_undoes_transform(transform in uniform_transform(), point in vector(1000.0)) {
+        let back = transform.inverse().transform_point(transform.transform_point(point));
+        prop_assert!(nearly_equal(back, point), "{:?} != {:?}", back, point);
+        prop_assert!(transform.combine(transform.inverse()).is_nearly_identity(TOLERANCE));
+    }
+
+    #[test]
+    fn combine_is_associative_for_uniform_scale(
+        a in uniform_transform(),
+        b in uniform_transform(),
+        c in uniform_transform(),
+        point in vector(100.0),
+    ) {
+        let left = a.combine(b).combine(c);
+        let right = a.combine(b.combine(c));
+        prop_assert!(nearly_equal(left.transform_point(point), right.transform_point(point)));
+        prop_assert!(left.is_nearly_equal(right, 1e-4));
+    }
+
+    #[test]
+    fn combine_matches_applying_child_then_parent(parent in uniform_transform(), child in uniform_transform(), point in vector(100.0)) {
+        let expected = parent.transform_point(child.transform_point(point));
+        prop_assert!(nearly_equal(parent.combine(child).transform_point(point), expected));
+    }
+
+    #[test]
+    fn transform_binary_round_trips(transform in transform()) {
+        prop_assert_eq!(Transform::from_binary(&transform.to_binary().unwrap()).unwrap(), transform);
+    }
+
+    #[test]
+    fn vector_binary_round_trips(v in vector(1e12)) {
+        prop_assert_eq!(Vector::from_binary(&v.to_binary().unwrap()).unwrap(), v);
+    }
+
+    #[test]
+    fn safe_normal_is_unit_or_zero(v in vector(1e6)) {
+        let normal = v.get_safe_normal(1e-8);
+        prop_assert!(normal == Vector::ZERO || normal.is_normalized());
+        prop_assert!(normal.dot(v) >= 0.0);
+    }
+
+    #[test]
+    fn vinterp_constant_to_never_overshoots(from in vector(1000.0), to in vector(1000.0), dt in 0.0f64..1.0, speed in 0.0f64..5000.0) {
+        let next = from.vinterp_constant_to(to, dt, speed);
+        prop_assert!(next.distance(to) <= from.distance(to) + TOLERANCE);
+        prop_assert!(next.distance(from) <= speed * dt + TOLERANCE);
+    }
+
+    #[test]
+    fn rotator_quaternion_round_trips(pitch in -89.0f64..89.0, yaw in -179.0f64..179.0, roll in -179.0f64..179.0) {
+        let rotator = Rotator::new(pitch, yaw, roll);
+        let back = Rotator::from_quaternion(rotator.to_quaternion());
+        prop_assert!(back.is_nearly_equal(rotator, 1e-6), "{:?} != {:?}", back, rotator);
+    }
+}
@@ -0,0 +1,76 @@
+//! Checks that reading an rkyv archive doesn't touch the heap
+//!
+//! Lives in its own test binary because it installs a counting
+//! `#[global_allocator]`, which would otherwise apply to every unit test.
+
+#![cfg(feature = "rkyv")]
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use ue_types::{ArchivedRepMovement, NetworkGUID, RepMovement, Rotator, Vector, ZeroCopy};
+
+/// System allocator that counts allocations made by the current thread
+struct CountingAllocator;
+
+std::thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(Cell::get)
+}
+
+fn movement() -> RepMovement {
+    let mut movement = RepMovement::from_transform(
+        Vector::new(1200.5, -340.25, 88.0),
+        Rotator::new(-5.0, 90.0, 2.5),
+        Vector::new(600.0, 0.0, -12.0),
+    );
+    movement.angular_velocity = Vector::new(0.0, 45.0, 0.0);
+    movement.set_location_base(NetworkGUID::new(42), Vector::new(0.0, 0.0, 50.0));
+    movement.server_frame = 9_001;
+    movement.is_simulated = true;
+    movement
+}
+
+#[test]
+fn test_archived_read_does_not_allocate() {
+    // Archive once up front, as a snapshot buffer received from elsewhere would be
+    let snapshots: Vec<_> = (0..64)
+        .map(|frame| {
+            let mut movement = movement();
+            movement.server_frame = frame;
+            movement.location.x += frame as f64;
+            movement.to_archive_bytes().unwrap()
+        })
+        .collect();
+
+    // The counter sees the archiving above, so a zero delta below is meaningful
+    assert!(allocations() >= snapshots.len());
+    let before = allocations();
+    let mut total_x = 0.0;
+    let mut last_frame = 0;
+    for bytes in &snapshots {
+        let archived: &ArchivedRepMovement = RepMovement::as_archived(bytes).unwrap();
+        total_x += archived.location.x;
+        last_frame = archived.server_frame;
+        assert!(archived.has_location_base);
+    }
+    assert_eq!(allocations(), before);
+    assert_eq!(last_frame, 63);
+    assert_eq!(total_x, 64.0 * 1200.5 + (0..64).sum::<u32>() as f64);
+}